@@ -53,6 +53,26 @@ impl CfgEvaluator for CargoEnvCfgEvaluator {
     }
 }
 
+/// The C++ code of a cxx-qt bridge is generated for the same cfgs as the C++ code of its CXX bridge
+impl cxx_qt_gen::CfgEvaluator for CargoEnvCfgEvaluator {
+    fn eval(&self, name: &str, query_value: Option<&str>) -> cxx_qt_gen::CfgResult {
+        match CfgEvaluator::eval(self, name, query_value) {
+            CfgResult::True => cxx_qt_gen::CfgResult::True,
+            CfgResult::False => cxx_qt_gen::CfgResult::False,
+            CfgResult::Undetermined { msg } => cxx_qt_gen::CfgResult::Undetermined { msg },
+        }
+    }
+}
+
+/// Whether the Cargo feature with the given name is enabled for the crate of the build script
+///
+/// This reads the environment directly rather than through [ENV],
+/// so that it can be called before the build script has defined all of its cfgs.
+pub(super) fn feature_enabled(feature: &str) -> bool {
+    let name = feature.to_uppercase().replace('-', "_");
+    env::var_os(format!("CARGO_FEATURE_{name}")).is_some()
+}
+
 impl CargoEnv {
     fn load() -> Self {
         const CARGO_FEATURE_PREFIX: &str = "CARGO_FEATURE_";
@@ -7,16 +7,16 @@
 
 use serde::{Deserialize, Serialize};
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
 /// When generating a library with cxx-qt-build, the library may need to export certain flags or headers.
 /// These are all specified by this Interface struct, which should be passed to the [crate::CxxQtBuilder::library] function.
 pub struct Interface {
-    pub(crate) compile_definitions: HashMap<String, Option<String>>,
+    pub(crate) compile_definitions: BTreeMap<String, Option<String>>,
     pub(crate) initializers: Vec<PathBuf>,
     // The name of the links keys, whose CXX-Qt dependencies to reexport
-    pub(crate) reexport_links: HashSet<String>,
+    pub(crate) reexport_links: BTreeSet<String>,
     pub(crate) exported_include_prefixes: Vec<String>,
     pub(crate) exported_include_directories: Vec<(PathBuf, String)>,
     // TODO: In future, we want to also set up the include paths so that you can include anything
@@ -29,9 +29,9 @@ pub struct Interface {
 impl Default for Interface {
     fn default() -> Self {
         Self {
-            compile_definitions: HashMap::new(),
+            compile_definitions: BTreeMap::new(),
             initializers: Vec::new(),
-            reexport_links: HashSet::new(),
+            reexport_links: BTreeSet::new(),
             exported_include_prefixes: vec![super::crate_name()],
             exported_include_directories: Vec::new(),
         }
@@ -48,7 +48,7 @@ impl Interface {
     /// build. Due to technical limitations, they can not be imported into CMake with the
     /// cxxqt_import_crate function.
     pub fn define(mut self, variable: &str, value: Option<&str>) -> Self {
-        use std::collections::hash_map::Entry::*;
+        use std::collections::btree_map::Entry::*;
 
         let entry = self.compile_definitions.entry(variable.to_owned());
         match entry {
@@ -177,9 +177,13 @@ impl Dependency {
     ///
     /// See also the internals "build system" section of our book.
     pub(crate) fn find_all() -> Vec<Dependency> {
-        std::env::vars_os()
+        // Sort the variables, so that the dependencies are always in the same order
+        let mut vars = std::env::vars_os()
             .map(|(var, value)| (var.to_string_lossy().to_string(), value))
             .filter(|(var, _)| var.starts_with("DEP_") && var.ends_with("_CXX_QT_MANIFEST_PATH"))
+            .collect::<Vec<_>>();
+        vars.sort();
+        vars.into_iter()
             .map(|(_, manifest_path)| {
                 let manifest_path = PathBuf::from(manifest_path);
                 let manifest: Manifest = serde_json::from_str(
@@ -205,7 +209,7 @@ impl Dependency {
 pub(crate) fn initializer_paths(
     interface: Option<&Interface>,
     dependencies: &[Dependency],
-) -> HashSet<PathBuf> {
+) -> BTreeSet<PathBuf> {
     dependencies
         .iter()
         .flat_map(|dep| dep.manifest.initializers.iter().cloned())
@@ -264,7 +268,7 @@ pub(crate) fn all_compile_definitions(
 ) -> Vec<(String, Option<String>)> {
     // For each definition, store the name of the crate that defines it so we can generate a
     // nicer error message
-    let mut definitions: HashMap<String, (Option<String>, String)> = interface
+    let mut definitions: BTreeMap<String, (Option<String>, String)> = interface
         .iter()
         .flat_map(|interface| &interface.compile_definitions)
         .map(|(key, value)| (key.clone(), (value.clone(), crate::crate_name())))
@@ -272,7 +276,7 @@ pub(crate) fn all_compile_definitions(
 
     for dependency in dependencies {
         for (variable, value) in &dependency.manifest.defines {
-            use std::collections::hash_map::Entry::*;
+            use std::collections::btree_map::Entry::*;
             let entry = definitions.entry(variable.to_owned());
 
             match entry {
@@ -28,22 +28,29 @@ pub use opts::QObjectHeaderOpts;
 
 mod qml_modules;
 use qml_modules::OwningQmlModule;
-pub use qml_modules::QmlModule;
+pub use qml_modules::{QmlModule, QmlModuleOverride, QmlPluginType};
 
+mod report;
+use report::BridgeReport;
+
+use clang_format::{clang_format_with_style, ClangFormatStyle};
+pub use cxx_qt_gen::RegisteredType;
 pub use qt_build_utils::MocArguments;
 use qt_build_utils::SemVer;
 use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashSet,
+    collections::BTreeSet,
     env,
-    fs::File,
     io::Write,
     path::{Path, PathBuf},
+    process::{Command, Stdio},
 };
 
 use cxx_qt_gen::{
-    parse_qt_file, write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
-    GeneratedRustBlocks, Parser,
+    parse_qt_file, write_cpp_qobject_headers_with_style, write_cpp_with_style, write_qml_docs,
+    write_rust, BridgeMetadata, CppFragment, CxxQtItem, GeneratedCppBlocks, GeneratedRustBlocks,
+    Parser, TypeRegistry,
 };
 
 // TODO: we need to eventually support having multiple modules defined in a single file. This
@@ -54,16 +61,49 @@ use cxx_qt_gen::{
 // QObject macros and at most one "raw CXX" macro per file already. For now this remains a TODO
 // as to keep things simpler. We also want to able to warn users about duplicate names eventually.
 
+#[derive(Serialize, Deserialize)]
 struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
     qobject: Option<PathBuf>,
     qobject_header: Option<PathBuf>,
+    /// Headers of QObjects with a `#[cxx_file_stem]`, which are moc'd separately
+    qobject_class_headers: Vec<PathBuf>,
+    /// The JSON description of the QObjects of the bridge, see [CxxQtBuilder::metadata_json]
+    metadata: Option<PathBuf>,
+    /// The expanded Rust code of the bridge
+    rust: PathBuf,
+}
+
+/// The JSON description of a bridge, which is combined into [CxxQtBuilder::metadata_json]
+struct BridgeMetadataFile {
+    /// The path of the Rust file of the bridge
+    rust_file: PathBuf,
+    /// The URI of the QML module of the bridge, if it is part of one
+    qml_uri: Option<String>,
+    /// The path of the JSON description of the bridge
+    path: PathBuf,
+}
+
+/// How the generated C++ code is formatted, see [CxxQtBuilder::clang_format_style_file]
+#[derive(Debug)]
+struct CppFormatting {
+    /// The clang-format style, or `None` to leave the generated code unformatted
+    style: Option<ClangFormatStyle>,
+    /// Whether the code generated by cxx is formatted too, which is only done when requested
+    /// as it slows down the build
+    format_cxx: bool,
 }
 
 struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
+    /// The headers of QObjects with a `#[cxx_file_stem]`, with their file stem
+    cxx_qt_qobject_headers: Vec<(String, String)>,
+    /// The description of the QObjects of the bridge
+    metadata: Option<BridgeMetadata>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    /// The Rust code of the bridge after the CXX-Qt expansion, which is passed to cxx
+    rust: String,
 }
 
 impl GeneratedCpp {
@@ -72,6 +112,8 @@ impl GeneratedCpp {
         rust_file_path: impl AsRef<Path>,
         relative_path: impl AsRef<Path>,
         include_prefix: &str,
+        formatting: &CppFormatting,
+        type_registry: &TypeRegistry,
     ) -> Result<Self, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
@@ -82,6 +124,8 @@ impl GeneratedCpp {
             .map_err(to_diagnostic)?;
 
         let mut cxx_qt = None;
+        let mut cxx_qt_qobject_headers = vec![];
+        let mut metadata = None;
         let mut tokens = proc_macro2::TokenStream::new();
 
         // Add any attributes in the file into the tokenstream
@@ -97,7 +141,8 @@ impl GeneratedCpp {
             // Remove the .rs extension
             .with_extension("")
             .to_string_lossy()
-            .to_string();
+            // Use the same separator on all platforms, so that the generated code is identical
+            .replace('\\', "/");
 
         // The include path we inject needs any prefix (eg the crate name) too
         let include_ident = format!("{include_prefix}/{file_ident}");
@@ -128,19 +173,36 @@ impl GeneratedCpp {
                     }
                     found_bridge = true;
 
-                    let parser = Parser::from(m.clone())
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    let generated_cpp = GeneratedCppBlocks::from(&parser)
+                    let parser = Parser::from_with_registry(m.clone(), type_registry)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
+                    metadata = Some(
+                        BridgeMetadata::from(&parser)
+                            .map_err(GeneratedError::from)
+                            .map_err(to_diagnostic)?,
+                    );
+                    let generated_cpp = GeneratedCppBlocks::from_with_cfg_evaluator(
+                        &parser,
+                        &cfg_evaluator::CargoEnvCfgEvaluator,
+                    )
+                    .map_err(GeneratedError::from)
+                    .map_err(to_diagnostic)?;
                     let generated_rust = GeneratedRustBlocks::from(&parser)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
 
                     // TODO: we'll have to extend the C++ data here rather than overwriting
                     // assuming we share the same file
-                    cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
+                    cxx_qt = Some(write_cpp_with_style(
+                        &generated_cpp,
+                        &include_ident,
+                        formatting.style.as_ref(),
+                    ));
+                    cxx_qt_qobject_headers = write_cpp_qobject_headers_with_style(
+                        &generated_cpp,
+                        &include_ident,
+                        formatting.style.as_ref(),
+                    );
                     let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
 
                     // We need to do this and can't rely on the macro, as we need to generate the
@@ -153,19 +215,122 @@ impl GeneratedCpp {
             }
         }
 
+        let rust = tokens.to_string();
         let mut opt = cxx_gen::Opt::default();
         opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
-        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
+        let mut cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
+        if let (true, Some(style)) = (formatting.format_cxx, &formatting.style) {
+            let format_cxx = |code: &[u8]| {
+                let code = String::from_utf8_lossy(code);
+                clang_format_with_style(&code, style)
+                    .map(String::into_bytes)
+                    .ok()
+            };
+            if let Some(header) = format_cxx(&cxx.header) {
+                cxx.header = header;
+            }
+            if let Some(implementation) = format_cxx(&cxx.implementation) {
+                cxx.implementation = implementation;
+            }
+        }
 
         Ok(GeneratedCpp {
             cxx_qt,
+            cxx_qt_qobject_headers,
+            metadata,
             cxx,
             file_ident,
+            rust,
         })
     }
 
+    /// Write the generated Rust and C++ code in a readable form into the given directory,
+    /// for inspecting the generated code when debugging a bridge.
+    ///
+    /// The Rust code is formatted with rustfmt and the C++ code with clang-format, if they are available.
+    pub fn write_dump(&self, dump_directory: impl AsRef<Path>) {
+        let dump_path = |extension: &str| {
+            let path = dump_directory
+                .as_ref()
+                .join(format!("{}.{extension}", self.file_ident));
+            if let Some(directory) = path.parent() {
+                std::fs::create_dir_all(directory)
+                    .expect("Could not create directory to dump generated files");
+            }
+            path
+        };
+        let format_cpp = |cpp: &str| {
+            clang_format_with_style(cpp, &ClangFormatStyle::File).unwrap_or_else(|_| cpp.to_owned())
+        };
+
+        std::fs::write(dump_path("rs"), format_rust(&self.rust))
+            .expect("Could not dump generated Rust code");
+        if let Some(CppFragment::Pair { header, source }) = &self.cxx_qt {
+            std::fs::write(dump_path("cxxqt.h"), header)
+                .expect("Could not dump generated cxx-qt header");
+            std::fs::write(dump_path("cxxqt.cpp"), source)
+                .expect("Could not dump generated cxx-qt source");
+        }
+        for (cxx_file_stem, header) in &self.cxx_qt_qobject_headers {
+            let path = self.qobject_header_path(dump_directory.as_ref(), cxx_file_stem);
+            std::fs::write(path, header).expect("Could not dump generated cxx-qt QObject header");
+        }
+        std::fs::write(
+            dump_path("cxx.h"),
+            format_cpp(&String::from_utf8_lossy(&self.cxx.header)),
+        )
+        .expect("Could not dump generated cxx header");
+        std::fs::write(
+            dump_path("cxx.cpp"),
+            format_cpp(&String::from_utf8_lossy(&self.cxx.implementation)),
+        )
+        .expect("Could not dump generated cxx source");
+    }
+
+    /// Write the expanded Rust code of the bridge into the given directory, named after the path
+    /// of the bridge, so that a crate can include it with `cxx_qt::include_bridge!` instead of
+    /// expanding the bridge with the `cxx_qt::bridge` macro.
+    pub fn write_rust(&self, rust_directory: impl AsRef<Path>) -> PathBuf {
+        let rust_path = rust_directory
+            .as_ref()
+            .join(format!("{}.rs", self.file_ident));
+        if let Some(directory) = rust_path.parent() {
+            std::fs::create_dir_all(directory)
+                .expect("Could not create directory to write cxx-qt generated Rust files");
+        }
+        write_if_changed(&rust_path, self.rust.as_bytes())
+            .expect("Could not write cxx-qt generated Rust file");
+        rust_path
+    }
+
+    /// Write the description of the QObjects of the bridge as JSON into the given directory,
+    /// named after the path of the bridge, returning the path of the file if the bridge has QObjects
+    pub fn write_metadata(&self, metadata_directory: impl AsRef<Path>) -> Option<PathBuf> {
+        let metadata = self.metadata.as_ref()?;
+        let metadata_path = metadata_directory
+            .as_ref()
+            .join(format!("{}.json", self.file_ident));
+        if let Some(directory) = metadata_path.parent() {
+            std::fs::create_dir_all(directory)
+                .expect("Could not create directory to write cxx-qt metadata files");
+        }
+        let json = serde_json::to_string_pretty(metadata)
+            .expect("Could not serialize the cxx-qt metadata of the bridge");
+        write_if_changed(&metadata_path, json.as_bytes())
+            .expect("Could not write cxx-qt metadata file");
+        Some(metadata_path)
+    }
+
+    /// The path of the header of a QObject with a `#[cxx_file_stem]`, which is next to the header
+    /// of the bridge
+    fn qobject_header_path(&self, header_directory: &Path, cxx_file_stem: &str) -> PathBuf {
+        header_directory
+            .join(&self.file_ident)
+            .with_file_name(format!("{cxx_file_stem}.cxxqt.h"))
+    }
+
     /// Write generated .cpp and .h files to specified directories. Returns the paths of all files written.
     pub fn write_to_directories(
         self,
@@ -179,6 +344,9 @@ impl GeneratedCpp {
             plain_cpp: PathBuf::new(),
             qobject: None,
             qobject_header: None,
+            qobject_class_headers: vec![],
+            metadata: None,
+            rust: PathBuf::new(),
         };
         if let Some(cxx_qt_generated) = &self.cxx_qt {
             let header_path = PathBuf::from(format!(
@@ -190,34 +358,43 @@ impl GeneratedCpp {
                 std::fs::create_dir_all(directory)
                     .expect("Could not create directory to write cxx-qt generated files");
             }
-            let mut header =
-                File::create(&header_path).expect("Could not create cxx-qt header file");
             let header_generated = match cxx_qt_generated {
                 CppFragment::Pair { header, source: _ } => header,
                 CppFragment::Header(header) => header,
                 CppFragment::Source(_) => panic!("Unexpected call for source fragment."),
             };
-            header
-                .write_all(header_generated.as_bytes())
+            write_if_changed(&header_path, header_generated.as_bytes())
                 .expect("Could not write cxx-qt header file");
             cpp_file_paths.qobject_header = Some(header_path);
 
-            let cpp_path = PathBuf::from(format!(
-                "{}/{}.cxxqt.cpp",
-                cpp_directory.display(),
-                self.file_ident
-            ));
-            if let Some(directory) = cpp_path.parent() {
-                std::fs::create_dir_all(directory)
-                    .expect("Could not create directory to write cxx-qt generated files");
+            for (cxx_file_stem, header) in &self.cxx_qt_qobject_headers {
+                let header_path = self.qobject_header_path(header_directory, cxx_file_stem);
+                if cpp_file_paths.qobject_header.as_ref() == Some(&header_path) {
+                    panic!(
+                        "The cxx_file_stem \"{cxx_file_stem}\" of a QObject in {} is the same as the file of the bridge.",
+                        self.file_ident
+                    );
+                }
+                write_if_changed(&header_path, header.as_bytes())
+                    .expect("Could not write cxx-qt QObject header file");
+                cpp_file_paths.qobject_class_headers.push(header_path);
             }
-            let mut cpp = File::create(&cpp_path).expect("Could not create cxx-qt source file");
+
             let source_generated = match cxx_qt_generated {
                 CppFragment::Pair { header: _, source } => source,
                 CppFragment::Header(_) => panic!("Unexpected call for header fragment."),
                 CppFragment::Source(source) => source,
             };
-            cpp.write_all(source_generated.as_bytes())
+            let cpp_path = content_hashed_path(
+                cpp_directory,
+                &format!("{}.cxxqt", self.file_ident),
+                source_generated.as_bytes(),
+            );
+            if let Some(directory) = cpp_path.parent() {
+                std::fs::create_dir_all(directory)
+                    .expect("Could not create directory to write cxx-qt generated files");
+            }
+            write_if_changed(&cpp_path, source_generated.as_bytes())
                 .expect("Could not write cxx-qt source file");
             cpp_file_paths.qobject = Some(cpp_path);
         }
@@ -231,22 +408,18 @@ impl GeneratedCpp {
             std::fs::create_dir_all(directory)
                 .expect("Could not create directory to write cxx-qt generated header files");
         }
-        let mut header = File::create(header_path).expect("Could not create cxx header file");
-        header
-            .write_all(&self.cxx.header)
-            .expect("Could not write cxx header file");
+        write_if_changed(&header_path, &self.cxx.header).expect("Could not write cxx header file");
 
-        let cpp_path = PathBuf::from(format!(
-            "{}/{}.cxx.cpp",
-            cpp_directory.display(),
-            self.file_ident
-        ));
+        let cpp_path = content_hashed_path(
+            cpp_directory,
+            &format!("{}.cxx", self.file_ident),
+            &self.cxx.implementation,
+        );
         if let Some(directory) = cpp_path.parent() {
             std::fs::create_dir_all(directory)
                 .expect("Could not create directory to write cxx-qt generated source files");
         }
-        let mut cpp = File::create(&cpp_path).expect("Could not create cxx source file");
-        cpp.write_all(&self.cxx.implementation)
+        write_if_changed(&cpp_path, &self.cxx.implementation)
             .expect("Could not write cxx source file");
         cpp_file_paths.plain_cpp = cpp_path;
 
@@ -254,11 +427,223 @@ impl GeneratedCpp {
     }
 }
 
+/// Format Rust code with rustfmt, returning the code unchanged if rustfmt is not available
+fn format_rust(code: &str) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => return code.to_owned(),
+    };
+
+    // Scope stdin to close it, so that rustfmt starts formatting
+    {
+        let mut stdin = child.stdin.take().expect("no stdin handle");
+        if stdin.write_all(code.as_bytes()).is_err() {
+            return code.to_owned();
+        }
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8(output.stdout).unwrap_or_else(|_| code.to_owned())
+        }
+        _ => code.to_owned(),
+    }
+}
+
+/// Write a file only if its contents changed, which keeps the modification time of unchanged
+/// generated files so that C++ build systems and compiler caches don't rebuild them.
+fn write_if_changed(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    match std::fs::read(path) {
+        Ok(existing) if existing == contents => Ok(()),
+        _ => std::fs::write(path, contents),
+    }
+}
+
+/// A hash of the contents of a generated file that is stable across platforms and Rust versions (FNV-1a)
+fn content_hash(contents: &[u8]) -> u64 {
+    contents.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The path of a generated source file named after a hash of its contents, for example
+/// `src/lib.cxxqt.0123456789abcdef.cpp`, so that identical generated code results in identical
+/// compiler invocations which compiler caches can reuse.
+///
+/// Sources with the same stem that were generated by previous builds are removed.
+fn content_hashed_path(directory: &Path, stem: &str, contents: &[u8]) -> PathBuf {
+    let path = directory.join(format!("{stem}.{:016x}.cpp", content_hash(contents)));
+
+    if let (Some(parent), Some(file_stem)) = (path.parent(), Path::new(stem).file_name()) {
+        let prefix = format!("{}.", file_stem.to_string_lossy());
+        for entry in std::fs::read_dir(parent).into_iter().flatten().flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let is_stale = file_name
+                .strip_prefix(&prefix)
+                .and_then(|hash| hash.strip_suffix(".cpp"))
+                .is_some_and(|hash| {
+                    hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit())
+                });
+            if is_stale && entry.path() != path {
+                // Ignore errors, a stale source file is not compiled anyway
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    path
+}
+
+/// The version of the format of [BridgeCache], which needs to be increased whenever the format
+/// changes, e.g. when a field is added to [GeneratedCppFilePaths]
+const BRIDGE_CACHE_VERSION: u32 = 1;
+
+/// The cached result of generating the C++ code of a bridge, see [generate_cxxqt_cpp_files]
+#[derive(Serialize, Deserialize)]
+struct BridgeCache {
+    version: u32,
+    input_hash: u64,
+    file_paths: GeneratedCppFilePaths,
+}
+
+/// Only the version of a [BridgeCache], which can be read from caches of any format
+#[derive(Deserialize)]
+struct BridgeCacheVersion {
+    version: Option<u32>,
+}
+
+/// A hash of everything that affects the C++ code generated for a bridge: the Rust source,
+/// the include prefix and header directory, the cfgs and features evaluated by cxx,
+/// the formatting of the C++ code, the registered types, and the build script itself,
+/// which changes when cxx-qt-build is updated.
+fn bridge_input_hash(
+    rust_file_path: &Path,
+    include_prefix: &str,
+    header_dir: &Path,
+    formatting: &CppFormatting,
+    type_registry: &TypeRegistry,
+) -> u64 {
+    let mut input = std::fs::read(rust_file_path).unwrap_or_default();
+    input.extend(include_prefix.as_bytes());
+    input.extend(header_dir.to_string_lossy().as_bytes());
+    input.extend(format!("{formatting:?}").as_bytes());
+    input.extend(format!("{type_registry:?}").as_bytes());
+    if let Some(ClangFormatStyle::Custom(style)) = &formatting.style {
+        if let Some(style_file) = style.strip_prefix("file:") {
+            input.extend(std::fs::read(style_file).unwrap_or_default());
+        }
+    }
+
+    let mut cfg_vars = env::vars()
+        .filter(|(var, _)| var.starts_with("CARGO_CFG_") || var.starts_with("CARGO_FEATURE_"))
+        .collect::<Vec<_>>();
+    cfg_vars.sort();
+    for (var, value) in cfg_vars {
+        input.extend(format!("{var}={value}\n").as_bytes());
+    }
+
+    if let Some(modified) = env::current_exe()
+        .and_then(|exe| exe.metadata())
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+    {
+        input.extend(modified.as_nanos().to_le_bytes());
+    }
+
+    content_hash(&input)
+}
+
+/// Read the generated file paths of a bridge from its cache, if the cache has the current
+/// [BRIDGE_CACHE_VERSION], the inputs of the bridge are unchanged and all generated files still exist
+fn read_bridge_cache(cache_path: &Path, input_hash: u64) -> Option<GeneratedCppFilePaths> {
+    let cache_json = std::fs::read_to_string(cache_path).ok()?;
+    // Caches written by other versions of cxx-qt-build are outdated, rather than invalid
+    let version = serde_json::from_str::<BridgeCacheVersion>(&cache_json)
+        .ok()
+        .and_then(|cache| cache.version);
+    if version != Some(BRIDGE_CACHE_VERSION) {
+        return None;
+    }
+
+    let cache: BridgeCache = match serde_json::from_str(&cache_json) {
+        Ok(cache) => cache,
+        Err(err) => {
+            println!(
+                "cargo::warning=Ignoring invalid cxx-qt-gen cache {}: {err}",
+                cache_path.display()
+            );
+            return None;
+        }
+    };
+    let file_paths = cache.file_paths;
+    let all_exist = std::iter::once(&file_paths.plain_cpp)
+        .chain(std::iter::once(&file_paths.rust))
+        .chain(file_paths.qobject.iter())
+        .chain(file_paths.qobject_header.iter())
+        .chain(file_paths.qobject_class_headers.iter())
+        .chain(file_paths.metadata.iter())
+        .all(|path| path.exists());
+    if cache.input_hash == input_hash && all_exist {
+        Some(file_paths)
+    } else {
+        None
+    }
+}
+
+fn write_bridge_cache(cache_path: &Path, cache: &BridgeCache) {
+    if let Some(directory) = cache_path.parent() {
+        std::fs::create_dir_all(directory).expect("Could not create cxx-qt-gen cache directory");
+    }
+    let cache_json = serde_json::to_string(cache).expect("Failed to convert cache to JSON!");
+    std::fs::write(cache_path, cache_json).expect("Could not write cxx-qt-gen cache file");
+}
+
+/// The number of jobs that Cargo allows the build script to run in parallel
+fn num_jobs() -> usize {
+    env::var("NUM_JOBS")
+        .ok()
+        .and_then(|num_jobs| num_jobs.parse::<usize>().ok())
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// The Rust module path of a file of the crate, eg `crate::types` for `src/types.rs`
+fn rust_module_path(rust_file: &Path) -> Option<String> {
+    let relative = rust_file.strip_prefix("src").ok()?.with_extension("");
+    let mut segments = relative
+        .components()
+        .map(|component| component.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?;
+    if matches!(segments.as_slice(), ["lib"] | ["main"]) || segments.last() == Some(&"mod") {
+        segments.pop();
+    }
+    Some(
+        std::iter::once("crate")
+            .chain(segments)
+            .collect::<Vec<_>>()
+            .join("::"),
+    )
+}
+
 /// Generate C++ files from a given list of Rust files, returning the generated paths
+///
+/// The files are generated in parallel, using up to [num_jobs] threads.
+///
+/// If a `dump_dir` is given, the generated code is also written into it in a readable form.
 fn generate_cxxqt_cpp_files(
-    rs_source: &[impl AsRef<Path>],
+    rs_source: &[impl AsRef<Path> + Sync],
     header_dir: impl AsRef<Path>,
     include_prefix: &str,
+    dump_dir: Option<&Path>,
+    formatting: &CppFormatting,
+    type_registry: &TypeRegistry,
 ) -> Vec<GeneratedCppFilePaths> {
     let cxx_qt_dir = dir::out().join("cxx-qt-gen");
     std::fs::create_dir_all(&cxx_qt_dir).expect("Failed to create cxx-qt-gen directory!");
@@ -267,22 +652,73 @@ fn generate_cxxqt_cpp_files(
     let header_dir = header_dir.as_ref().join(include_prefix);
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
 
-    let mut generated_file_paths: Vec<GeneratedCppFilePaths> = Vec::with_capacity(rs_source.len());
-    for rs_path in rs_source {
+    let generate = |rs_path: &Path| -> GeneratedCppFilePaths {
         let path = manifest_dir.join(rs_path);
         println!("cargo:rerun-if-changed={}", path.to_string_lossy());
 
-        let generated_code = match GeneratedCpp::new(&path, rs_path, include_prefix) {
-            Ok(v) => v,
-            Err(diagnostic) => {
-                diagnostic.report();
-                std::process::exit(1);
+        // Cargo reruns the build script when any bridge changes,
+        // so only regenerate the bridges whose inputs changed since the last run
+        let cache_path = cxx_qt_dir
+            .join("cache")
+            .join(rs_path.with_extension("json"));
+        let input_hash = bridge_input_hash(
+            &path,
+            include_prefix,
+            &header_dir,
+            formatting,
+            type_registry,
+        );
+        // The generated code is only available when regenerating, so ignore the cache when dumping
+        if dump_dir.is_none() {
+            if let Some(file_paths) = read_bridge_cache(&cache_path, input_hash) {
+                return file_paths;
             }
-        };
-        generated_file_paths.push(generated_code.write_to_directories(&cxx_qt_dir, &header_dir));
-    }
+        }
 
-    generated_file_paths
+        let generated_code =
+            match GeneratedCpp::new(&path, rs_path, include_prefix, formatting, type_registry) {
+                Ok(v) => v,
+                Err(diagnostic) => {
+                    diagnostic.report();
+                    std::process::exit(1);
+                }
+            };
+        if let Some(dump_dir) = dump_dir {
+            generated_code.write_dump(dump_dir);
+        }
+        let rust = generated_code.write_rust(cxx_qt_dir.join("rust"));
+        let metadata = generated_code.write_metadata(cxx_qt_dir.join("metadata"));
+        let mut file_paths = generated_code.write_to_directories(&cxx_qt_dir, &header_dir);
+        file_paths.metadata = metadata;
+        file_paths.rust = rust;
+        let cache = BridgeCache {
+            version: BRIDGE_CACHE_VERSION,
+            input_hash,
+            file_paths,
+        };
+        write_bridge_cache(&cache_path, &cache);
+        cache.file_paths
+    };
+
+    // Keep the order of the generated files stable by joining the threads in order
+    let chunk_size = rs_source.len().div_ceil(num_jobs()).max(1);
+    std::thread::scope(|scope| {
+        let threads = rs_source
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(|| {
+                    chunk
+                        .iter()
+                        .map(|rs_path| generate(rs_path.as_ref()))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .flat_map(|thread| thread.join().expect("Failed to generate C++ files"))
+            .collect()
+    })
 }
 
 pub(crate) fn module_name_from_uri(module_uri: &str) -> String {
@@ -311,6 +747,17 @@ fn panic_duplicate_file_and_qml_module(
     panic!("CXX-Qt bridge Rust file {} specified in QML module {uri} (version {version_major}.{version_minor}), but also specified via CxxQtBuilder::file. Bridge files must be specified via CxxQtBuilder::file or CxxQtBuilder::qml_module, but not both.", path.as_ref().display());
 }
 
+/// A plugin that is linked and imported when Qt is built statically
+enum StaticQtPlugin {
+    Platform,
+    Plugin {
+        plugin_type: String,
+        plugin_name: String,
+        class_name: String,
+    },
+    QmlModule(String),
+}
+
 /// Run cxx-qt's C++ code generator on Rust modules marked with the `cxx_qt::bridge` macro, compile
 /// the code, and link to Qt. This is the complement of the `cxx_qt::bridge` macro, which the Rust
 /// compiler uses to generate the corresponding Rust code. No dependencies besides Qt, a C++17 compiler,
@@ -341,6 +788,18 @@ fn panic_duplicate_file_and_qml_module(
 /// Under the hood, [CxxQtBuilder] uses [cc::Build], which allows compiling aditional C++ files as well.
 /// Refer to [CxxQtBuilder::cc_builder] for details.
 ///
+/// The C++ code is generated, run through moc and compiled in parallel, using as many jobs as Cargo
+/// allows. Compiler caches are supported through [cc::Build] as well: sccache is used when the
+/// `RUSTC_WRAPPER` environment variable is set to it, and other wrappers can be prepended to the
+/// `CXX` environment variable, for example `CXX="ccache c++"`. Generated files are only rewritten
+/// when their contents change, so unchanged files are not rebuilt by C++ build systems.
+/// When the build script is rerun, only the bridges whose Rust source, cfgs or features changed are
+/// generated again, and moc is only run again for headers that changed.
+///
+/// The expanded Rust code of each bridge is also generated, so that a bridge which is not part of
+/// the module tree of the crate can be included with `cxx_qt::include_bridge!("src/lib.rs")`.
+/// Then rustc doesn't need to expand the `cxx_qt::bridge` macro.
+///
 /// In addition to autogenerating and building QObject C++ subclasses, manually written QObject
 /// subclasses can be parsed by moc and built using [CxxQtBuilder::qobject_header].
 #[derive(Default)]
@@ -348,18 +807,41 @@ pub struct CxxQtBuilder {
     rust_sources: Vec<PathBuf>,
     qobject_headers: Vec<QObjectHeaderOpts>,
     qrc_files: Vec<PathBuf>,
-    qt_modules: HashSet<String>,
+    qt_modules: BTreeSet<String>,
     qml_modules: Vec<OwningQmlModule>,
+    qml_module_overrides: Vec<(String, QmlModuleOverride)>,
     cc_builder: cc::Build,
     public_interface: Option<Interface>,
     include_prefix: String,
     initializers: Vec<String>,
+    qmlcachegen: Option<bool>,
+    qmllint: bool,
+    qmlformat: bool,
+    translations: Vec<PathBuf>,
+    lupdate: bool,
+    compile_in_cmake: bool,
+    header_install_dir: Option<PathBuf>,
+    qmake_pri: bool,
+    qmake_pri_install_path: Option<PathBuf>,
+    pkg_config: bool,
+    static_qt_plugins: Vec<StaticQtPlugin>,
+    android_application_binary: Option<String>,
+    deploy_windows_dlls: bool,
+    dump_dir: Option<PathBuf>,
+    metadata_json: Option<PathBuf>,
+    qml_docs_dir: Option<PathBuf>,
+    bridge_metadata: Vec<BridgeMetadataFile>,
+    clang_format: Option<bool>,
+    clang_format_style_file: Option<PathBuf>,
+    type_registry: TypeRegistry,
+    build_report: bool,
+    bridge_reports: Vec<BridgeReport>,
 }
 
 impl CxxQtBuilder {
     /// Create a new builder
     pub fn new() -> Self {
-        let mut qt_modules = HashSet::new();
+        let mut qt_modules = BTreeSet::new();
         qt_modules.insert("Core".to_owned());
         Self {
             rust_sources: vec![],
@@ -367,10 +849,33 @@ impl CxxQtBuilder {
             qrc_files: vec![],
             qt_modules,
             qml_modules: vec![],
+            qml_module_overrides: vec![],
             cc_builder: cc::Build::new(),
             initializers: vec![],
             public_interface: None,
             include_prefix: crate_name(),
+            qmlcachegen: None,
+            qmllint: false,
+            qmlformat: false,
+            translations: vec![],
+            lupdate: false,
+            compile_in_cmake: false,
+            header_install_dir: None,
+            qmake_pri: false,
+            qmake_pri_install_path: None,
+            pkg_config: false,
+            static_qt_plugins: vec![],
+            android_application_binary: None,
+            deploy_windows_dlls: false,
+            dump_dir: None,
+            metadata_json: None,
+            qml_docs_dir: None,
+            bridge_metadata: vec![],
+            clang_format: None,
+            clang_format_style_file: None,
+            type_registry: TypeRegistry::default(),
+            build_report: false,
+            bridge_reports: vec![],
         }
     }
 
@@ -391,6 +896,11 @@ impl CxxQtBuilder {
 
     /// Specify rust file paths to parse through the cxx-qt marco
     /// Relative paths are treated as relative to the path of your crate's Cargo.toml file
+    ///
+    /// The shared structs, shared enums and opaque C++ types of a file with a plain `#[cxx::bridge]`
+    /// are registered like [CxxQtBuilder::register_type], so the `#[cxx_qt::bridge]`s of the crate can use them
+    /// in their signatures without declaring them. The bridge module must be visible to the other bridges,
+    /// eg `pub mod ffi`, and the file must be the module matching its path, eg `src/types.rs` for `crate::types`.
     pub fn file(mut self, rust_source: impl AsRef<Path>) -> Self {
         let rust_source = rust_source.as_ref().to_path_buf();
         for qml_module in &self.qml_modules {
@@ -428,6 +938,97 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Include the given files into the binary with [Qt's resource system](https://doc.qt.io/qt-6/resources.html)
+    /// without writing a .qrc file by hand.
+    ///
+    /// The files are available under the given prefix with their path as given here,
+    /// for example `:/images/icons/app.png` for a prefix of `/images` and a file of `icons/app.png`.
+    /// Relative paths are relative to the directory of the crate.
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .qrc_resources("/images", &["icons/app.png", "icons/close.png"])
+    ///     .build();
+    /// ```
+    ///
+    /// The generated .qrc file is built in the same way as with [CxxQtBuilder::qrc].
+    pub fn qrc_resources(mut self, prefix: &str, files: &[impl AsRef<Path>]) -> Self {
+        let qrc_dir = dir::out().join("cxx-qt-build").join("qrc");
+        std::fs::create_dir_all(&qrc_dir).expect("Could not create qrc directory");
+
+        // The file name is used as the name of the resource initializer by rcc,
+        // so it needs to be unique across crates.
+        let qrc_path = qrc_dir.join(format!(
+            "{}_resources_{}.qrc",
+            crate_name().replace('-', "_"),
+            self.qrc_files.len()
+        ));
+
+        let mut qrc_files = String::new();
+        for file in files {
+            let file = file.as_ref();
+            println!("cargo:rerun-if-changed={}", file.display());
+            qrc_files.push_str(&format!(
+                "    <file alias=\"{}\">{}</file>\n",
+                file.display(),
+                std::fs::canonicalize(file)
+                    .unwrap_or_else(|_| panic!("Could not canonicalize path {}", file.display()))
+                    .display()
+            ));
+        }
+        std::fs::write(
+            &qrc_path,
+            format!("<RCC>\n<qresource prefix=\"{prefix}\">\n{qrc_files}</qresource>\n</RCC>\n"),
+        )
+        .expect("Could not write qrc file");
+
+        self.qrc_files.push(qrc_path);
+        self
+    }
+
+    /// Compile the given Qt Linguist `.ts` files with [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html)
+    /// and include the `.qm` files into the binary with [Qt's resource system](https://doc.qt.io/qt-6/resources.html).
+    ///
+    /// The `.qm` files are available under `:/i18n` with the file name of their `.ts` file, for example
+    /// `:/i18n/app_de.qm` for `i18n/app_de.ts`, so they can be loaded with a
+    /// [QTranslator](https://doc.qt.io/qt-6/qtranslator.html). Relative paths are relative to the directory of the crate.
+    /// Use [CxxQtBuilder::lupdate] to update the `.ts` files from the sources.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::{CxxQtBuilder, QmlModule};
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &["qml/main.qml"],
+    ///         ..Default::default()
+    ///     })
+    ///     .translations(&["i18n/app_de.ts", "i18n/app_fr.ts"])
+    ///     .lupdate(true)
+    ///     .build();
+    /// ```
+    pub fn translations(mut self, ts_files: &[impl AsRef<Path>]) -> Self {
+        for ts_file in ts_files {
+            let ts_file = ts_file.as_ref();
+            println!("cargo:rerun-if-changed={}", ts_file.display());
+            self.translations.push(ts_file.to_path_buf());
+        }
+        self
+    }
+
+    /// Enable or disable running [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html) to update the
+    /// `.ts` files of [CxxQtBuilder::translations] with the translatable strings of the bridges and the
+    /// `.qml` files of the QML modules.
+    ///
+    /// lupdate finds `qsTr()` in the QML files, and parses the Rust files like C++ files,
+    /// so that it finds calls like `translate("Context", "Text")`. The `.ts` files are only written
+    /// when their contents change. This is disabled by default.
+    pub fn lupdate(mut self, enabled: bool) -> Self {
+        self.lupdate = enabled;
+        self
+    }
+
     /// Link additional [Qt modules](https://doc.qt.io/qt-6/qtmodules.html).
     /// Specify their names without the `Qt` prefix, for example `"Widgets"`.
     /// The `Core` module and any modules from dependencies are linked automatically; there is no need to specify them.
@@ -441,108 +1042,521 @@ impl CxxQtBuilder {
         self
     }
 
-    /// Instead of generating files under the crate name, generate files under the given prefix.
-    pub fn include_prefix(mut self, prefix: &str) -> Self {
-        prefix.clone_into(&mut self.include_prefix);
+    /// Enable or disable compiling the `.qml` files of QML modules ahead-of-time with
+    /// [qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html).
+    ///
+    /// By default qmlcachegen is used when it is available, which requires Qt 6.
+    /// When explicitly enabled, the build fails if qmlcachegen can not be used,
+    /// which ensures that targets without a QML JIT don't fall back to interpreting QML at startup.
+    pub fn qmlcachegen(mut self, enabled: bool) -> Self {
+        self.qmlcachegen = Some(enabled);
         self
     }
 
-    /// Register a QML module at build time. The `rust_files` of the [QmlModule] struct
-    /// should contain `#[cxx_qt::bridge]` modules with QObject types annotated with `#[qml_element]`.
+    /// Enable or disable running [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html)
+    /// on the `.qml` files of the QML modules.
     ///
-    /// The QmlModule struct's `qml_files` are registered with the [Qt Resource System](https://doc.qt.io/qt-6/resources.html) in
-    /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
-    /// Additional resources such as images can be added to the Qt resources for the QML module by specifying
-    /// the `qrc_files` field.
+    /// The `qmldir` and `plugin.qmltypes` generated for the QML modules are passed as import paths,
+    /// so that qmllint knows about the types defined in Rust. When enabled, warnings or errors
+    /// reported by qmllint fail the build. This is disabled by default.
+    pub fn qmllint(mut self, enabled: bool) -> Self {
+        self.qmllint = enabled;
+        self
+    }
+
+    /// Enable or disable checking that the `.qml` files of the QML modules are formatted with
+    /// [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html).
     ///
-    /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html)
-    /// to compile the specified `.qml` files ahead-of-time.
+    /// The files are not modified, instead the build fails if a file can not be parsed or is not formatted.
+    /// Run `qmlformat -i` on the files to format them. This is disabled by default.
+    pub fn qmlformat(mut self, enabled: bool) -> Self {
+        self.qmlformat = enabled;
+        self
+    }
+
+    /// Generate the C++ code but let CMake compile and link it instead of compiling it with cc-rs.
     ///
-    /// ```no_run
-    /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
+    /// Instead of a static library, a `cxx-qt-sources.cmake` file is written into the crate directory
+    /// of the cxx-qt-build target directory. It sets the following variables, where `<CRATE>` is the crate
+    /// name in uppercase with `-` replaced by `_`:
     ///
-    /// CxxQtBuilder::new()
-    ///     .qml_module(QmlModule {
-    ///         uri: "com.kdab.cxx_qt.demo",
-    ///         rust_files: &["src/cxxqt_object.rs"],
-    ///         qml_files: &["qml/main.qml"],
-    ///         ..Default::default()
-    ///     })
-    ///     .build();
+    /// - `CXX_QT_<CRATE>_SOURCES` the generated and additional C++ sources
+    /// - `CXX_QT_<CRATE>_INCLUDE_DIRS` the include directories needed by the sources
+    /// - `CXX_QT_<CRATE>_DEFINITIONS` the compile definitions needed by the sources
+    /// - `CXX_QT_<CRATE>_QT_MODULES` the Qt modules that need to be linked
+    /// - `CXX_QT_<CRATE>_OBJECTS` object files with static initializers that must be linked into the target
+    ///
+    /// ```cmake
+    /// include(${CXX_QT_EXPORT_DIR}/crates/my_crate/cxx-qt-sources.cmake)
+    /// target_sources(my_app PRIVATE ${CXX_QT_MY_CRATE_SOURCES} ${CXX_QT_MY_CRATE_OBJECTS})
+    /// target_include_directories(my_app PRIVATE ${CXX_QT_MY_CRATE_INCLUDE_DIRS})
+    /// target_compile_definitions(my_app PRIVATE ${CXX_QT_MY_CRATE_DEFINITIONS})
     /// ```
-    pub fn qml_module<A: AsRef<Path>, B: AsRef<Path>>(
-        mut self,
-        qml_module: QmlModule<A, B>,
-    ) -> CxxQtBuilder {
-        let qml_module = OwningQmlModule::from(qml_module);
-        for path in &qml_module.rust_files {
-            if self.rust_sources.contains(path) {
-                panic_duplicate_file_and_qml_module(
-                    path,
-                    &qml_module.uri,
-                    qml_module.version_major,
-                    qml_module.version_minor,
-                );
-            }
-        }
-        self.qml_modules.push(qml_module);
+    ///
+    /// The Rust static library of the crate still needs to be linked, e.g. with Corrosion.
+    pub fn compile_in_cmake(mut self) -> Self {
+        self.compile_in_cmake = true;
         self
     }
 
-    /// Specify a C++ header containing a Q_OBJECT macro to run [moc](https://doc.qt.io/qt-6/moc.html) on.
-    /// This allows building QObject C++ subclasses besides the ones autogenerated by cxx-qt.
-    pub fn qobject_header(mut self, opts: impl Into<QObjectHeaderOpts>) -> Self {
-        let opts = opts.into();
-        println!("cargo:rerun-if-changed={}", opts.path.display());
-        self.qobject_headers.push(opts);
+    /// Install the generated headers into the given include directory, so that C++ code
+    /// outside of the build can include them, for example `#include <my_crate/src/my_object.cxxqt.h>`.
+    /// Relative paths are relative to the directory of the crate.
+    ///
+    /// The include paths are the same as within the build, they consist of the include prefix
+    /// (see [CxxQtBuilder::include_prefix]) and the path of the Rust file relative to the crate.
+    /// The headers of cxx, cxx-qt and reexported dependencies are installed as well.
+    ///
+    /// A `cxxqt_version.h` header is written into the include prefix, which defines
+    /// `<PREFIX>_VERSION_MAJOR`, `<PREFIX>_VERSION_MINOR`, `<PREFIX>_VERSION_PATCH` and `<PREFIX>_VERSION_STRING`
+    /// from the version of the crate, where `<PREFIX>` is the include prefix in uppercase.
+    pub fn install_headers(mut self, directory: impl AsRef<Path>) -> Self {
+        self.header_install_dir = Some(directory.as_ref().to_path_buf());
         self
     }
 
-    /// Use a closure to run additional customization on [CxxQtBuilder]'s internal [cc::Build]
-    /// before calling [CxxQtBuilder::build]. This allows to add extra include paths, compiler flags,
-    /// or anything else available via [cc::Build]'s API. For example, to add an include path for
-    /// manually written C++ headers located in a directory called `include` within your crate:
+    /// Write a `<crate name>.pri` file into the crate directory of the cxx-qt-build target directory,
+    /// which can be included in a qmake project to link the crate.
     ///
-    /// ```no_run
-    /// # use cxx_qt_build::CxxQtBuilder;
+    /// It adds the Qt modules, include paths, compile definitions and libraries needed by the crate.
+    /// The static library is expected in the cargo target directory of the current profile,
+    /// so the crate must be built with the `staticlib` crate type.
+    pub fn qmake_pri(mut self) -> Self {
+        self.qmake_pri = true;
+        self
+    }
+
+    /// Like [CxxQtBuilder::qmake_pri], but also write the `.pri` file to the given path,
+    /// so that a qmake project can include it from a known location, for example
+    /// `include(../rust/my_crate.pri)`. Relative paths are relative to the directory of the crate.
     ///
-    /// CxxQtBuilder::new()
-    ///     .file("src/lib.rs")
-    ///     .cc_builder(|cc| {
-    ///         cc.include("include");
-    ///     })
-    ///     .build();
-    /// ```
-    pub fn cc_builder(mut self, mut callback: impl FnMut(&mut cc::Build)) -> Self {
-        callback(&mut self.cc_builder);
+    /// If the headers are installed with [CxxQtBuilder::install_headers],
+    /// the `.pri` file adds the install directory to the include paths instead of the build directory.
+    pub fn install_qmake_pri(mut self, path: impl AsRef<Path>) -> Self {
+        self.qmake_pri = true;
+        self.qmake_pri_install_path = Some(path.as_ref().to_path_buf());
         self
     }
 
-    fn define_cfg_variable(key: String, value: Option<&str>) {
-        if let Some(value) = value {
-            println!("cargo:rustc-cfg={key}=\"{value}\"");
-        } else {
-            println!("cargo:rustc-cfg={key}");
-        }
-        let variable_cargo = format!("CARGO_CFG_{}", key);
-        env::set_var(variable_cargo, value.unwrap_or("true"));
+    /// Write a `<crate name>.pc` [pkg-config](https://www.freedesktop.org/wiki/Software/pkg-config/) file
+    /// into the crate directory of the cxx-qt-build target directory.
+    ///
+    /// The same information as for [CxxQtBuilder::qmake_pri] is included, the Qt modules are
+    /// listed as required pkg-config packages.
+    pub fn pkg_config(mut self) -> Self {
+        self.pkg_config = true;
+        self
     }
 
-    fn define_cfg_check_variable(key: String, values: Option<Vec<&str>>) {
-        if let Some(values) = values {
-            let values = values
-                .iter()
-                // Escape and add quotes
-                .map(|value| format!("\"{}\"", value.escape_default()))
-                .collect::<Vec<_>>()
-                .join(", ");
-            println!("cargo:rustc-check-cfg=cfg({key}, values({values}))");
-        } else {
-            println!("cargo:rustc-check-cfg=cfg({key})");
-        }
+    /// When Qt is built statically, link and import the default platform plugin of the target,
+    /// for example `qxcb` on Linux or `qwindows` on Windows.
+    ///
+    /// With a static Qt, plugins are not loaded at runtime, so they need to be linked into the binary.
+    /// Plugins should only be imported once, so only call this in the crate of the final binary.
+    /// When Qt is built dynamically, this does nothing.
+    pub fn qt_static_platform_plugin(mut self) -> Self {
+        self.static_qt_plugins.push(StaticQtPlugin::Platform);
+        self
     }
 
-    fn define_qt_version_cfg_variables(version: &SemVer) {
+    /// When Qt is built statically, link and import the given Qt plugin, for example
+    /// `.qt_static_plugin("imageformats", "qsvg", "QSvgPlugin")`.
+    ///
+    /// When Qt is built dynamically, this does nothing. See [CxxQtBuilder::qt_static_platform_plugin].
+    pub fn qt_static_plugin(
+        mut self,
+        plugin_type: &str,
+        plugin_name: &str,
+        class_name: &str,
+    ) -> Self {
+        self.static_qt_plugins.push(StaticQtPlugin::Plugin {
+            plugin_type: plugin_type.to_owned(),
+            plugin_name: plugin_name.to_owned(),
+            class_name: class_name.to_owned(),
+        });
+        self
+    }
+
+    /// When Qt is built statically, link and import the plugin of a QML module shipped with Qt,
+    /// for example `"QtQuick.Controls"`. Every QML module that is imported by the QML files
+    /// of the application needs to be specified, including `"QtQuick"` and `"QtQml"`.
+    ///
+    /// When Qt is built dynamically, this does nothing. See [CxxQtBuilder::qt_static_platform_plugin].
+    pub fn qt_static_qml_import(mut self, uri: &str) -> Self {
+        self.static_qt_plugins
+            .push(StaticQtPlugin::QmlModule(uri.to_owned()));
+        self
+    }
+
+    /// When targeting Android, write an `android-deployment-settings.json` file into the crate directory
+    /// of the cxx-qt-build target directory, which can be passed to `androiddeployqt --input` to package
+    /// the given application binary (for example `libmy_app_arm64-v8a.so`) into an APK.
+    ///
+    /// The Android SDK is read from the `ANDROID_SDK_ROOT` or `ANDROID_HOME` environment variable and
+    /// the NDK from `ANDROID_NDK_ROOT` or `ANDROID_NDK_HOME`. The directories of the QML modules are
+    /// used as the QML root paths so that androiddeployqt can find the QML imports.
+    ///
+    /// When not targeting Android, this does nothing.
+    pub fn android_deployment_settings(mut self, application_binary: &str) -> Self {
+        self.android_application_binary = Some(application_binary.to_owned());
+        self
+    }
+
+    /// When targeting Windows, copy the DLLs of the linked Qt modules and the platform plugin
+    /// next to the executables and test executables of the current Cargo profile,
+    /// so that `cargo run` and `cargo test` work without adding the bin directory of Qt to `PATH`.
+    ///
+    /// When not targeting Windows or when Qt is static, this does nothing.
+    pub fn deploy_windows_dlls(mut self) -> Self {
+        self.deploy_windows_dlls = true;
+        self
+    }
+
+    /// Write the generated Rust and C++ code of each bridge into the given directory in a readable,
+    /// formatted form, named after the path of the bridge, for example `src/lib.rs`, `src/lib.cxxqt.h`
+    /// and `src/lib.cxx.cpp`. This is useful to inspect the generated code when debugging a bridge,
+    /// instead of searching for it in the `OUT_DIR` of the crate.
+    ///
+    /// The directory can also be set with the `CXX_QT_DUMP_DIR` environment variable,
+    /// which allows dumping the code without changing the build script.
+    pub fn dump_generated_code(mut self, dir: impl AsRef<Path>) -> Self {
+        self.dump_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Write a JSON description of the QObjects of every bridge into the given file, for tools
+    /// such as API checkers and documentation generators.
+    ///
+    /// Each bridge is listed with the path of its Rust file, the URI of its QML module, if any,
+    /// and the properties, signals, invokables and QEnums of its QObjects, with their Rust and C++ types.
+    /// This is the [BridgeMetadata] of the bridge serialized with serde.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    ///
+    /// CxxQtBuilder::new()
+    ///     .file("src/lib.rs")
+    ///     .metadata_json("target/cxx-qt-metadata.json")
+    ///     .build();
+    /// ```
+    pub fn metadata_json(mut self, path: impl AsRef<Path>) -> Self {
+        self.metadata_json = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Write Markdown documentation of the QML types of each QML module into the given directory,
+    /// named after the URI of the module, for example `com/kdab/cxx_qt/demo.md`.
+    ///
+    /// The documentation lists the properties, signals, invokables and QEnums of each `#[qml_element]`
+    /// with their QML types, and the doc comments of the QObjects, signals, invokables and QEnums in the bridges.
+    pub fn qml_docs(mut self, dir: impl AsRef<Path>) -> Self {
+        self.qml_docs_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Register a C++ type which can be used in the signatures of every bridge of the crate
+    /// without declaring it in an `unsafe extern "C++"` block of the bridge.
+    ///
+    /// The Rust type must already be bound to the C++ type by a CXX bridge, for example as an opaque type.
+    /// This allows using project specific C++ types, such as a `Money` value type, in the
+    /// properties, invokables and signals of QObjects.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::{CxxQtBuilder, RegisteredType};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .file("src/lib.rs")
+    ///     .register_type(
+    ///         RegisteredType::new("crate::money::ffi::Money", "money.h")
+    ///             .unwrap()
+    ///             .namespace("finance"),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn register_type(mut self, ty: RegisteredType) -> Self {
+        self.type_registry.register(ty);
+        self
+    }
+
+    /// Register the types of the plain `#[cxx::bridge]`s of the crate, so that the `#[cxx_qt::bridge]`s can use them
+    fn register_cxx_bridge_types(&mut self) {
+        let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let rust_files = self.rust_sources.iter().chain(
+            self.qml_modules
+                .iter()
+                .flat_map(|module| &module.rust_files),
+        );
+        for rust_file in rust_files {
+            let Some(module_path) = rust_module_path(rust_file) else {
+                continue;
+            };
+            let file = match parse_qt_file(manifest_dir.join(rust_file)) {
+                Ok(file) => file,
+                // The error is reported when the file is generated
+                Err(_) => continue,
+            };
+            // This is the header which GeneratedCpp::new writes for the bridge
+            let include = format!(
+                "{}/{}.cxx.h",
+                self.include_prefix,
+                rust_file
+                    .with_extension("")
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            );
+            for item in &file.items {
+                if let CxxQtItem::Cxx(module) = item {
+                    if let Err(err) =
+                        self.type_registry
+                            .register_cxx_bridge(module, &module_path, &include)
+                    {
+                        Diagnostic::new(manifest_dir.join(rust_file), GeneratedError::from(err))
+                            .report();
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Print a report of the code generated for each bridge as Cargo warnings, to find the bridges
+    /// which slow down the build the most.
+    ///
+    /// For each bridge the report lists the lines of generated C++ and Rust code, how long moc took
+    /// for its QObject headers and how long compiling its C++ code took, starting with the slowest bridge.
+    /// The report can also be enabled by setting the `CXX_QT_BUILD_REPORT` environment variable.
+    ///
+    /// To measure the compile time of each bridge, its C++ code is compiled an additional time,
+    /// so this slows down the build. As moc and the code generation are skipped for bridges which
+    /// did not change, force a rebuild of the crate, e.g. with `cargo clean -p <crate>`, for complete times.
+    pub fn build_report(mut self, enabled: bool) -> Self {
+        self.build_report = enabled;
+        self
+    }
+
+    fn build_report_enabled(&self) -> bool {
+        println!("cargo:rerun-if-env-changed=CXX_QT_BUILD_REPORT");
+        self.build_report || env::var_os("CXX_QT_BUILD_REPORT").is_some()
+    }
+
+    fn dump_dir(&self) -> Option<PathBuf> {
+        println!("cargo:rerun-if-env-changed=CXX_QT_DUMP_DIR");
+        self.dump_dir
+            .clone()
+            .or_else(|| env::var_os("CXX_QT_DUMP_DIR").map(PathBuf::from))
+    }
+
+    /// Format the generated C++ code with clang-format using the given
+    /// [style file](https://clang.llvm.org/docs/ClangFormatStyleOptions.html),
+    /// for example when the generated headers are checked by the format CI of a C++ project.
+    /// A relative path is relative to the directory of the crate. This requires clang-format 14 or later.
+    ///
+    /// Unlike the default formatting, this also formats the code generated by cxx.
+    /// The clang-format executable can be chosen with the `CLANG_FORMAT_BINARY` environment variable.
+    pub fn clang_format_style_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.clang_format_style_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Enable or disable formatting the generated C++ code with clang-format.
+    ///
+    /// By default the C++ code generated by CXX-Qt is formatted using the `.clang-format` file of the
+    /// crate directory or its parents, if clang-format is available. When explicitly enabled,
+    /// the code generated by cxx is formatted too. When disabled, no code is formatted,
+    /// which speeds up the build.
+    pub fn clang_format(mut self, enabled: bool) -> Self {
+        self.clang_format = Some(enabled);
+        self
+    }
+
+    fn cpp_formatting(&self) -> CppFormatting {
+        if self.clang_format == Some(false) {
+            return CppFormatting {
+                style: None,
+                format_cxx: false,
+            };
+        }
+
+        if let Some(style_file) = &self.clang_format_style_file {
+            let style_file =
+                PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(style_file);
+            println!("cargo:rerun-if-changed={}", style_file.display());
+            CppFormatting {
+                style: Some(ClangFormatStyle::Custom(format!(
+                    "file:{}",
+                    style_file.display()
+                ))),
+                format_cxx: true,
+            }
+        } else {
+            CppFormatting {
+                style: Some(ClangFormatStyle::File),
+                format_cxx: self.clang_format == Some(true),
+            }
+        }
+    }
+
+    /// Instead of generating files under the crate name, generate files under the given prefix.
+    pub fn include_prefix(mut self, prefix: &str) -> Self {
+        prefix.clone_into(&mut self.include_prefix);
+        self
+    }
+
+    /// Register a QML module at build time. The `rust_files` of the [QmlModule] struct
+    /// should contain `#[cxx_qt::bridge]` modules with QObject types annotated with `#[qml_element]`.
+    ///
+    /// The QmlModule struct's `qml_files` are registered with the [Qt Resource System](https://doc.qt.io/qt-6/resources.html) in
+    /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
+    /// Additional resources such as images can be added to the Qt resources for the QML module by specifying
+    /// the `qrc_files` field. A whole directory of `.qml` files and resources can be bundled into the module
+    /// by specifying the `qml_dirs` field instead, similar to `qt_add_qml_module` in CMake.
+    ///
+    /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html)
+    /// to compile the specified `.qml` files ahead-of-time.
+    ///
+    /// The `qmldir` and `plugin.qmltypes` of the module are exported into `qml_modules/<module name>/qml`
+    /// of the cxx-qt-build target directory, which can be added to the QML import path of qmllint or
+    /// Qt Creator so that they know about the types defined in Rust.
+    ///
+    /// By default the QML plugin is linked statically. Set the `plugin_type` field to
+    /// [QmlPluginType::Dynamic] to instead generate a plugin that is loaded by the QML engine
+    /// at runtime, for example by QML Designer or other applications that do not link to the crate.
+    /// The shared library of the plugin then has to be linked by CMake, see [QmlPluginType::Dynamic]
+    /// for the required steps.
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &["qml/main.qml"],
+    ///         ..Default::default()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn qml_module<A: AsRef<Path>, B: AsRef<Path>>(
+        mut self,
+        qml_module: QmlModule<A, B>,
+    ) -> CxxQtBuilder {
+        let qml_module = OwningQmlModule::from(qml_module);
+        for path in &qml_module.rust_files {
+            if self.rust_sources.contains(path) {
+                panic_duplicate_file_and_qml_module(
+                    path,
+                    &qml_module.uri,
+                    qml_module.version_major,
+                    qml_module.version_minor,
+                );
+            }
+        }
+        self.qml_modules.push(qml_module);
+        self
+    }
+
+    /// Override the URI and version of the QML modules of the builder when the Cargo feature
+    /// of the crate with the given name is enabled.
+    ///
+    /// This allows the same source tree to register its types under a different QML module,
+    /// for example for the beta builds of a release channel. When several features with an
+    /// override are enabled, they are applied in the order of the calls.
+    ///
+    /// Note that the QML files of the module, and of applications importing it, must import the
+    /// overridden URI, and that a [QmlPluginType::Dynamic] plugin is named after it.
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::{CxxQtBuilder, QmlModule, QmlModuleOverride};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &[] as &[&str],
+    ///         ..Default::default()
+    ///     })
+    ///     // Registers the types as com.kdab.cxx_qt.demo.beta 2.0 with `--features beta`
+    ///     .qml_module_override(
+    ///         "beta",
+    ///         QmlModuleOverride {
+    ///             uri_suffix: Some(".beta".to_owned()),
+    ///             version_major: Some(2),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn qml_module_override(mut self, feature: &str, qml_override: QmlModuleOverride) -> Self {
+        self.qml_module_overrides
+            .push((feature.to_owned(), qml_override));
+        self
+    }
+
+    /// Apply the overrides of the enabled features to the QML modules
+    fn apply_qml_module_overrides(&mut self) {
+        for (feature, qml_override) in &self.qml_module_overrides {
+            if cfg_evaluator::feature_enabled(feature) {
+                for qml_module in &mut self.qml_modules {
+                    qml_module.apply_override(qml_override);
+                }
+            }
+        }
+    }
+
+    /// Specify a C++ header containing a Q_OBJECT macro to run [moc](https://doc.qt.io/qt-6/moc.html) on.
+    /// This allows building QObject C++ subclasses besides the ones autogenerated by cxx-qt.
+    pub fn qobject_header(mut self, opts: impl Into<QObjectHeaderOpts>) -> Self {
+        let opts = opts.into();
+        println!("cargo:rerun-if-changed={}", opts.path.display());
+        self.qobject_headers.push(opts);
+        self
+    }
+
+    /// Use a closure to run additional customization on [CxxQtBuilder]'s internal [cc::Build]
+    /// before calling [CxxQtBuilder::build]. This allows to add extra include paths, compiler flags,
+    /// or anything else available via [cc::Build]'s API. For example, to add an include path for
+    /// manually written C++ headers located in a directory called `include` within your crate:
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    ///
+    /// CxxQtBuilder::new()
+    ///     .file("src/lib.rs")
+    ///     .cc_builder(|cc| {
+    ///         cc.include("include");
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn cc_builder(mut self, mut callback: impl FnMut(&mut cc::Build)) -> Self {
+        callback(&mut self.cc_builder);
+        self
+    }
+
+    fn define_cfg_variable(key: String, value: Option<&str>) {
+        if let Some(value) = value {
+            println!("cargo:rustc-cfg={key}=\"{value}\"");
+        } else {
+            println!("cargo:rustc-cfg={key}");
+        }
+        let variable_cargo = format!("CARGO_CFG_{}", key);
+        env::set_var(variable_cargo, value.unwrap_or("true"));
+    }
+
+    fn define_cfg_check_variable(key: String, values: Option<Vec<&str>>) {
+        if let Some(values) = values {
+            let values = values
+                .iter()
+                // Escape and add quotes
+                .map(|value| format!("\"{}\"", value.escape_default()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            println!("cargo:rustc-check-cfg=cfg({key}, values({values}))");
+        } else {
+            println!("cargo:rustc-check-cfg=cfg({key})");
+        }
+    }
+
+    fn define_qt_version_cfg_variables(version: &SemVer) {
         // Allow for Qt 5 or Qt 6 as valid values
         CxxQtBuilder::define_cfg_check_variable(
             "cxxqt_qt_version_major".to_owned(),
@@ -597,6 +1611,54 @@ impl CxxQtBuilder {
         }
     }
 
+    fn install_header_directory(source: &Path, destination: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(destination)?;
+        for entry in std::fs::read_dir(source)? {
+            let path = entry?.path();
+            let destination = destination.join(path.file_name().unwrap());
+            // Follow the symlinks to dependencies, so that their headers are copied as well
+            if path.is_dir() {
+                Self::install_header_directory(&path, &destination)?;
+            } else {
+                std::fs::copy(&path, &destination)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn install_headers_into(&self, install_dir: &Path) {
+        Self::install_header_directory(&dir::header_root(), install_dir).unwrap_or_else(|err| {
+            panic!(
+                "Failed to install headers into {}: {err}",
+                install_dir.display()
+            )
+        });
+
+        let macro_prefix = self
+            .include_prefix
+            .to_uppercase()
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let version_header = format!(
+            "// Generated by cxx-qt-build for the crate {crate_name}\n\
+            #pragma once\n\
+            \n\
+            #define {macro_prefix}_VERSION_MAJOR {major}\n\
+            #define {macro_prefix}_VERSION_MINOR {minor}\n\
+            #define {macro_prefix}_VERSION_PATCH {patch}\n\
+            #define {macro_prefix}_VERSION_STRING \"{version}\"\n",
+            crate_name = crate_name(),
+            major = env::var("CARGO_PKG_VERSION_MAJOR").unwrap(),
+            minor = env::var("CARGO_PKG_VERSION_MINOR").unwrap(),
+            patch = env::var("CARGO_PKG_VERSION_PATCH").unwrap(),
+            version = env::var("CARGO_PKG_VERSION").unwrap(),
+        );
+        let version_header_dir = install_dir.join(&self.include_prefix);
+        std::fs::create_dir_all(&version_header_dir)
+            .expect("Could not create header install directory");
+        std::fs::write(version_header_dir.join("cxxqt_version.h"), version_header)
+            .expect("Failed to write cxxqt_version.h");
+    }
+
     fn symlink_directory(target: impl AsRef<Path>, link: impl AsRef<Path>) -> std::io::Result<()> {
         #[cfg(unix)]
         let result = std::os::unix::fs::symlink(target, link);
@@ -649,6 +1711,10 @@ impl CxxQtBuilder {
         include_paths: &[impl AsRef<Path>],
         defines: &[(String, Option<String>)],
     ) {
+        // cc-rs can't find the compilers of the Android NDK by itself,
+        // set it first so that the flags below are checked with the right compiler
+        qt_build_utils::setup_android_compiler(builder);
+
         // Note, ensure our settings stay in sync across cxx-qt, cxx-qt-build, and cxx-qt-lib
         builder.cpp(true);
         builder.std("c++17");
@@ -670,12 +1736,21 @@ impl CxxQtBuilder {
     }
 
     fn moc_qobject_headers(&mut self, qtbuild: &mut qt_build_utils::QtBuild) {
-        for QObjectHeaderOpts {
-            path,
-            moc_arguments,
-        } in &self.qobject_headers
-        {
-            let moc_products = qtbuild.moc(path, moc_arguments.clone());
+        let inputs: Vec<_> = self
+            .qobject_headers
+            .iter()
+            .map(
+                |QObjectHeaderOpts {
+                     path,
+                     moc_arguments,
+                 }| (path.clone(), moc_arguments.clone()),
+            )
+            .collect();
+        let headers: Vec<_> = inputs.iter().map(|(path, _)| path.clone()).collect();
+        for (header, moc_products) in headers.iter().zip(qtbuild.moc_parallel(inputs)) {
+            if let Some(duration) = moc_products.duration {
+                report::record_moc_time(&mut self.bridge_reports, header, duration);
+            }
             // Include the moc folder
             if let Some(dir) = moc_products.cpp.parent() {
                 self.cc_builder.include(dir);
@@ -689,12 +1764,38 @@ impl CxxQtBuilder {
         header_dir: impl AsRef<Path>,
         include_prefix: &str,
     ) {
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &header_dir, include_prefix) {
+        let generated = generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &header_dir,
+            include_prefix,
+            self.dump_dir().as_deref(),
+            &self.cpp_formatting(),
+            &self.type_registry,
+        );
+        let build_report = self.build_report_enabled();
+        for (rust_file, files) in self.rust_sources.iter().zip(generated) {
+            if build_report {
+                self.bridge_reports
+                    .push(BridgeReport::new(rust_file, &files));
+            }
+            if let Some(path) = files.metadata {
+                self.bridge_metadata.push(BridgeMetadataFile {
+                    rust_file: rust_file.clone(),
+                    qml_uri: None,
+                    path,
+                });
+            }
             self.cc_builder.file(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
                 self.cc_builder.file(&qobject);
                 self.qobject_headers.push(qobject_header.into());
             }
+            self.qobject_headers.extend(
+                files
+                    .qobject_class_headers
+                    .into_iter()
+                    .map(QObjectHeaderOpts::from),
+            );
         }
     }
 
@@ -740,6 +1841,27 @@ impl CxxQtBuilder {
         generated_header_dir: impl AsRef<Path>,
         header_prefix: &str,
     ) {
+        let dump_dir = self.dump_dir();
+        let formatting = self.cpp_formatting();
+        let build_report = self.build_report_enabled();
+
+        // A dynamic plugin exports the plugin entry points from the shared library,
+        // so there can only be one per library and QT_STATICPLUGIN must not be defined.
+        let is_dynamic = self
+            .qml_modules
+            .iter()
+            .any(|qml_module| qml_module.plugin_type == QmlPluginType::Dynamic);
+        if is_dynamic && self.qml_modules.len() > 1 {
+            panic!(
+                "A QmlModule with QmlPluginType::Dynamic must be the only QML module of a CxxQtBuilder.\n\
+                Found QML modules: {:?}",
+                self.qml_modules
+                    .iter()
+                    .map(|qml_module| &qml_module.uri)
+                    .collect::<Vec<_>>()
+            );
+        }
+
         for qml_module in &self.qml_modules {
             dir::clean(dir::module_target(&qml_module.uri))
                 .expect("Failed to clean qml module export directory!");
@@ -766,7 +1888,7 @@ impl CxxQtBuilder {
                         String::new()
                     }
                 })
-                .collect::<HashSet<String>>();
+                .collect::<BTreeSet<String>>();
             if dirs.len() > 1 {
                 panic!(
                     "Only one directory is supported per QmlModule for rust_files.\n\
@@ -782,12 +1904,28 @@ impl CxxQtBuilder {
             let cc_builder = &mut self.cc_builder;
             qtbuild.cargo_link_libraries(cc_builder);
 
-            let mut moc_include_paths = HashSet::new();
-            for files in generate_cxxqt_cpp_files(
+            let mut moc_include_paths = BTreeSet::new();
+            let mut moc_inputs = Vec::new();
+            let generated = generate_cxxqt_cpp_files(
                 &qml_module.rust_files,
                 &generated_header_dir,
                 header_prefix,
-            ) {
+                dump_dir.as_deref(),
+                &formatting,
+                &self.type_registry,
+            );
+            for (rust_file, files) in qml_module.rust_files.iter().zip(generated) {
+                if build_report {
+                    self.bridge_reports
+                        .push(BridgeReport::new(rust_file, &files));
+                }
+                if let Some(path) = files.metadata {
+                    self.bridge_metadata.push(BridgeMetadataFile {
+                        rust_file: rust_file.clone(),
+                        qml_uri: Some(qml_module.uri.clone()),
+                        path,
+                    });
+                }
                 cc_builder.file(files.plain_cpp);
                 if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header)
                 {
@@ -798,27 +1936,39 @@ impl CxxQtBuilder {
                     }
 
                     cc_builder.file(&qobject);
-                    let moc_products = qtbuild.moc(
+                    moc_inputs.push((
                         qobject_header,
                         MocArguments::default().uri(qml_module.uri.clone()),
-                    );
-                    // Include the moc folder
-                    if let Some(dir) = moc_products.cpp.parent() {
-                        moc_include_paths.insert(dir.to_path_buf());
-                    }
-                    cc_builder.file(moc_products.cpp);
-                    qml_metatypes_json.push(moc_products.metatypes_json);
+                    ));
+                }
+                for qobject_header in files.qobject_class_headers {
+                    moc_inputs.push((
+                        qobject_header,
+                        MocArguments::default().uri(qml_module.uri.clone()),
+                    ));
                 }
             }
+            let moc_headers: Vec<_> = moc_inputs.iter().map(|(path, _)| path.clone()).collect();
+            for (header, moc_products) in moc_headers.iter().zip(qtbuild.moc_parallel(moc_inputs)) {
+                if let Some(duration) = moc_products.duration {
+                    report::record_moc_time(&mut self.bridge_reports, header, duration);
+                }
+                // Include the moc folder
+                if let Some(dir) = moc_products.cpp.parent() {
+                    moc_include_paths.insert(dir.to_path_buf());
+                }
+                cc_builder.file(moc_products.cpp);
+                qml_metatypes_json.push(moc_products.metatypes_json);
+            }
 
             let qml_module_registration_files = qtbuild.register_qml_module(
                 &qml_metatypes_json,
                 &qml_module.uri,
                 qml_module.version_major,
                 qml_module.version_minor,
-                // TODO: This will be passed to the `optional plugin ...` part of the qmldir
-                // We don't load any shared libraries, so the name shouldn't matter
-                // But make sure it still works
+                // This will be passed to the `optional plugin ...` part of the qmldir.
+                // For static plugins we don't load any shared libraries, so the name shouldn't matter.
+                // For dynamic plugins the shared library must be named after it.
                 &module_name_from_uri(&qml_module.uri),
                 &qml_module.qml_files,
                 &qml_module.qrc_files,
@@ -847,14 +1997,25 @@ impl CxxQtBuilder {
                 cc_builder.file(qmlcachegen_file);
             }
             // This is required, as described here: plugin_builder
-            cc_builder.define("QT_STATICPLUGIN", None);
+            if qml_module.plugin_type == QmlPluginType::Static {
+                cc_builder.define("QT_STATICPLUGIN", None);
+            }
+
+            Self::export_qml_module_metadata(
+                &qml_module.uri,
+                qml_module.plugin_type,
+                &qml_module_registration_files.qmldir,
+                &qml_module_registration_files.qmltypes,
+            );
 
             // If any of the files inside the qml module change, then trigger a rerun
             for path in qml_module.qml_files.iter().chain(
                 qml_module
                     .rust_files
                     .iter()
-                    .chain(qml_module.qrc_files.iter()),
+                    .chain(qml_module.qrc_files.iter())
+                    // Watch the directories as well, so that added files trigger a rerun
+                    .chain(qml_module.qml_dirs.iter()),
             ) {
                 println!("cargo:rerun-if-changed={}", path.display());
             }
@@ -867,14 +2028,267 @@ impl CxxQtBuilder {
             // For CMake builds, we export this file to then later include it as an object library in
             // CMake.
             // In cargo builds, add the object file as a direct argument to the linker.
+            //
+            // Dynamic plugins are instead loaded by the QML engine, so there is nothing
+            // to import at startup. An empty object file is still built so that the
+            // exported layout is the same for both plugin types.
+            let plugin_init = match qml_module.plugin_type {
+                QmlPluginType::Static => qml_module_registration_files.plugin_init,
+                QmlPluginType::Dynamic => {
+                    let file_name =
+                        format!("{}_dynamic_init.cpp", module_name_from_uri(&qml_module.uri));
+                    let plugin_init = qml_module_registration_files
+                        .plugin_init
+                        .with_file_name(file_name);
+                    std::fs::write(
+                        &plugin_init,
+                        "// Dynamic QML plugins are loaded by the QML engine at runtime\n",
+                    )
+                    .expect("Failed to write plugin initializer file");
+                    plugin_init
+                }
+            };
             Self::build_object_file(
                 init_builder,
-                &qml_module_registration_files.plugin_init,
+                &plugin_init,
                 dir::module_target(&qml_module.uri).join("plugin_init.o"),
             );
         }
     }
 
+    /// Run qmllint and qmlformat on the QML files of the QML modules, if they are enabled
+    fn check_qml_files(&self, qtbuild: &qt_build_utils::QtBuild) {
+        if self.qmlformat {
+            for qml_module in &self.qml_modules {
+                qtbuild.qmlformat(&qml_module.qml_files);
+            }
+        }
+
+        if self.qmllint {
+            // The exported qmldir and plugin.qmltypes describe the types defined in Rust
+            let import_paths = self
+                .qml_modules
+                .iter()
+                .map(|qml_module| dir::module_target(&qml_module.uri).join("qml"))
+                .collect::<Vec<_>>();
+            for qml_module in &self.qml_modules {
+                qtbuild.qmllint(&qml_module.qml_files, &import_paths);
+            }
+        }
+    }
+
+    /// Write the documentation of the QML modules into the directory of [CxxQtBuilder::qml_docs]
+    fn write_qml_docs(&self) {
+        if let Some(qml_docs_dir) = &self.qml_docs_dir {
+            for qml_module in &self.qml_modules {
+                let bridges = self
+                    .bridge_metadata
+                    .iter()
+                    .filter(|bridge| bridge.qml_uri.as_ref() == Some(&qml_module.uri))
+                    .map(|bridge| {
+                        serde_json::from_str::<BridgeMetadata>(
+                            &std::fs::read_to_string(&bridge.path)
+                                .expect("Could not read cxx-qt metadata file"),
+                        )
+                        .expect("Could not parse cxx-qt metadata file")
+                    })
+                    .collect::<Vec<_>>();
+                let docs_path = qml_docs_dir
+                    .join(qml_module.uri.replace('.', "/"))
+                    .with_extension("md");
+                if let Some(directory) = docs_path.parent() {
+                    std::fs::create_dir_all(directory)
+                        .expect("Could not create directory to write QML documentation");
+                }
+                write_if_changed(
+                    &docs_path,
+                    write_qml_docs(&qml_module.uri, &bridges).as_bytes(),
+                )
+                .expect("Could not write QML documentation");
+            }
+        }
+    }
+
+    /// Combine the JSON descriptions of the bridges into the file of [CxxQtBuilder::metadata_json]
+    fn write_metadata_json(&self) {
+        if let Some(metadata_json) = &self.metadata_json {
+            let bridges = self
+                .bridge_metadata
+                .iter()
+                .map(|bridge| {
+                    let metadata: serde_json::Value = serde_json::from_str(
+                        &std::fs::read_to_string(&bridge.path)
+                            .expect("Could not read cxx-qt metadata file"),
+                    )
+                    .expect("Could not parse cxx-qt metadata file");
+                    serde_json::json!({
+                        "rust_file": bridge.rust_file,
+                        "qml_uri": bridge.qml_uri,
+                        "metadata": metadata,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let json = serde_json::to_string_pretty(&serde_json::json!({ "bridges": bridges }))
+                .expect("Could not serialize cxx-qt metadata");
+            if let Some(directory) = metadata_json.parent() {
+                std::fs::create_dir_all(directory)
+                    .expect("Could not create directory to write cxx-qt metadata");
+            }
+            write_if_changed(metadata_json, json.as_bytes())
+                .expect("Could not write cxx-qt metadata");
+        }
+    }
+
+    /// Export the qmldir and qmltypes of a QML module into `module_target/qml/<uri dirs>`,
+    /// so that the `qml` folder can be used as a QML import path by tooling such as qmllint
+    /// and Qt Creator, or by the QML engine to load a dynamic plugin.
+    fn export_qml_module_metadata(
+        uri: &str,
+        plugin_type: QmlPluginType,
+        qmldir: &Path,
+        qmltypes: &Path,
+    ) {
+        let plugin_dir = dir::module_target(uri)
+            .join("qml")
+            .join(uri.replace('.', "/"));
+        std::fs::create_dir_all(&plugin_dir).expect("Could not create QML module directory");
+
+        let mut qmldir = std::fs::read_to_string(qmldir).expect("Could not read qmldir file");
+        if plugin_type == QmlPluginType::Dynamic {
+            // The engine must load the shared library, so the plugin is no longer optional
+            qmldir = qmldir.replace("optional plugin ", "plugin ");
+        }
+        std::fs::write(plugin_dir.join("qmldir"), qmldir).expect("Could not write qmldir file");
+
+        if qmltypes.exists() {
+            std::fs::copy(qmltypes, plugin_dir.join("plugin.qmltypes"))
+                .expect("Could not copy plugin.qmltypes");
+        }
+    }
+
+    fn link_static_qt_plugins(&mut self, qtbuild: &qt_build_utils::QtBuild) {
+        let mut class_names = Vec::new();
+        for plugin in &self.static_qt_plugins {
+            match plugin {
+                StaticQtPlugin::Platform => {
+                    let (plugin_name, class_name) =
+                        qt_build_utils::QtBuild::default_static_platform_plugin()
+                            .expect("No default Qt platform plugin is known for this target");
+                    qtbuild.cargo_link_static_plugin(
+                        "platforms",
+                        plugin_name,
+                        &mut self.cc_builder,
+                    );
+                    class_names.push(class_name.to_owned());
+                }
+                StaticQtPlugin::Plugin {
+                    plugin_type,
+                    plugin_name,
+                    class_name,
+                } => {
+                    qtbuild.cargo_link_static_plugin(
+                        plugin_type,
+                        plugin_name,
+                        &mut self.cc_builder,
+                    );
+                    class_names.push(class_name.clone());
+                }
+                StaticQtPlugin::QmlModule(uri) => {
+                    if let Some(class_name) =
+                        qtbuild.cargo_link_static_qml_module(uri, &mut self.cc_builder)
+                    {
+                        class_names.push(class_name);
+                    }
+                }
+            }
+        }
+
+        // The imports need to be in an initializer, so that the linker keeps them
+        if !class_names.is_empty() {
+            let imports = class_names
+                .iter()
+                .map(|class_name| format!("Q_IMPORT_PLUGIN({class_name})"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            self.initializers
+                .push(format!("#include <QtCore/QtPlugin>\n{imports}\n"));
+        }
+    }
+
+    fn write_android_deployment_settings(
+        &self,
+        qtbuild: &qt_build_utils::QtBuild,
+        application_binary: &str,
+    ) {
+        let (abi, ndk_root) = match (
+            qt_build_utils::android_abi(),
+            qt_build_utils::android_ndk_root(),
+        ) {
+            (Some(abi), Some(ndk_root)) => (abi, ndk_root),
+            (Some(_), None) => panic!("The Android NDK could not be found, set ANDROID_NDK_ROOT"),
+            (None, _) => return,
+        };
+        let sdk_root = env::var("ANDROID_SDK_ROOT")
+            .or_else(|_| env::var("ANDROID_HOME"))
+            .expect("The Android SDK could not be found, set ANDROID_SDK_ROOT");
+        let toolchain_target = env::var("TARGET")
+            .unwrap()
+            .replace("armv7-linux-androideabi", "arm-linux-androideabi");
+
+        let qml_root_paths = self
+            .qml_modules
+            .iter()
+            .flat_map(|qml_module| qml_module.qml_files.iter().chain(&qml_module.qml_dirs))
+            .filter_map(|path| std::fs::canonicalize(path).ok())
+            .filter_map(|path| {
+                if path.is_dir() {
+                    Some(path)
+                } else {
+                    path.parent().map(Path::to_path_buf)
+                }
+            })
+            .collect::<BTreeSet<_>>();
+
+        let tool_path = |tool_name: &str| {
+            qtbuild
+                .tool_path(tool_name)
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_default()
+        };
+
+        let settings = serde_json::json!({
+            "description": "This file is generated by cxx-qt-build to be read by androiddeployqt and should not be modified by hand.",
+            "qt": qtbuild.qmake_query("QT_INSTALL_PREFIX"),
+            "sdk": sdk_root,
+            "ndk": ndk_root,
+            "toolchain-prefix": "llvm",
+            "tool-prefix": "llvm",
+            "ndk-host": qt_build_utils::android_ndk_host_tag(),
+            "architectures": { abi: toolchain_target },
+            "android-min-sdk-version": qt_build_utils::android_api_level().to_string(),
+            "qml-importscanner-binary": tool_path("qmlimportscanner"),
+            "rcc-binary": tool_path("rcc"),
+            "qml-root-path": qml_root_paths,
+            "stdcpp-path": ndk_root
+                .join("toolchains")
+                .join("llvm")
+                .join("prebuilt")
+                .join(qt_build_utils::android_ndk_host_tag())
+                .join("sysroot")
+                .join("usr")
+                .join("lib"),
+            "application-binary": application_binary,
+        });
+
+        let settings_path = dir::crate_target().join("android-deployment-settings.json");
+        std::fs::write(
+            &settings_path,
+            serde_json::to_string_pretty(&settings)
+                .expect("Failed to convert Android deployment settings to JSON!"),
+        )
+        .expect("Failed to write android-deployment-settings.json!");
+    }
+
     fn setup_qt5_compatibility(&mut self, qtbuild: &qt_build_utils::QtBuild) {
         // If we are using Qt 5 then write the std_types source
         // This registers std numbers as a type for use in QML
@@ -897,7 +2311,7 @@ impl CxxQtBuilder {
         }
     }
 
-    fn generate_init_code(&self, initializers: &HashSet<PathBuf>) -> String {
+    fn generate_init_code(&self, initializers: &BTreeSet<PathBuf>) -> String {
         initializers
             .iter()
             .map(|path| std::fs::read_to_string(path).expect("Could not read initializer file!"))
@@ -906,7 +2320,7 @@ impl CxxQtBuilder {
             .join("\n")
     }
 
-    fn build_initializers(&mut self, init_builder: &cc::Build, initializers: &HashSet<PathBuf>) {
+    fn build_initializers(&mut self, init_builder: &cc::Build, initializers: &BTreeSet<PathBuf>) {
         let initializers_path = dir::out().join("cxx-qt-build").join("initializers");
         std::fs::create_dir_all(&initializers_path).expect("Failed to create initializers path!");
 
@@ -920,10 +2334,171 @@ impl CxxQtBuilder {
         );
     }
 
+    /// Object files with static initializers that need to be linked into the final binary
+    fn initializer_objects(&self) -> Vec<PathBuf> {
+        // The initializers of dependencies are part of initializers.o
+        let mut objects = vec![dir::crate_target().join("initializers.o")];
+        for qml_module in &self.qml_modules {
+            objects.push(dir::module_target(&qml_module.uri).join("plugin_init.o"));
+        }
+        objects
+    }
+
+    fn write_qmake_and_pkg_config_files(
+        &self,
+        qt_version: &SemVer,
+        qt_modules: &BTreeSet<String>,
+        compile_definitions: &[(String, Option<String>)],
+    ) {
+        let mut qt_modules = qt_modules.iter().collect::<Vec<_>>();
+        qt_modules.sort();
+
+        // Assume the default layout of target/<profile>/build/<crate>-<hash>/out
+        let lib_dir = dir::out()
+            .ancestors()
+            .nth(3)
+            .expect("Could not find the cargo target directory from OUT_DIR")
+            .to_path_buf();
+        let lib_name = crate_name().replace('-', "_");
+        // Relative install directories are relative to the crate directory
+        let include_dir = self
+            .header_install_dir
+            .as_ref()
+            .map(|install_dir| {
+                PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(install_dir)
+            })
+            .unwrap_or_else(dir::header_root);
+        let defines = compile_definitions
+            .iter()
+            .map(|(variable, value)| match value {
+                Some(value) => format!("{variable}={value}"),
+                None => variable.clone(),
+            })
+            .collect::<Vec<_>>();
+        let objects = self
+            .initializer_objects()
+            .iter()
+            .map(|object| object.display().to_string())
+            .collect::<Vec<_>>();
+
+        if self.qmake_pri {
+            let qt = qt_modules
+                .iter()
+                .map(|qt_module| qt_module.to_lowercase())
+                .collect::<Vec<_>>();
+            let pri = format!(
+                "# Generated by cxx-qt-build for the crate {crate_name}\n\
+                QT += {qt}\n\
+                INCLUDEPATH += {include_dir}\n\
+                DEFINES += {defines}\n\
+                LIBS += {objects} -L{lib_dir} -l{lib_name}\n",
+                crate_name = crate_name(),
+                qt = qt.join(" "),
+                include_dir = include_dir.display(),
+                defines = defines.join(" "),
+                objects = objects.join(" "),
+                lib_dir = lib_dir.display(),
+            );
+            let pri_path = dir::crate_target().join(format!("{}.pri", crate_name()));
+            std::fs::write(pri_path, &pri).expect("Failed to write qmake .pri file!");
+
+            if let Some(install_path) = &self.qmake_pri_install_path {
+                if let Some(parent) = install_path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .expect("Could not create qmake .pri install directory");
+                }
+                std::fs::write(install_path, &pri).expect("Failed to install qmake .pri file!");
+            }
+        }
+
+        if self.pkg_config {
+            let requires = qt_modules
+                .iter()
+                .map(|qt_module| format!("Qt{}{qt_module}", qt_version.major))
+                .collect::<Vec<_>>();
+            let cflags = std::iter::once(format!("-I{}", include_dir.display()))
+                .chain(defines.iter().map(|define| format!("-D{define}")))
+                .collect::<Vec<_>>();
+            let pc = format!(
+                "# Generated by cxx-qt-build for the crate {crate_name}\n\
+                Name: {crate_name}\n\
+                Description: {description}\n\
+                Version: {version}\n\
+                Requires: {requires}\n\
+                Cflags: {cflags}\n\
+                Libs: {objects} -L{lib_dir} -l{lib_name}\n",
+                crate_name = crate_name(),
+                description = env::var("CARGO_PKG_DESCRIPTION").unwrap_or_default(),
+                version = env::var("CARGO_PKG_VERSION").unwrap(),
+                requires = requires.join(" "),
+                cflags = cflags.join(" "),
+                objects = objects.join(" "),
+                lib_dir = lib_dir.display(),
+            );
+            let pc_path = dir::crate_target().join(format!("{}.pc", crate_name()));
+            std::fs::write(pc_path, pc).expect("Failed to write pkg-config .pc file!");
+        }
+    }
+
+    fn write_cmake_sources(&self, qt_modules: &BTreeSet<String>) {
+        // cc::Build doesn't expose the include directories and definitions, so read them back
+        // from the arguments of the compiler.
+        let mut include_dirs = Vec::new();
+        let mut definitions = Vec::new();
+        let compiler = self.cc_builder.get_compiler();
+        let mut args = compiler.args().iter().map(|arg| arg.to_string_lossy());
+        while let Some(arg) = args.next() {
+            if arg == "-I" || arg == "/I" {
+                include_dirs.extend(args.next().map(|dir| dir.to_string()));
+            } else if let Some(dir) = arg.strip_prefix("-I").or_else(|| arg.strip_prefix("/I")) {
+                include_dirs.push(dir.to_string());
+            } else if let Some(define) = arg.strip_prefix("-D").or_else(|| arg.strip_prefix("/D")) {
+                definitions.push(define.to_string());
+            }
+        }
+
+        fn cmake_list<T: std::fmt::Display>(items: impl IntoIterator<Item = T>) -> String {
+            items
+                .into_iter()
+                .map(|item| format!("\n    \"{}\"", item.to_string().replace('\\', "/")))
+                .collect()
+        }
+
+        let mut qt_modules = qt_modules.iter().collect::<Vec<_>>();
+        qt_modules.sort();
+
+        let prefix = format!("CXX_QT_{}", crate_name().to_uppercase().replace('-', "_"));
+        let cmake = format!(
+            "# Generated by cxx-qt-build for the crate {crate_name}\n\
+            set({prefix}_SOURCES{sources})\n\
+            set({prefix}_INCLUDE_DIRS{include_dirs})\n\
+            set({prefix}_DEFINITIONS{definitions})\n\
+            set({prefix}_QT_MODULES{qt_modules})\n\
+            set({prefix}_OBJECTS{objects})\n",
+            crate_name = crate_name(),
+            sources = cmake_list(self.cc_builder.get_files().map(|file| file.display())),
+            include_dirs = cmake_list(include_dirs),
+            definitions = cmake_list(definitions),
+            qt_modules = cmake_list(qt_modules),
+            objects = cmake_list(
+                self.initializer_objects()
+                    .iter()
+                    .map(|object| object.display())
+            ),
+        );
+
+        let cmake_path = dir::crate_target().join("cxx-qt-sources.cmake");
+        std::fs::write(&cmake_path, cmake).expect("Failed to write cxx-qt-sources.cmake!");
+        println!(
+            "cargo::metadata=CXX_QT_CMAKE_SOURCES_PATH={}",
+            cmake_path.to_string_lossy()
+        );
+    }
+
     fn generate_cpp_from_qrc_files(
         &mut self,
         qtbuild: &mut qt_build_utils::QtBuild,
-    ) -> HashSet<PathBuf> {
+    ) -> BTreeSet<PathBuf> {
         self.qrc_files
             .iter()
             .map(|qrc_file| {
@@ -938,11 +2513,58 @@ impl CxxQtBuilder {
             .collect()
     }
 
+    /// Update the translation files with lupdate if enabled, and compile them with lrelease into
+    /// a qrc file, returning the path of the C++ file generated by rcc
+    fn build_translations(&self, qtbuild: &mut qt_build_utils::QtBuild) -> Option<PathBuf> {
+        if self.translations.is_empty() {
+            return None;
+        }
+
+        if self.lupdate {
+            let sources = self
+                .rust_sources
+                .iter()
+                .chain(self.qml_modules.iter().flat_map(|qml_module| {
+                    qml_module.rust_files.iter().chain(&qml_module.qml_files)
+                }))
+                .collect::<Vec<_>>();
+            qtbuild.lupdate(&sources, &self.translations);
+        }
+
+        let mut qrc_files = String::new();
+        for ts_file in &self.translations {
+            let qm_file = qtbuild.lrelease(ts_file);
+            qrc_files.push_str(&format!(
+                "    <file alias=\"{}\">{}</file>\n",
+                qm_file.file_name().unwrap().to_string_lossy(),
+                qm_file.display()
+            ));
+        }
+
+        // The file name is used as the name of the resource initializer by rcc,
+        // so it needs to be unique across crates.
+        let qrc_dir = dir::out().join("cxx-qt-build").join("qrc");
+        std::fs::create_dir_all(&qrc_dir).expect("Could not create qrc directory");
+        let qrc_path = qrc_dir.join(format!(
+            "{}_translations.qrc",
+            crate_name().replace('-', "_")
+        ));
+        std::fs::write(
+            &qrc_path,
+            format!("<RCC>\n<qresource prefix=\"/i18n\">\n{qrc_files}</qresource>\n</RCC>\n"),
+        )
+        .expect("Could not write qrc file");
+
+        // The .qm files are generated, so unlike the qrc files of the builder
+        // they must not trigger a rerun of the build script
+        Some(qtbuild.qrc(&qrc_path))
+    }
+
     fn write_manifest(
         &self,
         dependencies: &[Dependency],
-        qt_modules: HashSet<String>,
-        initializers: HashSet<PathBuf>,
+        qt_modules: BTreeSet<String>,
+        initializers: BTreeSet<PathBuf>,
     ) {
         if let Some(interface) = &self.public_interface {
             // We automatically reexport all qt_modules and initializers from downstream dependencies
@@ -977,7 +2599,7 @@ impl CxxQtBuilder {
         }
     }
 
-    fn qt_modules(&self, dependencies: &[Dependency]) -> HashSet<String> {
+    fn qt_modules(&self, dependencies: &[Dependency]) -> BTreeSet<String> {
         let mut qt_modules = self.qt_modules.clone();
         for dependency in dependencies {
             qt_modules.extend(dependency.manifest.qt_modules.iter().cloned());
@@ -1025,7 +2647,25 @@ impl CxxQtBuilder {
         let mut qtbuild = qt_build_utils::QtBuild::new(qt_modules.iter().cloned().collect())
             .expect("Could not find Qt installation");
         qtbuild.cargo_link_libraries(&mut self.cc_builder);
+        if let Some(qmlcachegen) = self.qmlcachegen {
+            qtbuild.qmlcachegen(qmlcachegen);
+        }
+        // Qt for WebAssembly is always static and can only use the qwasm platform plugin,
+        // which qt-build-utils links, so import it without requiring qt_static_platform_plugin
+        if qt_build_utils::emscripten_targeted()
+            && qt_modules.contains("Gui")
+            && !self
+                .static_qt_plugins
+                .iter()
+                .any(|plugin| matches!(plugin, StaticQtPlugin::Platform))
+        {
+            self.static_qt_plugins.push(StaticQtPlugin::Platform);
+        }
+        if qtbuild.is_static() {
+            self.link_static_qt_plugins(&qtbuild);
+        }
         Self::define_qt_version_cfg_variables(qtbuild.version());
+        self.apply_qml_module_overrides();
 
         // Setup compilers
         // Static QML plugin and Qt resource initializers need to be linked as their own separate
@@ -1048,12 +2688,25 @@ impl CxxQtBuilder {
         Self::setup_cc_builder(&mut self.cc_builder, &include_paths, &compile_definitions);
 
         Self::setup_cc_builder(&mut init_builder, &include_paths, &compile_definitions);
+        // cargo_link_libraries only adds -pthread and the framework paths for the main cc_builder
+        if qt_build_utils::emscripten_targeted() && qtbuild.wasm_threads_enabled() {
+            init_builder.flag("-pthread");
+        }
+        for framework_path in qtbuild.framework_paths() {
+            init_builder.flag(format!("-F{}", framework_path.display()));
+        }
         // Note: From now on the init_builder is correctly configured.
         // When building object files with this builder, we always need to copy it first.
         // So remove `mut` to ensure that we can't accidentally change the configuration or add
         // files.
         let init_builder = init_builder;
 
+        self.register_cxx_bridge_types();
+        // Share the registered types with the #[cxx_qt::bridge] macro, which reads them from OUT_DIR
+        self.type_registry
+            .write_to_dir(dir::out())
+            .expect("Failed to write the cxx-qt-gen type registry!");
+
         // Generate files
         self.generate_cpp_files_from_cxxqt_bridges(&header_root, &self.include_prefix.clone());
 
@@ -1068,7 +2721,13 @@ impl CxxQtBuilder {
             &self.include_prefix.clone(),
         );
 
+        report::measure_compile_times(&mut self.bridge_reports, &init_builder);
+        self.check_qml_files(&qtbuild);
+        self.write_metadata_json();
+        self.write_qml_docs();
+
         let mut initializers = self.generate_cpp_from_qrc_files(&mut qtbuild);
+        initializers.extend(self.build_translations(&mut qtbuild));
         initializers.extend(dependencies::initializer_paths(
             self.public_interface.as_ref(),
             &dependencies,
@@ -1078,9 +2737,13 @@ impl CxxQtBuilder {
 
         self.build_initializers(&init_builder, &initializers);
 
-        // Only compile if we have added files to the builder
-        // otherwise we end up with no static library but ask cargo to link to it which causes an error
-        if self.cc_builder.get_files().count() > 0 {
+        if self.compile_in_cmake {
+            // CMake compiles the files of the builder, so there is no static library to link to
+            self.write_cmake_sources(&qt_modules);
+        } else if self.cc_builder.get_files().count() > 0 {
+            // Only compile if we have added files to the builder
+            // otherwise we end up with no static library but ask cargo to link to it which causes an error
+            //
             // The linker argument order matters!
             // We need to link the object file first, then link the static library.
             // Otherwise, the linker will be unable to find the symbols in the static library file.
@@ -1092,6 +2755,692 @@ impl CxxQtBuilder {
             self.cc_builder.compile(&static_lib_name());
         }
 
+        if let Some(application_binary) = &self.android_application_binary {
+            self.write_android_deployment_settings(&qtbuild, application_binary);
+        }
+
+        if self.deploy_windows_dlls {
+            qtbuild.cargo_deploy_windows_dlls();
+        }
+
+        if self.qmake_pri || self.pkg_config {
+            self.write_qmake_and_pkg_config_files(
+                qtbuild.version(),
+                &qt_modules,
+                &compile_definitions,
+            );
+        }
+
+        if let Some(header_install_dir) = &self.header_install_dir {
+            self.install_headers_into(header_install_dir);
+        }
+
         self.write_manifest(&dependencies, qt_modules, initializers);
+
+        report::print(&mut self.bridge_reports);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A new empty directory for a test, inside the temporary directory of the system
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir()
+            .join(format!("cxx-qt-build-tests-{}", std::process::id()))
+            .join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Set the OUT_DIR to a new empty directory for the duration of a test
+    ///
+    /// The environment is shared between the threads of the tests,
+    /// so the returned guard needs to be held until the test finished.
+    fn test_out_dir(name: &str) -> (std::sync::MutexGuard<'static, ()>, PathBuf) {
+        static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        // A failed test poisons the lock, which is not a problem for the other tests
+        let lock = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let out_dir = test_dir(name);
+        env::set_var("OUT_DIR", &out_dir);
+        (lock, out_dir)
+    }
+
+    /// Write a bridge cache whose generated files exist in the directory
+    fn write_test_cache(dir: &Path, input_hash: u64) -> PathBuf {
+        let plain_cpp = dir.join("bridge.cxx.cpp");
+        let rust = dir.join("bridge.rs");
+        std::fs::write(&plain_cpp, "").unwrap();
+        std::fs::write(&rust, "").unwrap();
+
+        let cache_path = dir.join("cache").join("bridge.json");
+        write_bridge_cache(
+            &cache_path,
+            &BridgeCache {
+                version: BRIDGE_CACHE_VERSION,
+                input_hash,
+                file_paths: GeneratedCppFilePaths {
+                    plain_cpp,
+                    qobject: None,
+                    qobject_header: None,
+                    qobject_class_headers: vec![],
+                    metadata: None,
+                    rust,
+                },
+            },
+        );
+        cache_path
+    }
+
+    fn formatting() -> CppFormatting {
+        CppFormatting {
+            style: None,
+            format_cxx: false,
+        }
+    }
+
+    #[test]
+    fn test_bridge_cache_hit() {
+        let dir = test_dir("cache_hit");
+        let cache_path = write_test_cache(&dir, 42);
+
+        let file_paths = read_bridge_cache(&cache_path, 42).unwrap();
+        assert_eq!(file_paths.plain_cpp, dir.join("bridge.cxx.cpp"));
+        assert_eq!(file_paths.rust, dir.join("bridge.rs"));
+    }
+
+    #[test]
+    fn test_bridge_cache_miss() {
+        let dir = test_dir("cache_miss");
+        let cache_path = write_test_cache(&dir, 42);
+
+        assert!(read_bridge_cache(&cache_path, 43).is_none());
+        assert!(read_bridge_cache(&dir.join("cache").join("other.json"), 42).is_none());
+    }
+
+    #[test]
+    fn test_bridge_cache_invalidated_by_missing_file() {
+        let dir = test_dir("cache_missing_file");
+        let cache_path = write_test_cache(&dir, 42);
+
+        std::fs::remove_file(dir.join("bridge.rs")).unwrap();
+        assert!(read_bridge_cache(&cache_path, 42).is_none());
+    }
+
+    #[test]
+    fn test_bridge_cache_invalidated_by_version() {
+        let dir = test_dir("cache_version");
+        let cache_path = write_test_cache(&dir, 42);
+
+        let cache_json = std::fs::read_to_string(&cache_path).unwrap();
+        let outdated = cache_json.replace(
+            &format!("\"version\":{BRIDGE_CACHE_VERSION}"),
+            &format!("\"version\":{}", BRIDGE_CACHE_VERSION + 1),
+        );
+        assert_ne!(cache_json, outdated);
+        std::fs::write(&cache_path, outdated).unwrap();
+        assert!(read_bridge_cache(&cache_path, 42).is_none());
+
+        // Caches from before the version was added don't have one
+        std::fs::write(&cache_path, r#"{"input_hash":42,"file_paths":{}}"#).unwrap();
+        assert!(read_bridge_cache(&cache_path, 42).is_none());
+
+        std::fs::write(&cache_path, "not json").unwrap();
+        assert!(read_bridge_cache(&cache_path, 42).is_none());
+    }
+
+    #[test]
+    fn test_bridge_input_hash() {
+        let dir = test_dir("input_hash");
+        let rust_file = dir.join("bridge.rs");
+        std::fs::write(&rust_file, "mod ffi {}").unwrap();
+        let registry = TypeRegistry::default();
+        let hash = |include_prefix: &str| {
+            bridge_input_hash(&rust_file, include_prefix, &dir, &formatting(), &registry)
+        };
+
+        let original = hash("crate");
+        assert_eq!(original, hash("crate"));
+        assert_ne!(original, hash("other_crate"));
+
+        std::fs::write(&rust_file, "mod ffi { struct Changed; }").unwrap();
+        assert_ne!(original, hash("crate"));
+
+        let formatted = bridge_input_hash(
+            &rust_file,
+            "crate",
+            &dir,
+            &CppFormatting {
+                style: Some(ClangFormatStyle::Mozilla),
+                format_cxx: false,
+            },
+            &registry,
+        );
+        assert_ne!(hash("crate"), formatted);
+    }
+
+    #[test]
+    fn test_static_qt_plugins() {
+        let builder = CxxQtBuilder::new()
+            .qt_static_platform_plugin()
+            .qt_static_plugin("imageformats", "qsvg", "QSvgPlugin")
+            .qt_static_qml_import("QtQuick.Controls");
+        assert!(matches!(
+            builder.static_qt_plugins.as_slice(),
+            [
+                StaticQtPlugin::Platform,
+                StaticQtPlugin::Plugin {
+                    plugin_type,
+                    plugin_name,
+                    class_name,
+                },
+                StaticQtPlugin::QmlModule(uri),
+            ] if plugin_type == "imageformats"
+                && plugin_name == "qsvg"
+                && class_name == "QSvgPlugin"
+                && uri == "QtQuick.Controls"
+        ));
+    }
+
+    #[test]
+    fn test_translations() {
+        let builder = CxxQtBuilder::new();
+        assert!(builder.translations.is_empty());
+        assert!(!builder.lupdate);
+
+        let builder = builder
+            .translations(&["i18n/app_de.ts"])
+            .translations(&[PathBuf::from("i18n/app_fr.ts")])
+            .lupdate(true);
+        assert_eq!(
+            builder.translations,
+            vec![
+                PathBuf::from("i18n/app_de.ts"),
+                PathBuf::from("i18n/app_fr.ts")
+            ]
+        );
+        assert!(builder.lupdate);
+    }
+
+    #[test]
+    fn test_export_dynamic_qml_plugin() {
+        let (_lock, out_dir) = test_out_dir("export_dynamic_qml_plugin");
+        let qmldir = out_dir.join("qmldir");
+        let qmltypes = out_dir.join("plugin.qmltypes");
+        std::fs::write(
+            &qmldir,
+            "module com.kdab.demo\noptional plugin com_kdab_demo\nclassname com_kdab_demo_plugin\n",
+        )
+        .unwrap();
+        std::fs::write(&qmltypes, "Module {}").unwrap();
+
+        let plugin_dir = dir::module_target("com.kdab.demo")
+            .join("qml")
+            .join("com")
+            .join("kdab")
+            .join("demo");
+        CxxQtBuilder::export_qml_module_metadata(
+            "com.kdab.demo",
+            QmlPluginType::Static,
+            &qmldir,
+            &qmltypes,
+        );
+        assert_eq!(
+            std::fs::read_to_string(plugin_dir.join("qmldir")).unwrap(),
+            "module com.kdab.demo\noptional plugin com_kdab_demo\nclassname com_kdab_demo_plugin\n"
+        );
+
+        CxxQtBuilder::export_qml_module_metadata(
+            "com.kdab.demo",
+            QmlPluginType::Dynamic,
+            &qmldir,
+            &qmltypes,
+        );
+        // The engine has to load the plugin, so it is not optional
+        assert_eq!(
+            std::fs::read_to_string(plugin_dir.join("qmldir")).unwrap(),
+            "module com.kdab.demo\nplugin com_kdab_demo\nclassname com_kdab_demo_plugin\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(plugin_dir.join("plugin.qmltypes")).unwrap(),
+            "Module {}"
+        );
+    }
+
+    #[test]
+    fn test_qml_plugin_type() {
+        let qml_module: OwningQmlModule = QmlModule::<&str, &str>::default().into();
+        assert_eq!(qml_module.plugin_type, QmlPluginType::Static);
+
+        let qml_module: OwningQmlModule = QmlModule::<&str, &str> {
+            plugin_type: QmlPluginType::Dynamic,
+            ..Default::default()
+        }
+        .into();
+        assert_eq!(qml_module.plugin_type, QmlPluginType::Dynamic);
+    }
+
+    #[test]
+    fn test_qrc_resources() {
+        let (_lock, out_dir) = test_out_dir("qrc_resources");
+        let builder = CxxQtBuilder::new()
+            .qrc_resources("/sources", &["src/lib.rs", "src/dir.rs"])
+            .qrc_resources("/manifest", &["Cargo.toml"]);
+
+        // Each call writes a separate qrc file
+        let qrc_dir = out_dir.join("cxx-qt-build").join("qrc");
+        assert_eq!(
+            builder.qrc_files,
+            vec![
+                qrc_dir.join("cxx_qt_build_resources_0.qrc"),
+                qrc_dir.join("cxx_qt_build_resources_1.qrc"),
+            ]
+        );
+        let canonical = |path: &str| std::fs::canonicalize(path).unwrap().display().to_string();
+        assert_eq!(
+            std::fs::read_to_string(&builder.qrc_files[0]).unwrap(),
+            format!(
+                "<RCC>\n<qresource prefix=\"/sources\">\n    <file alias=\"src/lib.rs\">{}</file>\n    <file alias=\"src/dir.rs\">{}</file>\n</qresource>\n</RCC>\n",
+                canonical("src/lib.rs"),
+                canonical("src/dir.rs")
+            )
+        );
+        assert_eq!(
+            std::fs::read_to_string(&builder.qrc_files[1]).unwrap(),
+            format!(
+                "<RCC>\n<qresource prefix=\"/manifest\">\n    <file alias=\"Cargo.toml\">{}</file>\n</qresource>\n</RCC>\n",
+                canonical("Cargo.toml")
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_cmake_sources() {
+        let (_lock, out_dir) = test_out_dir("write_cmake_sources");
+        std::fs::create_dir_all(dir::crate_target()).unwrap();
+        let mut builder = CxxQtBuilder::new()
+            .compile_in_cmake()
+            .qml_module(QmlModule::<&str, &str> {
+                uri: "com.kdab.demo",
+                ..Default::default()
+            });
+        assert!(builder.compile_in_cmake);
+        builder
+            .cc_builder
+            .target("x86_64-unknown-linux-gnu")
+            .host("x86_64-unknown-linux-gnu")
+            .opt_level(0)
+            .cargo_metadata(false)
+            .include("/include/dir")
+            .define("QT_STATICPLUGIN", None)
+            .define("VERSION", "2")
+            .file("/src/bridge.cpp");
+
+        builder.write_cmake_sources(&BTreeSet::from(["Qml".to_string(), "Core".to_string()]));
+        let target = out_dir.join("cxx-qt-build").join("target");
+        assert_eq!(
+            std::fs::read_to_string(dir::crate_target().join("cxx-qt-sources.cmake")).unwrap(),
+            format!(
+                r#"# Generated by cxx-qt-build for the crate cxx-qt-build
+set(CXX_QT_CXX_QT_BUILD_SOURCES
+    "/src/bridge.cpp")
+set(CXX_QT_CXX_QT_BUILD_INCLUDE_DIRS
+    "/include/dir")
+set(CXX_QT_CXX_QT_BUILD_DEFINITIONS
+    "QT_STATICPLUGIN"
+    "VERSION=2")
+set(CXX_QT_CXX_QT_BUILD_QT_MODULES
+    "Core"
+    "Qml")
+set(CXX_QT_CXX_QT_BUILD_OBJECTS
+    "{}"
+    "{}")
+"#,
+                target
+                    .join("crates")
+                    .join("cxx-qt-build")
+                    .join("initializers.o")
+                    .display(),
+                target
+                    .join("qml_modules")
+                    .join("com_kdab_demo")
+                    .join("plugin_init.o")
+                    .display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_install_headers() {
+        let (_lock, out_dir) = test_out_dir("install_headers");
+        let header_root = dir::header_root();
+        std::fs::create_dir_all(header_root.join("cxx-qt-build").join("src")).unwrap();
+        std::fs::write(
+            header_root
+                .join("cxx-qt-build")
+                .join("src")
+                .join("my_object.cxxqt.h"),
+            "#pragma once",
+        )
+        .unwrap();
+        // The headers of dependencies are linked into the header root
+        let dependency = out_dir.join("dependency");
+        std::fs::create_dir_all(&dependency).unwrap();
+        std::fs::write(dependency.join("dependency.h"), "#pragma once").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&dependency, header_root.join("dependency")).unwrap();
+
+        let install_dir = out_dir.join("install");
+        let builder = CxxQtBuilder::new().install_headers(&install_dir);
+        assert_eq!(builder.header_install_dir, Some(install_dir.clone()));
+        builder.install_headers_into(&install_dir);
+
+        assert!(install_dir
+            .join("cxx-qt-build")
+            .join("src")
+            .join("my_object.cxxqt.h")
+            .is_file());
+        #[cfg(unix)]
+        assert!(install_dir
+            .join("dependency")
+            .join("dependency.h")
+            .is_file());
+        assert_eq!(
+            std::fs::read_to_string(install_dir.join("cxx-qt-build").join("cxxqt_version.h"))
+                .unwrap(),
+            format!(
+                "// Generated by cxx-qt-build for the crate cxx-qt-build
+#pragma once
+
+#define CXX_QT_BUILD_VERSION_MAJOR {}
+#define CXX_QT_BUILD_VERSION_MINOR {}
+#define CXX_QT_BUILD_VERSION_PATCH {}
+#define CXX_QT_BUILD_VERSION_STRING \"{}\"
+",
+                env!("CARGO_PKG_VERSION_MAJOR"),
+                env!("CARGO_PKG_VERSION_MINOR"),
+                env!("CARGO_PKG_VERSION_PATCH"),
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_qmake_and_pkg_config_files() {
+        let (_lock, dir) = test_out_dir("write_qmake_and_pkg_config_files");
+        // The library is found from the default layout of target/<profile>/build/<crate>-<hash>/out
+        let profile_dir = dir.join("target").join("debug");
+        env::set_var(
+            "OUT_DIR",
+            profile_dir.join("build").join("my-crate-1234").join("out"),
+        );
+        std::fs::create_dir_all(dir::crate_target()).unwrap();
+
+        let pri_install_path = dir.join("install").join("my_crate.pri");
+        let builder = CxxQtBuilder::new()
+            .install_qmake_pri(&pri_install_path)
+            .pkg_config();
+        builder.write_qmake_and_pkg_config_files(
+            &SemVer::new("6.5.0").unwrap(),
+            &BTreeSet::from(["Qml".to_string(), "Core".to_string()]),
+            &[
+                ("QT_STATICPLUGIN".to_string(), None),
+                ("VERSION".to_string(), Some("2".to_string())),
+            ],
+        );
+
+        let header_root = dir::header_root().display().to_string();
+        let initializers = dir::crate_target()
+            .join("initializers.o")
+            .display()
+            .to_string();
+        let profile_dir = profile_dir.display();
+        let pri = std::fs::read_to_string(dir::crate_target().join("cxx-qt-build.pri")).unwrap();
+        assert_eq!(
+            pri,
+            format!(
+                "# Generated by cxx-qt-build for the crate cxx-qt-build
+QT += core qml
+INCLUDEPATH += {header_root}
+DEFINES += QT_STATICPLUGIN VERSION=2
+LIBS += {initializers} -L{profile_dir} -lcxx_qt_build
+"
+            )
+        );
+        assert_eq!(std::fs::read_to_string(pri_install_path).unwrap(), pri);
+
+        assert_eq!(
+            std::fs::read_to_string(dir::crate_target().join("cxx-qt-build.pc")).unwrap(),
+            format!(
+                "# Generated by cxx-qt-build for the crate cxx-qt-build
+Name: cxx-qt-build
+Description: {description}
+Version: {version}
+Requires: Qt6Core Qt6Qml
+Cflags: -I{header_root} -DQT_STATICPLUGIN -DVERSION=2
+Libs: {initializers} -L{profile_dir} -lcxx_qt_build
+",
+                description = env!("CARGO_PKG_DESCRIPTION"),
+                version = env!("CARGO_PKG_VERSION"),
+            )
+        );
+    }
+
+    #[test]
+    fn test_content_hash() {
+        // The FNV-1a hash must not change between platforms and Rust versions
+        assert_eq!(content_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(content_hash(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(content_hash(b"ab"), content_hash(b"ba"));
+    }
+
+    #[test]
+    fn test_content_hashed_path() {
+        let dir = test_dir("content_hashed_path");
+        let path = content_hashed_path(&dir, "src/lib.cxxqt", b"a");
+        assert_eq!(path, dir.join("src").join("lib.cxxqt.af63dc4c8601ec8c.cpp"));
+
+        let src = dir.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        for file in [
+            "lib.cxxqt.af63dc4c8601ec8c.cpp",
+            "lib.cxxqt.0123456789abcdef.cpp",
+            "lib.cxx.0123456789abcdef.cpp",
+            "lib.cxxqt.handwritten.cpp",
+        ] {
+            std::fs::write(src.join(file), "").unwrap();
+        }
+
+        // Only sources with the same stem and another hash are removed
+        assert_eq!(content_hashed_path(&dir, "src/lib.cxxqt", b"a"), path);
+        assert!(path.exists());
+        assert!(!src.join("lib.cxxqt.0123456789abcdef.cpp").exists());
+        assert!(src.join("lib.cxx.0123456789abcdef.cpp").exists());
+        assert!(src.join("lib.cxxqt.handwritten.cpp").exists());
+    }
+
+    /// Generate the code of a bridge with a QObject in `src/bridge.rs`
+    fn generate_test_bridge(dir: &Path) -> GeneratedCpp {
+        let rust_file = dir.join("bridge.rs");
+        std::fs::write(
+            &rust_file,
+            r#"
+            #[cxx_qt::bridge]
+            mod qobject {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        GeneratedCpp::new(
+            &rust_file,
+            Path::new("src/bridge.rs"),
+            "crate",
+            &formatting(),
+            &TypeRegistry::default(),
+        )
+        .unwrap_or_else(|_| panic!("Failed to generate the bridge"))
+    }
+
+    #[test]
+    fn test_write_dump() {
+        let dir = test_dir("write_dump");
+        let generated_code = generate_test_bridge(&dir);
+
+        let dump_dir = dir.join("dump");
+        generated_code.write_dump(&dump_dir);
+        // The files are named after the path of the bridge
+        for extension in ["rs", "cxxqt.h", "cxxqt.cpp", "cxx.h", "cxx.cpp"] {
+            assert!(dump_dir
+                .join("src")
+                .join(format!("bridge.{extension}"))
+                .is_file());
+        }
+        assert!(
+            std::fs::read_to_string(dump_dir.join("src").join("bridge.cxxqt.h"))
+                .unwrap()
+                .contains("class MyObject")
+        );
+        assert!(
+            std::fs::read_to_string(dump_dir.join("src").join("bridge.rs"))
+                .unwrap()
+                .contains("MyObjectRust")
+        );
+    }
+
+    #[test]
+    fn test_format_rust() {
+        // The code is formatted if rustfmt is available, otherwise it is unchanged
+        let formatted = format_rust("fn main(){let x=1;}");
+        assert!(
+            formatted == "fn main() {\n    let x = 1;\n}\n" || formatted == "fn main(){let x=1;}"
+        );
+        // Invalid code is returned unchanged
+        assert_eq!(format_rust("fn main() {"), "fn main() {");
+    }
+
+    #[test]
+    fn test_dump_dir() {
+        let (_lock, dir) = test_out_dir("dump_dir");
+        env::remove_var("CXX_QT_DUMP_DIR");
+        assert_eq!(CxxQtBuilder::new().dump_dir(), None);
+
+        env::set_var("CXX_QT_DUMP_DIR", dir.join("env"));
+        assert_eq!(CxxQtBuilder::new().dump_dir(), Some(dir.join("env")));
+        // The directory of the builder takes precedence
+        assert_eq!(
+            CxxQtBuilder::new()
+                .dump_generated_code(dir.join("builder"))
+                .dump_dir(),
+            Some(dir.join("builder"))
+        );
+        env::remove_var("CXX_QT_DUMP_DIR");
+    }
+
+    #[test]
+    fn test_write_rust() {
+        let dir = test_dir("write_rust");
+        let generated_code = generate_test_bridge(&dir);
+
+        // include_bridge! includes the code from the path of the bridge
+        let rust_path = generated_code.write_rust(dir.join("rust"));
+        assert_eq!(rust_path, dir.join("rust").join("src").join("bridge.rs"));
+        let rust = std::fs::read_to_string(&rust_path).unwrap();
+        assert_eq!(rust, generated_code.rust);
+        assert!(rust.parse::<proc_macro2::TokenStream>().is_ok());
+
+        // Unchanged code is not written again, so that the crate is not rebuilt
+        let modified = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1000);
+        std::fs::File::options()
+            .write(true)
+            .open(&rust_path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        generated_code.write_rust(dir.join("rust"));
+        assert_eq!(rust_path.metadata().unwrap().modified().unwrap(), modified);
+    }
+
+    #[test]
+    fn test_metadata_json() {
+        let dir = test_dir("metadata_json");
+        let generated_code = generate_test_bridge(&dir);
+        let metadata_path = generated_code.write_metadata(dir.join("metadata")).unwrap();
+        assert_eq!(
+            metadata_path,
+            dir.join("metadata").join("src").join("bridge.json")
+        );
+
+        let metadata_json = dir.join("cxx-qt-metadata.json");
+        let mut builder = CxxQtBuilder::new().metadata_json(&metadata_json);
+        builder.bridge_metadata.push(BridgeMetadataFile {
+            rust_file: PathBuf::from("src/bridge.rs"),
+            qml_uri: Some("com.kdab.demo".to_string()),
+            path: metadata_path,
+        });
+        builder.write_metadata_json();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(metadata_json).unwrap()).unwrap();
+        let bridge = &json["bridges"][0];
+        assert_eq!(bridge["rust_file"], "src/bridge.rs");
+        assert_eq!(bridge["qml_uri"], "com.kdab.demo");
+        assert_eq!(
+            bridge["metadata"]["qobjects"][0]["properties"][0]["name"],
+            "number"
+        );
+    }
+
+    #[test]
+    fn test_metadata_json_cxx_bridge() {
+        let dir = test_dir("metadata_json_cxx_bridge");
+        let rust_file = dir.join("bridge.rs");
+        std::fs::write(&rust_file, "#[cxx::bridge]\nmod ffi {}\n").unwrap();
+        let generated_code = GeneratedCpp::new(
+            &rust_file,
+            Path::new("src/bridge.rs"),
+            "crate",
+            &formatting(),
+            &TypeRegistry::default(),
+        )
+        .unwrap_or_else(|_| panic!("Failed to generate the bridge"));
+
+        // Plain CXX bridges have no QObjects to describe
+        assert_eq!(generated_code.write_metadata(dir.join("metadata")), None);
+    }
+
+    #[test]
+    fn test_write_qmake_pri_with_installed_headers() {
+        let (_lock, dir) = test_out_dir("write_qmake_pri_with_installed_headers");
+        env::set_var(
+            "OUT_DIR",
+            dir.join("target")
+                .join("debug")
+                .join("build")
+                .join("my-crate-1234")
+                .join("out"),
+        );
+        std::fs::create_dir_all(dir::crate_target()).unwrap();
+
+        // The .pri file is installed next to the qmake project, which uses the installed headers
+        let pri_install_path = dir.join("qmake_app").join("rust").join("my_crate.pri");
+        CxxQtBuilder::new()
+            .install_headers("include")
+            .install_qmake_pri(&pri_install_path)
+            .write_qmake_and_pkg_config_files(
+                &SemVer::new("6.5.0").unwrap(),
+                &BTreeSet::from(["Core".to_string()]),
+                &[],
+            );
+        assert!(!dir::crate_target().join("cxx-qt-build.pc").exists());
+        let pri = std::fs::read_to_string(pri_install_path).unwrap();
+        let include_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("include");
+        assert!(pri.contains(&format!("INCLUDEPATH += {}\n", include_dir.display())));
     }
 }
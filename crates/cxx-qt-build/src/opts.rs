@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use std::{
-    collections::HashSet,
+    collections::BTreeSet,
     path::{Path, PathBuf},
 };
 
@@ -14,11 +14,11 @@ use crate::MocArguments;
 #[derive(Default)]
 pub struct CxxQtBuildersOpts {
     /// Any extra definitions
-    pub(crate) defines: HashSet<String>,
+    pub(crate) defines: BTreeSet<String>,
     /// Contents, directory, file name
     pub(crate) headers: Vec<(String, String, String)>,
     /// Qt modules that are required
-    pub(crate) qt_modules: HashSet<String>,
+    pub(crate) qt_modules: BTreeSet<String>,
     /// Added initializer code required to be linked into a separate object file
     pub(crate) initializers: Vec<String>,
 }
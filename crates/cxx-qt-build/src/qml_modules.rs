@@ -7,6 +7,38 @@
 
 use std::path::{Path, PathBuf};
 
+/// How the QML plugin of a [QmlModule] is loaded by the QML engine
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QmlPluginType {
+    /// The plugin is linked into the application and registered at startup
+    /// with `Q_IMPORT_PLUGIN`. This is the default.
+    #[default]
+    Static,
+    /// The plugin is built as a loadable QQmlExtensionPlugin that the QML engine
+    /// finds via the `qmldir` file in an import path at runtime.
+    ///
+    /// cxx-qt-build compiles the plugin class without `QT_STATICPLUGIN` and exports a `qmldir`
+    /// (with a non-optional `plugin` line) and `plugin.qmltypes` into
+    /// `qml_modules/<module name>/qml/<URI as directories>` of the cxx-qt-build target directory,
+    /// where the module name is the URI with `.` replaced by `_`.
+    ///
+    /// Cargo cannot produce the shared library itself, as a `cdylib` only exports the symbols
+    /// of Rust code and not the `qt_plugin_instance` entry point of the plugin class. Instead the static library of the crate has to be linked into a
+    /// shared library by the C++ build system:
+    /// 1. Import the crate with `cxx_qt_import_crate` in CMake, with an explicit `CXX_QT_EXPORT_DIR`
+    /// 2. Create a `SHARED` library named after the module name
+    /// 3. Link the crate target into it with `$<LINK_LIBRARY:WHOLE_ARCHIVE,...>` (CMake 3.24),
+    ///    so that the plugin class is not discarded by the linker
+    /// 4. Place the shared library next to the exported `qmldir` and `plugin.qmltypes`
+    ///    and add the `qml` directory to the QML import path
+    ///
+    /// See the build systems page of the book for a complete CMake example.
+    ///
+    /// Only one dynamic QML module can be built per [crate::CxxQtBuilder], and it
+    /// cannot be combined with static QML modules.
+    Dynamic,
+}
+
 /// Metadata for registering a QML module with [crate::CxxQtBuilder::qml_module]
 pub struct QmlModule<'a, A, B>
 where
@@ -33,6 +65,16 @@ where
     // and an empty slice is likely desired in most cases; most users probably don't
     // care about this field.
     pub qrc_files: &'a [A],
+    /// Directories that are bundled into the module, similar to `qt_add_qml_module` in CMake
+    ///
+    /// All `.qml` and `.js` files found recursively are added to the `qml_files` and all other
+    /// files to the `qrc_files`. QML files starting with an uppercase letter are registered as
+    /// types of the module in the generated qmldir.
+    //
+    // Reuse the `A` generic for the same reason as qrc_files.
+    pub qml_dirs: &'a [A],
+    /// Whether the QML plugin is linked statically or loaded dynamically by the QML engine
+    pub plugin_type: QmlPluginType,
 }
 
 impl<'a, A, B> Default for QmlModule<'a, A, B>
@@ -48,10 +90,24 @@ where
             rust_files: &[],
             qml_files: &[],
             qrc_files: &[],
+            qml_dirs: &[],
+            plugin_type: QmlPluginType::Static,
         }
     }
 }
 
+/// Overrides of the URI and version of the QML modules of a [crate::CxxQtBuilder],
+/// see [crate::CxxQtBuilder::qml_module_override]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QmlModuleOverride {
+    /// Appended to the URI of the QML modules, e.g. `.dev` registers `com.kdab.demo` as `com.kdab.demo.dev`
+    pub uri_suffix: Option<String>,
+    /// Replaces the major version of the QML modules
+    pub version_major: Option<usize>,
+    /// Replaces the minor version of the QML modules
+    pub version_minor: Option<usize>,
+}
+
 /// Same as [QmlModule], but this struct owns the data instead of referencing it.
 /// This avoids needing to specify generics to instantiate a [crate::CxxQtBuilder], which
 /// contains a `Vec<OwningQmlModule>` member.
@@ -62,21 +118,121 @@ pub(crate) struct OwningQmlModule {
     pub rust_files: Vec<PathBuf>,
     pub qml_files: Vec<PathBuf>,
     pub qrc_files: Vec<PathBuf>,
+    pub qml_dirs: Vec<PathBuf>,
+    pub plugin_type: QmlPluginType,
 }
 
 fn collect_pathbuf_vec(asref: &[impl AsRef<Path>]) -> Vec<PathBuf> {
     asref.iter().map(|p| p.as_ref().to_path_buf()).collect()
 }
 
+/// Recursively collect all files in a directory, sorted to keep the generated files stable
+fn collect_dir_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let mut entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|_| panic!("Could not read QML directory {}", dir.display()))
+        .map(|entry| entry.expect("Could not read QML directory entry").path())
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_dir_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+impl OwningQmlModule {
+    /// Apply the URI suffix and versions of the override to the module
+    pub(crate) fn apply_override(&mut self, qml_override: &QmlModuleOverride) {
+        if let Some(uri_suffix) = &qml_override.uri_suffix {
+            self.uri.push_str(uri_suffix);
+        }
+        if let Some(version_major) = qml_override.version_major {
+            self.version_major = version_major;
+        }
+        if let Some(version_minor) = qml_override.version_minor {
+            self.version_minor = version_minor;
+        }
+    }
+}
+
 impl<A: AsRef<Path>, B: AsRef<Path>> From<QmlModule<'_, A, B>> for OwningQmlModule {
     fn from(other: QmlModule<'_, A, B>) -> Self {
+        let mut qml_files = collect_pathbuf_vec(other.qml_files);
+        let mut qrc_files = collect_pathbuf_vec(other.qrc_files);
+        let qml_dirs = collect_pathbuf_vec(other.qml_dirs);
+        for qml_dir in &qml_dirs {
+            let mut dir_files = Vec::new();
+            collect_dir_files(qml_dir, &mut dir_files);
+
+            for file in dir_files {
+                match file.extension().and_then(|extension| extension.to_str()) {
+                    Some("qml") | Some("js") | Some("mjs") => qml_files.push(file),
+                    // The qmldir is generated for the module
+                    _ if file.file_name().is_some_and(|name| name == "qmldir") => {}
+                    _ => qrc_files.push(file),
+                }
+            }
+        }
+
         OwningQmlModule {
             uri: other.uri.to_string(),
             version_major: other.version_major,
             version_minor: other.version_minor,
             rust_files: collect_pathbuf_vec(other.rust_files),
-            qml_files: collect_pathbuf_vec(other.qml_files),
-            qrc_files: collect_pathbuf_vec(other.qrc_files),
+            qml_files,
+            qrc_files,
+            qml_dirs,
+            plugin_type: other.plugin_type,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qml_dirs() {
+        let dir = std::env::temp_dir()
+            .join(format!("cxx-qt-build-tests-{}", std::process::id()))
+            .join("qml_dirs");
+        let _ = std::fs::remove_dir_all(&dir);
+        for file in [
+            "Main.qml",
+            "qmldir",
+            "components/Button.qml",
+            "components/helper.js",
+            "images/icon.png",
+        ] {
+            let path = dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+
+        let qml_module: OwningQmlModule = QmlModule {
+            qml_files: &["extra/Extra.qml"],
+            qml_dirs: std::slice::from_ref(&dir),
+            ..Default::default()
+        }
+        .into();
+
+        // The files are sorted and the qmldir is generated instead
+        assert_eq!(
+            qml_module.qml_files,
+            vec![
+                PathBuf::from("extra/Extra.qml"),
+                dir.join("Main.qml"),
+                dir.join("components").join("Button.qml"),
+                dir.join("components").join("helper.js"),
+            ]
+        );
+        assert_eq!(
+            qml_module.qrc_files,
+            vec![dir.join("images").join("icon.png")]
+        );
+        assert_eq!(qml_module.qml_dirs, vec![dir]);
+    }
+}
@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A report of the size and build time of the code generated for each bridge,
+//! see [crate::CxxQtBuilder::build_report]
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{dir, format_rust, GeneratedCppFilePaths};
+
+/// The measurements of the code generated for a bridge
+pub(crate) struct BridgeReport {
+    /// The path of the Rust file of the bridge
+    rust_file: PathBuf,
+    /// The generated C++ sources, which are compiled
+    cpp_sources: Vec<PathBuf>,
+    /// The generated QObject headers, which are moc'd
+    cpp_headers: Vec<PathBuf>,
+    /// The generated Rust code of the bridge
+    rust: PathBuf,
+    /// How long moc took for the headers of the bridge, zero if they were up to date
+    moc_time: Duration,
+    /// How long compiling the C++ sources of the bridge took
+    compile_time: Duration,
+}
+
+impl BridgeReport {
+    pub(crate) fn new(rust_file: &Path, files: &GeneratedCppFilePaths) -> Self {
+        Self {
+            rust_file: rust_file.to_path_buf(),
+            cpp_sources: std::iter::once(&files.plain_cpp)
+                .chain(&files.qobject)
+                .cloned()
+                .collect(),
+            cpp_headers: files
+                .qobject_header
+                .iter()
+                .chain(&files.qobject_class_headers)
+                .cloned()
+                .collect(),
+            rust: files.rust.clone(),
+            moc_time: Duration::ZERO,
+            compile_time: Duration::ZERO,
+        }
+    }
+}
+
+/// Add the time moc took for a header to the report of the bridge which generated it
+pub(crate) fn record_moc_time(reports: &mut [BridgeReport], header: &Path, duration: Duration) {
+    if let Some(report) = reports
+        .iter_mut()
+        .find(|report| report.cpp_headers.iter().any(|path| path == header))
+    {
+        report.moc_time += duration;
+    }
+}
+
+/// Measure how long compiling the C++ sources of each bridge takes
+///
+/// The sources are compiled with a copy of the given builder into a separate directory,
+/// in addition to compiling them into the library of the crate.
+pub(crate) fn measure_compile_times(reports: &mut [BridgeReport], builder: &cc::Build) {
+    if reports.is_empty() {
+        return;
+    }
+    let out_dir = dir::out().join("cxx-qt-build").join("report");
+    std::fs::create_dir_all(&out_dir).expect("Could not create build report directory");
+
+    for report in reports {
+        let mut report_builder = builder.clone();
+        report_builder.out_dir(&out_dir).files(&report.cpp_sources);
+
+        let start = Instant::now();
+        report_builder.compile_intermediates();
+        report.compile_time = start.elapsed();
+    }
+}
+
+/// The number of lines of a file, or zero if it can't be read
+fn count_lines(path: &Path) -> usize {
+    std::fs::read_to_string(path)
+        .map(|contents| contents.lines().count())
+        .unwrap_or_default()
+}
+
+/// Print the reports as Cargo warnings, starting with the bridge which takes the longest to build
+pub(crate) fn print(reports: &mut [BridgeReport]) {
+    if reports.is_empty() {
+        return;
+    }
+    reports.sort_by_key(|report| std::cmp::Reverse(report.moc_time + report.compile_time));
+
+    println!("cargo::warning=CXX-Qt build report, slowest bridges first:");
+    for report in reports.iter() {
+        let cpp_lines: usize = report
+            .cpp_sources
+            .iter()
+            .chain(&report.cpp_headers)
+            .map(|path| count_lines(path))
+            .sum();
+        // The generated Rust code is a single line, so count the lines of the formatted code
+        let rust_lines = std::fs::read_to_string(&report.rust)
+            .map(|rust| format_rust(&rust).lines().count())
+            .unwrap_or_default();
+        println!(
+            "cargo::warning={}: {cpp_lines} lines of C++, {rust_lines} lines of Rust, moc {:.2}s, compile {:.2}s",
+            report.rust_file.display(),
+            report.moc_time.as_secs_f64(),
+            report.compile_time.as_secs_f64(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(name: &str) -> BridgeReport {
+        BridgeReport::new(
+            Path::new(&format!("src/{name}.rs")),
+            &GeneratedCppFilePaths {
+                plain_cpp: PathBuf::from(format!("{name}.cxx.cpp")),
+                qobject: Some(PathBuf::from(format!("{name}.cxxqt.cpp"))),
+                qobject_header: Some(PathBuf::from(format!("{name}.cxxqt.h"))),
+                qobject_class_headers: vec![PathBuf::from(format!("{name}/MyObject.h"))],
+                metadata: None,
+                rust: PathBuf::from(format!("{name}.rs")),
+            },
+        )
+    }
+
+    #[test]
+    fn test_bridge_report() {
+        let report = report("lib");
+        assert_eq!(report.rust_file, PathBuf::from("src/lib.rs"));
+        assert_eq!(
+            report.cpp_sources,
+            vec![PathBuf::from("lib.cxx.cpp"), PathBuf::from("lib.cxxqt.cpp")]
+        );
+        assert_eq!(
+            report.cpp_headers,
+            vec![
+                PathBuf::from("lib.cxxqt.h"),
+                PathBuf::from("lib/MyObject.h")
+            ]
+        );
+        assert_eq!(report.moc_time, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_record_moc_time() {
+        let mut reports = vec![report("first"), report("second")];
+        record_moc_time(
+            &mut reports,
+            Path::new("second.cxxqt.h"),
+            Duration::from_millis(10),
+        );
+        record_moc_time(
+            &mut reports,
+            Path::new("second/MyObject.h"),
+            Duration::from_millis(5),
+        );
+        // Headers which are not generated by a bridge are ignored
+        record_moc_time(
+            &mut reports,
+            Path::new("other.h"),
+            Duration::from_millis(100),
+        );
+
+        assert_eq!(reports[0].moc_time, Duration::ZERO);
+        assert_eq!(reports[1].moc_time, Duration::from_millis(15));
+    }
+
+    #[test]
+    fn test_count_lines() {
+        let path = std::env::temp_dir().join(format!(
+            "cxx-qt-build-report-count-lines-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "first\nsecond\nthird\n").unwrap();
+        assert_eq!(count_lines(&path), 3);
+        std::fs::remove_file(&path).unwrap();
+
+        // Missing files are counted as empty
+        assert_eq!(count_lines(&path), 0);
+    }
+
+    #[test]
+    fn test_print_sorts_slowest_first() {
+        let mut reports = vec![report("fast"), report("slow"), report("medium")];
+        reports[0].compile_time = Duration::from_millis(10);
+        reports[1].moc_time = Duration::from_millis(20);
+        reports[1].compile_time = Duration::from_millis(30);
+        reports[2].compile_time = Duration::from_millis(40);
+
+        print(&mut reports);
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.rust_file.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PathBuf::from("src/slow.rs"),
+                PathBuf::from("src/medium.rs"),
+                PathBuf::from("src/fast.rs"),
+            ]
+        );
+    }
+}
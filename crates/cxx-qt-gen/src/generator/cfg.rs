@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::{expr::expr_to_string, path::path_compare_str};
+use syn::{punctuated::Punctuated, Attribute, Error, Meta, Result, Token};
+
+/// The result of evaluating a single cfg, such as `target_os = "android"` or `feature = "extra"`
+#[derive(Debug, PartialEq, Eq)]
+pub enum CfgResult {
+    /// The cfg is enabled
+    True,
+    /// The cfg is disabled
+    False,
+    /// The cfg can't be evaluated, the message explains why
+    Undetermined {
+        /// The reason the cfg can't be evaluated
+        msg: String,
+    },
+}
+
+impl From<bool> for CfgResult {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// Evaluates the cfgs of a bridge for the target that the C++ code is generated for
+///
+/// The Rust code of items with a `#[cfg(...)]` is evaluated by the compiler,
+/// but the C++ code is generated only for the items whose cfgs are enabled.
+/// A build script, like cxx-qt-build, evaluates the cfgs from the environment variables that Cargo sets.
+pub trait CfgEvaluator {
+    /// Evaluate a cfg with the given name, and the value for cfgs like `name = "value"`
+    fn eval(&self, name: &str, query_value: Option<&str>) -> CfgResult;
+}
+
+/// A [CfgEvaluator] which can't evaluate any cfg, so bridges which use cfgs fail to generate C++ code
+pub struct UnsupportedCfgEvaluator;
+
+impl CfgEvaluator for UnsupportedCfgEvaluator {
+    fn eval(&self, _name: &str, _query_value: Option<&str>) -> CfgResult {
+        CfgResult::Undetermined {
+            msg: "cfg attributes in a bridge require a CfgEvaluator to generate C++, eg the one of cxx-qt-build".to_owned(),
+        }
+    }
+}
+
+/// Returns true if all of the `#[cfg(...)]` attributes are enabled
+pub(crate) fn try_eval_attributes(
+    cfg_evaluator: &dyn CfgEvaluator,
+    attrs: &[Attribute],
+) -> Result<bool> {
+    for attr in attrs
+        .iter()
+        .filter(|attr| path_compare_str(attr.path(), &["cfg"]))
+    {
+        if !try_eval_predicate(cfg_evaluator, &attr.parse_args()?)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Evaluate a cfg predicate, eg `all(unix, not(feature = "extra"))`
+fn try_eval_predicate(cfg_evaluator: &dyn CfgEvaluator, predicate: &Meta) -> Result<bool> {
+    let (name, query_value) = match predicate {
+        Meta::Path(path) => (path.require_ident()?, None),
+        Meta::NameValue(name_value) => (
+            name_value.path.require_ident()?,
+            Some(expr_to_string(&name_value.value)?),
+        ),
+        Meta::List(list) => {
+            let nested = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let mut results = nested
+                .iter()
+                .map(|predicate| try_eval_predicate(cfg_evaluator, predicate));
+            return if list.path.is_ident("all") {
+                results.try_fold(true, |all, result| Ok(all && result?))
+            } else if list.path.is_ident("any") {
+                results.try_fold(false, |any, result| Ok(any || result?))
+            } else if list.path.is_ident("not") && nested.len() == 1 {
+                results.next().unwrap_or(Ok(false)).map(|result| !result)
+            } else {
+                Err(Error::new_spanned(
+                    list,
+                    "Expected a cfg predicate like all(...), any(...) or not(...)",
+                ))
+            };
+        }
+    };
+
+    match cfg_evaluator.eval(&name.to_string(), query_value.as_deref()) {
+        CfgResult::True => Ok(true),
+        CfgResult::False => Ok(false),
+        CfgResult::Undetermined { msg } => Err(Error::new_spanned(predicate, msg)),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    /// A [CfgEvaluator] for tests, where only `unix` and `feature = "extra"` are enabled
+    pub struct MockCfgEvaluator;
+
+    impl CfgEvaluator for MockCfgEvaluator {
+        fn eval(&self, name: &str, query_value: Option<&str>) -> CfgResult {
+            match (name, query_value) {
+                ("unix", None) | ("feature", Some("extra")) => CfgResult::True,
+                ("test", None) => CfgResult::Undetermined {
+                    msg: "cfg(test) is not supported".to_owned(),
+                },
+                _ => CfgResult::False,
+            }
+        }
+    }
+
+    fn eval(attrs: &[Attribute]) -> Result<bool> {
+        try_eval_attributes(&MockCfgEvaluator, attrs)
+    }
+
+    #[test]
+    fn test_try_eval_attributes() {
+        assert!(eval(&[]).unwrap());
+        assert!(eval(&[parse_quote! { #[doc = "not a cfg"] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(unix)] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(windows)] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(feature = "extra")] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(feature = "other")] }]).unwrap());
+        assert!(!eval(&[
+            parse_quote! { #[cfg(unix)] },
+            parse_quote! { #[cfg(windows)] }
+        ])
+        .unwrap());
+    }
+
+    #[test]
+    fn test_try_eval_predicates() {
+        assert!(eval(&[parse_quote! { #[cfg(all(unix, feature = "extra"))] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(all(unix, windows))] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(any(windows, unix))] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(any(windows, target_os = "android"))] }]).unwrap());
+        assert!(eval(&[parse_quote! { #[cfg(not(windows))] }]).unwrap());
+        assert!(!eval(&[parse_quote! { #[cfg(not(any(unix, windows)))] }]).unwrap());
+    }
+
+    #[test]
+    fn test_try_eval_invalid() {
+        assert!(eval(&[parse_quote! { #[cfg(test)] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(not(unix, windows))] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(unknown(unix))] }]).is_err());
+        assert!(eval(&[parse_quote! { #[cfg(feature = 1)] }]).is_err());
+        assert!(
+            try_eval_attributes(&UnsupportedCfgEvaluator, &[parse_quote! { #[cfg(unix)] }])
+                .is_err()
+        );
+    }
+}
@@ -16,7 +16,13 @@ fn default_constructor(
     qobject: &GeneratedCppQObject,
     base_class: String,
     initializers: String,
+    connections: &[String],
 ) -> GeneratedCppQObjectBlocks {
+    let body = if connections.is_empty() {
+        "{ }".to_owned()
+    } else {
+        format!("{{\n  {}\n}}", connections.join("\n  "))
+    };
     let class_name = qobject.name.cxx_unqualified();
     let rust_obj = qobject.rust_struct.cxx_qualified();
     let constructor = if qobject.has_qobject_macro {
@@ -27,7 +33,7 @@ fn default_constructor(
             {class_name}::{class_name}(QObject* parent)
               : {base_class}(parent)
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 namespace_internals = qobject.namespace_internals,
             ),
@@ -40,7 +46,7 @@ fn default_constructor(
             {class_name}::{class_name}()
               {base_class_line}
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 base_class_line = if base_class.is_empty() {
                     // CODECOV_EXCLUDE_START
@@ -84,6 +90,7 @@ pub fn generate(
     constructors: &[&Constructor],
     base_class: String,
     class_initializers: &[String],
+    connections: &[String],
     type_names: &TypeNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let initializers = class_initializers
@@ -93,7 +100,12 @@ pub fn generate(
         .join("");
 
     if constructors.is_empty() {
-        return Ok(default_constructor(qobject, base_class, initializers));
+        return Ok(default_constructor(
+            qobject,
+            base_class,
+            initializers,
+            connections,
+        ));
     }
 
     let mut generated = GeneratedCppQObjectBlocks::default();
@@ -101,6 +113,10 @@ pub fn generate(
     let class_name = qobject.name.cxx_unqualified();
     let rust_obj = qobject.rust_struct.cxx_qualified();
     let namespace_internals = &qobject.namespace_internals;
+    let connections = connections
+        .iter()
+        .map(|connection| format!("{connection}\n  "))
+        .collect::<String>();
     for (index, constructor) in constructors.iter().enumerate() {
         let argument_list = expand_arguments(&constructor.arguments, type_names)?;
         let constructor_argument_names = argument_names(&constructor.arguments);
@@ -143,7 +159,7 @@ pub fn generate(
                   : {base_class}({base_args})
                   , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::newRs{index}(::std::move(args.new_))){initializers}
                 {{
-                  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
+                  {connections}::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
                 }}
                 "#,
             },
@@ -173,6 +189,7 @@ mod tests {
             namespace_internals: "rust".to_string(),
             blocks: GeneratedCppQObjectBlocks::default(),
             has_qobject_macro: true,
+            cxx_file_stem: None,
         }
     }
 
@@ -200,6 +217,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &["member1(1)".to_string(), "member2{ 2 }".to_string()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -230,6 +248,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -252,6 +271,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_constructor_with_connections() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "BaseClass".to_owned(),
+            &[],
+            &["connect(a);".to_string(), "connect(b);".to_string()],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : BaseClass(parent)
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      connect(a);
+                      connect(b);
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
     #[test]
     fn default_constructor_no_qobject_macro() {
         let mut qobject = qobject_for_testing();
@@ -261,6 +311,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -293,6 +344,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -343,6 +395,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &["initializer".to_string()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -397,6 +450,7 @@ mod tests {
             ],
             "BaseClass".to_owned(),
             &["initializer".to_string()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
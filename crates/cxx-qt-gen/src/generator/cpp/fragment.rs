@@ -19,6 +19,29 @@ pub enum CppFragment {
     Source(String),
 }
 
+impl CppFragment {
+    /// Only compile the fragment when the given preprocessor condition is true
+    pub fn guarded(self, condition: &str) -> Self {
+        match self {
+            CppFragment::Pair { header, source } => CppFragment::Pair {
+                header: guard_cpp(&header, condition),
+                source: guard_cpp(&source, condition),
+            },
+            CppFragment::Header(header) => CppFragment::Header(guard_cpp(&header, condition)),
+            CppFragment::Source(source) => CppFragment::Source(guard_cpp(&source, condition)),
+        }
+    }
+}
+
+/// Wrap the given C++ code in an `#if` block with the given preprocessor condition
+pub fn guard_cpp(code: &str, condition: &str) -> String {
+    if code.is_empty() {
+        String::new()
+    } else {
+        format!("#if {condition}\n{code}\n#endif")
+    }
+}
+
 impl Default for CppFragment {
     fn default() -> Self {
         CppFragment::Pair {
@@ -47,4 +70,28 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn test_guarded() {
+        let condition = "QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)";
+        assert_eq!(
+            CppFragment::Pair {
+                header: "void f();".to_owned(),
+                source: "void f() {}".to_owned(),
+            }
+            .guarded(condition),
+            CppFragment::Pair {
+                header: format!("#if {condition}\nvoid f();\n#endif"),
+                source: format!("#if {condition}\nvoid f() {{}}\n#endif"),
+            }
+        );
+        assert_eq!(
+            CppFragment::Header("void f();".to_owned()).guarded(condition),
+            CppFragment::Header(format!("#if {condition}\nvoid f();\n#endif"))
+        );
+        assert_eq!(
+            CppFragment::Source(String::new()).guarded(condition),
+            CppFragment::Source(String::new())
+        );
+    }
 }
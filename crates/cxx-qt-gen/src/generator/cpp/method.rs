@@ -13,6 +13,7 @@ use crate::{
     naming::TypeNames,
     parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
 };
+use indoc::formatdoc;
 use syn::Result;
 
 pub fn generate_cpp_methods(
@@ -63,10 +64,56 @@ pub fn generate_cpp_methods(
         //
         // CXX generates the source and we just need the matching header.
         let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
-        generated.methods.push(CppFragment::Header(format!(
-            "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};",
-            ident = invokable.name.cxx_unqualified(),
-        )));
+        let ident = invokable.name.cxx_unqualified();
+        let guarded = |fragment: CppFragment| {
+            if let Some(since) = &invokable.since {
+                fragment.guarded(&since.cpp_condition())
+            } else {
+                fragment
+            }
+        };
+
+        if let Some(ownership) = &invokable.ownership {
+            // The Rust implementation is a private method, and the Q_INVOKABLE sets the ownership
+            // of the returned object before QML receives it
+            let implementation_ident = invokable.cxx_ident_of_implementation();
+            generated.private_methods.push(guarded(CppFragment::Header(format!(
+                "{return_cxx_ty} {implementation_ident}({parameter_types}){is_const} {has_noexcept};"
+            ))));
+
+            let qobject_ident = type_names
+                .lookup(&invokable.qobject_ident)?
+                .cxx_unqualified();
+            let arguments = parameters
+                .iter()
+                .map(|parameter| format!("::std::move({ident})", ident = parameter.ident))
+                .collect::<Vec<String>>()
+                .join(", ");
+            generated.methods.push(guarded(CppFragment::Pair {
+                header: format!(
+                    "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};"
+                ),
+                source: formatdoc! {
+                    r#"
+                    {return_cxx_ty}
+                    {qobject_ident}::{ident}({parameter_types}){is_const} {has_noexcept}
+                    {{
+                      auto object = {implementation_ident}({arguments});
+                      QQmlEngine::setObjectOwnership(object, {ownership});
+                      return object;
+                    }}
+                    "#,
+                    ownership = ownership.as_cpp_str(),
+                },
+            }));
+            generated
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+        } else {
+            generated.methods.push(guarded(CppFragment::Header(format!(
+                "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};"
+            ))));
+        }
     }
 
     Ok(generated)
@@ -76,7 +123,9 @@ pub fn generate_cpp_methods(
 mod tests {
     use super::*;
 
-    use crate::generator::cpp::property::tests::require_header;
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
+    use crate::syntax::safety::Safety;
+    use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use std::collections::HashSet;
     use syn::{parse_quote, ForeignItemFn};
@@ -167,4 +216,87 @@ mod tests {
         // private methods
         assert_eq!(generated.private_methods.len(), 0);
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.4")]
+            fn new_invokable(self: &MyObject);
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+
+        let generated =
+            generate_cpp_methods(&invokables.iter().collect(), &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)
+            Q_INVOKABLE void newInvokable() const noexcept;
+            #endif"#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_worker() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable(worker = set_result)]
+            fn calculate(self: &MyObject, input: i32) -> i32;
+        };
+        let invokables = [ParsedMethod::parse(method, Safety::Safe).unwrap()];
+
+        let generated =
+            generate_cpp_methods(&invokables.iter().collect(), &TypeNames::mock()).unwrap();
+
+        // The Q_INVOKABLE returns before the result is ready
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void calculate(::std::int32_t input) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_ownership() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[qml_ownership = "javascript"]
+            fn create_child(self: &MyObject, name: &QString) -> *mut MyObject;
+        };
+        let invokables = [ParsedMethod::parse(method, Safety::Safe).unwrap()];
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated = generate_cpp_methods(&invokables.iter().collect(), &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE MyObject* createChild(QString const& name) const noexcept;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObject*
+            MyObject::createChild(QString const& name) const noexcept
+            {
+              auto object = createChildCxxQtOwnership(::std::move(name));
+              QQmlEngine::setObjectOwnership(object, QQmlEngine::JavaScriptOwnership);
+              return object;
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "MyObject* createChildCxxQtOwnership(QString const& name) const noexcept;"
+        );
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+    }
 }
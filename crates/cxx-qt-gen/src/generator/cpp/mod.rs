@@ -9,10 +9,12 @@ pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
+pub mod newcppobject;
 pub mod property;
 pub mod qenum;
 pub mod qnamespace;
 pub mod qobject;
+pub mod sharedstruct;
 pub mod signal;
 pub mod threading;
 
@@ -20,7 +22,8 @@ mod utils;
 
 use std::collections::BTreeSet;
 
-use crate::generator::cpp::fragment::CppNamedType;
+use crate::generator::cfg::{CfgEvaluator, UnsupportedCfgEvaluator};
+use crate::generator::cpp::fragment::{CppFragment, CppNamedType};
 use crate::naming::cpp::syn_type_to_cpp_type;
 use crate::naming::TypeNames;
 use crate::{generator::structuring, parser::Parser};
@@ -38,12 +41,26 @@ pub struct GeneratedCppBlocks {
     pub qobjects: Vec<GeneratedCppQObject>,
     /// Generated extern C++Qt blocks
     pub extern_cxx_qt: Vec<GeneratedCppExternCxxQtBlocks>,
+    /// Fragments that aren't associated with any QObjects (e.g. QVariantMap conversions of shared structs)
+    pub fragments: Vec<CppFragment>,
 }
 
 impl GeneratedCppBlocks {
     /// Create a [GeneratedCppBlocks] from the given [Parser] object
+    ///
+    /// Bridges with `#[cfg(...)]` attributes need a [CfgEvaluator], see [GeneratedCppBlocks::from_with_cfg_evaluator].
     pub fn from(parser: &Parser) -> Result<GeneratedCppBlocks> {
-        let structures = structuring::Structures::new(&parser.cxx_qt_data)?;
+        Self::from_with_cfg_evaluator(parser, &UnsupportedCfgEvaluator)
+    }
+
+    /// Create a [GeneratedCppBlocks] from the given [Parser] object,
+    /// generating only the items whose `#[cfg(...)]` attributes are enabled by the [CfgEvaluator]
+    pub fn from_with_cfg_evaluator(
+        parser: &Parser,
+        cfg_evaluator: &dyn CfgEvaluator,
+    ) -> Result<GeneratedCppBlocks> {
+        let mut structures = structuring::Structures::new(&parser.cxx_qt_data)?;
+        structures.retain_enabled(cfg_evaluator)?;
 
         let mut includes = BTreeSet::new();
 
@@ -60,6 +77,14 @@ impl GeneratedCppBlocks {
                 .iter()
                 .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes)),
         );
+        let fragments = parser
+            .cxx_qt_data
+            .shared_structs
+            .iter()
+            .map(|shared_struct| {
+                sharedstruct::generate(shared_struct, &parser.type_names, &mut includes)
+            })
+            .collect::<Result<Vec<_>>>()?;
         Ok(GeneratedCppBlocks {
             forward_declares,
             includes,
@@ -72,6 +97,7 @@ impl GeneratedCppBlocks {
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
                 &parser.type_names,
             )?,
+            fragments,
         })
     }
 }
@@ -109,6 +135,7 @@ pub fn get_cpp_params(method: &ForeignItemFn, type_names: &TypeNames) -> Result<
 mod tests {
     use super::*;
 
+    use crate::generator::cfg::tests::MockCfgEvaluator;
     use crate::parser::Parser;
     use syn::{parse_quote, ItemMod};
 
@@ -130,6 +157,96 @@ mod tests {
         assert_eq!(cpp.qobjects[0].name.namespace(), None);
     }
 
+    #[test]
+    fn test_generated_cpp_blocks_type_paths() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(cxx_qt_lib::QString, title)]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn label(self: &MyObject, color: &cxx_qt_lib::QColor) -> cxx_qt_lib::QString;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let has_header = |expected: &str| {
+            cpp.qobjects[0].blocks.methods.iter().any(|method| {
+                matches!(
+                    method,
+                    CppFragment::Pair { header, .. } | CppFragment::Header(header)
+                        if header.contains(expected)
+                )
+            })
+        };
+        assert!(has_header("QString const& getTitle() const noexcept;"));
+        assert!(has_header(
+            "Q_INVOKABLE QString label(QColor const& color) const noexcept;"
+        ));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_cfgs() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    #[cfg_attr(windows, qproperty(i32, handle))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    #[cfg(windows)]
+                    type WindowsObject = super::WindowsObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    #[cfg(feature = "extra")]
+                    fn extra(self: &MyObject);
+
+                    #[qinvokable]
+                    #[cfg(not(unix))]
+                    fn other(self: &MyObject);
+
+                    #[qsignal]
+                    #[cfg(windows)]
+                    fn handle_changed(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        // Without an evaluator the cfgs can't be evaluated
+        assert!(GeneratedCppBlocks::from(&parser).is_err());
+
+        let cpp = GeneratedCppBlocks::from_with_cfg_evaluator(&parser, &MockCfgEvaluator).unwrap();
+        assert_eq!(cpp.qobjects.len(), 1);
+        let has_header = |expected: &str| {
+            cpp.qobjects[0].blocks.methods.iter().any(|method| {
+                matches!(
+                    method,
+                    CppFragment::Pair { header, .. } | CppFragment::Header(header)
+                        if header.contains(expected)
+                )
+            })
+        };
+        assert!(has_header("getNumber"));
+        assert!(!has_header("getHandle"));
+        assert!(has_header("Q_INVOKABLE void extra() const"));
+        assert!(!has_header("other"));
+        assert_eq!(cpp.qobjects[0].blocks.metaobjects.len(), 1);
+        assert!(!has_header("handleChanged"));
+    }
+
     #[test]
     fn test_generated_cpp_blocks_namespace() {
         let module: ItemMod = parse_quote! {
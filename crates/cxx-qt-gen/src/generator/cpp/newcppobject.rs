@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectNames,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    namespace_internals: &str,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = qobject_idents.name.cxx_qualified();
+
+    result.fragments.push(CppFragment::Pair {
+        header: formatdoc! {
+            r#"
+            namespace {namespace_internals} {{
+            ::std::unique_ptr<{cpp_class}>
+            newCppObject();
+            }} // namespace {namespace_internals}
+            "#
+        },
+        source: formatdoc! {
+            r#"
+            namespace {namespace_internals} {{
+            ::std::unique_ptr<{cpp_class}>
+            newCppObject()
+            {{
+              return ::std::make_unique<{cpp_class}>();
+            }}
+            }} // namespace {namespace_internals}
+            "#
+        },
+    });
+
+    result.includes.insert("#include <memory>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_pair;
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_new_cpp_object() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents, "cxx_qt_my_object").unwrap();
+
+        assert_eq!(generated.fragments.len(), 1);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace cxx_qt_my_object {
+            ::std::unique_ptr<MyObject>
+            newCppObject();
+            } // namespace cxx_qt_my_object
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace cxx_qt_my_object {
+            ::std::unique_ptr<MyObject>
+            newCppObject()
+            {
+              return ::std::make_unique<MyObject>();
+            }
+            } // namespace cxx_qt_my_object
+            "#}
+        );
+
+        assert!(generated.includes.contains("#include <memory>"));
+    }
+}
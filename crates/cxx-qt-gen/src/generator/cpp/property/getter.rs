@@ -8,10 +8,16 @@ use crate::generator::{
     naming::property::{NameState, QPropertyNames},
 };
 
-pub fn generate(idents: &QPropertyNames, return_cxx_ty: &str) -> Option<CppFragment> {
+pub fn generate(idents: &QPropertyNames, return_cxx_ty: &str, mapped: bool) -> Option<CppFragment> {
     if let NameState::Auto(name) = &idents.getter {
+        // A mapped property converts the Rust value, so it can only be returned by value
+        let return_cxx_ty = if mapped {
+            return_cxx_ty.to_owned()
+        } else {
+            format!("{return_cxx_ty} const&")
+        };
         Some(CppFragment::Header(format!(
-            "{return_cxx_ty} const& {ident_getter}() const noexcept;",
+            "{return_cxx_ty} {ident_getter}() const noexcept;",
             ident_getter = name.cxx_unqualified()
         )))
     } else {
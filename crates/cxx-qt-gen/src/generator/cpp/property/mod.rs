@@ -11,7 +11,7 @@ use crate::generator::{
 use crate::{
     naming::cpp::syn_type_to_cpp_type, naming::TypeNames, parser::property::ParsedQProperty,
 };
-use syn::Result;
+use syn::{Error, Result};
 
 mod getter;
 mod meta;
@@ -19,7 +19,7 @@ mod setter;
 mod signal;
 
 pub fn generate_cpp_properties(
-    properties: &Vec<ParsedQProperty>,
+    properties: &[&ParsedQProperty],
     qobject_idents: &QObjectNames,
     type_names: &TypeNames,
     structured_qobject: &StructuredQObject,
@@ -30,13 +30,13 @@ pub fn generate_cpp_properties(
     for property in properties {
         // Cache the idents as they are used in multiple places
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
-        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+        let cxx_ty = syn_type_to_cpp_type(property.cxx_ty(), type_names)?;
 
         generated
             .metaobjects
             .push(meta::generate(&idents, &property.flags, &cxx_ty));
 
-        if let Some(getter) = getter::generate(&idents, &cxx_ty) {
+        if let Some(getter) = getter::generate(&idents, &cxx_ty, property.mapping.is_some()) {
             generated.methods.push(getter);
         }
 
@@ -47,6 +47,16 @@ pub fn generate_cpp_properties(
         if let Some(notify) = signal::generate(&idents, &qobject_idents.name) {
             signals.push(notify)
         }
+
+        if let Some((method, member)) = property.flags.notify_throttle_ms.and_then(|interval| {
+            signal::generate_throttled(&idents, &qobject_idents.name, interval)
+        }) {
+            generated
+                .includes
+                .insert("#include <QtCore/QTimer>".to_owned());
+            generated.methods.push(method);
+            generated.private_methods.push(member);
+        }
     }
 
     generated.append(&mut generate_cpp_signals(
@@ -58,6 +68,57 @@ pub fn generate_cpp_properties(
     Ok(generated)
 }
 
+/// Generate the connections of computed properties, which forward the notify signal of each
+/// property in `depends_on` to the notify signal of the computed property in the constructor
+pub fn generate_cpp_property_dependencies(
+    properties: &[&ParsedQProperty],
+    structured_qobject: &StructuredQObject,
+) -> Result<Vec<String>> {
+    let class_name = structured_qobject.declaration.name.cxx_unqualified();
+
+    let mut connections = vec![];
+    for property in properties {
+        if property.flags.depends_on.is_empty() {
+            continue;
+        }
+
+        let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
+        // The parser ensures that a computed property has a notify signal
+        let notify_cpp = if let Some(notify) = &idents.notify {
+            notify.cxx_unqualified()
+        } else {
+            continue;
+        };
+
+        for dependency_ident in &property.flags.depends_on {
+            let dependency = properties
+                .iter()
+                .find(|dependency| dependency.name.rust_unqualified() == dependency_ident)
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        dependency_ident,
+                        "Unknown property in depends_on, it must be a property of the same QObject!",
+                    )
+                })?;
+            let dependency_idents =
+                QPropertyNames::try_from_property(dependency, structured_qobject)?;
+            let dependency_notify = dependency_idents.notify.as_ref().ok_or_else(|| {
+                Error::new_spanned(
+                    dependency_ident,
+                    "A property in depends_on must have a NOTIFY signal!",
+                )
+            })?;
+
+            connections.push(format!(
+                "::QObject::connect(this, &{class_name}::{dependency_notify}, this, &{class_name}::{notify_cpp});",
+                dependency_notify = dependency_notify.cxx_unqualified(),
+            ));
+        }
+    }
+
+    Ok(connections)
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -97,7 +158,7 @@ pub mod tests {
     fn setup_generated(input: &mut ItemStruct) -> Result<GeneratedCppQObjectBlocks> {
         let property = ParsedQProperty::parse(&input.attrs.remove(0))?;
 
-        let properties = vec![property];
+        let properties = vec![&property];
 
         let qobject_idents = create_qobjectname();
 
@@ -159,7 +220,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = vec![&property];
 
         let qobject_idents = create_qobjectname();
 
@@ -197,7 +258,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = vec![&property];
 
         let qobject_idents = create_qobjectname();
 
@@ -238,7 +299,7 @@ pub mod tests {
 
         let property = mock_property(input);
 
-        let properties = vec![property];
+        let properties = vec![&property];
 
         let qobject_idents = create_qobjectname();
 
@@ -278,6 +339,145 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_map_with() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(Decimal, amount, cxx_type = "i32", map_with = "DecimalAsI32")]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t amount READ getAmount WRITE setAmount NOTIFY amountChanged)"
+        );
+
+        // The mapped value is returned by value
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "::std::int32_t getAmount() const noexcept;");
+
+        let header = require_header(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_SLOT void setAmount(::std::int32_t value) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_notify_throttle() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(f64, reading, notify_throttle_ms = 16)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert!(generated.includes.contains("#include <QtCore/QTimer>"));
+        // getter, setter, throttled helper and then the notify signal
+        assert_eq!(generated.methods.len(), 4);
+        let (header, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(header, "void readingChangedThrottled() noexcept;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::readingChangedThrottled() noexcept
+            {
+              if (m_readingChangedThrottledPending) {
+                return;
+              }
+
+              m_readingChangedThrottledPending = true;
+              ::QTimer::singleShot(16, this, [this]() {
+                m_readingChangedThrottledPending = false;
+                Q_EMIT readingChanged();
+              });
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = require_header(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(header, "bool m_readingChangedThrottledPending = false;");
+    }
+
+    #[test]
+    fn test_notify_deferred() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, count, notify_deferred)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        let (header, source) = require_pair(&generated.methods[2]).unwrap();
+        assert_str_eq!(header, "void countChangedThrottled() noexcept;");
+        assert!(source.contains("::QTimer::singleShot(0, this, [this]() {"));
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QString, first_name)]
+                    #[qproperty(QString, last_name)]
+                    #[qproperty(QString, full_name, READ = full_name, NOTIFY, depends_on(first_name, last_name))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[cxx_name = "getFullName"]
+                    fn full_name(self: &MyObject) -> QString;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let structured_qobject = structures.qobjects.first().unwrap();
+
+        let connections =
+            generate_cpp_property_dependencies(&structured_qobject.properties, structured_qobject)
+                .unwrap();
+        assert_eq!(
+            connections,
+            vec![
+                "::QObject::connect(this, &MyObject::firstNameChanged, this, &MyObject::fullNameChanged);",
+                "::QObject::connect(this, &MyObject::lastNameChanged, this, &MyObject::fullNameChanged);",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies_invalid() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QString, first_name, READ)]
+                    #[qproperty(QString, full_name, depends_on(first_name, middle_name))]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let structured_qobject = structures.qobjects.first().unwrap();
+
+        // first_name has no notify signal
+        assert!(generate_cpp_property_dependencies(
+            &structured_qobject.properties,
+            structured_qobject,
+        )
+        .is_err());
+
+        // first_name is not one of the given properties
+        assert!(generate_cpp_property_dependencies(
+            &structured_qobject.properties[1..],
+            structured_qobject,
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_generate_cpp_properties() {
         let input1: ItemStruct = parse_quote! {
@@ -290,7 +490,9 @@ pub mod tests {
             struct MyStruct;
         };
 
-        let properties = vec![mock_property(input1), mock_property(input2)];
+        let property1 = mock_property(input1);
+        let property2 = mock_property(input2);
+        let properties = vec![&property1, &property2];
 
         let qobject_idents = create_qobjectname();
 
@@ -468,11 +670,14 @@ pub mod tests {
 
     #[test]
     fn test_generate_cpp_properties_mapped_cxx_name() {
-        let properties = vec![ParsedQProperty {
+        let property = ParsedQProperty {
             name: property_name_from_rust_name(format_ident!("mapped_property")),
             ty: parse_quote! { A },
             flags: QPropertyFlags::default(),
-        }];
+            mapping: None,
+            cfgs: vec![],
+        };
+        let properties = vec![&property];
         let qobject_idents = create_qobjectname();
 
         let obj = ParsedQObject::mock();
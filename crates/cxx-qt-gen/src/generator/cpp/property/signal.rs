@@ -3,8 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use indoc::formatdoc;
 use syn::ForeignItemFn;
 
+use crate::generator::cpp::fragment::CppFragment;
 use crate::naming::Name;
 use crate::syntax::safety::Safety;
 use crate::{
@@ -30,3 +32,45 @@ pub fn generate(idents: &QPropertyNames, qobject_name: &Name) -> Option<ParsedSi
         None
     }
 }
+
+/// The helper which coalesces the notify signal of a property with `notify_throttle_ms`
+///
+/// Returns the public method and the private member which tracks a pending notify signal
+pub fn generate_throttled(
+    idents: &QPropertyNames,
+    qobject_name: &Name,
+    interval_ms: u32,
+) -> Option<(CppFragment, CppFragment)> {
+    let throttled = idents.notify_throttled.as_ref()?;
+    let notify = idents.notify.as_ref()?;
+
+    let throttled_cpp = throttled.cxx_unqualified();
+    let notify_cpp = notify.cxx_unqualified();
+    let pending = format!("m_{throttled_cpp}Pending");
+    // The source of methods is written within the namespace of the QObject
+    let qobject_ident = qobject_name.cxx_unqualified();
+
+    let method = CppFragment::Pair {
+        header: format!("void {throttled_cpp}() noexcept;"),
+        source: formatdoc! {
+            r#"
+            void
+            {qobject_ident}::{throttled_cpp}() noexcept
+            {{
+              if ({pending}) {{
+                return;
+              }}
+
+              {pending} = true;
+              ::QTimer::singleShot({interval_ms}, this, [this]() {{
+                {pending} = false;
+                Q_EMIT {notify_cpp}();
+              }});
+            }}
+            "#
+        },
+    };
+    let member = CppFragment::Header(format!("bool {pending} = false;"));
+
+    Some((method, member))
+}
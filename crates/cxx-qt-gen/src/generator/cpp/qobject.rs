@@ -6,8 +6,15 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
-            property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
+            constructor, cxxqttype,
+            fragment::CppFragment,
+            inherit,
+            method::generate_cpp_methods,
+            newcppobject,
+            property::{generate_cpp_properties, generate_cpp_property_dependencies},
+            qenum,
+            signal::{generate_cpp_signal_forwards, generate_cpp_signals},
+            threading,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         structuring::StructuredQObject,
@@ -93,6 +100,8 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// The file stem of a separate header for the class, if it is not in the header of the bridge
+    pub cxx_file_stem: Option<String>,
 }
 
 impl GeneratedCppQObject {
@@ -111,6 +120,7 @@ impl GeneratedCppQObject {
             namespace_internals: namespace_idents.internal,
             blocks: GeneratedCppQObjectBlocks::from(qobject),
             has_qobject_macro: qobject.has_qobject_macro,
+            cxx_file_stem: qobject.cxx_file_stem.clone(),
         };
 
         let base_class = if let Some(ident) = &qobject.base_class {
@@ -132,7 +142,7 @@ impl GeneratedCppQObject {
 
         // Generate methods for the properties, invokables, signals
         generated.blocks.append(&mut generate_cpp_properties(
-            &qobject.properties,
+            &structured_qobject.properties,
             &qobject_idents,
             type_names,
             structured_qobject,
@@ -167,11 +177,26 @@ impl GeneratedCppQObject {
             class_initializers.push(initializer);
         }
 
+        // If this type can be constructed from Rust then add generation
+        if structured_qobject.new_cpp_object {
+            generated.blocks.append(&mut newcppobject::generate(
+                &qobject_idents,
+                &generated.namespace_internals,
+            )?);
+        }
+
+        let mut connections = generate_cpp_signal_forwards(structured_qobject)?;
+        connections.append(&mut generate_cpp_property_dependencies(
+            &structured_qobject.properties,
+            structured_qobject,
+        )?);
+
         generated.blocks.append(&mut constructor::generate(
             &generated,
             &structured_qobject.constructors,
             base_class,
             &class_initializers,
+            &connections,
             type_names,
         )?);
 
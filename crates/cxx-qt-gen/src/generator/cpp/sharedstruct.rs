@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeSet;
+
+use indoc::formatdoc;
+use syn::Result;
+
+use crate::{
+    generator::cpp::{fragment::CppFragment, utils::Indent},
+    naming::{cpp::syn_type_to_cpp_type, TypeNames},
+    parser::sharedstruct::ParsedSharedStruct,
+};
+
+/// Declare the shared struct as a metatype and register converters to and from a QVariantMap,
+/// so that QML sees the struct as a JavaScript object with a property for each field
+pub fn generate(
+    shared_struct: &ParsedSharedStruct,
+    type_names: &TypeNames,
+    includes: &mut BTreeSet<String>,
+) -> Result<CppFragment> {
+    includes.insert("#include <QtCore/QMetaType>".to_owned());
+    includes.insert("#include <QtCore/QVariant>".to_owned());
+
+    let ty = shared_struct.name.cxx_qualified();
+    let ident = format!("register_{}", ty.replace("::", "_"));
+
+    let mut to_map = vec![];
+    let mut from_map = vec![];
+    for field in &shared_struct.fields {
+        let name = &field.cxx_name;
+        let field_ty = syn_type_to_cpp_type(&field.ty, type_names)?;
+        to_map.push(format!(
+            "map.insert(QStringLiteral(\"{name}\"), QVariant::fromValue(value.{name}));"
+        ));
+        from_map.push(format!(
+            "value.{name} = map.value(QStringLiteral(\"{name}\")).value<{field_ty}>();"
+        ));
+    }
+
+    Ok(CppFragment::Pair {
+        header: format!("Q_DECLARE_METATYPE({ty})"),
+        source: formatdoc! {r#"
+            static const bool {ident} = []() {{
+              qRegisterMetaType<{ty}>();
+              QMetaType::registerConverter<{ty}, QVariantMap>([]({ty} const& value) {{
+                QVariantMap map;
+            {to_map}
+                return map;
+              }});
+              QMetaType::registerConverter<QVariantMap, {ty}>([](QVariantMap const& map) {{
+                {ty} value{{}};
+            {from_map}
+                return value;
+              }});
+              return true;
+            }}();
+            "#,
+            to_map = to_map.join("\n").indented(4),
+            from_map = from_map.join("\n").indented(4),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_pair;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+    use syn::{parse_quote, ItemStruct};
+
+    #[test]
+    fn test_generate() {
+        let mut item: ItemStruct = parse_quote! {
+            #[qvariant_map]
+            struct Point {
+                x: i32,
+                #[cxx_name = "posY"]
+                y: f64,
+            }
+        };
+        let shared_struct =
+            ParsedSharedStruct::parse(&mut item, Some("geometry"), &format_ident!("ffi")).unwrap();
+        let mut includes = BTreeSet::new();
+        let (header, source) =
+            require_pair(&generate(&shared_struct, &TypeNames::mock(), &mut includes).unwrap())
+                .unwrap();
+
+        assert_eq!(includes.len(), 2);
+        assert_str_eq!(header, "Q_DECLARE_METATYPE(geometry::Point)");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                static const bool register_geometry_Point = []() {
+                  qRegisterMetaType<geometry::Point>();
+                  QMetaType::registerConverter<geometry::Point, QVariantMap>([](geometry::Point const& value) {
+                    QVariantMap map;
+                    map.insert(QStringLiteral("x"), QVariant::fromValue(value.x));
+                    map.insert(QStringLiteral("posY"), QVariant::fromValue(value.posY));
+                    return map;
+                  });
+                  QMetaType::registerConverter<QVariantMap, geometry::Point>([](QVariantMap const& map) {
+                    geometry::Point value{};
+                    value.x = map.value(QStringLiteral("x")).value<::std::int32_t>();
+                    value.posY = map.value(QStringLiteral("posY")).value<double>();
+                    return value;
+                  });
+                  return true;
+                }();
+            "#}
+        );
+    }
+}
@@ -5,18 +5,22 @@
 
 use crate::{
     generator::{
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{
+            fragment::{guard_cpp, CppFragment},
+            qobject::GeneratedCppQObjectBlocks,
+        },
         naming::{
             qobject::QObjectNames,
             signals::{QSignalHelperNames, QSignalNames},
         },
+        structuring::StructuredQObject,
     },
     naming::{cpp::syn_type_to_cpp_type, Name, TypeNames},
     parser::{parameter::ParsedFunctionParameter, signals::ParsedSignal},
 };
 use indoc::formatdoc;
 use std::collections::BTreeSet;
-use syn::Result;
+use syn::{Error, Result, Type};
 
 #[derive(Default)]
 pub struct CppSignalFragment {
@@ -42,6 +46,8 @@ struct Parameters {
     types_with_self: String,
     /// Raw ::std::move values of the parameters including self
     values_with_self: String,
+    /// Raw ::std::move values of the parameters
+    values: Vec<String>,
 }
 
 /// From given parameters, mappings, and self value constructor the combined parameter lines
@@ -56,13 +62,19 @@ fn parameter_types_and_values(
 
     for parameter in parameters {
         let cxx_ty = syn_type_to_cpp_type(&parameter.ty, type_names)?;
-        let ident_str = parameter.ident.to_string();
+        let ident_str = parameter.cxx_ident();
         parameter_named_types_with_self.push(format!("{cxx_ty} {ident_str}",));
         parameter_types_with_self.push(cxx_ty.clone());
-        parameter_values_with_self.push(format!("::std::move({ident_str})"));
+        // References are forwarded as they are, only owned values are moved into the handler
+        if matches!(parameter.ty, Type::Reference(_)) {
+            parameter_values_with_self.push(ident_str);
+        } else {
+            parameter_values_with_self.push(format!("::std::move({ident_str})"));
+        }
     }
 
     let parameter_named_types = parameter_named_types_with_self.join(", ");
+    let parameter_values = parameter_values_with_self.clone();
 
     // Insert the extra argument into the closure
     let self_ty = self_ty.cxx_qualified();
@@ -75,6 +87,7 @@ fn parameter_types_and_values(
         named_types_with_self: parameter_named_types_with_self.join(", "),
         types_with_self: parameter_types_with_self.join(", "),
         values_with_self: parameter_values_with_self.join(", "),
+        values: parameter_values,
     })
 }
 
@@ -125,7 +138,57 @@ pub fn generate_cpp_signal(
     });
 
     // Generate the Q_SIGNAL if this is not an existing signal
-    if !signal.inherit {
+    if !signal.inherit && signal.private {
+        // A private signal uses the QPrivateSignal pattern of Qt, so that it can only be emitted
+        // by the class itself or the emit function which is declared as a friend
+        let emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+        let emit_parameters = if parameters_named_types.is_empty() {
+            format!("{qobject_ident_namespaced}& self")
+        } else {
+            format!("{qobject_ident_namespaced}& self, {parameters_named_types}")
+        };
+        let private_signal_values = parameters
+            .values
+            .into_iter()
+            .chain(std::iter::once(format!(
+                "{qobject_ident_namespaced}::QPrivateSignal()"
+            )))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let private_signal_parameters = if parameters_named_types.is_empty() {
+            "QPrivateSignal".to_owned()
+        } else {
+            format!("{parameters_named_types}, QPrivateSignal")
+        };
+
+        generated.methods.push(CppFragment::Header(format!(
+            "Q_SIGNAL void {signal_ident}({private_signal_parameters});"
+        )));
+        generated.methods.push(CppFragment::Header(format!(
+            "friend void ::{namespace}::{emit_ident_cpp}({emit_parameters});"
+        )));
+        generated.fragments.push(CppFragment::Pair {
+            header: formatdoc! {
+                r#"
+                namespace {namespace} {{
+                void
+                {emit_ident_cpp}({emit_parameters});
+                }} // namespace {namespace}
+                "#
+            },
+            source: formatdoc! {
+                r#"
+                namespace {namespace} {{
+                void
+                {emit_ident_cpp}({emit_parameters})
+                {{
+                    Q_EMIT self.{signal_ident}({private_signal_values});
+                }}
+                }} // namespace {namespace}
+                "#
+            },
+        });
+    } else if !signal.inherit {
         generated.methods.push(CppFragment::Header(format!(
             "Q_SIGNAL void {signal_ident}({parameters_named_types});"
         )));
@@ -185,6 +248,26 @@ pub fn generate_cpp_signal(
         }
     });
 
+    // Only compile the signal with the Qt versions that it is available in
+    if let Some(since) = &signal.since {
+        let condition = since.cpp_condition();
+        generated.forward_declares = generated
+            .forward_declares
+            .iter()
+            .map(|forward_declare| guard_cpp(forward_declare, &condition))
+            .collect();
+        generated.fragments = generated
+            .fragments
+            .into_iter()
+            .map(|fragment| fragment.guarded(&condition))
+            .collect();
+        generated.methods = generated
+            .methods
+            .into_iter()
+            .map(|method| method.guarded(&condition))
+            .collect();
+    }
+
     Ok(generated)
 }
 
@@ -208,12 +291,65 @@ pub fn generate_cpp_signals(
     Ok(generated)
 }
 
+/// Generate the connections of the `#[forward_from(...)]` attributes of the signals and methods,
+/// which are made in the constructor of the QObject
+pub fn generate_cpp_signal_forwards(structured_qobject: &StructuredQObject) -> Result<Vec<String>> {
+    let class_name = structured_qobject.declaration.name.cxx_unqualified();
+    let targets = structured_qobject
+        .signals
+        .iter()
+        .map(|signal| (&signal.method_fields, Some(*signal)))
+        .chain(
+            structured_qobject
+                .methods
+                .iter()
+                .map(|method| (&method.method_fields, None)),
+        );
+
+    let mut connections = vec![];
+    for (target, target_signal) in targets {
+        if target.forward_from.is_empty() {
+            continue;
+        }
+        if target_signal.is_some_and(|signal| signal.private && !signal.inherit) {
+            return Err(Error::new_spanned(
+                &target.method.sig.ident,
+                "Signals cannot be forwarded to a private signal, as it can only be emitted from Rust",
+            ));
+        }
+
+        let target_ident = target.name.cxx_unqualified();
+        for source_ident in &target.forward_from {
+            let source_ident_cpp = structured_qobject.signal_lookup(source_ident)?;
+            let source_ident_cpp = source_ident_cpp.cxx_unqualified();
+            let source_since = structured_qobject
+                .signals
+                .iter()
+                .find(|signal| signal.name.rust_unqualified() == source_ident)
+                .and_then(|signal| signal.since.as_ref());
+
+            let mut connection = format!(
+                "::QObject::connect(this, &{class_name}::{source_ident_cpp}, this, &{class_name}::{target_ident});"
+            );
+            // Only connect the signals with the Qt versions that they are available in
+            for since in target.since.iter().chain(source_since) {
+                connection = guard_cpp(&connection, &since.cpp_condition());
+            }
+            connections.push(connection);
+        }
+    }
+
+    Ok(connections)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::generator::cpp::property::tests::{require_header, require_pair};
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::generator::structuring::Structures;
+    use crate::parser::Parser;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use syn::{parse_quote, ForeignItemFn};
@@ -600,4 +736,224 @@ mod tests {
             "#}
         );
     }
+
+    #[test]
+    fn test_generate_cpp_signals_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.4")]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)
+            Q_SIGNAL void ready();
+            #endif"#}
+        );
+
+        assert_eq!(generated.forward_declares_namespaced.len(), 1);
+        assert!(generated.forward_declares_namespaced[0]
+            .starts_with("#if QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)\n"));
+        assert!(generated.forward_declares_namespaced[0].ends_with("\n#endif"));
+
+        assert_eq!(generated.fragments.len(), 1);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        for code in [header, source] {
+            assert!(code.starts_with("#if QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)\n"));
+            assert!(code.ends_with("\n#endif"));
+        }
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_reference_parameter() {
+        let method: ForeignItemFn = parse_quote! {
+            fn text_changed(self: Pin<&mut MyObject>, text: &QString, count: i32);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
+
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void textChanged(QString const& text, ::std::int32_t count);"
+        );
+
+        // The reference is forwarded to the handler without moving it
+        let (_, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert!(source.contains(
+            "call_MyObject_signal_handler_textChanged(*this, self, text, ::std::move(count));"
+        ));
+        assert!(source.contains(">(self, text, ::std::move(count));"));
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_parameter_cxx_name() {
+        let method: ForeignItemFn = parse_quote! {
+            fn moved(self: Pin<&mut MyObject>, #[cxx_name = "posX"] pos_x: i32);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "Q_SIGNAL void moved(::std::int32_t posX);");
+
+        let (_, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert!(source.contains(">(self, ::std::move(posX));"));
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_private() {
+        let method: ForeignItemFn = parse_quote! {
+            pub(self) fn ready(self: Pin<&mut MyObject>, value: i32);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        assert_str_eq!(
+            require_header(&generated.methods[0]).unwrap(),
+            "Q_SIGNAL void ready(::std::int32_t value, QPrivateSignal);"
+        );
+        assert_str_eq!(
+            require_header(&generated.methods[1]).unwrap(),
+            "friend void ::rust::cxxqtgen1::MyObject_readyEmit(MyObject& self, ::std::int32_t value);"
+        );
+
+        assert_eq!(generated.fragments.len(), 2);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_readyEmit(MyObject& self, ::std::int32_t value);
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_readyEmit(MyObject& self, ::std::int32_t value)
+            {
+                Q_EMIT self.ready(::std::move(value), MyObject::QPrivateSignal());
+            }
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+    }
+
+    fn forwards_from_bridge(module: syn::ItemMod) -> Result<Vec<String>> {
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        generate_cpp_signal_forwards(structures.qobjects.first().unwrap())
+    }
+
+    #[test]
+    fn test_generate_cpp_signal_forwards() {
+        let connections = forwards_from_bridge(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    #[inherit]
+                    fn text_changed(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    #[forward_from(text_changed)]
+                    fn validated(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    #[qt(since = "6.4")]
+                    fn reset(self: Pin<&mut MyObject>);
+
+                    #[qinvokable]
+                    #[forward_from(validated, reset)]
+                    fn store(self: Pin<&mut MyObject>);
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(connections.len(), 3);
+        assert_str_eq!(
+            connections[0],
+            "::QObject::connect(this, &MyObject::textChanged, this, &MyObject::validated);"
+        );
+        assert_str_eq!(
+            connections[1],
+            "::QObject::connect(this, &MyObject::validated, this, &MyObject::store);"
+        );
+        assert_str_eq!(
+            connections[2],
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)
+            ::QObject::connect(this, &MyObject::reset, this, &MyObject::store);
+            #endif"}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_signal_forwards_invalid() {
+        // Unknown signal
+        assert!(forwards_from_bridge(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    #[forward_from(missing)]
+                    fn store(self: Pin<&mut MyObject>);
+                }
+            }
+        })
+        .is_err());
+
+        // Private signals can only be emitted from Rust
+        assert!(forwards_from_bridge(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    #[forward_from(ready)]
+                    pub(self) fn private_ready(self: Pin<&mut MyObject>);
+                }
+            }
+        })
+        .is_err());
+    }
 }
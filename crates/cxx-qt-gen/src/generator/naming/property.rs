@@ -55,6 +55,8 @@ pub struct QPropertyNames {
     pub getter: NameState,
     pub setter: Option<NameState>,
     pub notify: Option<NameState>,
+    /// The helper which coalesces notify signals, if the property has a `notify_throttle_ms`
+    pub notify_throttled: Option<Name>,
     pub reset: Option<Name>,
 }
 
@@ -101,6 +103,11 @@ impl QPropertyNames {
             })
             .transpose()?;
 
+        let notify_throttled = notify
+            .as_ref()
+            .filter(|_| flags.notify_throttle_ms.is_some())
+            .map(|notify| throttled_name_from_notify(notify));
+
         let reset = flags
             .reset
             .as_ref()
@@ -111,6 +118,7 @@ impl QPropertyNames {
             getter,
             setter,
             notify,
+            notify_throttled,
             reset,
             name: property_name.clone(),
         })
@@ -150,6 +158,14 @@ fn notify_name_from_property(name: &Name) -> Name {
         .with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
 }
 
+/// For a given notify signal name generate the name of the helper which throttles it
+fn throttled_name_from_notify(notify: &Name) -> Name {
+    notify
+        .clone()
+        .with_rust_name(format_ident!("{}_throttled", notify.rust_unqualified()))
+        .with_cxx_name(format!("{}Throttled", notify.cxx_unqualified()))
+}
+
 #[cfg(test)]
 pub mod tests {
     use syn::parse_quote;
@@ -163,6 +179,8 @@ pub mod tests {
             name: property_name_from_rust_name(format_ident!("my_property")),
             ty: parse_quote! { i32 },
             flags: QPropertyFlags::default(),
+            mapping: None,
+            cfgs: vec![],
         };
 
         let obj = ParsedQObject::mock();
@@ -195,5 +213,31 @@ pub mod tests {
             names.notify.as_ref().unwrap().rust_unqualified(),
             "my_property_changed"
         );
+        assert!(names.notify_throttled.is_none());
+    }
+
+    #[test]
+    fn test_parsed_property_notify_throttled() {
+        let property = ParsedQProperty {
+            name: property_name_from_rust_name(format_ident!("my_property")),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                notify_throttle_ms: Some(16),
+                ..QPropertyFlags::default()
+            },
+            mapping: None,
+            cfgs: vec![],
+        };
+
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+
+        let throttled = names.notify_throttled.unwrap();
+        assert_eq!(throttled.cxx_unqualified(), "myPropertyChangedThrottled");
+        assert_eq!(
+            throttled.rust_unqualified(),
+            "my_property_changed_throttled"
+        );
     }
 }
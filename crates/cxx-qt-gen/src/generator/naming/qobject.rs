@@ -21,6 +21,8 @@ pub struct QObjectNames {
     pub cxx_qt_thread_class: Ident,
     /// The name of the Rust closure wrapper to be passed in to CxxQtThread
     pub cxx_qt_thread_queued_fn_struct: Ident,
+    /// The name of the enum of signals which can be emitted with CxxQtThread
+    pub queued_signal_enum: Ident,
 }
 
 impl QObjectNames {
@@ -42,6 +44,7 @@ impl QObjectNames {
             cxx_qt_thread_queued_fn_struct: cxx_qt_thread_queued_fn_struct_from_ident(
                 qobject_name.rust_unqualified(),
             ),
+            queued_signal_enum: queued_signal_enum_from_ident(qobject_name.rust_unqualified()),
         })
     }
 
@@ -53,6 +56,7 @@ impl QObjectNames {
             rust_struct: Name::mock(&ident_right.to_string()),
             cxx_qt_thread_class: cxx_qt_thread_class_from_ident(&ident_left),
             cxx_qt_thread_queued_fn_struct: cxx_qt_thread_queued_fn_struct_from_ident(&ident_left),
+            queued_signal_enum: queued_signal_enum_from_ident(&ident_left),
         }
     }
 
@@ -94,6 +98,11 @@ fn cxx_qt_thread_queued_fn_struct_from_ident(ident: &Ident) -> Ident {
     format_ident!("{ident}CxxQtThreadQueuedFn")
 }
 
+/// For a given ident generate the QueuedSignal enum ident
+fn queued_signal_enum_from_ident(ident: &Ident) -> Ident {
+    format_ident!("{ident}QueuedSignal")
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -117,6 +126,7 @@ pub mod tests {
             names.cxx_qt_thread_queued_fn_struct,
             "MyObjectCxxQtThreadQueuedFn"
         );
+        assert_eq!(names.queued_signal_enum, "MyObjectQueuedSignal");
 
         assert_eq!(
             names.cxx_qt_ffi_method("threading_clone").into_cxx_parts(),
@@ -38,6 +38,8 @@ fn on_from_signal(ident: &Ident) -> Ident {
 
 pub struct QSignalHelperNames {
     pub connect_name: Name,
+    /// The free function which emits a private signal, as it has a QPrivateSignal parameter
+    pub emit_name: Name,
     pub function_call: Ident,
     pub function_drop: Ident,
     pub handler_alias: Ident,
@@ -84,10 +86,22 @@ impl QSignalHelperNames {
             idents.connect_name.cxx_unqualified()
         ));
 
+        let emit_name = Name::new(format_ident!(
+            "{}_emit_{}",
+            qobject_name.rust_unqualified(),
+            idents.name.rust_unqualified()
+        ))
+        .with_cxx_name(format!(
+            "{}_{}Emit",
+            qobject_name.cxx_unqualified(),
+            idents.name.cxx_unqualified()
+        ));
+
         // TODO: in the future we might improve the naming of the methods
         // to avoid collisions (maybe use a separator similar to how CXX uses $?)
         Ok(Self {
             connect_name,
+            emit_name,
             function_drop: format_ident!("drop_{qobject_ident}_signal_handler_{signal_ident}"),
             function_call: format_ident!("call_{qobject_ident}_signal_handler_{signal_ident}"),
             handler_alias_namespaced: format!("::{namespace}::{handler_alias}"),
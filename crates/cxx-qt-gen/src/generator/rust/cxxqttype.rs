@@ -46,6 +46,14 @@ pub fn generate(
                     fn #rust_mut_fn_name(outer: Pin<&mut #cpp_struct_ident>) -> Pin<&mut #rust_struct_ident>;
                 }
             },
+            quote! {
+                unsafe extern "C++" {
+                    // Bind to the method of the QObject base class, so that C++ upcasts the pointer
+                    #[doc(hidden)]
+                    #[cxx_name = "deleteLater"]
+                    fn cxx_qt_ffi_delete_later(self: Pin<&mut #cpp_struct_ident>);
+                }
+            },
         ],
         implementation: vec![
             quote! {
@@ -53,7 +61,7 @@ pub fn generate(
                     type Target = #rust_struct_ident;
 
                     fn deref(&self) -> &Self::Target {
-                        #rust_fn_qualified(self)
+                        ::cxx_qt::CxxQtType::rust(self)
                     }
                 }
             },
@@ -62,12 +70,18 @@ pub fn generate(
                     type Rust = #rust_struct_ident;
 
                     fn rust(&self) -> &Self::Rust {
+                        ::cxx_qt::assert_can_borrow(self, false);
                         #rust_fn_qualified(self)
                     }
 
                     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+                        ::cxx_qt::assert_can_borrow(&*self, true);
                         #rust_mut_fn_qualified(self)
                     }
+
+                    fn delete_later(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_ffi_delete_later()
+                    }
                 }
             },
         ],
@@ -98,7 +112,7 @@ mod tests {
 
         let generated = generate(&qobject_names, &TypeNames::mock()).unwrap();
 
-        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
 
         // CXX bridges
@@ -125,6 +139,16 @@ mod tests {
                 }
             },
         );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "deleteLater"]
+                    fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+                }
+            },
+        );
 
         // CXX-Qt generated contents
         assert_tokens_eq(
@@ -134,7 +158,7 @@ mod tests {
                     type Target = MyObjectRust;
 
                     fn deref(&self) -> &Self::Target {
-                        qobject::cxx_qt_ffi_my_object_unsafe_rust(self)
+                        ::cxx_qt::CxxQtType::rust(self)
                     }
                 }
             },
@@ -146,12 +170,18 @@ mod tests {
                     type Rust = MyObjectRust;
 
                     fn rust(&self) -> &Self::Rust {
+                        ::cxx_qt::assert_can_borrow(self, false);
                         qobject::cxx_qt_ffi_my_object_unsafe_rust(self)
                     }
 
                     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+                        ::cxx_qt::assert_can_borrow(&*self, true);
                         qobject::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
                     }
+
+                    fn delete_later(self: core::pin::Pin<&mut Self>) {
+                        self.cxx_qt_ffi_delete_later()
+                    }
                 }
             },
         );
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use proc_macro2::TokenStream;
-use syn::{Item, Result};
+use syn::{Attribute, ForeignItem, Item, Result};
 
 #[derive(Default, Eq, PartialEq, Debug)]
 pub struct GeneratedRustFragment {
@@ -20,6 +20,54 @@ impl GeneratedRustFragment {
         self.cxx_qt_mod_contents
             .append(&mut other.cxx_qt_mod_contents);
     }
+
+    /// Add an attribute, eg a cfg, to all of the generated items
+    ///
+    /// For extern blocks the attribute is added to the items within the block.
+    pub fn add_attribute(&mut self, attr: &Attribute) {
+        for item in self
+            .cxx_mod_contents
+            .iter_mut()
+            .chain(self.cxx_qt_mod_contents.iter_mut())
+        {
+            if let Item::ForeignMod(foreign_mod) = item {
+                for foreign_item in &mut foreign_mod.items {
+                    if let Some(attrs) = foreign_item_attrs_mut(foreign_item) {
+                        attrs.push(attr.clone());
+                    }
+                }
+            } else if let Some(attrs) = item_attrs_mut(item) {
+                attrs.push(attr.clone());
+            }
+        }
+    }
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn foreign_item_attrs_mut(item: &mut ForeignItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        ForeignItem::Fn(item) => Some(&mut item.attrs),
+        ForeignItem::Macro(item) => Some(&mut item.attrs),
+        ForeignItem::Static(item) => Some(&mut item.attrs),
+        ForeignItem::Type(item) => Some(&mut item.attrs),
+        _ => None,
+    }
 }
 
 /// A generic Rust CXX bridge definition and the corresponding implementation
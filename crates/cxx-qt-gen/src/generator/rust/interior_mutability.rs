@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectNames,
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::Result;
+
+pub fn generate(
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = qobject_names.name.rust_unqualified();
+    let rust_struct_ident = qobject_names.rust_struct.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+
+    let (rust_ptr_fn_name, rust_ptr_fn_attrs, rust_ptr_fn_qualified) = qobject_names
+        .cxx_qt_ffi_method("unsafeRustPtr")
+        .into_cxx_parts();
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #(#rust_ptr_fn_attrs)*
+                fn #rust_ptr_fn_name(outer: &#cpp_struct_ident) -> *mut #rust_struct_ident;
+            }
+        }],
+        implementation: vec![quote! {
+            impl ::cxx_qt::InteriorMutability for #qualified_impl {
+                fn rust_ptr(&self) -> *mut Self::Rust {
+                    #rust_ptr_fn_qualified(self)
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    #[test]
+    fn test_generate_rust_interior_mutability() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate(&qobject_names, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "unsafeRustPtr"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_unsafe_rust_ptr(outer: &MyObject) -> *mut MyObjectRust;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ::cxx_qt::InteriorMutability for qobject::MyObject {
+                    fn rust_ptr(&self) -> *mut Self::Rust {
+                        qobject::cxx_qt_ffi_my_object_unsafe_rust_ptr(self)
+                    }
+                }
+            },
+        );
+    }
+}
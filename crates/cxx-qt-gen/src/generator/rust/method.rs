@@ -9,14 +9,71 @@ use crate::{
         naming::qobject::QObjectNames,
         rust::fragment::{GeneratedRustFragment, RustFragmentPair},
     },
-    parser::method::ParsedMethod,
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::method::{ParsedMethod, ParsedWorkerInvokable},
 };
-use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Ident, Result, ReturnType};
+
+/// Generate the method which is called by C++ for a worker invokable,
+/// it spawns the body of the invokable on the worker thread and gives the result to the done method
+fn generate_worker_wrapper(
+    invokable: &ParsedMethod,
+    worker: &ParsedWorkerInvokable,
+    wrapper_ident: &Ident,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<TokenStream> {
+    let cpp_class_name_rust = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let invokable_ident_rust = invokable.name.rust_unqualified();
+    let invokable_name = invokable_ident_rust.to_string();
+
+    let self_sig = if invokable.mutable {
+        quote! { self: core::pin::Pin<&mut Self> }
+    } else {
+        quote! { &self }
+    };
+    let parameter_signatures = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let parameter_idents = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect::<Vec<_>>();
+
+    let done = match (&worker.done, &worker.output) {
+        (Some(done), ReturnType::Default) => quote! { |qobject, ()| qobject.#done() },
+        (Some(done), ReturnType::Type(..)) => quote! { |qobject, output| qobject.#done(output) },
+        (None, _) => quote! { |_, ()| {} },
+    };
+
+    Ok(quote! {
+        impl #qualified_impl {
+            #[doc(hidden)]
+            pub fn #wrapper_ident(#self_sig, #(#parameter_signatures),*) {
+                use cxx_qt::Worker;
+                let spawned = self.spawn_work(move || Self::#invokable_ident_rust(#(#parameter_idents),*), #done);
+                if let Err(error) = spawned {
+                    panic!("cannot run the invokable {} on the worker thread: {}", #invokable_name, error);
+                }
+            }
+        }
+    })
+}
 
 pub fn generate_rust_methods(
     invokables: &Vec<&ParsedMethod>,
     qobject_names: &QObjectNames,
+    type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
@@ -24,8 +81,22 @@ pub fn generate_rust_methods(
     for &invokable in invokables {
         // TODO: once we aren't using qobject::T in the extern "RustQt"
         // we can just pass through the original ExternFn block and add the attribute?
-        let invokable_ident_cpp = invokable.name.cxx_unqualified();
-        let invokable_ident_rust = invokable.name.rust_unqualified();
+        let invokable_ident_cpp = invokable.cxx_ident_of_implementation();
+        let mut invokable_ident_rust = invokable.name.rust_unqualified().clone();
+
+        // The body of a worker invokable is run by a generated method, which C++ calls instead
+        let mut implementation = vec![];
+        if let Some(worker) = &invokable.worker {
+            let wrapper_ident = format_ident!("{invokable_ident_rust}_cxx_qt_worker");
+            implementation.push(generate_worker_wrapper(
+                invokable,
+                worker,
+                &wrapper_ident,
+                qobject_names,
+                type_names,
+            )?);
+            invokable_ident_rust = wrapper_ident;
+        }
 
         let parameter_signatures = get_params_tokens(
             invokable.mutable,
@@ -60,15 +131,20 @@ pub fn generate_rust_methods(
                     #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
                 }
             }],
-            implementation: vec![],
+            implementation,
         };
 
-        generated
-            .cxx_mod_contents
-            .append(&mut fragment.cxx_bridge_as_items()?);
-        generated
-            .cxx_qt_mod_contents
-            .append(&mut fragment.implementation_as_items()?);
+        let mut generated_method = GeneratedRustFragment {
+            cxx_mod_contents: fragment.cxx_bridge_as_items()?,
+            cxx_qt_mod_contents: fragment.implementation_as_items()?,
+        };
+        if let Some(since) = &invokable.since {
+            generated_method.add_attribute(&since.cfg_attribute());
+        }
+        for cfg in &invokable.cfgs {
+            generated_method.add_attribute(cfg);
+        }
+        generated.append(&mut generated_method);
     }
 
     Ok(generated)
@@ -79,6 +155,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::syntax::safety::Safety;
     use crate::tests::assert_tokens_eq;
     use syn::{parse_quote, ForeignItemFn};
 
@@ -98,8 +175,12 @@ mod tests {
         ];
         let qobject_names = create_qobjectname();
 
-        let generated =
-            generate_rust_methods(&invokables.iter().collect(), &qobject_names).unwrap();
+        let generated = generate_rust_methods(
+            &invokables.iter().collect(),
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
@@ -152,4 +233,145 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_worker_invokables() {
+        let method1: ForeignItemFn = parse_quote! {
+            #[qinvokable(worker = set_result)]
+            fn calculate(self: &MyObject, input: i32) -> i32;
+        };
+        let method2: ForeignItemFn = parse_quote! {
+            #[qinvokable(worker)]
+            fn save(self: Pin<&mut MyObject>);
+        };
+        let invokables = [
+            ParsedMethod::parse(method1, Safety::Safe).unwrap(),
+            ParsedMethod::parse(method2, Safety::Safe).unwrap(),
+        ];
+        let qobject_names = create_qobjectname();
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect(),
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // calculate
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "calculate"]
+                    #[doc(hidden)]
+                    fn calculate_cxx_qt_worker(self: &MyObject, input: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn calculate_cxx_qt_worker(&self, input: i32) {
+                        use cxx_qt::Worker;
+                        let spawned = self.spawn_work(move | | Self::calculate(input), |qobject, output| qobject.set_result(output));
+                        if let Err(error) = spawned {
+                            panic!("cannot run the invokable {} on the worker thread: {}", "calculate", error);
+                        }
+                    }
+                }
+            },
+        );
+
+        // save
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "save"]
+                    #[doc(hidden)]
+                    fn save_cxx_qt_worker(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn save_cxx_qt_worker(self: core::pin::Pin<&mut Self>,) {
+                        use cxx_qt::Worker;
+                        let spawned = self.spawn_work(move | | Self::save(), |_, ()| {});
+                        if let Err(error) = spawned {
+                            panic!("cannot run the invokable {} on the worker thread: {}", "save", error);
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.4")]
+            fn new_invokable(self: &MyObject);
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+        let qobject_names = create_qobjectname();
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect(),
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "newInvokable"]
+                    #[doc(hidden)]
+                    #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_4))]
+                    fn new_invokable(self: &MyObject);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_ownership() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            #[qml_ownership = "cpp"]
+            fn create_child(self: &MyObject) -> *mut MyObject;
+        };
+        let invokables = [ParsedMethod::parse(method, Safety::Safe).unwrap()];
+        let qobject_names = create_qobjectname();
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect(),
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "createChildCxxQtOwnership"]
+                    #[doc(hidden)]
+                    fn create_child(self: &MyObject) -> *mut MyObject;
+                }
+            },
+        );
+    }
 }
@@ -8,12 +8,15 @@ pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
+pub mod interior_mutability;
 pub mod method;
+pub mod newcppobject;
 pub mod property;
 pub mod qenum;
 pub mod qobject;
 pub mod signals;
 pub mod threading;
+pub mod worker;
 
 use crate::generator::{rust::fragment::GeneratedRustFragment, structuring};
 use crate::parser::{parameter::ParsedFunctionParameter, Parser};
@@ -57,6 +60,20 @@ impl GeneratedRustBlocks {
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
 
+        // The Rust types of generic QObjects are declared next to the bridge
+        if !parser.cxx_qt_data.generic_instantiations.is_empty() {
+            fragments.push(GeneratedRustFragment {
+                cxx_mod_contents: vec![],
+                cxx_qt_mod_contents: parser
+                    .cxx_qt_data
+                    .generic_instantiations
+                    .iter()
+                    .cloned()
+                    .map(Item::Type)
+                    .collect(),
+            });
+        }
+
         let namespace = parser.cxx_qt_data.namespace.clone().unwrap_or_default();
         let passthrough_mod = &parser.passthrough_module;
 
@@ -162,6 +179,54 @@ mod tests {
         assert_eq!(rust.fragments.len(), 1);
     }
 
+    #[test]
+    fn test_generated_rust_blocks_cfgs() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[cfg_attr(windows, qproperty(i32, handle))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    #[cfg(unix)]
+                    type UnixObject = super::UnixObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    #[cfg(feature = "extra")]
+                    fn extra(self: &MyObject);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        let cfg_count = |fragment: &GeneratedRustFragment, cfg: &str| {
+            fragment
+                .cxx_mod_contents
+                .iter()
+                .chain(fragment.cxx_qt_mod_contents.iter())
+                .map(|item| quote! { #item }.to_string().matches(cfg).count())
+                .sum::<usize>()
+        };
+        // The getter, setter and notify signal of the property
+        assert!(cfg_count(&rust.fragments[0], "cfg (windows)") >= 3);
+        // The invokable
+        assert_eq!(
+            cfg_count(&rust.fragments[0], "cfg (feature = \"extra\")"),
+            1
+        );
+        // Every item of the QObject
+        assert!(cfg_count(&rust.fragments[1], "cfg (unix)") > 0);
+        assert!(rust.fragments[1]
+            .cxx_qt_mod_contents
+            .iter()
+            .all(|item| quote! { #item }.to_string().contains("cfg (unix)")));
+    }
+
     #[test]
     fn test_generated_rust_blocks_foreign_qobject() {
         let module: ItemMod = parse_quote! {
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{namespace::NamespaceName, qobject::QObjectNames},
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    naming::TypeNames,
+    parser::constructor::Constructor,
+};
+use quote::quote;
+use syn::{Error, Result};
+
+pub fn generate(
+    constructors: &[&Constructor],
+    qobject_names: &QObjectNames,
+    namespace_ident: &NamespaceName,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    // The QObject is constructed without arguments, which is only possible with the
+    // default constructor or a constructor without arguments
+    if let Some(constructor) = constructors.first() {
+        if !constructors
+            .iter()
+            .any(|constructor| constructor.arguments.is_empty())
+        {
+            return Err(Error::new_spanned(
+                &constructor.imp,
+                "cxx_qt::NewCppObject requires a constructor without arguments, implement cxx_qt::Constructor<()> as well!",
+            ));
+        }
+    }
+
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+    let (new_cpp_object_name, new_cpp_object_attrs, new_cpp_object_qualified) = qobject_names
+        .cxx_qt_ffi_method("newCppObject")
+        .with_namespace(namespace_ident.internal.clone())
+        .into_cxx_parts();
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #(#new_cpp_object_attrs)*
+                fn #new_cpp_object_name() -> UniquePtr<#cpp_struct_ident>;
+            }
+        }],
+        implementation: vec![quote! {
+            impl cxx_qt::NewCppObject for #qualified_impl {
+                fn new_cpp_object() -> cxx::UniquePtr<Self> {
+                    #new_cpp_object_qualified()
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_new_cpp_object() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated =
+            generate(&[], &qobject_names, &namespace_ident, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "newCppObject"]
+                    #[namespace = "cxx_qt_my_object"]
+                    fn cxx_qt_ffi_my_object_new_cpp_object() -> UniquePtr<MyObject>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::NewCppObject for qobject::MyObject {
+                    fn new_cpp_object() -> cxx::UniquePtr<Self> {
+                        qobject::cxx_qt_ffi_my_object_new_cpp_object()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_new_cpp_object_constructors() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let with_arguments =
+            Constructor::parse(parse_quote! { impl cxx_qt::Constructor<(i32,)> for MyObject {} })
+                .unwrap();
+        let without_arguments =
+            Constructor::parse(parse_quote! { impl cxx_qt::Constructor<()> for MyObject {} })
+                .unwrap();
+
+        assert!(generate(
+            &[&with_arguments],
+            &qobject_names,
+            &namespace_ident,
+            &TypeNames::mock()
+        )
+        .is_err());
+        assert!(generate(
+            &[&with_arguments, &without_arguments],
+            &qobject_names,
+            &namespace_ident,
+            &TypeNames::mock()
+        )
+        .is_ok());
+    }
+}
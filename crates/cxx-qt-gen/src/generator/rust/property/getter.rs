@@ -15,12 +15,13 @@ use crate::{
     naming::TypeNames,
 };
 use quote::quote;
-use syn::{Result, Type};
+use syn::{Path, Result, Type};
 
 pub fn generate(
     idents: &QPropertyNames,
     qobject_names: &QObjectNames,
     cxx_ty: &Type,
+    map_with: Option<&Path>,
     type_names: &TypeNames,
 ) -> Result<Option<RustFragmentPair>> {
     if let NameState::Auto(getter) = &idents.getter {
@@ -34,6 +35,28 @@ pub fn generate(
 
         let cxx_namespace = qobject_names.namespace_tokens();
 
+        // A mapped property converts the Rust value into the Qt type, so it is returned by value
+        if let Some(map_with) = map_with {
+            return Ok(Some(RustFragmentPair {
+                cxx_bridge: vec![quote! {
+                    extern "Rust" {
+                        #[cxx_name = #getter_cpp]
+                        #cxx_namespace
+                        unsafe fn #getter_rust(self: &#cpp_class_name_rust) -> #cxx_ty;
+                    }
+                }],
+                implementation: vec![quote! {
+                    impl #qualified_impl {
+                        #[doc = "Getter for the Q_PROPERTY "]
+                        #[doc = #ident_str]
+                        pub fn #getter_rust(&self) -> #qualified_ty {
+                            <#map_with as cxx_qt::MapQtValue>::to_qt(&self.#ident)
+                        }
+                    }
+                }],
+            }));
+        }
+
         Ok(Some(RustFragmentPair {
             cxx_bridge: vec![quote! {
                 extern "Rust" {
@@ -30,28 +30,53 @@ pub fn generate_rust_properties(
 
     for property in properties {
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
-
-        if let Some(getter) = getter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
+        let map_with = property.mapping.as_ref().map(|mapping| &mapping.map_with);
+        let mut generated_property = GeneratedRustFragment::default();
+
+        if let Some(getter) = getter::generate(
+            &idents,
+            qobject_names,
+            property.cxx_ty(),
+            map_with,
+            type_names,
+        )? {
+            generated_property
                 .cxx_mod_contents
                 .append(&mut getter.cxx_bridge_as_items()?);
-            generated
+            generated_property
                 .cxx_qt_mod_contents
                 .append(&mut getter.implementation_as_items()?);
         };
 
-        if let Some(setter) = setter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
+        if let Some(setter) = setter::generate(
+            &idents,
+            qobject_names,
+            property.cxx_ty(),
+            map_with,
+            type_names,
+        )? {
+            generated_property
                 .cxx_mod_contents
                 .append(&mut setter.cxx_bridge_as_items()?);
-            generated
+            generated_property
                 .cxx_qt_mod_contents
                 .append(&mut setter.implementation_as_items()?);
         }
 
-        if let Some(notify) = signal::generate(&idents, qobject_names) {
+        if let Some(mut notify) = signal::generate(&idents, qobject_names) {
+            notify.method_fields.cfgs.clone_from(&property.cfgs);
             signals.push(notify)
         }
+
+        if let Some(throttled) = signal::generate_throttled(&idents, qobject_names) {
+            generated_property.cxx_mod_contents.push(throttled);
+        }
+
+        // The property is only compiled when all of its cfgs are enabled
+        for cfg in &property.cfgs {
+            generated_property.add_attribute(cfg);
+        }
+        generated.append(&mut generated_property);
     }
 
     generated.append(&mut generate_rust_signals(
@@ -68,7 +93,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::property::property_name_from_rust_name;
-    use crate::parser::property::QPropertyFlags;
+    use crate::parser::property::{QPropertyFlags, QPropertyMapping};
     use crate::parser::qobject::ParsedQObject;
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
     use quote::format_ident;
@@ -81,16 +106,22 @@ mod tests {
                 name: property_name_from_rust_name(format_ident!("trivial_property")),
                 ty: parse_quote! { i32 },
                 flags: QPropertyFlags::default(),
+                mapping: None,
+                cfgs: vec![],
             },
             ParsedQProperty {
                 name: property_name_from_rust_name(format_ident!("opaque_property")),
                 ty: parse_quote! { UniquePtr<QColor> },
                 flags: QPropertyFlags::default(),
+                mapping: None,
+                cfgs: vec![],
             },
             ParsedQProperty {
                 name: property_name_from_rust_name(format_ident!("unsafe_property")),
                 ty: parse_quote! { *mut T },
                 flags: QPropertyFlags::default(),
+                mapping: None,
+                cfgs: vec![],
             },
         ];
         let qobject_names = create_qobjectname();
@@ -157,10 +188,15 @@ mod tests {
                     #[doc = "trivial_property"]
                     pub fn set_trivial_property(mut self: core::pin::Pin<&mut Self>, value: i32) {
                         use cxx_qt::CxxQtType;
-                        if self.trivial_property == value {
-                            return;
+                        {
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", "trivial_property", error)
+                            });
+                            if rust.trivial_property == value {
+                                return;
+                            }
+                            rust.as_mut().trivial_property = value;
                         }
-                        self.as_mut().rust_mut().trivial_property = value;
                         self.as_mut().trivial_property_changed();
                     }
                 }
@@ -210,10 +246,15 @@ mod tests {
                     #[doc = "opaque_property"]
                     pub fn set_opaque_property(mut self: core::pin::Pin<&mut Self>, value: cxx::UniquePtr<QColor>) {
                         use cxx_qt::CxxQtType;
-                        if self.opaque_property == value {
-                            return;
+                        {
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", "opaque_property", error)
+                            });
+                            if rust.opaque_property == value {
+                                return;
+                            }
+                            rust.as_mut().opaque_property = value;
                         }
-                        self.as_mut().rust_mut().opaque_property = value;
                         self.as_mut().opaque_property_changed();
                     }
                 }
@@ -263,10 +304,15 @@ mod tests {
                     #[doc = "unsafe_property"]
                     pub fn set_unsafe_property(mut self: core::pin::Pin<&mut Self>, value: *mut T) {
                         use cxx_qt::CxxQtType;
-                        if self.unsafe_property == value {
-                            return;
+                        {
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", "unsafe_property", error)
+                            });
+                            if rust.unsafe_property == value {
+                                return;
+                            }
+                            rust.as_mut().unsafe_property = value;
                         }
-                        self.as_mut().rust_mut().unsafe_property = value;
                         self.as_mut().unsafe_property_changed();
                     }
                 }
@@ -650,4 +696,153 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_map_with() {
+        let properties = vec![ParsedQProperty {
+            name: property_name_from_rust_name(format_ident!("amount")),
+            ty: parse_quote! { Decimal },
+            flags: QPropertyFlags::default(),
+            mapping: Some(QPropertyMapping {
+                cxx_type: parse_quote! { QString },
+                map_with: parse_quote! { DecimalAsQString },
+            }),
+            cfgs: vec![],
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Getter
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "getAmount"]
+                    unsafe fn amount(self: &MyObject) -> QString;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "amount"]
+                    pub fn amount(&self) -> QString {
+                        <DecimalAsQString as cxx_qt::MapQtValue>::to_qt(&self.amount)
+                    }
+                }
+            },
+        );
+
+        // Setter
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "setAmount"]
+                    fn set_amount(self: Pin<&mut MyObject>, value: QString);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "amount"]
+                    pub fn set_amount(mut self: core::pin::Pin<&mut Self>, value: QString) {
+                        use cxx_qt::CxxQtType;
+                        let value = <DecimalAsQString as cxx_qt::MapQtValue>::from_qt(value);
+                        {
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", "amount", error)
+                            });
+                            if rust.amount == value {
+                                return;
+                            }
+                            rust.as_mut().amount = value;
+                        }
+                        self.as_mut().amount_changed();
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_notify_throttle() {
+        let properties = vec![ParsedQProperty {
+            name: property_name_from_rust_name(format_ident!("reading")),
+            ty: parse_quote! { f64 },
+            flags: QPropertyFlags {
+                notify_throttle_ms: Some(16),
+                ..QPropertyFlags::default()
+            },
+            mapping: None,
+            cfgs: vec![],
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &TypeNames::mock(),
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Setter emits through the throttled helper
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "reading"]
+                    pub fn set_reading(mut self: core::pin::Pin<&mut Self>, value: f64) {
+                        use cxx_qt::CxxQtType;
+                        {
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", "reading", error)
+                            });
+                            if rust.reading == value {
+                                return;
+                            }
+                            rust.as_mut().reading = value;
+                        }
+                        self.as_mut().reading_changed_throttled();
+                    }
+                }
+            },
+        );
+
+        // Throttled helper
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "readingChangedThrottled"]
+                    fn reading_changed_throttled(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+    }
 }
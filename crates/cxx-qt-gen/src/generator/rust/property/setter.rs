@@ -15,12 +15,13 @@ use crate::{
     naming::TypeNames,
 };
 use quote::quote;
-use syn::{Result, Type};
+use syn::{Path, Result, Type};
 
 pub fn generate(
     idents: &QPropertyNames,
     qobject_names: &QObjectNames,
     cxx_ty: &Type,
+    map_with: Option<&Path>,
     type_names: &TypeNames,
 ) -> Result<Option<RustFragmentPair>> {
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
@@ -33,12 +34,17 @@ pub fn generate(
         let ident_str = ident.to_string();
 
         // Generate a notify name if it was provided, otherwise return empty
-        let notify_binding = match &idents.notify {
-            Some(notify) => {
+        // A throttled notify signal is emitted through the helper which coalesces the changes
+        let notify_binding = match (&idents.notify_throttled, &idents.notify) {
+            (Some(throttled), _) => {
+                let throttled_ident = throttled.rust_unqualified();
+                quote! {self.as_mut().#throttled_ident();}
+            }
+            (None, Some(notify)) => {
                 let notify_ident = notify.rust_unqualified();
                 quote! {self.as_mut().#notify_ident();}
             }
-            None => quote! {},
+            (None, None) => quote! {},
         };
 
         let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
@@ -51,6 +57,13 @@ pub fn generate(
             quote! {}
         };
 
+        // A mapped property converts the value from Qt before comparing it with the Rust value
+        let map_value = map_with.map(|map_with| {
+            quote! {
+                let value = <#map_with as cxx_qt::MapQtValue>::from_qt(value);
+            }
+        });
+
         let cxx_namespace = qobject_names.namespace_tokens();
 
         Ok(Some(RustFragmentPair {
@@ -71,12 +84,19 @@ pub fn generate(
                     #[doc = #ident_str]
                     pub fn #setter_rust(mut self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
                         use cxx_qt::CxxQtType;
-                        if self.#ident == value {
-                            // don't want to set the value again and reemit the signal,
-                            // as this can cause binding loops
-                            return;
+                        #map_value
+                        {
+                            // Track the borrow, so that re-entering the QObject while it is set is detected
+                            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                                panic!("cannot set the property {}: {}", #ident_str, error)
+                            });
+                            if rust.#ident == value {
+                                // don't want to set the value again and reemit the signal,
+                                // as this can cause binding loops
+                                return;
+                            }
+                            rust.as_mut().#ident = value;
                         }
-                        self.as_mut().rust_mut().#ident = value;
                         #notify_binding
                     }
                 }
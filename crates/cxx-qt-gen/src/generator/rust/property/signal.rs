@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::ForeignItemFn;
+use syn::{ForeignItemFn, Item};
 
 use crate::syntax::safety::Safety;
 use crate::{
@@ -33,3 +33,21 @@ pub fn generate(idents: &QPropertyNames, qobject_names: &QObjectNames) -> Option
         None
     }
 }
+
+/// Declare the C++ helper which coalesces the notify signal of a property with `notify_throttle_ms`
+pub fn generate_throttled(idents: &QPropertyNames, qobject_names: &QObjectNames) -> Option<Item> {
+    let throttled = idents.notify_throttled.as_ref()?;
+    let throttled_rust = throttled.rust_unqualified();
+    let throttled_cpp = throttled.cxx_unqualified();
+    let cpp_class_rust = &qobject_names.name.rust_unqualified();
+    let cxx_namespace = qobject_names.namespace_tokens();
+
+    Some(syn::parse_quote! {
+        unsafe extern "C++" {
+            #[doc(hidden)]
+            #[cxx_name = #throttled_cpp]
+            #cxx_namespace
+            fn #throttled_rust(self: Pin<&mut #cpp_class_rust>);
+        }
+    })
+}
@@ -9,11 +9,12 @@ use crate::{
         rust::{
             constructor, cxxqttype,
             fragment::{GeneratedRustFragment, RustFragmentPair},
-            inherit,
+            inherit, interior_mutability,
             method::generate_rust_methods,
+            newcppobject,
             property::generate_rust_properties,
             signals::generate_rust_signals,
-            threading,
+            threading, worker,
         },
     },
     naming::TypeNames,
@@ -49,6 +50,7 @@ impl GeneratedRustFragment {
         generated.append(&mut generate_rust_methods(
             &structured_qobject.methods,
             &qobject_names,
+            type_names,
         )?);
         generated.append(&mut inherit::generate(
             &qobject_names,
@@ -84,6 +86,11 @@ impl GeneratedRustFragment {
                 &namespace_idents,
                 type_names,
             )?);
+            generated.append(&mut threading::generate_queued_signals(
+                &qobject_names,
+                &structured_qobject.signals,
+                type_names,
+            )?);
         }
 
         generated.append(&mut constructor::generate(
@@ -93,8 +100,36 @@ impl GeneratedRustFragment {
             type_names,
         )?);
 
+        // If this type can be constructed from Rust then add generation
+        if structured_qobject.new_cpp_object {
+            generated.append(&mut newcppobject::generate(
+                &structured_qobject.constructors,
+                &qobject_names,
+                &namespace_idents,
+                type_names,
+            )?);
+        }
+
+        // If this type has a worker then add generation
+        if structured_qobject.worker {
+            generated.append(&mut worker::generate(&qobject_names, type_names)?);
+        }
+
+        // If the Rust struct can be mutated through &self then add generation
+        if structured_qobject.interior_mutability {
+            generated.append(&mut interior_mutability::generate(
+                &qobject_names,
+                type_names,
+            )?);
+        }
+
         generated.append(&mut cxxqttype::generate(&qobject_names, type_names)?);
 
+        // The QObject is only compiled when all of its cfgs are enabled
+        for cfg in &qobject.cfgs {
+            generated.add_attribute(cfg);
+        }
+
         Ok(generated)
     }
 }
@@ -224,7 +259,7 @@ mod tests {
             &parser.type_names,
         )
         .unwrap();
-        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_mod_contents.len(), 7);
         assert_tokens_eq(
             &rust.cxx_mod_contents[0],
             quote! {
@@ -113,7 +113,7 @@ pub fn generate_rust_signal(
 
     let return_type = &signal.method.sig.output;
 
-    // TODO: what happens with RustQt signals, can they be private yet?
+    let mut implementation = vec![];
     if !signal.private {
         cxx_bridge.push(quote! {
             #unsafe_block extern "C++" {
@@ -123,6 +123,35 @@ pub fn generate_rust_signal(
                 #unsafe_call fn #signal_ident_cpp(#parameter_signatures) #return_type;
             }
         });
+    } else if !signal.inherit {
+        // A private signal of a RustQt QObject is emitted through a free function, which can
+        // construct the QPrivateSignal, and only the module of the bridge can call the emitter
+        let emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+        let emit_ident_rust = idents_helper.emit_name.rust_unqualified();
+        let namespace_str = &idents_helper.namespace;
+        cxx_bridge.push(quote! {
+            #unsafe_block extern "C++" {
+                #[doc(hidden)]
+                #[namespace = #namespace_str]
+                #[cxx_name = #emit_ident_cpp]
+                #unsafe_call fn #emit_ident_rust(self_value: #self_type_cxx, #(#parameters_cxx),*);
+            }
+        });
+
+        let emit_call = quote! { #module_ident::#emit_ident_rust(self, #(#parameters_name),*) };
+        let emit_call = if signal.safe {
+            emit_call
+        } else {
+            quote! { unsafe { #emit_call } }
+        };
+        implementation.push(quote! {
+            impl #qualified_impl {
+                #(#doc_comments)*
+                #unsafe_call fn #signal_ident_cpp(self: #self_type_qualified, #(#parameters_qualified_arg),*) {
+                    #emit_call;
+                }
+            }
+        });
     }
 
     let closure_struct = idents_helper.struct_closure;
@@ -156,9 +185,7 @@ pub fn generate_rust_signal(
         }
     });
 
-    let fragment = RustFragmentPair {
-        cxx_bridge,
-        implementation: vec![
+    implementation.append(&mut vec![
             quote! {
                 impl #qualified_impl {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -219,7 +246,11 @@ pub fn generate_rust_signal(
             quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, [usize; 2]);
             },
-        ],
+    ]);
+
+    let fragment = RustFragmentPair {
+        cxx_bridge,
+        implementation,
     };
 
     let mut generated = GeneratedRustFragment::default();
@@ -230,6 +261,14 @@ pub fn generate_rust_signal(
         .cxx_qt_mod_contents
         .append(&mut fragment.implementation_as_items()?);
 
+    // Only compile the signal with the Qt versions that it is available in
+    if let Some(since) = &signal.since {
+        generated.add_attribute(&since.cfg_attribute());
+    }
+    for cfg in &signal.cfgs {
+        generated.add_attribute(cfg);
+    }
+
     Ok(generated)
 }
 
@@ -848,6 +887,8 @@ mod tests {
                 name: Name::new(format_ident!("ready")),
                 ..mock.method_fields
             },
+            // Private signals of extern "C++Qt" blocks are always inherited and have no emitter
+            inherit: true,
             private: true,
             ..mock
         };
@@ -859,4 +900,75 @@ mod tests {
 
         common_asserts(&generated.cxx_mod_contents, &generated.cxx_qt_mod_contents);
     }
+
+    #[test]
+    fn test_generate_rust_signal_private() {
+        let method: ForeignItemFn = parse_quote! {
+            pub(self) fn ready(self: Pin<&mut MyObject>, value: &QString);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(&qsignal, &qobject_name, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_readyEmit"]
+                    fn MyObject_emit_ready(self_value: Pin<&mut MyObject>, value: &QString);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    fn ready(self: core::pin::Pin<&mut qobject::MyObject>, value: &QString) {
+                        qobject::MyObject_emit_ready(self, value);
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.4")]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let type_names = TypeNames::mock();
+
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(&qsignal, &qobject_name, &type_names).unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "ready"]
+                    #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_4))]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[7],
+            quote! {
+                #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_4))]
+                cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>, [usize; 2]);
+            },
+        );
+    }
 }
@@ -5,16 +5,19 @@
 
 use crate::{
     generator::{
+        naming::signals::QSignalNames,
         naming::{
             namespace::{namespace_combine_ident, NamespaceName},
             qobject::QObjectNames,
         },
         rust::fragment::GeneratedRustFragment,
     },
-    naming::TypeNames,
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::signals::ParsedSignal,
 };
-use quote::quote;
-use syn::Result;
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{Result, Type};
 
 use super::fragment::RustFragmentPair;
 
@@ -40,6 +43,10 @@ pub fn generate(
     let (thread_drop_name, thread_drop_attrs, thread_drop_qualified) = qobject_names
         .cxx_qt_ffi_method("cxxQtThreadDrop")
         .into_cxx_parts();
+    let (thread_is_current_name, thread_is_current_attrs, thread_is_current_qualified) =
+        qobject_names
+            .cxx_qt_ffi_method("cxxQtThreadIsCurrentThread")
+            .into_cxx_parts();
     let (thread_fn_name, thread_fn_attrs, thread_fn_qualified) =
         qobject_names.cxx_qt_ffi_method("qtThread").into_cxx_parts();
 
@@ -76,6 +83,7 @@ pub fn generate(
                         cxx_qt_thread: &#cxx_qt_thread_ident,
                         func: fn(Pin<&mut #cpp_struct_ident>, Box<#cxx_qt_thread_queued_fn_ident>),
                         arg: Box<#cxx_qt_thread_queued_fn_ident>,
+                        priority: i32,
                     ) -> Result<()>;
 
                     #[doc(hidden)]
@@ -85,6 +93,10 @@ pub fn generate(
                     #[doc(hidden)]
                     #(#thread_drop_attrs)*
                     fn #thread_drop_name(cxx_qt_thread: &mut #cxx_qt_thread_ident);
+
+                    #[doc(hidden)]
+                    #(#thread_is_current_attrs)*
+                    fn #thread_is_current_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
                 }
             },
             quote! {
@@ -106,7 +118,7 @@ pub fn generate(
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F, priority: cxx_qt::EventPriority) -> std::result::Result<(), cxx::Exception>
                     where
                         F: FnOnce(core::pin::Pin<&mut #qualified_impl>),
                         F: Send + 'static,
@@ -123,7 +135,7 @@ pub fn generate(
                             (arg.inner)(obj)
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
-                        #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg), priority as i32)
                     }
 
                     #[doc(hidden)]
@@ -137,6 +149,12 @@ pub fn generate(
                     {
                         #thread_drop_qualified(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn threading_is_current_thread(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #thread_is_current_qualified(cxx_qt_thread)
+                    }
                 }
             },
             quote! {
@@ -160,6 +178,107 @@ pub fn generate(
     Ok(blocks)
 }
 
+/// Generate the `<QObject>QueuedSignal` enum, which allows a background thread to emit
+/// the signals of the QObject with `CxxQtThread::emit`
+pub fn generate_queued_signals(
+    qobject_names: &QObjectNames,
+    signals: &[&ParsedSignal],
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    // Private signals can only be emitted by the module of the bridge and unsafe signals can't be emitted safely
+    let signals: Vec<&ParsedSignal> = signals
+        .iter()
+        .copied()
+        .filter(|signal| !signal.private && signal.safe)
+        .collect();
+    if signals.is_empty() {
+        return Ok(blocks);
+    }
+
+    let qobject_ident = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(qobject_ident)?;
+    let enum_ident = &qobject_names.queued_signal_enum;
+    let enum_doc = format!(
+        "The signals of [{qobject_ident}], which can be emitted from a background thread with [cxx_qt::CxxQtThread::emit]"
+    );
+
+    let mut variants = vec![];
+    let mut arms = vec![];
+    for signal in signals {
+        let idents = QSignalNames::from(signal);
+        let emit_ident = idents.name.rust_unqualified();
+        let variant_ident = format_ident!(
+            "{}",
+            emit_ident
+                .to_string()
+                .trim_start_matches("r#")
+                .to_case(Case::Pascal)
+        );
+        let cfg = signal
+            .since
+            .as_ref()
+            .map(|since| since.cfg_attribute())
+            .into_iter()
+            .chain(signal.cfgs.iter().cloned())
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![];
+        let mut arguments = vec![];
+        for parameter in &signal.parameters {
+            let ident = &parameter.ident;
+            // References are stored as owned values, so that the signal can be sent to the thread
+            let ty = if let Type::Reference(reference) = &parameter.ty {
+                arguments.push(quote! { &#ident });
+                syn_type_cxx_bridge_to_qualified(&reference.elem, type_names)?
+            } else {
+                arguments.push(quote! { #ident });
+                syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?
+            };
+            fields.push(quote! { #ident: #ty });
+        }
+        let field_idents = signal.parameters.iter().map(|parameter| &parameter.ident);
+
+        if fields.is_empty() {
+            variants.push(quote! { #(#cfg)* #variant_ident });
+            arms.push(quote! { #(#cfg)* Self::#variant_ident => qobject.#emit_ident() });
+        } else {
+            variants.push(quote! { #(#cfg)* #variant_ident { #(#fields),* } });
+            arms.push(quote! {
+                #(#cfg)* Self::#variant_ident { #(#field_idents),* } => qobject.#emit_ident(#(#arguments),*)
+            });
+        }
+    }
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![],
+        implementation: vec![
+            quote! {
+                #[doc = #enum_doc]
+                pub enum #enum_ident {
+                    #(#variants),*
+                }
+            },
+            quote! {
+                impl cxx_qt::QueuedSignal<#qualified_impl> for #enum_ident {
+                    fn emit(self, qobject: core::pin::Pin<&mut #qualified_impl>) {
+                        match self {
+                            #(#arms),*
+                        }
+                    }
+                }
+            },
+        ],
+    };
+
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +287,7 @@ mod tests {
     use crate::tests::assert_tokens_eq;
 
     use crate::parser::qobject::tests::create_parsed_qobject;
+    use syn::parse_quote;
 
     #[test]
     fn test_generate_rust_threading() {
@@ -205,6 +325,7 @@ mod tests {
                         cxx_qt_thread: &MyObjectCxxQtThread,
                         func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
                         arg: Box<MyObjectCxxQtThreadQueuedFn>,
+                        priority: i32,
                     ) -> Result<()>;
 
                     #[doc(hidden)]
@@ -216,6 +337,11 @@ mod tests {
                     #[cxx_name = "cxxQtThreadDrop"]
                     #[namespace = "rust::cxxqt1"]
                     fn cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadIsCurrentThread"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_cxx_qt_thread_is_current_thread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
                 }
             },
         );
@@ -243,7 +369,7 @@ mod tests {
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F, priority: cxx_qt::EventPriority) -> std::result::Result<(), cxx::Exception>
                     where
                         F: FnOnce(core::pin::Pin<&mut qobject::MyObject>),
                         F: Send + 'static,
@@ -260,7 +386,7 @@ mod tests {
                             (arg.inner)(obj)
                         }
                         let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
-                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_queue(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_queue(cxx_qt_thread, func, std::boxed::Box::new(arg), priority as i32)
                     }
 
                     #[doc(hidden)]
@@ -274,6 +400,12 @@ mod tests {
                     {
                         qobject::cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn threading_is_current_thread(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool
+                    {
+                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_is_current_thread(cxx_qt_thread)
+                    }
                 }
             },
         );
@@ -289,4 +421,63 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_queued_signals() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let ready = ParsedSignal::mock(&parse_quote! {
+            fn ready(self: Pin<&mut MyObject>);
+        });
+        let error = ParsedSignal::mock(&parse_quote! {
+            fn error_occurred(self: Pin<&mut MyObject>, message: &QString, code: i32);
+        });
+        let private = ParsedSignal::mock(&parse_quote! {
+            pub(self) fn internal(self: Pin<&mut MyObject>);
+        });
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated =
+            generate_queued_signals(&qobject_names, &[&ready, &error, &private], &type_names)
+                .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 0);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc = "The signals of [MyObject], which can be emitted from a background thread with [cxx_qt::CxxQtThread::emit]"]
+                pub enum MyObjectQueuedSignal {
+                    Ready,
+                    ErrorOccurred { message: QString, code: i32 }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl cxx_qt::QueuedSignal<qobject::MyObject> for MyObjectQueuedSignal {
+                    fn emit(self, qobject: core::pin::Pin<&mut qobject::MyObject>) {
+                        match self {
+                            Self::Ready => qobject.ready(),
+                            Self::ErrorOccurred { message, code } => qobject.error_occurred(&message, code)
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_queued_signals_none() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate_queued_signals(&qobject_names, &[], &TypeNames::mock()).unwrap();
+
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+    }
 }
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectNames,
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::Result;
+
+pub fn generate(
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![],
+        implementation: vec![quote! {
+            impl cxx_qt::Worker for #qualified_impl {
+                fn worker_thread() -> &'static cxx_qt::WorkerThread {
+                    // The thread is shared by all instances of the QObject and started on first use
+                    static WORKER_THREAD: cxx_qt::WorkerThread = cxx_qt::WorkerThread::new();
+                    &WORKER_THREAD
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    #[test]
+    fn test_generate_rust_worker() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate(&qobject_names, &TypeNames::mock()).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::Worker for qobject::MyObject {
+                    fn worker_thread() -> &'static cxx_qt::WorkerThread {
+                        static WORKER_THREAD: cxx_qt::WorkerThread = cxx_qt::WorkerThread::new();
+                        &WORKER_THREAD
+                    }
+                }
+            },
+        );
+    }
+}
@@ -14,12 +14,14 @@
 /// All resulting structures are listed in the `Structures` struct.
 pub mod qobject;
 
+use crate::generator::cfg::{try_eval_attributes, CfgEvaluator};
 use crate::parser::{
     cxxqtdata::ParsedCxxQtData,
     trait_impl::{TraitImpl, TraitKind},
+    ErrorCollector,
 };
 pub use qobject::StructuredQObject;
-use syn::{Error, Ident, Result};
+use syn::{Attribute, Error, Ident, Result};
 
 /// The list of all structures that could be associated from the parsed data.
 /// Most importantly, this includes the list of qobjects.
@@ -68,10 +70,56 @@ impl<'a> Structures<'a> {
                     }
                     qobject.threading = true;
                 }
+                TraitKind::NewCppObject => {
+                    if qobject.new_cpp_object {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "NewCppObject already implemented on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.new_cpp_object = true;
+                }
+                TraitKind::Worker => {
+                    if qobject.worker {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "Worker already implemented on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.worker = true;
+                }
+                TraitKind::InteriorMutability => {
+                    if qobject.interior_mutability {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "InteriorMutability already implemented on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.interior_mutability = true;
+                }
                 // TODO: Check for duplicate declarations?
                 TraitKind::Constructor(ref constructor) => qobject.constructors.push(constructor),
             }
         }
+
+        // A worker queues its results onto the Qt thread, so it requires threading
+        for imp in trait_impls {
+            if imp.kind == TraitKind::Worker && !find_qobject(qobjects, &imp.qobject)?.threading {
+                return Err(Error::new_spanned(
+                    &imp.declaration,
+                    "cxx_qt::Worker requires cxx_qt::Threading to be implemented as well!",
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -84,35 +132,105 @@ impl<'a> Structures<'a> {
             .map(StructuredQObject::from_qobject)
             .collect();
 
+        // Each item is associated independently, so report the errors of all items at once
+        let mut errors = ErrorCollector::default();
+
         for qenum in &cxxqtdata.qenums {
             if let Some(qobject_ident) = &qenum.qobject {
-                let qobject = find_qobject(&mut qobjects, qobject_ident)?;
-                qobject.qenums.push(qenum);
+                if let Some(qobject) = errors.collect(find_qobject(&mut qobjects, qobject_ident)) {
+                    qobject.qenums.push(qenum);
+                }
             }
         }
 
         // Associate each method parsed with its appropriate qobject
         for method in &cxxqtdata.methods {
-            let qobject = find_qobject(&mut qobjects, &method.qobject_ident)?;
-            qobject.methods.push(method);
+            let result = find_qobject(&mut qobjects, &method.qobject_ident).and_then(|qobject| {
+                qobject.check_unique_name(method.name.rust_unqualified())?;
+                qobject.methods.push(method);
+                Ok(())
+            });
+            errors.collect(result);
         }
 
         // Associate each inherited method parsed with its appropriate qobject
         for inherited_method in &cxxqtdata.inherited_methods {
-            let qobject = find_qobject(&mut qobjects, &inherited_method.qobject_ident)?;
-            qobject.inherited_methods.push(inherited_method);
+            let result =
+                find_qobject(&mut qobjects, &inherited_method.qobject_ident).and_then(|qobject| {
+                    qobject.check_unique_name(inherited_method.name.rust_unqualified())?;
+                    qobject.inherited_methods.push(inherited_method);
+                    Ok(())
+                });
+            errors.collect(result);
         }
 
         // Associate each signal parsed with its appropriate qobject
         for signal in &cxxqtdata.signals {
-            let qobject = find_qobject(&mut qobjects, &signal.qobject_ident)?;
-            qobject.signals.push(signal);
+            let result = find_qobject(&mut qobjects, &signal.qobject_ident).and_then(|qobject| {
+                qobject.check_unique_name(signal.name.rust_unqualified())?;
+                qobject.signals.push(signal);
+                Ok(())
+            });
+            errors.collect(result);
         }
 
-        Self::structure_trait_impls(&mut qobjects, &cxxqtdata.trait_impls)?;
+        errors.collect(Self::structure_trait_impls(
+            &mut qobjects,
+            &cxxqtdata.trait_impls,
+        ));
+
+        // The body of a worker invokable is run on the worker thread of its QObject
+        for qobject in &qobjects {
+            for method in &qobject.methods {
+                if method.worker.is_some() && !qobject.worker {
+                    errors.push(Error::new_spanned(
+                        &method.method.sig.ident,
+                        format!(
+                            "#[qinvokable(worker)] requires cxx_qt::Worker to be implemented for {qobject}!",
+                            qobject = qobject.declaration.name.rust_unqualified()
+                        ),
+                    ));
+                }
+            }
+        }
+        errors.finish()?;
 
         Ok(Structures { qobjects })
     }
+
+    /// Remove the QObjects, properties, methods and signals whose `#[cfg(...)]` attributes are disabled
+    pub fn retain_enabled(&mut self, cfg_evaluator: &dyn CfgEvaluator) -> Result<()> {
+        retain_enabled(&mut self.qobjects, cfg_evaluator, |qobject| {
+            &qobject.declaration.cfgs
+        })?;
+        for qobject in &mut self.qobjects {
+            retain_enabled(&mut qobject.properties, cfg_evaluator, |property| {
+                &property.cfgs
+            })?;
+            retain_enabled(&mut qobject.methods, cfg_evaluator, |method| &method.cfgs)?;
+            retain_enabled(&mut qobject.inherited_methods, cfg_evaluator, |method| {
+                &method.cfgs
+            })?;
+            retain_enabled(&mut qobject.signals, cfg_evaluator, |signal| &signal.cfgs)?;
+        }
+        Ok(())
+    }
+}
+
+/// Remove the items whose `#[cfg(...)]` attributes are disabled
+fn retain_enabled<T>(
+    items: &mut Vec<T>,
+    cfg_evaluator: &dyn CfgEvaluator,
+    cfgs: impl Fn(&T) -> &[Attribute],
+) -> Result<()> {
+    let mut enabled = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if try_eval_attributes(cfg_evaluator, cfgs(&item))? {
+            enabled.push(item);
+        }
+    }
+    *items = enabled;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -263,6 +381,88 @@ mod tests {
         assert!(Structures::new(&parser.cxx_qt_data).is_err());
     }
 
+    #[test]
+    fn test_duplicate_new_cpp_object() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt::NewCppObject for MyObject {}},
+            parse_quote! {impl cxx_qt::NewCppObject for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_worker() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt::Threading for MyObject {}},
+            parse_quote! {impl cxx_qt::Worker for MyObject {}},
+            parse_quote! {impl cxx_qt::Worker for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_interior_mutability() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt::InteriorMutability for MyObject {}},
+            parse_quote! {impl cxx_qt::InteriorMutability for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
+    #[test]
+    fn test_worker_invokable_requires_worker() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                unsafe extern "RustQt" {
+                    #[qinvokable(worker)]
+                    fn save(self: &MyObject);
+                }
+            },
+            parse_quote! {impl cxx_qt::Threading for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge.clone()).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+
+        bridge
+            .content
+            .as_mut()
+            .unwrap()
+            .1
+            .push(parse_quote! {impl cxx_qt::Worker for MyObject {}});
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert!(structures.qobjects[0].methods[0].worker.is_some());
+    }
+
+    #[test]
+    fn test_worker_requires_threading() {
+        let mut bridge = mock_bridge();
+        bridge
+            .content
+            .as_mut()
+            .unwrap()
+            .1
+            .push(parse_quote! {impl cxx_qt::Worker for MyObject {}});
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt::Worker for MyObject {}},
+            parse_quote! {impl cxx_qt::Threading for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert!(structures.qobjects[0].worker);
+    }
+
     #[test]
     fn test_create_invalid_structures() {
         assert_parse_errors! {
@@ -329,6 +529,63 @@ mod tests {
                     }
                 }
             }
+
+            {
+                // Duplicate signal
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qsignal]
+                        fn ready(self: Pin<&mut MyObject>);
+
+                        #[qsignal]
+                        fn ready(self: Pin<&mut MyObject>);
+                    }
+                }
+            }
+
+            {
+                // Signal with the same name as a method
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qinvokable]
+                        fn ready(self: Pin<&mut MyObject>);
+
+                        #[qsignal]
+                        fn ready(self: Pin<&mut MyObject>);
+                    }
+                }
+            }
         }
     }
+
+    #[test]
+    fn test_create_structures_reports_all_errors() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn test_fn(self: Pin<&mut UnknownObject>);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut OtherUnknownObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let error = Structures::new(&parser.cxx_qt_data).err().unwrap();
+        assert_eq!(error.into_iter().count(), 2);
+    }
 }
@@ -8,21 +8,26 @@ use crate::naming::Name;
 use crate::parser::constructor::Constructor;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::property::ParsedQProperty;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
 use proc_macro2::Ident;
-use syn::Result;
+use syn::{Error, Result};
 
 /// The StructuredQObject contains the parsed QObject and all members.
 /// This includes QEnums, QSignals, methods, etc.
 pub struct StructuredQObject<'a> {
     pub declaration: &'a ParsedQObject,
+    pub properties: Vec<&'a ParsedQProperty>,
     pub qenums: Vec<&'a ParsedQEnum>,
     pub methods: Vec<&'a ParsedMethod>,
     pub inherited_methods: Vec<&'a ParsedInheritedMethod>,
     pub signals: Vec<&'a ParsedSignal>,
     pub constructors: Vec<&'a Constructor>,
     pub threading: bool,
+    pub new_cpp_object: bool,
+    pub worker: bool,
+    pub interior_mutability: bool,
 }
 
 fn lookup<T>(invokables: &[T], id: &Ident, name_getter: impl Fn(&T) -> &Name) -> Option<Name> {
@@ -38,16 +43,20 @@ impl<'a> StructuredQObject<'a> {
         self.declaration.name.rust_unqualified() == ident
     }
 
-    /// Creates a [StructuredQObject] from a [ParsedQObject] with empty enum, method and signal collections
+    /// Creates a [StructuredQObject] from a [ParsedQObject] with all of its properties and empty enum, method and signal collections
     pub fn from_qobject(qobject: &'a ParsedQObject) -> Self {
         Self {
             declaration: qobject,
+            properties: qobject.properties.iter().collect(),
             qenums: vec![],
             methods: vec![],
             inherited_methods: vec![],
             signals: vec![],
             constructors: vec![],
             threading: false,
+            new_cpp_object: false,
+            worker: false,
+            interior_mutability: false,
         }
     }
 
@@ -64,6 +73,22 @@ impl<'a> StructuredQObject<'a> {
             .ok_or_else(|| not_found_error("Signal", id))
     }
 
+    /// Returns an error if a method or signal with the provided Rust ident was already added,
+    /// as they would generate conflicting functions on the QObject
+    pub fn check_unique_name(&self, id: &Ident) -> Result<()> {
+        if self.method_lookup(id).is_ok() || self.signal_lookup(id).is_ok() {
+            Err(Error::new_spanned(
+                id,
+                format!(
+                    "A method or signal with name `{id}` is already declared on QObject `{qobject}`!",
+                    qobject = self.declaration.name.rust_unqualified()
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
     #[cfg(test)]
     pub fn mock(obj: &'a ParsedQObject) -> Self {
         Self::from_qobject(obj)
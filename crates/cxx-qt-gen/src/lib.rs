@@ -7,21 +7,70 @@
 #![deny(missing_docs)]
 
 //! The cxx-qt-gen crate provides methods for generated C++ and Rust code from a TokenStream.
+//!
+//! A bridge is first parsed into a [Parser], from which the C++ and Rust code is generated
+//! as [GeneratedCppBlocks] and [GeneratedRustBlocks], which are then written out with
+//! [write_cpp] and [write_rust]. Tooling which only needs to know the API that a bridge exposes
+//! to C++ and QML can describe it with [BridgeMetadata] instead.
+//!
+//! ```
+//! use cxx_qt_gen::{
+//!     write_cpp_with_style, write_rust, BridgeMetadata, GeneratedCppBlocks, GeneratedRustBlocks,
+//!     Parser,
+//! };
+//!
+//! # fn main() -> cxx_qt_gen::Result<()> {
+//! let parser = Parser::from(syn::parse_quote! {
+//!     #[cxx_qt::bridge]
+//!     mod qobject {
+//!         extern "RustQt" {
+//!             #[qobject]
+//!             #[qproperty(i32, number)]
+//!             type MyObject = super::MyObjectRust;
+//!         }
+//!     }
+//! })?;
+//!
+//! let metadata = BridgeMetadata::from(&parser)?;
+//! assert_eq!(metadata.qobjects[0].properties[0].name, "number");
+//!
+//! // The include path is the path of the generated header without the extension
+//! let cpp = write_cpp_with_style(&GeneratedCppBlocks::from(&parser)?, "qobject", None);
+//! let rust = write_rust(&GeneratedRustBlocks::from(&parser)?, Some("qobject"));
+//! # let _ = (cpp, rust);
+//! # Ok(())
+//! # }
+//! ```
 
 mod generator;
+mod metadata;
 mod naming;
 mod parser;
+mod registry;
+pub mod snapshot;
 mod syntax;
 mod writer;
 
 pub use generator::{
+    cfg::{CfgEvaluator, CfgResult, UnsupportedCfgEvaluator},
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
     rust::GeneratedRustBlocks,
 };
+pub use metadata::{
+    BridgeMetadata, InvokableMetadata, ParameterMetadata, PropertyMetadata, QEnumMetadata,
+    QObjectMetadata, QmlMetadata, SignalMetadata, TypeMetadata,
+};
+pub use naming::camel_case_name;
 pub use parser::Parser;
+pub use registry::{RegisteredType, TypeRegistry};
 pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
-pub use writer::{cpp::write_cpp, rust::write_rust};
+pub use writer::{
+    cpp::{write_cpp, write_cpp_qobject_headers_with_style, write_cpp_with_style},
+    qmldocs::write_qml_docs,
+    rust::write_rust,
+};
 
+pub use clang_format::ClangFormatStyle;
 pub use syn::{Error, Result};
 
 #[cfg(test)]
@@ -0,0 +1,508 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A description of the API which a bridge exposes to C++ and QML.
+//!
+//! This is intended for tooling, such as documentation generators or API checkers,
+//! which need to know the QObjects of a bridge without depending on the generated code.
+
+use crate::{
+    generator::{naming::property::QPropertyNames, structuring::Structures},
+    naming::{
+        cpp::{syn_type_to_cpp_return_type, syn_type_to_cpp_type},
+        Name, TypeNames,
+    },
+    parser::{
+        doc_lines, method::MethodFields, parameter::ParsedFunctionParameter, qenum::ParsedQEnum,
+        Parser,
+    },
+};
+use quote::ToTokens;
+use syn::{Attribute, Result, ReturnType, Type};
+
+/// The description of all the QObjects and QEnums of a bridge
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BridgeMetadata {
+    /// The namespace of the bridge, from `#[cxx_qt::bridge(namespace = "...")]`
+    pub namespace: Option<String>,
+    /// The QObjects declared in the bridge, in the order of declaration
+    pub qobjects: Vec<QObjectMetadata>,
+    /// The QEnums of the bridge which are not associated with a QObject
+    pub qenums: Vec<QEnumMetadata>,
+}
+
+impl BridgeMetadata {
+    /// Create a [BridgeMetadata] from the given [Parser] object
+    pub fn from(parser: &Parser) -> Result<BridgeMetadata> {
+        let structures = Structures::new(&parser.cxx_qt_data)?;
+        let type_names = &parser.type_names;
+
+        let qobjects = structures
+            .qobjects
+            .iter()
+            .map(|structured_qobject| {
+                let qobject = structured_qobject.declaration;
+                let properties = qobject
+                    .properties
+                    .iter()
+                    .map(|property| {
+                        let names =
+                            QPropertyNames::try_from_property(property, structured_qobject)?;
+                        let flags = &property.flags;
+                        Ok(PropertyMetadata {
+                            name: property.name.cxx_unqualified(),
+                            rust_name: property.name.rust_unqualified().to_string(),
+                            ty: TypeMetadata::from_type(property.cxx_ty(), type_names)?,
+                            getter: names.getter.cxx_unqualified(),
+                            setter: names.setter.map(|setter| setter.cxx_unqualified()),
+                            notify: names.notify.map(|notify| notify.cxx_unqualified()),
+                            reset: names.reset.map(|reset| reset.cxx_unqualified()),
+                            constant: flags.constant,
+                            required: flags.required,
+                            is_final: flags.is_final,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let signals = structured_qobject
+                    .signals
+                    .iter()
+                    .map(|signal| {
+                        Ok(SignalMetadata {
+                            name: signal.name.cxx_unqualified(),
+                            rust_name: signal.name.rust_unqualified().to_string(),
+                            parameters: ParameterMetadata::from_parameters(
+                                &signal.parameters,
+                                type_names,
+                            )?,
+                            inherit: signal.inherit,
+                            private: signal.private,
+                            docs: docs(&signal.docs),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let invokables = structured_qobject
+                    .methods
+                    .iter()
+                    .filter(|method| method.is_qinvokable)
+                    .map(|method| InvokableMetadata::from_method(method, type_names))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(QObjectMetadata {
+                    name: qobject.name.cxx_unqualified(),
+                    rust_name: qobject.name.rust_unqualified().to_string(),
+                    namespace: namespace(&qobject.name),
+                    base_class: qobject.base_class.as_ref().map(ToString::to_string),
+                    qml: qobject
+                        .qml_metadata
+                        .as_ref()
+                        .map(|qml_metadata| QmlMetadata {
+                            name: qml_metadata.name.clone(),
+                            uncreatable: qml_metadata.uncreatable,
+                            singleton: qml_metadata.singleton,
+                        }),
+                    properties,
+                    signals,
+                    invokables,
+                    qenums: structured_qobject
+                        .qenums
+                        .iter()
+                        .map(|qenum| QEnumMetadata::from(*qenum))
+                        .collect(),
+                    docs: docs(&qobject.declaration.attrs),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(BridgeMetadata {
+            namespace: parser.cxx_qt_data.namespace.clone(),
+            qobjects,
+            qenums: parser
+                .cxx_qt_data
+                .qenums
+                .iter()
+                .filter(|qenum| qenum.qobject.is_none())
+                .map(QEnumMetadata::from)
+                .collect(),
+        })
+    }
+}
+
+/// The description of a QObject
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct QObjectMetadata {
+    /// The name of the C++ class
+    pub name: String,
+    /// The name of the Rust type
+    pub rust_name: String,
+    /// The C++ namespace of the class
+    pub namespace: Option<String>,
+    /// The base class from a `#[base = ...]` attribute, otherwise the class inherits from `QObject`
+    pub base_class: Option<String>,
+    /// How the type is registered with QML, if it has a `#[qml_element]` attribute
+    pub qml: Option<QmlMetadata>,
+    /// The properties declared with `#[qproperty]`
+    pub properties: Vec<PropertyMetadata>,
+    /// The signals declared with `#[qsignal]`, this does not include the notify signals of the properties
+    pub signals: Vec<SignalMetadata>,
+    /// The methods declared with `#[qinvokable]`
+    pub invokables: Vec<InvokableMetadata>,
+    /// The QEnums declared inside of the QObject
+    pub qenums: Vec<QEnumMetadata>,
+    /// The lines of the doc comment of the type
+    pub docs: Vec<String>,
+}
+
+/// How a QObject is registered with QML
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct QmlMetadata {
+    /// The name of the type in QML
+    pub name: String,
+    /// Whether the type has a `#[qml_uncreatable]` attribute
+    pub uncreatable: bool,
+    /// Whether the type has a `#[qml_singleton]` attribute
+    pub singleton: bool,
+}
+
+/// The description of a Q_PROPERTY
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PropertyMetadata {
+    /// The name of the property in C++ and QML
+    pub name: String,
+    /// The name of the property in Rust
+    pub rust_name: String,
+    /// The type of the property, for a property with a `cxx_type` this is the type exposed to Qt
+    pub ty: TypeMetadata,
+    /// The name of the C++ getter
+    pub getter: String,
+    /// The name of the C++ setter, if the property is writable
+    pub setter: Option<String>,
+    /// The name of the notify signal, if the property has one
+    pub notify: Option<String>,
+    /// The name of the C++ reset method, if the property has one
+    pub reset: Option<String>,
+    /// Whether the property is `CONSTANT`
+    pub constant: bool,
+    /// Whether the property is `REQUIRED`
+    pub required: bool,
+    /// Whether the property is `FINAL`
+    pub is_final: bool,
+}
+
+/// The description of a signal
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SignalMetadata {
+    /// The name of the signal in C++ and QML
+    pub name: String,
+    /// The name of the signal in Rust
+    pub rust_name: String,
+    /// The parameters of the signal, without the QObject
+    pub parameters: Vec<ParameterMetadata>,
+    /// Whether the signal is defined in the base class
+    pub inherit: bool,
+    /// Whether the signal is private
+    pub private: bool,
+    /// The lines of the doc comment of the signal, including the docs of its parameters
+    pub docs: Vec<String>,
+}
+
+/// The description of a Q_INVOKABLE
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InvokableMetadata {
+    /// The name of the method in C++ and QML
+    pub name: String,
+    /// The name of the method in Rust
+    pub rust_name: String,
+    /// The parameters of the method, without the QObject
+    pub parameters: Vec<ParameterMetadata>,
+    /// The return type of the method, if it returns a value
+    pub return_type: Option<TypeMetadata>,
+    /// Whether the method takes the QObject mutably, otherwise it is a const method in C++
+    pub mutable: bool,
+    /// The lines of the doc comment of the method
+    pub docs: Vec<String>,
+}
+
+impl InvokableMetadata {
+    fn from_method(method: &MethodFields, type_names: &TypeNames) -> Result<Self> {
+        let return_type = match &method.method.sig.output {
+            ReturnType::Type(_, ty) => {
+                syn_type_to_cpp_return_type(&method.method.sig.output, type_names)?.map(|cxx| {
+                    TypeMetadata {
+                        rust: type_to_string(ty),
+                        cxx,
+                    }
+                })
+            }
+            ReturnType::Default => None,
+        };
+
+        Ok(Self {
+            name: method.name.cxx_unqualified(),
+            rust_name: method.name.rust_unqualified().to_string(),
+            parameters: ParameterMetadata::from_parameters(&method.parameters, type_names)?,
+            return_type,
+            mutable: method.mutable,
+            docs: docs(&method.method.attrs),
+        })
+    }
+}
+
+/// The description of a parameter of a signal or invokable
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ParameterMetadata {
+    /// The name of the parameter in C++ and QML
+    pub name: String,
+    /// The type of the parameter
+    pub ty: TypeMetadata,
+}
+
+impl ParameterMetadata {
+    fn from_parameters(
+        parameters: &[ParsedFunctionParameter],
+        type_names: &TypeNames,
+    ) -> Result<Vec<Self>> {
+        parameters
+            .iter()
+            .map(|parameter| {
+                Ok(Self {
+                    name: parameter.cxx_ident(),
+                    ty: TypeMetadata::from_type(&parameter.ty, type_names)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A type as it is written in the bridge and as it is exposed to C++
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TypeMetadata {
+    /// The Rust type, eg `&QString`
+    pub rust: String,
+    /// The C++ type, eg `QString const&`
+    pub cxx: String,
+}
+
+impl TypeMetadata {
+    fn from_type(ty: &Type, type_names: &TypeNames) -> Result<Self> {
+        Ok(Self {
+            rust: type_to_string(ty),
+            cxx: syn_type_to_cpp_type(ty, type_names)?,
+        })
+    }
+}
+
+/// The description of a QEnum
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct QEnumMetadata {
+    /// The name of the enum in C++ and QML
+    pub name: String,
+    /// The name of the enum in Rust
+    pub rust_name: String,
+    /// The C++ namespace of the enum
+    pub namespace: Option<String>,
+    /// The names of the variants
+    pub variants: Vec<String>,
+    /// The lines of the doc comment of the enum
+    pub docs: Vec<String>,
+}
+
+impl From<&ParsedQEnum> for QEnumMetadata {
+    fn from(qenum: &ParsedQEnum) -> Self {
+        Self {
+            name: qenum.name.cxx_unqualified(),
+            rust_name: qenum.name.rust_unqualified().to_string(),
+            namespace: namespace(&qenum.name),
+            variants: qenum.variants.iter().map(ToString::to_string).collect(),
+            docs: docs(&qenum.item.attrs),
+        }
+    }
+}
+
+/// The namespace of the name, if it is not empty
+fn namespace(name: &Name) -> Option<String> {
+    name.namespace()
+        .filter(|namespace| !namespace.is_empty())
+        .map(str::to_owned)
+}
+
+/// The lines of the doc comments in the attributes, without the space which follows `///`
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    doc_lines(attrs)
+        .into_iter()
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect()
+}
+
+/// Format a type like it is written in Rust, rather than with the spacing of a token stream
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" < ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_bridge_metadata() {
+        let parser = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                extern "RustQt" {
+                    /// A counter
+                    #[qobject]
+                    #[namespace = "cxx_qt::my_object"]
+                    #[qml_element = "Counter"]
+                    #[qproperty(i32, count)]
+                    #[qproperty(QString, label, cxx_name = "displayLabel", READ, CONSTANT)]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    /// Emitted when the counter wraps
+                    #[qsignal]
+                    fn wrapped(
+                        self: Pin<&mut MyObject>,
+                        #[cxx_name = "newValue"] new_value: i32,
+                        label: &QString,
+                    );
+
+                    /// Increment the counter
+                    #[qinvokable]
+                    fn increment(self: Pin<&mut MyObject>, amount: i32) -> bool;
+
+                    #[qinvokable]
+                    fn label_text(self: &MyObject) -> QString;
+
+                    fn not_invokable(self: &MyObject);
+                }
+
+                #[qenum]
+                #[namespace = "cxx_qt::my_object"]
+                enum Mode {
+                    Up,
+                    Down,
+                }
+            }
+        })
+        .unwrap();
+
+        let metadata = BridgeMetadata::from(&parser).unwrap();
+        assert!(metadata.namespace.is_none());
+        assert_eq!(metadata.qobjects.len(), 1);
+        assert_eq!(metadata.qenums.len(), 1);
+        assert_eq!(metadata.qenums[0].name, "Mode");
+        assert_eq!(
+            metadata.qenums[0].namespace.as_deref(),
+            Some("cxx_qt::my_object")
+        );
+        assert_eq!(metadata.qenums[0].variants, vec!["Up", "Down"]);
+
+        let qobject = &metadata.qobjects[0];
+        assert_eq!(qobject.name, "MyObject");
+        assert_eq!(qobject.rust_name, "MyObject");
+        assert_eq!(qobject.namespace.as_deref(), Some("cxx_qt::my_object"));
+        assert!(qobject.base_class.is_none());
+        assert_eq!(
+            qobject.qml,
+            Some(QmlMetadata {
+                name: "Counter".to_owned(),
+                uncreatable: false,
+                singleton: false,
+            })
+        );
+        assert_eq!(qobject.docs, vec!["A counter"]);
+
+        assert_eq!(qobject.properties.len(), 2);
+        let count = &qobject.properties[0];
+        assert_eq!(count.name, "count");
+        assert_eq!(count.ty.rust, "i32");
+        assert_eq!(count.ty.cxx, "::std::int32_t");
+        assert_eq!(count.getter, "getCount");
+        assert_eq!(count.setter.as_deref(), Some("setCount"));
+        assert_eq!(count.notify.as_deref(), Some("countChanged"));
+        assert!(!count.constant);
+        let label = &qobject.properties[1];
+        assert_eq!(label.name, "displayLabel");
+        assert_eq!(label.rust_name, "label");
+        assert_eq!(label.ty.cxx, "QString");
+        assert!(label.setter.is_none());
+        assert!(label.notify.is_none());
+        assert!(label.constant);
+
+        assert_eq!(qobject.signals.len(), 1);
+        let signal = &qobject.signals[0];
+        assert_eq!(signal.name, "wrapped");
+        assert_eq!(signal.docs, vec!["Emitted when the counter wraps"]);
+        assert_eq!(
+            signal.parameters,
+            vec![
+                ParameterMetadata {
+                    name: "newValue".to_owned(),
+                    ty: TypeMetadata {
+                        rust: "i32".to_owned(),
+                        cxx: "::std::int32_t".to_owned(),
+                    },
+                },
+                ParameterMetadata {
+                    name: "label".to_owned(),
+                    ty: TypeMetadata {
+                        rust: "&QString".to_owned(),
+                        cxx: "QString const&".to_owned(),
+                    },
+                },
+            ]
+        );
+
+        assert_eq!(qobject.invokables.len(), 2);
+        let increment = &qobject.invokables[0];
+        assert_eq!(increment.name, "increment");
+        assert!(increment.mutable);
+        assert_eq!(increment.docs, vec!["Increment the counter"]);
+        assert_eq!(
+            increment.return_type.as_ref().map(|ty| ty.cxx.as_str()),
+            Some("bool")
+        );
+        let label_text = &qobject.invokables[1];
+        assert_eq!(label_text.name, "labelText");
+        assert!(!label_text.mutable);
+        assert_eq!(
+            label_text.return_type.as_ref().map(|ty| ty.rust.as_str()),
+            Some("QString")
+        );
+    }
+
+    #[test]
+    fn test_type_to_string() {
+        let ty: Type = parse_quote! { Pin<&mut cxx_qt_lib::QList<QString, i32>> };
+        assert_eq!(
+            type_to_string(&ty),
+            "Pin<&mut cxx_qt_lib::QList<QString, i32>>"
+        );
+    }
+}
@@ -7,9 +7,8 @@ use crate::naming::TypeNames;
 use crate::syntax::lifetimes::err_unsupported_type;
 use quote::ToTokens;
 use syn::{
-    spanned::Spanned, Attribute, Error, Expr, ExprLit, GenericArgument, Lit, PathArguments,
-    PathSegment, Result, ReturnType, Type, TypeArray, TypeBareFn, TypePtr, TypeReference,
-    TypeSlice,
+    Attribute, Error, Expr, ExprLit, GenericArgument, Lit, PathArguments, PathSegment, Result,
+    ReturnType, Type, TypeArray, TypeBareFn, TypePtr, TypeReference, TypeSlice,
 };
 
 pub(crate) fn err_unsupported_item(item: &impl ToTokens) -> Error {
@@ -51,8 +50,8 @@ pub(crate) fn syn_type_to_cpp_return_type(
                     let mut args = path_argument_to_string(&segment.arguments, type_names)?
                         .unwrap_or_default();
                     if args.len() != 1 {
-                        return Err(Error::new(
-                            return_ty.span(),
+                        return Err(Error::new_spanned(
+                            return_ty,
                             "Result must have one argument!",
                         ));
                     }
@@ -98,11 +97,11 @@ pub(crate) fn syn_type_to_cpp_type(ty: &Type, type_names: &TypeNames) -> Result<
             {
                 len.base10_parse::<usize>()?
             } else {
-                return Err(Error::new(ty.span(), "Array length must be an integer!"));
+                return Err(Error::new_spanned(len, "Array length must be an integer!"));
             };
 
             if len == 0 {
-                return Err(Error::new(ty.span(), "Array length must be > 0!"));
+                return Err(Error::new_spanned(len, "Array length must be > 0!"));
             }
 
             Ok(format!(
@@ -140,8 +139,8 @@ pub(crate) fn syn_type_to_cpp_type(ty: &Type, type_names: &TypeNames) -> Result<
                 let first = ty_strings.first().unwrap();
                 Ok(first.to_owned())
             } else {
-                Err(Error::new(
-                    ty.span(),
+                Err(Error::new_spanned(
+                    ty,
                     "Paths with multiple segments are not supported in types!",
                 ))
             }
@@ -186,8 +185,8 @@ pub(crate) fn syn_type_to_cpp_type(ty: &Type, type_names: &TypeNames) -> Result<
 fn generic_argument_to_string(generic: &GenericArgument, type_names: &TypeNames) -> Result<String> {
     match generic {
         GenericArgument::Type(ty) => syn_type_to_cpp_type(ty, type_names),
-        _other => Err(Error::new(
-            generic.span(),
+        _other => Err(Error::new_spanned(
+            generic,
             "Unsupported GenericArgument type!",
         )),
     }
@@ -227,7 +226,7 @@ fn path_segment_to_string(segment: &PathSegment, type_names: &TypeNames) -> Resu
                 path_argument_to_string(&segment.arguments, type_names)?.unwrap_or_else(Vec::new);
 
             if args.len() != 1 {
-                return Err(Error::new(segment.span(), "Pin must have one argument!"));
+                return Err(Error::new_spanned(segment, "Pin must have one argument!"));
             }
             return Ok(args.pop().unwrap());
         }
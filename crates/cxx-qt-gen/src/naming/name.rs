@@ -22,6 +22,14 @@ impl AutoCamel {
     }
 }
 
+/// Returns the camelCase name which C++ and QML use for a Rust identifier without a `cxx_name`,
+/// e.g. `first_name` becomes `firstName` like the name of a property.
+pub fn camel_case_name(ident: &Ident) -> String {
+    Name::new(ident.clone())
+        .with_options(None, None, AutoCamel::Enabled)
+        .cxx_unqualified()
+}
+
 /// This struct contains all names a certain syntax element may have
 ///
 /// This includes the rust_name, cxx_name, as well as qualifications like
@@ -357,4 +365,10 @@ mod tests {
         assert!(name.module().is_none());
         assert!(name.require_module().is_err());
     }
+
+    #[test]
+    fn test_camel_case_name() {
+        assert_eq!(camel_case_name(&format_ident!("first_name")), "firstName");
+        assert_eq!(camel_case_name(&format_ident!("age")), "age");
+    }
 }
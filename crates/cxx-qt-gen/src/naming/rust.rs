@@ -5,7 +5,6 @@
 
 use crate::naming::TypeNames;
 use crate::syntax::lifetimes::err_unsupported_type;
-use syn::spanned::Spanned;
 use syn::{
     Error, GenericArgument, PathArguments, PathSegment, Result, ReturnType, Type, TypePath,
     TypeReference,
@@ -29,7 +28,10 @@ fn qualify_type_path(ty_path: &TypePath, type_names: &TypeNames) -> Result<Type>
                 if let GenericArgument::Type(ty) = arg {
                     *ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
                 } else {
-                    return Err(Error::new(arg.span(), "Unsupported GenericArgument type!"));
+                    return Err(Error::new_spanned(
+                        &arg,
+                        "Unsupported GenericArgument type!",
+                    ));
                 }
             }
         }
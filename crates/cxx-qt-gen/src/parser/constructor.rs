@@ -46,7 +46,7 @@ impl Constructor {
     fn parse_argument_list(ty: Type) -> Result<Vec<Type>> {
         Ok(match ty {
             Type::Tuple(tuple) => tuple.elems.into_iter().collect(),
-            _ => return Err(Error::new(ty.span(), "Expected a tuple as argument list!\nNote that a tuple of a single type needs to use a trailing comma, e.g. (i32,)"))
+            _ => return Err(Error::new_spanned(&ty, "Expected a tuple as argument list!\nNote that a tuple of a single type needs to use a trailing comma, e.g. (i32,)"))
         })
     }
 
@@ -9,14 +9,18 @@ use crate::naming::cpp::err_unsupported_item;
 use crate::{
     parser::{
         externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, require_attributes, signals::ParsedSignal,
+        qenum::ParsedQEnum, qobject::ParsedQObject, require_attributes,
+        sharedstruct::ParsedSharedStruct, signals::ParsedSignal, ErrorCollector,
     },
     syntax::{
         attribute::attribute_get_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
         path::path_compare_str, safety::Safety,
     },
 };
-use syn::{ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
+use syn::{
+    ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, ItemStruct, ItemType,
+    Meta, Result,
+};
 
 pub struct ParsedCxxQtData {
     /// Map of the QObjects defined in the module that will be used for code generation
@@ -26,6 +30,8 @@ pub struct ParsedCxxQtData {
     pub qobjects: Vec<ParsedQObject>,
     /// List of QEnums defined in the module, that aren't associated with a QObject
     pub qenums: Vec<ParsedQEnum>,
+    /// List of the shared structs which are converted to and from a QVariantMap
+    pub shared_structs: Vec<ParsedSharedStruct>,
     /// List of methods and Q_INVOKABLES found
     pub methods: Vec<ParsedMethod>,
     /// List of the Q_SIGNALS found
@@ -42,6 +48,8 @@ pub struct ParsedCxxQtData {
     pub trait_impls: Vec<TraitImpl>,
     /// The ident of the module, used for mappings
     pub module_ident: Ident,
+    /// Aliases of the Rust types of the instantiations of generic QObjects
+    pub generic_instantiations: Vec<ItemType>,
 }
 
 impl ParsedCxxQtData {
@@ -50,6 +58,7 @@ impl ParsedCxxQtData {
         Self {
             qobjects: Vec::new(),
             qenums: vec![],
+            shared_structs: vec![],
             methods: vec![],
             signals: vec![],
             inherited_methods: vec![],
@@ -58,6 +67,7 @@ impl ParsedCxxQtData {
             extern_cxxqt_blocks: Vec::<ParsedExternCxxQt>::default(),
             module_ident,
             namespace,
+            generic_instantiations: vec![],
         }
     }
 
@@ -69,6 +79,7 @@ impl ParsedCxxQtData {
             Item::Impl(imp) => self.parse_impl(imp),
             Item::ForeignMod(foreign_mod) => self.parse_foreign_mod(foreign_mod),
             Item::Enum(enum_item) => self.parse_enum(enum_item),
+            Item::Struct(struct_item) => self.parse_struct(struct_item),
             Item::Macro(mac) => self.parse_macro(mac),
             _ => Ok(Some(item)),
         }
@@ -94,6 +105,19 @@ impl ParsedCxxQtData {
         }
     }
 
+    fn parse_struct(&mut self, mut item: ItemStruct) -> Result<Option<Item>> {
+        if attribute_get_path(&item.attrs, &["qvariant_map"]).is_some() {
+            let shared_struct = ParsedSharedStruct::parse(
+                &mut item,
+                self.namespace.as_deref(),
+                &self.module_ident,
+            )?;
+            self.shared_structs.push(shared_struct);
+        }
+        // The struct itself is still a CXX shared struct
+        Ok(Some(Item::Struct(item)))
+    }
+
     fn parse_macro(&mut self, item: ItemMacro) -> Result<Option<Item>> {
         if path_compare_str(&item.mac.path, &["qnamespace"]) {
             let qnamespace = ParsedQNamespace::parse(item)?;
@@ -141,45 +165,54 @@ impl ParsedCxxQtData {
             Safety::Unsafe
         };
 
+        // Items are parsed independently, so report the errors of all items at once
+        let mut errors = ErrorCollector::default();
         for item in foreign_mod.items.drain(..) {
-            match item {
-                ForeignItem::Fn(foreign_fn) => {
-                    // Test if the function is a signal
-                    if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
-                        let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
-                        self.signals.push(parsed_signal_method);
-
-                        // Test if the function is an inheritance method
-                        //
-                        // Note that we need to test for qsignal first as qsignals have their own inherit meaning
-                    } else if attribute_get_path(&foreign_fn.attrs, &["inherit"]).is_some() {
-                        let parsed_inherited_method =
-                            ParsedInheritedMethod::parse(foreign_fn, safe_call)?;
-
-                        self.inherited_methods.push(parsed_inherited_method);
-                        // Remaining methods are either C++ methods or invokables
-                    } else {
-                        let parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
-                        self.methods.push(parsed_method);
-                    }
+            let result = self.parse_rust_qt_item(item, safe_call, namespace.as_deref());
+            errors.collect(result);
+        }
+        errors.finish()
+    }
+
+    fn parse_rust_qt_item(
+        &mut self,
+        item: ForeignItem,
+        safe_call: Safety,
+        namespace: Option<&str>,
+    ) -> Result<()> {
+        match item {
+            ForeignItem::Fn(foreign_fn) => {
+                // Test if the function is a signal
+                if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
+                    let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                    self.signals.push(parsed_signal_method);
+
+                    // Test if the function is an inheritance method
+                    //
+                    // Note that we need to test for qsignal first as qsignals have their own inherit meaning
+                } else if attribute_get_path(&foreign_fn.attrs, &["inherit"]).is_some() {
+                    let parsed_inherited_method =
+                        ParsedInheritedMethod::parse(foreign_fn, safe_call)?;
+
+                    self.inherited_methods.push(parsed_inherited_method);
+                    // Remaining methods are either C++ methods or invokables
+                } else {
+                    let parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
+                    self.methods.push(parsed_method);
                 }
-                ForeignItem::Verbatim(tokens) => {
-                    let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
+            }
+            ForeignItem::Verbatim(tokens) => {
+                let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
 
-                    // Load the QObject
-                    let qobject = ParsedQObject::parse(
-                        foreign_alias,
-                        namespace.as_deref(),
-                        &self.module_ident,
-                    )?;
+                // Load the QObject
+                let qobject = ParsedQObject::parse(foreign_alias, namespace, &self.module_ident)?;
 
-                    // Note that we assume a compiler error will occur later
-                    // if you had two structs with the same name
-                    self.qobjects.push(qobject);
-                }
-                // Const Macro, Type are unsupported in extern "RustQt" for now
-                _ => return Err(err_unsupported_item(&item)),
+                // Note that we assume a compiler error will occur later
+                // if you had two structs with the same name
+                self.qobjects.push(qobject);
             }
+            // Const Macro, Type are unsupported in extern "RustQt" for now
+            _ => return Err(err_unsupported_item(&item)),
         }
         Ok(())
     }
@@ -535,6 +568,39 @@ mod tests {
         assert!(matches!(result, Some(Item::Impl(_))));
     }
 
+    #[test]
+    fn test_parse_qvariant_map_struct() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let result = cxxqtdata
+            .parse_cxx_qt_item(parse_quote! {
+                #[qvariant_map]
+                struct Point {
+                    x: i32,
+                }
+            })
+            .unwrap();
+        assert_eq!(cxxqtdata.shared_structs.len(), 1);
+        assert_eq!(
+            result,
+            Some(parse_quote! {
+                struct Point {
+                    x: i32,
+                }
+            })
+        );
+
+        let result = cxxqtdata
+            .parse_cxx_qt_item(parse_quote! {
+                struct Size {
+                    width: i32,
+                }
+            })
+            .unwrap();
+        assert_eq!(cxxqtdata.shared_structs.len(), 1);
+        assert!(matches!(result, Some(Item::Struct(_))));
+    }
+
     #[test]
     fn test_parse_namespaced_qenum() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -4,10 +4,24 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::{externqobject::ParsedExternQObject, require_attributes, signals::ParsedSignal},
-    syntax::{attribute::attribute_get_path, expr::expr_to_string, safety::Safety},
+    parser::{
+        externqobject::ParsedExternQObject,
+        property::{FlagState, ParsedQProperty},
+        require_attributes,
+        signals::ParsedSignal,
+    },
+    syntax::{
+        attribute::attribute_get_path, expr::expr_to_string, path::path_compare_str, safety::Safety,
+    },
 };
-use syn::{spanned::Spanned, Error, ForeignItem, Ident, ItemForeignMod, Result, Token};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Error, ForeignItem, ForeignItemFn, Ident, ItemForeignMod, Result, Token};
+
+/// The Rust name of a C++ accessor, eg `is_checked` for `isChecked`
+fn rust_name_from_cxx_name(ident: &Ident) -> Ident {
+    format_ident!("{}", ident.to_string().to_case(Case::Snake))
+}
 
 /// Representation of an extern "C++Qt" block
 #[derive(Default)]
@@ -71,13 +85,27 @@ impl ParsedExternCxxQt {
                 ForeignItem::Type(foreign_ty) => {
                     // Test that there is a #[qobject] attribute on any type
                     if attribute_get_path(&foreign_ty.attrs, &["qobject"]).is_some() {
+                        // Properties of an existing QObject are wrappers around its accessors
+                        for attr in foreign_ty
+                            .attrs
+                            .iter()
+                            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
+                        {
+                            let property = ParsedQProperty::parse(attr)?;
+                            extern_cxx_block.parse_property(
+                                &property,
+                                &foreign_ty.ident,
+                                safe_call,
+                            )?;
+                        }
+
                         let extern_ty =
                             ParsedExternQObject::parse(foreign_ty, module_ident, parent_namespace)?;
                         // Pass through types separately for generation
                         extern_cxx_block.qobjects.push(extern_ty);
                     } else {
-                        return Err(Error::new(
-                            foreign_ty.span(),
+                        return Err(Error::new_spanned(
+                            &foreign_ty,
                             "Types in extern \"C++Qt\" blocks must be tagged with #[qobject]!, use an extern \"C++\" block for non QObject types",
                         ));
                     }
@@ -90,11 +118,91 @@ impl ParsedExternCxxQt {
 
         Ok(extern_cxx_block)
     }
+
+    /// Declare the accessors of a property of an existing QObject
+    ///
+    /// The names of the accessors follow the Qt conventions, eg `value` and `setValue`,
+    /// or are the C++ names given with the flags, eg `READ = isChecked`.
+    fn parse_property(
+        &mut self,
+        property: &ParsedQProperty,
+        qobject_ident: &Ident,
+        safe_call: Safety,
+    ) -> Result<()> {
+        let ty = &property.ty;
+        let cxx_property_name = property.name.cxx_unqualified();
+        let rust_property_name = property.name.rust_unqualified();
+        let unsafety = if safe_call == Safety::Unsafe {
+            Some(quote! { unsafe })
+        } else {
+            None
+        };
+
+        // Custom flags give the C++ name of the accessor
+        let accessor_name =
+            |flag: &FlagState, auto_cxx_name: String, auto_rust_name: Ident| match flag {
+                FlagState::Auto => (auto_cxx_name, auto_rust_name),
+                FlagState::Custom(ident) => (ident.to_string(), rust_name_from_cxx_name(ident)),
+            };
+
+        let (cxx_name, rust_name) = accessor_name(
+            &property.flags.read,
+            cxx_property_name.to_owned(),
+            rust_property_name.clone(),
+        );
+        let getter: ForeignItemFn = parse_quote! {
+            #[cxx_name = #cxx_name]
+            #unsafety fn #rust_name(self: &#qobject_ident) -> #ty;
+        };
+        self.passthrough_items.push(ForeignItem::Fn(getter));
+
+        if let Some(write) = &property.flags.write {
+            let (cxx_name, rust_name) = accessor_name(
+                write,
+                format!("set{}", cxx_property_name.to_case(Case::Pascal)),
+                format_ident!("set_{rust_property_name}"),
+            );
+            let setter: ForeignItemFn = parse_quote! {
+                #[cxx_name = #cxx_name]
+                #unsafety fn #rust_name(self: Pin<&mut #qobject_ident>, value: &#ty);
+            };
+            self.passthrough_items.push(ForeignItem::Fn(setter));
+        }
+
+        if let Some(reset) = &property.flags.reset {
+            let (cxx_name, rust_name) = (reset.to_string(), rust_name_from_cxx_name(reset));
+            let reset: ForeignItemFn = parse_quote! {
+                #[cxx_name = #cxx_name]
+                #unsafety fn #rust_name(self: Pin<&mut #qobject_ident>);
+            };
+            self.passthrough_items.push(ForeignItem::Fn(reset));
+        }
+
+        // The accessors already use the C++ type of the existing property
+        if let Some(mapping) = &property.mapping {
+            return Err(Error::new_spanned(
+                &mapping.map_with,
+                "A property in an extern \"C++Qt\" block can't be mapped with cxx_type and map_with!",
+            ));
+        }
+
+        // The parameters of the notify signal differ between properties, eg `valueChanged(int)`
+        // or `widthChanged()`, so it has to be declared with a #[qsignal] instead
+        if let Some(FlagState::Custom(notify)) = &property.flags.notify {
+            return Err(Error::new_spanned(
+                notify,
+                "The notify signal of a property in an extern \"C++Qt\" block can't be specified! Declare it with #[qsignal] instead",
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::assert_tokens_eq;
     use quote::format_ident;
 
     use syn::parse_quote;
@@ -161,6 +269,114 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_none());
     }
 
+    #[test]
+    fn test_extern_cxxqt_type_qproperty() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, value)]
+                    #[qproperty(bool, checked, READ = isChecked, WRITE = setChecked, RESET = resetChecked)]
+                    type QSlider;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.qobjects.len(), 1);
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 5);
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[0],
+            quote! {
+                #[cxx_name = "value"]
+                fn value(self: &QSlider) -> i32;
+            },
+        );
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[1],
+            quote! {
+                #[cxx_name = "setValue"]
+                fn set_value(self: Pin<&mut QSlider>, value: &i32);
+            },
+        );
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[2],
+            quote! {
+                #[cxx_name = "isChecked"]
+                fn is_checked(self: &QSlider) -> bool;
+            },
+        );
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[4],
+            quote! {
+                #[cxx_name = "resetChecked"]
+                fn reset_checked(self: Pin<&mut QSlider>);
+            },
+        );
+
+        // Notify signals are declared separately
+        assert!(extern_cxx_qt.signals.is_empty());
+    }
+
+    #[test]
+    fn test_extern_cxxqt_type_qproperty_custom_notify() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, value, READ, NOTIFY = valueChanged)]
+                    type QSlider;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        );
+        assert!(extern_cxx_qt.is_err());
+    }
+
+    #[test]
+    fn test_extern_cxxqt_type_qproperty_unsafe_block() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, value, READ)]
+                    type QSlider;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 1);
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[0],
+            quote! {
+                #[cxx_name = "value"]
+                unsafe fn value(self: &QSlider) -> i32;
+            },
+        );
+    }
+
+    #[test]
+    fn test_extern_cxxqt_type_qproperty_mapped() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(Decimal, value, cxx_type = "QString", map_with = "DecimalAsQString")]
+                    type QLineEdit;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        );
+        assert!(extern_cxx_qt.is_err());
+    }
+
     #[test]
     fn test_extern_cxxqt_type_non_type() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
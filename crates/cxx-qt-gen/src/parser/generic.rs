@@ -0,0 +1,581 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Monomorphise generic QObjects into a QObject for each of the types listed in `#[instantiate(...)]`.
+//!
+//! ```ignore
+//! extern "RustQt" {
+//!     #[qobject]
+//!     #[qproperty(T, value)]
+//!     #[instantiate(f64, Names = QString)]
+//!     type ValueModel<T> = super::ValueModelRust<T>;
+//! }
+//! ```
+//!
+//! declares the QObjects `ValueModelF64` and `Names`, whose Rust types are `ValueModelRust<f64>`
+//! and `ValueModelRust<QString>`. The methods, signals and trait implementations of `ValueModel<T>`
+//! are declared for each of the QObjects.
+
+use crate::syntax::path::path_compare_str;
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, FnArg, ForeignItem, ForeignItemFn, GenericArgument, GenericParam, Ident, Item,
+    ItemForeignMod, ItemImpl, ItemMod, ItemType, PathArguments, Result, ReturnType, Token, Type,
+};
+
+/// A QObject which is declared for one of the types of a generic QObject
+struct Instantiation {
+    /// The name of the QObject, eg `ValueModelF64`
+    ident: Ident,
+    /// The type which replaces the type parameter, eg `f64`
+    ty: Type,
+}
+
+/// An entry of `#[instantiate(...)]`, which is either a type or a named type like `Name = T`
+struct InstantiationArg {
+    ident: Option<Ident>,
+    ty: Type,
+}
+
+impl Parse for InstantiationArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident = input.parse()?;
+            let _equals: Token![=] = input.parse()?;
+            Some(ident)
+        } else {
+            None
+        };
+        Ok(Self {
+            ident,
+            ty: input.parse()?,
+        })
+    }
+}
+
+/// A generic QObject with the QObjects it is instantiated as
+struct GenericQObject {
+    /// The name of the generic QObject, eg `ValueModel`
+    ident: Ident,
+    /// The type parameter, eg `T`
+    param: Ident,
+    instantiations: Vec<Instantiation>,
+}
+
+/// The name of a type in upper camel case, eg `F64` for `f64` and `QListI32` for `QList<i32>`
+fn type_name(ty: &Type) -> Result<String> {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            let ident = segment.ident.to_string();
+            let mut name = ident[..1].to_uppercase() + &ident[1..];
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                for argument in &arguments.args {
+                    if let GenericArgument::Type(ty) = argument {
+                        name.push_str(&type_name(ty)?);
+                    }
+                }
+            }
+            return Ok(name);
+        }
+    }
+
+    Err(Error::new_spanned(
+        ty,
+        "The name of the QObject can't be derived from this type, name it like `#[instantiate(MyName = T)]`",
+    ))
+}
+
+/// Replace each occurrence of the identifier in the tokens with the replacement
+fn substitute(tokens: TokenStream, ident: &Ident, replacement: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ref token_ident) if token_ident == ident => replacement.clone(),
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    substitute(group.stream(), ident, replacement),
+                );
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into_token_stream()
+            }
+            other => other.into_token_stream(),
+        })
+        .collect()
+}
+
+/// Replace `ValueModel<f64>` with the name of the instantiation in the type
+fn replace_generic_type(ty: &mut Type, generic: &GenericQObject, instantiation: &Instantiation) {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            if let [segment] = type_path
+                .path
+                .segments
+                .iter_mut()
+                .collect::<Vec<_>>()
+                .as_mut_slice()
+            {
+                if segment.ident == generic.ident {
+                    segment.ident = instantiation.ident.clone();
+                    segment.arguments = PathArguments::None;
+                } else if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    for argument in &mut arguments.args {
+                        if let GenericArgument::Type(ty) = argument {
+                            replace_generic_type(ty, generic, instantiation);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(reference) => {
+            replace_generic_type(&mut reference.elem, generic, instantiation)
+        }
+        Type::Ptr(ptr) => replace_generic_type(&mut ptr.elem, generic, instantiation),
+        _ => {}
+    }
+}
+
+/// Returns true if the tokens mention the generic QObject
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token_ident) => token_ident == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+impl GenericQObject {
+    /// Parse a generic QObject, returning None if the type isn't generic
+    fn parse(alias: &ItemType) -> Result<Option<Self>> {
+        if alias.generics.params.is_empty() {
+            return Ok(None);
+        }
+
+        let param = match alias.generics.params.iter().collect::<Vec<_>>().as_slice() {
+            [GenericParam::Type(param)] if param.bounds.is_empty() => param.ident.clone(),
+            _ => {
+                return Err(Error::new_spanned(
+                    &alias.generics,
+                    "Generic QObjects must have exactly one type parameter without bounds",
+                ))
+            }
+        };
+        let attr = alias
+            .attrs
+            .iter()
+            .find(|attr| path_compare_str(attr.path(), &["instantiate"]))
+            .ok_or_else(|| {
+                Error::new_spanned(
+                    &alias.ident,
+                    "Generic QObjects must list the types they are instantiated with, like `#[instantiate(f64, QString)]`",
+                )
+            })?;
+        let args =
+            attr.parse_args_with(Punctuated::<InstantiationArg, Token![,]>::parse_terminated)?;
+        if args.is_empty() {
+            return Err(Error::new_spanned(
+                attr,
+                "#[instantiate] must list at least one type",
+            ));
+        }
+        let instantiations = args
+            .into_iter()
+            .map(|arg| {
+                // Types without an explicit name are named after the generic QObject
+                let ident = match arg.ident {
+                    Some(ident) => ident,
+                    None => format_ident!("{}{}", alias.ident, type_name(&arg.ty)?),
+                };
+                Ok(Instantiation { ident, ty: arg.ty })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Some(Self {
+            ident: alias.ident.clone(),
+            param,
+            instantiations,
+        }))
+    }
+
+    /// The QObject declarations for each instantiation, and the aliases of their Rust types
+    fn instantiate_qobject(&self, alias: &ItemType) -> Result<Vec<(ItemType, ItemType)>> {
+        let rust_type = if let Type::Path(type_path) = &*alias.ty {
+            match type_path
+                .path
+                .segments
+                .iter()
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                [krate, rust_type] if krate.ident == "super" => (*rust_type).clone(),
+                _ => {
+                    return Err(Error::new_spanned(
+                        &alias.ty,
+                        "Type alias path must have at exactly two segments, super::T!",
+                    ))
+                }
+            }
+        } else {
+            return Err(Error::new_spanned(
+                &alias.ty,
+                "Type alias path must have at exactly two segments, super::T!",
+            ));
+        };
+
+        self.instantiations
+            .iter()
+            .map(|instantiation| {
+                let ty = instantiation.ty.to_token_stream();
+                let ident = &instantiation.ident;
+                let rust_ident = format_ident!("{ident}Rust");
+                let attrs = alias
+                    .attrs
+                    .iter()
+                    .filter(|attr| !path_compare_str(attr.path(), &["instantiate"]))
+                    .map(|attr| substitute(attr.to_token_stream(), &self.param, &ty));
+                let vis = &alias.vis;
+                let qobject = syn::parse2(quote! {
+                    #(#attrs)*
+                    #vis type #ident = super::#rust_ident;
+                })?;
+
+                let rust_type = substitute(rust_type.to_token_stream(), &self.param, &ty);
+                let rust_alias = syn::parse2(quote! {
+                    #[doc(hidden)]
+                    type #rust_ident = #rust_type;
+                })?;
+                Ok((qobject, rust_alias))
+            })
+            .collect()
+    }
+
+    /// The method or signal for each instantiation
+    fn instantiate_fn(&self, foreign_fn: &ForeignItemFn) -> Result<Vec<ForeignItemFn>> {
+        self.instantiations
+            .iter()
+            .map(|instantiation| {
+                let tokens = substitute(
+                    foreign_fn.to_token_stream(),
+                    &self.param,
+                    &instantiation.ty.to_token_stream(),
+                );
+                let mut foreign_fn: ForeignItemFn = syn::parse2(tokens)?;
+                for input in &mut foreign_fn.sig.inputs {
+                    match input {
+                        FnArg::Receiver(receiver) => {
+                            replace_generic_type(&mut receiver.ty, self, instantiation)
+                        }
+                        FnArg::Typed(pat_type) => {
+                            replace_generic_type(&mut pat_type.ty, self, instantiation)
+                        }
+                    }
+                }
+                if let ReturnType::Type(_, ty) = &mut foreign_fn.sig.output {
+                    replace_generic_type(ty, self, instantiation);
+                }
+                Ok(foreign_fn)
+            })
+            .collect()
+    }
+
+    /// The trait implementation for each instantiation
+    fn instantiate_impl(&self, imp: &ItemImpl) -> Result<Vec<ItemImpl>> {
+        self.instantiations
+            .iter()
+            .map(|instantiation| {
+                let tokens = substitute(
+                    imp.to_token_stream(),
+                    &self.param,
+                    &instantiation.ty.to_token_stream(),
+                );
+                let mut imp: ItemImpl = syn::parse2(tokens)?;
+                replace_generic_type(&mut imp.self_ty, self, instantiation);
+                Ok(imp)
+            })
+            .collect()
+    }
+}
+
+/// Replace the generic QObjects of the bridge with a QObject for each instantiation
+///
+/// Returns the type aliases of the Rust types of the instantiations, eg `type ValueModelF64Rust = ValueModelRust<f64>;`,
+/// which are declared next to the bridge.
+pub(crate) fn instantiate_generic_qobjects(module: &mut ItemMod) -> Result<Vec<ItemType>> {
+    let items = if let Some((_, items)) = module.content.as_mut() {
+        items
+    } else {
+        return Ok(vec![]);
+    };
+
+    // Instantiate the QObjects first, so that their methods can be found in any block
+    let mut generics = vec![];
+    let mut rust_aliases = vec![];
+    for foreign_mod in items.iter_mut().filter_map(rust_qt_foreign_mod) {
+        let mut foreign_items = vec![];
+        for item in foreign_mod.items.drain(..) {
+            let alias = match &item {
+                ForeignItem::Verbatim(tokens) => syn::parse2::<ItemType>(tokens.clone()).ok(),
+                _ => None,
+            };
+            match alias.as_ref().map(GenericQObject::parse).transpose()? {
+                Some(Some(generic)) => {
+                    for (qobject, rust_alias) in
+                        generic.instantiate_qobject(alias.as_ref().unwrap())?
+                    {
+                        foreign_items.push(ForeignItem::Verbatim(qobject.into_token_stream()));
+                        rust_aliases.push(rust_alias);
+                    }
+                    generics.push(generic);
+                }
+                _ => foreign_items.push(item),
+            }
+        }
+        foreign_mod.items = foreign_items;
+    }
+    if generics.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let find_generic = |tokens: TokenStream| {
+        generics
+            .iter()
+            .find(|generic| mentions(tokens.clone(), &generic.ident))
+    };
+    let mut instantiated_items = vec![];
+    for item in items.drain(..) {
+        match item {
+            Item::ForeignMod(mut foreign_mod) if is_rust_qt(&foreign_mod) => {
+                let mut foreign_items = vec![];
+                for item in foreign_mod.items.drain(..) {
+                    match item {
+                        ForeignItem::Fn(foreign_fn) => {
+                            // The generic QObject is the self type of the method
+                            let receiver = foreign_fn.sig.inputs.first().to_token_stream();
+                            if let Some(generic) = find_generic(receiver) {
+                                foreign_items.extend(
+                                    generic
+                                        .instantiate_fn(&foreign_fn)?
+                                        .into_iter()
+                                        .map(ForeignItem::Fn),
+                                );
+                            } else {
+                                foreign_items.push(ForeignItem::Fn(foreign_fn));
+                            }
+                        }
+                        other => foreign_items.push(other),
+                    }
+                }
+                foreign_mod.items = foreign_items;
+                instantiated_items.push(Item::ForeignMod(foreign_mod));
+            }
+            Item::Impl(imp) => {
+                if let Some(generic) = find_generic(imp.self_ty.to_token_stream()) {
+                    instantiated_items
+                        .extend(generic.instantiate_impl(&imp)?.into_iter().map(Item::Impl));
+                } else {
+                    instantiated_items.push(Item::Impl(imp));
+                }
+            }
+            other => instantiated_items.push(other),
+        }
+    }
+    *items = instantiated_items;
+
+    Ok(rust_aliases)
+}
+
+/// Returns true if the block is an extern "RustQt" block
+fn is_rust_qt(foreign_mod: &ItemForeignMod) -> bool {
+    foreign_mod
+        .abi
+        .name
+        .as_ref()
+        .is_some_and(|name| name.value() == "RustQt")
+}
+
+/// The extern "RustQt" block of the item
+fn rust_qt_foreign_mod(item: &mut Item) -> Option<&mut ItemForeignMod> {
+    match item {
+        Item::ForeignMod(foreign_mod) if is_rust_qt(foreign_mod) => Some(foreign_mod),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{generator::rust::GeneratedRustBlocks, parser::Parser};
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_instantiate_generic_qobjects() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(T, value)]
+                    #[instantiate(f64, Names = QString)]
+                    type ValueModel<T> = super::ValueModelRust<T>;
+                }
+
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut ValueModel<T>>, value: T);
+
+                    #[qsignal]
+                    fn changed(self: Pin<&mut ValueModel<T>>);
+                }
+
+                impl cxx_qt::Threading for ValueModel<T> {}
+            }
+        };
+        let rust_aliases = instantiate_generic_qobjects(&mut module).unwrap();
+
+        assert_eq!(rust_aliases.len(), 2);
+        assert_eq!(
+            rust_aliases[0].to_token_stream().to_string(),
+            quote! {
+                #[doc(hidden)]
+                type ValueModelF64Rust = ValueModelRust<f64>;
+            }
+            .to_string()
+        );
+        assert_eq!(rust_aliases[1].ident, "NamesRust");
+
+        let items = module.content.unwrap().1;
+        assert_eq!(items.len(), 4);
+        assert_eq!(
+            items[0].to_token_stream().to_string(),
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(f64, value)]
+                    type ValueModelF64 = super::ValueModelF64Rust;
+
+                    #[qobject]
+                    #[qproperty(QString, value)]
+                    type Names = super::NamesRust;
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            items[1].to_token_stream().to_string(),
+            quote! {
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut ValueModelF64>, value: f64);
+
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut Names>, value: QString);
+
+                    #[qsignal]
+                    fn changed(self: Pin<&mut ValueModelF64>);
+
+                    #[qsignal]
+                    fn changed(self: Pin<&mut Names>);
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            items[2].to_token_stream().to_string(),
+            quote! { impl cxx_qt::Threading for ValueModelF64 {} }.to_string()
+        );
+        assert_eq!(
+            items[3].to_token_stream().to_string(),
+            quote! { impl cxx_qt::Threading for Names {} }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_instantiate_without_generics() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let expected = module.clone();
+        assert!(instantiate_generic_qobjects(&mut module)
+            .unwrap()
+            .is_empty());
+        assert_eq!(module, expected);
+    }
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(type_name(&parse_quote! { f64 }).unwrap(), "F64");
+        assert_eq!(
+            type_name(&parse_quote! { cxx_qt_lib::QList<i32> }).unwrap(),
+            "QListI32"
+        );
+        assert!(type_name(&parse_quote! { &str }).is_err());
+    }
+
+    #[test]
+    fn test_instantiate_invalid() {
+        let invalid = [
+            // Missing #[instantiate]
+            quote! { #[qobject] type ValueModel<T> = super::ValueModelRust<T>; },
+            // Nothing to instantiate
+            quote! { #[qobject] #[instantiate()] type ValueModel<T> = super::ValueModelRust<T>; },
+            // More than one type parameter
+            quote! { #[qobject] #[instantiate(f64)] type ValueModel<T, U> = super::ValueModelRust<T, U>; },
+            // The name can't be derived from the type
+            quote! { #[qobject] #[instantiate(&'static str)] type ValueModel<T> = super::ValueModelRust<T>; },
+            // The Rust type isn't in super
+            quote! { #[qobject] #[instantiate(f64)] type ValueModel<T> = ValueModelRust<T>; },
+        ];
+        for tokens in invalid {
+            let mut module: ItemMod = parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #tokens
+                    }
+                }
+            };
+            assert!(instantiate_generic_qobjects(&mut module).is_err());
+        }
+    }
+
+    #[test]
+    fn test_generated_rust_aliases() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[instantiate(f64, i32)]
+                    type ValueModel<T> = super::ValueModelRust<T>;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        assert_eq!(parser.cxx_qt_data.qobjects.len(), 2);
+        assert_eq!(
+            parser.cxx_qt_data.qobjects[0].name.rust_unqualified(),
+            "ValueModelF64"
+        );
+        assert_eq!(
+            parser.cxx_qt_data.qobjects[1].name.rust_unqualified(),
+            "ValueModelI32"
+        );
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.fragments.len(), 3);
+        assert_eq!(rust.fragments[2].cxx_qt_mod_contents.len(), 2);
+    }
+}
@@ -18,8 +18,14 @@ pub struct ParsedInheritedMethod {
 }
 
 impl ParsedInheritedMethod {
-    const ALLOWED_ATTRS: [&'static str; 5] =
-        ["cxx_name", "rust_name", "qinvokable", "doc", "inherit"];
+    const ALLOWED_ATTRS: [&'static str; 6] = [
+        "cxx_name",
+        "rust_name",
+        "qinvokable",
+        "doc",
+        "inherit",
+        "cfg",
+    ];
 
     pub fn parse(method: ForeignItemFn, safety: Safety) -> Result<Self> {
         check_safety(&method, &safety)?;
@@ -0,0 +1,314 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Checks for common mistakes in bridges, so that the error message can suggest the fix.
+
+use crate::parser::{cxxqtdata::ParsedCxxQtData, property::FlagState};
+use quote::ToTokens;
+use syn::{Error, Result, ReturnType, Type};
+
+/// Attributes which are commonly used instead of the CXX-Qt attribute with the same meaning
+const MISTAKEN_ATTRIBUTES: [(&str, &str); 8] = [
+    ("property", "qproperty"),
+    ("q_property", "qproperty"),
+    ("invokable", "qinvokable"),
+    ("q_invokable", "qinvokable"),
+    ("signal", "qsignal"),
+    ("q_signal", "qsignal"),
+    ("qml", "qml_element"),
+    ("override", "cxx_override"),
+];
+
+/// Rust types which can't be used for properties, with the Qt type from cxx-qt-lib to use instead
+const NON_QT_PROPERTY_TYPES: [(&str, &str); 4] = [
+    ("String", "QString"),
+    ("str", "QString"),
+    ("Vec", "QList<T>"),
+    ("HashMap", "QHash<K, V>"),
+];
+
+/// The Levenshtein distance between two strings
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    for (i, left_char) in left.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+/// Find the allowed attribute that was most likely meant by an unsupported attribute,
+/// either because it is a common mistake like `#[property]` or a typo like `#[qinvokeable]`
+pub fn suggest_attribute<'a>(name: &str, allowed: &[&'a str]) -> Option<&'a str> {
+    let mistaken = MISTAKEN_ATTRIBUTES
+        .iter()
+        .find(|(mistake, _)| *mistake == name)
+        .and_then(|(_, attribute)| allowed.iter().find(|allowed| *allowed == attribute));
+
+    mistaken
+        .or_else(|| {
+            allowed
+                .iter()
+                .map(|allowed| (edit_distance(name, allowed), allowed))
+                .filter(|(distance, _)| *distance <= 2)
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(_, allowed)| allowed)
+        })
+        .copied()
+}
+
+/// Checks that the type of a property is not a Rust type which has no Qt meta type,
+/// suggesting the Qt type from cxx-qt-lib that should be used instead
+pub fn check_property_type(ty: &Type) -> Result<()> {
+    let ident = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.segments.last().map(|segment| &segment.ident)
+        }
+        Type::Reference(reference) => {
+            if let Type::Path(type_path) = &*reference.elem {
+                type_path.path.get_ident()
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    let non_qt_type = ident.and_then(|ident| {
+        NON_QT_PROPERTY_TYPES
+            .iter()
+            .find(|(rust_type, _)| ident == rust_type)
+    });
+    if let Some((rust_type, qt_type)) = non_qt_type {
+        return Err(Error::new_spanned(
+            ty,
+            format!(
+                "`{ty}` can't be used as the type of a property as `{rust_type}` is not a Qt type! Use `{qt_type}` from cxx-qt-lib instead",
+                ty = ty.to_token_stream()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that a QObject doesn't declare the accessors of a property which is not declared,
+/// suggesting the `#[qproperty]` that was most likely forgotten
+///
+/// A `set_x` method is considered to be the setter of a property `x` when there is also
+/// an `x_changed` signal, or when both are invokables and there is an `x` getter.
+pub fn check_missing_properties(cxx_qt_data: &ParsedCxxQtData) -> Result<()> {
+    for qobject in &cxx_qt_data.qobjects {
+        let qobject_ident = qobject.name.rust_unqualified();
+        let methods = || {
+            cxx_qt_data
+                .methods
+                .iter()
+                .filter(|method| &method.qobject_ident == qobject_ident)
+        };
+        // Custom accessors of declared properties are not the accessors of a missing property
+        let custom_accessors: Vec<_> = qobject
+            .properties
+            .iter()
+            .flat_map(|property| {
+                let flags = &property.flags;
+                [
+                    Some(&flags.read),
+                    flags.write.as_ref(),
+                    flags.notify.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .filter_map(|flag| match flag {
+                    FlagState::Custom(ident) => Some(ident),
+                    FlagState::Auto => None,
+                })
+                .chain(flags.reset.as_ref())
+            })
+            .collect();
+        let is_custom_accessor = |ident: &syn::Ident| custom_accessors.contains(&ident);
+
+        for setter in methods() {
+            let setter_ident = setter.name.rust_unqualified().to_string();
+            let Some(property) = setter_ident.strip_prefix("set_") else {
+                continue;
+            };
+            let declared = qobject
+                .properties
+                .iter()
+                .any(|declared| declared.name.rust_unqualified() == property);
+            if declared
+                || setter.parameters.len() != 1
+                || is_custom_accessor(setter.name.rust_unqualified())
+            {
+                continue;
+            }
+
+            let notify = format!("{property}_changed");
+            let accessor = cxx_qt_data
+                .signals
+                .iter()
+                .filter(|signal| &signal.qobject_ident == qobject_ident)
+                .find(|signal| signal.name.rust_unqualified() == &notify)
+                .map(|signal| signal.name.rust_unqualified())
+                .filter(|signal| !is_custom_accessor(signal))
+                .or_else(|| {
+                    methods()
+                        .filter(|getter| setter.is_qinvokable && getter.is_qinvokable)
+                        .find(|getter| {
+                            getter.name.rust_unqualified() == property
+                                && getter.parameters.is_empty()
+                                && getter.method.sig.output != ReturnType::Default
+                        })
+                        .map(|getter| getter.name.rust_unqualified())
+                        .filter(|getter| !is_custom_accessor(getter))
+                });
+
+            if let Some(accessor) = accessor {
+                let ty = match &setter.parameters[0].ty {
+                    Type::Reference(reference) => &*reference.elem,
+                    ty => ty,
+                };
+                return Err(Error::new_spanned(
+                    &setter.method.sig.ident,
+                    format!(
+                        "`{setter_ident}` and `{accessor}` look like the accessors of a property `{property}`, but it is not declared on `{qobject_ident}`!\n\
+                        Did you forget `#[qproperty({ty}, {property})]` on the QObject? The getter, setter and notify signal are then generated",
+                        ty = ty.to_token_stream()
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("qinvokable", "qinvokable"), 0);
+        assert_eq!(edit_distance("qinvokeable", "qinvokable"), 1);
+        assert_eq!(edit_distance("qsignals", "qsignal"), 1);
+        assert_eq!(edit_distance("", "doc"), 3);
+    }
+
+    #[test]
+    fn test_suggest_attribute() {
+        let allowed = ["cxx_name", "qinvokable", "cxx_override", "doc"];
+        assert_eq!(suggest_attribute("invokable", &allowed), Some("qinvokable"));
+        assert_eq!(
+            suggest_attribute("qinvokeable", &allowed),
+            Some("qinvokable")
+        );
+        assert_eq!(
+            suggest_attribute("override", &allowed),
+            Some("cxx_override")
+        );
+        // qproperty is not allowed on this item, so it is not suggested
+        assert_eq!(suggest_attribute("property", &allowed), None);
+        assert_eq!(suggest_attribute("unrelated", &allowed), None);
+    }
+
+    #[test]
+    fn test_check_property_type() {
+        assert!(check_property_type(&parse_quote! { i32 }).is_ok());
+        assert!(check_property_type(&parse_quote! { QString }).is_ok());
+        assert!(check_property_type(&parse_quote! { QList_i32 }).is_ok());
+        assert!(check_property_type(&parse_quote! { *mut MyObject }).is_ok());
+
+        assert!(check_property_type(&parse_quote! { String }).is_err());
+        assert!(check_property_type(&parse_quote! { std::string::String }).is_err());
+        assert!(check_property_type(&parse_quote! { &str }).is_err());
+        assert!(check_property_type(&parse_quote! { Vec<i32> }).is_err());
+        assert!(check_property_type(&parse_quote! { HashMap<i32, QString> }).is_err());
+    }
+
+    #[test]
+    fn test_check_missing_properties() {
+        use crate::parser::Parser;
+
+        // The setter and notify signal of a property that was not declared
+        let error = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    fn set_number(self: Pin<&mut MyObject>, number: i32);
+
+                    #[qsignal]
+                    fn number_changed(self: Pin<&mut MyObject>);
+                }
+            }
+        })
+        .err()
+        .unwrap();
+        assert!(error.to_string().contains(
+            "`set_number` and `number_changed` look like the accessors of a property `number`"
+        ));
+        assert!(error
+            .to_string()
+            .contains("Did you forget `#[qproperty(i32, number)]` on the QObject?"));
+
+        // A getter and setter which are invokables
+        let error = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn name(self: &MyObject) -> QString;
+
+                    #[qinvokable]
+                    fn set_name(self: Pin<&mut MyObject>, name: &QString);
+                }
+            }
+        })
+        .err()
+        .unwrap();
+        assert!(error
+            .to_string()
+            .contains("Did you forget `#[qproperty(QString, name)]` on the QObject?"));
+
+        // The property is declared, the setter is a custom setter of another property
+        // or the setter has no accessor
+        assert!(Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number, READ, WRITE = set_number, NOTIFY)]
+                    #[qproperty(QUrl, connected_url, READ, WRITE = set_url, NOTIFY = url_changed)]
+                    type MyObject = super::MyObjectRust;
+
+                    fn set_number(self: Pin<&mut MyObject>, number: i32);
+
+                    fn set_url(self: Pin<&mut MyObject>, url: QUrl);
+
+                    #[qsignal]
+                    fn url_changed(self: Pin<&mut MyObject>);
+
+                    #[qinvokable]
+                    fn set_enabled(self: Pin<&mut MyObject>, enabled: bool);
+                }
+            }
+        })
+        .is_ok());
+    }
+}
@@ -4,12 +4,20 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
     naming::Name,
-    parser::{check_safety, parameter::ParsedFunctionParameter, require_attributes},
-    syntax::{foreignmod, safety::Safety, types},
+    parser::{
+        check_safety, extract_cfgs, parameter::ParsedFunctionParameter, qtversion::QtVersion,
+        require_attributes,
+    },
+    syntax::{
+        attribute::attribute_get_path, expr::expr_to_string, foreignmod, safety::Safety, types,
+    },
 };
 use core::ops::Deref;
 use std::collections::{BTreeMap, HashSet};
-use syn::{Attribute, ForeignItemFn, Ident, Result};
+use syn::{
+    punctuated::Punctuated, Attribute, Error, Expr, FnArg, ForeignItemFn, Ident, Meta, PatType,
+    Result, ReturnType, Token, Type,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -43,6 +51,126 @@ impl ParsedQInvokableSpecifiers {
     }
 }
 
+/// Describes who owns the QObject returned by a Q_INVOKABLE, from a `#[qml_ownership = "..."]` attribute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QmlOwnership {
+    /// The object is owned by C++ or Rust and QML never deletes it
+    Cpp,
+    /// The object is owned by QML and deleted by the garbage collector once it is no longer referenced
+    JavaScript,
+}
+
+impl QmlOwnership {
+    /// The `QQmlEngine::ObjectOwnership` value of the ownership
+    pub fn as_cpp_str(&self) -> &str {
+        match self {
+            QmlOwnership::Cpp => "QQmlEngine::CppOwnership",
+            QmlOwnership::JavaScript => "QQmlEngine::JavaScriptOwnership",
+        }
+    }
+
+    fn parse(attr: &Attribute, method: &ForeignItemFn, is_qinvokable: bool) -> Result<Self> {
+        let value = expr_to_string(&attr.meta.require_name_value()?.value)?;
+        let ownership = match value.as_str() {
+            "cpp" => QmlOwnership::Cpp,
+            "javascript" => QmlOwnership::JavaScript,
+            _ => {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Unsupported qml_ownership, expected \"cpp\" or \"javascript\"",
+                ))
+            }
+        };
+
+        if !is_qinvokable {
+            return Err(Error::new_spanned(
+                attr,
+                "qml_ownership can only be used on a #[qinvokable]",
+            ));
+        }
+
+        let returns_mut_ptr = matches!(
+            &method.sig.output,
+            ReturnType::Type(_, ty) if matches!(&**ty, Type::Ptr(ptr) if ptr.mutability.is_some())
+        );
+        if !returns_mut_ptr {
+            return Err(Error::new_spanned(
+                &method.sig.output,
+                "qml_ownership requires the invokable to return a QObject pointer, eg *mut T",
+            ));
+        }
+
+        Ok(ownership)
+    }
+}
+
+/// Describes a Q_INVOKABLE whose body runs on the worker thread of the QObject,
+/// from a `#[qinvokable(worker)]` or `#[qinvokable(worker = method)]` attribute
+pub struct ParsedWorkerInvokable {
+    /// The return type of the body, which is given to the done method instead of C++
+    pub output: ReturnType,
+    /// The method of the QObject which is called with the result on its Qt thread
+    pub done: Option<Ident>,
+}
+
+impl ParsedWorkerInvokable {
+    fn parse(attr: &Attribute, method: &mut ForeignItemFn) -> Result<Option<Self>> {
+        // A plain #[qinvokable] is not run on the worker thread
+        if matches!(attr.meta, Meta::Path(_)) {
+            return Ok(None);
+        }
+
+        let meta = attr.parse_args::<Meta>()?;
+        if !meta.path().is_ident("worker") {
+            return Err(Error::new_spanned(
+                meta.path(),
+                "Unsupported qinvokable argument, expected `worker` or `worker = method`",
+            ));
+        }
+        let done = match &meta {
+            Meta::Path(_) => None,
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Path(path) if path.path.get_ident().is_some() => path.path.get_ident().cloned(),
+                value => {
+                    return Err(Error::new_spanned(
+                        value,
+                        "Expected the name of the method which receives the result, eg worker = set_result",
+                    ))
+                }
+            },
+            Meta::List(list) => {
+                return Err(Error::new_spanned(
+                    list,
+                    "Expected `worker` or `worker = method`",
+                ))
+            }
+        };
+
+        // The parameters are moved to the worker thread, so they need to be owned
+        for input in method.sig.inputs.iter().skip(1) {
+            if let FnArg::Typed(PatType { ty, .. }) = input {
+                if matches!(&**ty, Type::Reference(_) | Type::Ptr(_)) {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "The parameters of a worker invokable are moved to the worker thread, so they can't be references or pointers",
+                    ));
+                }
+            }
+        }
+
+        if done.is_none() && method.sig.output != ReturnType::Default {
+            return Err(Error::new_spanned(
+                &method.sig.output,
+                "The result of a worker invokable is given to a method of the QObject on its Qt thread, eg #[qinvokable(worker = set_result)]",
+            ));
+        }
+
+        // The Q_INVOKABLE returns immediately, the result is given to the done method later
+        let output = std::mem::replace(&mut method.sig.output, ReturnType::Default);
+        Ok(Some(Self { output, done }))
+    }
+}
+
 /// Describes a single method (which could be a Q_INVOKABLE) for a struct
 pub struct ParsedMethod {
     /// The common fields which are available on all callable types
@@ -51,19 +179,27 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
+    /// Who owns the QObject returned to QML, if it is set explicitly
+    pub ownership: Option<QmlOwnership>,
+    /// Whether the body of the Q_INVOKABLE runs on the worker thread of the QObject
+    pub worker: Option<ParsedWorkerInvokable>,
     // No docs field since the docs should be on the method implementation outside the bridge
     // This means any docs on the bridge declaration would be ignored
 }
 
 impl ParsedMethod {
-    const ALLOWED_ATTRS: [&'static str; 7] = [
+    const ALLOWED_ATTRS: [&'static str; 11] = [
         "cxx_name",
         "rust_name",
         "qinvokable",
+        "qml_ownership",
         "cxx_final",
         "cxx_override",
         "cxx_virtual",
         "doc",
+        "qt",
+        "forward_from",
+        "cfg",
     ];
 
     #[cfg(test)]
@@ -103,19 +239,50 @@ impl ParsedMethod {
 
     pub fn parse(method: ForeignItemFn, safety: Safety) -> Result<Self> {
         check_safety(&method, &safety)?;
-        let fields = MethodFields::parse(method)?;
-        let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
+        let mut fields = MethodFields::parse(method)?;
+        let method_attrs = fields.method.attrs.clone();
+        let attrs = require_attributes(&method_attrs, &Self::ALLOWED_ATTRS)?;
 
         // Determine if the method is invokable
         let is_qinvokable = attrs.contains_key("qinvokable");
+        let ownership = attrs
+            .get("qml_ownership")
+            .map(|attr| QmlOwnership::parse(attr, &fields.method, is_qinvokable))
+            .transpose()?;
+        let worker = attrs
+            .get("qinvokable")
+            .map(|attr| ParsedWorkerInvokable::parse(attr, &mut fields.method))
+            .transpose()?
+            .flatten();
+        if let (Some(_), Some(attr)) = (&worker, attrs.get("qml_ownership")) {
+            return Err(Error::new_spanned(
+                attr,
+                "qml_ownership can't be used on a worker invokable, as it doesn't return the object to QML",
+            ));
+        }
         let specifiers = ParsedQInvokableSpecifiers::from_attrs(attrs);
 
         Ok(Self {
             method_fields: fields,
             specifiers,
             is_qinvokable,
+            ownership,
+            worker,
         })
     }
+
+    /// The C++ name of the method which is implemented in Rust
+    ///
+    /// When the ownership of the returned object is set, the Q_INVOKABLE is a C++ wrapper
+    /// which calls this method and then sets the ownership of the object.
+    pub fn cxx_ident_of_implementation(&self) -> String {
+        let ident = self.name.cxx_unqualified();
+        if self.ownership.is_some() {
+            format!("{ident}CxxQtOwnership")
+        } else {
+            ident
+        }
+    }
 }
 
 impl Deref for ParsedMethod {
@@ -136,6 +303,13 @@ pub struct MethodFields {
     pub parameters: Vec<ParsedFunctionParameter>,
     pub safe: bool,
     pub name: Name,
+    /// The minimum Qt version, from a `#[qt(since = "...")]` attribute
+    pub since: Option<QtVersion>,
+    /// The `#[cfg(...)]` attributes, the item is only generated when all of them are enabled
+    pub cfgs: Vec<Attribute>,
+    /// The signals of the QObject which are connected to this signal or method,
+    /// from a `#[forward_from(...)]` attribute
+    pub forward_from: Vec<Ident>,
 }
 
 impl MethodFields {
@@ -147,6 +321,13 @@ impl MethodFields {
         let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
         let safe = method.sig.unsafety.is_none();
         let name = Name::from_rust_ident_and_attrs(&method.sig.ident, &method.attrs, None, None)?;
+        let since = QtVersion::from_attrs(&method.attrs)?;
+        let cfgs = extract_cfgs(&method.attrs);
+        let forward_from = attribute_get_path(&method.attrs, &["forward_from"])
+            .map(|attr| attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated))
+            .transpose()?
+            .map(|signals| signals.into_iter().collect())
+            .unwrap_or_default();
 
         Ok(MethodFields {
             method,
@@ -155,6 +336,155 @@ impl MethodFields {
             parameters,
             safe,
             name,
+            since,
+            cfgs,
+            forward_from,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_ownership() {
+        let method = ParsedMethod::parse(
+            parse_quote! {
+                #[qinvokable]
+                #[qml_ownership = "javascript"]
+                fn create_child(self: &MyObject) -> *mut MyObject;
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+        assert_eq!(method.ownership, Some(QmlOwnership::JavaScript));
+        assert_eq!(
+            method.cxx_ident_of_implementation(),
+            "createChildCxxQtOwnership"
+        );
+
+        let method = ParsedMethod::parse(
+            parse_quote! {
+                #[qinvokable]
+                fn create_child(self: &MyObject) -> *mut MyObject;
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+        assert_eq!(method.ownership, None);
+        assert_eq!(method.cxx_ident_of_implementation(), "createChild");
+    }
+
+    #[test]
+    fn test_parse_ownership_invalid() {
+        assert_parse_errors! {
+            |input| ParsedMethod::parse(input, Safety::Safe) =>
+
+            // Unknown ownership
+            {
+                #[qinvokable]
+                #[qml_ownership = "rust"]
+                fn create_child(self: &MyObject) -> *mut MyObject;
+            }
+            // Not an invokable
+            {
+                #[qml_ownership = "cpp"]
+                fn create_child(self: &MyObject) -> *mut MyObject;
+            }
+            // Not returning a pointer
+            {
+                #[qinvokable]
+                #[qml_ownership = "cpp"]
+                fn create_child(self: &MyObject) -> UniquePtr<MyObject>;
+            }
+            // Returning a const pointer
+            {
+                #[qinvokable]
+                #[qml_ownership = "cpp"]
+                fn create_child(self: &MyObject) -> *const MyObject;
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_worker() {
+        let method = ParsedMethod::parse(
+            parse_quote! {
+                #[qinvokable(worker = set_result)]
+                fn calculate(self: &MyObject, input: i32) -> i32;
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+        let worker = method.worker.as_ref().unwrap();
+        assert_eq!(worker.done, Some(format_ident!("set_result")));
+        assert_eq!(worker.output, parse_quote! { -> i32 });
+        // The Q_INVOKABLE doesn't return the result
+        assert_eq!(method.method.sig.output, ReturnType::Default);
+
+        let method = ParsedMethod::parse(
+            parse_quote! {
+                #[qinvokable(worker)]
+                fn save(self: &MyObject, path: QString);
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+        let worker = method.worker.as_ref().unwrap();
+        assert_eq!(worker.done, None);
+        assert_eq!(worker.output, ReturnType::Default);
+
+        let method = ParsedMethod::parse(
+            parse_quote! {
+                #[qinvokable]
+                fn calculate(self: &MyObject, input: i32) -> i32;
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+        assert!(method.worker.is_none());
+    }
+
+    #[test]
+    fn test_parse_worker_invalid() {
+        assert_parse_errors! {
+            |input| ParsedMethod::parse(input, Safety::Safe) =>
+
+            // Unknown argument
+            {
+                #[qinvokable(thread)]
+                fn calculate(self: &MyObject);
+            }
+            // The done method must be an ident
+            {
+                #[qinvokable(worker = "set_result")]
+                fn calculate(self: &MyObject) -> i32;
+            }
+            {
+                #[qinvokable(worker(set_result))]
+                fn calculate(self: &MyObject) -> i32;
+            }
+            // A result without a done method
+            {
+                #[qinvokable(worker)]
+                fn calculate(self: &MyObject) -> i32;
+            }
+            // References can't be moved to the worker thread
+            {
+                #[qinvokable(worker)]
+                fn save(self: &MyObject, path: &QString);
+            }
+            // The object isn't returned to QML
+            {
+                #[qinvokable(worker = set_child)]
+                #[qml_ownership = "cpp"]
+                fn create_child(self: &MyObject) -> *mut MyObject;
+            }
+        }
+    }
+}
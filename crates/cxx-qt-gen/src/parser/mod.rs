@@ -7,18 +7,25 @@ pub mod constructor;
 pub mod cxxqtdata;
 pub mod externcxxqt;
 pub mod externqobject;
+pub mod generic;
 pub mod inherit;
+pub mod lint;
 pub mod method;
 pub mod parameter;
 pub mod property;
+pub mod propertytable;
 pub mod qenum;
 pub mod qnamespace;
 pub mod qobject;
+pub mod qtversion;
+pub mod sharedstruct;
 pub mod signals;
 pub mod trait_impl;
+pub mod typepath;
 
 use crate::{
     naming::TypeNames,
+    registry::TypeRegistry,
     syntax::{expr::expr_to_string, path::path_compare_str, safety::Safety},
 };
 use cxxqtdata::ParsedCxxQtData;
@@ -27,14 +34,15 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Brace, Semi},
-    Attribute, Error, ForeignItemFn, Ident, Item, ItemMod, Meta, Result, Token, Visibility,
+    Attribute, Error, Expr, ExprLit, ForeignItemFn, Ident, Item, ItemMod, Lit, Meta, Result, Token,
+    Visibility,
 };
 
 /// Validates that an invokable is either unsafe, or is in an unsafe extern block
 fn check_safety(method: &ForeignItemFn, safety: &Safety) -> Result<()> {
     if safety == &Safety::Unsafe && method.sig.unsafety.is_none() {
-        Err(Error::new(
-            method.span(),
+        Err(Error::new_spanned(
+            &method.sig,
             "Invokables must be marked as unsafe or wrapped in an `unsafe extern \"RustQt\"` block!",
         ))
     } else {
@@ -51,6 +59,32 @@ pub fn extract_docs(attrs: &[Attribute]) -> Vec<Attribute> {
         .collect()
 }
 
+/// Iterate the attributes of the item to extract `#[cfg(...)]` attributes
+pub fn extract_cfgs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| path_compare_str(attr.meta.path(), &["cfg"]))
+        .cloned()
+        .collect()
+}
+
+/// The text of each doc comment line in the given attributes
+pub fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    extract_docs(attrs)
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) => Some(lit_str.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 /// Splits a path by :: separators e.g. "cxx_qt::bridge" becomes ["cxx_qt", "bridge"]
 fn split_path(path_str: &str) -> Vec<&str> {
     let path = if path_str.contains("::") {
@@ -75,10 +109,17 @@ pub fn require_attributes<'a>(
         if let Some(index) = index {
             output.insert(allowed[index], attr); // Doesn't error on duplicates
         } else {
-            return Err(Error::new(
-                attr.span(),
+            let suggestion = attr
+                .meta
+                .path()
+                .get_ident()
+                .and_then(|ident| lint::suggest_attribute(&ident.to_string(), allowed))
+                .map(|suggestion| format!("\nDid you mean `#[{suggestion}]`?"))
+                .unwrap_or_default();
+            return Err(Error::new_spanned(
+                attr,
                 format!(
-                    "Unsupported attribute! The only attributes allowed on this item are\n{}",
+                    "Unsupported attribute! The only attributes allowed on this item are\n{}{suggestion}",
                     allowed.join(", ")
                 ),
             ));
@@ -87,6 +128,50 @@ pub fn require_attributes<'a>(
     Ok(output)
 }
 
+/// Collects the errors of independent items, so that all of them are reported at once
+/// instead of stopping at the first one
+#[derive(Default)]
+pub struct ErrorCollector {
+    error: Option<Error>,
+}
+
+impl ErrorCollector {
+    /// Add an error to the collected errors
+    pub fn push(&mut self, error: Error) {
+        if let Some(collected) = &mut self.error {
+            collected.combine(error);
+        } else {
+            self.error = Some(error);
+        }
+    }
+
+    /// Returns the value of the result, or collects its error and returns None
+    pub fn collect<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns all of the collected errors combined into one, if there were any
+    pub fn finish(self) -> Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    /// Returns all of the collected errors combined into one, followed by the given error
+    pub fn finish_with(self, error: Error) -> Error {
+        if let Some(mut collected) = self.error {
+            collected.combine(error);
+            collected
+        } else {
+            error
+        }
+    }
+}
+
 /// Struct representing the necessary components of a cxx mod to be passed through to generation
 pub struct PassthroughMod {
     pub(crate) items: Option<Vec<Item>>,
@@ -141,8 +226,8 @@ impl Parser {
                                 namespace = Some(expr_to_string(&name_value.value)?);
                                 // Parse any custom file stem
                             } else if name_value.path.is_ident("cxx_file_stem") {
-                                return Err(Error::new(
-                                    meta.span(),
+                                return Err(Error::new_spanned(
+                                    &meta,
                                     "cxx_file_stem is unsupported, instead the input file name will be used",
                                 ));
                             }
@@ -161,10 +246,12 @@ impl Parser {
         Ok(namespace)
     }
 
+    /// Items that fail to parse are skipped and their errors are added to the given collector
     fn parse_module_contents(
         mut module: ItemMod,
         namespace: Option<String>,
-    ) -> Result<(ParsedCxxQtData, ItemMod)> {
+        errors: &mut ErrorCollector,
+    ) -> (ParsedCxxQtData, ItemMod) {
         let mut others = vec![];
 
         let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), namespace);
@@ -175,7 +262,7 @@ impl Parser {
             for item in items.into_iter() {
                 // Try to find any CXX-Qt items, if found add them to the relevant
                 // qobject or extern C++Qt block. Otherwise return them to be added to other
-                if let Some(other) = cxx_qt_data.parse_cxx_qt_item(item)? {
+                if let Some(Some(other)) = errors.collect(cxx_qt_data.parse_cxx_qt_item(item)) {
                     // Unknown item so add to the other list
                     others.push(other);
                 }
@@ -190,7 +277,7 @@ impl Parser {
             module.content = None;
             module.semi = Some(Semi::default());
         }
-        Ok((cxx_qt_data, module))
+        (cxx_qt_data, module)
     }
 
     /// The "Naming phase", it generates a list of all nameable types in our bridge.
@@ -208,9 +295,44 @@ impl Parser {
     }
 
     /// Constructs a Parser object from a given [syn::ItemMod] block
-    pub fn from(mut module: ItemMod) -> Result<Self> {
+    pub fn from(module: ItemMod) -> Result<Self> {
+        Self::from_with_registry(module, &TypeRegistry::default())
+    }
+
+    /// Constructs a Parser object from a given [syn::ItemMod] block,
+    /// declaring the types of the [TypeRegistry] which are used by the bridge
+    pub fn from_with_registry(module: ItemMod, registry: &TypeRegistry) -> Result<Self> {
+        let (parser, errors) = Self::from_partial_with_registry(module, registry)?;
+        errors.map_or(Ok(parser), Err)
+    }
+
+    /// Constructs a Parser object from a given [syn::ItemMod] block, skipping the items that fail to parse
+    ///
+    /// The errors of the skipped items are returned alongside the Parser,
+    /// so that code can still be generated for the rest of the bridge.
+    /// This allows IDEs to provide completion for the generated API of a bridge that is being edited.
+    ///
+    /// Errors that affect the whole bridge are still returned as an error.
+    pub fn from_partial(module: ItemMod) -> Result<(Self, Option<Error>)> {
+        Self::from_partial_with_registry(module, &TypeRegistry::default())
+    }
+
+    /// Constructs a Parser object like [Parser::from_partial],
+    /// declaring the types of the [TypeRegistry] which are used by the bridge
+    pub fn from_partial_with_registry(
+        mut module: ItemMod,
+        registry: &TypeRegistry,
+    ) -> Result<(Self, Option<Error>)> {
         let namespace = Self::parse_mod_attributes(&mut module)?;
-        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace)?;
+        propertytable::expand_property_tables(&mut module)?;
+        let generic_instantiations = generic::instantiate_generic_qobjects(&mut module)?;
+        typepath::resolve_type_paths(&mut module, registry)?;
+        registry.declare_in(&mut module);
+        // Items are parsed independently, so report the errors of all items at once
+        let mut errors = ErrorCollector::default();
+        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace, &mut errors);
+        errors.collect(lint::check_missing_properties(&cxx_qt_data));
+        cxx_qt_data.generic_instantiations = generic_instantiations;
         let type_names = Self::naming_phase(
             &mut cxx_qt_data,
             module
@@ -219,14 +341,19 @@ impl Parser {
                 .map(|brace_and_items| &brace_and_items.1)
                 .unwrap_or(&vec![]),
             &module.ident,
-        )?;
+        );
+        let type_names = match type_names {
+            Ok(type_names) => type_names,
+            // Without the type names nothing can be generated
+            Err(error) => return Err(errors.finish_with(error)),
+        };
 
-        // Return the successful Parser object
-        Ok(Self {
+        let parser = Self {
             passthrough_module: PassthroughMod::parse(module),
             type_names,
             cxx_qt_data,
-        })
+        };
+        Ok((parser, errors.finish().err()))
     }
 }
 
@@ -408,6 +535,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parser_reports_all_errors() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[namespace = 1]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qsignal]
+                    fn ready(self: &MyObject);
+                }
+            }
+        };
+        let error = Parser::from(module).err().unwrap();
+        assert_eq!(error.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_parser_from_partial() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn invokable(self: &MyObject);
+
+                    #[qsignal]
+                    fn ready(self: &MyObject);
+                }
+            }
+        };
+        let (parser, errors) = Parser::from_partial(module).unwrap();
+        assert_eq!(errors.unwrap().into_iter().count(), 1);
+        assert_eq!(parser.cxx_qt_data.qobjects.len(), 1);
+        assert_eq!(parser.cxx_qt_data.methods.len(), 1);
+        assert!(parser.cxx_qt_data.signals.is_empty());
+
+        // The skipped items don't prevent the rest of the bridge from being generated
+        assert!(crate::generator::rust::GeneratedRustBlocks::from(&parser).is_ok());
+    }
+
+    #[test]
+    fn test_parser_from_partial_invalid_bridge() {
+        let module: ItemMod = parse_quote! {
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        assert!(Parser::from_partial(module).is_err());
+    }
+
     #[test]
     fn test_cxx_qobject_namespace() {
         let module: ItemMod = parse_quote! {
@@ -4,10 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::syntax::types;
-use syn::{
-    spanned::Spanned, Error, FnArg, Ident, Pat, PatIdent, PatType, Receiver, Result, Signature,
-    Type,
-};
+use syn::{Error, FnArg, Ident, Pat, PatIdent, PatType, Receiver, Result, Signature, Type};
 
 /// Describes a single parameter for a function
 #[derive(Clone, Debug, PartialEq)]
@@ -16,6 +13,8 @@ pub struct ParsedFunctionParameter {
     pub ident: Ident,
     /// The [syn::Type] of the parameter
     pub ty: Type,
+    /// The name of the parameter in C++, if it differs from the Rust ident
+    pub cxx_name: Option<String>,
 }
 
 impl ParsedFunctionParameter {
@@ -80,8 +79,8 @@ impl ParsedFunctionParameter {
         let ident = if let Pat::Ident(PatIdent { ident, .. }) = &*type_pattern.pat {
             ident.clone()
         } else {
-            return Err(Error::new(
-                type_pattern.span(),
+            return Err(Error::new_spanned(
+                type_pattern,
                 "Invalid argument ident format!\nPlease specify like `arg: Ty`",
             ));
         };
@@ -89,8 +88,16 @@ impl ParsedFunctionParameter {
         Ok(ParsedFunctionParameter {
             ident,
             ty: (*type_pattern.ty).clone(),
+            cxx_name: None,
         })
     }
+
+    /// The name of the parameter in C++
+    pub fn cxx_ident(&self) -> String {
+        self.cxx_name
+            .clone()
+            .unwrap_or_else(|| self.ident.to_string())
+    }
 }
 
 #[cfg(test)]
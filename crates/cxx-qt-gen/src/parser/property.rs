@@ -5,12 +5,12 @@
 
 use crate::generator::naming::property::property_name_from_rust_name;
 use crate::naming::{AutoCamel, Name};
+use crate::parser::lint;
 use crate::syntax::expr::expr_to_string;
 use syn::{
     parse::{Error, ParseStream},
     punctuated::Punctuated,
-    spanned::Spanned,
-    Attribute, Expr, Ident, Meta, MetaNameValue, Result, Token, Type,
+    Attribute, Expr, ExprLit, Ident, Lit, Meta, MetaNameValue, Path, Result, Token, Type,
 };
 
 #[cfg(test)]
@@ -35,6 +35,12 @@ pub struct QPropertyFlags {
     pub(crate) is_final: bool,
     pub(crate) constant: bool,
     pub(crate) required: bool,
+    /// The interval in milliseconds which notify signals from the setter are coalesced over
+    ///
+    /// An interval of zero is used for `notify_deferred`, which emits on the next cycle of the event loop
+    pub(crate) notify_throttle_ms: Option<u32>,
+    /// The properties which this property is computed from, their notify signals also notify this property
+    pub(crate) depends_on: Vec<Ident>,
 }
 
 impl Default for QPropertyFlags {
@@ -48,10 +54,21 @@ impl Default for QPropertyFlags {
             is_final: false,
             constant: false,
             required: false,
+            notify_throttle_ms: None,
+            depends_on: vec![],
         }
     }
 }
 
+/// Describes how the Rust type of a property is converted to the type exposed to Qt
+#[derive(Debug)]
+pub struct QPropertyMapping {
+    /// The type from the bridge which is exposed to Qt, eg `QString`
+    pub cxx_type: Type,
+    /// The path to the type implementing `cxx_qt::MapQtValue` to convert between the types
+    pub map_with: Path,
+}
+
 /// Describes a single Q_PROPERTY for a struct
 pub struct ParsedQProperty {
     /// The [syn::Ident] of the property
@@ -60,6 +77,44 @@ pub struct ParsedQProperty {
     pub ty: Type,
     /// Property flag collection
     pub flags: QPropertyFlags,
+    /// The mapping of the Rust type to a Qt type, if `cxx_type` and `map_with` were passed
+    pub mapping: Option<QPropertyMapping>,
+    /// The `#[cfg(...)]` attributes of the property, from a `#[cfg_attr(..., qproperty(...))]` attribute
+    pub cfgs: Vec<Attribute>,
+}
+
+/// Parses the string value of a flag such as `cxx_type = "QString"`
+fn parse_string_value<T: syn::parse::Parse>(name_value: &MetaNameValue, usage: &str) -> Result<T> {
+    let error = || {
+        Error::new_spanned(
+            &name_value.value,
+            format!("Invalid value, it must be a string like `{usage}`!"),
+        )
+    };
+    let string = expr_to_string(&name_value.value).map_err(|_| error())?;
+    syn::parse_str(&string).map_err(|_| error())
+}
+
+/// Parses the interval of `notify_throttle_ms = 16`, which must be a positive integer
+fn parse_throttle_value(name_value: &MetaNameValue) -> Result<u32> {
+    let error = || {
+        Error::new_spanned(
+            &name_value.value,
+            "Invalid value, it must be a positive integer like `notify_throttle_ms = 16`!",
+        )
+    };
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(lit_int),
+        ..
+    }) = &name_value.value
+    {
+        match lit_int.base10_parse::<u32>() {
+            Ok(0) | Err(_) => Err(error()),
+            Ok(interval) => Ok(interval),
+        }
+    } else {
+        Err(error())
+    }
 }
 
 fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
@@ -78,8 +133,8 @@ fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
     } else if let Expr::Path(path_expr) = expr {
         path_expr.path.require_ident()?.clone()
     } else {
-        return Err(Error::new(
-            expr.span(),
+        return Err(Error::new_spanned(
+            expr,
             "Function signatures must be identifiers!",
         ));
     };
@@ -94,14 +149,21 @@ fn parse_meta(meta: Meta) -> Result<(Ident, Option<Ident>)> {
             let (field, ident) = parse_meta_name_value(&name_value)?;
             Ok((field, Some(ident)))
         }
-        _ => Err(Error::new(
-            meta.span(),
+        _ => Err(Error::new_spanned(
+            &meta,
             "Invalid syntax, flags must be specified as either `READ` or `READ = my_getter`!",
         )),
     }
 }
 
 impl ParsedQProperty {
+    /// The type of the property as it is exposed to Qt
+    pub fn cxx_ty(&self) -> &Type {
+        self.mapping
+            .as_ref()
+            .map_or(&self.ty, |mapping| &mapping.cxx_type)
+    }
+
     pub fn parse(attr: &Attribute) -> Result<Self> {
         attr.parse_args_with(|input: ParseStream| -> Result<Self> {
             let ty = input.parse()?;
@@ -109,11 +171,15 @@ impl ParsedQProperty {
             let ident = input.parse()?;
 
             if input.is_empty() {
+                lint::check_property_type(&ty)?;
+
                 // No flags passed so desugar: #[qproperty(T, ident)] -> #[qproperty(T, ident, read, write, notify)]
                 Ok(Self {
                     name: property_name_from_rust_name(ident),
                     ty,
                     flags: QPropertyFlags::default(),
+                    mapping: None,
+                    cfgs: vec![],
                 })
             } else {
                 let _comma = input.parse::<Token![,]>()?; // Start of final identifiers
@@ -135,6 +201,10 @@ impl ParsedQProperty {
                 let mut reset = None;
                 let mut cxx_name = None;
                 let mut rust_name = None;
+                let mut cxx_type = None;
+                let mut map_with = None;
+                let mut notify_throttle_ms = None;
+                let mut depends_on = vec![];
 
                 let map_auto_or_custom = |variable: &mut Option<FlagState>, value: &Option<Ident>| {
                     *variable = Some(value.as_ref().map_or(FlagState::Auto, |ident| FlagState::Custom(ident.clone())));
@@ -168,7 +238,7 @@ impl ParsedQProperty {
                                 "RESET" => reset = require_value("RESET flag", "RESET = my_reset_fn")?,
                                 _ => return Err(Error::new(
                                     ident.span(),
-                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
+                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL, cxx_name / rust_name, cxx_type / map_with, notify_throttle_ms / notify_deferred or depends_on(...)",
                                 ))
                             }
                         }
@@ -178,18 +248,91 @@ impl ParsedQProperty {
                 };
 
                 for flag in flags {
+                    // A deferred notify signal is throttled over an interval of zero
+                    if let Meta::Path(path) = &flag {
+                        if path.is_ident("notify_deferred") {
+                            if notify_throttle_ms.is_some() {
+                                return Err(Error::new_spanned(
+                                    path,
+                                    "notify_deferred cannot be combined with notify_throttle_ms!",
+                                ));
+                            }
+                            notify_throttle_ms = Some(0);
+                            continue;
+                        }
+                    }
+
+                    // The dependencies of a computed property are a list of property names
+                    if let Meta::List(list) = &flag {
+                        if list.path.is_ident("depends_on") {
+                            let idents = list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                            depends_on.extend(idents);
+                            continue;
+                        }
+                    }
+
+                    // The mapping flags take a type or path instead of an identifier
+                    if let Meta::NameValue(name_value) = &flag {
+                        if name_value.path.is_ident("cxx_type") {
+                            cxx_type = Some(parse_string_value(name_value, "cxx_type = \"QString\"")?);
+                            continue;
+                        }
+                        if name_value.path.is_ident("map_with") {
+                            map_with = Some(parse_string_value(name_value, "map_with = \"MyMapper\"")?);
+                            continue;
+                        }
+                        if name_value.path.is_ident("notify_throttle_ms") {
+                            if notify_throttle_ms.is_some() {
+                                return Err(Error::new_spanned(
+                                    name_value,
+                                    "notify_throttle_ms cannot be combined with notify_deferred!",
+                                ));
+                            }
+                            notify_throttle_ms = Some(parse_throttle_value(name_value)?);
+                            continue;
+                        }
+                    }
+
                     let (field, maybe_value) = parse_meta(flag)?;
                     update_fields(&field, maybe_value)?;
                 }
 
+                let mapping = match (cxx_type, map_with) {
+                    (Some(cxx_type), Some(map_with)) => Some(QPropertyMapping { cxx_type, map_with }),
+                    (None, None) => None,
+                    _ => return Err(Error::new_spanned(
+                        &punctuated_flags,
+                        "cxx_type and map_with must be passed together, eg `cxx_type = \"QString\", map_with = \"MyMapper\"`!",
+                    )),
+                };
+
+                // A mapped property can store any Rust type as Qt only sees the cxx_type
+                if mapping.is_none() {
+                    lint::check_property_type(&ty)?;
+                }
+
                 // Constance check
                 if constant && (write.is_some() || notify.is_some()) {
-                    return Err(Error::new(
-                        punctuated_flags.span(),
+                    return Err(Error::new_spanned(
+                        &punctuated_flags,
                         "QProperties marked as CONSTANT cannot have a setter or notify signal!",
                     ))
                 }
 
+                if notify_throttle_ms.is_some() && read_required && notify.is_none() {
+                    return Err(Error::new_spanned(
+                        &punctuated_flags,
+                        "notify_throttle_ms and notify_deferred can only be used on a property with a NOTIFY signal!",
+                    ))
+                }
+
+                if !depends_on.is_empty() && read_required && notify.is_none() {
+                    return Err(Error::new_spanned(
+                        &punctuated_flags,
+                        "depends_on can only be used on a property with a NOTIFY signal!",
+                    ))
+                }
+
                 let name = Name::new(ident).with_options(cxx_name.map(|ident| ident.to_string()), rust_name, AutoCamel::Enabled);
 
                 // This check is needed otherwise this fn would error unless READ, WRITE, etc... was passed with cxx_name
@@ -206,11 +349,15 @@ impl ParsedQProperty {
                                 is_final,
                                 constant,
                                 required,
+                                notify_throttle_ms,
+                                depends_on,
                             },
+                            mapping,
+                            cfgs: vec![],
                         })
                     } else {
-                        Err(Error::new(
-                            punctuated_flags.span(),
+                        Err(Error::new_spanned(
+                            &punctuated_flags,
                             "If any flags are passed, READ must be explicitly specified!",
                         ))
                     }
@@ -218,7 +365,14 @@ impl ParsedQProperty {
                     Ok(Self {
                         name,
                         ty,
-                        flags: QPropertyFlags::default(), // This block is hit if no flags, or only cxx / rust name were passed
+                        // This block is hit if no flags, or only naming, mapping, throttle or dependency flags were passed
+                        flags: QPropertyFlags {
+                            notify_throttle_ms,
+                            depends_on,
+                            ..QPropertyFlags::default()
+                        },
+                        mapping,
+                        cfgs: vec![],
                     })
                 }
             }
@@ -249,6 +403,51 @@ mod tests {
         assert_eq!(property.name.rust_unqualified(), "my_name");
     }
 
+    #[test]
+    fn test_parse_notify_throttle() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(f64, reading, notify_throttle_ms = 16)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.notify_throttle_ms, Some(16));
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(f64, reading, READ, NOTIFY = reading_changed, notify_throttle_ms = 100)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.notify_throttle_ms, Some(100));
+        assert!(property.flags.write.is_none());
+    }
+
+    #[test]
+    fn test_parse_notify_deferred() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, count, notify_deferred)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.notify_throttle_ms, Some(0));
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+    }
+
+    #[test]
+    fn test_parse_depends_on() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(QString, full_name, READ = full_name, NOTIFY, depends_on(first_name, last_name))]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(
+            property.flags.depends_on,
+            vec![format_ident!("first_name"), format_ident!("last_name")]
+        );
+        assert!(property.flags.write.is_none());
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+    }
+
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors! {
@@ -260,6 +459,20 @@ mod tests {
             { #[qproperty(T, name, READ, RESET)] }
             // Unknown flag
             { #[qproperty(T, name, READ = blah, a, NOTIFY = blahblah)] }
+            // Throttle without a notify signal
+            { #[qproperty(T, name, READ, WRITE, notify_throttle_ms = 16)] }
+            // Throttle interval is not a positive integer
+            { #[qproperty(T, name, notify_throttle_ms = "16")] }
+            { #[qproperty(T, name, notify_throttle_ms = 0)] }
+            // Deferred without a notify signal
+            { #[qproperty(T, name, READ, notify_deferred)] }
+            // Deferred and throttled
+            { #[qproperty(T, name, notify_deferred, notify_throttle_ms = 16)] }
+            { #[qproperty(T, name, notify_throttle_ms = 16, notify_deferred)] }
+            // Dependencies without a notify signal
+            { #[qproperty(T, name, READ = name, depends_on(other))] }
+            // Dependencies which aren't identifiers
+            { #[qproperty(T, name, depends_on("other"))] }
             // Invalid function specification syntax
             { #[qproperty(T, name, READ(my_getter))] }
             // Read missing
@@ -280,9 +493,30 @@ mod tests {
             { #[qproperty(T, name, cxx_name = "")] }
             // cxx_name had no value provided
             { #[qproperty(T, name, cxx_name)] }
+            // Rust type without a Qt meta type
+            { #[qproperty(String, name)] }
+            // cxx_type without map_with
+            { #[qproperty(Decimal, name, cxx_type = "QString")] }
+            // map_with should be a string
+            { #[qproperty(Decimal, name, cxx_type = "QString", map_with = DecimalAsQString)] }
         }
     }
 
+    #[test]
+    fn test_parse_map_with() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(String, name, cxx_type = "QString", map_with = "mappers::StringAsQString")]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.ty, parse_quote! { String });
+        assert_eq!(property.cxx_ty(), &parse_quote! { QString });
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+
+        let mapping = property.mapping.unwrap();
+        assert_eq!(mapping.map_with, parse_quote! { mappers::StringAsQString });
+    }
+
     #[test]
     fn test_parse_constant() {
         let input: ItemStruct = parse_quote! {
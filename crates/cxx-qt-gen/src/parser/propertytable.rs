@@ -0,0 +1,278 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Expand `qproperties!` tables into `#[qproperty]` attributes of their QObject.
+//!
+//! ```ignore
+//! extern "RustQt" {
+//!     #[qobject]
+//!     type Settings = super::SettingsRust;
+//!
+//!     qproperties! {
+//!         Settings {
+//!             i32 => font_size, line_height;
+//!             QString [READ, NOTIFY] => theme, language;
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! adds `#[qproperty(i32, font_size)]`, `#[qproperty(i32, line_height)]`,
+//! `#[qproperty(QString, theme, READ, NOTIFY)]` and `#[qproperty(QString, language, READ, NOTIFY)]` to `Settings`.
+
+use crate::syntax::path::path_compare_str;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, Error, ForeignItem, ForeignItemMacro, Ident, Item, ItemMod, ItemType, Result, Token,
+    Type,
+};
+
+/// A row of a property table, declaring properties of the same type and flags
+struct PropertyRow {
+    ty: Type,
+    flags: Option<TokenStream>,
+    names: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for PropertyRow {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        let flags = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        let _arrow: Token![=>] = input.parse()?;
+
+        // The names end at the semicolon of the row
+        let mut names = Punctuated::new();
+        loop {
+            names.push_value(input.parse()?);
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+            names.push_punct(input.parse()?);
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+        }
+        Ok(Self { ty, flags, names })
+    }
+}
+
+/// The contents of a `qproperties!` macro, which declares properties of a QObject
+struct PropertyTable {
+    qobject: Ident,
+    rows: Punctuated<PropertyRow, Token![;]>,
+}
+
+impl Parse for PropertyTable {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let qobject = input.parse()?;
+        let content;
+        braced!(content in input);
+        Ok(Self {
+            qobject,
+            rows: content.parse_terminated(PropertyRow::parse, Token![;])?,
+        })
+    }
+}
+
+impl PropertyTable {
+    /// The `#[qproperty]` attributes declared by the table
+    fn attrs(&self) -> Vec<Attribute> {
+        self.rows
+            .iter()
+            .flat_map(|row| {
+                let ty = &row.ty;
+                let flags = row.flags.as_ref().map(|flags| quote! { , #flags });
+                row.names
+                    .iter()
+                    .map(move |name| parse_quote! { #[qproperty(#ty, #name #flags)] })
+            })
+            .collect()
+    }
+}
+
+/// Replace the `qproperties!` macros in the extern "RustQt" blocks of the bridge
+/// with `#[qproperty]` attributes on their QObjects
+pub(crate) fn expand_property_tables(module: &mut ItemMod) -> Result<()> {
+    let items = if let Some((_, items)) = module.content.as_mut() {
+        items
+    } else {
+        return Ok(());
+    };
+
+    let mut tables = vec![];
+    for foreign_mod in items.iter_mut().filter_map(rust_qt_foreign_mod) {
+        let mut foreign_items = vec![];
+        for item in foreign_mod.items.drain(..) {
+            match item {
+                ForeignItem::Macro(ForeignItemMacro { ref mac, .. })
+                    if path_compare_str(&mac.path, &["qproperties"]) =>
+                {
+                    tables.push(mac.parse_body::<PropertyTable>()?);
+                }
+                other => foreign_items.push(other),
+            }
+        }
+        foreign_mod.items = foreign_items;
+    }
+
+    for table in tables {
+        // QObjects are declared as type aliases, which syn parses as verbatim tokens
+        let qobject = items
+            .iter_mut()
+            .filter_map(rust_qt_foreign_mod)
+            .flat_map(|foreign_mod| foreign_mod.items.iter_mut())
+            .find_map(|item| match item {
+                ForeignItem::Verbatim(tokens) => syn::parse2::<ItemType>(tokens.clone())
+                    .ok()
+                    .filter(|alias| alias.ident == table.qobject)
+                    .map(|alias| (tokens, alias)),
+                _ => None,
+            });
+        let (tokens, mut alias) = qobject.ok_or_else(|| {
+            Error::new_spanned(
+                &table.qobject,
+                format!(
+                    "No QObject named {} is declared in an extern \"RustQt\" block of this bridge",
+                    table.qobject
+                ),
+            )
+        })?;
+        alias.attrs.extend(table.attrs());
+        *tokens = alias.into_token_stream();
+    }
+
+    Ok(())
+}
+
+/// The extern "RustQt" block of the item
+fn rust_qt_foreign_mod(item: &mut Item) -> Option<&mut syn::ItemForeignMod> {
+    match item {
+        Item::ForeignMod(foreign_mod)
+            if foreign_mod
+                .abi
+                .name
+                .as_ref()
+                .is_some_and(|name| name.value() == "RustQt") =>
+        {
+            Some(foreign_mod)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+
+    #[test]
+    fn test_expand_property_tables() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(bool, enabled)]
+                    type Settings = super::SettingsRust;
+                }
+
+                extern "RustQt" {
+                    qproperties! {
+                        Settings {
+                            i32 => font_size, line_height;
+                            QString [READ, NOTIFY = theme_changed] => theme;
+                        }
+                    }
+                }
+            }
+        };
+        expand_property_tables(&mut module).unwrap();
+
+        let items = module.content.unwrap().1;
+        assert_eq!(
+            items[0].to_token_stream().to_string(),
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(bool, enabled)]
+                    #[qproperty(i32, font_size)]
+                    #[qproperty(i32, line_height)]
+                    #[qproperty(QString, theme, READ, NOTIFY = theme_changed)]
+                    type Settings = super::SettingsRust;
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            items[1].to_token_stream().to_string(),
+            quote! { extern "RustQt" {} }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_property_tables() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type Settings = super::SettingsRust;
+
+                    qproperties! {
+                        Settings {
+                            i32 => font_size, line_height, tab_width,;
+                            QString [READ] => theme
+                        }
+                    }
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let properties = &parser.cxx_qt_data.qobjects[0].properties;
+        assert_eq!(properties.len(), 4);
+        assert_eq!(properties[2].name.rust_unqualified(), "tab_width");
+        assert_eq!(properties[3].name.rust_unqualified(), "theme");
+        assert!(properties[3].flags.write.is_none());
+    }
+
+    #[test]
+    fn test_expand_property_tables_invalid() {
+        let invalid = [
+            // Unknown QObject
+            quote! { qproperties! { Unknown { i32 => number; } } },
+            // Missing arrow
+            quote! { qproperties! { Settings { i32 number; } } },
+            // Missing name
+            quote! { qproperties! { Settings { i32 => ; } } },
+        ];
+        for tokens in invalid {
+            let mut module: ItemMod = parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type Settings = super::SettingsRust;
+
+                        #tokens
+                    }
+                }
+            };
+            assert!(expand_property_tables(&mut module).is_err());
+        }
+    }
+}
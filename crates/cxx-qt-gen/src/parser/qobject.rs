@@ -5,13 +5,15 @@
 
 use crate::{
     naming::Name,
-    parser::{property::ParsedQProperty, require_attributes},
+    parser::{extract_cfgs, property::ParsedQProperty, require_attributes},
     syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
 };
 #[cfg(test)]
 use quote::format_ident;
 
-use syn::{Attribute, Error, Expr, Ident, Meta, Result};
+use syn::{
+    parse_quote, punctuated::Punctuated, Attribute, Error, Expr, Ident, Meta, Result, Token,
+};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -39,13 +41,18 @@ pub struct ParsedQObject {
     pub qml_metadata: Option<QmlElementMetadata>,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// The file stem of a separate header to generate the class into, from `#[cxx_file_stem = "..."]`
+    pub cxx_file_stem: Option<String>,
+    /// The `#[cfg(...)]` attributes, the QObject is only generated when all of them are enabled
+    pub cfgs: Vec<Attribute>,
     /// The original declaration entered by the user, i.e. a type alias with a list of attributes
     pub declaration: ForeignTypeIdentAlias,
 }
 
 impl ParsedQObject {
-    const ALLOWED_ATTRS: [&'static str; 10] = [
+    const ALLOWED_ATTRS: [&'static str; 13] = [
         "cxx_name",
+        "cxx_file_stem",
         "rust_name",
         "namespace",
         "doc",
@@ -55,6 +62,8 @@ impl ParsedQObject {
         "qml_uncreatable",
         "qml_singleton",
         "qproperty",
+        "cfg",
+        "cfg_attr",
     ];
     #[cfg(test)]
     pub fn mock() -> Self {
@@ -65,6 +74,8 @@ impl ParsedQObject {
             properties: vec![],
             qml_metadata: None,
             has_qobject_macro: false,
+            cxx_file_stem: None,
+            cfgs: vec![],
             declaration: ForeignTypeIdentAlias {
                 attrs: vec![],
                 ident_left: format_ident!("MyObject"),
@@ -97,6 +108,21 @@ impl ParsedQObject {
             })
             .transpose()?;
 
+        let cxx_file_stem = attributes
+            .get("cxx_file_stem")
+            .map(|attr| -> Result<String> {
+                let expr = &attr.meta.require_name_value()?.value;
+                let stem = expr_to_string(expr)?;
+                if stem.is_empty() || stem.contains(['/', '\\', '.']) {
+                    return Err(Error::new_spanned(
+                        expr,
+                        "cxx_file_stem must be a file name without a directory or extension!",
+                    ));
+                }
+                Ok(stem)
+            })
+            .transpose()?;
+
         // Ensure that if there is no qobject macro that a base class is specificed
         if !has_qobject_macro && base_class.is_none() {
             return Err(Error::new_spanned(
@@ -119,6 +145,7 @@ impl ParsedQObject {
         // and remove the #[qproperty] attribute
         let properties = Self::parse_property_attributes(&declaration.attrs)?;
         let inner = declaration.ident_right.clone();
+        let cfgs = extract_cfgs(&declaration.attrs);
 
         Ok(Self {
             base_class,
@@ -128,6 +155,8 @@ impl ParsedQObject {
             properties,
             qml_metadata,
             has_qobject_macro,
+            cxx_file_stem,
+            cfgs,
         })
     }
 
@@ -155,11 +184,49 @@ impl ParsedQObject {
         // Once extract_if is stable, this would allow comparing all the elements using
         // path_compare_str and building ParsedQProperty from the extracted elements.
         // https://doc.rust-lang.org/nightly/std/vec/struct.Vec.html#method.extract_if
-        attrs
-            .iter()
-            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
-            .map(ParsedQProperty::parse)
-            .collect::<Result<Vec<_>>>()
+        let mut properties = vec![];
+        for attr in attrs {
+            if path_compare_str(attr.meta.path(), &["qproperty"]) {
+                properties.push(ParsedQProperty::parse(attr)?);
+            } else if path_compare_str(attr.meta.path(), &["cfg_attr"]) {
+                properties.append(&mut Self::parse_cfg_attr_properties(attr)?);
+            }
+        }
+        Ok(properties)
+    }
+
+    /// Parse the properties of a `#[cfg_attr(predicate, qproperty(...), ...)]` attribute,
+    /// which are only generated when the predicate is enabled
+    fn parse_cfg_attr_properties(attr: &Attribute) -> Result<Vec<ParsedQProperty>> {
+        let mut nested = attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+            .into_iter();
+        let predicate = nested.next().ok_or_else(|| {
+            Error::new_spanned(attr, "Expected #[cfg_attr(predicate, qproperty(...))]")
+        })?;
+        let cfg: Attribute = parse_quote! { #[cfg(#predicate)] };
+
+        let metas = nested.collect::<Vec<_>>();
+        if metas.is_empty() {
+            return Err(Error::new_spanned(
+                attr,
+                "Expected #[cfg_attr(predicate, qproperty(...))]",
+            ));
+        }
+        metas
+            .into_iter()
+            .map(|meta| {
+                if !path_compare_str(meta.path(), &["qproperty"]) {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "Only qproperty attributes are supported in a #[cfg_attr] on a QObject",
+                    ));
+                }
+                let mut property = ParsedQProperty::parse(&parse_quote! { #[#meta] })?;
+                property.cfgs.push(cfg.clone());
+                Ok(property)
+            })
+            .collect()
     }
 }
 
@@ -336,6 +403,38 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_cxx_file_stem() {
+        let qobject = create_parsed_qobject();
+        assert!(qobject.cxx_file_stem.is_none());
+
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[cxx_file_stem = "my_object"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(qobject.cxx_file_stem.as_deref(), Some("my_object"));
+    }
+
+    #[test]
+    fn test_cfgs() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[cfg(unix)]
+            #[qproperty(i32, number)]
+            #[cfg_attr(target_os = "android", qproperty(QString, token), qproperty(i32, level))]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(qobject.cfgs.len(), 1);
+        assert_eq!(qobject.properties.len(), 3);
+        assert!(qobject.properties[0].cfgs.is_empty());
+        assert_eq!(qobject.properties[1].name.rust_unqualified(), "token");
+        assert_eq!(
+            qobject.properties[2].cfgs,
+            vec![parse_quote! { #[cfg(target_os = "android")] }]
+        );
+    }
+
     #[test]
     fn test_parse_errors() {
         assert_parse_errors! {
@@ -347,6 +446,36 @@ pub mod tests {
                 type MyObject = super::T;
             }
             { type MyObject = super::T; }
+            {
+                #[qobject]
+                #[cxx_file_stem = ""]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cxx_file_stem = "dir/my_object"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cxx_file_stem = "my_object.h"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cxx_file_stem]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cfg_attr(unix, qml_element)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cfg_attr(unix)]
+                type MyObject = super::T;
+            }
         }
     }
 }
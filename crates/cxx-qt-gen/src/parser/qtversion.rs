@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_get_path;
+use quote::format_ident;
+use syn::{parse_quote, Attribute, Error, LitStr, Result};
+
+/// The newest Qt major version that cxx-qt-build defines `cxxqt_qt_version_at_least` cfgs for
+const MAX_QT_VERSION_MAJOR: u32 = 7;
+
+/// The minimum Qt version that an item of the bridge requires, from a `#[qt(since = "6.4")]` attribute
+///
+/// The Rust code of the item is only compiled with the cfgs that cxx-qt-build defines for the Qt version
+/// and the C++ code with a `QT_VERSION` check, so that a crate can expose newer Qt APIs
+/// while still compiling against older Qt versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QtVersion {
+    /// The major version, eg 6
+    pub major: u32,
+    /// The minor version, eg 4
+    pub minor: u32,
+}
+
+impl QtVersion {
+    /// Find and parse a `#[qt(since = "...")]` attribute in the given attributes
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Option<Self>> {
+        attribute_get_path(attrs, &["qt"])
+            .map(Self::parse)
+            .transpose()
+    }
+
+    fn parse(attr: &Attribute) -> Result<Self> {
+        let mut since = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("since") {
+                let version: LitStr = meta.value()?.parse()?;
+                since = Some(Self::parse_version(&version)?);
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported qt attribute, expected `since = \"<major>.<minor>\"`"))
+            }
+        })?;
+
+        since.ok_or_else(|| {
+            Error::new_spanned(attr, "Expected `#[qt(since = \"<major>.<minor>\")]`")
+        })
+    }
+
+    fn parse_version(version: &LitStr) -> Result<Self> {
+        let value = version.value();
+        let parsed = value.split_once('.').and_then(|(major, minor)| {
+            Some(Self {
+                major: major.parse().ok()?,
+                minor: minor.parse().ok()?,
+            })
+        });
+
+        match parsed {
+            Some(parsed) if (5..=MAX_QT_VERSION_MAJOR).contains(&parsed.major) && parsed.minor <= 99 => {
+                Ok(parsed)
+            }
+            _ => Err(Error::new(
+                version.span(),
+                format!("Expected a Qt version from 5.0 to {MAX_QT_VERSION_MAJOR}.99 in the form \"<major>.<minor>\""),
+            )),
+        }
+    }
+
+    /// The cfg attribute for the Rust code of the item
+    pub fn cfg_attribute(&self) -> Attribute {
+        let at_least = format_ident!("cxxqt_qt_version_at_least_{}_{}", self.major, self.minor);
+        if self.major < MAX_QT_VERSION_MAJOR {
+            let next_major = format_ident!("cxxqt_qt_version_at_least_{}", self.major + 1);
+            parse_quote! { #[cfg(any(#next_major, #at_least))] }
+        } else {
+            parse_quote! { #[cfg(#at_least)] }
+        }
+    }
+
+    /// The preprocessor condition for the C++ code of the item
+    pub fn cpp_condition(&self) -> String {
+        format!(
+            "QT_VERSION >= QT_VERSION_CHECK({major}, {minor}, 0)",
+            major = self.major,
+            minor = self.minor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{assert_parse_errors, assert_tokens_eq};
+    use quote::quote;
+    use syn::ForeignItemFn;
+
+    #[test]
+    fn test_qt_version_from_attrs() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.4")]
+            fn new_api(self: &MyObject);
+        };
+        let since = QtVersion::from_attrs(&method.attrs).unwrap().unwrap();
+        assert_eq!(since, QtVersion { major: 6, minor: 4 });
+        assert_eq!(
+            since.cpp_condition(),
+            "QT_VERSION >= QT_VERSION_CHECK(6, 4, 0)"
+        );
+        assert_tokens_eq(
+            &since.cfg_attribute(),
+            quote! {
+                #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_4))]
+            },
+        );
+
+        let method: ForeignItemFn = parse_quote! {
+            fn old_api(self: &MyObject);
+        };
+        assert!(QtVersion::from_attrs(&method.attrs).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_qt_version_latest_major() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "7.0")]
+            fn future_api(self: &MyObject);
+        };
+        let since = QtVersion::from_attrs(&method.attrs).unwrap().unwrap();
+        assert_tokens_eq(
+            &since.cfg_attribute(),
+            quote! { #[cfg(cxxqt_qt_version_at_least_7_0)] },
+        );
+    }
+
+    #[test]
+    fn test_qt_version_invalid() {
+        let parse = |method: ForeignItemFn| QtVersion::from_attrs(&method.attrs);
+        assert_parse_errors! {
+            parse =>
+
+            { #[qt(since = "6")] fn f(self: &MyObject); }
+            { #[qt(since = "4.8")] fn f(self: &MyObject); }
+            { #[qt(since = "6.x")] fn f(self: &MyObject); }
+            { #[qt(until = "6.4")] fn f(self: &MyObject); }
+            { #[qt] fn f(self: &MyObject); }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{naming::Name, syntax::expr::expr_to_string};
+use syn::{Error, Fields, Ident, ItemStruct, Result, Type};
+
+/// A field of a shared struct which is converted to an entry of a QVariantMap
+pub struct ParsedSharedStructField {
+    /// The name of the field in C++, which is also the key of the entry
+    pub cxx_name: String,
+    /// The Rust type of the field
+    pub ty: Type,
+}
+
+/// A CXX shared struct with a `#[qvariant_map]` attribute,
+/// which is converted to and from a QVariantMap so that it can be used from QML
+pub struct ParsedSharedStruct {
+    /// The name of the struct
+    pub name: Name,
+    /// The fields of the struct
+    pub fields: Vec<ParsedSharedStructField>,
+}
+
+impl ParsedSharedStruct {
+    /// Parse the struct, removing the `#[qvariant_map]` attribute so that it can be passed through to CXX
+    pub fn parse(
+        item: &mut ItemStruct,
+        parent_namespace: Option<&str>,
+        module: &Ident,
+    ) -> Result<Self> {
+        item.attrs
+            .retain(|attr| !attr.path().is_ident("qvariant_map"));
+
+        let name =
+            Name::from_ident_and_attrs(&item.ident, &item.attrs, parent_namespace, Some(module))?;
+
+        let named_fields = if let Fields::Named(named_fields) = &item.fields {
+            named_fields
+        } else {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "#[qvariant_map] is only supported on shared structs with named fields",
+            ));
+        };
+
+        let fields = named_fields
+            .named
+            .iter()
+            .map(|field| {
+                // The struct is default constructed before the entries of the map are assigned
+                if matches!(field.ty, Type::Reference(_)) {
+                    return Err(Error::new_spanned(
+                        &field.ty,
+                        "#[qvariant_map] structs cannot contain references",
+                    ));
+                }
+
+                let cxx_name = field
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path().is_ident("cxx_name"))
+                    .map(|attr| expr_to_string(&attr.meta.require_name_value()?.value))
+                    .transpose()?;
+                Ok(ParsedSharedStructField {
+                    cxx_name: cxx_name.unwrap_or_else(|| {
+                        field
+                            .ident
+                            .as_ref()
+                            .map(Ident::to_string)
+                            .unwrap_or_default()
+                    }),
+                    ty: field.ty.clone(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { name, fields })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse() {
+        let mut item: ItemStruct = parse_quote! {
+            #[qvariant_map]
+            #[namespace = "geometry"]
+            struct Point {
+                x: i32,
+                #[cxx_name = "posY"]
+                y: i32,
+            }
+        };
+        let shared_struct =
+            ParsedSharedStruct::parse(&mut item, Some("other"), &format_ident!("ffi")).unwrap();
+
+        assert_eq!(item.attrs.len(), 1);
+        assert_eq!(shared_struct.name.cxx_qualified(), "geometry::Point");
+        assert_eq!(shared_struct.fields.len(), 2);
+        assert_eq!(shared_struct.fields[0].cxx_name, "x");
+        assert_eq!(shared_struct.fields[1].cxx_name, "posY");
+        assert_eq!(shared_struct.fields[1].ty, parse_quote! { i32 });
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_parse_errors! {
+            |mut item: ItemStruct| ParsedSharedStruct::parse(&mut item, None, &format_ident!("ffi")) =>
+
+            { #[qvariant_map] struct Point(i32, i32); }
+            { #[qvariant_map] struct Name<'a> { name: &'a str } }
+            { #[qvariant_map] struct Point { #[cxx_name = 1] x: i32 } }
+        }
+    }
+}
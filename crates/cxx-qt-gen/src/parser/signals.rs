@@ -3,11 +3,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
-    parser::{check_safety, extract_docs, method::MethodFields, require_attributes},
-    syntax::{path::path_compare_str, safety::Safety},
+    parser::{check_safety, doc_lines, extract_docs, method::MethodFields, require_attributes},
+    syntax::{expr::expr_to_string, path::path_compare_str, safety::Safety},
 };
 use core::ops::Deref;
-use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Result, Visibility};
+use syn::{parse_quote, Attribute, Error, FnArg, ForeignItemFn, Result, Type, Visibility};
 #[derive(Clone)]
 /// Describes an individual Signal
 pub struct ParsedSignal {
@@ -22,7 +22,18 @@ pub struct ParsedSignal {
 }
 
 impl ParsedSignal {
-    const ALLOWED_ATTRS: [&'static str; 5] = ["cxx_name", "rust_name", "inherit", "doc", "qsignal"];
+    const ALLOWED_ATTRS: [&'static str; 8] = [
+        "cxx_name",
+        "rust_name",
+        "inherit",
+        "doc",
+        "qsignal",
+        "qt",
+        "forward_from",
+        "cfg",
+    ];
+
+    const ALLOWED_PARAMETER_ATTRS: [&'static str; 2] = ["cxx_name", "doc"];
 
     #[cfg(test)]
     /// Test fn for creating a mocked signal from a method body
@@ -33,17 +44,63 @@ impl ParsedSignal {
     pub fn parse(method: ForeignItemFn, safety: Safety) -> Result<Self> {
         check_safety(&method, &safety)?;
 
-        let docs = extract_docs(&method.attrs);
-        let fields = MethodFields::parse(method)?;
+        let mut docs = extract_docs(&method.attrs);
+        let mut fields = MethodFields::parse(method)?;
         let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
 
         if !fields.mutable {
-            return Err(Error::new(
-                fields.method.span(),
+            return Err(Error::new_spanned(
+                fields.method.sig.inputs.first(),
                 "signals must be mutable, use Pin<&mut T> instead of T for the self type",
             ));
         }
 
+        // Every connected handler receives the same arguments, so they can't be mutably borrowed
+        if let Some(parameter) = fields.parameters.iter().find(|parameter| {
+            matches!(&parameter.ty, Type::Reference(reference) if reference.mutability.is_some())
+        }) {
+            return Err(Error::new_spanned(
+                &parameter.ty,
+                "signal parameters cannot be mutable references, use &T to pass a const reference or T to pass an owned value",
+            ));
+        }
+
+        // Parameters can be renamed for C++ and documented, the docs are listed with the signal
+        let mut parameter_docs = vec![];
+        let typed_inputs = fields
+            .method
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => Some(pat_type),
+                FnArg::Receiver(_) => None,
+            });
+        for (parameter, pat_type) in fields.parameters.iter_mut().zip(typed_inputs) {
+            let parameter_attrs =
+                require_attributes(&pat_type.attrs, &Self::ALLOWED_PARAMETER_ATTRS)?;
+            if let Some(attr) = parameter_attrs.get("cxx_name") {
+                parameter.cxx_name = Some(expr_to_string(&attr.meta.require_name_value()?.value)?);
+            }
+
+            let lines = doc_lines(&pat_type.attrs);
+            if let Some((first, rest)) = lines.split_first() {
+                let ident = &parameter.ident;
+                parameter_docs.push(format!(" * `{ident}` -{first}"));
+                parameter_docs.extend(rest.iter().map(|line| format!("  {line}")));
+            }
+        }
+        if !parameter_docs.is_empty() {
+            if !docs.is_empty() {
+                docs.push(parse_quote! { #[doc = ""] });
+            }
+            docs.push(parse_quote! { #[doc = " # Parameters"] });
+            docs.push(parse_quote! { #[doc = ""] });
+            docs.extend(parameter_docs.into_iter().map(|line| -> Attribute {
+                parse_quote! { #[doc = #line] }
+            }));
+        }
+
         let inherit = attrs.contains_key("inherit");
 
         let private = if let Visibility::Restricted(vis_restricted) = &fields.method.vis {
@@ -95,6 +152,12 @@ mod tests {
             { fn ready(x: f64); }
             // Self needs to be receiver like self: &T instead of &self
             { fn ready(&self); }
+            // No mutable references as parameters
+            { fn ready(self: Pin<&mut MyObject>, value: &mut QString); }
+            // Unsupported parameter attribute
+            { fn ready(self: Pin<&mut MyObject>, #[rust_name = "other"] value: f64); }
+            // Parameter cxx_name must be a string
+            { fn ready(self: Pin<&mut MyObject>, #[cxx_name = 1] value: f64); }
         }
     }
 
@@ -177,6 +240,37 @@ mod tests {
         assert!(!signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_parameter_attributes() {
+        let method: ForeignItemFn = parse_quote! {
+            /// Emitted when ready
+            fn ready(
+                self: Pin<&mut MyObject>,
+                /// The horizontal position
+                #[cxx_name = "posX"]
+                x: f64,
+                y: f64,
+            );
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+        assert_eq!(signal.parameters.len(), 2);
+        assert_eq!(signal.parameters[0].cxx_name, Some("posX".to_owned()));
+        assert_eq!(signal.parameters[0].cxx_ident(), "posX");
+        assert_eq!(signal.parameters[1].cxx_name, None);
+        assert_eq!(signal.parameters[1].cxx_ident(), "y");
+
+        assert_eq!(
+            doc_lines(&signal.docs),
+            vec![
+                " Emitted when ready",
+                "",
+                " # Parameters",
+                "",
+                " * `x` - The horizontal position",
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_signal_private() {
         let method: ForeignItemFn = parse_quote! {
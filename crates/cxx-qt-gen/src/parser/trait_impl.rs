@@ -12,23 +12,33 @@ use crate::{parser::constructor::Constructor, syntax::path::path_compare_str};
 pub enum TraitKind {
     Threading,
     Constructor(Constructor),
+    NewCppObject,
+    Worker,
+    InteriorMutability,
 }
 
 impl TraitKind {
-    fn parse_threading(not: &Option<Token![!]>, path: &Path, imp: &ItemImpl) -> Result<Self> {
+    /// Parse a marker trait which is safe to implement and can't be negated
+    fn parse_marker(
+        kind: Self,
+        trait_name: &str,
+        not: &Option<Token![!]>,
+        path: &Path,
+        imp: &ItemImpl,
+    ) -> Result<Self> {
         if let Some(unsafety) = imp.unsafety.as_ref() {
             return Err(Error::new_spanned(
                 unsafety,
-                "Unnecessary unsafe, cxx_qt::Threading is safe to implement!",
+                format!("Unnecessary unsafe, cxx_qt::{trait_name} is safe to implement!"),
             ));
         }
         if not.is_some() {
             return Err(Error::new_spanned(
                 path,
-                "Negative impls for cxx_qt::Threading are not allowed!",
+                format!("Negative impls for cxx_qt::{trait_name} are not allowed!"),
             ));
         }
-        Ok(Self::Threading)
+        Ok(kind)
     }
 
     fn parse_constructor(imp: &ItemImpl) -> Result<Self> {
@@ -43,9 +53,21 @@ impl TraitKind {
             .ok_or_else(|| Error::new_spanned(imp.clone(), "Expected trait impl!"))?;
 
         if path_compare_str(path, &["cxx_qt", "Threading"]) {
-            Self::parse_threading(not, path, imp)
+            Self::parse_marker(Self::Threading, "Threading", not, path, imp)
         } else if path_compare_str(path, &["cxx_qt", "Constructor"]) {
             Self::parse_constructor(imp)
+        } else if path_compare_str(path, &["cxx_qt", "NewCppObject"]) {
+            Self::parse_marker(Self::NewCppObject, "NewCppObject", not, path, imp)
+        } else if path_compare_str(path, &["cxx_qt", "Worker"]) {
+            Self::parse_marker(Self::Worker, "Worker", not, path, imp)
+        } else if path_compare_str(path, &["cxx_qt", "InteriorMutability"]) {
+            Self::parse_marker(
+                Self::InteriorMutability,
+                "InteriorMutability",
+                not,
+                path,
+                imp,
+            )
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
@@ -55,6 +77,9 @@ impl TraitKind {
                     CXX-Qt currently only supports:
                       - cxx_qt::Threading
                       - cxx_qt::Constructor
+                      - cxx_qt::NewCppObject
+                      - cxx_qt::Worker
+                      - cxx_qt::InteriorMutability
                       - (cxx_qt::Locking has been removed as of CXX-Qt 0.7)
                     Note that the trait must always be fully-qualified.
                     "},
@@ -120,6 +145,36 @@ mod tests {
         assert_eq!(marker.kind, TraitKind::Threading);
     }
 
+    #[test]
+    fn parse_new_cpp_object() {
+        let imp = parse_quote! {
+            impl cxx_qt::NewCppObject for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(marker.kind, TraitKind::NewCppObject);
+    }
+
+    #[test]
+    fn parse_worker() {
+        let imp = parse_quote! {
+            impl cxx_qt::Worker for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(marker.kind, TraitKind::Worker);
+    }
+
+    #[test]
+    fn parse_interior_mutability() {
+        let imp = parse_quote! {
+            impl cxx_qt::InteriorMutability for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(marker.kind, TraitKind::InteriorMutability);
+    }
+
     #[test]
     fn parse_constructor() {
         let imp = parse_quote! {
@@ -141,6 +196,18 @@ mod tests {
             { unsafe impl cxx_qt::Threading for QObject {} }
             // Threading cannot be negated
             { impl !cxx_qt::Threading for QObject {} }
+            // NewCppObject is safe to implement
+            { unsafe impl cxx_qt::NewCppObject for QObject {} }
+            // NewCppObject cannot be negated
+            { impl !cxx_qt::NewCppObject for QObject {} }
+            // Worker is safe to implement
+            { unsafe impl cxx_qt::Worker for QObject {} }
+            // Worker cannot be negated
+            { impl !cxx_qt::Worker for QObject {} }
+            // InteriorMutability is safe to implement
+            { unsafe impl cxx_qt::InteriorMutability for QObject {} }
+            // InteriorMutability cannot be negated
+            { impl !cxx_qt::InteriorMutability for QObject {} }
             // Invalid QObject name
             { impl cxx_qt::Locking for my::path {} }
             // Invalid trait name
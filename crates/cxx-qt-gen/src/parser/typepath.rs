@@ -0,0 +1,402 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Resolve the paths of types in signatures to the types declared in the bridge.
+//!
+//! CXX only supports single identifiers as types, so `cxx_qt_lib::QString` in the signature of
+//! an invokable, signal or property is replaced by the identifier which the bridge uses for it.
+
+use crate::{registry::TypeRegistry, syntax::path::path_compare_str};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::ParseStream, Attribute, Error, FnArg, ForeignItem, GenericArgument, Ident, Item,
+    ItemForeignMod, ItemMod, ItemType, Path, PathArguments, Result, ReturnType, Type,
+};
+
+/// The crate whose types are declared automatically when they are used with their path
+const CXX_QT_LIB: &str = "cxx_qt_lib";
+
+/// Replace the paths of types in the signatures of the bridge with the identifiers they are declared as
+///
+/// A path is resolved to a `type X = path;` alias of the bridge, a type of the [TypeRegistry],
+/// or for `cxx_qt_lib` types to a new declaration including the header of cxx-qt-lib.
+/// Aliases of `cxx_qt_lib` types with a different name, eg `type Text = cxx_qt_lib::QString;`,
+/// are given the C++ name of the type.
+pub(crate) fn resolve_type_paths(module: &mut ItemMod, registry: &TypeRegistry) -> Result<()> {
+    let items = if let Some((_, items)) = module.content.as_mut() {
+        items
+    } else {
+        return Ok(());
+    };
+
+    let mut resolver = TypePathResolver {
+        registry,
+        aliases: vec![],
+        declared: vec![],
+        declarations: vec![],
+    };
+    for foreign_mod in foreign_mods(items, &["C++"]) {
+        resolver.collect_declarations(foreign_mod);
+    }
+    for foreign_mod in foreign_mods(items, &["RustQt", "C++Qt"]) {
+        resolver.resolve_foreign_mod(foreign_mod)?;
+    }
+
+    items.extend(resolver.declarations.into_iter().map(Item::ForeignMod));
+    Ok(())
+}
+
+/// The extern blocks of the bridge with one of the given ABIs
+fn foreign_mods<'a>(
+    items: &'a mut [Item],
+    abis: &'a [&str],
+) -> impl Iterator<Item = &'a mut ItemForeignMod> {
+    items.iter_mut().filter_map(move |item| match item {
+        Item::ForeignMod(foreign_mod)
+            if foreign_mod
+                .abi
+                .name
+                .as_ref()
+                .is_some_and(|name| abis.contains(&name.value().as_str())) =>
+        {
+            Some(foreign_mod)
+        }
+        _ => None,
+    })
+}
+
+/// A path as a string which doesn't depend on the spacing or a leading `::`
+fn path_key(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.to_token_stream().to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// The name of a `cxx_qt_lib` type, if the path is a `cxx_qt_lib` type without generic arguments
+fn cxx_qt_lib_ident(path: &Path) -> Option<&Ident> {
+    match path.segments.iter().collect::<Vec<_>>().as_slice() {
+        [krate, ty] if krate.ident == CXX_QT_LIB && ty.arguments.is_none() => Some(&ty.ident),
+        _ => None,
+    }
+}
+
+struct TypePathResolver<'a> {
+    registry: &'a TypeRegistry,
+    /// The paths of the `type X = path;` aliases, with the identifier they are declared as
+    aliases: Vec<(String, Ident)>,
+    /// The identifiers of all types declared in extern "C++" blocks
+    declared: Vec<Ident>,
+    /// The declarations of `cxx_qt_lib` types which are added to the bridge
+    declarations: Vec<ItemForeignMod>,
+}
+
+impl TypePathResolver<'_> {
+    fn collect_declarations(&mut self, foreign_mod: &mut ItemForeignMod) {
+        for item in &mut foreign_mod.items {
+            match item {
+                ForeignItem::Type(foreign_ty) => self.declared.push(foreign_ty.ident.clone()),
+                ForeignItem::Verbatim(tokens) => {
+                    let mut alias = if let Ok(alias) = syn::parse2::<ItemType>(tokens.clone()) {
+                        alias
+                    } else {
+                        continue;
+                    };
+                    if let Type::Path(type_path) = &*alias.ty {
+                        if let Some(ident) = cxx_qt_lib_ident(&type_path.path) {
+                            // CXX checks that the C++ name of the alias matches the C++ name of the type
+                            if *ident != alias.ident
+                                && !alias
+                                    .attrs
+                                    .iter()
+                                    .any(|attr| attr.path().is_ident("cxx_name"))
+                            {
+                                let cxx_name = ident.to_string();
+                                alias
+                                    .attrs
+                                    .push(syn::parse_quote! { #[cxx_name = #cxx_name] });
+                                *tokens = alias.to_token_stream();
+                            }
+                        }
+                        self.aliases
+                            .push((path_key(&type_path.path), alias.ident.clone()));
+                    }
+                    self.declared.push(alias.ident);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn resolve_foreign_mod(&mut self, foreign_mod: &mut ItemForeignMod) -> Result<()> {
+        for item in &mut foreign_mod.items {
+            match item {
+                ForeignItem::Fn(foreign_fn) => {
+                    for input in &mut foreign_fn.sig.inputs {
+                        if let FnArg::Typed(pat_type) = input {
+                            self.resolve_type(&mut pat_type.ty)?;
+                        }
+                    }
+                    if let ReturnType::Type(_, ty) = &mut foreign_fn.sig.output {
+                        self.resolve_type(ty)?;
+                    }
+                }
+                // The QObjects of extern "RustQt" blocks, whose properties can use paths
+                ForeignItem::Verbatim(tokens) => {
+                    let mut alias = if let Ok(alias) = syn::parse2::<ItemType>(tokens.clone()) {
+                        alias
+                    } else {
+                        continue;
+                    };
+                    let mut changed = false;
+                    for attr in &mut alias.attrs {
+                        if path_compare_str(attr.path(), &["qproperty"]) {
+                            changed |= self.resolve_property(attr)?;
+                        }
+                    }
+                    if changed {
+                        *tokens = alias.to_token_stream();
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve the type of a `#[qproperty(T, ...)]` attribute, returning true if it changed
+    fn resolve_property(&mut self, attr: &mut Attribute) -> Result<bool> {
+        // Invalid properties are reported when the property is parsed
+        let (mut ty, rest) = if let Ok(args) = attr.parse_args_with(|input: ParseStream| {
+            let ty: Type = input.parse()?;
+            let rest: TokenStream = input.parse()?;
+            Ok((ty, rest))
+        }) {
+            args
+        } else {
+            return Ok(false);
+        };
+        let original = ty.clone();
+        self.resolve_type(&mut ty)?;
+        if ty == original {
+            return Ok(false);
+        }
+
+        let path = attr.path().clone();
+        *attr = syn::parse_quote! { #[#path(#ty #rest)] };
+        Ok(true)
+    }
+
+    fn resolve_type(&mut self, ty: &mut Type) -> Result<()> {
+        match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => {
+                if type_path.path.segments.len() > 1 {
+                    if let Some(ident) = self.resolve_path(&type_path.path)? {
+                        *ty = syn::parse_quote! { #ident };
+                    }
+                } else {
+                    for segment in &mut type_path.path.segments {
+                        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                            for argument in &mut arguments.args {
+                                if let GenericArgument::Type(ty) = argument {
+                                    self.resolve_type(ty)?;
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Type::Reference(reference) => self.resolve_type(&mut reference.elem),
+            Type::Ptr(ptr) => self.resolve_type(&mut ptr.elem),
+            Type::Array(array) => self.resolve_type(&mut array.elem),
+            Type::Slice(slice) => self.resolve_type(&mut slice.elem),
+            _ => Ok(()),
+        }
+    }
+
+    /// The identifier for the path, or None if it can't be resolved
+    fn resolve_path(&mut self, path: &Path) -> Result<Option<Ident>> {
+        let key = path_key(path);
+        if let Some((_, ident)) = self.aliases.iter().find(|(alias, _)| *alias == key) {
+            return Ok(Some(ident.clone()));
+        }
+        if let Some(ident) = self.registry.ident_for_path(path) {
+            return Ok(Some(ident));
+        }
+
+        if let Some(ident) = cxx_qt_lib_ident(path) {
+            if self.declared.contains(ident) {
+                return Err(Error::new_spanned(
+                    path,
+                    format!("{ident} is already declared in the bridge as a different type, declare an alias like `type {ident}Alias = {key};` to use both types"),
+                ));
+            }
+
+            let include = format!("cxx-qt-lib/{}.h", ident.to_string().to_lowercase());
+            self.declarations.push(syn::parse_quote! {
+                unsafe extern "C++" {
+                    include!(#include);
+                    type #ident = #path;
+                }
+            });
+            self.aliases.push((key, ident.clone()));
+            self.declared.push(ident.clone());
+            return Ok(Some(ident.clone()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{registry::RegisteredType, tests::assert_tokens_eq};
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn resolve(mut module: ItemMod) -> Result<Vec<Item>> {
+        let mut registry = TypeRegistry::default();
+        registry.register(RegisteredType::new("crate::money::Money", "money.h").unwrap());
+        resolve_type_paths(&mut module, &registry)?;
+        Ok(module.content.unwrap().1)
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let items = resolve(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type Text = cxx_qt_lib::QString;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(::cxx_qt_lib::QString, title, READ)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn label(self: &MyObject, prefix: &cxx_qt_lib::QString) -> cxx_qt_lib::QString;
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    #[cxx_name = "QString"]
+                    type Text = cxx_qt_lib::QString;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(Text, title, READ)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn label(self: &MyObject, prefix: &Text) -> Text;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_cxx_qt_lib() {
+        let items = resolve(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qsignal]
+                    fn moved(self: Pin<&mut MyObject>, point: cxx_qt_lib::QPoint, points: Vec<cxx_qt_lib::QPoint>);
+
+                    #[qinvokable]
+                    fn total(self: &MyObject) -> crate::money::Money;
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                unsafe extern "RustQt" {
+                    #[qsignal]
+                    fn moved(self: Pin<&mut MyObject>, point: QPoint, points: Vec<QPoint>);
+
+                    #[qinvokable]
+                    fn total(self: &MyObject) -> Money;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qpoint.h");
+                    type QPoint = cxx_qt_lib::QPoint;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_unknown_path() {
+        let items = resolve(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn value(self: &MyObject) -> other::Value;
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn value(self: &MyObject) -> other::Value;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_conflicting_name() {
+        assert!(resolve(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("my_string.h");
+                    type QString;
+                }
+
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn value(self: &MyObject) -> cxx_qt_lib::QString;
+                }
+            }
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,552 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A registry of C++ types which can be used in every bridge of a crate without declaring them.
+
+use crate::syntax::{attribute::attribute_get_path, expr::expr_to_string};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::path::{Path as FilePath, PathBuf};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Brace,
+    Attribute, Error, ForeignItem, Ident, Item, ItemForeignMod, ItemMod, Meta, Path, Result, Token,
+};
+
+/// The name of the file in `OUT_DIR` which is used to share the registry between
+/// the build script and the `#[cxx_qt::bridge]` macro
+const REGISTRY_FILE_NAME: &str = "cxx-qt-gen-type-registry.rs";
+
+/// A C++ type, such as an opaque type of the project, which is declared once for all bridges.
+///
+/// The Rust type must already be bound to the C++ type by a CXX bridge, so that it implements `cxx::ExternType`,
+/// for example with an opaque type in a `#[cxx::bridge]` of the crate.
+///
+/// Registering `my_crate::money::Money` with the include `"money.h"` is the same as
+/// declaring the following in every bridge which uses `Money` in a signature.
+///
+/// ```ignore
+/// unsafe extern "C++" {
+///     include!("money.h");
+///     type Money = my_crate::money::Money;
+/// }
+/// ```
+///
+/// The type is stored as plain strings, so that the registry can be shared between the threads of a build script.
+#[derive(Clone, Debug)]
+pub struct RegisteredType {
+    /// The name of the type in bridges, the last segment of the path
+    ident: String,
+    /// The Rust path of the type, as tokens separated by spaces, eg `crate :: money :: Money`
+    path: String,
+    /// The segments of the path without a leading `::`, to compare paths with
+    segments: String,
+    include: String,
+    cxx_name: Option<String>,
+    namespace: Option<String>,
+}
+
+impl RegisteredType {
+    /// Register the Rust type at the given path, whose C++ declaration is found in the given include.
+    ///
+    /// The type is available in bridges by the last segment of the path.
+    pub fn new(rust_path: &str, include: &str) -> Result<Self> {
+        let path = syn::parse_str::<Path>(rust_path)?;
+        let ident = if let Some(segment) = path.segments.last() {
+            segment.ident.clone()
+        } else {
+            return Err(Error::new_spanned(
+                &path,
+                "The type path must not be empty!",
+            ));
+        };
+        Ok(Self {
+            ident: ident.to_string(),
+            path: path.to_token_stream().to_string(),
+            segments: path.segments.to_token_stream().to_string(),
+            include: include.to_owned(),
+            cxx_name: None,
+            namespace: None,
+        })
+    }
+
+    /// Set the name of the type in C++ if it differs from the Rust name
+    pub fn cxx_name(mut self, cxx_name: &str) -> Self {
+        self.cxx_name = Some(cxx_name.to_owned());
+        self
+    }
+
+    /// Set the C++ namespace of the type, eg `my_project::finance`
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_owned());
+        self
+    }
+
+    fn foreign_mod(&self) -> ItemForeignMod {
+        let ident = format_ident!("{}", self.ident);
+        let path: Path =
+            syn::parse_str(&self.path).expect("The path of a registered type is always valid");
+        let include = &self.include;
+        let cxx_name = self
+            .cxx_name
+            .as_ref()
+            .map(|cxx_name| quote! { #[cxx_name = #cxx_name] });
+        let namespace = self
+            .namespace
+            .as_ref()
+            .map(|namespace| quote! { #[namespace = #namespace] });
+        syn::parse_quote! {
+            unsafe extern "C++" {
+                include!(#include);
+                #cxx_name
+                #namespace
+                type #ident = #path;
+            }
+        }
+    }
+
+    /// Parse a type which has been written by [TypeRegistry::write_to_dir]
+    fn from_foreign_mod(foreign_mod: &ItemForeignMod) -> Result<Self> {
+        let error = || Error::new_spanned(foreign_mod, "Invalid type in the type registry!");
+
+        let mut include = None;
+        for item in &foreign_mod.items {
+            match item {
+                ForeignItem::Macro(mac) if mac.mac.path.is_ident("include") => {
+                    include = Some(mac.mac.parse_body::<syn::LitStr>()?.value());
+                }
+                ForeignItem::Verbatim(tokens) => {
+                    let alias = syn::parse2::<syn::ItemType>(tokens.clone())?;
+                    let path = if let syn::Type::Path(type_path) = *alias.ty {
+                        type_path.path
+                    } else {
+                        return Err(error());
+                    };
+                    let mut ty = RegisteredType::new(
+                        &path.to_token_stream().to_string(),
+                        &include.clone().ok_or_else(error)?,
+                    )?;
+                    ty.cxx_name = attribute_string(&alias.attrs, "cxx_name")?;
+                    ty.namespace = attribute_string(&alias.attrs, "namespace")?;
+                    return Ok(ty);
+                }
+                _ => return Err(error()),
+            }
+        }
+
+        Err(error())
+    }
+}
+
+/// A collection of [RegisteredType]s which are declared in the bridges that use them.
+///
+/// This allows the signatures of a bridge to use project specific C++ types without
+/// declaring them in every bridge or changing cxx-qt-gen itself.
+#[derive(Clone, Debug, Default)]
+pub struct TypeRegistry {
+    types: Vec<RegisteredType>,
+}
+
+impl TypeRegistry {
+    /// Add a type to the registry
+    pub fn register(&mut self, ty: RegisteredType) {
+        self.types.push(ty);
+    }
+
+    /// Register the shared structs, shared enums and opaque C++ types of a `#[cxx::bridge]`,
+    /// so that they can be used in the signatures of the `#[cxx_qt::bridge]`s of the crate.
+    ///
+    /// The `module_path` is the Rust path of the module which contains the bridge, eg `crate::types`,
+    /// and the `include` is the header which CXX generates for the bridge, eg `my_crate/src/types.cxx.h`.
+    /// Type aliases and Rust types of the bridge are not registered.
+    pub fn register_cxx_bridge(
+        &mut self,
+        module: &ItemMod,
+        module_path: &str,
+        include: &str,
+    ) -> Result<()> {
+        let bridge_namespace = cxx_bridge_namespace(&module.attrs)?;
+        let module_ident = &module.ident;
+        let items = module
+            .content
+            .as_ref()
+            .map_or(&[] as &[Item], |(_, items)| items);
+
+        // The shared types and the opaque C++ types with the namespace of their extern block
+        let mut types: Vec<(&Ident, &[Attribute], Option<String>)> = vec![];
+        for item in items {
+            match item {
+                Item::Struct(item_struct) => {
+                    types.push((&item_struct.ident, &item_struct.attrs, None))
+                }
+                Item::Enum(item_enum) => types.push((&item_enum.ident, &item_enum.attrs, None)),
+                Item::ForeignMod(foreign_mod)
+                    if foreign_mod
+                        .abi
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| name.value() == "C++") =>
+                {
+                    let block_namespace = attribute_string(&foreign_mod.attrs, "namespace")?;
+                    types.extend(foreign_mod.items.iter().filter_map(|item| match item {
+                        ForeignItem::Type(foreign_ty) => Some((
+                            &foreign_ty.ident,
+                            foreign_ty.attrs.as_slice(),
+                            block_namespace.clone(),
+                        )),
+                        _ => None,
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        for (ident, attrs, block_namespace) in types {
+            let mut ty =
+                RegisteredType::new(&format!("{module_path}::{module_ident}::{ident}"), include)?;
+            ty.cxx_name = attribute_string(attrs, "cxx_name")?;
+            ty.namespace = attribute_string(attrs, "namespace")?
+                .or(block_namespace)
+                .or_else(|| bridge_namespace.clone());
+            self.register(ty);
+        }
+        Ok(())
+    }
+
+    /// The identifier of the registered type with the given Rust path
+    pub(crate) fn ident_for_path(&self, path: &Path) -> Option<Ident> {
+        let path = path.segments.to_token_stream().to_string();
+        self.types
+            .iter()
+            .find(|ty| ty.segments == path)
+            .map(|ty| format_ident!("{}", ty.ident))
+    }
+
+    /// Returns true if no types have been registered
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Write the registry into the given directory, which should be the `OUT_DIR` of the crate,
+    /// so that the `#[cxx_qt::bridge]` macro can read it with [TypeRegistry::from_out_dir]
+    pub fn write_to_dir(&self, directory: impl AsRef<FilePath>) -> std::io::Result<()> {
+        let contents: TokenStream = self
+            .types
+            .iter()
+            .map(|ty| ty.foreign_mod().into_token_stream())
+            .collect();
+        std::fs::write(
+            directory.as_ref().join(REGISTRY_FILE_NAME),
+            contents.to_string(),
+        )
+    }
+
+    /// Read the registry which has been written into the `OUT_DIR` of the crate being compiled
+    ///
+    /// The registry is empty if the crate has no build script or no types have been registered.
+    pub fn from_out_dir() -> Result<Self> {
+        let path = if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+            PathBuf::from(out_dir).join(REGISTRY_FILE_NAME)
+        } else {
+            return Ok(Self::default());
+        };
+
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            syn::parse_str(&contents)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Declare the registered types which are used but not declared in the given bridge
+    pub(crate) fn declare_in(&self, module: &mut ItemMod) {
+        let used = tokens_idents(&module.to_token_stream());
+        let items = module
+            .content
+            .as_ref()
+            .map_or(&[] as &[Item], |(_, items)| items);
+        let mut declared: Vec<Ident> = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::ForeignMod(foreign_mod) => Some(foreign_mod),
+                _ => None,
+            })
+            .flat_map(|foreign_mod| &foreign_mod.items)
+            .filter_map(|item| match item {
+                ForeignItem::Type(foreign_ty) => Some(foreign_ty.ident.clone()),
+                ForeignItem::Verbatim(tokens) => syn::parse2::<syn::ItemType>(tokens.clone())
+                    .ok()
+                    .map(|ty| ty.ident),
+                _ => None,
+            })
+            .collect();
+        // Shared types of the bridge shadow registered types with the same name
+        declared.extend(items.iter().filter_map(|item| match item {
+            Item::Struct(item_struct) => Some(item_struct.ident.clone()),
+            Item::Enum(item_enum) => Some(item_enum.ident.clone()),
+            _ => None,
+        }));
+
+        let mut missing: Vec<Item> = self
+            .types
+            .iter()
+            .filter(|ty| {
+                used.iter().any(|ident| *ident == ty.ident)
+                    && !declared.iter().any(|ident| *ident == ty.ident)
+            })
+            .map(|ty| Item::ForeignMod(ty.foreign_mod()))
+            .collect();
+        if !missing.is_empty() {
+            let (_, items) = module
+                .content
+                .get_or_insert_with(|| (Brace::default(), vec![]));
+            items.append(&mut missing);
+            module.semi = None;
+        }
+    }
+}
+
+impl Parse for TypeRegistry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut types = vec![];
+        while !input.is_empty() {
+            let foreign_mod: ItemForeignMod = input.parse()?;
+            types.push(RegisteredType::from_foreign_mod(&foreign_mod)?);
+        }
+        Ok(Self { types })
+    }
+}
+
+/// The namespace of a `#[cxx::bridge(namespace = "...")]` attribute
+fn cxx_bridge_namespace(attrs: &[Attribute]) -> Result<Option<String>> {
+    if let Some(attr) = attribute_get_path(attrs, &["cxx", "bridge"]) {
+        if !matches!(attr.meta, Meta::Path(_)) {
+            for meta in attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+                if let Meta::NameValue(name_value) = meta {
+                    if name_value.path.is_ident("namespace") {
+                        return expr_to_string(&name_value.value).map(Some);
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The string value of a `#[name = "..."]` attribute
+fn attribute_string(attrs: &[Attribute], name: &str) -> Result<Option<String>> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(name))
+        .map(|attr| expr_to_string(&attr.meta.require_name_value()?.value))
+        .transpose()
+}
+
+/// Collects all identifiers in a token stream, including nested groups
+fn tokens_idents(tokens: &TokenStream) -> Vec<Ident> {
+    let mut idents = vec![];
+    for token in tokens.clone() {
+        match token {
+            TokenTree::Ident(ident) => idents.push(ident),
+            TokenTree::Group(group) => idents.append(&mut tokens_idents(&group.stream())),
+            _ => {}
+        }
+    }
+    idents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register(
+            RegisteredType::new("crate::money::Money", "money.h")
+                .unwrap()
+                .namespace("finance")
+                .cxx_name("MoneyValue"),
+        );
+        registry.register(RegisteredType::new("crate::Unused", "unused.h").unwrap());
+        registry
+    }
+
+    #[test]
+    fn test_declare_used_types() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn total(self: &MyObject) -> Money;
+                }
+            }
+        };
+        registry().declare_in(&mut module);
+
+        let items = &module.content.unwrap().1;
+        assert_eq!(items.len(), 2);
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                unsafe extern "C++" {
+                    include!("money.h");
+                    #[cxx_name = "MoneyValue"]
+                    #[namespace = "finance"]
+                    type Money = crate::money::Money;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_skip_declared_types() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("other_money.h");
+                    type Money;
+                }
+            }
+        };
+        registry().declare_in(&mut module);
+
+        assert_eq!(module.content.unwrap().1.len(), 1);
+    }
+
+    #[test]
+    fn test_skip_shared_types() {
+        let mut module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                struct Money {
+                    cents: i64,
+                }
+
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn total(self: &MyObject) -> Money;
+                }
+            }
+        };
+        registry().declare_in(&mut module);
+
+        assert_eq!(module.content.unwrap().1.len(), 2);
+    }
+
+    #[test]
+    fn test_register_cxx_bridge() {
+        let module: ItemMod = parse_quote! {
+            #[cxx::bridge(namespace = "geometry")]
+            pub mod ffi {
+                struct Point {
+                    x: i32,
+                    y: i32,
+                }
+
+                #[namespace = "shapes"]
+                enum Shape {
+                    Circle,
+                    Square,
+                }
+
+                #[namespace = "canvas"]
+                unsafe extern "C++" {
+                    include!("canvas.h");
+                    #[cxx_name = "CanvasPen"]
+                    type Pen;
+                    type Brush = crate::brush::Brush;
+                }
+
+                extern "Rust" {
+                    type Painter;
+                }
+            }
+        };
+        let mut registry = TypeRegistry::default();
+        registry
+            .register_cxx_bridge(&module, "crate::geometry", "my_crate/src/geometry.cxx.h")
+            .unwrap();
+
+        assert_eq!(registry.types.len(), 3);
+        let path = |index: usize| registry.types[index].path.as_str();
+        assert_eq!(registry.types[0].ident, "Point");
+        assert_eq!(path(0), "crate :: geometry :: ffi :: Point");
+        assert_eq!(registry.types[0].include, "my_crate/src/geometry.cxx.h");
+        assert_eq!(registry.types[0].namespace.as_deref(), Some("geometry"));
+        assert_eq!(registry.types[1].ident, "Shape");
+        assert_eq!(registry.types[1].namespace.as_deref(), Some("shapes"));
+        assert_eq!(registry.types[2].ident, "Pen");
+        assert_eq!(path(2), "crate :: geometry :: ffi :: Pen");
+        assert_eq!(registry.types[2].cxx_name.as_deref(), Some("CanvasPen"));
+        assert_eq!(registry.types[2].namespace.as_deref(), Some("canvas"));
+
+        let mut bridge: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod qobject {
+                extern "RustQt" {
+                    #[qinvokable]
+                    fn origin(self: &Canvas) -> Point;
+                }
+            }
+        };
+        registry.declare_in(&mut bridge);
+        assert_tokens_eq(
+            &bridge.content.unwrap().1[1],
+            quote! {
+                unsafe extern "C++" {
+                    include!("my_crate/src/geometry.cxx.h");
+                    #[namespace = "geometry"]
+                    type Point = crate::geometry::ffi::Point;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_register_cxx_bridge_invalid() {
+        let module: ItemMod = parse_quote! {
+            #[cxx::bridge(namespace = 1)]
+            mod ffi {
+                struct Point {
+                    x: i32,
+                }
+            }
+        };
+        assert!(TypeRegistry::default()
+            .register_cxx_bridge(&module, "crate", "my_crate/src/lib.cxx.h")
+            .is_err());
+    }
+
+    #[test]
+    fn test_registry_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<TypeRegistry>();
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let registry = registry();
+        let contents: TokenStream = registry
+            .types
+            .iter()
+            .map(|ty| ty.foreign_mod().into_token_stream())
+            .collect();
+        let parsed: TypeRegistry = syn::parse2(contents).unwrap();
+
+        assert_eq!(parsed.types.len(), 2);
+        assert_eq!(parsed.types[0].ident, "Money");
+        assert_eq!(parsed.types[0].include, "money.h");
+        assert_eq!(parsed.types[0].cxx_name.as_deref(), Some("MoneyValue"));
+        assert_eq!(parsed.types[0].namespace.as_deref(), Some("finance"));
+        assert_eq!(parsed.types[1].ident, "Unused");
+        assert!(parsed.types[1].cxx_name.is_none());
+    }
+}
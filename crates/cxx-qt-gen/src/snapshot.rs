@@ -0,0 +1,312 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers to snapshot-test the code generated for the bridges of a crate.
+//!
+//! The generated Rust, C++ header and C++ source of a bridge are compared against files which are
+//! checked into the repository, so that changes to the generated code, for example of the ABI of a QObject,
+//! show up in review. This works like the `test_inputs` and `test_outputs` of cxx-qt-gen itself.
+//!
+//! ```no_run
+//! #[test]
+//! fn cxxqt_object_snapshot() {
+//!     cxx_qt_gen::snapshot::assert_bridge_snapshot("src/cxxqt_object.rs", "tests/snapshots");
+//! }
+//! ```
+//!
+//! The snapshots are written by running the tests with the `CXX_QT_UPDATE_SNAPSHOTS` environment variable set,
+//! for example `CXX_QT_UPDATE_SNAPSHOTS=1 cargo test`.
+//!
+//! The C++ code is formatted with clang-format using the `.clang-format` file of the current directory
+//! or its parents, and the Rust code with rustfmt, so the same versions should be used to update and check snapshots.
+
+use crate::{
+    write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks, GeneratedRustBlocks, Parser,
+};
+use proc_macro2::Span;
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+use syn::{Error, ItemMod, Result};
+
+/// The environment variable which causes [assert_bridge_snapshot] to write the snapshots
+const UPDATE_SNAPSHOTS_ENV: &str = "CXX_QT_UPDATE_SNAPSHOTS";
+
+/// The code generated for a bridge, in the form that it is compared with a snapshot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BridgeSnapshot {
+    /// The Rust code which the `#[cxx_qt::bridge]` module expands to
+    pub rust: String,
+    /// The generated C++ header
+    pub header: String,
+    /// The generated C++ source
+    pub source: String,
+}
+
+impl BridgeSnapshot {
+    /// Generate the code for the `#[cxx_qt::bridge]` module of the given Rust file
+    ///
+    /// The file stem is used as the include path of the generated header.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = crate::parse_qt_file(path)?;
+        let module = file
+            .items
+            .into_iter()
+            .find_map(|item| match item {
+                CxxQtItem::CxxQt(module) => Some(module),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    Span::call_site(),
+                    format!("No #[cxx_qt::bridge] module found in {}", path.display()),
+                )
+            })?;
+        let include_path = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self::from_module(module, &include_path)
+    }
+
+    /// Generate the code for the given `#[cxx_qt::bridge]` module
+    pub fn from_module(module: ItemMod, include_path: &str) -> Result<Self> {
+        let parser = Parser::from(module)?;
+
+        let (header, source) = match write_cpp(&GeneratedCppBlocks::from(&parser)?, include_path) {
+            CppFragment::Pair { header, source } => (header, source),
+            // CODECOV_EXCLUDE_START
+            _ => unreachable!("write_cpp always returns a header and source pair"),
+            // CODECOV_EXCLUDE_STOP
+        };
+        let rust = format_rust(
+            &write_rust(&GeneratedRustBlocks::from(&parser)?, Some(include_path)).to_string(),
+        );
+
+        Ok(Self {
+            rust: sanitize(rust),
+            header: sanitize(header),
+            source: sanitize(source),
+        })
+    }
+
+    /// The generated files with the extension of their snapshot
+    fn files(&self) -> [(&'static str, &str); 3] {
+        [
+            ("rs", &self.rust),
+            ("h", &self.header),
+            ("cpp", &self.source),
+        ]
+    }
+}
+
+/// Assert that the code generated for the bridge in the given Rust file matches the snapshots in the given directory
+///
+/// The snapshots are named after the file stem of the bridge, eg `cxxqt_object.rs`, `cxxqt_object.h` and `cxxqt_object.cpp`.
+/// When the `CXX_QT_UPDATE_SNAPSHOTS` environment variable is set, the snapshots are written instead.
+pub fn assert_bridge_snapshot(bridge: impl AsRef<Path>, snapshot_dir: impl AsRef<Path>) {
+    let bridge = bridge.as_ref();
+    let snapshot = BridgeSnapshot::from_file(bridge).unwrap_or_else(|err| {
+        panic!(
+            "Failed to generate the code of the bridge {}: {err}",
+            bridge.display()
+        )
+    });
+    let stem = bridge
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some();
+
+    if let Err(message) = check_snapshot(&snapshot, &stem, snapshot_dir.as_ref(), update) {
+        panic!("{message}");
+    }
+}
+
+/// Compare the snapshot with the files in the directory, or write them if `update` is true
+fn check_snapshot(
+    snapshot: &BridgeSnapshot,
+    stem: &str,
+    snapshot_dir: &Path,
+    update: bool,
+) -> std::result::Result<(), String> {
+    if update {
+        std::fs::create_dir_all(snapshot_dir).map_err(|err| err.to_string())?;
+    }
+
+    let mut mismatches = vec![];
+    for (extension, generated) in snapshot.files() {
+        let path = snapshot_dir.join(format!("{stem}.{extension}"));
+        if update {
+            std::fs::write(&path, generated).map_err(|err| err.to_string())?;
+            continue;
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(expected) => {
+                if let Some(difference) = first_difference(&sanitize(expected), generated) {
+                    mismatches.push(format!("{}: {difference}", path.display()));
+                }
+            }
+            Err(_) => mismatches.push(format!("{}: the snapshot does not exist", path.display())),
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "The generated code does not match the snapshots, rerun the tests with {UPDATE_SNAPSHOTS_ENV}=1 to update them\n{}",
+            mismatches.join("\n")
+        ))
+    }
+}
+
+/// Describe the first line which differs between the expected and generated code
+fn first_difference(expected: &str, generated: &str) -> Option<String> {
+    if expected == generated {
+        return None;
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut generated_lines = generated.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), generated_lines.next()) {
+            (Some(expected), Some(generated)) if expected == generated => line += 1,
+            (expected, generated) => {
+                return Some(format!(
+                    "line {line} differs\n  expected: {}\n  generated: {}",
+                    expected.unwrap_or("<end of file>"),
+                    generated.unwrap_or("<end of file>")
+                ))
+            }
+        }
+    }
+}
+
+/// Remove carriage returns, so that snapshots compare equal on all platforms
+fn sanitize(mut code: String) -> String {
+    code.retain(|c| c != '\r');
+    code
+}
+
+/// Format Rust code with rustfmt, returning the code unchanged if rustfmt is not available
+fn format_rust(code: &str) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => return code.to_owned(),
+    };
+
+    // Scope stdin to close it, so that rustfmt starts formatting
+    if let Some(mut stdin) = child.stdin.take() {
+        if stdin.write_all(code.as_bytes()).is_err() {
+            return code.to_owned();
+        }
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8(output.stdout).unwrap_or_else(|_| code.to_owned())
+        }
+        _ => code.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    fn snapshot() -> BridgeSnapshot {
+        BridgeSnapshot::from_module(
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(i32, number)]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            },
+            "my_object",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_module() {
+        let snapshot = snapshot();
+        assert!(snapshot.header.contains("class MyObject"));
+        assert!(snapshot.header.contains("#include \"my_object.cxx.h\""));
+        assert!(snapshot.source.contains("#include \"my_object.cxxqt.h\""));
+        assert!(snapshot.rust.contains("my_object.cxxqt.h"));
+    }
+
+    #[test]
+    fn test_from_module_error() {
+        assert!(BridgeSnapshot::from_module(
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject;
+                    }
+                }
+            },
+            "my_object",
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let snapshot_dir =
+            std::env::temp_dir().join(format!("cxx-qt-gen-snapshot-test-{}", std::process::id()));
+        let snapshot = snapshot();
+
+        let missing = check_snapshot(&snapshot, "my_object", &snapshot_dir, false).unwrap_err();
+        assert!(missing.contains("the snapshot does not exist"));
+
+        check_snapshot(&snapshot, "my_object", &snapshot_dir, true).unwrap();
+        check_snapshot(&snapshot, "my_object", &snapshot_dir, false).unwrap();
+
+        let mut changed = snapshot.clone();
+        changed.header = changed
+            .header
+            .replace("class MyObject", "class MyRenamedObject");
+        let mismatch = check_snapshot(&changed, "my_object", &snapshot_dir, false).unwrap_err();
+        assert!(mismatch.contains("my_object.h: line"));
+        assert!(mismatch.contains("generated: class MyRenamedObject"));
+        assert!(!mismatch.contains("my_object.cpp"));
+
+        std::fs::remove_dir_all(snapshot_dir).unwrap();
+    }
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference("a\nb\n", "a\nb\n"), None);
+        assert_eq!(
+            first_difference("a\nb\n", "a\nc\n").unwrap(),
+            "line 2 differs\n  expected: b\n  generated: c"
+        );
+        assert_eq!(
+            first_difference("a\n", "a\nb\n").unwrap(),
+            "line 2 differs\n  expected: <end of file>\n  generated: b"
+        );
+    }
+}
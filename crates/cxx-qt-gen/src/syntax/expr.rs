@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::{spanned::Spanned, Error, Expr, ExprLit, Lit, Result};
+use syn::{Error, Expr, ExprLit, Lit, Result};
 
 /// Convert a given [syn::Expr] to a String
 pub fn expr_to_string(expr: &Expr) -> Result<String> {
@@ -15,7 +15,7 @@ pub fn expr_to_string(expr: &Expr) -> Result<String> {
         return Ok(lit_str.value());
     }
 
-    Err(Error::new(expr.span(), "Expected a string literal!"))
+    Err(Error::new_spanned(expr, "Expected a string literal!"))
 }
 
 #[cfg(test)]
@@ -6,10 +6,8 @@
 use proc_macro2::TokenStream;
 use syn::{
     parse::{Parse, ParseStream, Parser},
-    parse_quote,
-    spanned::Spanned,
-    Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod, Path, Receiver,
-    Result, Signature, Token, Visibility,
+    parse_quote, Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod,
+    Path, Receiver, Result, Signature, Token, Visibility,
 };
 
 /// For a given [syn::ForeignItem] return the [syn::ForeignItemType] if there is one
@@ -70,8 +68,8 @@ fn verbatim_to_foreign_type(tokens: &TokenStream) -> Result<Option<ForeignItemTy
             // Error as we have parsed the attributes and visiblity but have an unknown stream
             //
             // To return None here we should instead peek
-            Err(Error::new(
-                tokens.span(),
+            Err(Error::new_spanned(
+                tokens,
                 "Unsupported verbatim input in ForeignItem!",
             ))
         }
@@ -118,15 +116,15 @@ impl Parse for ForeignTypeIdentAlias {
                 //
                 // Note that we would need to still use the last segment as the Rust name internally
                 if path.segments.len() != 2 {
-                    return Err(Error::new(
-                        path.span(),
+                    return Err(Error::new_spanned(
+                        &path,
                         "Type alias path must have at exactly two segments, super::T!",
                     ));
                 }
 
                 if path.segments[0].ident != "super" {
-                    return Err(Error::new(
-                        path.span(),
+                    return Err(Error::new_spanned(
+                        &path,
                         "Type alias path must have super as the first segment, super::T!",
                     ));
                 }
@@ -135,8 +133,8 @@ impl Parse for ForeignTypeIdentAlias {
             };
 
             if ident_left == ident_right {
-                return Err(Error::new(
-                    path.span(),
+                return Err(Error::new_spanned(
+                    &path,
                     "Type alias path must have differing idents, type A = super::B. A and B cannot be the same!",
                 ));
             }
@@ -161,30 +159,31 @@ impl Parse for ForeignTypeIdentAlias {
 pub fn self_type_from_foreign_fn(signature: &Signature) -> Result<Receiver> {
     if let Some(FnArg::Receiver(receiver)) = signature.inputs.iter().next() {
         if !receiver.attrs.is_empty() {
-            return Err(Error::new(
-                receiver.span(),
+            return Err(Error::new_spanned(
+                receiver,
                 "Attributes on the `self:` receiver are not supported!",
             ));
         }
 
+        let ident = &signature.ident;
         if receiver.mutability.is_some() {
-            return Err(Error::new(
-                receiver.span(),
-                "mut on self (i.e. `&mut self`) are not supported! Use `self: Pin<&mut T>` instead",
+            return Err(Error::new_spanned(
+                receiver,
+                format!("mut on self (i.e. `&mut self`) are not supported! Use `self: Pin<&mut T>` instead, where T is the QObject type, e.g. `fn {ident}(self: Pin<&mut MyObject>)`"),
             ));
         }
 
         if receiver.reference.is_some() {
-            return Err(Error::new(
-                receiver.span(),
-                "Reference on self (i.e. `&self`) are not supported! Use `self: &T` instead",
+            return Err(Error::new_spanned(
+                receiver,
+                format!("Reference on self (i.e. `&self`) are not supported! Use `self: &T` instead, where T is the QObject type, e.g. `fn {ident}(self: &MyObject)`"),
             ));
         }
 
         if receiver.colon_token.is_none() {
-            return Err(Error::new(
-                receiver.span(),
-                "`self` is not supported as receiver! Use `self: T` to indicate a type.",
+            return Err(Error::new_spanned(
+                receiver,
+                format!("`self` is not supported as receiver! Use `self: T` to indicate a type, e.g. `fn {ident}(self: &MyObject)`"),
             ));
         }
 
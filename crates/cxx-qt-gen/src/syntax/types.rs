@@ -117,7 +117,10 @@ pub fn extract_qobject_ident(ty: &Type) -> Result<(Ident, Option<Mut>)> {
         Type::Reference(type_ref) => {
             let (ident, mutability) = extract_qobject_ident_from_ref(type_ref)?;
             if mutability.is_some() {
-                return Err(err_pin_misuse(type_ref));
+                return Err(Error::new_spanned(
+                    type_ref,
+                    format!("A mutable reference to a QObject is not supported as it could be moved! Use `Pin<&mut {ident}>` instead"),
+                ));
             }
             Ok((ident, mutability))
         }
@@ -3,9 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
-use crate::generator::cpp::GeneratedCppBlocks;
+use crate::generator::cpp::{qobject::GeneratedCppQObject, GeneratedCppBlocks};
 use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_header};
 use indoc::formatdoc;
 
@@ -64,23 +64,22 @@ fn forward_declare(generated: &GeneratedCppBlocks) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
-/// For a given GeneratedCppBlocks write the classes
-fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
-    generated.qobjects.iter().map(|qobject| {
-        let ident = &qobject.name.cxx_unqualified();
-        let qobject_macro = if qobject.has_qobject_macro {
-            "Q_OBJECT"
-        } else {
-            ""
-        };
-        let qobject_assert = if qobject.has_qobject_macro {
-            format!("static_assert(::std::is_base_of<QObject, {ident}>::value, \"{ident} must inherit from QObject\");")
-        } else {
-            "".to_owned()
-        };
-        let class_definition = namespaced(
-            qobject.name.namespace().unwrap_or_default(),
-            &formatdoc! { r#"
+/// For a given GeneratedCppQObject write the class
+fn qobject_header(qobject: &GeneratedCppQObject) -> String {
+    let ident = &qobject.name.cxx_unqualified();
+    let qobject_macro = if qobject.has_qobject_macro {
+        "Q_OBJECT"
+    } else {
+        ""
+    };
+    let qobject_assert = if qobject.has_qobject_macro {
+        format!("static_assert(::std::is_base_of<QObject, {ident}>::value, \"{ident} must inherit from QObject\");")
+    } else {
+        "".to_owned()
+    };
+    let class_definition = namespaced(
+        qobject.name.namespace().unwrap_or_default(),
+        &formatdoc! { r#"
                 class {ident} : {base_classes}
                 {{
                   {qobject_macro}
@@ -99,31 +98,76 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
             public_methods = create_block("public", &qobject.blocks.methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
             private_methods = create_block("private", &qobject.blocks.private_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
-        });
+        },
+    );
 
-        let fragments = qobject
-            .blocks
-            .fragments
-            .iter()
-            .filter_map(pair_as_header)
-            .collect::<Vec<String>>()
-            .join("\n");
+    let fragments = qobject
+        .blocks
+        .fragments
+        .iter()
+        .filter_map(pair_as_header)
+        .collect::<Vec<String>>()
+        .join("\n");
 
-        let declare_metatype = if qobject.has_qobject_macro {
-            let ty = qobject.name.cxx_qualified();
-            format!("Q_DECLARE_METATYPE({ty}*)")
-        } else {
-            "".to_owned()
-        };
+    let declare_metatype = if qobject.has_qobject_macro {
+        let ty = qobject.name.cxx_qualified();
+        format!("Q_DECLARE_METATYPE({ty}*)")
+    } else {
+        "".to_owned()
+    };
 
-        formatdoc! {r#"
+    formatdoc! {r#"
             {fragments}
             {class_definition}
 
             {declare_metatype}
             "#
+    }
+}
+
+/// The include path of the separate header for the given cxx_file_stem,
+/// which is next to the header of the bridge
+fn qobject_include_path(include_path: &str, cxx_file_stem: &str) -> String {
+    if let Some((directory, _)) = include_path.rsplit_once('/') {
+        format!("{directory}/{cxx_file_stem}")
+    } else {
+        cxx_file_stem.to_owned()
+    }
+}
+
+/// For a given GeneratedCppBlocks write the QObjects with a cxx_file_stem into separate C++ headers
+///
+/// QObjects with the same cxx_file_stem share a header. The headers are returned with their
+/// cxx_file_stem, they are written next to the header of the bridge as `{cxx_file_stem}.cxxqt.h`.
+pub fn write_cpp_qobject_headers(
+    generated: &GeneratedCppBlocks,
+    include_path: &str,
+) -> Vec<(String, String)> {
+    let mut headers = BTreeMap::<&str, Vec<String>>::new();
+    for qobject in &generated.qobjects {
+        if let Some(cxx_file_stem) = &qobject.cxx_file_stem {
+            headers
+                .entry(cxx_file_stem)
+                .or_default()
+                .push(qobject_header(qobject));
         }
-    }).collect::<Vec<String>>()
+    }
+
+    headers
+        .into_iter()
+        .map(|(cxx_file_stem, qobjects)| {
+            let header = formatdoc! {r#"
+                #pragma once
+
+                #include "{include_path}.cxxqt.h"
+
+                {qobjects}
+            "#,
+            qobjects = qobjects.join("\n"),
+            };
+            (cxx_file_stem.to_owned(), header)
+        })
+        .collect()
 }
 
 /// For a given GeneratedCppBlocks write this into a C++ header
@@ -154,6 +198,21 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
             .join("\n")
     };
     let extern_cxx_qt = extract_extern_qt(generated, pair_as_header);
+    // The separate QObject headers include this header, so they are included at the end
+    // so that including either header results in all of the classes of the bridge being defined
+    let qobject_includes = generated
+        .qobjects
+        .iter()
+        .filter_map(|qobject| qobject.cxx_file_stem.as_deref())
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .map(|cxx_file_stem| {
+            format!(
+                "#include \"{}.cxxqt.h\"\n",
+                qobject_include_path(include_path, cxx_file_stem)
+            )
+        })
+        .collect::<String>();
 
     formatdoc! {r#"
         #pragma once
@@ -165,9 +224,15 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
 
         {extern_cxx_qt}
         {qobjects}
-    "#,
+        {qobject_includes}"#,
     forward_declare = forward_declare(generated).join("\n"),
-    qobjects = qobjects_header(generated).join("\n"),
+    qobjects = generated
+        .qobjects
+        .iter()
+        .filter(|qobject| qobject.cxx_file_stem.is_none())
+        .map(qobject_header)
+        .collect::<Vec<String>>()
+        .join("\n"),
     }
 }
 
@@ -288,4 +353,50 @@ public:
 
         assert_str_eq!(format_cpp(&header), format_cpp(expected))
     }
+
+    #[test]
+    fn test_write_cpp_qobject_headers() {
+        let mut generated = create_generated_cpp_multi_qobjects();
+        generated.qobjects[1].cxx_file_stem = Some("second_object".to_owned());
+
+        let header = write_cpp_header(&generated, "cxx-qt-gen/cxx_file_stem");
+        assert!(header.contains("class FirstObject : "));
+        assert!(!header.contains("class SecondObject : "));
+        assert!(header.ends_with("#include \"cxx-qt-gen/second_object.cxxqt.h\"\n"));
+
+        let headers = write_cpp_qobject_headers(&generated, "cxx-qt-gen/cxx_file_stem");
+        assert_eq!(headers.len(), 1);
+        let (cxx_file_stem, header) = &headers[0];
+        assert_eq!(cxx_file_stem, "second_object");
+        assert!(
+            header.starts_with("#pragma once\n\n#include \"cxx-qt-gen/cxx_file_stem.cxxqt.h\"\n")
+        );
+        assert!(header.contains("class SecondObject : "));
+        assert!(header.contains("Q_DECLARE_METATYPE(cxx_qt::SecondObject*)"));
+        assert!(!header.contains("class FirstObject : "));
+    }
+
+    #[test]
+    fn test_write_cpp_qobject_headers_shared_stem() {
+        let mut generated = create_generated_cpp_multi_qobjects();
+        for qobject in &mut generated.qobjects {
+            qobject.cxx_file_stem = Some("objects".to_owned());
+        }
+
+        let header = write_cpp_header(&generated, "ffi");
+        assert!(!header.contains("class FirstObject : "));
+        assert!(!header.contains("class SecondObject : "));
+        assert_eq!(header.matches("#include \"objects.cxxqt.h\"").count(), 1);
+
+        let headers = write_cpp_qobject_headers(&generated, "ffi");
+        assert_eq!(headers.len(), 1);
+        assert!(headers[0].1.contains("class FirstObject : "));
+        assert!(headers[0].1.contains("class SecondObject : "));
+    }
+
+    #[test]
+    fn test_write_cpp_qobject_headers_none() {
+        let generated = create_generated_cpp();
+        assert!(write_cpp_qobject_headers(&generated, "cxx-qt-gen/cxx_file_stem").is_empty());
+    }
 }
@@ -8,7 +8,7 @@ pub mod source;
 
 use crate::generator::cpp::{fragment::CppFragment, GeneratedCppBlocks};
 use clang_format::{clang_format_with_style, ClangFormatStyle};
-use header::write_cpp_header;
+use header::{write_cpp_header, write_cpp_qobject_headers};
 use indoc::formatdoc;
 use source::write_cpp_source;
 
@@ -26,15 +26,58 @@ pub fn namespaced(namespace: &str, cpp_code: &str) -> String {
 }
 
 /// For a given GeneratedCppBlocks write this into a C++ header and source pair
+///
+/// The C++ code is formatted with clang-format using the `.clang-format` file
+/// of the current directory or its parents.
 pub fn write_cpp(generated: &GeneratedCppBlocks, include_path: &str) -> CppFragment {
+    write_cpp_with_style(generated, include_path, Some(&ClangFormatStyle::File))
+}
+
+/// For a given GeneratedCppBlocks write this into a C++ header and source pair,
+/// formatted with clang-format using the given style, or unformatted if the style is `None`
+///
+/// If clang-format is not available or fails, the code is left unformatted.
+pub fn write_cpp_with_style(
+    generated: &GeneratedCppBlocks,
+    include_path: &str,
+    style: Option<&ClangFormatStyle>,
+) -> CppFragment {
     let header = write_cpp_header(generated, include_path);
     let source = write_cpp_source(generated, include_path);
 
-    CppFragment::Pair {
-        header: clang_format_with_style(&header, &ClangFormatStyle::File).unwrap_or(header),
-        source: clang_format_with_style(&source, &ClangFormatStyle::File).unwrap_or(source),
+    if let Some(style) = style {
+        CppFragment::Pair {
+            header: clang_format_with_style(&header, style).unwrap_or(header),
+            source: clang_format_with_style(&source, style).unwrap_or(source),
+        }
+    } else {
+        CppFragment::Pair { header, source }
     }
 }
+
+/// For a given GeneratedCppBlocks write the QObjects with a `#[cxx_file_stem]` into separate
+/// C++ headers, formatted with clang-format using the given style, or unformatted if the style is `None`
+///
+/// The headers are returned with their file stem, they are expected to be written next to the
+/// header of the bridge as `{cxx_file_stem}.cxxqt.h`.
+pub fn write_cpp_qobject_headers_with_style(
+    generated: &GeneratedCppBlocks,
+    include_path: &str,
+    style: Option<&ClangFormatStyle>,
+) -> Vec<(String, String)> {
+    write_cpp_qobject_headers(generated, include_path)
+        .into_iter()
+        .map(|(cxx_file_stem, header)| {
+            let header = if let Some(style) = style {
+                clang_format_with_style(&header, style).unwrap_or(header)
+            } else {
+                header
+            };
+            (cxx_file_stem, header)
+        })
+        .collect()
+}
+
 /// Extract the header from a given CppFragment
 pub fn pair_as_header(pair: &CppFragment) -> Option<String> {
     match pair {
@@ -60,13 +103,10 @@ pub fn extract_extern_qt(
     generated
         .extern_cxx_qt
         .iter()
-        .flat_map(|block| {
-            block
-                .fragments
-                .iter()
-                .filter_map(&mut filter_fn)
-                .collect::<Vec<String>>()
-        })
+        .flat_map(|block| &block.fragments)
+        // Fragments which aren't associated with a QObject also need the CXX header
+        .chain(&generated.fragments)
+        .filter_map(&mut filter_fn)
         .collect::<Vec<String>>()
         .join("\n")
 }
@@ -96,6 +136,7 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             extern_cxx_qt: vec![],
+            fragments: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     name: if let Some(namespace) = namespace {
@@ -110,6 +151,7 @@ mod tests {
                         "cxx_qt_my_object".to_owned()
                     },
                     has_qobject_macro: true,
+                    cxx_file_stem: None,
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -226,12 +268,14 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             extern_cxx_qt: vec![],
+            fragments: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     name: Name::mock_namespaced("FirstObject", "cxx_qt"),
                     rust_struct: Name::mock("FirstObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_first_object".to_owned(),
                     has_qobject_macro: true,
+                    cxx_file_stem: None,
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -275,6 +319,7 @@ mod tests {
                     rust_struct: Name::mock("SecondObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_second_object".to_owned(),
                     has_qobject_macro: true,
+                    cxx_file_stem: None,
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -566,6 +611,8 @@ mod tests {
 
         } // namespace cxx_qt::my_object
 
+        static const int register_cxx_qt_my_object_MyObject = qRegisterMetaType<cxx_qt::my_object::MyObject*>();
+
         "#}
     }
 
@@ -590,6 +637,8 @@ mod tests {
 
         } // namespace cxx_qt
 
+        static const int register_cxx_qt_FirstObject = qRegisterMetaType<cxx_qt::FirstObject*>();
+
         namespace cxx_qt {
         int
         SecondObject::count() const
@@ -609,6 +658,8 @@ mod tests {
 
         } // namespace cxx_qt
 
+        static const int register_cxx_qt_SecondObject = qRegisterMetaType<cxx_qt::SecondObject*>();
+
         "#}
     }
 
@@ -663,6 +714,8 @@ mod tests {
             // non-const private method
         }
 
+        static const int register_MyObject = qRegisterMetaType<MyObject*>();
+
         "#}
     }
 
@@ -711,6 +764,25 @@ mod tests {
         assert_str_eq!(source, format_cpp(expected_source_multi_qobjects()));
     }
 
+    #[test]
+    fn test_write_cpp_without_style() {
+        let generated = create_generated_cpp();
+        let (header, source) = require_pair(&write_cpp_with_style(
+            &generated,
+            "cxx-qt-gen/cxx_file_stem",
+            None,
+        ))
+        .unwrap();
+        assert_str_eq!(
+            header,
+            write_cpp_header(&generated, "cxx-qt-gen/cxx_file_stem")
+        );
+        assert_str_eq!(
+            source,
+            write_cpp_source(&generated, "cxx-qt-gen/cxx_file_stem")
+        );
+    }
+
     #[test]
     fn test_write_cpp_no_namespace() {
         let generated = create_generated_cpp_no_namespace();
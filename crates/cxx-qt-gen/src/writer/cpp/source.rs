@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::cpp::GeneratedCppBlocks;
+use crate::generator::cpp::{qobject::GeneratedCppQObject, GeneratedCppBlocks};
 use crate::writer::cpp::{extract_extern_qt, namespaced, pair_as_source};
 use indoc::formatdoc;
 
@@ -29,12 +29,30 @@ fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
                 .iter()
                 .filter_map(pair_as_source)
                 .chain([namespaced])
+                .chain(register_metatype(qobject))
                 .collect::<Vec<String>>()
                 .join("\n")
         })
         .collect::<Vec<String>>()
 }
 
+/// Register the pointer type of a QObject with the Qt meta-type system when the library is loaded,
+/// so that it can be used in queued connections and stored in a QVariant without manual registration
+fn register_metatype(qobject: &GeneratedCppQObject) -> Option<String> {
+    if !qobject.has_qobject_macro {
+        return None;
+    }
+
+    let ty = qobject.name.cxx_qualified();
+    let ident = format!(
+        "register_{}",
+        ty.trim_start_matches("::").replace("::", "_")
+    );
+    Some(format!(
+        "static const int {ident} = qRegisterMetaType<{ty}*>();\n"
+    ))
+}
+
 /// For a given GeneratedCppBlocks write this into a C++ source
 pub fn write_cpp_source(generated: &GeneratedCppBlocks, include_path: &str) -> String {
     let extern_cxx_qt = extract_extern_qt(generated, pair_as_source);
@@ -4,4 +4,5 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 pub mod cpp;
+pub mod qmldocs;
 pub mod rust;
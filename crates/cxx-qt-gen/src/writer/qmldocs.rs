@@ -0,0 +1,352 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::metadata::{
+    BridgeMetadata, InvokableMetadata, ParameterMetadata, PropertyMetadata, QEnumMetadata,
+    QObjectMetadata, SignalMetadata, TypeMetadata,
+};
+
+/// For the given bridges of a QML module write Markdown documentation of the QML types
+///
+/// Only QObjects with a `#[qml_element]` are documented, using the doc comments of the bridges.
+pub fn write_qml_docs(uri: &str, bridges: &[BridgeMetadata]) -> String {
+    let mut docs = format!("# {uri}\n");
+    for qobject in bridges
+        .iter()
+        .flat_map(|bridge| &bridge.qobjects)
+        .filter(|qobject| qobject.qml.is_some())
+    {
+        docs.push('\n');
+        docs.push_str(&qobject_docs(uri, qobject));
+    }
+    docs
+}
+
+/// Write the documentation of a single QML type
+fn qobject_docs(uri: &str, qobject: &QObjectMetadata) -> String {
+    let qml = qobject.qml.as_ref();
+    let name = qml.map_or(qobject.name.as_str(), |qml| qml.name.as_str());
+
+    let mut docs = format!("## {name}\n\n");
+    docs.push_str(&format!("`import {uri}`"));
+    if let Some(base_class) = &qobject.base_class {
+        docs.push_str(&format!(", inherits `{base_class}`"));
+    }
+    docs.push('\n');
+    if qml.is_some_and(|qml| qml.singleton) {
+        docs.push_str("\nThis type is a singleton.\n");
+    }
+    if qml.is_some_and(|qml| qml.uncreatable) {
+        docs.push_str("\nThis type cannot be created in QML.\n");
+    }
+    docs.push_str(&paragraph(&qobject.docs));
+
+    let properties = qobject
+        .properties
+        .iter()
+        .map(property_docs)
+        .collect::<Vec<_>>();
+    docs.push_str(&section("Properties", &properties));
+
+    // Private signals can't be used from QML
+    let signals = qobject
+        .signals
+        .iter()
+        .filter(|signal| !signal.private)
+        .map(signal_docs)
+        .collect::<Vec<_>>();
+    docs.push_str(&section("Signals", &signals));
+
+    let methods = qobject
+        .invokables
+        .iter()
+        .map(invokable_docs)
+        .collect::<Vec<_>>();
+    docs.push_str(&section("Methods", &methods));
+
+    let enums = qobject.qenums.iter().map(qenum_docs).collect::<Vec<_>>();
+    docs.push_str(&section("Enums", &enums));
+
+    docs
+}
+
+fn property_docs(property: &PropertyMetadata) -> String {
+    let mut attributes = vec![];
+    if property.constant {
+        attributes.push("constant");
+    } else if property.setter.is_none() {
+        attributes.push("read-only");
+    }
+    if property.required {
+        attributes.push("required");
+    }
+    if property.reset.is_some() {
+        attributes.push("resettable");
+    }
+
+    let mut docs = format!(
+        "- `{name}`: `{ty}`",
+        name = property.name,
+        ty = qml_type(&property.ty)
+    );
+    if !attributes.is_empty() {
+        docs.push_str(&format!(" ({})", attributes.join(", ")));
+    }
+    docs
+}
+
+fn signal_docs(signal: &SignalMetadata) -> String {
+    let mut docs = format!(
+        "- `{name}({parameters})`",
+        name = signal.name,
+        parameters = parameters(&signal.parameters)
+    );
+    docs.push_str(&indented(&signal.docs));
+    docs
+}
+
+fn invokable_docs(invokable: &InvokableMetadata) -> String {
+    let return_type = invokable
+        .return_type
+        .as_ref()
+        .map(|ty| format!(": {}", qml_type(ty)))
+        .unwrap_or_default();
+    let mut docs = format!(
+        "- `{name}({parameters}){return_type}`",
+        name = invokable.name,
+        parameters = parameters(&invokable.parameters)
+    );
+    docs.push_str(&indented(&invokable.docs));
+    docs
+}
+
+fn qenum_docs(qenum: &QEnumMetadata) -> String {
+    let mut docs = format!(
+        "- `{name}`: {variants}",
+        name = qenum.name,
+        variants = qenum
+            .variants
+            .iter()
+            .map(|variant| format!("`{name}.{variant}`", name = qenum.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    docs.push_str(&indented(&qenum.docs));
+    docs
+}
+
+fn parameters(parameters: &[ParameterMetadata]) -> String {
+    parameters
+        .iter()
+        .map(|parameter| format!("{}: {}", parameter.name, qml_type(&parameter.ty)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A titled list of items, or nothing if there are no items
+fn section(title: &str, items: &[String]) -> String {
+    if items.is_empty() {
+        String::new()
+    } else {
+        format!("\n### {title}\n\n{}\n", items.join("\n").trim_end())
+    }
+}
+
+/// The doc comment lines as a paragraph after a blank line
+fn paragraph(lines: &[String]) -> String {
+    if lines.is_empty() {
+        String::new()
+    } else {
+        format!("\n{}\n", lines.join("\n"))
+    }
+}
+
+/// The doc comment lines indented below a list item
+fn indented(lines: &[String]) -> String {
+    if lines.is_empty() {
+        String::new()
+    } else {
+        let lines = lines
+            .iter()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("  {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("\n\n{lines}\n")
+    }
+}
+
+/// The name of the type in QML, or the C++ type if it has no QML equivalent
+fn qml_type(ty: &TypeMetadata) -> String {
+    let cxx = ty
+        .cxx
+        .trim_end_matches("const&")
+        .trim_end_matches('&')
+        .trim()
+        .trim_start_matches("::");
+    match cxx {
+        "bool" => "bool",
+        "std::int8_t" | "std::int16_t" | "std::int32_t" | "std::uint8_t" | "std::uint16_t"
+        | "std::uint32_t" => "int",
+        "float" | "double" => "real",
+        "QString" => "string",
+        "QUrl" => "url",
+        "QColor" => "color",
+        "QDate" | "QDateTime" => "date",
+        "QPoint" | "QPointF" => "point",
+        "QSize" | "QSizeF" => "size",
+        "QRect" | "QRectF" => "rect",
+        "QVariant" => "var",
+        "QStringList" => "list<string>",
+        other => other,
+    }
+    .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_write_qml_docs() {
+        let parser = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                extern "RustQt" {
+                    /// A counter which can be incremented
+                    ///
+                    /// The count wraps at 100.
+                    #[qobject]
+                    #[qml_element]
+                    #[qproperty(i32, count)]
+                    #[qproperty(QString, label, READ)]
+                    type Counter = super::CounterRust;
+
+                    #[qobject]
+                    type Internal = super::InternalRust;
+                }
+
+                unsafe extern "RustQt" {
+                    /// Emitted when the counter wraps
+                    #[qsignal]
+                    fn wrapped(self: Pin<&mut Counter>, #[cxx_name = "newValue"] new_value: i32);
+
+                    #[qsignal]
+                    pub(self) fn private_signal(self: Pin<&mut Counter>);
+
+                    /// Increment the counter
+                    #[qinvokable]
+                    fn increment(self: Pin<&mut Counter>, amount: i32) -> bool;
+
+                    #[qinvokable]
+                    fn reset_label(self: Pin<&mut Counter>, label: &QString);
+                }
+            }
+        })
+        .unwrap();
+        let metadata = BridgeMetadata::from(&parser).unwrap();
+
+        assert_str_eq!(
+            write_qml_docs("com.kdab.counter", &[metadata]),
+            indoc! {r#"
+                # com.kdab.counter
+
+                ## Counter
+
+                `import com.kdab.counter`
+
+                A counter which can be incremented
+
+                The count wraps at 100.
+
+                ### Properties
+
+                - `count`: `int`
+                - `label`: `string` (read-only)
+
+                ### Signals
+
+                - `wrapped(newValue: int)`
+
+                  Emitted when the counter wraps
+
+                ### Methods
+
+                - `increment(amount: int): bool`
+
+                  Increment the counter
+
+                - `resetLabel(label: string)`
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_write_qml_docs_singleton() {
+        let parser = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element = "Settings"]
+                    #[qml_singleton]
+                    #[qml_uncreatable]
+                    #[base = QAbstractListModel]
+                    #[qproperty(f64, scale, READ, CONSTANT)]
+                    type AppSettings = super::AppSettingsRust;
+                }
+
+                /// The theme of the application
+                #[qenum(AppSettings)]
+                enum Theme {
+                    Light,
+                    Dark,
+                }
+            }
+        })
+        .unwrap();
+        let metadata = BridgeMetadata::from(&parser).unwrap();
+
+        assert_str_eq!(
+            write_qml_docs("com.kdab.settings", &[metadata]),
+            indoc! {r#"
+                # com.kdab.settings
+
+                ## Settings
+
+                `import com.kdab.settings`, inherits `QAbstractListModel`
+
+                This type is a singleton.
+
+                This type cannot be created in QML.
+
+                ### Properties
+
+                - `scale`: `real` (constant)
+
+                ### Enums
+
+                - `Theme`: `Theme.Light`, `Theme.Dark`
+
+                  The theme of the application
+            "#}
+        );
+    }
+}
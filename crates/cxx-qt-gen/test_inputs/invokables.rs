@@ -57,6 +57,8 @@ mod ffi {
 
     impl cxx_qt::Threading for MyObject {}
 
+    impl cxx_qt::InteriorMutability for MyObject {}
+
     impl<'a>
         cxx_qt::Constructor<
             (i32, &'a QString),
@@ -5,3 +5,5 @@ MyObject::MyObject(QObject* parent)
   , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::cxx_qt_my_object::createRs())
 {
 }
+
+static const int register_MyObject = qRegisterMetaType<MyObject*>();
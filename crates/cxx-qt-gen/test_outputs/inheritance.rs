@@ -79,6 +79,11 @@ mod inheritance {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+    }
 }
 impl cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {}
 #[allow(unused_imports)]
@@ -91,15 +96,20 @@ pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
 impl ::core::ops::Deref for inheritance::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
-        inheritance::cxx_qt_ffi_my_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for inheritance::MyObject {
     type Rust = MyObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         inheritance::cxx_qt_ffi_my_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         inheritance::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
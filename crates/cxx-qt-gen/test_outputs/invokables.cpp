@@ -41,3 +41,6 @@ MyObject::MyObject(
 }
 
 } // namespace cxx_qt::my_object
+
+static const int register_cxx_qt_my_object_MyObject =
+  qRegisterMetaType<cxx_qt::my_object::MyObject*>();
@@ -124,6 +124,7 @@ mod ffi {
             cxx_qt_thread: &MyObjectCxxQtThread,
             func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
             arg: Box<MyObjectCxxQtThreadQueuedFn>,
+            priority: i32,
         ) -> Result<()>;
         #[doc(hidden)]
         #[cxx_name = "cxxQtThreadClone"]
@@ -135,6 +136,12 @@ mod ffi {
         #[cxx_name = "cxxQtThreadDrop"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_cxx_qt_thread_is_current_thread(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> bool;
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
@@ -229,6 +236,12 @@ mod ffi {
             args: CxxQtConstructorInitializeArgumentsMyObject1,
         );
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustPtr"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_unsafe_rust_ptr(outer: &MyObject) -> *mut MyObjectRust;
+    }
     unsafe extern "C++" {
         #[doc(hidden)]
         #[cxx_name = "unsafeRust"]
@@ -243,6 +256,11 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+    }
 }
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
@@ -254,6 +272,7 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
+        priority: cxx_qt::EventPriority,
     ) -> std::result::Result<(), cxx::Exception>
     where
         F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
@@ -274,6 +293,7 @@ impl cxx_qt::Threading for ffi::MyObject {
             cxx_qt_thread,
             func,
             std::boxed::Box::new(arg),
+            priority as i32,
         )
     }
     #[doc(hidden)]
@@ -284,6 +304,10 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn threading_drop(cxx_qt_thread: &mut ffi::MyObjectCxxQtThread) {
         ffi::cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread);
     }
+    #[doc(hidden)]
+    fn threading_is_current_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_my_object_cxx_qt_thread_is_current_thread(cxx_qt_thread)
+    }
 }
 #[doc(hidden)]
 pub struct MyObjectCxxQtThreadQueuedFn {
@@ -359,18 +383,28 @@ pub fn initialize_my_object_1(
 ) {
     <ffi::MyObject as cxx_qt::Constructor<()>>::initialize(qobject, ());
 }
+impl ::cxx_qt::InteriorMutability for ffi::MyObject {
+    fn rust_ptr(&self) -> *mut Self::Rust {
+        ffi::cxx_qt_ffi_my_object_unsafe_rust_ptr(self)
+    }
+}
 impl ::core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyObject {
     type Rust = MyObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
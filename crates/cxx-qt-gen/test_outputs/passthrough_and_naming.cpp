@@ -289,6 +289,9 @@ MyObject::MyObject(QObject* parent)
 
 } // namespace cxx_qt::multi_object
 
+static const int register_cxx_qt_multi_object_MyObject =
+  qRegisterMetaType<cxx_qt::multi_object::MyObject*>();
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -411,6 +414,9 @@ SecondObject::SecondObject(QObject* parent)
 
 } // namespace second_object
 
+static const int register_second_object_SecondObject =
+  qRegisterMetaType<second_object::SecondObject*>();
+
 namespace my_namespace {
 MyCxxName::MyCxxName(QObject* parent)
   : QObject(parent)
@@ -420,3 +426,6 @@ MyCxxName::MyCxxName(QObject* parent)
 }
 
 } // namespace my_namespace
+
+static const int register_my_namespace_MyCxxName =
+  qRegisterMetaType<my_namespace::MyCxxName*>();
@@ -183,6 +183,11 @@ pub mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "SecondObjectRust"]
@@ -295,6 +300,11 @@ pub mod ffi {
             outer: Pin<&mut SecondObject>,
         ) -> Pin<&mut SecondObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut SecondObject>);
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "ThirdObjectRust"]
@@ -331,6 +341,11 @@ pub mod ffi {
             outer: Pin<&mut MyRustName>,
         ) -> Pin<&mut ThirdObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyRustName>);
+    }
     #[namespace = ""]
     unsafe extern "C++" {
         #[namespace = "cxx_qt::multi_object"]
@@ -451,10 +466,15 @@ impl ffi::MyObject {
     #[doc = "property_name"]
     pub fn set_property_name(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.property_name == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "property_name", error)
+            });
+            if rust.property_name == value {
+                return;
+            }
+            rust.as_mut().property_name = value;
         }
-        self.as_mut().rust_mut().property_name = value;
         self.as_mut().property_name_changed();
     }
 }
@@ -592,17 +612,22 @@ pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
 impl ::core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyObject {
     type Rust = MyObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
 impl ffi::SecondObject {
     #[doc = "Getter for the Q_PROPERTY "]
@@ -616,10 +641,15 @@ impl ffi::SecondObject {
     #[doc = "property_name"]
     pub fn set_property_name(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.property_name == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "property_name", error)
+            });
+            if rust.property_name == value {
+                return;
+            }
+            rust.as_mut().property_name = value;
         }
-        self.as_mut().rust_mut().property_name = value;
         self.as_mut().property_name_changed();
     }
 }
@@ -757,17 +787,22 @@ pub fn create_rs_second_object_rust() -> std::boxed::Box<SecondObjectRust> {
 impl ::core::ops::Deref for ffi::SecondObject {
     type Target = SecondObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_second_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::SecondObject {
     type Rust = SecondObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_second_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_second_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
 #[doc(hidden)]
 pub fn create_rs_third_object_rust() -> std::boxed::Box<ThirdObjectRust> {
@@ -776,17 +811,22 @@ pub fn create_rs_third_object_rust() -> std::boxed::Box<ThirdObjectRust> {
 impl ::core::ops::Deref for ffi::MyRustName {
     type Target = ThirdObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_my_cxx_name_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyRustName {
     type Rust = ThirdObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_my_cxx_name_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_my_cxx_name_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
 impl ffi::QPushButton {
     #[doc = "Connect the given function pointer to the signal "]
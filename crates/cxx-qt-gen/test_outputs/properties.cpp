@@ -345,3 +345,6 @@ MyObject::MyObject(QObject* parent)
 }
 
 } // namespace cxx_qt::my_object
+
+static const int register_cxx_qt_my_object_MyObject =
+  qRegisterMetaType<cxx_qt::my_object::MyObject*>();
@@ -360,6 +360,11 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+    }
 }
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
@@ -373,10 +378,15 @@ impl ffi::MyObject {
     #[doc = "primitive"]
     pub fn set_primitive(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.primitive == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "primitive", error)
+            });
+            if rust.primitive == value {
+                return;
+            }
+            rust.as_mut().primitive = value;
         }
-        self.as_mut().rust_mut().primitive = value;
         self.as_mut().primitive_changed();
     }
 }
@@ -392,10 +402,16 @@ impl ffi::MyObject {
     #[doc = "trivial"]
     pub fn set_trivial(mut self: core::pin::Pin<&mut Self>, value: ffi::QPoint) {
         use cxx_qt::CxxQtType;
-        if self.trivial == value {
-            return;
+        {
+            let mut rust = self
+                .as_mut()
+                .try_rust_mut()
+                .unwrap_or_else(|error| panic!("cannot set the property {}: {}", "trivial", error));
+            if rust.trivial == value {
+                return;
+            }
+            rust.as_mut().trivial = value;
         }
-        self.as_mut().rust_mut().trivial = value;
         self.as_mut().trivial_changed();
     }
 }
@@ -418,10 +434,15 @@ impl ffi::MyObject {
     #[doc = "renamed_property"]
     pub fn set_renamed_property(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.renamed_property == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "renamed_property", error)
+            });
+            if rust.renamed_property == value {
+                return;
+            }
+            rust.as_mut().renamed_property = value;
         }
-        self.as_mut().rust_mut().renamed_property = value;
         self.as_mut().renamed_property_changed();
     }
 }
@@ -437,10 +458,18 @@ impl ffi::MyObject {
     #[doc = "renamed_property_2"]
     pub fn set_renamed_property_2(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.renamed_property_2 == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!(
+                    "cannot set the property {}: {}",
+                    "renamed_property_2", error
+                )
+            });
+            if rust.renamed_property_2 == value {
+                return;
+            }
+            rust.as_mut().renamed_property_2 = value;
         }
-        self.as_mut().rust_mut().renamed_property_2 = value;
         self.as_mut().renamed_property_2_changed();
     }
 }
@@ -456,10 +485,18 @@ impl ffi::MyObject {
     #[doc = "custom_on_changed_prop"]
     pub fn set_custom_on_changed_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.custom_on_changed_prop == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!(
+                    "cannot set the property {}: {}",
+                    "custom_on_changed_prop", error
+                )
+            });
+            if rust.custom_on_changed_prop == value {
+                return;
+            }
+            rust.as_mut().custom_on_changed_prop = value;
         }
-        self.as_mut().rust_mut().custom_on_changed_prop = value;
         self.as_mut().my_on_changed();
     }
 }
@@ -482,10 +519,15 @@ impl ffi::MyObject {
     #[doc = "resettable_prop"]
     pub fn set_resettable_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.resettable_prop == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "resettable_prop", error)
+            });
+            if rust.resettable_prop == value {
+                return;
+            }
+            rust.as_mut().resettable_prop = value;
         }
-        self.as_mut().rust_mut().resettable_prop = value;
     }
 }
 impl ffi::MyObject {
@@ -500,10 +542,15 @@ impl ffi::MyObject {
     #[doc = "required_prop"]
     pub fn set_required_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.required_prop == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "required_prop", error)
+            });
+            if rust.required_prop == value {
+                return;
+            }
+            rust.as_mut().required_prop = value;
         }
-        self.as_mut().rust_mut().required_prop = value;
     }
 }
 impl ffi::MyObject {
@@ -518,10 +565,15 @@ impl ffi::MyObject {
     #[doc = "final_prop"]
     pub fn set_final_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
         use cxx_qt::CxxQtType;
-        if self.final_prop == value {
-            return;
+        {
+            let mut rust = self.as_mut().try_rust_mut().unwrap_or_else(|error| {
+                panic!("cannot set the property {}: {}", "final_prop", error)
+            });
+            if rust.final_prop == value {
+                return;
+            }
+            rust.as_mut().final_prop = value;
         }
-        self.as_mut().rust_mut().final_prop = value;
     }
 }
 impl ffi::MyObject {
@@ -921,15 +973,20 @@ pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
 impl ::core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyObject {
     type Rust = MyObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
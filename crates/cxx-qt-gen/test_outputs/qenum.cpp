@@ -10,6 +10,9 @@ MyObject::MyObject(QObject* parent)
 
 } // namespace cxx_qt::my_object
 
+static const int register_cxx_qt_my_object_MyObject =
+  qRegisterMetaType<cxx_qt::my_object::MyObject*>();
+
 namespace cxx_qt::my_object {
 CxxName::CxxName(QObject* parent)
   : QObject(parent)
@@ -19,3 +22,6 @@ CxxName::CxxName(QObject* parent)
 }
 
 } // namespace cxx_qt::my_object
+
+static const int register_cxx_qt_my_object_CxxName =
+  qRegisterMetaType<cxx_qt::my_object::CxxName*>();
@@ -108,6 +108,11 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyObject>);
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "InternalObject"]
@@ -144,6 +149,11 @@ mod ffi {
             outer: Pin<&mut MyRenamedObject>,
         ) -> Pin<&mut InternalObject>;
     }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "deleteLater"]
+        fn cxx_qt_ffi_delete_later(self: Pin<&mut MyRenamedObject>);
+    }
 }
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
@@ -152,17 +162,22 @@ pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
 impl ::core::ops::Deref for ffi::MyObject {
     type Target = MyObjectRust;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyObject {
     type Rust = MyObjectRust;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
 #[doc(hidden)]
 pub fn create_rs_internal_object() -> std::boxed::Box<InternalObject> {
@@ -171,15 +186,20 @@ pub fn create_rs_internal_object() -> std::boxed::Box<InternalObject> {
 impl ::core::ops::Deref for ffi::MyRenamedObject {
     type Target = InternalObject;
     fn deref(&self) -> &Self::Target {
-        ffi::cxx_qt_ffi_cxx_name_unsafe_rust(self)
+        ::cxx_qt::CxxQtType::rust(self)
     }
 }
 impl ::cxx_qt::CxxQtType for ffi::MyRenamedObject {
     type Rust = InternalObject;
     fn rust(&self) -> &Self::Rust {
+        ::cxx_qt::assert_can_borrow(self, false);
         ffi::cxx_qt_ffi_cxx_name_unsafe_rust(self)
     }
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ::cxx_qt::assert_can_borrow(&*self, true);
         ffi::cxx_qt_ffi_cxx_name_unsafe_rust_mut(self)
     }
+    fn delete_later(self: core::pin::Pin<&mut Self>) {
+        self.cxx_qt_ffi_delete_later()
+    }
 }
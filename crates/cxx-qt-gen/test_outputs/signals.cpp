@@ -147,7 +147,7 @@ operator()<cxx_qt::my_object::MyObject&,
                                            ::std::move(first),
                                            ::std::move(second),
                                            ::std::move(third),
-                                           ::std::move(fourth));
+                                           fourth);
 }
 
 static_assert(alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
@@ -184,7 +184,7 @@ MyObject_dataChangedConnect(
                                                  ::std::move(first),
                                                  ::std::move(second),
                                                  ::std::move(third),
-                                                 ::std::move(fourth));
+                                                 fourth);
     },
     type);
 }
@@ -224,7 +224,7 @@ operator()<cxx_qt::my_object::MyObject&,
                                        ::std::move(first),
                                        ::std::move(second),
                                        ::std::move(third),
-                                       ::std::move(fourth));
+                                       fourth);
 }
 
 static_assert(alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
@@ -261,7 +261,7 @@ MyObject_newDataConnect(
                                                  ::std::move(first),
                                                  ::std::move(second),
                                                  ::std::move(third),
-                                                 ::std::move(fourth));
+                                                 fourth);
     },
     type);
 }
@@ -276,3 +276,6 @@ MyObject::MyObject(QObject* parent)
 }
 
 } // namespace cxx_qt::my_object
+
+static const int register_cxx_qt_my_object_MyObject =
+  qRegisterMetaType<cxx_qt::my_object::MyObject*>();
@@ -12,6 +12,25 @@ fn header_dir() -> PathBuf {
         .join("cxx-qt-lib-extras")
 }
 
+fn qt_version_major() -> u32 {
+    qt_build_utils::QtBuild::new(vec![])
+        .expect("Could not find Qt installation")
+        .version()
+        .major
+}
+
+fn qt_statemachine_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_STATEMACHINE").is_ok()
+}
+
+fn qt_network_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_NETWORK").is_ok()
+}
+
+fn qt_quick_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICK").is_ok()
+}
+
 fn write_headers_in(subfolder: &str) {
     println!("cargo::rerun-if-changed=include/{subfolder}");
 
@@ -37,6 +56,12 @@ fn write_headers() {
 
     write_headers_in("core");
     write_headers_in("gui");
+    if qt_network_enabled() {
+        write_headers_in("network");
+    }
+    if qt_quick_enabled() {
+        write_headers_in("quick");
+    }
 }
 
 fn main() {
@@ -52,24 +77,105 @@ fn main() {
         .qt_module("Gui")
         .qt_module("Widgets");
 
-    let rust_bridges = vec![
+    let mut rust_bridges = vec![
+        "core/qdatastream",
         "core/qelapsedtimer",
+        "core/qfile",
         "core/qcommandlineoption",
         "core/qcommandlineparser",
+        "core/qitemselectionmodel",
+        "core/qprocess",
+        "core/qsavefile",
+        "core/qstringlistmodel",
+        "core/qtemporaryfile",
+        "core/vecmodel",
+        "core/qvariantanimation",
+        "core/qpropertyanimation",
+        "gui/qaccessible",
         "gui/qapplication",
+        "gui/qinputmethod",
+        "gui/qscreen",
+        "gui/qstandarditem",
+        "gui/qstandarditemmodel",
+        "gui/qundostack",
     ];
 
+    // QPromise and the continuations of QFuture are only available in Qt 6,
+    // this needs to match the cxxqt_qt_version_major cfg of the qfuture module
+    let qt6 = qt_version_major() == 6;
+    if qt6 {
+        rust_bridges.push("core/qfuture");
+    }
+
+    if qt_statemachine_enabled() {
+        // The state machine framework is a separate module in Qt 6
+        if qt6 {
+            builder = builder.qt_module("StateMachine");
+        }
+        rust_bridges.push("core/qstatemachine");
+    }
+
+    if qt_network_enabled() {
+        builder = builder.qt_module("Network");
+        rust_bridges.extend([
+            "network/qnetworkaccessmanager",
+            "network/qnetworkreply",
+            "network/qnetworkrequest",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        builder = builder.qt_module("Quick");
+        rust_bridges.push("quick/qquickwindow");
+    }
+
     for rust_source in &rust_bridges {
         builder = builder.file(format!("src/{rust_source}.rs"));
     }
 
-    let cpp_files = vec![
+    let mut cpp_files = vec![
+        "core/qdatastream",
         "core/qelapsedtimer",
+        "core/qfile",
         "core/qcommandlineoption",
         "core/qcommandlineparser",
+        "core/qitemselectionmodel",
+        "core/qprocess",
+        "core/qsavefile",
+        "core/qstringlistmodel",
+        "core/qtemporaryfile",
+        "core/vecmodel",
+        "core/qvariantanimation",
+        "core/qpropertyanimation",
+        "gui/qaccessible",
         "gui/qapplication",
+        "gui/qinputmethod",
+        "gui/qscreen",
+        "gui/qstandarditem",
+        "gui/qstandarditemmodel",
+        "gui/qundostack",
     ];
 
+    if qt6 {
+        cpp_files.push("core/qfuture");
+    }
+
+    if qt_statemachine_enabled() {
+        cpp_files.push("core/qstatemachine");
+    }
+
+    if qt_network_enabled() {
+        cpp_files.extend([
+            "network/qnetworkaccessmanager",
+            "network/qnetworkreply",
+            "network/qnetworkrequest",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        cpp_files.push("quick/qquickwindow");
+    }
+
     builder = builder.cc_builder(move |cc| {
         for cpp_file in &cpp_files {
             cc.file(format!("src/{cpp_file}.cpp"));
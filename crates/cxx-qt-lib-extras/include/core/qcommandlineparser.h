@@ -38,5 +38,8 @@ qcommandlineparserValues(const QCommandLineParser& parser,
 bool
 qcommandlineparserIsSetFromQString(const QCommandLineParser& parser,
                                    const QString& optionName);
+
+bool
+qcommandlineparserProcessApplication(QCommandLineParser& parser);
 } // namespace cxxqtlib1
 } // namespace rust
@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QDataStream>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QDataStreamByteOrder = QDataStream::ByteOrder;
+using QDataStreamStatus = QDataStream::Status;
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewReader(const QByteArray& data);
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewWriter(QByteArray* data);
+
+void
+qdatastreamReadBool(QDataStream& stream, bool& value);
+void
+qdatastreamReadI32(QDataStream& stream, ::std::int32_t& value);
+void
+qdatastreamReadI64(QDataStream& stream, ::std::int64_t& value);
+void
+qdatastreamReadU32(QDataStream& stream, ::std::uint32_t& value);
+void
+qdatastreamReadU64(QDataStream& stream, ::std::uint64_t& value);
+void
+qdatastreamReadF64(QDataStream& stream, double& value);
+void
+qdatastreamReadQByteArray(QDataStream& stream, QByteArray& value);
+void
+qdatastreamReadQString(QDataStream& stream, QString& value);
+void
+qdatastreamReadQVariant(QDataStream& stream, QVariant& value);
+
+void
+qdatastreamWriteBool(QDataStream& stream, bool value);
+void
+qdatastreamWriteI32(QDataStream& stream, ::std::int32_t value);
+void
+qdatastreamWriteI64(QDataStream& stream, ::std::int64_t value);
+void
+qdatastreamWriteU32(QDataStream& stream, ::std::uint32_t value);
+void
+qdatastreamWriteU64(QDataStream& stream, ::std::uint64_t value);
+void
+qdatastreamWriteF64(QDataStream& stream, double value);
+void
+qdatastreamWriteQByteArray(QDataStream& stream, const QByteArray& value);
+void
+qdatastreamWriteQString(QDataStream& stream, const QString& value);
+void
+qdatastreamWriteQVariant(QDataStream& stream, const QVariant& value);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QFile>
+#include <QtCore/QIODevice>
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+
+::std::unique_ptr<QFile>
+qfileNew(const QString& name);
+
+bool
+qfileOpen(QFile& file, QIODeviceOpenModeFlag mode);
+
+::std::int64_t
+qfileSize(const QFile& file);
+
+::std::int64_t
+qfileWrite(QFile& file, const QByteArray& data);
+
+} // namespace cxxqtlib1
+} // namespace rust
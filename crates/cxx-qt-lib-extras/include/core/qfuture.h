@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QFuture>
+#include <QtCore/QPromise>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QFuture_QVariant = QFuture<QVariant>;
+using QPromise_QVariant = QPromise<QVariant>;
+
+// Defined on the Rust side and woken when the future finishes
+struct QFutureWaker;
+
+::std::unique_ptr<QFuture_QVariant>
+qfutureClone(const QFuture_QVariant& future);
+
+void
+qfutureOnFinished(const QFuture_QVariant& future,
+                  ::rust::Box<QFutureWaker> waker);
+
+QVariant
+qfutureResult(const QFuture_QVariant& future);
+
+::std::unique_ptr<QPromise_QVariant>
+qpromiseNew();
+
+bool
+qpromiseAddResult(QPromise_QVariant& promise, const QVariant& value);
+
+::std::unique_ptr<QFuture_QVariant>
+qpromiseFuture(const QPromise_QVariant& promise);
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QItemSelectionModel>
+#include <QtCore/QList>
+#include <QtCore/QModelIndex>
+#include <QtCore/QPersistentModelIndex>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QItemSelectionModelSelectionFlag = QItemSelectionModel::SelectionFlag;
+
+::std::unique_ptr<QItemSelectionModel>
+qitemselectionmodelNew();
+
+void
+qitemselectionmodelSelect(QItemSelectionModel& selectionModel,
+                          const QModelIndex& index,
+                          QItemSelectionModelSelectionFlag command);
+
+QList<QPersistentModelIndex>
+qitemselectionmodelSelectedIndexes(const QItemSelectionModel& selectionModel);
+
+void
+qitemselectionmodelSetCurrentIndex(QItemSelectionModel& selectionModel,
+                                   const QModelIndex& index,
+                                   QItemSelectionModelSelectionFlag command);
+
+}
+}
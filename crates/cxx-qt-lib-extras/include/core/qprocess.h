@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QProcess>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QProcessExitStatus = QProcess::ExitStatus;
+using QProcessProcessError = QProcess::ProcessError;
+using QProcessProcessState = QProcess::ProcessState;
+
+::std::unique_ptr<QProcess>
+qprocessNew();
+
+void
+qprocessStart(QProcess& process,
+              const QString& program,
+              const QStringList& arguments);
+
+::std::int64_t
+qprocessWrite(QProcess& process, const QByteArray& data);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QPropertyAnimation>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QPropertyAnimation>
+qpropertyanimationNew(QObject* target, const QByteArray& propertyName);
+
+const QVariantAnimation&
+qpropertyanimationAsVariantAnimation(const QPropertyAnimation& animation);
+
+QVariantAnimation&
+qpropertyanimationAsVariantAnimationMut(QPropertyAnimation& animation);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QSaveFile>
+#include <QtCore/QString>
+
+#include "cxx-qt-lib-extras/qfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSaveFile>
+qsavefileNew(const QString& name);
+
+bool
+qsavefileOpen(QSaveFile& file, QIODeviceOpenModeFlag mode);
+
+::std::int64_t
+qsavefileWrite(QSaveFile& file, const QByteArray& data);
+
+} // namespace cxxqtlib1
+} // namespace rust
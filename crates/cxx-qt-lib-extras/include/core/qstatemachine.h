@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtStateMachine/QState>
+#include <QtStateMachine/QStateMachine>
+#else
+#include <QtCore/QState>
+#include <QtCore/QStateMachine>
+#endif
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Defined on the Rust side and owned by the transition which it is added with
+struct QStateTransitionRust;
+
+::std::unique_ptr<QStateMachine>
+qstatemachineNew();
+
+const QState&
+qstatemachineAsState(const QStateMachine& machine);
+
+QState&
+qstatemachineAsStateMut(QStateMachine& machine);
+
+void
+qstatemachinePostEvent(QStateMachine& machine, const QString& event);
+
+QState*
+qstateAddState(QState& parent);
+
+void
+qstateAddTransition(QState& source,
+                    const QString& event,
+                    QState* target,
+                    ::rust::Box<QStateTransitionRust> transition);
+
+void
+qstateSetInitialState(QState& parent, QState* state);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QStringListModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew(const QStringList& strings);
+
+bool
+qstringlistmodelInsert(QStringListModel& model,
+                       ::std::int32_t row,
+                       const QString& value);
+
+bool
+qstringlistmodelRemove(QStringListModel& model, ::std::int32_t row);
+
+bool
+qstringlistmodelSet(QStringListModel& model,
+                    ::std::int32_t row,
+                    const QString& value);
+
+}
+}
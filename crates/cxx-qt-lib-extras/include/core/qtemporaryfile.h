@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QString>
+#include <QtCore/QTemporaryFile>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNew();
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNewWithTemplate(const QString& templateName);
+
+bool
+qtemporaryfileOpen(QTemporaryFile& file);
+
+bool
+qtemporaryfileSeek(QTemporaryFile& file, ::std::int64_t position);
+
+::std::int64_t
+qtemporaryfileSize(const QTemporaryFile& file);
+
+::std::int64_t
+qtemporaryfileWrite(QTemporaryFile& file, const QByteArray& data);
+
+} // namespace cxxqtlib1
+} // namespace rust
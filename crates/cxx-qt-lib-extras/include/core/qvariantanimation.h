@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QEasingCurve>
+#include <QtCore/QVariantAnimation>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QAbstractAnimationDirection = QAbstractAnimation::Direction;
+using QAbstractAnimationState = QAbstractAnimation::State;
+using QEasingCurveType = QEasingCurve::Type;
+
+::std::unique_ptr<QVariantAnimation>
+qvariantanimationNew();
+
+QEasingCurveType
+qvariantanimationEasingCurveType(const QVariantAnimation& animation);
+
+void
+qvariantanimationSetEasingCurveType(QVariantAnimation& animation,
+                                    QEasingCurveType type);
+
+void
+qvariantanimationStart(QVariantAnimation& animation);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QAbstractListModel>
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Defined on the Rust side and owned by the VecListModel, provides the items
+struct VecModelRust;
+
+// A list model whose rows are the items of a Rust Vec, the roles are
+// Qt::UserRole and onwards
+class VecListModel : public QAbstractListModel
+{
+public:
+  explicit VecListModel(::rust::Box<VecModelRust> items);
+
+  int rowCount(const QModelIndex& parent = QModelIndex()) const override;
+  QVariant data(const QModelIndex& index, int role) const override;
+  QHash<int, QByteArray> roleNames() const override;
+
+  // Public versions of the protected methods of QAbstractItemModel
+  void beginInsert(::std::int32_t first, ::std::int32_t last);
+  void endInsert();
+  void beginRemove(::std::int32_t first, ::std::int32_t last);
+  void endRemove();
+  void beginReset();
+  void endReset();
+  void rowsChanged(::std::int32_t first, ::std::int32_t last);
+
+private:
+  ::rust::Box<VecModelRust> m_items;
+  QHash<int, QByteArray> m_roleNames;
+};
+
+::std::unique_ptr<VecListModel>
+veclistmodelNew(::rust::Box<VecModelRust> items);
+
+}
+}
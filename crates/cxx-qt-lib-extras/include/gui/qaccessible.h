@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtGui/QAccessible>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QAccessibleRole = QAccessible::Role;
+
+// Defined on the Rust side and owned by the accessible interface of an object
+struct QAccessibleItemRust;
+
+void
+qaccessibleInstall(const QObject* object,
+                   ::rust::Box<QAccessibleItemRust> item);
+
+void
+qaccessibleNotifyNameChanged(const QObject* object);
+
+void
+qaccessibleNotifyValueChanged(const QObject* object);
+
+QString
+qaccessibleDecreaseAction();
+
+QString
+qaccessibleIncreaseAction();
+
+QString
+qaccessiblePressAction();
+
+QString
+qaccessibleToggleAction();
+
+} // namespace cxxqtlib1
+} // namespace rust
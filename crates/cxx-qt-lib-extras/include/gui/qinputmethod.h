@@ -0,0 +1,18 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QInputMethod>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QInputMethod*
+qinputmethodInstance();
+
+} // namespace cxxqtlib1
+} // namespace rust
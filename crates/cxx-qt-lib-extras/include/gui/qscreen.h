@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtGui/QScreen>
+
+#include "cxx-qt/connection.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Defined on the Rust side and owned by the connection, called with a screen
+struct QScreenCallbackRust;
+
+::QMetaObject::Connection
+qscreenOnPrimaryScreenChanged(::rust::Box<QScreenCallbackRust> callback);
+
+::QMetaObject::Connection
+qscreenOnScreenAdded(::rust::Box<QScreenCallbackRust> callback);
+
+::QMetaObject::Connection
+qscreenOnScreenRemoved(::rust::Box<QScreenCallbackRust> callback);
+
+QScreen*
+qscreenPrimaryScreen();
+
+::std::int32_t
+qscreenScreensCount();
+
+QScreen*
+qscreenScreensAt(::std::int32_t index);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtGui/QStandardItem>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStandardItem>
+qstandarditemNew();
+
+::std::unique_ptr<QStandardItem>
+qstandarditemNewWithText(const QString& text);
+
+void
+qstandarditemAppendRow(QStandardItem& item,
+                       ::std::unique_ptr<QStandardItem> child);
+
+}
+}
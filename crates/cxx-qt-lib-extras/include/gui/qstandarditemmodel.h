@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QStandardItem>
+#include <QtGui/QStandardItemModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStandardItemModel>
+qstandarditemmodelNew();
+
+void
+qstandarditemmodelAppendRow(QStandardItemModel& model,
+                            ::std::unique_ptr<QStandardItem> item);
+
+}
+}
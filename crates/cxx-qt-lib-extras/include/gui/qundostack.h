@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtGui/QUndoStack>
+#else
+#include <QtWidgets/QUndoStack>
+#endif
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Defined on the Rust side and owned by the QUndoCommand that is pushed
+struct QUndoCommandRust;
+
+::std::unique_ptr<QUndoStack>
+qundostackNew();
+
+void
+qundostackPush(QUndoStack& stack,
+               const QString& text,
+               ::rust::Box<QUndoCommandRust> command);
+
+}
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtNetwork/QNetworkAccessManager>
+#include <QtNetwork/QNetworkReply>
+#include <QtNetwork/QNetworkRequest>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QNetworkAccessManager>
+qnetworkaccessmanagerNew();
+
+QNetworkReply*
+qnetworkaccessmanagerGet(QNetworkAccessManager& manager,
+                         const QNetworkRequest& request);
+
+QNetworkReply*
+qnetworkaccessmanagerPost(QNetworkAccessManager& manager,
+                          const QNetworkRequest& request,
+                          const QByteArray& data);
+
+} // namespace cxxqtlib1
+} // namespace rust
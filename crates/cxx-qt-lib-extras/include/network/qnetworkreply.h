@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtNetwork/QNetworkReply>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QNetworkReplyNetworkError = QNetworkReply::NetworkError;
+
+::std::int32_t
+qnetworkreplyHttpStatusCode(const QNetworkReply& reply);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtQuick/QQuickItem>
+#include <QtQuick/QQuickWindow>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQuickWindow*
+qquickwindowFromObject(const QObject* object);
+
+} // namespace cxxqtlib1
+} // namespace rust
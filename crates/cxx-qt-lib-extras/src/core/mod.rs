@@ -3,11 +3,55 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qdatastream;
+pub use qdatastream::{
+    QDataStream, QDataStreamByteOrder, QDataStreamStatus, QDataStreamValue, QDataStreamWriter,
+};
+
 mod qelapsedtimer;
 pub use qelapsedtimer::QElapsedTimer;
 
+mod qfile;
+pub use qfile::{QFile, QIODeviceOpenModeFlag};
+
+mod qsavefile;
+pub use qsavefile::QSaveFile;
+
+mod qtemporaryfile;
+pub use qtemporaryfile::QTemporaryFile;
+
 mod qcommandlineoption;
 pub use qcommandlineoption::QCommandLineOption;
 
 mod qcommandlineparser;
 pub use qcommandlineparser::QCommandLineParser;
+
+mod qitemselectionmodel;
+pub use qitemselectionmodel::{QItemSelectionModel, QItemSelectionModelSelectionFlag};
+
+mod qprocess;
+pub use qprocess::{QProcess, QProcessExitStatus, QProcessProcessError, QProcessProcessState};
+
+#[cfg(cxxqt_qt_version_major = "6")]
+mod qfuture;
+#[cfg(cxxqt_qt_version_major = "6")]
+pub use qfuture::{QFuture, QFutureResult, QPromise};
+
+mod qstringlistmodel;
+pub use qstringlistmodel::QStringListModel;
+
+mod vecmodel;
+pub use vecmodel::{VecListModel, VecModel, VecModelItem};
+
+mod qvariantanimation;
+pub use qvariantanimation::{
+    QAbstractAnimationDirection, QAbstractAnimationState, QEasingCurveType, QVariantAnimation,
+};
+
+mod qpropertyanimation;
+pub use qpropertyanimation::QPropertyAnimation;
+
+#[cfg(feature = "qt_statemachine")]
+mod qstatemachine;
+#[cfg(feature = "qt_statemachine")]
+pub use qstatemachine::{QState, QStateMachine};
@@ -103,6 +103,26 @@ impl From<&ffi::QStringList> for QCommandLineOption {
     }
 }
 
+impl QCommandLineOption {
+    /// Constructs a command line option object with the given names, description, value name and default value.
+    ///
+    /// If the value name is empty the option is a flag, otherwise it expects a value.
+    pub fn new(
+        names: &ffi::QStringList,
+        description: &ffi::QString,
+        value_name: &ffi::QString,
+        default_value: &ffi::QString,
+    ) -> Self {
+        let mut option = Self::from(names);
+        option.set_description(description);
+        option.set_value_name(value_name);
+        if !default_value.is_empty() {
+            option.set_default_value(default_value);
+        }
+        option
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side to ensure the size is the same.
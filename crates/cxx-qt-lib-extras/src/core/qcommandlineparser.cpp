@@ -6,6 +6,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include "cxx-qt-lib-extras/qcommandlineparser.h"
 
+#include <QtCore/QCoreApplication>
+
 #include <cxx-qt-lib/assertion_utils.h>
 
 // QCommandLineParser has a single pointer as it's member
@@ -39,5 +41,17 @@ qcommandlineparserIsSetFromQString(const QCommandLineParser& parser,
   return parser.isSet(optionName);
 }
 
+bool
+qcommandlineparserProcessApplication(QCommandLineParser& parser)
+{
+  const auto* app = QCoreApplication::instance();
+  if (app == nullptr) {
+    return false;
+  }
+
+  parser.process(*app);
+  return true;
+}
+
 }
 }
@@ -59,6 +59,15 @@ mod ffi {
         #[rust_name = "add_version_option"]
         fn addVersionOption(self: &mut QCommandLineParser) -> QCommandLineOption;
 
+        /// Defines an additional argument to the application, for the benefit of the help text.
+        #[rust_name = "add_positional_argument"]
+        fn addPositionalArgument(
+            self: &mut QCommandLineParser,
+            name: &QString,
+            description: &QString,
+            syntax: &QString,
+        );
+
         /// Returns the application description.
         #[rust_name = "application_description"]
         fn applicationDescription(self: &QCommandLineParser) -> QString;
@@ -128,6 +137,9 @@ mod ffi {
 
         #[rust_name = "is_set_from_qstring"]
         fn qcommandlineparserIsSetFromQString(parser: &QCommandLineParser, name: &QString) -> bool;
+
+        #[rust_name = "qcommandlineparser_process_application"]
+        fn qcommandlineparserProcessApplication(parser: &mut QCommandLineParser) -> bool;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -169,6 +181,14 @@ impl QCommandLineParser {
     pub fn is_set(&self, name: &ffi::QString) -> bool {
         ffi::is_set_from_qstring(self, name)
     }
+
+    /// Processes the command line arguments of the current QCoreApplication, QGuiApplication or QApplication.
+    ///
+    /// Unlike process() with the arguments of the application, the options which are handled by Qt itself are removed.
+    /// Returns false if no application has been constructed.
+    pub fn process_application(&mut self) -> bool {
+        ffi::qcommandlineparser_process_application(self)
+    }
 }
 
 impl Default for QCommandLineParser {
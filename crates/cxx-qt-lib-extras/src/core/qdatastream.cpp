@@ -0,0 +1,155 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qdatastream.h"
+
+namespace {
+
+// Read a value with the Qt type of the stream operator, as the fixed size
+// integer types of the standard library may be different types
+template<typename QtType, typename T>
+void
+readAs(QDataStream& stream, T& value)
+{
+  QtType qtValue{};
+  stream >> qtValue;
+  value = static_cast<T>(qtValue);
+}
+
+template<typename QtType, typename T>
+void
+writeAs(QDataStream& stream, const T& value)
+{
+  stream << static_cast<QtType>(value);
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewReader(const QByteArray& data)
+{
+  return ::std::make_unique<QDataStream>(data);
+}
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewWriter(QByteArray* data)
+{
+  return ::std::make_unique<QDataStream>(data, QIODevice::WriteOnly);
+}
+
+void
+qdatastreamReadBool(QDataStream& stream, bool& value)
+{
+  readAs<bool>(stream, value);
+}
+
+void
+qdatastreamReadI32(QDataStream& stream, ::std::int32_t& value)
+{
+  readAs<qint32>(stream, value);
+}
+
+void
+qdatastreamReadI64(QDataStream& stream, ::std::int64_t& value)
+{
+  readAs<qint64>(stream, value);
+}
+
+void
+qdatastreamReadU32(QDataStream& stream, ::std::uint32_t& value)
+{
+  readAs<quint32>(stream, value);
+}
+
+void
+qdatastreamReadU64(QDataStream& stream, ::std::uint64_t& value)
+{
+  readAs<quint64>(stream, value);
+}
+
+void
+qdatastreamReadF64(QDataStream& stream, double& value)
+{
+  readAs<double>(stream, value);
+}
+
+void
+qdatastreamReadQByteArray(QDataStream& stream, QByteArray& value)
+{
+  stream >> value;
+}
+
+void
+qdatastreamReadQString(QDataStream& stream, QString& value)
+{
+  stream >> value;
+}
+
+void
+qdatastreamReadQVariant(QDataStream& stream, QVariant& value)
+{
+  stream >> value;
+}
+
+void
+qdatastreamWriteBool(QDataStream& stream, bool value)
+{
+  writeAs<bool>(stream, value);
+}
+
+void
+qdatastreamWriteI32(QDataStream& stream, ::std::int32_t value)
+{
+  writeAs<qint32>(stream, value);
+}
+
+void
+qdatastreamWriteI64(QDataStream& stream, ::std::int64_t value)
+{
+  writeAs<qint64>(stream, value);
+}
+
+void
+qdatastreamWriteU32(QDataStream& stream, ::std::uint32_t value)
+{
+  writeAs<quint32>(stream, value);
+}
+
+void
+qdatastreamWriteU64(QDataStream& stream, ::std::uint64_t value)
+{
+  writeAs<quint64>(stream, value);
+}
+
+void
+qdatastreamWriteF64(QDataStream& stream, double value)
+{
+  writeAs<double>(stream, value);
+}
+
+void
+qdatastreamWriteQByteArray(QDataStream& stream, const QByteArray& value)
+{
+  stream << value;
+}
+
+void
+qdatastreamWriteQString(QDataStream& stream, const QString& value)
+{
+  stream << value;
+}
+
+void
+qdatastreamWriteQVariant(QDataStream& stream, const QVariant& value)
+{
+  stream << value;
+}
+
+}
+}
@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QByteArray, QString, QVariant};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the byte order used for reading and writing numbers.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDataStreamByteOrder {
+        /// Most significant byte first (the default)
+        BigEndian,
+        /// Least significant byte first
+        LittleEndian,
+    }
+
+    /// This enum describes the current status of the data stream.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDataStreamStatus {
+        /// The data stream is operating normally.
+        Ok,
+        /// The data stream has read past the end of the data in the underlying device.
+        ReadPastEnd,
+        /// The data stream has read corrupt data.
+        ReadCorruptData,
+        /// The data stream cannot write to the underlying device.
+        WriteFailed,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib-extras/qdatastream.h");
+        /// A stream of binary data in the serialization format of Qt, which is independent
+        /// of the operating system and CPU byte order.
+        ///
+        /// Read a stream with [QDataStream::from_bytes] and write one with a [QDataStreamWriter].
+        type QDataStream;
+
+        /// Returns true if the stream has no more data to read; otherwise returns false.
+        #[rust_name = "at_end"]
+        fn atEnd(self: &QDataStream) -> bool;
+
+        /// Returns the current byte order setting.
+        #[rust_name = "byte_order"]
+        fn byteOrder(self: &QDataStream) -> QDataStreamByteOrder;
+
+        /// Resets the status of the data stream.
+        #[rust_name = "reset_status"]
+        fn resetStatus(self: Pin<&mut QDataStream>);
+
+        /// Sets the serialization byte order.
+        #[rust_name = "set_byte_order"]
+        fn setByteOrder(self: Pin<&mut QDataStream>, order: QDataStreamByteOrder);
+
+        /// Sets the version number of the data serialization format, which must match the version
+        /// used by the peer, for example 19 for `QDataStream::Qt_5_15` or 20 for `QDataStream::Qt_6_0`.
+        #[rust_name = "set_version"]
+        fn setVersion(self: Pin<&mut QDataStream>, version: i32);
+
+        /// Returns the status of the data stream.
+        fn status(self: &QDataStream) -> QDataStreamStatus;
+
+        /// Returns the version number of the data serialization format.
+        fn version(self: &QDataStream) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QDataStreamByteOrder;
+        type QDataStreamStatus;
+
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_new_reader"]
+        fn qdatastreamNewReader(data: &QByteArray) -> UniquePtr<QDataStream>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_new_writer"]
+        unsafe fn qdatastreamNewWriter(data: *mut QByteArray) -> UniquePtr<QDataStream>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_bool"]
+        fn qdatastreamReadBool(stream: Pin<&mut QDataStream>, value: &mut bool);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_i32"]
+        fn qdatastreamReadI32(stream: Pin<&mut QDataStream>, value: &mut i32);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_i64"]
+        fn qdatastreamReadI64(stream: Pin<&mut QDataStream>, value: &mut i64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_u32"]
+        fn qdatastreamReadU32(stream: Pin<&mut QDataStream>, value: &mut u32);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_u64"]
+        fn qdatastreamReadU64(stream: Pin<&mut QDataStream>, value: &mut u64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_f64"]
+        fn qdatastreamReadF64(stream: Pin<&mut QDataStream>, value: &mut f64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_qbytearray"]
+        fn qdatastreamReadQByteArray(stream: Pin<&mut QDataStream>, value: &mut QByteArray);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_qstring"]
+        fn qdatastreamReadQString(stream: Pin<&mut QDataStream>, value: &mut QString);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_read_qvariant"]
+        fn qdatastreamReadQVariant(stream: Pin<&mut QDataStream>, value: &mut QVariant);
+
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_bool"]
+        fn qdatastreamWriteBool(stream: Pin<&mut QDataStream>, value: bool);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_i32"]
+        fn qdatastreamWriteI32(stream: Pin<&mut QDataStream>, value: i32);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_i64"]
+        fn qdatastreamWriteI64(stream: Pin<&mut QDataStream>, value: i64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_u32"]
+        fn qdatastreamWriteU32(stream: Pin<&mut QDataStream>, value: u32);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_u64"]
+        fn qdatastreamWriteU64(stream: Pin<&mut QDataStream>, value: u64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_f64"]
+        fn qdatastreamWriteF64(stream: Pin<&mut QDataStream>, value: f64);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_qbytearray"]
+        fn qdatastreamWriteQByteArray(stream: Pin<&mut QDataStream>, value: &QByteArray);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_qstring"]
+        fn qdatastreamWriteQString(stream: Pin<&mut QDataStream>, value: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_write_qvariant"]
+        fn qdatastreamWriteQVariant(stream: Pin<&mut QDataStream>, value: &QVariant);
+    }
+
+    // QDataStream can't be copied and refers to its device,
+    // so we need to use references or pointers.
+    impl UniquePtr<QDataStream> {}
+}
+
+pub use ffi::{QDataStream, QDataStreamByteOrder, QDataStreamStatus};
+
+impl QDataStream {
+    /// Create a new QDataStream which reads from a copy of the given data
+    pub fn from_bytes(data: &QByteArray) -> UniquePtr<Self> {
+        ffi::qdatastream_new_reader(data)
+    }
+
+    /// Reads a value from the stream.
+    ///
+    /// If the data could not be read, the value is default-constructed and [QDataStream::status] reports the error.
+    pub fn read<T: QDataStreamValue>(self: Pin<&mut Self>) -> T {
+        T::read(self)
+    }
+
+    /// Writes a value to the stream.
+    pub fn write<T: QDataStreamValue>(self: Pin<&mut Self>, value: &T) {
+        T::write(self, value)
+    }
+}
+
+/// A value that can be read from and written to a [QDataStream] with the stream operators of Qt.
+pub trait QDataStreamValue {
+    fn read(stream: Pin<&mut QDataStream>) -> Self;
+    fn write(stream: Pin<&mut QDataStream>, value: &Self);
+}
+
+macro_rules! impl_qdatastream_value {
+    ( $typeName:ty, $read:ident, $write:ident ) => {
+        impl QDataStreamValue for $typeName {
+            fn read(stream: Pin<&mut QDataStream>) -> Self {
+                let mut value = <$typeName>::default();
+                ffi::$read(stream, &mut value);
+                value
+            }
+
+            fn write(stream: Pin<&mut QDataStream>, value: &Self) {
+                ffi::$write(stream, *value);
+            }
+        }
+    };
+    ( $typeName:ty, $read:ident, $write:ident, by_ref ) => {
+        impl QDataStreamValue for $typeName {
+            fn read(stream: Pin<&mut QDataStream>) -> Self {
+                let mut value = <$typeName>::default();
+                ffi::$read(stream, &mut value);
+                value
+            }
+
+            fn write(stream: Pin<&mut QDataStream>, value: &Self) {
+                ffi::$write(stream, value);
+            }
+        }
+    };
+}
+
+impl_qdatastream_value!(bool, qdatastream_read_bool, qdatastream_write_bool);
+impl_qdatastream_value!(i32, qdatastream_read_i32, qdatastream_write_i32);
+impl_qdatastream_value!(i64, qdatastream_read_i64, qdatastream_write_i64);
+impl_qdatastream_value!(u32, qdatastream_read_u32, qdatastream_write_u32);
+impl_qdatastream_value!(u64, qdatastream_read_u64, qdatastream_write_u64);
+impl_qdatastream_value!(f64, qdatastream_read_f64, qdatastream_write_f64);
+impl_qdatastream_value!(
+    QByteArray,
+    qdatastream_read_qbytearray,
+    qdatastream_write_qbytearray,
+    by_ref
+);
+impl_qdatastream_value!(
+    QString,
+    qdatastream_read_qstring,
+    qdatastream_write_qstring,
+    by_ref
+);
+impl_qdatastream_value!(
+    QVariant,
+    qdatastream_read_qvariant,
+    qdatastream_write_qvariant,
+    by_ref
+);
+
+/// Writes a [QDataStream] into a [QByteArray], which can then be sent to a peer with [QDataStreamWriter::into_bytes].
+pub struct QDataStreamWriter {
+    // The stream refers to the data, so it is declared first to be dropped first
+    stream: UniquePtr<QDataStream>,
+    data: Box<QByteArray>,
+}
+
+impl Default for QDataStreamWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QDataStreamWriter {
+    /// Create a new writer with an empty QByteArray
+    pub fn new() -> Self {
+        let mut data = Box::<QByteArray>::default();
+        // SAFETY: the data is boxed so it does not move, and it is dropped after the stream
+        let stream = unsafe { ffi::qdatastream_new_writer(&mut *data) };
+        Self { stream, data }
+    }
+
+    /// Returns the stream to write values to, or to change the version and byte order with.
+    pub fn stream(&mut self) -> Pin<&mut QDataStream> {
+        self.stream.pin_mut()
+    }
+
+    /// Finishes writing and returns the data of the stream.
+    pub fn into_bytes(mut self) -> QByteArray {
+        // Destroy the stream before taking the data which it refers to
+        self.stream = UniquePtr::null();
+        core::mem::take(&mut *self.data)
+    }
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QFile>
+qfileNew(const QString& name)
+{
+  return ::std::make_unique<QFile>(name);
+}
+
+bool
+qfileOpen(QFile& file, QIODeviceOpenModeFlag mode)
+{
+  // The flag may be a combination of flags from Rust, so convert to QFlags
+  return file.open(QIODevice::OpenMode(mode));
+}
+
+::std::int64_t
+qfileSize(const QFile& file)
+{
+  return static_cast<::std::int64_t>(file.size());
+}
+
+::std::int64_t
+qfileWrite(QFile& file, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(file.write(data));
+}
+
+}
+}
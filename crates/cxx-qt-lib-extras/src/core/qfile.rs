@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QByteArray, QString};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum is used with open() to describe the mode in which a device is opened.
+    ///
+    /// Flags can be combined with the `|` operator, eg `WriteOnly | Truncate`.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIODeviceOpenModeFlag {
+        /// The device is not open.
+        NotOpen = 0x0000,
+        /// The device is open for reading.
+        ReadOnly = 0x0001,
+        /// The device is open for writing. Note that, for file-system subclasses, this mode implies Truncate.
+        WriteOnly = 0x0002,
+        /// The device is open for reading and writing.
+        ReadWrite = 0x0003,
+        /// The device is opened in append mode so that all data is written to the end of the file.
+        Append = 0x0004,
+        /// If possible, the device is truncated before it is opened. All earlier contents of the device are lost.
+        Truncate = 0x0008,
+        /// When reading, the end-of-line terminators are translated to '\n'.
+        /// When writing, the end-of-line terminators are translated to the local encoding.
+        Text = 0x0010,
+        /// Any buffer in the device is bypassed.
+        Unbuffered = 0x0020,
+        /// Fail if the file to be opened already exists. Create and open the file only if it does not exist.
+        NewOnly = 0x0040,
+        /// Fail if the file to be opened does not exist.
+        ExistingOnly = 0x0080,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qfile.h");
+        /// A file which can be read and written, including the files of the
+        /// [Qt Resource System](https://doc.qt.io/qt-6/resources.html) with paths starting with `:/`.
+        type QFile;
+
+        /// Calls flush() and closes the file. Errors from flush are ignored.
+        fn close(self: Pin<&mut QFile>);
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QFile) -> QString;
+
+        /// Returns true if the file specified by file_name() exists; otherwise returns false.
+        fn exists(self: &QFile) -> bool;
+
+        /// Returns the name of the file.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QFile) -> QString;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QFile) -> bool;
+
+        /// Reads all remaining data from the device, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QFile>) -> QByteArray;
+
+        /// Removes the file specified by file_name(). Returns true if successful; otherwise returns false.
+        ///
+        /// The file is closed before it is removed.
+        fn remove(self: Pin<&mut QFile>) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QIODeviceOpenModeFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_new"]
+        fn qfileNew(name: &QString) -> UniquePtr<QFile>;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_open"]
+        fn qfileOpen(file: Pin<&mut QFile>, mode: QIODeviceOpenModeFlag) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_size"]
+        fn qfileSize(file: &QFile) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qfile_write"]
+        fn qfileWrite(file: Pin<&mut QFile>, data: &QByteArray) -> i64;
+    }
+
+    // QFile is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QFile>::isRelocatable);
+    impl UniquePtr<QFile> {}
+}
+
+pub use ffi::{QFile, QIODeviceOpenModeFlag};
+
+impl core::ops::BitOr for QIODeviceOpenModeFlag {
+    type Output = Self;
+
+    /// Combine two open mode flags, eg `WriteOnly | Truncate`
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            repr: self.repr | rhs.repr,
+        }
+    }
+}
+
+impl QFile {
+    /// Create a new QFile to represent the file with the given name
+    pub fn new(name: &QString) -> UniquePtr<Self> {
+        ffi::qfile_new(name)
+    }
+
+    /// Opens the file with the given mode, returns true if successful; otherwise false.
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        ffi::qfile_open(self, mode)
+    }
+
+    /// Reads the entire contents of the file at the given path.
+    ///
+    /// Unlike [std::fs::read] this can read the files that are bundled into the
+    /// application with the Qt Resource System, for example `QFile::read(&QString::from(":/qml/main.qml"))`.
+    /// Returns the error string of the file if it could not be opened.
+    pub fn read(path: &QString) -> Result<QByteArray, QString> {
+        let mut file = Self::new(path);
+        let mut file = file.pin_mut();
+        if file.as_mut().open(QIODeviceOpenModeFlag::ReadOnly) {
+            Ok(file.read_all())
+        } else {
+            Err(file.error_string())
+        }
+    }
+
+    /// Returns the size of the file.
+    pub fn size(&self) -> i64 {
+        ffi::qfile_size(self)
+    }
+
+    /// Writes the content of data to the file.
+    ///
+    /// Returns the number of bytes that were actually written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qfile_write(self, data)
+    }
+}
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qfuture.h"
+
+#include "cxx-qt-lib-extras-internals/src/core/qfuture.cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QFuture_QVariant>
+qfutureClone(const QFuture_QVariant& future)
+{
+  return ::std::make_unique<QFuture_QVariant>(future);
+}
+
+void
+qfutureOnFinished(const QFuture_QVariant& future,
+                  ::rust::Box<QFutureWaker> waker)
+{
+  // The continuations are copied by Qt, so they share ownership of the waker
+  auto shared =
+    ::std::make_shared<::rust::Box<QFutureWaker>>(::std::move(waker));
+
+  // The continuation is not invoked when the future is canceled,
+  // but then the future returned by then() is canceled as well
+  QFuture_QVariant(future)
+    .then(QtFuture::Launch::Sync,
+          [shared](QFuture_QVariant) { (*shared)->wake(); })
+    .onCanceled([shared]() { (*shared)->wake(); });
+}
+
+QVariant
+qfutureResult(const QFuture_QVariant& future)
+{
+  return future.result();
+}
+
+::std::unique_ptr<QPromise_QVariant>
+qpromiseNew()
+{
+  return ::std::make_unique<QPromise_QVariant>();
+}
+
+bool
+qpromiseAddResult(QPromise_QVariant& promise, const QVariant& value)
+{
+  return promise.addResult(value);
+}
+
+::std::unique_ptr<QFuture_QVariant>
+qpromiseFuture(const QPromise_QVariant& promise)
+{
+  return ::std::make_unique<QFuture_QVariant>(promise.future());
+}
+
+}
+}
@@ -0,0 +1,246 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use cxx::UniquePtr;
+use cxx_qt_lib::QVariant;
+use std::sync::{Arc, Mutex};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qfuture.h");
+        /// A `QFuture<QVariant>`, which represents the result of an asynchronous computation.
+        #[cxx_name = "QFuture_QVariant"]
+        type QFuture;
+        /// A `QPromise<QVariant>`, which provides the result of an asynchronous computation to a [QFuture].
+        #[cxx_name = "QPromise_QVariant"]
+        type QPromise;
+
+        /// Cancels the asynchronous computation represented by this future.
+        fn cancel(self: Pin<&mut QFuture>);
+
+        /// Returns true if the asynchronous computation has been canceled with the cancel() function,
+        /// or the promise was destroyed without finishing; otherwise returns false.
+        #[rust_name = "is_canceled"]
+        fn isCanceled(self: &QFuture) -> bool;
+
+        /// Returns true if the asynchronous computation represented by this future has finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QFuture) -> bool;
+
+        /// Returns true if the asynchronous computation represented by this future is currently running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QFuture) -> bool;
+
+        /// Returns true if the asynchronous computation represented by this future has been started; otherwise returns false.
+        #[rust_name = "is_started"]
+        fn isStarted(self: &QFuture) -> bool;
+
+        /// Returns the number of continuous results available in this future.
+        #[rust_name = "result_count"]
+        fn resultCount(self: &QFuture) -> i32;
+
+        /// Waits for the asynchronous computation to finish (including cancel()ed computations).
+        #[rust_name = "wait_for_finished"]
+        fn waitForFinished(self: Pin<&mut QFuture>);
+
+        /// Reports that the computation is finished.
+        fn finish(self: Pin<&mut QPromise>);
+
+        /// Returns whether the computation has been canceled with QFuture::cancel().
+        #[rust_name = "is_canceled"]
+        fn isCanceled(self: &QPromise) -> bool;
+
+        /// Sets the progress range of the computation to be between minimum and maximum.
+        #[rust_name = "set_progress_range"]
+        fn setProgressRange(self: Pin<&mut QPromise>, minimum: i32, maximum: i32);
+
+        /// Sets the progress value of the computation to value.
+        #[rust_name = "set_progress_value"]
+        fn setProgressValue(self: Pin<&mut QPromise>, value: i32);
+
+        /// Reports that the computation is started.
+        fn start(self: Pin<&mut QPromise>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QFutureWaker;
+
+        fn wake(self: &QFutureWaker);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfuture_clone"]
+        fn qfutureClone(future: &QFuture) -> UniquePtr<QFuture>;
+
+        #[doc(hidden)]
+        #[rust_name = "qfuture_on_finished"]
+        fn qfutureOnFinished(future: &QFuture, waker: Box<QFutureWaker>);
+
+        #[doc(hidden)]
+        #[rust_name = "qfuture_result"]
+        fn qfutureResult(future: &QFuture) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "qpromise_new"]
+        fn qpromiseNew() -> UniquePtr<QPromise>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpromise_add_result"]
+        fn qpromiseAddResult(promise: Pin<&mut QPromise>, value: &QVariant) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qpromise_future"]
+        fn qpromiseFuture(promise: &QPromise) -> UniquePtr<QFuture>;
+    }
+
+    // QFuture and QPromise are not trivial to CXX and QPromise can't be copied,
+    // so we need to use references or pointers.
+    impl UniquePtr<QFuture> {}
+    impl UniquePtr<QPromise> {}
+}
+
+pub use ffi::{QFuture, QPromise};
+
+// Safety:
+//
+// QFuture and QPromise share their state with a thread-safe QFutureInterface,
+// they are designed to be used from a different thread than the one which created them.
+unsafe impl Send for QFuture {}
+unsafe impl Send for QPromise {}
+
+#[doc(hidden)]
+pub struct QFutureWaker {
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl QFutureWaker {
+    fn wake(&self) {
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+impl QFuture {
+    /// Returns another future for the same asynchronous computation.
+    pub fn clone_future(&self) -> UniquePtr<Self> {
+        ffi::qfuture_clone(self)
+    }
+
+    /// Returns the first result of the future, blocking until it is available.
+    ///
+    /// Returns `None` if the computation was canceled or finished without a result.
+    pub fn result(&self) -> Option<QVariant> {
+        // Wait through a copy of the future, as waiting requires a mutable future
+        let mut future = self.clone_future();
+        if let Some(future) = future.as_mut() {
+            future.wait_for_finished();
+        }
+
+        if self.result_count() > 0 {
+            Some(ffi::qfuture_result(self))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a Rust [Future] which resolves to the first result once the computation has finished.
+    ///
+    /// This allows for awaiting QFutures from C++ APIs, such as QtConcurrent::run(), in Rust async code.
+    /// The [Future] resolves to `None` if the computation was canceled or finished without a result.
+    pub fn to_future(&self) -> QFutureResult {
+        QFutureResult {
+            future: self.clone_future(),
+            waker: None,
+        }
+    }
+}
+
+/// A Rust [Future] for the result of a [QFuture], which is created with [QFuture::to_future].
+pub struct QFutureResult {
+    future: UniquePtr<QFuture>,
+    waker: Option<Arc<Mutex<Option<Waker>>>>,
+}
+
+impl Future for QFutureResult {
+    type Output = Option<QVariant>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let future = if let Some(future) = this.future.as_ref() {
+            future
+        } else {
+            return Poll::Ready(None);
+        };
+
+        if future.is_finished() {
+            return if future.result_count() > 0 {
+                Poll::Ready(Some(ffi::qfuture_result(future)))
+            } else {
+                Poll::Ready(None)
+            };
+        }
+
+        if let Some(waker) = &this.waker {
+            *waker.lock().unwrap() = Some(cx.waker().clone());
+        } else {
+            // The waker is woken on the thread which finishes the computation,
+            // if the computation finished since checking above it is woken immediately
+            let waker = Arc::new(Mutex::new(Some(cx.waker().clone())));
+            ffi::qfuture_on_finished(
+                future,
+                Box::new(QFutureWaker {
+                    waker: waker.clone(),
+                }),
+            );
+            this.waker = Some(waker);
+        }
+
+        Poll::Pending
+    }
+}
+
+impl QPromise {
+    /// Create a new QPromise, the result can then be awaited through the QFuture from [QPromise::future].
+    ///
+    /// If the promise is dropped before it is finished, the computation is canceled.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qpromise_new()
+    }
+
+    /// Adds a result to the internal result collection, returns false if the promise is canceled or finished.
+    pub fn add_result(self: Pin<&mut Self>, value: &QVariant) -> bool {
+        ffi::qpromise_add_result(self, value)
+    }
+
+    /// Returns a future associated with this promise, which can be returned to C++ APIs expecting a QFuture.
+    pub fn future(&self) -> UniquePtr<QFuture> {
+        ffi::qpromise_future(self)
+    }
+
+    /// Starts the computation if needed, adds the result and finishes the computation.
+    ///
+    /// This is useful to report the output of a Rust future once it completes.
+    pub fn finish_with_result(mut self: Pin<&mut Self>, value: &QVariant) {
+        self.as_mut().start();
+        self.as_mut().add_result(value);
+        self.finish();
+    }
+}
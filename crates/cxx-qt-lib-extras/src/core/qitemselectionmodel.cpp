@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qitemselectionmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QItemSelectionModel>
+qitemselectionmodelNew()
+{
+  return ::std::make_unique<QItemSelectionModel>();
+}
+
+void
+qitemselectionmodelSelect(QItemSelectionModel& selectionModel,
+                          const QModelIndex& index,
+                          QItemSelectionModelSelectionFlag command)
+{
+  // The flag may be a combination of flags from Rust, so convert to QFlags
+  selectionModel.select(index, QItemSelectionModel::SelectionFlags(command));
+}
+
+QList<QPersistentModelIndex>
+qitemselectionmodelSelectedIndexes(const QItemSelectionModel& selectionModel)
+{
+  QList<QPersistentModelIndex> indexes;
+  const auto selected = selectionModel.selectedIndexes();
+  indexes.reserve(selected.size());
+  for (const auto& index : selected) {
+    indexes.append(QPersistentModelIndex(index));
+  }
+  return indexes;
+}
+
+void
+qitemselectionmodelSetCurrentIndex(QItemSelectionModel& selectionModel,
+                                   const QModelIndex& index,
+                                   QItemSelectionModelSelectionFlag command)
+{
+  selectionModel.setCurrentIndex(index,
+                                 QItemSelectionModel::SelectionFlags(command));
+}
+
+}
+}
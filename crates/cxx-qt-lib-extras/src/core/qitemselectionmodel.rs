@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QList, QModelIndex, QPersistentModelIndex};
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the way the selection model will be updated.
+    ///
+    /// Flags can be combined with the `|` operator, eg `Select | Rows`.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QItemSelectionModelSelectionFlag {
+        /// No selection will be made.
+        NoUpdate = 0x0000,
+        /// The complete selection will be cleared.
+        Clear = 0x0001,
+        /// All specified indexes will be selected.
+        Select = 0x0002,
+        /// All specified indexes will be deselected.
+        Deselect = 0x0004,
+        /// All specified indexes will be selected or deselected depending on their current state.
+        Toggle = 0x0008,
+        /// The current selection will be updated.
+        Current = 0x0010,
+        /// All indexes will be expanded to span rows.
+        Rows = 0x0020,
+        /// All indexes will be expanded to span columns.
+        Columns = 0x0040,
+        /// A combination of Select and Current, provided for convenience.
+        SelectCurrent = 0x0012,
+        /// A combination of Toggle and Current, provided for convenience.
+        ToggleCurrent = 0x0018,
+        /// A combination of Clear and Select, provided for convenience.
+        ClearAndSelect = 0x0003,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qitemselectionmodel.h");
+        #[qobject]
+        type QItemSelectionModel;
+
+        /// This signal is emitted whenever the current item changes.
+        /// The previous model item index is replaced by the current index as the selection's current item.
+        #[qsignal]
+        #[cxx_name = "currentChanged"]
+        fn current_changed(
+            self: Pin<&mut QItemSelectionModel>,
+            current: &QModelIndex,
+            previous: &QModelIndex,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QPersistentModelIndex = cxx_qt_lib::QList<cxx_qt_lib::QPersistentModelIndex>;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+
+        /// Clears the selection model. Emits selectionChanged() and currentChanged().
+        fn clear(self: Pin<&mut QItemSelectionModel>);
+
+        /// Clears the current index. Emits currentChanged().
+        #[rust_name = "clear_current_index"]
+        fn clearCurrentIndex(self: Pin<&mut QItemSelectionModel>);
+
+        /// Clears the selection in the selection model. Emits selectionChanged().
+        #[rust_name = "clear_selection"]
+        fn clearSelection(self: Pin<&mut QItemSelectionModel>);
+
+        /// Returns the model item index for the current item, or an invalid index if there is no current item.
+        #[rust_name = "current_index"]
+        fn currentIndex(self: &QItemSelectionModel) -> QModelIndex;
+
+        /// Returns true if the selection model contains any selected item, otherwise returns false.
+        #[rust_name = "has_selection"]
+        fn hasSelection(self: &QItemSelectionModel) -> bool;
+
+        /// Returns true if all items are selected in the row with the given parent.
+        #[rust_name = "is_row_selected"]
+        fn isRowSelected(self: &QItemSelectionModel, row: i32, parent: &QModelIndex) -> bool;
+
+        /// Returns true if the given model item index is selected.
+        #[rust_name = "is_selected"]
+        fn isSelected(self: &QItemSelectionModel, index: &QModelIndex) -> bool;
+
+        /// Clears the selection model. Does not emit any signals.
+        fn reset(self: Pin<&mut QItemSelectionModel>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QItemSelectionModelSelectionFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qitemselectionmodel_new"]
+        fn qitemselectionmodelNew() -> UniquePtr<QItemSelectionModel>;
+
+        #[doc(hidden)]
+        #[rust_name = "qitemselectionmodel_select"]
+        fn qitemselectionmodelSelect(
+            selection_model: Pin<&mut QItemSelectionModel>,
+            index: &QModelIndex,
+            command: QItemSelectionModelSelectionFlag,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qitemselectionmodel_selected_indexes"]
+        fn qitemselectionmodelSelectedIndexes(
+            selection_model: &QItemSelectionModel,
+        ) -> QList_QPersistentModelIndex;
+
+        #[doc(hidden)]
+        #[rust_name = "qitemselectionmodel_set_current_index"]
+        fn qitemselectionmodelSetCurrentIndex(
+            selection_model: Pin<&mut QItemSelectionModel>,
+            index: &QModelIndex,
+            command: QItemSelectionModelSelectionFlag,
+        );
+    }
+
+    // QItemSelectionModel is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QItemSelectionModel>::isRelocatable);
+    impl UniquePtr<QItemSelectionModel> {}
+}
+
+pub use ffi::{QItemSelectionModel, QItemSelectionModelSelectionFlag};
+
+impl core::ops::BitOr for QItemSelectionModelSelectionFlag {
+    type Output = Self;
+
+    /// Combine two selection flags, eg `Select | Rows`
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            repr: self.repr | rhs.repr,
+        }
+    }
+}
+
+impl QItemSelectionModel {
+    /// Create a new QItemSelectionModel without a model
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qitemselectionmodel_new()
+    }
+
+    /// Selects the model item index using the specified command, and emits selectionChanged().
+    pub fn select(
+        self: Pin<&mut Self>,
+        index: &QModelIndex,
+        command: QItemSelectionModelSelectionFlag,
+    ) {
+        ffi::qitemselectionmodel_select(self, index, command)
+    }
+
+    /// Returns a list of all selected model item indexes. The list contains no duplicates, and is not sorted.
+    ///
+    /// The indexes are returned as persistent indexes so that they stay valid when the model changes.
+    pub fn selected_indexes(&self) -> QList<QPersistentModelIndex> {
+        ffi::qitemselectionmodel_selected_indexes(self)
+    }
+
+    /// Sets the model item index to be the current item, and emits currentChanged().
+    /// The current item is used for keyboard navigation and focus indication;
+    /// it is independent of any selected items, although a selected item can also be the current item.
+    ///
+    /// Depending on the specified command, the index can also become part of the current selection.
+    pub fn set_current_index(
+        self: Pin<&mut Self>,
+        index: &QModelIndex,
+        command: QItemSelectionModelSelectionFlag,
+    ) {
+        ffi::qitemselectionmodel_set_current_index(self, index, command)
+    }
+}
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qprocess.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QProcess>
+qprocessNew()
+{
+  return ::std::make_unique<QProcess>();
+}
+
+void
+qprocessStart(QProcess& process,
+              const QString& program,
+              const QStringList& arguments)
+{
+  process.start(program, arguments);
+}
+
+::std::int64_t
+qprocessWrite(QProcess& process, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(process.write(data));
+}
+
+}
+}
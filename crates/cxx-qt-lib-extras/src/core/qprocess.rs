@@ -0,0 +1,238 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QString, QStringList};
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the different exit statuses of QProcess.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessExitStatus {
+        /// The process exited normally.
+        NormalExit,
+        /// The process crashed.
+        CrashExit,
+    }
+
+    /// This enum describes the different types of errors that are reported by QProcess.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessProcessError {
+        /// The process failed to start. Either the invoked program is missing,
+        /// or you may have insufficient permissions or resources to invoke the program.
+        FailedToStart,
+        /// The process crashed some time after starting successfully.
+        Crashed,
+        /// The last wait_for...() function timed out.
+        Timedout,
+        /// An error occurred when attempting to read from the process.
+        ReadError,
+        /// An error occurred when attempting to write to the process.
+        WriteError,
+        /// An unknown error occurred.
+        UnknownError,
+    }
+
+    /// This enum describes the different states of QProcess.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QProcessProcessState {
+        /// The process is not running.
+        NotRunning,
+        /// The process is starting, but the program has not yet been invoked.
+        Starting,
+        /// The process is running and is ready for reading and writing.
+        Running,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qprocess.h");
+        #[qobject]
+        type QProcess;
+
+        /// This signal is emitted when QProcess has called the program and the state is Running.
+        #[qsignal]
+        fn started(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted when the process finishes. exit_code is the exit code of the process
+        /// (only valid for normal exits), and exit_status is the exit status.
+        ///
+        /// Qt 5 also declares the deprecated `finished(int)` overload, which makes the signal ambiguous,
+        /// so there the same closure is called from the state changes instead.
+        #[qsignal]
+        #[qt(since = "6.0")]
+        fn finished(self: Pin<&mut QProcess>, exit_code: i32, exit_status: QProcessExitStatus);
+
+        /// This signal is emitted whenever the state of QProcess changes.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(self: Pin<&mut QProcess>, new_state: QProcessProcessState);
+
+        /// This signal is emitted when an error occurs with the process.
+        #[qsignal]
+        #[cxx_name = "errorOccurred"]
+        fn error_occurred(self: Pin<&mut QProcess>, error: QProcessProcessError);
+
+        /// This signal is emitted when the process has made new data available through its standard output channel.
+        #[qsignal]
+        #[cxx_name = "readyReadStandardOutput"]
+        fn ready_read_standard_output(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted when the process has made new data available through its standard error channel.
+        #[qsignal]
+        #[cxx_name = "readyReadStandardError"]
+        fn ready_read_standard_error(self: Pin<&mut QProcess>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        /// Returns the command line arguments the process was last started with.
+        fn arguments(self: &QProcess) -> QStringList;
+
+        /// Closes the write channel to the process, once all data has been written.
+        /// This is how the child is told that there is no more data on its standard input.
+        #[rust_name = "close_write_channel"]
+        fn closeWriteChannel(self: Pin<&mut QProcess>);
+
+        /// Returns the type of error that occurred last.
+        fn error(self: &QProcess) -> QProcessProcessError;
+
+        /// Returns the exit code of the last process that finished.
+        #[rust_name = "exit_code"]
+        fn exitCode(self: &QProcess) -> i32;
+
+        /// Returns the exit status of the last process that finished.
+        #[rust_name = "exit_status"]
+        fn exitStatus(self: &QProcess) -> QProcessExitStatus;
+
+        /// Kills the current process, causing it to exit immediately.
+        fn kill(self: Pin<&mut QProcess>);
+
+        /// Returns the program the process was last started with.
+        fn program(self: &QProcess) -> QString;
+
+        /// Reads all data currently available from the standard error of the process.
+        #[rust_name = "read_all_standard_error"]
+        fn readAllStandardError(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// Reads all data currently available from the standard output of the process.
+        #[rust_name = "read_all_standard_output"]
+        fn readAllStandardOutput(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// Sets the working directory to dir. QProcess will start the process in this directory.
+        #[rust_name = "set_working_directory"]
+        fn setWorkingDirectory(self: Pin<&mut QProcess>, dir: &QString);
+
+        /// Returns the current state of the process.
+        fn state(self: &QProcess) -> QProcessProcessState;
+
+        /// Attempts to terminate the process.
+        /// The process may not exit as a result of calling this function (it is given the chance to prompt the user for any unsaved files, etc).
+        fn terminate(self: Pin<&mut QProcess>);
+
+        /// Blocks until the process has finished, or until msecs milliseconds have passed.
+        /// Returns true if the process finished; otherwise returns false.
+        ///
+        /// Prefer connecting to the finished signal with [`QProcess::on_finished`] when running on the Qt event loop.
+        #[rust_name = "wait_for_finished"]
+        fn waitForFinished(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        /// Blocks until the process has started, or until msecs milliseconds have passed.
+        /// Returns true if the process was started successfully; otherwise returns false.
+        #[rust_name = "wait_for_started"]
+        fn waitForStarted(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        /// If QProcess has been assigned a working directory, this function returns the working directory
+        /// that the QProcess will enter before the program has started. Otherwise, an empty string is returned.
+        #[rust_name = "working_directory"]
+        fn workingDirectory(self: &QProcess) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QProcessExitStatus;
+        type QProcessProcessError;
+        type QProcessProcessState;
+
+        #[doc(hidden)]
+        #[rust_name = "qprocess_new"]
+        fn qprocessNew() -> UniquePtr<QProcess>;
+
+        #[doc(hidden)]
+        #[rust_name = "qprocess_start"]
+        fn qprocessStart(process: Pin<&mut QProcess>, program: &QString, arguments: &QStringList);
+
+        #[doc(hidden)]
+        #[rust_name = "qprocess_write"]
+        fn qprocessWrite(process: Pin<&mut QProcess>, data: &QByteArray) -> i64;
+    }
+
+    // QProcess is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QProcess>::isRelocatable);
+    impl UniquePtr<QProcess> {}
+}
+
+pub use ffi::{QProcess, QProcessExitStatus, QProcessProcessError, QProcessProcessState};
+
+impl QProcess {
+    /// Create a new QProcess
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qprocess_new()
+    }
+
+    /// Connect to the finished signal of the process, the closure receives
+    /// the exit code and the exit status of the process.
+    ///
+    /// The closure is only called for processes which started successfully,
+    /// use [`QProcess::on_error_occurred`] to be notified about failures to start.
+    #[cfg(cxxqt_qt_version_major = "5")]
+    pub fn on_finished<F>(
+        self: Pin<&mut Self>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard
+    where
+        F: FnMut(Pin<&mut Self>, i32, QProcessExitStatus) + 'static + Send,
+    {
+        let mut running = false;
+        self.on_state_changed(move |process, state| match state {
+            QProcessProcessState::Running => running = true,
+            QProcessProcessState::NotRunning if running => {
+                running = false;
+                let exit_code = process.exit_code();
+                let exit_status = process.exit_status();
+                closure(process, exit_code, exit_status);
+            }
+            _ => {}
+        })
+    }
+
+    /// Starts the given program in a new process, passing the command line arguments in arguments.
+    ///
+    /// This does not block, connect to [`QProcess::on_started`] and [`QProcess::on_finished`]
+    /// to be notified as the process progresses.
+    pub fn start(self: Pin<&mut Self>, program: &QString, arguments: &QStringList) {
+        ffi::qprocess_start(self, program, arguments)
+    }
+
+    /// Writes data to the standard input of the process.
+    ///
+    /// Returns the number of bytes that were actually written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qprocess_write(self, data)
+    }
+}
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qpropertyanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QPropertyAnimation>
+qpropertyanimationNew(QObject* target, const QByteArray& propertyName)
+{
+  return ::std::make_unique<QPropertyAnimation>(target, propertyName);
+}
+
+const QVariantAnimation&
+qpropertyanimationAsVariantAnimation(const QPropertyAnimation& animation)
+{
+  return animation;
+}
+
+QVariantAnimation&
+qpropertyanimationAsVariantAnimationMut(QPropertyAnimation& animation)
+{
+  return animation;
+}
+
+}
+}
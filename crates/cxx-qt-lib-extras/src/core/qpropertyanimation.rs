@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QVariantAnimation;
+use core::{ops::Deref, pin::Pin};
+use cxx::UniquePtr;
+use cxx_qt_lib::QByteArray;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qpropertyanimation.h");
+        /// An animation of a Qt property of a QObject, from a start value to an end value.
+        ///
+        /// The methods of [QVariantAnimation] are available through [Deref] and
+        /// [QPropertyAnimation::as_variant_animation_mut].
+        #[qobject]
+        type QPropertyAnimation;
+
+        /// QAbstractAnimation emits this signal after the animation has stopped and has reached the end.
+        #[qsignal]
+        fn finished(self: Pin<&mut QPropertyAnimation>);
+
+        /// QVariantAnimation emits this signal whenever the current value changes.
+        #[qsignal]
+        #[cxx_name = "valueChanged"]
+        fn value_changed(self: Pin<&mut QPropertyAnimation>, value: &QVariant);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib-extras/qvariantanimation.h");
+        type QVariantAnimation = crate::QVariantAnimation;
+
+        #[doc(hidden)]
+        type QObject;
+
+        /// Returns the name of the property which is animated.
+        #[rust_name = "property_name"]
+        fn propertyName(self: &QPropertyAnimation) -> QByteArray;
+
+        /// Sets the name of the property which is animated.
+        #[rust_name = "set_property_name"]
+        fn setPropertyName(self: Pin<&mut QPropertyAnimation>, property_name: &QByteArray);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_new"]
+        unsafe fn qpropertyanimationNew(
+            target: *mut QObject,
+            property_name: &QByteArray,
+        ) -> UniquePtr<QPropertyAnimation>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_as_variant_animation"]
+        fn qpropertyanimationAsVariantAnimation(
+            animation: &QPropertyAnimation,
+        ) -> &QVariantAnimation;
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_as_variant_animation_mut"]
+        fn qpropertyanimationAsVariantAnimationMut(
+            animation: Pin<&mut QPropertyAnimation>,
+        ) -> Pin<&mut QVariantAnimation>;
+    }
+
+    // QPropertyAnimation is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QPropertyAnimation>::isRelocatable);
+    impl UniquePtr<QPropertyAnimation> {}
+}
+
+pub use ffi::QPropertyAnimation;
+
+impl QPropertyAnimation {
+    /// Create a new QPropertyAnimation which animates the property with the given name of the target.
+    ///
+    /// The animation does not own the target, so the target must outlive the animation.
+    pub fn new<T>(target: Pin<&mut T>, property_name: &QByteArray) -> UniquePtr<Self>
+    where
+        T: cxx_qt::CxxQtType,
+    {
+        // SAFETY: the C++ class of a CXX-Qt QObject has its QObject base class first,
+        // so a pointer to it is also a valid pointer to its QObject.
+        unsafe {
+            let target = target.get_unchecked_mut() as *mut T as *mut ffi::QObject;
+            ffi::qpropertyanimation_new(target, property_name)
+        }
+    }
+
+    /// Returns a pinned mutable reference to the QVariantAnimation base class,
+    /// which allows for setting the duration, the values and the easing curve and starting the animation.
+    pub fn as_variant_animation_mut(self: Pin<&mut Self>) -> Pin<&mut QVariantAnimation> {
+        ffi::qpropertyanimation_as_variant_animation_mut(self)
+    }
+}
+
+impl Deref for QPropertyAnimation {
+    type Target = QVariantAnimation;
+
+    fn deref(&self) -> &Self::Target {
+        ffi::qpropertyanimation_as_variant_animation(self)
+    }
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qsavefile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSaveFile>
+qsavefileNew(const QString& name)
+{
+  return ::std::make_unique<QSaveFile>(name);
+}
+
+bool
+qsavefileOpen(QSaveFile& file, QIODeviceOpenModeFlag mode)
+{
+  // The flag may be a combination of flags from Rust, so convert to QFlags
+  return file.open(QIODevice::OpenMode(mode));
+}
+
+::std::int64_t
+qsavefileWrite(QSaveFile& file, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(file.write(data));
+}
+
+}
+}
@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QIODeviceOpenModeFlag;
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QByteArray, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qsavefile.h");
+        /// A file which is written atomically: the data is written to a temporary file
+        /// which replaces the target file when [QSaveFile::commit] is called.
+        ///
+        /// If an error occurs while writing or the file is dropped without committing,
+        /// the target file is left unchanged, so a crash cannot leave a partially written file behind.
+        type QSaveFile;
+
+        /// Cancels writing the new file. If the application changes its mind while writing,
+        /// it can call this function, which ensures that the original file will not be overwritten.
+        #[rust_name = "cancel_writing"]
+        fn cancelWriting(self: Pin<&mut QSaveFile>);
+
+        /// Commits the changes to disk, if all previous writes were successful.
+        ///
+        /// Returns true if the target file was replaced, otherwise false and the target file is unchanged.
+        fn commit(self: Pin<&mut QSaveFile>) -> bool;
+
+        /// Returns true if the fallback solution for saving files in read-only directories is enabled.
+        #[rust_name = "direct_write_fallback"]
+        fn directWriteFallback(self: &QSaveFile) -> bool;
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QSaveFile) -> QString;
+
+        /// Returns the name of the target file.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QSaveFile) -> QString;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QSaveFile) -> bool;
+
+        /// Allows writing directly to the target file when the directory does not allow creating
+        /// a temporary file next to it, which loses the atomicity of the write.
+        #[rust_name = "set_direct_write_fallback"]
+        fn setDirectWriteFallback(self: Pin<&mut QSaveFile>, enabled: bool);
+
+        /// Sets the name of the target file.
+        #[rust_name = "set_file_name"]
+        fn setFileName(self: Pin<&mut QSaveFile>, name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QIODeviceOpenModeFlag = crate::QIODeviceOpenModeFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qsavefile_new"]
+        fn qsavefileNew(name: &QString) -> UniquePtr<QSaveFile>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsavefile_open"]
+        fn qsavefileOpen(file: Pin<&mut QSaveFile>, mode: QIODeviceOpenModeFlag) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qsavefile_write"]
+        fn qsavefileWrite(file: Pin<&mut QSaveFile>, data: &QByteArray) -> i64;
+    }
+
+    // QSaveFile is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QSaveFile>::isRelocatable);
+    impl UniquePtr<QSaveFile> {}
+}
+
+pub use ffi::QSaveFile;
+
+impl QSaveFile {
+    /// Create a new QSaveFile for the target file with the given name
+    pub fn new(name: &QString) -> UniquePtr<Self> {
+        ffi::qsavefile_new(name)
+    }
+
+    /// Opens the file with the given mode, which must include `WriteOnly`.
+    /// Returns true if successful; otherwise false.
+    ///
+    /// The data is written to a temporary file until [QSaveFile::commit] is called.
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        ffi::qsavefile_open(self, mode)
+    }
+
+    /// Writes the content of data to the file.
+    ///
+    /// Returns the number of bytes that were actually written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qsavefile_write(self, data)
+    }
+
+    /// Atomically replaces the contents of the file at the given path with data.
+    ///
+    /// Returns the error string of the file if it could not be written, the file is then unchanged.
+    pub fn write_all(path: &QString, data: &QByteArray) -> Result<(), QString> {
+        let mut file = Self::new(path);
+        let mut file = file.pin_mut();
+        if file.as_mut().open(QIODeviceOpenModeFlag::WriteOnly)
+            && file.as_mut().write(data) == data.len() as i64
+            && file.as_mut().commit()
+        {
+            Ok(())
+        } else {
+            let error = file.error_string();
+            file.cancel_writing();
+            Err(error)
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qstatemachine.h"
+
+#include <QtCore/QEvent>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtStateMachine/QAbstractTransition>
+#else
+#include <QtCore/QAbstractTransition>
+#endif
+
+#include "cxx-qt-lib-extras-internals/src/core/qstatemachine.cxx.h"
+
+namespace {
+
+// An event with a name, which is posted to a state machine from Rust
+class RustStateMachineEvent : public QEvent
+{
+public:
+  explicit RustStateMachineEvent(const QString& name)
+    : QEvent(eventType())
+    , m_name(name)
+  {
+  }
+
+  static QEvent::Type eventType()
+  {
+    static const int type = QEvent::registerEventType();
+    return static_cast<QEvent::Type>(type);
+  }
+
+  const QString& name() const { return m_name; }
+
+private:
+  QString m_name;
+};
+
+// A transition which is triggered by the event with the given name and
+// forwards the transition to a closure implemented in Rust
+class RustStateTransition : public QAbstractTransition
+{
+public:
+  RustStateTransition(
+    const QString& event,
+    ::rust::Box<::rust::cxxqtlib1::QStateTransitionRust> transition)
+    : m_event(event)
+    , m_transition(::std::move(transition))
+  {
+  }
+
+protected:
+  bool eventTest(QEvent* event) override
+  {
+    return event->type() == RustStateMachineEvent::eventType() &&
+           static_cast<RustStateMachineEvent*>(event)->name() == m_event;
+  }
+
+  void onTransition(QEvent*) override { m_transition->triggered(); }
+
+private:
+  QString m_event;
+  ::rust::Box<::rust::cxxqtlib1::QStateTransitionRust> m_transition;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStateMachine>
+qstatemachineNew()
+{
+  return ::std::make_unique<QStateMachine>();
+}
+
+const QState&
+qstatemachineAsState(const QStateMachine& machine)
+{
+  return machine;
+}
+
+QState&
+qstatemachineAsStateMut(QStateMachine& machine)
+{
+  return machine;
+}
+
+void
+qstatemachinePostEvent(QStateMachine& machine, const QString& event)
+{
+  // The state machine takes ownership of the event
+  machine.postEvent(new RustStateMachineEvent(event));
+}
+
+QState*
+qstateAddState(QState& parent)
+{
+  // The parent state takes ownership of the child state
+  return new QState(&parent);
+}
+
+void
+qstateAddTransition(QState& source,
+                    const QString& event,
+                    QState* target,
+                    ::rust::Box<QStateTransitionRust> transition)
+{
+  auto* rustTransition =
+    new RustStateTransition(event, ::std::move(transition));
+  rustTransition->setTargetState(target);
+  // The source state takes ownership of the transition
+  source.addTransition(rustTransition);
+}
+
+void
+qstateSetInitialState(QState& parent, QState* state)
+{
+  parent.setInitialState(state);
+}
+
+}
+}
@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{ops::Deref, pin::Pin};
+use cxx::UniquePtr;
+use cxx_qt_lib::QString;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstatemachine.h");
+        /// A state of a [QStateMachine], which can have child states and transitions to other states.
+        ///
+        /// The state can be given to QML, for example from a property with the type `*mut QState`,
+        /// where its `active` property reflects whether the state machine is in this state.
+        #[qobject]
+        type QState;
+
+        /// This signal is emitted when the state has been entered.
+        #[qsignal]
+        fn entered(self: Pin<&mut QState>);
+
+        /// This signal is emitted when the state has been exited.
+        #[qsignal]
+        fn exited(self: Pin<&mut QState>);
+
+        /// This signal is emitted when a final child state of this state is entered.
+        #[qsignal]
+        fn finished(self: Pin<&mut QState>);
+
+        /// This signal is emitted when the active property is changed with active as argument.
+        #[qsignal]
+        #[cxx_name = "activeChanged"]
+        fn active_changed(self: Pin<&mut QState>, active: bool);
+
+        /// A hierarchical finite state machine, whose transitions are triggered by events
+        /// posted with [QStateMachine::post_event].
+        ///
+        /// The methods of [QState] are available through [Deref] and [QStateMachine::as_state_mut].
+        #[qobject]
+        type QStateMachine;
+
+        /// This signal is emitted when the state machine has entered its initial state.
+        #[qsignal]
+        fn started(self: Pin<&mut QStateMachine>);
+
+        /// This signal is emitted when the state machine has stopped.
+        #[qsignal]
+        fn stopped(self: Pin<&mut QStateMachine>);
+
+        /// This signal is emitted when the running property is changed with running as argument.
+        #[qsignal]
+        #[cxx_name = "runningChanged"]
+        fn running_changed(self: Pin<&mut QStateMachine>, running: bool);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns whether this state is currently active.
+        fn active(self: &QState) -> bool;
+
+        /// Returns whether this state machine is running.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QStateMachine) -> bool;
+
+        /// Starts this state machine. The machine will reset its configuration and transition to the initial state.
+        fn start(self: Pin<&mut QStateMachine>);
+
+        /// Stops this state machine. The state machine will stop processing events and then emit the stopped signal.
+        fn stop(self: Pin<&mut QStateMachine>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QStateTransitionRust;
+
+        fn triggered(self: &mut QStateTransitionRust);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_new"]
+        fn qstatemachineNew() -> UniquePtr<QStateMachine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_as_state"]
+        fn qstatemachineAsState(machine: &QStateMachine) -> &QState;
+
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_as_state_mut"]
+        fn qstatemachineAsStateMut(machine: Pin<&mut QStateMachine>) -> Pin<&mut QState>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_post_event"]
+        fn qstatemachinePostEvent(machine: Pin<&mut QStateMachine>, event: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_add_state"]
+        fn qstateAddState(parent: Pin<&mut QState>) -> *mut QState;
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_add_transition"]
+        unsafe fn qstateAddTransition(
+            source: Pin<&mut QState>,
+            event: &QString,
+            target: *mut QState,
+            transition: Box<QStateTransitionRust>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_set_initial_state"]
+        unsafe fn qstateSetInitialState(parent: Pin<&mut QState>, state: *mut QState);
+    }
+
+    // QStateMachine is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStateMachine>::isRelocatable);
+    impl UniquePtr<QStateMachine> {}
+}
+
+pub use ffi::{QState, QStateMachine};
+
+#[doc(hidden)]
+pub struct QStateTransitionRust {
+    on_transition: Box<dyn FnMut()>,
+}
+
+impl QStateTransitionRust {
+    fn triggered(&mut self) {
+        (self.on_transition)();
+    }
+}
+
+impl QState {
+    /// Creates a new child state of this state and returns a pointer to it.
+    ///
+    /// The child state is owned by this state and destroyed with it.
+    pub fn add_state(self: Pin<&mut Self>) -> *mut QState {
+        ffi::qstate_add_state(self)
+    }
+
+    /// Adds a transition from this state to the target state, which is triggered
+    /// when an event with the given name is posted with [QStateMachine::post_event] while this state is active.
+    ///
+    /// The closure is called when the transition is taken, after this state has been exited
+    /// and before the target state is entered. If the target is null, the transition is targetless:
+    /// the closure is called but the state machine stays in its current state.
+    ///
+    /// # Safety
+    ///
+    /// The target must be null or point to a state of the same state machine, which lives as long as this state.
+    pub unsafe fn add_transition<F>(
+        self: Pin<&mut Self>,
+        event: &QString,
+        target: *mut QState,
+        on_transition: F,
+    ) where
+        F: FnMut() + 'static,
+    {
+        ffi::qstate_add_transition(
+            self,
+            event,
+            target,
+            Box::new(QStateTransitionRust {
+                on_transition: Box::new(on_transition),
+            }),
+        );
+    }
+
+    /// Sets this state's initial state, which is entered when this state is entered.
+    ///
+    /// # Safety
+    ///
+    /// The state must be a valid pointer to a child state of this state.
+    pub unsafe fn set_initial_state(self: Pin<&mut Self>, state: *mut QState) {
+        ffi::qstate_set_initial_state(self, state);
+    }
+}
+
+impl QStateMachine {
+    /// Create a new QStateMachine
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qstatemachine_new()
+    }
+
+    /// Returns a pinned mutable reference to the QState base class,
+    /// which allows for adding states to the state machine and setting its initial state.
+    pub fn as_state_mut(self: Pin<&mut Self>) -> Pin<&mut QState> {
+        ffi::qstatemachine_as_state_mut(self)
+    }
+
+    /// Posts an event with the given name to the state machine,
+    /// which triggers the transitions for this event of the active states.
+    ///
+    /// The event is processed asynchronously from the event loop, events posted while
+    /// the state machine is not running are discarded.
+    pub fn post_event(self: Pin<&mut Self>, event: &QString) {
+        ffi::qstatemachine_post_event(self, event);
+    }
+}
+
+impl Deref for QStateMachine {
+    type Target = QState;
+
+    fn deref(&self) -> &Self::Target {
+        ffi::qstatemachine_as_state(self)
+    }
+}
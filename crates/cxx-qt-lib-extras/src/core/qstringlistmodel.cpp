@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qstringlistmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew(const QStringList& strings)
+{
+  return ::std::make_unique<QStringListModel>(strings);
+}
+
+bool
+qstringlistmodelInsert(QStringListModel& model,
+                       ::std::int32_t row,
+                       const QString& value)
+{
+  if (!model.insertRows(row, 1)) {
+    return false;
+  }
+  return model.setData(model.index(row), value);
+}
+
+bool
+qstringlistmodelRemove(QStringListModel& model, ::std::int32_t row)
+{
+  return model.removeRows(row, 1);
+}
+
+bool
+qstringlistmodelSet(QStringListModel& model,
+                    ::std::int32_t row,
+                    const QString& value)
+{
+  return model.setData(model.index(row), value);
+}
+
+}
+}
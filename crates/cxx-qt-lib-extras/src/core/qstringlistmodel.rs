@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QString, QStringList};
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstringlistmodel.h");
+        /// A model which provides a list of strings to views, the strings are available with the display role.
+        #[qobject]
+        type QStringListModel;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        /// Returns the number of rows in the model.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QStringListModel, parent: &QModelIndex) -> i32;
+
+        /// Sets the model's internal string list to strings. The model will notify any attached views that its underlying data has changed.
+        #[rust_name = "set_string_list"]
+        fn setStringList(self: Pin<&mut QStringListModel>, strings: &QStringList);
+
+        /// Returns the string list used by the model to store data.
+        #[rust_name = "string_list"]
+        fn stringList(self: &QStringListModel) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_new"]
+        fn qstringlistmodelNew(strings: &QStringList) -> UniquePtr<QStringListModel>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_insert"]
+        fn qstringlistmodelInsert(
+            model: Pin<&mut QStringListModel>,
+            row: i32,
+            value: &QString,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_remove"]
+        fn qstringlistmodelRemove(model: Pin<&mut QStringListModel>, row: i32) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_set"]
+        fn qstringlistmodelSet(
+            model: Pin<&mut QStringListModel>,
+            row: i32,
+            value: &QString,
+        ) -> bool;
+    }
+
+    // QStringListModel is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStringListModel>::isRelocatable);
+    impl UniquePtr<QStringListModel> {}
+}
+
+pub use ffi::QStringListModel;
+
+impl QStringListModel {
+    /// Create a new QStringListModel containing the given strings
+    pub fn new(strings: &QStringList) -> UniquePtr<Self> {
+        ffi::qstringlistmodel_new(strings)
+    }
+
+    /// Inserts the string at the given row, emitting the signals for views to show the new row.
+    /// Returns false if the row is out of range.
+    pub fn insert(self: Pin<&mut Self>, row: i32, value: &QString) -> bool {
+        ffi::qstringlistmodel_insert(self, row, value)
+    }
+
+    /// Removes the string at the given row, emitting the signals for views to remove the row.
+    /// Returns false if the row is out of range.
+    pub fn remove(self: Pin<&mut Self>, row: i32) -> bool {
+        ffi::qstringlistmodel_remove(self, row)
+    }
+
+    /// Replaces the string at the given row, emitting the signal for views to update the row.
+    /// Returns false if the row is out of range.
+    pub fn set(self: Pin<&mut Self>, row: i32, value: &QString) -> bool {
+        ffi::qstringlistmodel_set(self, row, value)
+    }
+}
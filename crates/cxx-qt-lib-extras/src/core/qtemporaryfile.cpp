@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qtemporaryfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNew()
+{
+  return ::std::make_unique<QTemporaryFile>();
+}
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNewWithTemplate(const QString& templateName)
+{
+  return ::std::make_unique<QTemporaryFile>(templateName);
+}
+
+bool
+qtemporaryfileOpen(QTemporaryFile& file)
+{
+  // QTemporaryFile::open() is a non-virtual overload which always opens the
+  // file in ReadWrite mode
+  return file.open();
+}
+
+bool
+qtemporaryfileSeek(QTemporaryFile& file, ::std::int64_t position)
+{
+  return file.seek(static_cast<qint64>(position));
+}
+
+::std::int64_t
+qtemporaryfileSize(const QTemporaryFile& file)
+{
+  return static_cast<::std::int64_t>(file.size());
+}
+
+::std::int64_t
+qtemporaryfileWrite(QTemporaryFile& file, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(file.write(data));
+}
+
+}
+}
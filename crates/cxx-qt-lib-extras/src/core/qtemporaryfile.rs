@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QByteArray, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qtemporaryfile.h");
+        /// A temporary file with a unique name, which is removed when it is dropped unless
+        /// [QTemporaryFile::set_auto_remove] is disabled.
+        type QTemporaryFile;
+
+        /// Returns true if the file is removed automatically when it is dropped.
+        #[rust_name = "auto_remove"]
+        fn autoRemove(self: &QTemporaryFile) -> bool;
+
+        /// Calls flush() and closes the file. The file can be opened again with open().
+        fn close(self: Pin<&mut QTemporaryFile>);
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QTemporaryFile) -> QString;
+
+        /// Returns the complete unique filename backing the file, which is empty until the file has been opened.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QTemporaryFile) -> QString;
+
+        /// Returns the file name template, which is used to create the unique file name.
+        #[rust_name = "file_template"]
+        fn fileTemplate(self: &QTemporaryFile) -> QString;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QTemporaryFile) -> bool;
+
+        /// Reads all remaining data from the device, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QTemporaryFile>) -> QByteArray;
+
+        /// Renames the temporary file to new_name, which disables removing it automatically.
+        /// Returns true if successful; otherwise false.
+        ///
+        /// This is done on the file system of the temporary file, use a template in the directory
+        /// of the target file to ensure that this is possible.
+        fn rename(self: Pin<&mut QTemporaryFile>, new_name: &QString) -> bool;
+
+        /// Sets whether the file is removed automatically when it is dropped.
+        #[rust_name = "set_auto_remove"]
+        fn setAutoRemove(self: Pin<&mut QTemporaryFile>, enabled: bool);
+
+        /// Sets the file name template, the last occurrence of six upper case 'X' characters
+        /// is replaced with unique characters. A relative template is relative to the current directory.
+        #[rust_name = "set_file_template"]
+        fn setFileTemplate(self: Pin<&mut QTemporaryFile>, template_name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_new"]
+        fn qtemporaryfileNew() -> UniquePtr<QTemporaryFile>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_new_with_template"]
+        fn qtemporaryfileNewWithTemplate(template_name: &QString) -> UniquePtr<QTemporaryFile>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_open"]
+        fn qtemporaryfileOpen(file: Pin<&mut QTemporaryFile>) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_seek"]
+        fn qtemporaryfileSeek(file: Pin<&mut QTemporaryFile>, position: i64) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_size"]
+        fn qtemporaryfileSize(file: &QTemporaryFile) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_write"]
+        fn qtemporaryfileWrite(file: Pin<&mut QTemporaryFile>, data: &QByteArray) -> i64;
+    }
+
+    // QTemporaryFile is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QTemporaryFile>::isRelocatable);
+    impl UniquePtr<QTemporaryFile> {}
+}
+
+pub use ffi::QTemporaryFile;
+
+impl QTemporaryFile {
+    /// Create a new QTemporaryFile in the temporary directory of the system
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qtemporaryfile_new()
+    }
+
+    /// Create a new QTemporaryFile with the given file name template, see [QTemporaryFile::set_file_template].
+    pub fn new_with_template(template_name: &QString) -> UniquePtr<Self> {
+        ffi::qtemporaryfile_new_with_template(template_name)
+    }
+
+    /// Creates a unique file name for the temporary file, and opens it in ReadWrite mode.
+    /// Returns true if successful; otherwise false.
+    pub fn open(self: Pin<&mut Self>) -> bool {
+        ffi::qtemporaryfile_open(self)
+    }
+
+    /// Seeks to the given position from the start of the file, for example to read
+    /// the data that has been written. Returns true on success.
+    pub fn seek(self: Pin<&mut Self>, position: i64) -> bool {
+        ffi::qtemporaryfile_seek(self, position)
+    }
+
+    /// Returns the size of the file.
+    pub fn size(&self) -> i64 {
+        ffi::qtemporaryfile_size(self)
+    }
+
+    /// Writes the content of data to the file.
+    ///
+    /// Returns the number of bytes that were actually written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qtemporaryfile_write(self, data)
+    }
+}
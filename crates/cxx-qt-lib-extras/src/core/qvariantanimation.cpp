@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qvariantanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QVariantAnimation>
+qvariantanimationNew()
+{
+  return ::std::make_unique<QVariantAnimation>();
+}
+
+QEasingCurveType
+qvariantanimationEasingCurveType(const QVariantAnimation& animation)
+{
+  return animation.easingCurve().type();
+}
+
+void
+qvariantanimationSetEasingCurveType(QVariantAnimation& animation,
+                                    QEasingCurveType type)
+{
+  animation.setEasingCurve(QEasingCurve(type));
+}
+
+void
+qvariantanimationStart(QVariantAnimation& animation)
+{
+  // The animation is owned by Rust, so it must not delete itself when stopped
+  animation.start(QAbstractAnimation::KeepWhenStopped);
+}
+
+}
+}
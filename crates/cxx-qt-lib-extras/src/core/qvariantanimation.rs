@@ -0,0 +1,272 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the direction of the animation when in Running state.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractAnimationDirection {
+        /// The current time of the animation increases with time (i.e., moves from 0 and towards the end / duration).
+        Forward,
+        /// The current time of the animation decreases with time (i.e., moves from the end / duration and towards 0).
+        Backward,
+    }
+
+    /// This enum describes the state of the animation.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractAnimationState {
+        /// The animation is not running.
+        Stopped,
+        /// The animation is paused (i.e., temporarily suspended).
+        Paused,
+        /// The animation is running.
+        Running,
+    }
+
+    /// The type of easing curve of an animation, which controls how the value progresses over time.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QEasingCurveType {
+        /// Easing curve for a linear (t) function: velocity is constant.
+        Linear,
+        /// Easing curve for a quadratic (t^2) function: accelerating from zero velocity.
+        InQuad,
+        /// Easing curve for a quadratic (t^2) function: decelerating to zero velocity.
+        OutQuad,
+        /// Easing curve for a quadratic (t^2) function: acceleration until halfway, then deceleration.
+        InOutQuad,
+        /// Easing curve for a quadratic (t^2) function: deceleration until halfway, then acceleration.
+        OutInQuad,
+        /// Easing curve for a cubic (t^3) function: accelerating from zero velocity.
+        InCubic,
+        /// Easing curve for a cubic (t^3) function: decelerating to zero velocity.
+        OutCubic,
+        /// Easing curve for a cubic (t^3) function: acceleration until halfway, then deceleration.
+        InOutCubic,
+        /// Easing curve for a cubic (t^3) function: deceleration until halfway, then acceleration.
+        OutInCubic,
+        /// Easing curve for a quartic (t^4) function: accelerating from zero velocity.
+        InQuart,
+        /// Easing curve for a quartic (t^4) function: decelerating to zero velocity.
+        OutQuart,
+        /// Easing curve for a quartic (t^4) function: acceleration until halfway, then deceleration.
+        InOutQuart,
+        /// Easing curve for a quartic (t^4) function: deceleration until halfway, then acceleration.
+        OutInQuart,
+        /// Easing curve for a quintic (t^5) function: accelerating from zero velocity.
+        InQuint,
+        /// Easing curve for a quintic (t^5) function: decelerating to zero velocity.
+        OutQuint,
+        /// Easing curve for a quintic (t^5) function: acceleration until halfway, then deceleration.
+        InOutQuint,
+        /// Easing curve for a quintic (t^5) function: deceleration until halfway, then acceleration.
+        OutInQuint,
+        /// Easing curve for a sinusoidal (sin(t)) function: accelerating from zero velocity.
+        InSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: decelerating to zero velocity.
+        OutSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: acceleration until halfway, then deceleration.
+        InOutSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: deceleration until halfway, then acceleration.
+        OutInSine,
+        /// Easing curve for an exponential (2^t) function: accelerating from zero velocity.
+        InExpo,
+        /// Easing curve for an exponential (2^t) function: decelerating to zero velocity.
+        OutExpo,
+        /// Easing curve for an exponential (2^t) function: acceleration until halfway, then deceleration.
+        InOutExpo,
+        /// Easing curve for an exponential (2^t) function: deceleration until halfway, then acceleration.
+        OutInExpo,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: accelerating from zero velocity.
+        InCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: decelerating to zero velocity.
+        OutCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: acceleration until halfway, then deceleration.
+        InOutCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: deceleration until halfway, then acceleration.
+        OutInCirc,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: accelerating from zero velocity.
+        InElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: decelerating to zero velocity.
+        OutElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: acceleration until halfway, then deceleration.
+        InOutElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: deceleration until halfway, then acceleration.
+        OutInElastic,
+        /// Easing curve for a back (overshooting cubic function: (s+1)*t^3 - s*t^2) easing in: accelerating from zero velocity.
+        InBack,
+        /// Easing curve for a back (overshooting cubic function: (s+1)*t^3 - s*t^2) easing out: decelerating to zero velocity.
+        OutBack,
+        /// Easing curve for a back (overshooting cubic function: (s+1)*t^3 - s*t^2) easing in/out: acceleration until halfway, then deceleration.
+        InOutBack,
+        /// Easing curve for a back (overshooting cubic easing: (s+1)*t^3 - s*t^2) easing out/in: deceleration until halfway, then acceleration.
+        OutInBack,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: accelerating from zero velocity.
+        InBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: decelerating from zero velocity.
+        OutBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function easing in/out: acceleration until halfway, then deceleration.
+        InOutBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function easing out/in: deceleration until halfway, then acceleration.
+        OutInBounce,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qvariantanimation.h");
+        /// An animation of a QVariant value from a start value to an end value,
+        /// connect to [`QVariantAnimation::on_value_changed`] to apply the value as the animation progresses.
+        #[qobject]
+        type QVariantAnimation;
+
+        /// QAbstractAnimation emits this signal after the animation has stopped and has reached the end.
+        #[qsignal]
+        fn finished(self: Pin<&mut QVariantAnimation>);
+
+        /// QAbstractAnimation emits this signal whenever the state of the animation has changed from old_state to new_state.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(
+            self: Pin<&mut QVariantAnimation>,
+            new_state: QAbstractAnimationState,
+            old_state: QAbstractAnimationState,
+        );
+
+        /// QVariantAnimation emits this signal whenever the current value changes.
+        #[qsignal]
+        #[cxx_name = "valueChanged"]
+        fn value_changed(self: Pin<&mut QVariantAnimation>, value: &QVariant);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Returns the current time of the animation in milliseconds.
+        #[rust_name = "current_time"]
+        fn currentTime(self: &QVariantAnimation) -> i32;
+
+        /// Returns the current value of the animation, interpolated between the start and end values.
+        #[rust_name = "current_value"]
+        fn currentValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns the direction of the animation.
+        fn direction(self: &QVariantAnimation) -> QAbstractAnimationDirection;
+
+        /// Returns the duration of the animation in milliseconds.
+        fn duration(self: &QVariantAnimation) -> i32;
+
+        /// Returns the end value of the animation.
+        #[rust_name = "end_value"]
+        fn endValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns how many times the animation should loop before it finishes, -1 loops forever.
+        #[rust_name = "loop_count"]
+        fn loopCount(self: &QVariantAnimation) -> i32;
+
+        /// Pauses the animation. When the animation is paused, state() returns Paused.
+        fn pause(self: Pin<&mut QVariantAnimation>);
+
+        /// Resumes the animation after it was paused.
+        fn resume(self: Pin<&mut QVariantAnimation>);
+
+        /// Sets the direction of the animation.
+        #[rust_name = "set_direction"]
+        fn setDirection(self: Pin<&mut QVariantAnimation>, direction: QAbstractAnimationDirection);
+
+        /// Sets the duration of the animation in milliseconds.
+        #[rust_name = "set_duration"]
+        fn setDuration(self: Pin<&mut QVariantAnimation>, msecs: i32);
+
+        /// Sets the end value of the animation.
+        #[rust_name = "set_end_value"]
+        fn setEndValue(self: Pin<&mut QVariantAnimation>, value: &QVariant);
+
+        /// Sets how many times the animation should loop before it finishes, -1 loops forever.
+        #[rust_name = "set_loop_count"]
+        fn setLoopCount(self: Pin<&mut QVariantAnimation>, loop_count: i32);
+
+        /// Sets the start value of the animation.
+        #[rust_name = "set_start_value"]
+        fn setStartValue(self: Pin<&mut QVariantAnimation>, value: &QVariant);
+
+        /// Returns the start value of the animation.
+        #[rust_name = "start_value"]
+        fn startValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns the state of the animation.
+        fn state(self: &QVariantAnimation) -> QAbstractAnimationState;
+
+        /// Stops the animation. When the animation is stopped, it emits the state_changed signal, and state() returns Stopped.
+        fn stop(self: Pin<&mut QVariantAnimation>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QAbstractAnimationDirection;
+        type QAbstractAnimationState;
+        type QEasingCurveType;
+
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_new"]
+        fn qvariantanimationNew() -> UniquePtr<QVariantAnimation>;
+
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_easing_curve_type"]
+        fn qvariantanimationEasingCurveType(animation: &QVariantAnimation) -> QEasingCurveType;
+
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_set_easing_curve_type"]
+        fn qvariantanimationSetEasingCurveType(
+            animation: Pin<&mut QVariantAnimation>,
+            easing_type: QEasingCurveType,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_start"]
+        fn qvariantanimationStart(animation: Pin<&mut QVariantAnimation>);
+    }
+
+    // QVariantAnimation is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QVariantAnimation>::isRelocatable);
+    impl UniquePtr<QVariantAnimation> {}
+}
+
+pub use ffi::{
+    QAbstractAnimationDirection, QAbstractAnimationState, QEasingCurveType, QVariantAnimation,
+};
+
+impl QVariantAnimation {
+    /// Create a new QVariantAnimation
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qvariantanimation_new()
+    }
+
+    /// Returns the type of the easing curve of the animation.
+    pub fn easing_curve_type(&self) -> QEasingCurveType {
+        ffi::qvariantanimation_easing_curve_type(self)
+    }
+
+    /// Sets the easing curve of the animation to a curve of the given type. The default is Linear.
+    pub fn set_easing_curve_type(self: Pin<&mut Self>, easing_type: QEasingCurveType) {
+        ffi::qvariantanimation_set_easing_curve_type(self, easing_type)
+    }
+
+    /// Starts the animation.
+    ///
+    /// The animation is owned by Rust, so it is not deleted when it stops and can be started again.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qvariantanimation_start(self)
+    }
+}
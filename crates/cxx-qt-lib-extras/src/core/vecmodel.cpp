@@ -0,0 +1,98 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/vecmodel.h"
+
+#include "cxx-qt-lib-extras-internals/src/core/vecmodel.cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+VecListModel::VecListModel(::rust::Box<VecModelRust> items)
+  : m_items(::std::move(items))
+{
+  const auto names = m_items->roleNames();
+  for (int i = 0; i < names.size(); ++i) {
+    m_roleNames.insert(Qt::UserRole + i, names.at(i));
+  }
+}
+
+int
+VecListModel::rowCount(const QModelIndex& parent) const
+{
+  // A list model only has children of the root index
+  if (parent.isValid()) {
+    return 0;
+  }
+  return m_items->rowCount();
+}
+
+QVariant
+VecListModel::data(const QModelIndex& index, int role) const
+{
+  if (!checkIndex(index, CheckIndexOption::IndexIsValid) ||
+      !m_roleNames.contains(role)) {
+    return QVariant();
+  }
+  return m_items->data(index.row(), role - Qt::UserRole);
+}
+
+QHash<int, QByteArray>
+VecListModel::roleNames() const
+{
+  return m_roleNames;
+}
+
+void
+VecListModel::beginInsert(::std::int32_t first, ::std::int32_t last)
+{
+  beginInsertRows(QModelIndex(), first, last);
+}
+
+void
+VecListModel::endInsert()
+{
+  endInsertRows();
+}
+
+void
+VecListModel::beginRemove(::std::int32_t first, ::std::int32_t last)
+{
+  beginRemoveRows(QModelIndex(), first, last);
+}
+
+void
+VecListModel::endRemove()
+{
+  endRemoveRows();
+}
+
+void
+VecListModel::beginReset()
+{
+  beginResetModel();
+}
+
+void
+VecListModel::endReset()
+{
+  endResetModel();
+}
+
+void
+VecListModel::rowsChanged(::std::int32_t first, ::std::int32_t last)
+{
+  Q_EMIT dataChanged(index(first), index(last));
+}
+
+::std::unique_ptr<VecListModel>
+veclistmodelNew(::rust::Box<VecModelRust> items)
+{
+  return ::std::make_unique<VecListModel>(::std::move(items));
+}
+
+}
+}
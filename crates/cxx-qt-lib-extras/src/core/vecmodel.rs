@@ -0,0 +1,267 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QByteArray, QList, QVariant};
+use std::{
+    cell::{Ref, RefCell},
+    rc::Rc,
+};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = cxx_qt_lib::QList<cxx_qt_lib::QByteArray>;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/vecmodel.h");
+        /// The QAbstractListModel of a [VecModel], which can be given to QML or views.
+        type VecListModel;
+
+        #[doc(hidden)]
+        #[rust_name = "begin_insert"]
+        fn beginInsert(self: Pin<&mut VecListModel>, first: i32, last: i32);
+        #[doc(hidden)]
+        #[rust_name = "end_insert"]
+        fn endInsert(self: Pin<&mut VecListModel>);
+        #[doc(hidden)]
+        #[rust_name = "begin_remove"]
+        fn beginRemove(self: Pin<&mut VecListModel>, first: i32, last: i32);
+        #[doc(hidden)]
+        #[rust_name = "end_remove"]
+        fn endRemove(self: Pin<&mut VecListModel>);
+        #[doc(hidden)]
+        #[rust_name = "begin_reset"]
+        fn beginReset(self: Pin<&mut VecListModel>);
+        #[doc(hidden)]
+        #[rust_name = "end_reset"]
+        fn endReset(self: Pin<&mut VecListModel>);
+        #[doc(hidden)]
+        #[rust_name = "rows_changed"]
+        fn rowsChanged(self: Pin<&mut VecListModel>, first: i32, last: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "veclistmodel_new"]
+        fn veclistmodelNew(items: Box<VecModelRust>) -> UniquePtr<VecListModel>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type VecModelRust;
+
+        #[cxx_name = "rowCount"]
+        fn row_count(self: &VecModelRust) -> i32;
+        fn data(self: &VecModelRust, row: i32, role: i32) -> QVariant;
+        #[cxx_name = "roleNames"]
+        fn role_names(self: &VecModelRust) -> QList_QByteArray;
+    }
+
+    // VecListModel is a QObject, so it can't be moved and needs to be used through pointers
+    impl UniquePtr<VecListModel> {}
+}
+
+pub use ffi::VecListModel;
+
+/// An item of a [VecModel], which provides the data for the roles of its row.
+pub trait VecModelItem {
+    /// The names of the roles, which delegates in QML can use as properties.
+    fn role_names() -> Vec<&'static str>;
+
+    /// Returns the data of the item for the role at the given index of [VecModelItem::role_names].
+    fn data(&self, role: usize) -> QVariant;
+}
+
+/// The items of a [VecModel] with their type erased, so that C++ can read them.
+trait VecModelItems {
+    fn len(&self) -> usize;
+    fn data(&self, row: usize, role: usize) -> QVariant;
+}
+
+impl<T> VecModelItems for RefCell<Vec<T>>
+where
+    T: VecModelItem,
+{
+    fn len(&self) -> usize {
+        self.borrow().len()
+    }
+
+    fn data(&self, row: usize, role: usize) -> QVariant {
+        self.borrow()
+            .get(row)
+            .map(|item| item.data(role))
+            .unwrap_or_default()
+    }
+}
+
+#[doc(hidden)]
+pub struct VecModelRust {
+    items: Rc<dyn VecModelItems>,
+    role_names: Vec<&'static str>,
+}
+
+impl VecModelRust {
+    fn row_count(&self) -> i32 {
+        to_row(self.items.len())
+    }
+
+    fn data(&self, row: i32, role: i32) -> QVariant {
+        match (usize::try_from(row), usize::try_from(role)) {
+            (Ok(row), Ok(role)) => self.items.data(row, role),
+            _ => QVariant::default(),
+        }
+    }
+
+    fn role_names(&self) -> QList<QByteArray> {
+        let mut names = QList::default();
+        for name in &self.role_names {
+            names.append(QByteArray::from(*name));
+        }
+        names
+    }
+}
+
+fn to_row(index: usize) -> i32 {
+    i32::try_from(index).expect("The row of a VecModel must fit into an i32")
+}
+
+/// A list model which exposes the items of a [Vec] to QML or views, without subclassing QAbstractListModel.
+///
+/// The roles of the model are the [VecModelItem::role_names] of the items.
+/// Changing the items through the methods of the [VecModel] emits the signals of the model,
+/// so that views update the changed rows.
+///
+/// The [VecListModel] can be given to QML, for example from a property with the type `*mut VecListModel`.
+pub struct VecModel<T> {
+    items: Rc<RefCell<Vec<T>>>,
+    model: UniquePtr<VecListModel>,
+}
+
+impl<T> Default for VecModel<T>
+where
+    T: VecModelItem + 'static,
+{
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> VecModel<T>
+where
+    T: VecModelItem + 'static,
+{
+    /// Create a new model with the given items
+    pub fn new(items: Vec<T>) -> Self {
+        let items = Rc::new(RefCell::new(items));
+        let model = ffi::veclistmodel_new(Box::new(VecModelRust {
+            items: items.clone(),
+            role_names: T::role_names(),
+        }));
+        Self { items, model }
+    }
+
+    /// Returns a pointer to the list model, which can be given to QML or views.
+    ///
+    /// The model is owned by the [VecModel] and destroyed when the [VecModel] is dropped,
+    /// after which the pointer dangles. The [VecModel] therefore needs to outlive every
+    /// user of the pointer, for example by storing it in the Rust struct of the QObject
+    /// which exposes the model as a property, and views need to be given a different model
+    /// before it is dropped. The model is not reparented, so QML does not take ownership of it.
+    ///
+    /// The model must only be used from the thread which created the [VecModel],
+    /// as the items are shared with it without synchronization.
+    pub fn model(&self) -> *mut VecListModel {
+        self.model.as_mut_ptr()
+    }
+
+    /// Returns the items of the model
+    pub fn items(&self) -> Ref<'_, Vec<T>> {
+        self.items.borrow()
+    }
+
+    /// Returns the number of items in the model
+    pub fn len(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    /// Returns true if the model has no items
+    pub fn is_empty(&self) -> bool {
+        self.items.borrow().is_empty()
+    }
+
+    /// Appends an item to the end of the model
+    pub fn push(&mut self, item: T) {
+        let len = self.len();
+        self.insert(len, item);
+    }
+
+    /// Inserts an item at the given index, shifting all items after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len(), "insertion index is out of bounds");
+
+        let row = to_row(index);
+        self.model.pin_mut().begin_insert(row, row);
+        self.items.borrow_mut().insert(index, item);
+        self.model.pin_mut().end_insert();
+    }
+
+    /// Removes and returns the item at the given index, shifting all items after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "removal index is out of bounds");
+
+        let row = to_row(index);
+        self.model.pin_mut().begin_remove(row, row);
+        let item = self.items.borrow_mut().remove(index);
+        self.model.pin_mut().end_remove();
+        item
+    }
+
+    /// Replaces the item at the given index, returning the previous item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, item: T) -> T {
+        let previous = std::mem::replace(&mut self.items.borrow_mut()[index], item);
+        let row = to_row(index);
+        self.model.pin_mut().rows_changed(row, row);
+        previous
+    }
+
+    /// Updates the item at the given index with the closure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn update(&mut self, index: usize, f: impl FnOnce(&mut T)) {
+        f(&mut self.items.borrow_mut()[index]);
+        let row = to_row(index);
+        self.model.pin_mut().rows_changed(row, row);
+    }
+
+    /// Replaces all items of the model
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.model.pin_mut().begin_reset();
+        *self.items.borrow_mut() = items;
+        self.model.pin_mut().end_reset();
+    }
+
+    /// Removes all items from the model
+    pub fn clear(&mut self) {
+        self.set_items(Vec::new());
+    }
+}
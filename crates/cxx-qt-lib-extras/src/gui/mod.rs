@@ -3,5 +3,23 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qaccessible;
+pub use qaccessible::{AccessibleItem, QAccessible, QAccessibleRole};
+
 mod qapplication;
 pub use qapplication::QApplication;
+
+mod qinputmethod;
+pub use qinputmethod::QInputMethod;
+
+mod qscreen;
+pub use qscreen::QScreen;
+
+mod qstandarditem;
+pub use qstandarditem::QStandardItem;
+
+mod qstandarditemmodel;
+pub use qstandarditemmodel::QStandardItemModel;
+
+mod qundostack;
+pub use qundostack::{QUndoStack, UndoCommand};
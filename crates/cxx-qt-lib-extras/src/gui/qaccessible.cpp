@@ -0,0 +1,186 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qaccessible.h"
+
+#include <QtGui/QAccessibleObject>
+#include <QtQuick/QQuickItem>
+#include <QtQuick/QQuickWindow>
+
+#include "cxx-qt-lib-extras-internals/src/gui/qaccessible.cxx.h"
+
+namespace {
+
+// An accessible interface for a leaf object, whose role, texts and actions
+// are provided by Rust. QQuickItems also report their geometry and state.
+class RustAccessibleInterface
+  : public QAccessibleObject
+  , public QAccessibleActionInterface
+{
+public:
+  RustAccessibleInterface(
+    QObject* object,
+    ::rust::Box<::rust::cxxqtlib1::QAccessibleItemRust> item)
+    : QAccessibleObject(object)
+    , m_item(::std::move(item))
+  {
+  }
+
+  void* interface_cast(QAccessible::InterfaceType type) override
+  {
+    if (type == QAccessible::ActionInterface) {
+      return static_cast<QAccessibleActionInterface*>(this);
+    }
+    return QAccessibleObject::interface_cast(type);
+  }
+
+  QAccessibleInterface* parent() const override
+  {
+    if (auto* item = quickItem()) {
+      auto* parentItem = item->parentItem();
+      // Items in the root of a window belong to the accessible window
+      if (item->window() &&
+          (!parentItem || parentItem == item->window()->contentItem())) {
+        return QAccessible::queryAccessibleInterface(item->window());
+      }
+      return QAccessible::queryAccessibleInterface(parentItem);
+    }
+    return QAccessible::queryAccessibleInterface(object()->parent());
+  }
+
+  QAccessibleInterface* child(int) const override { return nullptr; }
+  int childCount() const override { return 0; }
+  int indexOfChild(const QAccessibleInterface*) const override { return -1; }
+
+  QWindow* window() const override
+  {
+    if (auto* item = quickItem()) {
+      return item->window();
+    }
+    return nullptr;
+  }
+
+  QRect rect() const override
+  {
+    auto* item = quickItem();
+    if (!item || !item->window()) {
+      return QAccessibleObject::rect();
+    }
+
+    const QRectF sceneRect =
+      item->mapRectToScene(QRectF(0, 0, item->width(), item->height()));
+    const QPoint topLeft =
+      item->window()->mapToGlobal(sceneRect.topLeft().toPoint());
+    return QRect(topLeft, sceneRect.size().toSize());
+  }
+
+  QAccessible::Role role() const override { return m_item->role(); }
+
+  QAccessible::State state() const override
+  {
+    QAccessible::State state;
+    if (auto* item = quickItem()) {
+      state.invisible = !item->isVisible();
+      state.focusable = item->activeFocusOnTab();
+      state.focused = item->hasActiveFocus();
+    }
+    return state;
+  }
+
+  QString text(QAccessible::Text type) const override
+  {
+    switch (type) {
+      case QAccessible::Name:
+        return m_item->name();
+      case QAccessible::Description:
+        return m_item->description();
+      case QAccessible::Value:
+        return m_item->value();
+      default:
+        return QString();
+    }
+  }
+
+  QStringList actionNames() const override { return m_item->actionNames(); }
+
+  void doAction(const QString& actionName) override
+  {
+    m_item->doAction(actionName);
+  }
+
+  QStringList keyBindingsForAction(const QString&) const override
+  {
+    return QStringList();
+  }
+
+private:
+  QQuickItem* quickItem() const { return qobject_cast<QQuickItem*>(object()); }
+
+  ::rust::Box<::rust::cxxqtlib1::QAccessibleItemRust> m_item;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qaccessibleInstall(const QObject* object,
+                   ::rust::Box<QAccessibleItemRust> item)
+{
+  // The interface is cached for the object and deleted once it is destroyed
+  QAccessible::registerAccessibleInterface(new RustAccessibleInterface(
+    const_cast<QObject*>(object), ::std::move(item)));
+}
+
+void
+qaccessibleNotifyNameChanged(const QObject* object)
+{
+  QAccessibleEvent event(const_cast<QObject*>(object),
+                         QAccessible::NameChanged);
+  QAccessible::updateAccessibility(&event);
+}
+
+void
+qaccessibleNotifyValueChanged(const QObject* object)
+{
+  auto* mutableObject = const_cast<QObject*>(object);
+  auto* accessible = QAccessible::queryAccessibleInterface(mutableObject);
+  if (!accessible) {
+    return;
+  }
+
+  QAccessibleValueChangeEvent event(mutableObject,
+                                    accessible->text(QAccessible::Value));
+  QAccessible::updateAccessibility(&event);
+}
+
+QString
+qaccessibleDecreaseAction()
+{
+  return QAccessibleActionInterface::decreaseAction();
+}
+
+QString
+qaccessibleIncreaseAction()
+{
+  return QAccessibleActionInterface::increaseAction();
+}
+
+QString
+qaccessiblePressAction()
+{
+  return QAccessibleActionInterface::pressAction();
+}
+
+QString
+qaccessibleToggleAction()
+{
+  return QAccessibleActionInterface::toggleAction();
+}
+
+}
+}
@@ -0,0 +1,359 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QStringList};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the role of an accessible object, which assistive technologies
+    /// use to decide how to present the object to the user.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAccessibleRole {
+        /// The object has no role, which usually indicates an invalid object.
+        NoRole = 0x00000000,
+        /// The title bar caption of a window.
+        TitleBar = 0x00000001,
+        /// A menu bar from which menus are opened by the user.
+        MenuBar = 0x00000002,
+        /// A scroll bar, which allows the user to scroll the visible area.
+        ScrollBar = 0x00000003,
+        /// A grip that the user can drag to change the size of widgets.
+        Grip = 0x00000004,
+        /// An object that represents a sound.
+        Sound = 0x00000005,
+        /// An object that represents the mouse cursor.
+        Cursor = 0x00000006,
+        /// An object that represents the system caret (text cursor).
+        Caret = 0x00000007,
+        /// An object that is used to alert the user.
+        AlertMessage = 0x00000008,
+        /// A top level window.
+        Window = 0x00000009,
+        /// The client area in a window.
+        Client = 0x0000000A,
+        /// A menu which lists options that the user can select to perform an action.
+        PopupMenu = 0x0000000B,
+        /// An item in a menu or menu bar.
+        MenuItem = 0x0000000C,
+        /// A tool tip which provides information about other objects.
+        ToolTip = 0x0000000D,
+        /// The application's main window.
+        Application = 0x0000000E,
+        /// A document, for example in an office application.
+        Document = 0x0000000F,
+        /// A generic container.
+        Pane = 0x00000010,
+        /// An object that displays a graphical representation of data.
+        Chart = 0x00000011,
+        /// A dialog box.
+        Dialog = 0x00000012,
+        /// A visible border.
+        Border = 0x00000013,
+        /// An object that represents a logical grouping of other objects.
+        Grouping = 0x00000014,
+        /// A separator that divides space into two regions.
+        Separator = 0x00000015,
+        /// A tool bar, which groups widgets that the user accesses frequently.
+        ToolBar = 0x00000016,
+        /// A status bar.
+        StatusBar = 0x00000017,
+        /// A table representing data in a grid of rows and columns.
+        Table = 0x00000018,
+        /// A header for a column of data.
+        ColumnHeader = 0x00000019,
+        /// A header for a row of data.
+        RowHeader = 0x0000001A,
+        /// A column of cells, usually within a table.
+        Column = 0x0000001B,
+        /// A row of cells, usually within a table.
+        Row = 0x0000001C,
+        /// A cell in a table.
+        Cell = 0x0000001D,
+        /// A link to something else.
+        Link = 0x0000001E,
+        /// An object that displays help in a separate, short lived window.
+        HelpBalloon = 0x0000001F,
+        /// An object that provides interactive help.
+        Assistant = 0x00000020,
+        /// A list of items, from which the user can select one or more items.
+        List = 0x00000021,
+        /// An item in a list of items.
+        ListItem = 0x00000022,
+        /// A list of items in a tree structure.
+        Tree = 0x00000023,
+        /// An item in a tree structure.
+        TreeItem = 0x00000024,
+        /// A page tab that the user can select to switch to a different page in a dialog.
+        PageTab = 0x00000025,
+        /// A property page where the user can change options and settings.
+        PropertyPage = 0x00000026,
+        /// An indicator that represents a current value or item.
+        Indicator = 0x00000027,
+        /// A graphic or picture, e.g. an icon.
+        Graphic = 0x00000028,
+        /// Static text, such as labels for other widgets.
+        StaticText = 0x00000029,
+        /// Editable text such as a line or text edit.
+        EditableText = 0x0000002A,
+        /// A button.
+        PushButton = 0x0000002B,
+        /// An object that represents an option that can be turned on or off.
+        CheckBox = 0x0000002C,
+        /// An object that represents an option that is mutually exclusive with other options.
+        RadioButton = 0x0000002D,
+        /// A list of choices that the user can select from.
+        ComboBox = 0x0000002E,
+        /// The progress of a lengthy operation.
+        ProgressBar = 0x00000030,
+        /// An object that represents a dial or knob.
+        Dial = 0x00000031,
+        /// A hotkey field that allows the user to enter a key sequence.
+        HotkeyField = 0x00000032,
+        /// A slider that allows the user to select a value within a given range.
+        Slider = 0x00000033,
+        /// A spin box that allows the user to enter a value within a given range.
+        SpinBox = 0x00000034,
+        /// An object that displays graphics that the user can interact with.
+        Canvas = 0x00000035,
+        /// An object that displays an animation.
+        Animation = 0x00000036,
+        /// An object that represents a mathematical equation.
+        Equation = 0x00000037,
+        /// A button that drops down a list of items.
+        ButtonDropDown = 0x00000038,
+        /// A button that drops down a menu.
+        ButtonMenu = 0x00000039,
+        /// A button that drops down a grid.
+        ButtonDropGrid = 0x0000003A,
+        /// Blank space between other objects.
+        Whitespace = 0x0000003B,
+        /// A list of page tabs.
+        PageTabList = 0x0000003C,
+        /// A clock displaying time.
+        Clock = 0x0000003D,
+        /// A splitter distributing available space between its child widgets.
+        Splitter = 0x0000003E,
+        /// An object that can contain layered children, e.g. in a stack.
+        LayeredPane = 0x00000080,
+        /// A terminal or command line interface.
+        Terminal = 0x00000081,
+        /// The object represents the desktop or workspace.
+        Desktop = 0x00000082,
+        /// A paragraph of text.
+        Paragraph = 0x00000083,
+        /// HTML document, usually in a browser.
+        WebDocument = 0x00000084,
+        /// A section, for example in a document.
+        Section = 0x00000085,
+        /// A dialog that lets the user choose a color.
+        ColorChooser = 0x00000404,
+        /// A footer in a page.
+        Footer = 0x0000040E,
+        /// A web form containing controls.
+        Form = 0x00000410,
+        /// A heading in a document.
+        Heading = 0x00000414,
+        /// A section whose content is parenthetic or ancillary to the main content of the resource.
+        Note = 0x0000041B,
+        /// A section that supports the main content.
+        ComplementaryContent = 0x0000042C,
+        /// The first value to be used for user defined roles.
+        UserRole = 0x0000FFFF,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-lib-extras/qaccessible.h");
+        /// Static functions to make objects implemented in Rust accessible to assistive technologies,
+        /// such as screen readers.
+        type QAccessible;
+
+        #[doc(hidden)]
+        type QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QAccessibleItemRust;
+
+        fn role(self: &QAccessibleItemRust) -> QAccessibleRole;
+        fn name(self: &QAccessibleItemRust) -> QString;
+        fn description(self: &QAccessibleItemRust) -> QString;
+        fn value(self: &QAccessibleItemRust) -> QString;
+        #[cxx_name = "actionNames"]
+        fn action_names(self: &QAccessibleItemRust) -> QStringList;
+        #[cxx_name = "doAction"]
+        fn do_action(self: &mut QAccessibleItemRust, name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QAccessibleRole;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_install"]
+        unsafe fn qaccessibleInstall(object: *const QObject, item: Box<QAccessibleItemRust>);
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_notify_name_changed"]
+        unsafe fn qaccessibleNotifyNameChanged(object: *const QObject);
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_notify_value_changed"]
+        unsafe fn qaccessibleNotifyValueChanged(object: *const QObject);
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_decrease_action"]
+        fn qaccessibleDecreaseAction() -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_increase_action"]
+        fn qaccessibleIncreaseAction() -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_press_action"]
+        fn qaccessiblePressAction() -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_toggle_action"]
+        fn qaccessibleToggleAction() -> QString;
+    }
+}
+
+pub use ffi::{QAccessible, QAccessibleRole};
+
+/// The accessibility information of an object implemented in Rust, see [QAccessible::install].
+pub trait AccessibleItem {
+    /// The role of the object, which tells assistive technologies what kind of object it is.
+    fn role(&self) -> QAccessibleRole;
+
+    /// The name of the object, which is read out by screen readers.
+    fn name(&self) -> QString;
+
+    /// A short text describing the object.
+    fn description(&self) -> QString {
+        QString::default()
+    }
+
+    /// The value of the object, for example the position of a slider.
+    fn value(&self) -> QString {
+        QString::default()
+    }
+
+    /// The names of the actions that can be performed on the object,
+    /// such as [QAccessible::press_action].
+    fn action_names(&self) -> QStringList {
+        QStringList::default()
+    }
+
+    /// Performs the action with the given name, which is one of the [AccessibleItem::action_names].
+    fn do_action(&mut self, _name: &QString) {}
+}
+
+#[doc(hidden)]
+pub struct QAccessibleItemRust {
+    item: Box<dyn AccessibleItem>,
+}
+
+impl QAccessibleItemRust {
+    fn role(&self) -> QAccessibleRole {
+        self.item.role()
+    }
+
+    fn name(&self) -> QString {
+        self.item.name()
+    }
+
+    fn description(&self) -> QString {
+        self.item.description()
+    }
+
+    fn value(&self) -> QString {
+        self.item.value()
+    }
+
+    fn action_names(&self) -> QStringList {
+        self.item.action_names()
+    }
+
+    fn do_action(&mut self, name: &QString) {
+        self.item.do_action(name);
+    }
+}
+
+fn as_qobject<T>(object: &T) -> *const ffi::QObject
+where
+    T: cxx_qt::CxxQtType,
+{
+    // The C++ class of a CXX-Qt QObject has its QObject base class first,
+    // so a pointer to it is also a valid pointer to its QObject.
+    object as *const T as *const ffi::QObject
+}
+
+impl QAccessible {
+    /// Makes a QObject defined in Rust accessible with the information of the given [AccessibleItem],
+    /// for example a QObject with `#[base = QQuickItem]`, which then also reports its geometry and focus.
+    ///
+    /// The object is exposed as an accessible leaf without children. This should be called once,
+    /// before the object is shown, as Qt caches the accessible interface of each object.
+    pub fn install<T>(object: &T, item: impl AccessibleItem + 'static)
+    where
+        T: cxx_qt::CxxQtType,
+    {
+        // SAFETY: the pointer is a valid QObject, see as_qobject
+        unsafe {
+            ffi::qaccessible_install(
+                as_qobject(object),
+                Box::new(QAccessibleItemRust {
+                    item: Box::new(item),
+                }),
+            );
+        }
+    }
+
+    /// Notifies assistive technologies that the [AccessibleItem::name] of the object has changed.
+    pub fn notify_name_changed<T>(object: &T)
+    where
+        T: cxx_qt::CxxQtType,
+    {
+        // SAFETY: the pointer is a valid QObject, see as_qobject
+        unsafe { ffi::qaccessible_notify_name_changed(as_qobject(object)) }
+    }
+
+    /// Notifies assistive technologies that the [AccessibleItem::value] of the object has changed.
+    pub fn notify_value_changed<T>(object: &T)
+    where
+        T: cxx_qt::CxxQtType,
+    {
+        // SAFETY: the pointer is a valid QObject, see as_qobject
+        unsafe { ffi::qaccessible_notify_value_changed(as_qobject(object)) }
+    }
+
+    /// The name of the action which decreases the value of the object, for example of a slider.
+    pub fn decrease_action() -> QString {
+        ffi::qaccessible_decrease_action()
+    }
+
+    /// The name of the action which increases the value of the object, for example of a slider.
+    pub fn increase_action() -> QString {
+        ffi::qaccessible_increase_action()
+    }
+
+    /// The name of the default action of the object, for example pressing a button.
+    pub fn press_action() -> QString {
+        ffi::qaccessible_press_action()
+    }
+
+    /// The name of the action which toggles the object, for example a check box.
+    pub fn toggle_action() -> QString {
+        ffi::qaccessible_toggle_action()
+    }
+}
@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qinputmethod.h"
+
+#include <QtGui/QGuiApplication>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QInputMethod*
+qinputmethodInstance()
+{
+  return QGuiApplication::inputMethod();
+}
+
+}
+}
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qinputmethod.h");
+        /// Access to the active text input method, such as a virtual keyboard,
+        /// which is the same object as `Qt.inputMethod` in QML.
+        ///
+        /// The input method is owned by the QGuiApplication, use [QInputMethod::instance] to retrieve it.
+        #[qobject]
+        type QInputMethod;
+
+        /// This signal is emitted when the animating state of the input panel changes.
+        #[qsignal]
+        #[cxx_name = "animatingChanged"]
+        fn animating_changed(self: Pin<&mut QInputMethod>);
+
+        /// This signal is emitted when the cursor rectangle changes.
+        #[qsignal]
+        #[cxx_name = "cursorRectangleChanged"]
+        fn cursor_rectangle_changed(self: Pin<&mut QInputMethod>);
+
+        /// This signal is emitted when the keyboard rectangle of the input panel changes.
+        #[qsignal]
+        #[cxx_name = "keyboardRectangleChanged"]
+        fn keyboard_rectangle_changed(self: Pin<&mut QInputMethod>);
+
+        /// This signal is emitted when the visibility of the input panel changes.
+        #[qsignal]
+        #[cxx_name = "visibleChanged"]
+        fn visible_changed(self: Pin<&mut QInputMethod>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = cxx_qt_lib::QRectF;
+
+        /// Commits the word the user is currently composing to the editor.
+        ///
+        /// The input method commits the text to the focused item with an input method event.
+        fn commit(self: Pin<&mut QInputMethod>);
+
+        /// Returns the input item's cursor rectangle in window coordinates.
+        #[rust_name = "cursor_rectangle"]
+        fn cursorRectangle(self: &QInputMethod) -> QRectF;
+
+        /// Requests the virtual keyboard to close.
+        fn hide(self: Pin<&mut QInputMethod>);
+
+        /// Returns true if the input panel is currently animating.
+        #[rust_name = "is_animating"]
+        fn isAnimating(self: &QInputMethod) -> bool;
+
+        /// Returns true if the virtual keyboard is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QInputMethod) -> bool;
+
+        /// Returns the virtual keyboard's geometry in window coordinates,
+        /// which is empty if the keyboard is not visible.
+        #[rust_name = "keyboard_rectangle"]
+        fn keyboardRectangle(self: &QInputMethod) -> QRectF;
+
+        /// Resets the input method state, the text the user is currently composing is discarded.
+        fn reset(self: Pin<&mut QInputMethod>);
+
+        /// Controls the keyboard visibility, equivalent to calling show() if visible is true, or hide() otherwise.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QInputMethod>, visible: bool);
+
+        /// Requests the virtual keyboard to open.
+        fn show(self: Pin<&mut QInputMethod>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qinputmethod_instance"]
+        fn qinputmethodInstance() -> *mut QInputMethod;
+    }
+}
+
+pub use ffi::QInputMethod;
+
+impl QInputMethod {
+    /// Returns the input method of the application, or a null pointer
+    /// if the QGuiApplication has not been created yet.
+    pub fn instance() -> *mut QInputMethod {
+        ffi::qinputmethod_instance()
+    }
+}
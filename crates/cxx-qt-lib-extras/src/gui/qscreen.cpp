@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qscreen.h"
+
+#include <QtGui/QGuiApplication>
+
+#include "cxx-qt-lib-extras-internals/src/gui/qscreen.cxx.h"
+
+namespace {
+
+template<typename Signal>
+::QMetaObject::Connection
+connectToApplication(
+  Signal signal,
+  ::rust::Box<::rust::cxxqtlib1::QScreenCallbackRust> callback)
+{
+  return QObject::connect(
+    qGuiApp,
+    signal,
+    qGuiApp,
+    [callback = ::std::move(callback)](QScreen* screen) mutable {
+      callback->call(screen);
+    });
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::QMetaObject::Connection
+qscreenOnPrimaryScreenChanged(::rust::Box<QScreenCallbackRust> callback)
+{
+  return connectToApplication(&QGuiApplication::primaryScreenChanged,
+                              ::std::move(callback));
+}
+
+::QMetaObject::Connection
+qscreenOnScreenAdded(::rust::Box<QScreenCallbackRust> callback)
+{
+  return connectToApplication(&QGuiApplication::screenAdded,
+                              ::std::move(callback));
+}
+
+::QMetaObject::Connection
+qscreenOnScreenRemoved(::rust::Box<QScreenCallbackRust> callback)
+{
+  return connectToApplication(&QGuiApplication::screenRemoved,
+                              ::std::move(callback));
+}
+
+QScreen*
+qscreenPrimaryScreen()
+{
+  return QGuiApplication::primaryScreen();
+}
+
+::std::int32_t
+qscreenScreensCount()
+{
+  return static_cast<::std::int32_t>(QGuiApplication::screens().size());
+}
+
+QScreen*
+qscreenScreensAt(::std::int32_t index)
+{
+  return QGuiApplication::screens().at(index);
+}
+
+}
+}
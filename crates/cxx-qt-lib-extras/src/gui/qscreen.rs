@@ -0,0 +1,203 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt::QMetaObjectConnectionGuard;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qscreen.h");
+        /// A screen of the system, with the information that the Screen attached property reports in QML.
+        ///
+        /// The screens are owned by the QGuiApplication, so they can only be used once it has been created.
+        #[qobject]
+        type QScreen;
+
+        /// This signal is emitted when the available geometry of the screen changes.
+        #[qsignal]
+        #[cxx_name = "availableGeometryChanged"]
+        fn available_geometry_changed(self: Pin<&mut QScreen>, geometry: &QRect);
+
+        /// This signal is emitted when the geometry of the screen changes.
+        #[qsignal]
+        #[cxx_name = "geometryChanged"]
+        fn geometry_changed(self: Pin<&mut QScreen>, geometry: &QRect);
+
+        /// This signal is emitted when the logical dots per inch of the screen changes.
+        #[qsignal]
+        #[cxx_name = "logicalDotsPerInchChanged"]
+        fn logical_dots_per_inch_changed(self: Pin<&mut QScreen>, dpi: f64);
+
+        /// This signal is emitted when the refresh rate of the screen changes.
+        #[qsignal]
+        #[cxx_name = "refreshRateChanged"]
+        fn refresh_rate_changed(self: Pin<&mut QScreen>, refresh_rate: f64);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = cxx_qt_lib::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = cxx_qt_lib::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns the available geometry of the screen in pixels,
+        /// which excludes window manager reserved areas such as task bars and system menus.
+        #[rust_name = "available_geometry"]
+        fn availableGeometry(self: &QScreen) -> QRect;
+
+        /// Returns the color depth of the screen.
+        fn depth(self: &QScreen) -> i32;
+
+        /// Returns the ratio between physical pixels and device-independent pixels for the screen.
+        #[rust_name = "device_pixel_ratio"]
+        fn devicePixelRatio(self: &QScreen) -> f64;
+
+        /// Returns the geometry of the screen in pixels.
+        fn geometry(self: &QScreen) -> QRect;
+
+        /// Returns the number of logical dots or pixels per inch.
+        #[rust_name = "logical_dots_per_inch"]
+        fn logicalDotsPerInch(self: &QScreen) -> f64;
+
+        /// Returns the manufacturer of the screen.
+        fn manufacturer(self: &QScreen) -> QString;
+
+        /// Returns the model of the screen.
+        fn model(self: &QScreen) -> QString;
+
+        /// Returns a user presentable string representing the screen.
+        fn name(self: &QScreen) -> QString;
+
+        /// Returns the number of physical dots or pixels per inch.
+        #[rust_name = "physical_dots_per_inch"]
+        fn physicalDotsPerInch(self: &QScreen) -> f64;
+
+        /// Returns the approximate vertical refresh rate of the screen in Hz.
+        #[rust_name = "refresh_rate"]
+        fn refreshRate(self: &QScreen) -> f64;
+
+        /// Returns the serial number of the screen.
+        #[rust_name = "serial_number"]
+        fn serialNumber(self: &QScreen) -> QString;
+
+        /// Returns the pixel resolution of the screen.
+        fn size(self: &QScreen) -> QSize;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QScreenCallbackRust;
+
+        unsafe fn call(self: &mut QScreenCallbackRust, screen: *mut QScreen);
+    }
+
+    // These are all static, so we need to create bindings until CXX supports statics
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qscreen_on_primary_screen_changed"]
+        fn qscreenOnPrimaryScreenChanged(
+            callback: Box<QScreenCallbackRust>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qscreen_on_screen_added"]
+        fn qscreenOnScreenAdded(callback: Box<QScreenCallbackRust>) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qscreen_on_screen_removed"]
+        fn qscreenOnScreenRemoved(callback: Box<QScreenCallbackRust>) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qscreen_primary_screen"]
+        fn qscreenPrimaryScreen() -> *mut QScreen;
+
+        #[doc(hidden)]
+        #[rust_name = "qscreen_screens_count"]
+        fn qscreenScreensCount() -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qscreen_screens_at"]
+        fn qscreenScreensAt(index: i32) -> *mut QScreen;
+    }
+}
+
+pub use ffi::QScreen;
+
+#[doc(hidden)]
+pub struct QScreenCallbackRust {
+    callback: Box<dyn FnMut(*mut QScreen)>,
+}
+
+impl QScreenCallbackRust {
+    unsafe fn call(&mut self, screen: *mut QScreen) {
+        (self.callback)(screen);
+    }
+}
+
+fn screen_callback<F>(callback: F) -> Box<QScreenCallbackRust>
+where
+    F: FnMut(*mut QScreen) + 'static,
+{
+    Box::new(QScreenCallbackRust {
+        callback: Box::new(callback),
+    })
+}
+
+impl QScreen {
+    /// Connect to the primaryScreenChanged signal of the QGuiApplication,
+    /// the closure receives the new primary screen.
+    ///
+    /// The QGuiApplication must have been created before connecting.
+    pub fn on_primary_screen_changed<F>(closure: F) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(*mut QScreen) + 'static,
+    {
+        ffi::qscreen_on_primary_screen_changed(screen_callback(closure)).into()
+    }
+
+    /// Connect to the screenAdded signal of the QGuiApplication,
+    /// the closure receives the screen which has been added to the system.
+    ///
+    /// The QGuiApplication must have been created before connecting.
+    pub fn on_screen_added<F>(closure: F) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(*mut QScreen) + 'static,
+    {
+        ffi::qscreen_on_screen_added(screen_callback(closure)).into()
+    }
+
+    /// Connect to the screenRemoved signal of the QGuiApplication,
+    /// the closure receives the screen which is about to be removed from the system.
+    ///
+    /// The QGuiApplication must have been created before connecting.
+    pub fn on_screen_removed<F>(closure: F) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(*mut QScreen) + 'static,
+    {
+        ffi::qscreen_on_screen_removed(screen_callback(closure)).into()
+    }
+
+    /// Returns the primary screen of the application, or a null pointer if there is no screen.
+    pub fn primary_screen() -> *mut QScreen {
+        ffi::qscreen_primary_screen()
+    }
+
+    /// Returns the screens of the system, the first one is the primary screen.
+    pub fn screens() -> Vec<*mut QScreen> {
+        (0..ffi::qscreen_screens_count())
+            .map(ffi::qscreen_screens_at)
+            .collect()
+    }
+}
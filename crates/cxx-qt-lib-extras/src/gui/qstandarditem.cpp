@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qstandarditem.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStandardItem>
+qstandarditemNew()
+{
+  return ::std::make_unique<QStandardItem>();
+}
+
+::std::unique_ptr<QStandardItem>
+qstandarditemNewWithText(const QString& text)
+{
+  return ::std::make_unique<QStandardItem>(text);
+}
+
+void
+qstandarditemAppendRow(QStandardItem& item,
+                       ::std::unique_ptr<QStandardItem> child)
+{
+  // The parent item takes ownership of the child
+  item.appendRow(child.release());
+}
+
+}
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::QString;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qstandarditem.h");
+        type QStandardItem;
+
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Returns the number of child item columns that the item has.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QStandardItem) -> i32;
+
+        /// Returns the item's data for the given role, or an invalid QVariant if there is no data for the role.
+        fn data(self: &QStandardItem, role: i32) -> QVariant;
+
+        /// Returns true if the item has any children; otherwise returns false.
+        #[rust_name = "has_children"]
+        fn hasChildren(self: &QStandardItem) -> bool;
+
+        /// Returns the QModelIndex associated with this item.
+        fn index(self: &QStandardItem) -> QModelIndex;
+
+        /// Returns whether the item can be edited by the user.
+        #[rust_name = "is_editable"]
+        fn isEditable(self: &QStandardItem) -> bool;
+
+        /// Returns whether the item is enabled.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QStandardItem) -> bool;
+
+        /// Returns the number of child item rows that the item has.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QStandardItem) -> i32;
+
+        /// Sets the item's data for the given role to the specified value.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QStandardItem>, value: &QVariant, role: i32);
+
+        /// Sets whether the item is editable.
+        #[rust_name = "set_editable"]
+        fn setEditable(self: Pin<&mut QStandardItem>, editable: bool);
+
+        /// Sets whether the item is enabled.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QStandardItem>, enabled: bool);
+
+        /// Sets the item's text to the text specified.
+        #[rust_name = "set_text"]
+        fn setText(self: Pin<&mut QStandardItem>, text: &QString);
+
+        /// Returns the item's text. This is the text that's presented to the user in a view.
+        fn text(self: &QStandardItem) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstandarditem_new"]
+        fn qstandarditemNew() -> UniquePtr<QStandardItem>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstandarditem_new_with_text"]
+        fn qstandarditemNewWithText(text: &QString) -> UniquePtr<QStandardItem>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstandarditem_append_row"]
+        fn qstandarditemAppendRow(item: Pin<&mut QStandardItem>, child: UniquePtr<QStandardItem>);
+    }
+
+    // QStandardItem is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStandardItem>::isRelocatable);
+    impl UniquePtr<QStandardItem> {}
+}
+
+pub use ffi::QStandardItem;
+
+impl QStandardItem {
+    /// Create a new QStandardItem
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qstandarditem_new()
+    }
+
+    /// Create a new QStandardItem with the given text
+    pub fn new_with_text(text: &QString) -> UniquePtr<Self> {
+        ffi::qstandarditem_new_with_text(text)
+    }
+
+    /// Appends a row containing child, the item takes ownership of the child.
+    pub fn append_row(self: Pin<&mut Self>, child: UniquePtr<Self>) {
+        ffi::qstandarditem_append_row(self, child)
+    }
+}
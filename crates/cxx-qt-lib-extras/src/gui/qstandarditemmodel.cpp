@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qstandarditemmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStandardItemModel>
+qstandarditemmodelNew()
+{
+  return ::std::make_unique<QStandardItemModel>();
+}
+
+void
+qstandarditemmodelAppendRow(QStandardItemModel& model,
+                            ::std::unique_ptr<QStandardItem> item)
+{
+  // The model takes ownership of the item
+  model.appendRow(item.release());
+}
+
+}
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QStandardItem;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstandarditemmodel.h");
+        #[qobject]
+        type QStandardItemModel;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qstandarditem.h");
+        type QStandardItem = crate::QStandardItem;
+
+        include!("cxx-qt-lib/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Removes all items (including header items) from the model and sets the number of rows and columns to zero.
+        fn clear(self: Pin<&mut QStandardItemModel>);
+
+        /// Returns the number of columns under the given parent.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QStandardItemModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the data stored under the given role for the item referred to by the index.
+        fn data(self: &QStandardItemModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        /// Returns the index of the item in the model specified by the given row, column and parent index.
+        fn index(
+            self: &QStandardItemModel,
+            row: i32,
+            column: i32,
+            parent: &QModelIndex,
+        ) -> QModelIndex;
+
+        /// Returns a pointer to the item for the given row and column if one has been set; otherwise returns nullptr.
+        fn item(self: &QStandardItemModel, row: i32, column: i32) -> *mut QStandardItem;
+
+        /// Returns the number of rows under the given parent.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QStandardItemModel, parent: &QModelIndex) -> i32;
+
+        /// Sets the number of columns in this model to columns.
+        #[rust_name = "set_column_count"]
+        fn setColumnCount(self: Pin<&mut QStandardItemModel>, columns: i32);
+
+        /// Sets the role data for the item at index to value.
+        /// Returns true if successful; otherwise returns false.
+        #[rust_name = "set_data"]
+        fn setData(
+            self: Pin<&mut QStandardItemModel>,
+            index: &QModelIndex,
+            value: &QVariant,
+            role: i32,
+        ) -> bool;
+
+        /// Sets the horizontal header labels using labels.
+        #[rust_name = "set_horizontal_header_labels"]
+        fn setHorizontalHeaderLabels(self: Pin<&mut QStandardItemModel>, labels: &QStringList);
+
+        /// Sets the item role names to role_names, these are the names which are available in QML delegates.
+        #[rust_name = "set_item_role_names"]
+        fn setItemRoleNames(self: Pin<&mut QStandardItemModel>, role_names: &QHash_i32_QByteArray);
+
+        /// Sets the number of rows in this model to rows.
+        #[rust_name = "set_row_count"]
+        fn setRowCount(self: Pin<&mut QStandardItemModel>, rows: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstandarditemmodel_new"]
+        fn qstandarditemmodelNew() -> UniquePtr<QStandardItemModel>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstandarditemmodel_append_row"]
+        fn qstandarditemmodelAppendRow(
+            model: Pin<&mut QStandardItemModel>,
+            item: UniquePtr<QStandardItem>,
+        );
+    }
+
+    // QStandardItemModel is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStandardItemModel>::isRelocatable);
+    impl UniquePtr<QStandardItemModel> {}
+}
+
+pub use ffi::QStandardItemModel;
+
+impl QStandardItemModel {
+    /// Create a new QStandardItemModel
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qstandarditemmodel_new()
+    }
+
+    /// Appends a row containing item, the model takes ownership of the item.
+    pub fn append_row(self: Pin<&mut Self>, item: UniquePtr<QStandardItem>) {
+        ffi::qstandarditemmodel_append_row(self, item)
+    }
+}
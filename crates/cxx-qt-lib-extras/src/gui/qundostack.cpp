@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qundostack.h"
+
+#include "cxx-qt-lib-extras-internals/src/gui/qundostack.cxx.h"
+
+namespace {
+
+// A QUndoCommand which forwards redo and undo to a command implemented in Rust
+class RustQUndoCommand : public QUndoCommand
+{
+public:
+  RustQUndoCommand(const QString& text,
+                   ::rust::Box<::rust::cxxqtlib1::QUndoCommandRust> command)
+    : QUndoCommand(text)
+    , m_command(::std::move(command))
+  {
+  }
+
+  void redo() override { m_command->redo(); }
+  void undo() override { m_command->undo(); }
+
+private:
+  ::rust::Box<::rust::cxxqtlib1::QUndoCommandRust> m_command;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QUndoStack>
+qundostackNew()
+{
+  return ::std::make_unique<QUndoStack>();
+}
+
+void
+qundostackPush(QUndoStack& stack,
+               const QString& text,
+               ::rust::Box<QUndoCommandRust> command)
+{
+  // QUndoStack takes ownership of the command
+  stack.push(new RustQUndoCommand(text, ::std::move(command)));
+}
+
+}
+}
@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qundostack.h");
+        #[qobject]
+        type QUndoStack;
+
+        /// This signal is emitted whenever the value of can_undo() changes.
+        #[qsignal]
+        #[cxx_name = "canUndoChanged"]
+        fn can_undo_changed(self: Pin<&mut QUndoStack>, can_undo: bool);
+
+        /// This signal is emitted whenever the value of can_redo() changes.
+        #[qsignal]
+        #[cxx_name = "canRedoChanged"]
+        fn can_redo_changed(self: Pin<&mut QUndoStack>, can_redo: bool);
+
+        /// This signal is emitted whenever the stack enters or leaves the clean state.
+        #[qsignal]
+        #[cxx_name = "cleanChanged"]
+        fn clean_changed(self: Pin<&mut QUndoStack>, clean: bool);
+
+        /// This signal is emitted whenever a command modifies the state of the document.
+        #[qsignal]
+        #[cxx_name = "indexChanged"]
+        fn index_changed(self: Pin<&mut QUndoStack>, idx: i32);
+
+        /// This signal is emitted whenever the value of redo_text() changes.
+        #[qsignal]
+        #[cxx_name = "redoTextChanged"]
+        fn redo_text_changed(self: Pin<&mut QUndoStack>, redo_text: &QString);
+
+        /// This signal is emitted whenever the value of undo_text() changes.
+        #[qsignal]
+        #[cxx_name = "undoTextChanged"]
+        fn undo_text_changed(self: Pin<&mut QUndoStack>, undo_text: &QString);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Begins composition of a macro command with the given text description.
+        /// All subsequent pushed commands are grouped until end_macro() is called.
+        #[rust_name = "begin_macro"]
+        fn beginMacro(self: Pin<&mut QUndoStack>, text: &QString);
+
+        /// Returns true if there is a command available for redo; otherwise returns false.
+        #[rust_name = "can_redo"]
+        fn canRedo(self: &QUndoStack) -> bool;
+
+        /// Returns true if there is a command available for undo; otherwise returns false.
+        #[rust_name = "can_undo"]
+        fn canUndo(self: &QUndoStack) -> bool;
+
+        /// Clears the command stack by deleting all commands on it, and returns the stack to the clean state.
+        fn clear(self: Pin<&mut QUndoStack>);
+
+        /// Returns the number of commands on the stack. Macro commands are counted as one command.
+        fn count(self: &QUndoStack) -> i32;
+
+        /// Ends composition of a macro command.
+        #[rust_name = "end_macro"]
+        fn endMacro(self: Pin<&mut QUndoStack>);
+
+        /// Returns the index of the current command.
+        /// This is the command that will be executed on the next call to redo().
+        fn index(self: &QUndoStack) -> i32;
+
+        /// If the stack is in the clean state, returns true; otherwise returns false.
+        #[rust_name = "is_clean"]
+        fn isClean(self: &QUndoStack) -> bool;
+
+        /// Redoes the current command by calling redo() on it. Increments the current command index.
+        fn redo(self: Pin<&mut QUndoStack>);
+
+        /// Returns the text of the command which will be redone in the next call to redo().
+        #[rust_name = "redo_text"]
+        fn redoText(self: &QUndoStack) -> QString;
+
+        /// Marks the stack as clean and emits clean_changed() if the stack was not already clean.
+        #[rust_name = "set_clean"]
+        fn setClean(self: Pin<&mut QUndoStack>);
+
+        /// Sets the maximum number of commands on this stack.
+        /// The limit can only be set when the stack is empty.
+        #[rust_name = "set_undo_limit"]
+        fn setUndoLimit(self: Pin<&mut QUndoStack>, limit: i32);
+
+        /// Undoes the command below the current command by calling undo() on it. Decrements the current command index.
+        fn undo(self: Pin<&mut QUndoStack>);
+
+        /// Returns the maximum number of commands on this stack, 0 means there is no limit.
+        #[rust_name = "undo_limit"]
+        fn undoLimit(self: &QUndoStack) -> i32;
+
+        /// Returns the text of the command which will be undone in the next call to undo().
+        #[rust_name = "undo_text"]
+        fn undoText(self: &QUndoStack) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QUndoCommandRust;
+
+        fn redo(self: &mut QUndoCommandRust);
+        fn undo(self: &mut QUndoCommandRust);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qundostack_new"]
+        fn qundostackNew() -> UniquePtr<QUndoStack>;
+
+        #[doc(hidden)]
+        #[rust_name = "qundostack_push"]
+        fn qundostackPush(
+            stack: Pin<&mut QUndoStack>,
+            text: &QString,
+            command: Box<QUndoCommandRust>,
+        );
+    }
+
+    // QUndoStack is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QUndoStack>::isRelocatable);
+    impl UniquePtr<QUndoStack> {}
+}
+
+pub use ffi::QUndoStack;
+
+/// A command which can be pushed onto a [QUndoStack].
+///
+/// redo() is called once when the command is pushed onto the stack,
+/// it should then apply the change to the document and undo() should revert it.
+pub trait UndoCommand {
+    /// Applies a change to the document.
+    fn redo(&mut self);
+
+    /// Reverts a change to the document, the state of the document
+    /// should be the same as before redo() was called.
+    fn undo(&mut self);
+}
+
+struct UndoCommandClosures<R, U> {
+    redo: R,
+    undo: U,
+}
+
+impl<R, U> UndoCommand for UndoCommandClosures<R, U>
+where
+    R: FnMut(),
+    U: FnMut(),
+{
+    fn redo(&mut self) {
+        (self.redo)()
+    }
+
+    fn undo(&mut self) {
+        (self.undo)()
+    }
+}
+
+#[doc(hidden)]
+pub struct QUndoCommandRust {
+    command: Box<dyn UndoCommand>,
+}
+
+impl QUndoCommandRust {
+    fn redo(&mut self) {
+        self.command.redo();
+    }
+
+    fn undo(&mut self) {
+        self.command.undo();
+    }
+}
+
+impl QUndoStack {
+    /// Create a new QUndoStack
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qundostack_new()
+    }
+
+    /// Pushes command on the stack, with text as the description of the command.
+    ///
+    /// The command is redone immediately and then owned by the stack.
+    pub fn push(self: Pin<&mut Self>, text: &QString, command: impl UndoCommand + 'static) {
+        ffi::qundostack_push(
+            self,
+            text,
+            Box::new(QUndoCommandRust {
+                command: Box::new(command),
+            }),
+        );
+    }
+
+    /// Pushes a command on the stack which is implemented by the given redo and undo closures.
+    ///
+    /// This is a convenience for [QUndoStack::push] when a full [UndoCommand] implementation is not needed.
+    pub fn push_fn<R, U>(self: Pin<&mut Self>, text: &QString, redo: R, undo: U)
+    where
+        R: FnMut() + 'static,
+        U: FnMut() + 'static,
+    {
+        self.push(text, UndoCommandClosures { redo, undo });
+    }
+}
@@ -11,3 +11,13 @@ pub use crate::core::*;
 
 mod gui;
 pub use crate::gui::*;
+
+#[cfg(feature = "qt_network")]
+mod network;
+#[cfg(feature = "qt_network")]
+pub use crate::network::*;
+
+#[cfg(feature = "qt_quick")]
+mod quick;
+#[cfg(feature = "qt_quick")]
+pub use crate::quick::*;
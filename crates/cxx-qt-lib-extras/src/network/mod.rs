@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qnetworkaccessmanager;
+pub use qnetworkaccessmanager::QNetworkAccessManager;
+
+mod qnetworkreply;
+pub use qnetworkreply::{QNetworkReply, QNetworkReplyNetworkError};
+
+mod qnetworkrequest;
+pub use qnetworkrequest::QNetworkRequest;
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qnetworkaccessmanager.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QNetworkAccessManager>
+qnetworkaccessmanagerNew()
+{
+  return ::std::make_unique<QNetworkAccessManager>();
+}
+
+QNetworkReply*
+qnetworkaccessmanagerGet(QNetworkAccessManager& manager,
+                         const QNetworkRequest& request)
+{
+  return manager.get(request);
+}
+
+QNetworkReply*
+qnetworkaccessmanagerPost(QNetworkAccessManager& manager,
+                          const QNetworkRequest& request,
+                          const QByteArray& data)
+{
+  return manager.post(request, data);
+}
+
+}
+}
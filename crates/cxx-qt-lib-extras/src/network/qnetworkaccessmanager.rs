@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QNetworkReply, QNetworkRequest};
+use core::pin::Pin;
+use cxx_qt_lib::QByteArray;
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qnetworkaccessmanager.h");
+        #[qobject]
+        type QNetworkAccessManager;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib-extras/qnetworkreply.h");
+        type QNetworkReply = crate::QNetworkReply;
+        include!("cxx-qt-lib-extras/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+
+        /// Returns true if QNetworkAccessManager is currently configured to automatically delete QNetworkReplies, false otherwise.
+        #[rust_name = "auto_delete_replies"]
+        fn autoDeleteReplies(self: &QNetworkAccessManager) -> bool;
+
+        /// Enables or disables automatic deletion of QNetworkReplies.
+        /// Setting shouldAutoDelete to true is the same as setting the AutoDeleteReplyOnFinishAttribute attribute to true on all future QNetworkRequests.
+        #[rust_name = "set_auto_delete_replies"]
+        fn setAutoDeleteReplies(self: Pin<&mut QNetworkAccessManager>, should_auto_delete: bool);
+
+        /// Returns the timeout used for transfers, in milliseconds.
+        #[rust_name = "transfer_timeout"]
+        fn transferTimeout(self: &QNetworkAccessManager) -> i32;
+
+        /// Sets timeout as the transfer timeout in milliseconds.
+        #[rust_name = "set_transfer_timeout"]
+        fn setTransferTimeout(self: Pin<&mut QNetworkAccessManager>, timeout: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qnetworkaccessmanager_new"]
+        fn qnetworkaccessmanagerNew() -> UniquePtr<QNetworkAccessManager>;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkaccessmanager_get"]
+        unsafe fn qnetworkaccessmanagerGet(
+            manager: Pin<&mut QNetworkAccessManager>,
+            request: &QNetworkRequest,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkaccessmanager_post"]
+        unsafe fn qnetworkaccessmanagerPost(
+            manager: Pin<&mut QNetworkAccessManager>,
+            request: &QNetworkRequest,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+    }
+
+    // QNetworkAccessManager is not a trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QNetworkAccessManager>::isRelocatable);
+    impl UniquePtr<QNetworkAccessManager> {}
+}
+
+pub use ffi::QNetworkAccessManager;
+
+impl QNetworkAccessManager {
+    /// Create a new QNetworkAccessManager
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qnetworkaccessmanager_new()
+    }
+
+    /// Posts a request to obtain the contents of the target request
+    /// and returns a new QNetworkReply object opened for reading.
+    ///
+    /// The reply is owned by the manager, connect to [`QNetworkReply::on_finished`]
+    /// to receive the result and call [`QNetworkReply::delete_later`] once the data has been read.
+    pub fn get(self: Pin<&mut Self>, request: &QNetworkRequest) -> Pin<&mut QNetworkReply> {
+        // SAFETY: QNetworkAccessManager::get always returns a valid reply which is parented to the manager
+        unsafe { Pin::new_unchecked(&mut *ffi::qnetworkaccessmanager_get(self, request)) }
+    }
+
+    /// Sends an HTTP POST request to the destination specified by request
+    /// and returns a new QNetworkReply object opened for reading.
+    ///
+    /// The contents of the data device will be uploaded to the server.
+    /// The reply is owned by the manager, see [`QNetworkAccessManager::get`] for how to handle it.
+    pub fn post(
+        self: Pin<&mut Self>,
+        request: &QNetworkRequest,
+        data: &QByteArray,
+    ) -> Pin<&mut QNetworkReply> {
+        // SAFETY: QNetworkAccessManager::post always returns a valid reply which is parented to the manager
+        unsafe { Pin::new_unchecked(&mut *ffi::qnetworkaccessmanager_post(self, request, data)) }
+    }
+}
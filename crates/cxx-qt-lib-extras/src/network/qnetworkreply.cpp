@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qnetworkreply.h"
+
+#include <QtNetwork/QNetworkRequest>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qnetworkreplyHttpStatusCode(const QNetworkReply& reply)
+{
+  return static_cast<::std::int32_t>(
+    reply.attribute(QNetworkRequest::HttpStatusCodeAttribute).toInt());
+}
+
+}
+}
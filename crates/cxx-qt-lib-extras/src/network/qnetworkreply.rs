@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Indicates all possible error conditions found during the processing of the request.
+    ///
+    /// Only the most common error codes are listed here, other values may still be returned by Qt.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QNetworkReplyNetworkError {
+        /// No error condition.
+        NoError = 0,
+        /// The remote server refused the connection (the server is not accepting requests).
+        ConnectionRefusedError = 1,
+        /// The remote server closed the connection prematurely, before the entire reply was received and processed.
+        RemoteHostClosedError = 2,
+        /// The remote host name was not found (invalid hostname).
+        HostNotFoundError = 3,
+        /// The connection to the remote server timed out.
+        TimeoutError = 4,
+        /// The operation was canceled via calls to abort() or close() before it was finished.
+        OperationCanceledError = 5,
+        /// The SSL/TLS handshake failed and the encrypted channel could not be established.
+        SslHandshakeFailedError = 6,
+        /// An unknown network-related error was detected.
+        UnknownNetworkError = 99,
+        /// The connection to the proxy server was refused (the proxy server is not accepting requests).
+        ProxyConnectionRefusedError = 101,
+        /// The proxy requires authentication in order to honour the request but did not accept any credentials offered (if any).
+        ProxyAuthenticationRequiredError = 105,
+        /// The access to the remote content was denied (similar to HTTP error 403).
+        ContentAccessDenied = 201,
+        /// The remote content was not found at the server (similar to HTTP error 404).
+        ContentNotFoundError = 203,
+        /// The remote server requires authentication to serve the content but the credentials provided were not accepted (if any).
+        AuthenticationRequiredError = 204,
+        /// An unknown error related to the remote content was detected.
+        UnknownContentError = 299,
+        /// The Network Access API cannot honor the request because the protocol is not known.
+        ProtocolUnknownError = 301,
+        /// A breakdown in protocol was detected (parsing error, invalid or unexpected responses, etc.).
+        ProtocolFailure = 399,
+        /// The server encountered an unexpected condition which prevented it from fulfilling the request.
+        InternalServerError = 401,
+        /// An unknown error related to the server response was detected.
+        UnknownServerError = 499,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qnetworkreply.h");
+        #[qobject]
+        type QNetworkReply;
+
+        /// This signal is emitted when the reply has finished processing.
+        /// After this signal is emitted, there will be no more updates to the reply's data or metadata.
+        #[qsignal]
+        fn finished(self: Pin<&mut QNetworkReply>);
+
+        /// This signal is emitted to indicate the progress of the download part of this network request, if any.
+        #[qsignal]
+        #[cxx_name = "downloadProgress"]
+        fn download_progress(self: Pin<&mut QNetworkReply>, bytes_received: i64, bytes_total: i64);
+
+        /// This signal is emitted to indicate the progress of the upload part of this network request, if any.
+        #[qsignal]
+        #[cxx_name = "uploadProgress"]
+        fn upload_progress(self: Pin<&mut QNetworkReply>, bytes_sent: i64, bytes_total: i64);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        /// Aborts the operation immediately and close down any network connections still open.
+        fn abort(self: Pin<&mut QNetworkReply>);
+
+        /// Schedules this object for deletion.
+        ///
+        /// Replies are owned by the QNetworkAccessManager that created them,
+        /// call this once the reply has finished and its data has been read.
+        #[rust_name = "delete_later"]
+        fn deleteLater(self: Pin<&mut QNetworkReply>);
+
+        /// Returns the error that was found during the processing of this request.
+        /// If no error was found, returns NoError.
+        fn error(self: &QNetworkReply) -> QNetworkReplyNetworkError;
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QNetworkReply) -> QString;
+
+        /// Returns true when the reply has finished or was aborted.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QNetworkReply) -> bool;
+
+        /// Returns true when the request is still processing and the reply has not finished or was aborted yet.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QNetworkReply) -> bool;
+
+        /// Reads all remaining data from the reply, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QNetworkReply>) -> QByteArray;
+
+        /// Returns the URL of the content downloaded or uploaded.
+        /// Note that the URL may be different from that of the original request.
+        fn url(self: &QNetworkReply) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QNetworkReplyNetworkError;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkreply_http_status_code"]
+        fn qnetworkreplyHttpStatusCode(reply: &QNetworkReply) -> i32;
+    }
+}
+
+pub use ffi::{QNetworkReply, QNetworkReplyNetworkError};
+
+impl QNetworkReply {
+    /// Returns the HTTP status code of the reply, for example 200 or 404.
+    ///
+    /// If the reply is not a HTTP reply or no status code has been received yet, 0 is returned.
+    pub fn http_status_code(&self) -> i32 {
+        ffi::qnetworkreply_http_status_code(self)
+    }
+}
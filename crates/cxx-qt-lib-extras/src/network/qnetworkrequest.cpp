@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qnetworkrequest.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QNetworkRequest has a single shared data pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/access/qnetworkrequest.h?h=v5.15.6-lts-lgpl#n183
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/access/qnetworkrequest.h?h=v6.2.4#n178
+assert_alignment_and_size(QNetworkRequest, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QNetworkRequest>::value);
+static_assert(!::std::is_trivially_copy_constructible<QNetworkRequest>::value);
+
+static_assert(QTypeInfo<QNetworkRequest>::isRelocatable);
@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qnetworkrequest.h");
+        type QNetworkRequest = super::QNetworkRequest;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        /// Returns true if the raw header headerName is present in this network request.
+        #[rust_name = "has_raw_header"]
+        fn hasRawHeader(self: &QNetworkRequest, header_name: &QByteArray) -> bool;
+
+        /// Returns the raw form of header headerName.
+        /// If no such header is present, an empty QByteArray is returned.
+        #[rust_name = "raw_header"]
+        fn rawHeader(self: &QNetworkRequest, header_name: &QByteArray) -> QByteArray;
+
+        /// Sets the header headerName to be of value headerValue.
+        /// If headerName corresponds to a known header, the raw format will be parsed and the corresponding "cooked" header will be set as well.
+        #[rust_name = "set_raw_header"]
+        fn setRawHeader(
+            self: &mut QNetworkRequest,
+            header_name: &QByteArray,
+            header_value: &QByteArray,
+        );
+
+        /// Sets the URL this network request is referring to be url.
+        #[rust_name = "set_url"]
+        fn setUrl(self: &mut QNetworkRequest, url: &QUrl);
+
+        /// Returns the URL this network request is referring to.
+        fn url(self: &QNetworkRequest) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkrequest_drop"]
+        fn drop(request: &mut QNetworkRequest);
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkrequest_default"]
+        fn construct() -> QNetworkRequest;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkrequest_clone"]
+        fn construct(request: &QNetworkRequest) -> QNetworkRequest;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkrequest_init_from_qurl"]
+        fn construct(url: &QUrl) -> QNetworkRequest;
+    }
+}
+
+/// The QNetworkRequest class holds a request to be sent with QNetworkAccessManager.
+#[repr(C)]
+pub struct QNetworkRequest {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QNetworkRequest {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qnetworkrequest_clone(self)
+    }
+}
+
+impl Default for QNetworkRequest {
+    /// Constructs a QNetworkRequest object with no URL to be requested.
+    fn default() -> Self {
+        ffi::qnetworkrequest_default()
+    }
+}
+
+impl Drop for QNetworkRequest {
+    /// Destroys the QNetworkRequest object.
+    fn drop(&mut self) {
+        ffi::qnetworkrequest_drop(self)
+    }
+}
+
+impl From<&ffi::QUrl> for QNetworkRequest {
+    /// Constructs a QNetworkRequest object with url as the URL to be requested.
+    fn from(url: &ffi::QUrl) -> Self {
+        ffi::qnetworkrequest_init_from_qurl(url)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QNetworkRequest {
+    type Id = type_id!("QNetworkRequest");
+    type Kind = cxx::kind::Trivial;
+}
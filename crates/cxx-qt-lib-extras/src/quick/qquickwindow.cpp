@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquickwindow.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQuickWindow*
+qquickwindowFromObject(const QObject* object)
+{
+  // The object may be the window itself or an item which is shown in it
+  auto* mutableObject = const_cast<QObject*>(object);
+  if (auto* window = qobject_cast<QQuickWindow*>(mutableObject)) {
+    return window;
+  }
+  if (const auto* item = qobject_cast<const QQuickItem*>(object)) {
+    return item->window();
+  }
+  return nullptr;
+}
+
+}
+}
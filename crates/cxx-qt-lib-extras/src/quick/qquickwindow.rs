@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquickwindow.h");
+        /// The window which renders a Qt Quick scene.
+        ///
+        /// With the threaded render loop the rendering signals are emitted on the render thread,
+        /// connect to them with `ConnectionType::DirectConnection` to run per-frame logic on that thread,
+        /// otherwise the closure is queued onto the thread of the window.
+        #[qobject]
+        type QQuickWindow;
+
+        /// This signal is emitted after the GUI thread has processed all pending animations
+        /// and before the scene graph is synchronized.
+        #[qsignal]
+        #[cxx_name = "afterAnimating"]
+        fn after_animating(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted after the scene has completed rendering, before swapbuffers is called.
+        #[qsignal]
+        #[cxx_name = "afterRendering"]
+        fn after_rendering(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted after the scene graph is synchronized with the QML state.
+        #[qsignal]
+        #[cxx_name = "afterSynchronizing"]
+        fn after_synchronizing(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted after the preparations for the frame have been done, but before rendering starts.
+        #[qsignal]
+        #[cxx_name = "beforeRendering"]
+        fn before_rendering(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted before the scene graph is synchronized with the QML state.
+        #[qsignal]
+        #[cxx_name = "beforeSynchronizing"]
+        fn before_synchronizing(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted when a frame has been queued for presenting.
+        #[qsignal]
+        #[cxx_name = "frameSwapped"]
+        fn frame_swapped(self: Pin<&mut QQuickWindow>);
+    }
+
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        #[doc(hidden)]
+        type QObject;
+        include!("cxx-qt-lib-extras/qscreen.h");
+        type QScreen = crate::QScreen;
+
+        /// Returns the ratio between physical pixels and device-independent pixels for the window.
+        #[rust_name = "device_pixel_ratio"]
+        fn devicePixelRatio(self: &QQuickWindow) -> f64;
+
+        /// Returns the device pixel ratio for this window, which may differ from device_pixel_ratio()
+        /// when the window is rendered into an offscreen target.
+        #[rust_name = "effective_device_pixel_ratio"]
+        fn effectiveDevicePixelRatio(self: &QQuickWindow) -> f64;
+
+        /// Returns the height of the window in device-independent pixels.
+        fn height(self: &QQuickWindow) -> i32;
+
+        /// Returns whether the window is exposed and can be rendered to.
+        #[rust_name = "is_exposed"]
+        fn isExposed(self: &QQuickWindow) -> bool;
+
+        /// Returns true if the window is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickWindow) -> bool;
+
+        /// Returns the screen on which the window is shown, or a null pointer if there is none.
+        fn screen(self: &QQuickWindow) -> *mut QScreen;
+
+        /// Schedules the window to render another frame.
+        fn update(self: Pin<&mut QQuickWindow>);
+
+        /// Returns the width of the window in device-independent pixels.
+        fn width(self: &QQuickWindow) -> i32;
+
+        /// Returns the x position of the window on the desktop.
+        fn x(self: &QQuickWindow) -> i32;
+
+        /// Returns the y position of the window on the desktop.
+        fn y(self: &QQuickWindow) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_from_object"]
+        unsafe fn qquickwindowFromObject(object: *const QObject) -> *mut QQuickWindow;
+    }
+}
+
+pub use ffi::QQuickWindow;
+
+impl QQuickWindow {
+    /// Returns a pointer to the window of a QQuickItem, such as a QObject defined in Rust with
+    /// `#[base = QQuickItem]`, or to the window itself if the QObject is a QQuickWindow.
+    ///
+    /// The pointer is null if the item is not shown in a window or the QObject is neither an item nor a window.
+    /// The window is not owned by the item, so the pointer should not be kept once the item is removed from the window.
+    pub fn from_item<T>(item: &T) -> *mut QQuickWindow
+    where
+        T: cxx_qt::CxxQtType,
+    {
+        // SAFETY: the C++ class of a CXX-Qt QObject has its QObject base class first,
+        // so a pointer to it is also a valid pointer to its QObject.
+        unsafe { ffi::qquickwindow_from_object(item as *const T as *const ffi::QObject) }
+    }
+}
@@ -14,10 +14,22 @@ fn qt_qml_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QML").is_ok()
 }
 
+fn qt_quick_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICK").is_ok()
+}
+
 fn qt_quickcontrols_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QUICKCONTROLS").is_ok()
 }
 
+/// QAction moved from QtWidgets to QtGui in Qt 6, so it is only available with Qt 6
+fn qt_gui_action_enabled() -> bool {
+    qt_gui_enabled()
+        && qt_build_utils::QtBuild::new(vec![])
+            .map(|qtbuild| qtbuild.version().major >= 6)
+            .unwrap_or(false)
+}
+
 fn header_dir() -> PathBuf {
     PathBuf::from(std::env::var("OUT_DIR").unwrap())
         .join("include")
@@ -76,9 +88,12 @@ fn main() {
         Err(_) => false,
     };
 
+    let qt_gui_action = qt_gui_action_enabled();
+
     let mut rust_bridges = vec![
         "core/qbytearray",
         "core/qcoreapplication",
+        "core/qcryptographichash",
         "core/qdate",
         "core/qhash/qhash_i32_qbytearray",
         "core/qhash/qhash_qstring_qvariant",
@@ -115,6 +130,10 @@ fn main() {
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmessageauthenticationcode",
+        "core/qmetatype",
+        "core/qmimedatabase",
+        "core/qmimetype",
         "core/qmodelindex",
         "core/qpersistentmodelindex",
         "core/qpoint",
@@ -143,6 +162,7 @@ fn main() {
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
         "core/qtime",
         "core/qurl",
         "core/qvariant/mod",
@@ -167,6 +187,7 @@ fn main() {
         "core/qvariant/qvariant_qstringlist",
         "core/qvariant/qvariant_qtime",
         "core/qvariant/qvariant_qurl",
+        "core/qvariant/qvariant_qvariantmap",
         "core/qvariant/qvariant_u8",
         "core/qvariant/qvariant_u16",
         "core/qvariant/qvariant_u32",
@@ -205,25 +226,39 @@ fn main() {
         rust_bridges.extend([
             "core/qlist/qlist_qcolor",
             "core/qvariant/qvariant_qcolor",
+            "core/qvariant/qvariant_qicon",
             "core/qvector/qvector_qcolor",
             "gui/qcolor",
+            "gui/qcursor",
             "gui/qfont",
             "gui/qguiapplication",
+            "gui/qicon",
             "gui/qimage",
             "gui/qpainterpath",
             "gui/qpainter",
+            "gui/qpalette",
             "gui/qpen",
             "gui/qpolygon",
             "gui/qpolygonf",
             "gui/qregion",
+            "gui/qtextcharformat",
+            "gui/qvalidator",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
         ]);
     }
 
+    if qt_gui_action {
+        rust_bridges.push("gui/qaction");
+    }
+
     if qt_qml_enabled() {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qmlpromise",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -244,6 +279,7 @@ fn main() {
     let mut cpp_files = vec![
         "core/qbytearray",
         "core/qcoreapplication",
+        "core/qcryptographichash",
         "core/qdate",
         "core/qhash/qhash",
         "core/qline",
@@ -252,6 +288,10 @@ fn main() {
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmessageauthenticationcode",
+        "core/qmetatype",
+        "core/qmimedatabase",
+        "core/qmimetype",
         "core/qmodelindex",
         "core/qpersistentmodelindex",
         "core/qpoint",
@@ -263,6 +303,7 @@ fn main() {
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
         "core/qtime",
         "core/qurl",
         "core/qvariant/qvariant",
@@ -272,23 +313,35 @@ fn main() {
     if qt_gui_enabled() {
         cpp_files.extend([
             "gui/qcolor",
+            "gui/qcursor",
             "gui/qfont",
             "gui/qguiapplication",
+            "gui/qicon",
             "gui/qimage",
             "gui/qpainterpath",
             "gui/qpainter",
+            "gui/qpalette",
             "gui/qpen",
             "gui/qpolygon",
             "gui/qpolygonf",
             "gui/qregion",
+            "gui/qtextcharformat",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
         ]);
     }
 
+    if qt_gui_action {
+        cpp_files.push("gui/qaction");
+    }
+
     if qt_qml_enabled() {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qmlpromise",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -314,6 +367,10 @@ fn main() {
         interface = interface.define("CXX_QT_QML_FEATURE", None);
     }
 
+    if qt_quick_enabled() {
+        interface = interface.define("CXX_QT_QUICK_FEATURE", None);
+    }
+
     if qt_quickcontrols_enabled() {
         interface = interface.define("CXX_QT_QUICKCONTROLS_FEATURE", None);
     }
@@ -325,7 +382,13 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        builder = builder.qt_module("Qml");
+        builder = builder
+            .qt_module("Qml")
+            .qobject_header("include/qml/qmlpromise.h");
+    }
+
+    if qt_quick_enabled() {
+        builder = builder.qt_module("Quick");
     }
 
     if qt_quickcontrols_enabled() {
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QCryptographicHash>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QCryptographicHashAlgorithm = QCryptographicHash::Algorithm;
+
+::std::unique_ptr<QCryptographicHash>
+qcryptographichashNew(QCryptographicHashAlgorithm method);
+
+void
+qcryptographichashAddData(QCryptographicHash& hash, const QByteArray& data);
+
+QByteArray
+qcryptographichashHash(const QByteArray& data,
+                       QCryptographicHashAlgorithm method);
+
+::std::int32_t
+qcryptographichashHashLength(QCryptographicHashAlgorithm method);
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMessageAuthenticationCode>
+
+#include "cxx-qt-lib/qcryptographichash.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMessageAuthenticationCode>
+qmessageauthenticationcodeNew(QCryptographicHashAlgorithm method,
+                              const QByteArray& key);
+
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  const QByteArray& data);
+
+QByteArray
+qmessageauthenticationcodeHash(const QByteArray& message,
+                               const QByteArray& key,
+                               QCryptographicHashAlgorithm method);
+
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 const QByteArray& key);
+
+}
+}
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaObject>
+#include <QtCore/QMetaType>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qmetatypeIdFromName(const QByteArray& name);
+
+::std::int32_t
+qmetatypeIdFromVariant(const QVariant& variant);
+
+bool
+qmetatypeIsRegistered(::std::int32_t id);
+
+QByteArray
+qmetatypeName(::std::int32_t id);
+
+void
+qmetatypeRegisterTypedef(const QByteArray& name, ::std::int32_t id);
+
+}
+}
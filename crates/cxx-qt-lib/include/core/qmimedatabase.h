@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QMimeDatabase>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QMimeDatabase only contains a pointer to the shared database, so it can be moved in memory
+template<>
+struct IsRelocatable<QMimeDatabase> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+using QMimeDatabaseMatchMode = QMimeDatabase::MatchMode;
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -7,6 +7,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <cstdint>
+
 #include <QtCore/QByteArray>
 #include <QtCore/QStringList>
 
@@ -32,6 +34,12 @@ qstringToRustString(const QString& string);
 
 QString
 qstringArg(const QString& string, const QString& a);
+QString
+qstringArgF64(const QString& string, double a);
+QString
+qstringArgI64(const QString& string, ::std::int64_t a);
+QString
+qstringArgU64(const QString& string, ::std::uint64_t a);
 ::rust::isize
 qstringIndexOf(const QString& string,
                const QString& str,
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew();
+
+void
+qthreadStart(QThread& thread);
+
+bool
+qthreadWait(QThread& thread);
+
+QThread*
+qthreadCurrentThread();
+
+bool
+qobjectMoveToThread(QObject& object, const QThread& thread);
+
+QThread*
+qobjectThread(const QObject& object);
+
+}
+}
@@ -29,6 +29,7 @@
 
 #ifdef CXX_QT_GUI_FEATURE
 #include <QtGui/QColor>
+#include <QtGui/QIcon>
 #endif
 
 #include "rust/cxx.h"
@@ -76,6 +77,7 @@ CXX_QT_QVARIANT_CAN_CONVERT(I64)
 CXX_QT_QVARIANT_CAN_CONVERT(QByteArray)
 #ifdef CXX_QT_GUI_FEATURE
 CXX_QT_QVARIANT_CAN_CONVERT(QColor)
+CXX_QT_QVARIANT_CAN_CONVERT(QIcon)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT(QDate)
 CXX_QT_QVARIANT_CAN_CONVERT(QDateTime)
@@ -91,11 +93,15 @@ CXX_QT_QVARIANT_CAN_CONVERT(QString)
 CXX_QT_QVARIANT_CAN_CONVERT(QStringList)
 CXX_QT_QVARIANT_CAN_CONVERT(QTime)
 CXX_QT_QVARIANT_CAN_CONVERT(QUrl)
+CXX_QT_QVARIANT_CAN_CONVERT(QVariantMap)
 CXX_QT_QVARIANT_CAN_CONVERT(U8)
 CXX_QT_QVARIANT_CAN_CONVERT(U16)
 CXX_QT_QVARIANT_CAN_CONVERT(U32)
 CXX_QT_QVARIANT_CAN_CONVERT(U64)
 
+QString
+qvariantTypeName(const QVariant& variant);
+
 }
 }
 }
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QAction>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAction>
+qactionNew(const QString& text);
+
+void
+qactionSetShortcut(QAction& action, const QString& shortcut);
+
+QString
+qactionShortcut(const QAction& action);
+
+}
+}
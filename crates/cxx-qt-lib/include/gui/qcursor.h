@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QCursor>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QCursor only contains a d pointer, so it can be moved in memory
+template<>
+struct IsRelocatable<QCursor> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+
+QPoint
+qcursorPos();
+
+void
+qcursorSetPos(const QPoint& pos);
+
+} // namespace cxxqtlib1
+} // namespace rust
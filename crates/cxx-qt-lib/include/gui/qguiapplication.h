@@ -8,8 +8,10 @@
 
 #include <memory>
 
+#include <QtGui/QCursor>
 #include <QtGui/QFont>
 #include <QtGui/QGuiApplication>
+#include <QtGui/QPalette>
 
 #include "rust/cxx.h"
 
@@ -25,5 +27,32 @@ qguiapplicationSetFont(QGuiApplication& app, const QFont& font);
 QFont
 qguiapplicationFont(const QGuiApplication& app);
 
+void
+qguiapplicationSetPalette(QGuiApplication& app, const QPalette& palette);
+
+QPalette
+qguiapplicationPalette(const QGuiApplication& app);
+
+void
+qguiapplicationChangeOverrideCursor(QGuiApplication& app,
+                                    const QCursor& cursor);
+
+bool
+qguiapplicationHasOverrideCursor(const QGuiApplication& app);
+
+QCursor
+qguiapplicationOverrideCursor(const QGuiApplication& app);
+
+void
+qguiapplicationRestoreOverrideCursor(QGuiApplication& app);
+
+void
+qguiapplicationSetOverrideCursor(QGuiApplication& app, const QCursor& cursor);
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+Qt::ColorScheme
+qguiapplicationColorScheme(const QGuiApplication& app);
+#endif
+
 }
 }
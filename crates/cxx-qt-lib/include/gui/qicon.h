@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QIcon>
+#include <QtGui/QImage>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QIcon> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+using QIconMode = QIcon::Mode;
+using QIconState = QIcon::State;
+
+QIcon
+qiconFromQImage(const QImage& image);
+
+QIcon
+qiconFromTheme(const QString& name);
+
+bool
+qiconHasThemeIcon(const QString& name);
+
+void
+qiconSetThemeName(const QString& name);
+
+QString
+qiconThemeName();
+
+QImage
+qiconToQImage(const QIcon& icon,
+              const QSize& size,
+              QIconMode mode,
+              QIconState state);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QColor>
+#include <QtGui/QTextCharFormat>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QTextCharFormat> : ::std::true_type
+{};
+
+namespace cxxqtlib1 {
+
+QColor
+qtextcharformatBackground(const QTextCharFormat& format);
+
+QColor
+qtextcharformatForeground(const QTextCharFormat& format);
+
+void
+qtextcharformatSetBackground(QTextCharFormat& format, const QColor& color);
+
+void
+qtextcharformatSetForeground(QTextCharFormat& format, const QColor& color);
+
+} // namespace cxxqtlib1
+} // namespace rust
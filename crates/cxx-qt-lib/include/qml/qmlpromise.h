@@ -0,0 +1,65 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <vector>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QVariant>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A QObject which can be returned from an invokable to QML and used like a
+// JavaScript promise, it is settled from Rust with a QmlPromiseResolver.
+//
+// Note that this header is processed by moc, so it can't be guarded by
+// CXX_QT_QML_FEATURE like the other QML headers.
+class QmlPromise : public QObject
+{
+  Q_OBJECT
+
+public:
+  // Registers callbacks for when the promise is fulfilled or rejected,
+  // returns a promise which is settled with the result of the callback
+  Q_INVOKABLE QmlPromise* then(const QJSValue& onFulfilled,
+                               const QJSValue& onRejected = QJSValue());
+
+  void settle(bool fulfilled, const QVariant& value);
+
+private:
+  struct Reaction
+  {
+    QJSValue onFulfilled;
+    QJSValue onRejected;
+    QPointer<QmlPromise> next;
+  };
+
+  void react(const Reaction& reaction) const;
+
+  enum class State
+  {
+    Pending,
+    Fulfilled,
+    Rejected,
+  };
+
+  State m_state = State::Pending;
+  QVariant m_value;
+  ::std::vector<Reaction> m_reactions;
+};
+
+QmlPromise*
+qmlpromiseNew();
+
+void
+qmlpromiseSettle(QmlPromise* promise, bool fulfilled, const QVariant& value);
+
+}
+}
@@ -9,6 +9,9 @@ pub use qbytearray::QByteArray;
 mod qcoreapplication;
 pub use qcoreapplication::QCoreApplication;
 
+mod qcryptographichash;
+pub use qcryptographichash::{QCryptographicHash, QCryptographicHashAlgorithm};
+
 mod qdate;
 pub use qdate::QDate;
 
@@ -30,7 +33,7 @@ mod qlist;
 pub use qlist::{QList, QListElement};
 
 mod qmap;
-pub use qmap::{QMap, QMapPair, QMapPair_QString_QVariant};
+pub use qmap::{QMap, QMapPair, QMapPair_QString_QVariant, QVariantMap};
 
 mod qmargins;
 pub use qmargins::QMargins;
@@ -41,6 +44,18 @@ pub use qmarginsf::QMarginsF;
 // Reexport QMetaObjectConnection and guard from cxx-qt
 pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard};
 
+mod qmessageauthenticationcode;
+pub use qmessageauthenticationcode::QMessageAuthenticationCode;
+
+mod qmetatype;
+pub use qmetatype::QMetaType;
+
+mod qmimedatabase;
+pub use qmimedatabase::{QMimeDatabase, QMimeDatabaseMatchMode};
+
+mod qmimetype;
+pub use qmimetype::QMimeType;
+
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
@@ -63,18 +78,23 @@ mod qsizef;
 pub use qsizef::QSizeF;
 
 mod qstring;
-pub use qstring::QString;
+pub use qstring::{QString, QStringArg};
 
 mod qstringlist;
 pub use qstringlist::QStringList;
 
-mod qt;
+pub mod qt;
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+pub use qt::ColorScheme;
 pub use qt::{
-    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, CursorShape,
+    DateFormat, FillRule, LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode,
+    SplitBehaviorFlags, TimeSpec, TransformationMode,
 };
 
+mod qthread;
+pub use qthread::{MoveToThread, QThread, ThreadAffinity};
+
 mod qtime;
 pub use qtime::QTime;
 
@@ -98,6 +118,19 @@ pub use qvariant::{QVariant, QVariantValue};
 mod qvector;
 pub use qvector::{QVector, QVectorElement};
 
+/// The container that Qt APIs use for contiguous data, which is [QVector] in Qt 5 and [QList] in Qt 6.
+///
+/// For example `QImage::colorTable` returns a `QVector<QRgb>` in Qt 5 and a `QList<QRgb>` in Qt 6.
+/// Using this alias allows for code that works with both Qt 5 and Qt 6.
+#[cfg(cxxqt_qt_version_major = "5")]
+pub type QContiguousList<T> = QVector<T>;
+/// The container that Qt APIs use for contiguous data, which is [QVector] in Qt 5 and [QList] in Qt 6.
+///
+/// For example `QImage::colorTable` returns a `QVector<QRgb>` in Qt 5 and a `QList<QRgb>` in Qt 6.
+/// Using this alias allows for code that works with both Qt 5 and Qt 6.
+#[cfg(cxxqt_qt_version_major = "6")]
+pub type QContiguousList<T> = QList<T>;
+
 #[cxx::bridge]
 mod ffi {
     #[namespace = "rust::cxxqtlib1"]
@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qcryptographichash.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QCryptographicHash>
+qcryptographichashNew(QCryptographicHashAlgorithm method)
+{
+  return ::std::make_unique<QCryptographicHash>(method);
+}
+
+void
+qcryptographichashAddData(QCryptographicHash& hash, const QByteArray& data)
+{
+  // Qt 6 takes a QByteArrayView, so use C++ to choose the overload
+  hash.addData(data);
+}
+
+QByteArray
+qcryptographichashHash(const QByteArray& data,
+                       QCryptographicHashAlgorithm method)
+{
+  return QCryptographicHash::hash(data, method);
+}
+
+::std::int32_t
+qcryptographichashHashLength(QCryptographicHashAlgorithm method)
+{
+  return static_cast<::std::int32_t>(QCryptographicHash::hashLength(method));
+}
+
+}
+}
@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QByteArray;
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the cryptographic hash algorithms supported by QCryptographicHash.
+    ///
+    /// Note that in Qt the `Sha3_*` names may refer to the Keccak algorithms for compatibility,
+    /// so the `RealSha3_*` variants are used here to be explicit.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QCryptographicHashAlgorithm {
+        /// Generate an MD4 hash sum
+        Md4 = 0,
+        /// Generate an MD5 hash sum
+        Md5 = 1,
+        /// Generate an SHA-1 hash sum
+        Sha1 = 2,
+        /// Generate an SHA-224 hash sum (SHA-2)
+        Sha224 = 3,
+        /// Generate an SHA-256 hash sum (SHA-2)
+        Sha256 = 4,
+        /// Generate an SHA-384 hash sum (SHA-2)
+        Sha384 = 5,
+        /// Generate an SHA-512 hash sum (SHA-2)
+        Sha512 = 6,
+        /// Generate a Keccak-224 hash sum
+        Keccak_224 = 7,
+        /// Generate a Keccak-256 hash sum
+        Keccak_256 = 8,
+        /// Generate a Keccak-384 hash sum
+        Keccak_384 = 9,
+        /// Generate a Keccak-512 hash sum
+        Keccak_512 = 10,
+        /// Generate an SHA3-224 hash sum
+        RealSha3_224 = 11,
+        /// Generate an SHA3-256 hash sum
+        RealSha3_256 = 12,
+        /// Generate an SHA3-384 hash sum
+        RealSha3_384 = 13,
+        /// Generate an SHA3-512 hash sum
+        RealSha3_512 = 14,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+
+        include!("cxx-qt-lib/qcryptographichash.h");
+        /// The QCryptographicHash class provides a way to generate cryptographic hashes.
+        ///
+        /// The hashes are identical to the ones generated by QCryptographicHash in C++.
+        type QCryptographicHash;
+
+        /// Resets the object.
+        fn reset(self: Pin<&mut QCryptographicHash>);
+
+        /// Returns the final hash value.
+        fn result(self: &QCryptographicHash) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QCryptographicHashAlgorithm;
+
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_new"]
+        fn qcryptographichashNew(
+            method: QCryptographicHashAlgorithm,
+        ) -> UniquePtr<QCryptographicHash>;
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_add_data"]
+        fn qcryptographichashAddData(hash: Pin<&mut QCryptographicHash>, data: &QByteArray);
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_hash"]
+        fn qcryptographichashHash(
+            data: &QByteArray,
+            method: QCryptographicHashAlgorithm,
+        ) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_hash_length"]
+        fn qcryptographichashHashLength(method: QCryptographicHashAlgorithm) -> i32;
+    }
+
+    // QCryptographicHash is not copyable and is not relocatable in Qt,
+    // so we cannot mark it as a trivial type and need to use references or pointers.
+    impl UniquePtr<QCryptographicHash> {}
+}
+
+pub use ffi::{QCryptographicHash, QCryptographicHashAlgorithm};
+
+impl QCryptographicHash {
+    /// Constructs an object that can be used to create a cryptographic hash from data using method.
+    pub fn new(method: QCryptographicHashAlgorithm) -> cxx::UniquePtr<Self> {
+        ffi::qcryptographichash_new(method)
+    }
+
+    /// Adds the bytes in data to the cryptographic hash.
+    pub fn add_data(self: Pin<&mut Self>, data: &QByteArray) {
+        ffi::qcryptographichash_add_data(self, data);
+    }
+
+    /// Returns the hash of data using method.
+    pub fn hash(data: &QByteArray, method: QCryptographicHashAlgorithm) -> QByteArray {
+        ffi::qcryptographichash_hash(data, method)
+    }
+
+    /// Returns the size of the output of the selected hash method in bytes.
+    pub fn hash_length(method: QCryptographicHashAlgorithm) -> i32 {
+        ffi::qcryptographichash_hash_length(method)
+    }
+}
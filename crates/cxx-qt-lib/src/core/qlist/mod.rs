@@ -9,7 +9,7 @@ use crate::QColor;
 use crate::QDateTime;
 use crate::{
     QByteArray, QDate, QLine, QLineF, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF,
-    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant,
+    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant, QVector, QVectorElement,
 };
 use core::{marker::PhantomData, mem::MaybeUninit};
 use cxx::{type_id, ExternType};
@@ -168,6 +168,23 @@ where
     }
 }
 
+impl<T> From<&QVector<T>> for QList<T>
+where
+    T: QListElement + QVectorElement + Clone,
+{
+    /// Convert a reference to a [QVector] into a [QList] by making a deep copy of the data.
+    ///
+    /// In Qt 6 QVector is an alias of QList, this allows for code that works with both Qt 5 and Qt 6.
+    fn from(qvec: &QVector<T>) -> Self {
+        let mut qlist = Self::default();
+        qlist.reserve(qvec.len());
+        for element in qvec.iter() {
+            qlist.append_clone(element);
+        }
+        qlist
+    }
+}
+
 impl<T, S> From<S> for QList<T>
 where
     T: QListElement + Clone,
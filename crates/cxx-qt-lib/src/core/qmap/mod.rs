@@ -294,3 +294,6 @@ impl_qmap_pair!(
     QMapPair_QString_QVariant,
     "QMap_QString_QVariant"
 );
+
+/// A [QMap] of [crate::QString] keys to [crate::QVariant] values, which is a JavaScript object in QML
+pub type QVariantMap = QMap<QMapPair_QString_QVariant>;
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmessageauthenticationcode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMessageAuthenticationCode>
+qmessageauthenticationcodeNew(QCryptographicHashAlgorithm method,
+                              const QByteArray& key)
+{
+  return ::std::make_unique<QMessageAuthenticationCode>(method, key);
+}
+
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  const QByteArray& data)
+{
+  // Qt 6 takes a QByteArrayView, so use C++ to choose the overload
+  code.addData(data);
+}
+
+QByteArray
+qmessageauthenticationcodeHash(const QByteArray& message,
+                               const QByteArray& key,
+                               QCryptographicHashAlgorithm method)
+{
+  return QMessageAuthenticationCode::hash(message, key, method);
+}
+
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 const QByteArray& key)
+{
+  code.setKey(key);
+}
+
+}
+}
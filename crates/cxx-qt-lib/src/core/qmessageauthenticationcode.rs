@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QCryptographicHashAlgorithm};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+
+        include!("cxx-qt-lib/qmessageauthenticationcode.h");
+        /// The QMessageAuthenticationCode class provides a way to generate hash-based message authentication codes (HMAC).
+        type QMessageAuthenticationCode;
+
+        /// Resets message data. Calling this method doesn't affect the key.
+        fn reset(self: Pin<&mut QMessageAuthenticationCode>);
+
+        /// Returns the final authentication code.
+        fn result(self: &QMessageAuthenticationCode) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcryptographichash.h");
+        type QCryptographicHashAlgorithm = crate::QCryptographicHashAlgorithm;
+
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_new"]
+        fn qmessageauthenticationcodeNew(
+            method: QCryptographicHashAlgorithm,
+            key: &QByteArray,
+        ) -> UniquePtr<QMessageAuthenticationCode>;
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_add_data"]
+        fn qmessageauthenticationcodeAddData(
+            code: Pin<&mut QMessageAuthenticationCode>,
+            data: &QByteArray,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_hash"]
+        fn qmessageauthenticationcodeHash(
+            message: &QByteArray,
+            key: &QByteArray,
+            method: QCryptographicHashAlgorithm,
+        ) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_set_key"]
+        fn qmessageauthenticationcodeSetKey(
+            code: Pin<&mut QMessageAuthenticationCode>,
+            key: &QByteArray,
+        );
+    }
+
+    // QMessageAuthenticationCode is not copyable and is not relocatable in Qt,
+    // so we cannot mark it as a trivial type and need to use references or pointers.
+    impl UniquePtr<QMessageAuthenticationCode> {}
+}
+
+pub use ffi::QMessageAuthenticationCode;
+
+impl QMessageAuthenticationCode {
+    /// Constructs an object that can be used to create a cryptographic hash from data using method method and key key.
+    pub fn new(method: QCryptographicHashAlgorithm, key: &QByteArray) -> cxx::UniquePtr<Self> {
+        ffi::qmessageauthenticationcode_new(method, key)
+    }
+
+    /// Adds the bytes in data to the message.
+    pub fn add_data(self: Pin<&mut Self>, data: &QByteArray) {
+        ffi::qmessageauthenticationcode_add_data(self, data);
+    }
+
+    /// Returns the authentication code for the message message using the key key and the method method.
+    pub fn hash(
+        message: &QByteArray,
+        key: &QByteArray,
+        method: QCryptographicHashAlgorithm,
+    ) -> QByteArray {
+        ffi::qmessageauthenticationcode_hash(message, key, method)
+    }
+
+    /// Sets secret key. Calling this method automatically resets the object state.
+    pub fn set_key(self: Pin<&mut Self>, key: &QByteArray) {
+        ffi::qmessageauthenticationcode_set_key(self, key);
+    }
+}
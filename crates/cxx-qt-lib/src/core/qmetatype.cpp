@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmetatype.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qmetatypeIdFromName(const QByteArray& name)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return static_cast<::std::int32_t>(QMetaType::fromName(name).id());
+#else
+  return static_cast<::std::int32_t>(QMetaType::type(name.constData()));
+#endif
+}
+
+::std::int32_t
+qmetatypeIdFromVariant(const QVariant& variant)
+{
+  return static_cast<::std::int32_t>(variant.userType());
+}
+
+bool
+qmetatypeIsRegistered(::std::int32_t id)
+{
+  return QMetaType::isRegistered(id);
+}
+
+QByteArray
+qmetatypeName(::std::int32_t id)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QByteArray(QMetaType(id).name());
+#else
+  return QByteArray(QMetaType::typeName(id));
+#endif
+}
+
+void
+qmetatypeRegisterTypedef(const QByteArray& name, ::std::int32_t id)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  QMetaType::registerNormalizedTypedef(
+    QMetaObject::normalizedType(name.constData()), QMetaType(id));
+#else
+  QMetaType::registerNormalizedTypedef(
+    QMetaObject::normalizedType(name.constData()), id);
+#endif
+}
+
+}
+}
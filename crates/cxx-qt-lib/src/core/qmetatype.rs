@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QByteArray;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetatype.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmetatype_id_from_name"]
+        fn qmetatypeIdFromName(name: &QByteArray) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetatype_id_from_variant"]
+        fn qmetatypeIdFromVariant(variant: &QVariant) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetatype_is_registered"]
+        fn qmetatypeIsRegistered(id: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetatype_name"]
+        fn qmetatypeName(id: i32) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetatype_register_typedef"]
+        fn qmetatypeRegisterTypedef(name: &QByteArray, id: i32);
+    }
+}
+
+/// The QMetaType class manages named types in the meta-object system.
+///
+/// The type is represented by its id in the meta-object system, which is the same in Qt 5 and Qt 6.
+///
+/// Note that QObjects generated by CXX-Qt register their pointer types when the library is loaded,
+/// so that they can be used in queued connections and stored in a QVariant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QMetaType {
+    id: i32,
+}
+
+impl QMetaType {
+    /// Returns the QMetaType of the type with the given id.
+    pub fn from_id(id: i32) -> Self {
+        Self { id }
+    }
+
+    /// Returns the QMetaType matching type name, or None if the type is not registered.
+    pub fn from_name(name: &QByteArray) -> Option<Self> {
+        let meta_type = Self::from_id(ffi::qmetatype_id_from_name(name));
+        if meta_type.is_valid() {
+            Some(meta_type)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the QMetaType of the value stored in the variant.
+    pub fn from_variant(variant: &crate::QVariant) -> Self {
+        Self::from_id(ffi::qmetatype_id_from_variant(variant))
+    }
+
+    /// Returns the id of the type.
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Returns true if this QMetaType object contains valid information about a type, false otherwise.
+    pub fn is_valid(&self) -> bool {
+        // QMetaType::UnknownType is zero in Qt 5 and Qt 6
+        self.id != 0
+    }
+
+    /// Returns true if this QMetaType object has been registered with the meta-type system, false otherwise.
+    pub fn is_registered(&self) -> bool {
+        ffi::qmetatype_is_registered(self.id)
+    }
+
+    /// Returns the type name associated with this QMetaType, or an empty array if no matching type was found.
+    pub fn name(&self) -> QByteArray {
+        ffi::qmetatype_name(self.id)
+    }
+
+    /// Registers name as an alias of this type, so that it can be found with [QMetaType::from_name].
+    ///
+    /// This is useful when the C++ name of a type differs from the name used in signals and properties.
+    pub fn register_typedef(&self, name: &QByteArray) {
+        ffi::qmetatype_register_typedef(name, self.id);
+    }
+}
@@ -0,0 +1,18 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmimedatabase.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMimeDatabase has a single d pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/mimetypes/qmimedatabase.h?h=v5.15.6-lts-lgpl#n95
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/mimetypes/qmimedatabase.h?h=v6.2.4#n85
+assert_alignment_and_size(QMimeDatabase, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QMimeDatabase>::value);
+static_assert(!::std::is_trivially_copy_constructible<QMimeDatabase>::value);
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum specifies how matching a file to a MIME type is performed.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QMimeDatabaseMatchMode {
+        /// Both the file name and content are used to look for a match
+        MatchDefault = 0,
+        /// Only the file name is used to look for a match
+        MatchExtension = 1,
+        /// The file content is used to look for a match
+        MatchContent = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmimedatabase.h");
+        type QMimeDatabase = super::QMimeDatabase;
+        include!("cxx-qt-lib/qmimetype.h");
+        type QMimeType = crate::QMimeType;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns a MIME type for data.
+        ///
+        /// A valid MIME type is always returned. If data doesn't match any known MIME type data,
+        /// the default MIME type (application/octet-stream) is returned.
+        #[rust_name = "mime_type_for_data"]
+        fn mimeTypeForData(self: &QMimeDatabase, data: &QByteArray) -> QMimeType;
+
+        /// Returns a MIME type for the file named file_name using mode.
+        ///
+        /// A valid MIME type is always returned. The default MIME type (application/octet-stream)
+        /// is returned if the file could not be matched.
+        #[rust_name = "mime_type_for_file"]
+        fn mimeTypeForFile(
+            self: &QMimeDatabase,
+            file_name: &QString,
+            mode: QMimeDatabaseMatchMode,
+        ) -> QMimeType;
+
+        /// Returns a MIME type for the given file_name and data.
+        ///
+        /// This overload can be useful when the file is remote, and we started to download some of its data.
+        #[rust_name = "mime_type_for_file_name_and_data"]
+        fn mimeTypeForFileNameAndData(
+            self: &QMimeDatabase,
+            file_name: &QString,
+            data: &QByteArray,
+        ) -> QMimeType;
+
+        /// Returns a MIME type for name or an invalid one if none found.
+        #[rust_name = "mime_type_for_name"]
+        fn mimeTypeForName(self: &QMimeDatabase, name: &QString) -> QMimeType;
+
+        /// Returns the suffix for the file file_name, as known by the MIME database.
+        ///
+        /// This allows to pre-select "tar.bz2" for foo.tar.bz2, but still only "txt" for my.file.with.dots.txt.
+        #[rust_name = "suffix_for_file_name"]
+        fn suffixForFileName(self: &QMimeDatabase, file_name: &QString) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QMimeDatabaseMatchMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qmimedatabase_init_default"]
+        fn construct() -> QMimeDatabase;
+        #[doc(hidden)]
+        #[rust_name = "qmimedatabase_drop"]
+        fn drop(database: &mut QMimeDatabase);
+    }
+}
+
+pub use ffi::QMimeDatabaseMatchMode;
+
+/// The QMimeDatabase class maintains a database of MIME types.
+///
+/// The database is shared by all instances, so a QMimeDatabase can be created whenever it is needed.
+/// Files are classified the same way as by the file dialogs of Qt.
+#[repr(C)]
+pub struct QMimeDatabase {
+    _d: MaybeUninit<usize>,
+}
+
+impl Default for QMimeDatabase {
+    /// Constructs a QMimeDatabase object.
+    fn default() -> Self {
+        ffi::qmimedatabase_init_default()
+    }
+}
+
+impl Drop for QMimeDatabase {
+    fn drop(&mut self) {
+        ffi::qmimedatabase_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMimeDatabase {
+    type Id = type_id!("QMimeDatabase");
+    type Kind = cxx::kind::Trivial;
+}
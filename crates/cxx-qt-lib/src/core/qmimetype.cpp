@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmimetype.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMimeType has a single shared data pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/mimetypes/qmimetype.h?h=v5.15.6-lts-lgpl#n124
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/mimetypes/qmimetype.h?h=v6.2.4#n112
+assert_alignment_and_size(QMimeType, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QMimeType>::value);
+static_assert(!::std::is_trivially_copy_constructible<QMimeType>::value);
+
+static_assert(!::std::is_trivially_destructible<QMimeType>::value);
+
+static_assert(QTypeInfo<QMimeType>::isRelocatable);
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmimetype.h");
+        type QMimeType = super::QMimeType;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// Returns the list of aliases of this mimetype.
+        fn aliases(self: &QMimeType) -> QStringList;
+
+        /// Returns the names of the mimetype's ancestors, ie the parents and their parents.
+        #[rust_name = "all_ancestors"]
+        fn allAncestors(self: &QMimeType) -> QStringList;
+
+        /// Returns the description of the MIME type to be displayed on user interfaces.
+        fn comment(self: &QMimeType) -> QString;
+
+        /// Returns a filter string usable for a file dialog.
+        #[rust_name = "filter_string"]
+        fn filterString(self: &QMimeType) -> QString;
+
+        /// Returns the file name of a generic icon that represents the MIME type.
+        #[rust_name = "generic_icon_name"]
+        fn genericIconName(self: &QMimeType) -> QString;
+
+        /// Returns the list of glob matching patterns, for example `*.txt`.
+        #[rust_name = "glob_patterns"]
+        fn globPatterns(self: &QMimeType) -> QStringList;
+
+        /// Returns the file name of an icon image that represents the MIME type.
+        #[rust_name = "icon_name"]
+        fn iconName(self: &QMimeType) -> QString;
+
+        /// Returns true if this mimetype is mimeTypeName, or inherits mimeTypeName, or mimeTypeName is an alias for this mimetype.
+        fn inherits(self: &QMimeType, mime_type_name: &QString) -> bool;
+
+        /// Returns true if this MIME type is the default MIME type which applies to all files: application/octet-stream.
+        #[rust_name = "is_default"]
+        fn isDefault(self: &QMimeType) -> bool;
+
+        /// Returns true if the QMimeType object contains valid data, otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMimeType) -> bool;
+
+        /// Returns the name of the MIME type, for example `text/plain`.
+        fn name(self: &QMimeType) -> QString;
+
+        /// Returns the names of the parent MIME types.
+        #[rust_name = "parent_mime_types"]
+        fn parentMimeTypes(self: &QMimeType) -> QStringList;
+
+        /// Returns the preferred suffix for the MIME type, without the leading dot.
+        #[rust_name = "preferred_suffix"]
+        fn preferredSuffix(self: &QMimeType) -> QString;
+
+        /// Returns the known suffixes for the MIME type, without the leading dot.
+        fn suffixes(self: &QMimeType) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmimetype_init_default"]
+        fn construct() -> QMimeType;
+        #[doc(hidden)]
+        #[rust_name = "qmimetype_drop"]
+        fn drop(mime_type: &mut QMimeType);
+        #[doc(hidden)]
+        #[rust_name = "qmimetype_clone"]
+        fn construct(mime_type: &QMimeType) -> QMimeType;
+        #[doc(hidden)]
+        #[rust_name = "qmimetype_eq"]
+        fn operatorEq(a: &QMimeType, b: &QMimeType) -> bool;
+    }
+}
+
+/// The QMimeType class describes types of file or data, represented by a MIME type string.
+///
+/// MIME types are retrieved from a [QMimeDatabase](crate::QMimeDatabase).
+#[repr(C)]
+pub struct QMimeType {
+    _d: MaybeUninit<usize>,
+}
+
+impl Default for QMimeType {
+    /// Constructs an invalid MIME type.
+    fn default() -> Self {
+        ffi::qmimetype_init_default()
+    }
+}
+
+impl Drop for QMimeType {
+    fn drop(&mut self) {
+        ffi::qmimetype_drop(self);
+    }
+}
+
+impl Clone for QMimeType {
+    fn clone(&self) -> Self {
+        ffi::qmimetype_clone(self)
+    }
+}
+
+impl PartialEq for QMimeType {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qmimetype_eq(self, other)
+    }
+}
+
+impl Eq for QMimeType {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMimeType {
+    type Id = type_id!("QMimeType");
+    type Kind = cxx::kind::Trivial;
+}
@@ -61,6 +61,24 @@ qstringArg(const QString& string, const QString& a)
   return string.arg(a);
 }
 
+QString
+qstringArgF64(const QString& string, double a)
+{
+  return string.arg(a);
+}
+
+QString
+qstringArgI64(const QString& string, ::std::int64_t a)
+{
+  return string.arg(static_cast<qlonglong>(a));
+}
+
+QString
+qstringArgU64(const QString& string, ::std::uint64_t a)
+{
+  return string.arg(static_cast<qulonglong>(a));
+}
+
 ::rust::isize
 qstringIndexOf(const QString& string,
                const QString& str,
@@ -36,6 +36,11 @@ mod ffi {
         #[rust_name = "compare_i32"]
         fn compare(self: &QString, other: &QString, cs: CaseSensitivity) -> i32;
 
+        // We wrap this method to provide an enum so hide it from docs
+        #[doc(hidden)]
+        #[rust_name = "locale_aware_compare_i32"]
+        fn localeAwareCompare(self: &QString, other: &QString) -> i32;
+
         /// Returns true if this string contains an occurrence of the string str; otherwise returns false.
         fn contains(self: &QString, str: &QString, cs: CaseSensitivity) -> bool;
 
@@ -136,6 +141,15 @@ mod ffi {
         #[rust_name = "qstring_arg"]
         fn qstringArg(string: &QString, a: &QString) -> QString;
         #[doc(hidden)]
+        #[rust_name = "qstring_arg_f64"]
+        fn qstringArgF64(string: &QString, a: f64) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstring_arg_i64"]
+        fn qstringArgI64(string: &QString, a: i64) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstring_arg_u64"]
+        fn qstringArgU64(string: &QString, a: u64) -> QString;
+        #[doc(hidden)]
         #[rust_name = "qstring_index_of"]
         fn qstringIndexOf(
             string: &QString,
@@ -331,12 +345,43 @@ impl QString {
         ffi::qstring_arg(self, a)
     }
 
+    /// Returns a copy of this string with the lowest numbered place marker replaced by the number a.
+    ///
+    /// The number is formatted with the default format of QString::arg, a place marker of the form %L1
+    /// formats the number with the current locale.
+    pub fn arg_f64(&self, a: f64) -> Self {
+        ffi::qstring_arg_f64(self, a)
+    }
+
+    /// Returns a copy of this string with the lowest numbered place marker replaced by the number a.
+    ///
+    /// A place marker of the form %L1 formats the number with the current locale.
+    pub fn arg_i64(&self, a: i64) -> Self {
+        ffi::qstring_arg_i64(self, a)
+    }
+
+    /// Returns a copy of this string with the lowest numbered place marker replaced by the number a.
+    ///
+    /// A place marker of the form %L1 formats the number with the current locale.
+    pub fn arg_u64(&self, a: u64) -> Self {
+        ffi::qstring_arg_u64(self, a)
+    }
+
     /// Lexically compares this string with the other string and
     /// returns if this string is less than, equal to, or greater than the other string.
     pub fn compare(&self, other: &QString, cs: ffi::CaseSensitivity) -> Ordering {
         self.compare_i32(other, cs).cmp(&0)
     }
 
+    /// Compares this string with the other string in a locale-dependent manner and
+    /// returns if this string is less than, equal to, or greater than the other string.
+    ///
+    /// The comparison is performed using the platform's collation, so should be used
+    /// when sorting strings which are displayed to the user.
+    pub fn locale_aware_compare(&self, other: &QString) -> Ordering {
+        self.locale_aware_compare_i32(other).cmp(&0)
+    }
+
     /// Returns the index position of the first occurrence of the string str in this string,
     /// searching forward from index position from. Returns -1 if str is not found.
     pub fn index_of(&self, str: &QString, from: isize, cs: ffi::CaseSensitivity) -> isize {
@@ -417,6 +462,79 @@ impl QString {
     }
 }
 
+/// A value which can replace a place marker of a [QString] using QString::arg
+///
+/// This is used by the [qformat!](crate::qformat) macro, numbers use the numeric overloads of QString::arg
+/// so that place markers of the form %L1 are formatted with the current locale.
+pub trait QStringArg {
+    /// Returns a copy of the string with the lowest numbered place marker replaced by this value
+    fn arg_of(&self, string: &QString) -> QString;
+}
+
+impl QStringArg for QString {
+    fn arg_of(&self, string: &QString) -> QString {
+        string.arg(self)
+    }
+}
+
+impl QStringArg for str {
+    fn arg_of(&self, string: &QString) -> QString {
+        string.arg(&QString::from(self))
+    }
+}
+
+impl QStringArg for String {
+    fn arg_of(&self, string: &QString) -> QString {
+        string.arg(&QString::from(self))
+    }
+}
+
+impl<T: QStringArg + ?Sized> QStringArg for &T {
+    fn arg_of(&self, string: &QString) -> QString {
+        (**self).arg_of(string)
+    }
+}
+
+macro_rules! impl_qstring_arg {
+    ( $method:ident, $target:ty, $($typeName:ty),+ ) => {
+        $(
+            impl QStringArg for $typeName {
+                fn arg_of(&self, string: &QString) -> QString {
+                    string.$method(<$target>::from(*self))
+                }
+            }
+        )+
+    };
+}
+
+impl_qstring_arg!(arg_i64, i64, i8, i16, i32, i64);
+impl_qstring_arg!(arg_u64, u64, u8, u16, u32, u64);
+impl_qstring_arg!(arg_f64, f64, f32, f64);
+
+/// Create a [QString] from a format string with %1, %2, ... place markers, using QString::arg
+///
+/// The arguments replace the place markers in order of their number, so translated format strings
+/// can reorder the place markers. The arguments can be any type implementing [QStringArg](crate::QStringArg),
+/// such as a [QString], string slice or number.
+///
+/// ```ignore
+/// let name = QString::from("Basket");
+/// let text = cxx_qt_lib::qformat!("%1 has %2 items", &name, 3);
+/// assert_eq!(text.to_string(), "Basket has 3 items");
+/// ```
+///
+/// Note that like chained calls to QString::arg, place markers within an argument are replaced by later arguments.
+#[macro_export]
+macro_rules! qformat {
+    ($format:expr $(, $arg:expr)* $(,)?) => {{
+        let string = $crate::QString::from($format);
+        $(
+            let string = $crate::QStringArg::arg_of(&$arg, &string);
+        )*
+        string
+    }};
+}
+
 // Safety:
 //
 // Static checks on the C++ side to ensure the size is the same.
@@ -3,6 +3,20 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+//! Enums of the Qt namespace, such as `Qt::Key` and `Qt::ItemDataRole`.
+//!
+//! The enums can be converted to and from their integer values in Qt with [From], which is useful for
+//! values like roles of a model or flags that are combined.
+//!
+//! ```ignore
+//! use cxx_qt_lib::qt;
+//!
+//! let role = i32::from(qt::ItemDataRole::UserRole) + 1;
+//! let alignment = qt::AlignmentFlag::from(
+//!     qt::AlignmentFlag::AlignLeft.repr | qt::AlignmentFlag::AlignTop.repr,
+//! );
+//! ```
+
 #[cxx::bridge(namespace = "Qt")]
 mod ffi {
     /// This enum type defines what happens to the aspect ratio when scaling an rectangle.
@@ -120,6 +134,59 @@ mod ffi {
         LayoutDirectionAuto,
     }
 
+    /// This enum type defines the various cursors that can be used.
+    #[repr(i32)]
+    enum CursorShape {
+        /// The standard arrow cursor.
+        ArrowCursor = 0,
+        /// An arrow pointing upwards toward the top of the screen.
+        UpArrowCursor = 1,
+        /// A crosshair cursor, typically used to help the user accurately select a point on the screen.
+        CrossCursor = 2,
+        /// An hourglass or watch cursor, usually shown during operations that prevent the user from interacting with the application.
+        WaitCursor = 3,
+        /// A caret or ibeam cursor, indicating that a widget can accept and display text input.
+        IBeamCursor = 4,
+        /// A cursor used for elements that are used to vertically resize top-level windows.
+        SizeVerCursor = 5,
+        /// A cursor used for elements that are used to horizontally resize top-level windows.
+        SizeHorCursor = 6,
+        /// A cursor used for elements that are used to diagonally resize top-level windows at their top-right and bottom-left corners.
+        SizeBDiagCursor = 7,
+        /// A cursor used for elements that are used to diagonally resize top-level windows at their top-left and bottom-right corners.
+        SizeFDiagCursor = 8,
+        /// A cursor used for elements that are used to resize top-level windows in any direction.
+        SizeAllCursor = 9,
+        /// A blank/invisible cursor, typically used when the cursor shape needs to be hidden.
+        BlankCursor = 10,
+        /// A cursor used for vertical splitters, indicating that a handle can be dragged horizontally to adjust the use of available space.
+        SplitVCursor = 11,
+        /// A cursor used for horizontal splitters, indicating that a handle can be dragged vertically to adjust the use of available space.
+        SplitHCursor = 12,
+        /// A pointing hand cursor that is typically used for clickable elements such as hyperlinks.
+        PointingHandCursor = 13,
+        /// A slashed circle cursor, typically used during drag and drop operations to indicate that dragged content cannot be dropped.
+        ForbiddenCursor = 14,
+        /// An arrow with a question mark, typically used to indicate the presence of What's This? help for a widget.
+        WhatsThisCursor = 15,
+        /// An hourglass or watch cursor, usually shown during operations that allow the user to interact with the application while they are performed in the background.
+        BusyCursor = 16,
+        /// A cursor representing an open hand, typically used to indicate that the area under the cursor is the visible part of a canvas that the user can click and drag in order to scroll around.
+        OpenHandCursor = 17,
+        /// A cursor representing a closed hand, typically used to indicate that a dragging operation is in progress that involves scrolling.
+        ClosedHandCursor = 18,
+        /// A cursor that is usually used when dragging an item to copy it.
+        DragCopyCursor = 19,
+        /// A cursor that is usually used when dragging an item.
+        DragMoveCursor = 20,
+        /// A cursor that is usually used when dragging an item to make a link to it.
+        DragLinkCursor = 21,
+        /// A cursor created from a bitmap.
+        BitmapCursor = 24,
+        /// A custom cursor.
+        CustomCursor = 25,
+    }
+
     /// This enum type specifies the background mode
     #[repr(i32)]
     enum BGMode {
@@ -145,6 +212,250 @@ mod ffi {
         RelativeSize,
     }
 
+    /// This enum type is used to describe alignment, it contains horizontal and vertical flags that can be combined.
+    ///
+    /// Combinations of flags can be created from their integer values.
+    #[repr(i32)]
+    enum AlignmentFlag {
+        /// Aligns with the left edge.
+        AlignLeft = 0x0001,
+        /// Aligns with the right edge.
+        AlignRight = 0x0002,
+        /// Centers horizontally in the available space.
+        AlignHCenter = 0x0004,
+        /// Justifies the text in the available space.
+        AlignJustify = 0x0008,
+        /// Left and right are not swapped in right-to-left layouts.
+        AlignAbsolute = 0x0010,
+        /// Aligns with the top.
+        AlignTop = 0x0020,
+        /// Aligns with the bottom.
+        AlignBottom = 0x0040,
+        /// Centers vertically in the available space.
+        AlignVCenter = 0x0080,
+        /// Aligns with the baseline.
+        AlignBaseline = 0x0100,
+        /// Centers in both dimensions.
+        AlignCenter = 0x0084,
+    }
+
+    /// This enum type describes the roles of the data in a model, as used by QAbstractItemModel.
+    #[repr(i32)]
+    enum ItemDataRole {
+        /// The key data to be rendered in the form of text.
+        DisplayRole = 0,
+        /// The data to be rendered as a decoration in the form of an icon.
+        DecorationRole = 1,
+        /// The data in a form suitable for editing in an editor.
+        EditRole = 2,
+        /// The data displayed in the item's tooltip.
+        ToolTipRole = 3,
+        /// The data displayed in the status bar.
+        StatusTipRole = 4,
+        /// The data displayed for the item in "What's This?" mode.
+        WhatsThisRole = 5,
+        /// The font used for items rendered with the default delegate.
+        FontRole = 6,
+        /// The alignment of the text for items rendered with the default delegate.
+        TextAlignmentRole = 7,
+        /// The background brush used for items rendered with the default delegate.
+        BackgroundRole = 8,
+        /// The foreground brush used for items rendered with the default delegate.
+        ForegroundRole = 9,
+        /// This role is used to obtain the checked state of an item.
+        CheckStateRole = 10,
+        /// The text to be used by accessibility extensions and plugins, such as screen readers.
+        AccessibleTextRole = 11,
+        /// A description of the item for accessibility purposes.
+        AccessibleDescriptionRole = 12,
+        /// The size hint for the item that will be supplied to views.
+        SizeHintRole = 13,
+        /// The initial sort order of a header view section.
+        InitialSortOrderRole = 14,
+        /// The first role that can be used for application-specific purposes.
+        UserRole = 0x0100,
+    }
+
+    /// The key names used by Qt.
+    #[repr(i32)]
+    enum Key {
+        Key_Escape = 0x01000000,
+        Key_Tab = 0x01000001,
+        Key_Backtab = 0x01000002,
+        Key_Backspace = 0x01000003,
+        Key_Return = 0x01000004,
+        Key_Enter = 0x01000005,
+        Key_Insert = 0x01000006,
+        Key_Delete = 0x01000007,
+        Key_Pause = 0x01000008,
+        Key_Print = 0x01000009,
+        Key_SysReq = 0x0100000a,
+        Key_Clear = 0x0100000b,
+        Key_Home = 0x01000010,
+        Key_End = 0x01000011,
+        Key_Left = 0x01000012,
+        Key_Up = 0x01000013,
+        Key_Right = 0x01000014,
+        Key_Down = 0x01000015,
+        Key_PageUp = 0x01000016,
+        Key_PageDown = 0x01000017,
+        Key_Shift = 0x01000020,
+        Key_Control = 0x01000021,
+        Key_Meta = 0x01000022,
+        Key_Alt = 0x01000023,
+        Key_CapsLock = 0x01000024,
+        Key_NumLock = 0x01000025,
+        Key_ScrollLock = 0x01000026,
+        Key_F1 = 0x01000030,
+        Key_F2 = 0x01000031,
+        Key_F3 = 0x01000032,
+        Key_F4 = 0x01000033,
+        Key_F5 = 0x01000034,
+        Key_F6 = 0x01000035,
+        Key_F7 = 0x01000036,
+        Key_F8 = 0x01000037,
+        Key_F9 = 0x01000038,
+        Key_F10 = 0x01000039,
+        Key_F11 = 0x0100003a,
+        Key_F12 = 0x0100003b,
+        Key_F13 = 0x0100003c,
+        Key_F14 = 0x0100003d,
+        Key_F15 = 0x0100003e,
+        Key_F16 = 0x0100003f,
+        Key_F17 = 0x01000040,
+        Key_F18 = 0x01000041,
+        Key_F19 = 0x01000042,
+        Key_F20 = 0x01000043,
+        Key_F21 = 0x01000044,
+        Key_F22 = 0x01000045,
+        Key_F23 = 0x01000046,
+        Key_F24 = 0x01000047,
+        Key_F25 = 0x01000048,
+        Key_F26 = 0x01000049,
+        Key_F27 = 0x0100004a,
+        Key_F28 = 0x0100004b,
+        Key_F29 = 0x0100004c,
+        Key_F30 = 0x0100004d,
+        Key_F31 = 0x0100004e,
+        Key_F32 = 0x0100004f,
+        Key_F33 = 0x01000050,
+        Key_F34 = 0x01000051,
+        Key_F35 = 0x01000052,
+        Key_Super_L = 0x01000053,
+        Key_Super_R = 0x01000054,
+        Key_Menu = 0x01000055,
+        Key_Hyper_L = 0x01000056,
+        Key_Hyper_R = 0x01000057,
+        Key_Help = 0x01000058,
+        Key_Direction_L = 0x01000059,
+        Key_Direction_R = 0x01000060,
+        Key_Back = 0x01000061,
+        Key_Forward = 0x01000062,
+        Key_Space = 0x20,
+        Key_Exclam = 0x21,
+        Key_QuoteDbl = 0x22,
+        Key_NumberSign = 0x23,
+        Key_Dollar = 0x24,
+        Key_Percent = 0x25,
+        Key_Ampersand = 0x26,
+        Key_Apostrophe = 0x27,
+        Key_ParenLeft = 0x28,
+        Key_ParenRight = 0x29,
+        Key_Asterisk = 0x2a,
+        Key_Plus = 0x2b,
+        Key_Comma = 0x2c,
+        Key_Minus = 0x2d,
+        Key_Period = 0x2e,
+        Key_Slash = 0x2f,
+        Key_0 = 0x30,
+        Key_1 = 0x31,
+        Key_2 = 0x32,
+        Key_3 = 0x33,
+        Key_4 = 0x34,
+        Key_5 = 0x35,
+        Key_6 = 0x36,
+        Key_7 = 0x37,
+        Key_8 = 0x38,
+        Key_9 = 0x39,
+        Key_Colon = 0x3a,
+        Key_Semicolon = 0x3b,
+        Key_Less = 0x3c,
+        Key_Equal = 0x3d,
+        Key_Greater = 0x3e,
+        Key_Question = 0x3f,
+        Key_At = 0x40,
+        Key_A = 0x41,
+        Key_B = 0x42,
+        Key_C = 0x43,
+        Key_D = 0x44,
+        Key_E = 0x45,
+        Key_F = 0x46,
+        Key_G = 0x47,
+        Key_H = 0x48,
+        Key_I = 0x49,
+        Key_J = 0x4a,
+        Key_K = 0x4b,
+        Key_L = 0x4c,
+        Key_M = 0x4d,
+        Key_N = 0x4e,
+        Key_O = 0x4f,
+        Key_P = 0x50,
+        Key_Q = 0x51,
+        Key_R = 0x52,
+        Key_S = 0x53,
+        Key_T = 0x54,
+        Key_U = 0x55,
+        Key_V = 0x56,
+        Key_W = 0x57,
+        Key_X = 0x58,
+        Key_Y = 0x59,
+        Key_Z = 0x5a,
+        Key_BracketLeft = 0x5b,
+        Key_Backslash = 0x5c,
+        Key_BracketRight = 0x5d,
+        Key_AsciiCircum = 0x5e,
+        Key_Underscore = 0x5f,
+        Key_QuoteLeft = 0x60,
+        Key_BraceLeft = 0x7b,
+        Key_Bar = 0x7c,
+        Key_BraceRight = 0x7d,
+        Key_AsciiTilde = 0x7e,
+        Key_unknown = 0x01ffffff,
+    }
+
+    /// This enum type describes the different mouse buttons.
+    #[repr(i32)]
+    enum MouseButton {
+        /// The button state does not refer to any button.
+        NoButton = 0x00000000,
+        /// The left button is pressed, or an event refers to the left button.
+        LeftButton = 0x00000001,
+        /// The right button.
+        RightButton = 0x00000002,
+        /// The middle button.
+        MiddleButton = 0x00000004,
+        /// The 'Back' button, typically present on the 'thumb' side of a mouse with extra buttons.
+        BackButton = 0x00000008,
+        /// The 'Forward' button, typically present beside the 'Back' button.
+        ForwardButton = 0x00000010,
+        /// The 'Task' button.
+        TaskButton = 0x00000020,
+        /// This value corresponds to a mask of all possible mouse buttons.
+        AllButtons = 0x07ffffff,
+    }
+
+    /// This enum type represents the color scheme of the system, for example dark mode.
+    #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+    #[repr(i32)]
+    enum ColorScheme {
+        /// The color scheme is unknown.
+        Unknown,
+        /// Dark text on a light background.
+        Light,
+        /// Light text on a dark background, also known as dark mode.
+        Dark,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
@@ -160,15 +471,70 @@ mod ffi {
         type LayoutDirection;
         type BGMode;
         type ClipOperation;
+        type CursorShape;
         type SizeMode;
+        type AlignmentFlag;
+        type ItemDataRole;
+        type Key;
+        type MouseButton;
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+        type ColorScheme;
     }
 }
 
 pub use ffi::{
-    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, LayoutDirection,
-    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    AlignmentFlag, AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, CursorShape,
+    DateFormat, FillRule, ItemDataRole, Key, LayoutDirection, MouseButton, PenCapStyle,
+    PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec, TransformationMode,
 };
 
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+pub use ffi::ColorScheme;
+
 // Reexport ConnectionType from cxx-qt
 pub use cxx_qt::ConnectionType;
+
+macro_rules! impl_qt_enum_repr {
+    ($($enum:ty),* $(,)?) => {
+        $(
+            impl From<$enum> for i32 {
+                /// The integer value of the enum in Qt
+                fn from(value: $enum) -> Self {
+                    value.repr
+                }
+            }
+
+            impl From<i32> for $enum {
+                /// The enum for the integer value from Qt, values without a variant are preserved
+                fn from(repr: i32) -> Self {
+                    Self { repr }
+                }
+            }
+        )*
+    };
+}
+
+impl_qt_enum_repr!(
+    AlignmentFlag,
+    AspectRatioMode,
+    BGMode,
+    CaseSensitivity,
+    ClipOperation,
+    CursorShape,
+    DateFormat,
+    FillRule,
+    ItemDataRole,
+    Key,
+    LayoutDirection,
+    MouseButton,
+    PenCapStyle,
+    PenJoinStyle,
+    PenStyle,
+    SizeMode,
+    SplitBehaviorFlags,
+    TimeSpec,
+    TransformationMode,
+);
+
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+impl_qt_enum_repr!(ColorScheme);
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qthread.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+void
+qthreadStart(QThread& thread)
+{
+  thread.start();
+}
+
+bool
+qthreadWait(QThread& thread)
+{
+  return thread.wait();
+}
+
+QThread*
+qthreadCurrentThread()
+{
+  return QThread::currentThread();
+}
+
+bool
+qobjectMoveToThread(QObject& object, const QThread& thread)
+{
+  // moveToThread only returns whether the object was moved from Qt 6.7,
+  // so compare the thread affinity afterwards instead
+  auto target = const_cast<QThread*>(&thread);
+  object.moveToThread(target);
+  return object.thread() == target;
+}
+
+QThread*
+qobjectThread(const QObject& object)
+{
+  return object.thread();
+}
+
+}
+}
@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qthread.h");
+        /// The QThread class provides a platform-independent way to manage threads.
+        ///
+        /// A QThread runs its own event loop once it has been started,
+        /// which delivers the events and queued signals of the QObjects that live in the thread.
+        ///
+        /// Note that a QThread must not be destroyed while it is running,
+        /// call [QThread::quit] and then [QThread::wait] before dropping it.
+        #[qobject]
+        type QThread;
+
+        /// This signal is emitted from the associated thread when it starts executing,
+        /// before the event loop is started.
+        #[qsignal]
+        fn started(self: Pin<&mut QThread>);
+
+        /// This signal is emitted from the associated thread right before it finishes executing.
+        ///
+        /// When this signal is emitted, the event loop has already stopped running.
+        /// Connect this signal to `delete_later` of the worker objects to free them in the thread.
+        #[qsignal]
+        fn finished(self: Pin<&mut QThread>);
+    }
+
+    unsafe extern "C++" {
+        /// Tells the thread's event loop to exit with a return code.
+        ///
+        /// By convention, a return code of 0 means success, any non-zero value indicates an error.
+        fn exit(self: Pin<&mut QThread>, return_code: i32);
+
+        /// Returns true if the thread is finished, otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Returns true if the task running on this thread should be stopped.
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running, otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// Equivalent to calling [QThread::exit] with 0.
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread.
+        ///
+        /// This request is advisory and it is up to code running on the thread to decide
+        /// if and how it should act upon such request, see [QThread::is_interruption_requested].
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+    }
+
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        #[doc(hidden)]
+        type QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+        #[doc(hidden)]
+        #[rust_name = "qthread_start"]
+        fn qthreadStart(thread: Pin<&mut QThread>);
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+        #[doc(hidden)]
+        #[rust_name = "qobject_move_to_thread"]
+        fn qobjectMoveToThread(object: Pin<&mut QObject>, thread: &QThread) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_thread"]
+        fn qobjectThread(object: &QObject) -> *mut QThread;
+    }
+
+    // QThread is not a trivial to CXX and is not relocatable in Qt
+    // as it is a QObject. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    impl UniquePtr<QThread> {}
+}
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Create a new QThread, which is not started yet
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Returns a pointer to the QThread which manages the currently executing thread.
+    pub fn current_thread() -> *mut Self {
+        ffi::qthread_current_thread()
+    }
+
+    /// Returns true if this thread is the currently executing thread, otherwise returns false.
+    pub fn is_current_thread(&self) -> bool {
+        core::ptr::eq(self, Self::current_thread())
+    }
+
+    /// Begins execution of the thread, which runs its own event loop.
+    ///
+    /// The [QThread::started] signal is emitted when the thread starts executing.
+    /// If the thread is already running, this function does nothing.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qthread_start(self);
+    }
+
+    /// Blocks until the thread has finished execution, for example after [QThread::quit] was called.
+    ///
+    /// Returns true if the thread has finished or has not been started yet.
+    pub fn wait(self: Pin<&mut Self>) -> bool {
+        ffi::qthread_wait(self)
+    }
+}
+
+/// Changes the thread affinity of QObjects generated by CXX-Qt, see
+/// [`QObject::moveToThread`](https://doc.qt.io/qt-6/qobject.html#moveToThread).
+///
+/// This allows for the worker-object pattern of Qt, where a QObject is moved to a [QThread]
+/// and its slots are invoked through queued connections in the event loop of that thread.
+///
+/// ```rust,ignore
+/// use cxx_qt_lib::{MoveToThread, QThread};
+///
+/// let mut thread = QThread::new();
+/// // The worker must not have a parent to be moved
+/// let worker = qobject::Worker::new_cpp_object();
+/// worker.pin_mut().move_to_thread(&thread);
+/// thread.pin_mut().start();
+/// ```
+pub trait MoveToThread {
+    /// Changes the thread affinity of this object and its children to the given thread.
+    ///
+    /// The object cannot be moved if it has a parent, or if it is not called from the thread the
+    /// object currently lives in. Returns whether the object was moved to the thread.
+    fn move_to_thread(self: Pin<&mut Self>, thread: &QThread) -> bool;
+}
+
+impl<T> MoveToThread for T
+where
+    T: CxxQtType,
+{
+    fn move_to_thread(self: Pin<&mut Self>, thread: &QThread) -> bool {
+        // Safety:
+        //
+        // QObjects generated by CXX-Qt have their QObject base class as their first base class,
+        // so a pointer to T is also a pointer to the QObject, and it is not moved.
+        let object = unsafe {
+            Pin::new_unchecked(&mut *(self.get_unchecked_mut() as *mut T).cast::<ffi::QObject>())
+        };
+        ffi::qobject_move_to_thread(object, thread)
+    }
+}
+
+/// Introspects the thread affinity of QObjects generated by CXX-Qt, see
+/// [`QObject::thread`](https://doc.qt.io/qt-6/qobject.html#thread).
+///
+/// This helps to diagnose a QObject, or one of its properties, being accessed from a thread
+/// which the QObject does not live in, see also [assert_on_object_thread](crate::assert_on_object_thread).
+pub trait ThreadAffinity {
+    /// Returns a pointer to the thread in which the object lives.
+    fn thread(&self) -> *mut QThread;
+
+    /// Returns true if the object lives in the currently executing thread, otherwise returns false.
+    fn is_on_current_thread(&self) -> bool {
+        core::ptr::eq(self.thread(), QThread::current_thread())
+    }
+}
+
+impl<T> ThreadAffinity for T
+where
+    T: CxxQtType,
+{
+    fn thread(&self) -> *mut QThread {
+        // Safety:
+        //
+        // QObjects generated by CXX-Qt have their QObject base class as their first base class,
+        // so a pointer to T is also a pointer to the QObject.
+        let object = unsafe { &*(self as *const T).cast::<ffi::QObject>() };
+        ffi::qobject_thread(object)
+    }
+}
+
+/// Assert in debug builds that a QObject generated by CXX-Qt is accessed from the thread which it lives in
+///
+/// The object can be anything which dereferences to the QObject, such as `self` in a method of the QObject.
+/// When the assertion fails, the message names the expression of the object.
+///
+/// ```ignore
+/// impl qobject::MyObject {
+///     fn set_value(self: Pin<&mut Self>, value: i32) {
+///         cxx_qt_lib::assert_on_object_thread!(self);
+///         self.rust_mut().value = value;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_on_object_thread {
+    ($object:expr) => {
+        debug_assert!(
+            $crate::ThreadAffinity::is_on_current_thread(&*$object),
+            "`{}` is accessed from a thread which it does not live in",
+            stringify!($object)
+        )
+    };
+}
@@ -4,6 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx::{type_id, ExternType};
+use std::fmt;
 use std::mem::MaybeUninit;
 
 #[cxx::bridge]
@@ -11,6 +12,8 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qvariant.h");
         type QVariant = super::QVariant;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
 
         /// Convert this variant to type QMetaType::UnknownType and free up any resources used.
         fn clear(self: &mut QVariant);
@@ -20,6 +23,11 @@ mod ffi {
         /// Returns true if the storage type of this variant is not QMetaType::UnknownType; otherwise returns false.
         #[rust_name = "is_valid"]
         fn isValid(self: &QVariant) -> bool;
+        /// Returns the QMetaType id of the value stored in the variant.
+        ///
+        /// This is the same as `typeId()` in Qt 6, but is also available in Qt 5.
+        #[rust_name = "user_type"]
+        fn userType(self: &QVariant) -> i32;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -38,6 +46,16 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qvariant_eq"]
         fn operatorEq(a: &QVariant, b: &QVariant) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qvariant_to_debug_qstring"]
+        fn toQString(value: &QVariant) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qvariant_type_name"]
+        fn qvariantTypeName(variant: &QVariant) -> QString;
     }
 }
 
@@ -122,6 +140,13 @@ impl QVariant {
     pub fn value_or_default<T: QVariantValue>(&self) -> T {
         T::value_or_default(self)
     }
+
+    /// Returns the name of the type stored in the variant, eg `int` or `QString`.
+    ///
+    /// An empty string is returned for an invalid variant.
+    pub fn type_name(&self) -> crate::QString {
+        ffi::qvariant_type_name(self)
+    }
 }
 
 impl std::cmp::PartialEq for QVariant {
@@ -130,6 +155,13 @@ impl std::cmp::PartialEq for QVariant {
     }
 }
 
+impl fmt::Debug for QVariant {
+    /// Formats the variant as Qt's debug output does, eg `QVariant(int, 5)`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qvariant_to_debug_qstring(self))
+    }
+}
+
 pub trait QVariantValue {
     fn can_convert(variant: &QVariant) -> bool;
     fn construct(value: &Self) -> QVariant;
@@ -166,6 +198,8 @@ impl_qvariant_value!(i64, qvariant_i64);
 impl_qvariant_value!(crate::QByteArray, qvariant_qbytearray);
 #[cfg(feature = "qt_gui")]
 impl_qvariant_value!(crate::QColor, qvariant_qcolor);
+#[cfg(feature = "qt_gui")]
+impl_qvariant_value!(crate::QIcon, qvariant_qicon);
 impl_qvariant_value!(crate::QDate, qvariant_qdate);
 #[cfg(not(target_os = "emscripten"))]
 impl_qvariant_value!(crate::QDateTime, qvariant_qdatetime);
@@ -180,6 +214,7 @@ impl_qvariant_value!(crate::QSizeF, qvariant_qsizef);
 impl_qvariant_value!(crate::QString, qvariant_qstring);
 impl_qvariant_value!(crate::QStringList, qvariant_qstringlist);
 impl_qvariant_value!(crate::QTime, qvariant_qtime);
+impl_qvariant_value!(crate::QVariantMap, qvariant_qvariantmap);
 impl_qvariant_value!(crate::QUrl, qvariant_qurl);
 impl_qvariant_value!(u8, qvariant_u8);
 impl_qvariant_value!(u16, qvariant_u16);
@@ -68,6 +68,7 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::int64_t, I64)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QByteArray, QByteArray)
 #ifdef CXX_QT_GUI_FEATURE
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QColor, QColor)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QIcon, QIcon)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDate, QDate)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDateTime, QDateTime)
@@ -83,11 +84,19 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QString, QString)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QStringList, QStringList)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QTime, QTime)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QUrl, QUrl)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QVariantMap, QVariantMap)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint8_t, U8)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint16_t, U16)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint32_t, U32)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint64_t, U64)
 
+QString
+qvariantTypeName(const QVariant& variant)
+{
+  // typeName returns nullptr for an invalid variant
+  return QString::fromLatin1(variant.typeName());
+}
+
 }
 }
 }
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qicon.h");
+        type QIcon = crate::QIcon;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QIcon"]
+        fn qvariantCanConvertQIcon(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QIcon"]
+        fn qvariantConstruct(value: &QIcon) -> QVariant;
+        #[rust_name = "value_or_default_QIcon"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QIcon;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QIcon(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QIcon) -> ffi::QVariant {
+    ffi::construct_QIcon(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QIcon {
+    ffi::value_or_default_QIcon(variant)
+}
@@ -8,8 +8,9 @@ use crate::QColor;
 #[cfg(not(target_os = "emscripten"))]
 use crate::QDateTime;
 use crate::{
-    QByteArray, QDate, QLine, QLineF, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF,
-    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant,
+    QByteArray, QDate, QLine, QLineF, QList, QListElement, QMargins, QMarginsF,
+    QPersistentModelIndex, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl,
+    QVariant,
 };
 use core::{marker::PhantomData, mem::MaybeUninit};
 use cxx::{type_id, ExternType};
@@ -183,6 +184,23 @@ where
     }
 }
 
+impl<T> From<&QList<T>> for QVector<T>
+where
+    T: QVectorElement + QListElement + Clone,
+{
+    /// Convert a reference to a [QList] into a [QVector] by making a deep copy of the data.
+    ///
+    /// In Qt 6 QVector is an alias of QList, this allows for code that works with both Qt 5 and Qt 6.
+    fn from(qlist: &QList<T>) -> Self {
+        let mut qvec = Self::default();
+        qvec.reserve(qlist.len());
+        for element in qlist.iter() {
+            qvec.append_clone(element);
+        }
+        qvec
+    }
+}
+
 impl<T, S> From<S> for QVector<T>
 where
     T: QVectorElement + Clone,
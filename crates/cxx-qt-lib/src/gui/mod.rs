@@ -3,9 +3,17 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+#[cfg(cxxqt_qt_version_at_least_6)]
+mod qaction;
+#[cfg(cxxqt_qt_version_at_least_6)]
+pub use qaction::QAction;
+
 mod qcolor;
 pub use qcolor::{QColor, QColorNameFormat, QColorSpec};
 
+mod qcursor;
+pub use qcursor::QCursor;
+
 mod qguiapplication;
 pub use qguiapplication::QGuiApplication;
 
@@ -18,6 +26,9 @@ pub use qvector3d::QVector3D;
 mod qvector4d;
 pub use qvector4d::QVector4D;
 
+mod qicon;
+pub use qicon::{QIcon, QIconMode, QIconState};
+
 mod qimage;
 pub use qimage::{QImage, QImageFormat, QImageInvertMode};
 
@@ -27,6 +38,9 @@ pub use qpolygon::QPolygon;
 mod qpolygonf;
 pub use qpolygonf::QPolygonF;
 
+mod qpalette;
+pub use qpalette::{QPalette, QPaletteColorGroup, QPaletteColorRole};
+
 mod qpen;
 pub use qpen::QPen;
 
@@ -44,3 +58,9 @@ pub use qpainter::{QPainter, QPainterCompositionMode, QPainterRenderHint};
 
 mod qregion;
 pub use qregion::QRegion;
+
+mod qtextcharformat;
+pub use qtextcharformat::QTextCharFormat;
+
+mod qvalidator;
+pub use qvalidator::QValidatorState;
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qaction.h"
+
+#include <QtGui/QKeySequence>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAction>
+qactionNew(const QString& text)
+{
+  return ::std::make_unique<QAction>(text);
+}
+
+void
+qactionSetShortcut(QAction& action, const QString& shortcut)
+{
+  action.setShortcut(
+    QKeySequence::fromString(shortcut, QKeySequence::PortableText));
+}
+
+QString
+qactionShortcut(const QAction& action)
+{
+  return action.shortcut().toString(QKeySequence::PortableText);
+}
+
+}
+}
@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qaction.h");
+        /// The QAction class provides an abstraction for user commands that can be added to different user interface components.
+        ///
+        /// Note that QAction is only available with Qt 6, as it is part of QtWidgets in Qt 5.
+        #[qobject]
+        type QAction;
+
+        /// This signal is emitted whenever an action changes.
+        #[qsignal]
+        fn changed(self: Pin<&mut QAction>);
+
+        /// This signal is emitted whenever a checkable action changes its checked status.
+        #[qsignal]
+        fn toggled(self: Pin<&mut QAction>, checked: bool);
+
+        /// This signal is emitted when an action is activated by the user,
+        /// for example when the user clicks a menu option or presses the shortcut key combination.
+        ///
+        /// If the action is checkable, checked is true if the action is checked, or false if the action is unchecked.
+        #[qsignal]
+        fn triggered(self: Pin<&mut QAction>, checked: bool);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qicon.h");
+        type QIcon = crate::QIcon;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// Returns the user data of the action.
+        fn data(self: &QAction) -> QVariant;
+
+        /// Returns the icon of the action.
+        fn icon(self: &QAction) -> QIcon;
+
+        /// Returns whether the action is a checkable action.
+        #[rust_name = "is_checkable"]
+        fn isCheckable(self: &QAction) -> bool;
+
+        /// Returns whether the action is checked.
+        #[rust_name = "is_checked"]
+        fn isChecked(self: &QAction) -> bool;
+
+        /// Returns whether the action is enabled.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QAction) -> bool;
+
+        /// Returns whether the action can be seen, for example in menus and toolbars.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QAction) -> bool;
+
+        /// Sets whether the action is a checkable action.
+        #[rust_name = "set_checkable"]
+        fn setCheckable(self: Pin<&mut QAction>, checkable: bool);
+
+        /// Sets whether the action is checked, this only has an effect for checkable actions.
+        #[rust_name = "set_checked"]
+        fn setChecked(self: Pin<&mut QAction>, checked: bool);
+
+        /// Sets the user data of the action to data.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QAction>, data: &QVariant);
+
+        /// Sets whether the action is enabled.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QAction>, enabled: bool);
+
+        /// Sets the icon of the action.
+        #[rust_name = "set_icon"]
+        fn setIcon(self: Pin<&mut QAction>, icon: &QIcon);
+
+        /// Sets the descriptive text of the action.
+        #[rust_name = "set_text"]
+        fn setText(self: Pin<&mut QAction>, text: &QString);
+
+        /// Sets the tooltip of the action.
+        #[rust_name = "set_tool_tip"]
+        fn setToolTip(self: Pin<&mut QAction>, tip: &QString);
+
+        /// Sets whether the action can be seen, for example in menus and toolbars.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QAction>, visible: bool);
+
+        /// Returns the descriptive text of the action.
+        fn text(self: &QAction) -> QString;
+
+        /// This is a convenience function for the checked property, it toggles the checked state.
+        fn toggle(self: Pin<&mut QAction>);
+
+        /// Returns the tooltip of the action, which defaults to the text of the action.
+        #[rust_name = "tool_tip"]
+        fn toolTip(self: &QAction) -> QString;
+
+        /// Activates the action, this emits the triggered signal.
+        fn trigger(self: Pin<&mut QAction>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qaction_new"]
+        fn qactionNew(text: &QString) -> UniquePtr<QAction>;
+        #[doc(hidden)]
+        #[rust_name = "qaction_set_shortcut"]
+        fn qactionSetShortcut(action: Pin<&mut QAction>, shortcut: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qaction_shortcut"]
+        fn qactionShortcut(action: &QAction) -> QString;
+    }
+
+    // QAction is not a trivial to CXX and is not relocatable in Qt
+    // as it is a QObject. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    impl UniquePtr<QAction> {}
+}
+
+use crate::QString;
+use core::pin::Pin;
+
+pub use ffi::QAction;
+
+impl QAction {
+    /// Create a new QAction with the given descriptive text
+    pub fn new(text: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qaction_new(text)
+    }
+
+    /// Sets the primary shortcut of the action, in the portable text format of QKeySequence, for example "Ctrl+S".
+    ///
+    /// An empty string removes the shortcut.
+    pub fn set_shortcut(self: Pin<&mut Self>, shortcut: &QString) {
+        ffi::qaction_set_shortcut(self, shortcut);
+    }
+
+    /// Returns the primary shortcut of the action, in the portable text format of QKeySequence.
+    pub fn shortcut(&self) -> QString {
+        ffi::qaction_shortcut(self)
+    }
+}
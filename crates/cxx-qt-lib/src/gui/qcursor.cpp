@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qcursor.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QCursor has a single d pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/kernel/qcursor.h?h=v5.15.6-lts-lgpl#n129
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/kernel/qcursor.h?h=v6.2.4#n102
+assert_alignment_and_size(QCursor, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QCursor>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCursor>::value);
+
+static_assert(!::std::is_trivially_destructible<QCursor>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPoint
+qcursorPos()
+{
+  return QCursor::pos();
+}
+
+void
+qcursorSetPos(const QPoint& pos)
+{
+  QCursor::setPos(pos);
+}
+
+}
+}
@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type CursorShape = crate::CursorShape;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcursor.h");
+        type QCursor = super::QCursor;
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+
+        /// Sets the cursor to the shape identified by shape.
+        #[rust_name = "set_shape"]
+        fn setShape(self: &mut QCursor, shape: CursorShape);
+
+        /// Returns the cursor shape identifier.
+        fn shape(self: &QCursor) -> CursorShape;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcursor_init_default"]
+        fn construct() -> QCursor;
+        #[doc(hidden)]
+        #[rust_name = "qcursor_init_from_cursorshape"]
+        fn construct(shape: &CursorShape) -> QCursor;
+        #[doc(hidden)]
+        #[rust_name = "qcursor_drop"]
+        fn drop(cursor: &mut QCursor);
+        #[doc(hidden)]
+        #[rust_name = "qcursor_clone"]
+        fn construct(cursor: &QCursor) -> QCursor;
+
+        #[doc(hidden)]
+        #[rust_name = "qcursor_pos"]
+        fn qcursorPos() -> QPoint;
+        #[doc(hidden)]
+        #[rust_name = "qcursor_set_pos"]
+        fn qcursorSetPos(pos: &QPoint);
+    }
+}
+
+/// The QCursor class provides a mouse cursor with an arbitrary shape.
+///
+/// The cursor of a window or item can be changed to a cursor while a drag is in progress,
+/// or the cursor of the application can be overridden with `QGuiApplication::set_override_cursor`.
+#[repr(C)]
+pub struct QCursor {
+    _d: MaybeUninit<usize>,
+}
+
+impl Default for QCursor {
+    /// Constructs a cursor with the default arrow shape.
+    fn default() -> Self {
+        ffi::qcursor_init_default()
+    }
+}
+
+impl Drop for QCursor {
+    fn drop(&mut self) {
+        ffi::qcursor_drop(self);
+    }
+}
+
+impl Clone for QCursor {
+    fn clone(&self) -> Self {
+        ffi::qcursor_clone(self)
+    }
+}
+
+impl From<&ffi::CursorShape> for QCursor {
+    /// Constructs a cursor with the specified shape.
+    fn from(shape: &ffi::CursorShape) -> Self {
+        ffi::qcursor_init_from_cursorshape(shape)
+    }
+}
+
+impl QCursor {
+    /// Returns the position of the cursor (hot spot) of the primary screen in global screen coordinates.
+    pub fn pos() -> ffi::QPoint {
+        ffi::qcursor_pos()
+    }
+
+    /// Moves the cursor (hot spot) of the primary screen to the global screen position.
+    pub fn set_pos(pos: &ffi::QPoint) {
+        ffi::qcursor_set_pos(pos)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCursor {
+    type Id = type_id!("QCursor");
+    type Kind = cxx::kind::Trivial;
+}
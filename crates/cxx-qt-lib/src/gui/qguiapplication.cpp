@@ -10,6 +10,10 @@
 
 #include "cxx-qt-lib/qcoreapplication.h"
 
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+#include <QtGui/QStyleHints>
+#endif
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -41,5 +45,59 @@ qguiapplicationFont(const QGuiApplication& app)
   return app.font();
 }
 
+void
+qguiapplicationSetPalette(QGuiApplication& app, const QPalette& palette)
+{
+  app.setPalette(palette);
+}
+
+QPalette
+qguiapplicationPalette(const QGuiApplication& app)
+{
+  return app.palette();
+}
+
+void
+qguiapplicationChangeOverrideCursor(QGuiApplication& app, const QCursor& cursor)
+{
+  app.changeOverrideCursor(cursor);
+}
+
+bool
+qguiapplicationHasOverrideCursor(const QGuiApplication& app)
+{
+  return app.overrideCursor() != nullptr;
+}
+
+QCursor
+qguiapplicationOverrideCursor(const QGuiApplication& app)
+{
+  if (const auto cursor = app.overrideCursor()) {
+    return *cursor;
+  }
+
+  return QCursor();
+}
+
+void
+qguiapplicationRestoreOverrideCursor(QGuiApplication& app)
+{
+  app.restoreOverrideCursor();
+}
+
+void
+qguiapplicationSetOverrideCursor(QGuiApplication& app, const QCursor& cursor)
+{
+  app.setOverrideCursor(cursor);
+}
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+Qt::ColorScheme
+qguiapplicationColorScheme(const QGuiApplication& app)
+{
+  return app.styleHints()->colorScheme();
+}
+#endif
+
 }
 }
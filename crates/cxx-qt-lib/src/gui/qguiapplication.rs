@@ -4,7 +4,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QFont, QString, QStringList, QVector};
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+use crate::ColorScheme;
+use crate::{QByteArray, QCursor, QFont, QPalette, QString, QStringList, QVector};
 use core::pin::Pin;
 
 #[cxx::bridge]
@@ -20,6 +22,10 @@ mod ffi {
         type QVector_QByteArray = crate::QVector<QByteArray>;
         include!("cxx-qt-lib/qfont.h");
         type QFont = crate::QFont;
+        include!("cxx-qt-lib/qpalette.h");
+        type QPalette = crate::QPalette;
+        include!("cxx-qt-lib/qcursor.h");
+        type QCursor = crate::QCursor;
 
         include!("cxx-qt-lib/qguiapplication.h");
         type QGuiApplication;
@@ -75,6 +81,27 @@ mod ffi {
         #[rust_name = "qguiapplication_font"]
         fn qguiapplicationFont(app: &QGuiApplication) -> QFont;
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_set_palette"]
+        fn qguiapplicationSetPalette(app: Pin<&mut QGuiApplication>, palette: &QPalette);
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_palette"]
+        fn qguiapplicationPalette(app: &QGuiApplication) -> QPalette;
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_change_override_cursor"]
+        fn qguiapplicationChangeOverrideCursor(app: Pin<&mut QGuiApplication>, cursor: &QCursor);
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_has_override_cursor"]
+        fn qguiapplicationHasOverrideCursor(app: &QGuiApplication) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_override_cursor"]
+        fn qguiapplicationOverrideCursor(app: &QGuiApplication) -> QCursor;
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_restore_override_cursor"]
+        fn qguiapplicationRestoreOverrideCursor(app: Pin<&mut QGuiApplication>);
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_set_override_cursor"]
+        fn qguiapplicationSetOverrideCursor(app: Pin<&mut QGuiApplication>, cursor: &QCursor);
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_set_library_paths"]
         fn qapplicationSetLibraryPaths(app: Pin<&mut QGuiApplication>, paths: &QStringList);
         #[doc(hidden)]
@@ -85,6 +112,21 @@ mod ffi {
         fn qapplicationSetOrganizationName(app: Pin<&mut QGuiApplication>, name: &QString);
     }
 
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+        type ColorScheme = crate::ColorScheme;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_color_scheme"]
+        fn qguiapplicationColorScheme(app: &QGuiApplication) -> ColorScheme;
+    }
+
     // QGuiApplication is not a trivial to CXX and is not relocatable in Qt
     // as the following fails in C++. So we cannot mark it as a trivial type
     // and need to use references or pointers.
@@ -123,6 +165,21 @@ impl QGuiApplication {
         ffi::qguiapplication_font(self)
     }
 
+    /// Changes the currently active application override cursor to cursor.
+    ///
+    /// This function has no effect if set_override_cursor() was not called.
+    pub fn change_override_cursor(self: Pin<&mut Self>, cursor: &QCursor) {
+        ffi::qguiapplication_change_override_cursor(self, cursor);
+    }
+
+    /// Returns the color scheme of the system, for example whether dark mode is enabled.
+    ///
+    /// This is only available with Qt 6.5 or later, when the color scheme is unknown [ColorScheme::Unknown] is returned.
+    #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+    pub fn color_scheme(&self) -> ColorScheme {
+        ffi::qguiapplication_color_scheme(self)
+    }
+
     /// Returns a list of paths that the application will search when dynamically loading libraries.
     pub fn library_paths(&self) -> QStringList {
         ffi::qguiapplication_library_paths(self)
@@ -153,6 +210,17 @@ impl QGuiApplication {
         ffi::qguiapplication_new(&vector)
     }
 
+    /// Returns the active application override cursor.
+    ///
+    /// If no application cursor has been defined, `None` is returned.
+    pub fn override_cursor(&self) -> Option<QCursor> {
+        if ffi::qguiapplication_has_override_cursor(self) {
+            Some(ffi::qguiapplication_override_cursor(self))
+        } else {
+            None
+        }
+    }
+
     /// The Internet domain of the organization that wrote this application
     pub fn organization_domain(&self) -> QString {
         ffi::qguiapplication_organization_domain(self)
@@ -163,6 +231,19 @@ impl QGuiApplication {
         ffi::qguiapplication_organization_name(self)
     }
 
+    /// Returns the default application palette, which follows the system colors unless it has been changed.
+    pub fn palette(&self) -> QPalette {
+        ffi::qguiapplication_palette(self)
+    }
+
+    /// Undoes the last set_override_cursor().
+    ///
+    /// If set_override_cursor() has been called twice, calling restore_override_cursor() will activate the first cursor set.
+    /// Calling this function a second time restores the original widgets' cursors.
+    pub fn restore_override_cursor(self: Pin<&mut Self>) {
+        ffi::qguiapplication_restore_override_cursor(self);
+    }
+
     /// Set the name of this application
     pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qguiapplication_set_application_name(self, name);
@@ -183,6 +264,19 @@ impl QGuiApplication {
         ffi::qguiapplication_set_font(self, font);
     }
 
+    /// Changes the default application palette to palette.
+    pub fn set_palette(self: Pin<&mut Self>, palette: &QPalette) {
+        ffi::qguiapplication_set_palette(self, palette);
+    }
+
+    /// Sets the application override cursor to cursor, for example a wait cursor during a long operation.
+    ///
+    /// Application override cursors are intended for showing the user that the application is in a special state.
+    /// Every call must be matched by a call to restore_override_cursor(), as the cursors are stored on an internal stack.
+    pub fn set_override_cursor(self: Pin<&mut Self>, cursor: &QCursor) {
+        ffi::qguiapplication_set_override_cursor(self, cursor);
+    }
+
     /// Sets the list of directories to search when loading plugins with QLibrary to paths.
     /// All existing paths will be deleted and the path list will consist of the paths given in paths and the path to the application.
     pub fn set_library_paths(self: Pin<&mut Self>, paths: &QStringList) {
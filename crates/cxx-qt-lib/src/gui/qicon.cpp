@@ -0,0 +1,69 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qicon.h"
+
+#include <QtGui/QPixmap>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QIcon has a single d pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qicon.h?h=v5.15.6-lts-lgpl#n134
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qicon.h?h=v6.2.4#n119
+assert_alignment_and_size(QIcon, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QIcon>::value);
+static_assert(!::std::is_trivially_copy_constructible<QIcon>::value);
+
+static_assert(!::std::is_trivially_destructible<QIcon>::value);
+
+static_assert(QTypeInfo<QIcon>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QIcon
+qiconFromQImage(const QImage& image)
+{
+  return QIcon(QPixmap::fromImage(image));
+}
+
+QIcon
+qiconFromTheme(const QString& name)
+{
+  return QIcon::fromTheme(name);
+}
+
+bool
+qiconHasThemeIcon(const QString& name)
+{
+  return QIcon::hasThemeIcon(name);
+}
+
+void
+qiconSetThemeName(const QString& name)
+{
+  QIcon::setThemeName(name);
+}
+
+QString
+qiconThemeName()
+{
+  return QIcon::themeName();
+}
+
+QImage
+qiconToQImage(const QIcon& icon,
+              const QSize& size,
+              QIconMode mode,
+              QIconState state)
+{
+  return icon.pixmap(size, mode, state).toImage();
+}
+
+}
+}
@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type describes the mode for which a pixmap is intended to be used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIconMode {
+        /// Display the pixmap when the user is not interacting with the icon,
+        /// but the functionality represented by the icon is available.
+        Normal,
+        /// Display the pixmap when the functionality represented by the icon is not available.
+        Disabled,
+        /// Display the pixmap when the functionality represented by the icon is available
+        /// and the user is interacting with the icon, for example, moving the mouse over it or clicking it.
+        Active,
+        /// Display the pixmap when the item represented by the icon is selected.
+        Selected,
+    }
+
+    /// This enum describes the state for which a pixmap is intended to be used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIconState {
+        /// Display the pixmap when the widget is in an "on" state
+        On,
+        /// Display the pixmap when the widget is in an "off" state
+        Off,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qicon.h");
+        type QIcon = super::QIcon;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QSize = crate::QList<crate::QSize>;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Adds an image from the file with the given file name to the icon, as a specialization for size, mode and state.
+        ///
+        /// If size is invalid the size is read from the file.
+        #[rust_name = "add_file"]
+        fn addFile(
+            self: &mut QIcon,
+            file_name: &QString,
+            size: &QSize,
+            mode: QIconMode,
+            state: QIconState,
+        );
+
+        /// Returns a list of available icon sizes for the specified mode and state.
+        #[rust_name = "available_sizes"]
+        fn availableSizes(self: &QIcon, mode: QIconMode, state: QIconState) -> QList_QSize;
+
+        /// Returns a number that identifies the contents of this QIcon object.
+        /// Distinct QIcon objects can have the same key if they refer to the same contents.
+        #[rust_name = "cache_key"]
+        fn cacheKey(self: &QIcon) -> i64;
+
+        /// Returns true if the icon is empty; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QIcon) -> bool;
+
+        /// Returns the name used to create the icon, if available.
+        fn name(self: &QIcon) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QIconMode;
+        type QIconState;
+
+        #[doc(hidden)]
+        #[rust_name = "qicon_init_default"]
+        fn construct() -> QIcon;
+        #[doc(hidden)]
+        #[rust_name = "qicon_init_from_qstring"]
+        fn construct(file_name: &QString) -> QIcon;
+        #[doc(hidden)]
+        #[rust_name = "qicon_drop"]
+        fn drop(icon: &mut QIcon);
+        #[doc(hidden)]
+        #[rust_name = "qicon_clone"]
+        fn construct(icon: &QIcon) -> QIcon;
+
+        #[doc(hidden)]
+        #[rust_name = "qicon_from_qimage"]
+        fn qiconFromQImage(image: &QImage) -> QIcon;
+        #[doc(hidden)]
+        #[rust_name = "qicon_from_theme"]
+        fn qiconFromTheme(name: &QString) -> QIcon;
+        #[doc(hidden)]
+        #[rust_name = "qicon_has_theme_icon"]
+        fn qiconHasThemeIcon(name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qicon_set_theme_name"]
+        fn qiconSetThemeName(name: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qicon_theme_name"]
+        fn qiconThemeName() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qicon_to_qimage"]
+        fn qiconToQImage(icon: &QIcon, size: &QSize, mode: QIconMode, state: QIconState) -> QImage;
+    }
+}
+
+pub use ffi::{QIconMode, QIconState};
+
+/// The QIcon class provides scalable icons in different modes and states.
+///
+/// Icons can be stored in a [QVariant](crate::QVariant), for example to return them from the decoration role of a model.
+#[repr(C)]
+pub struct QIcon {
+    _d: MaybeUninit<usize>,
+}
+
+impl Default for QIcon {
+    /// Constructs a null icon.
+    fn default() -> Self {
+        ffi::qicon_init_default()
+    }
+}
+
+impl Drop for QIcon {
+    fn drop(&mut self) {
+        ffi::qicon_drop(self);
+    }
+}
+
+impl Clone for QIcon {
+    fn clone(&self) -> Self {
+        ffi::qicon_clone(self)
+    }
+}
+
+impl From<&ffi::QString> for QIcon {
+    /// Constructs an icon from the file with the given file name, which can be a Qt resource path.
+    ///
+    /// The file is loaded on demand, if it does not exist or is of an unknown type the icon is empty.
+    fn from(file_name: &ffi::QString) -> Self {
+        ffi::qicon_init_from_qstring(file_name)
+    }
+}
+
+impl From<&ffi::QImage> for QIcon {
+    /// Constructs an icon from an image, which is converted to a pixmap.
+    fn from(image: &ffi::QImage) -> Self {
+        ffi::qicon_from_qimage(image)
+    }
+}
+
+impl QIcon {
+    /// Returns the icon with the given name in the current icon theme.
+    ///
+    /// If no such icon is found in the current theme, `None` is returned.
+    pub fn from_theme(name: &ffi::QString) -> Option<Self> {
+        let icon = ffi::qicon_from_theme(name);
+        if icon.is_null() {
+            None
+        } else {
+            Some(icon)
+        }
+    }
+
+    /// Returns true if there is an icon available for name in the current icon theme, otherwise returns false.
+    pub fn has_theme_icon(name: &ffi::QString) -> bool {
+        ffi::qicon_has_theme_icon(name)
+    }
+
+    /// Sets the current icon theme to name.
+    pub fn set_theme_name(name: &ffi::QString) {
+        ffi::qicon_set_theme_name(name)
+    }
+
+    /// Returns the name of the current icon theme.
+    pub fn theme_name() -> ffi::QString {
+        ffi::qicon_theme_name()
+    }
+
+    /// Returns an image with the requested size, mode, and state, generating one if necessary.
+    ///
+    /// The image might be smaller than requested, but never larger.
+    pub fn to_image(&self, size: &ffi::QSize, mode: QIconMode, state: QIconState) -> ffi::QImage {
+        ffi::qicon_to_qimage(self, size, mode, state)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QIcon {
+    type Id = type_id!("QIcon");
+    type Kind = cxx::kind::Trivial;
+}
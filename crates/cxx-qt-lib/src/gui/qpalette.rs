@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the different color groups of a palette.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPaletteColorGroup {
+        /// Used for the window which has keyboard focus.
+        Active = 0,
+        /// Used for widgets (not windows) that are disabled for some reason.
+        Disabled = 1,
+        /// Used for windows which do not have keyboard focus.
+        Inactive = 2,
+    }
+
+    /// This enum describes the different roles of colors of a palette.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPaletteColorRole {
+        /// A general foreground color.
+        WindowText = 0,
+        /// The general button background color.
+        Button = 1,
+        /// Lighter than Button color.
+        Light = 2,
+        /// Between Button and Light.
+        Midlight = 3,
+        /// Darker than Button.
+        Dark = 4,
+        /// Between Button and Dark.
+        Mid = 5,
+        /// The foreground color used with Base.
+        Text = 6,
+        /// A text color that is very different from WindowText, and contrasts well with e.g. Dark.
+        BrightText = 7,
+        /// A foreground color used with the Button color.
+        ButtonText = 8,
+        /// Used mostly as the background color for text entry widgets.
+        Base = 9,
+        /// A general background color.
+        Window = 10,
+        /// A very dark color.
+        Shadow = 11,
+        /// A color to indicate a selected item or the current item.
+        Highlight = 12,
+        /// A text color that contrasts with Highlight.
+        HighlightedText = 13,
+        /// A text color used for unvisited hyperlinks.
+        Link = 14,
+        /// A text color used for already visited hyperlinks.
+        LinkVisited = 15,
+        /// Used as the alternate background color in views with alternating row colors.
+        AlternateBase = 16,
+        /// No role; this special role is often used to indicate that a role has not been assigned.
+        NoRole = 17,
+        /// Used as the background color for tooltips.
+        ToolTipBase = 18,
+        /// Used as the foreground color for tooltips.
+        ToolTipText = 19,
+        /// Used as the placeholder color for various text input widgets.
+        PlaceholderText = 20,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpalette.h");
+        type QPalette = super::QPalette;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+
+        /// Returns a number that identifies the contents of this palette.
+        /// If two palettes have the same cache key, they are guaranteed to be equal.
+        #[rust_name = "cache_key"]
+        fn cacheKey(self: &QPalette) -> i64;
+
+        /// Returns the color in the specified color group, used for the given color role.
+        fn color<'a>(
+            self: &'a QPalette,
+            group: QPaletteColorGroup,
+            role: QPaletteColorRole,
+        ) -> &'a QColor;
+
+        /// Returns the palette's current color group.
+        #[rust_name = "current_color_group"]
+        fn currentColorGroup(self: &QPalette) -> QPaletteColorGroup;
+
+        /// Returns true if the ColorGroup group is set for this palette; otherwise returns false.
+        #[rust_name = "is_brush_set"]
+        fn isBrushSet(self: &QPalette, group: QPaletteColorGroup, role: QPaletteColorRole) -> bool;
+
+        /// Returns true if this palette and other are copies of each other,
+        /// i.e. one of them was created as a copy of the other and neither was subsequently modified.
+        #[rust_name = "is_copy_of"]
+        fn isCopyOf(self: &QPalette, other: &QPalette) -> bool;
+
+        /// Returns true if the color groups group1 and group2 are equal; otherwise returns false.
+        #[rust_name = "is_equal"]
+        fn isEqual(self: &QPalette, group1: QPaletteColorGroup, group2: QPaletteColorGroup)
+            -> bool;
+
+        /// Sets the color in the specified color group, used for the given color role, to the specified solid color.
+        #[rust_name = "set_color"]
+        fn setColor(
+            self: &mut QPalette,
+            group: QPaletteColorGroup,
+            role: QPaletteColorRole,
+            color: &QColor,
+        );
+
+        /// Set the palette's current color group to group.
+        #[rust_name = "set_current_color_group"]
+        fn setCurrentColorGroup(self: &mut QPalette, group: QPaletteColorGroup);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QPaletteColorGroup;
+        type QPaletteColorRole;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_init_default"]
+        fn construct() -> QPalette;
+        #[doc(hidden)]
+        #[rust_name = "qpalette_init_from_qcolor"]
+        fn construct(button: &QColor) -> QPalette;
+        #[doc(hidden)]
+        #[rust_name = "qpalette_drop"]
+        fn drop(palette: &mut QPalette);
+        #[doc(hidden)]
+        #[rust_name = "qpalette_clone"]
+        fn construct(palette: &QPalette) -> QPalette;
+        #[doc(hidden)]
+        #[rust_name = "qpalette_eq"]
+        fn operatorEq(a: &QPalette, b: &QPalette) -> bool;
+    }
+}
+
+pub use ffi::{QPaletteColorGroup, QPaletteColorRole};
+
+/// The QPalette class contains color groups for each widget state.
+///
+/// The palette of the application can be retrieved with `QGuiApplication::palette`,
+/// for example to follow the system colors when styling QML.
+#[repr(C)]
+pub struct QPalette {
+    _d: MaybeUninit<usize>,
+    _current_group: MaybeUninit<u32>,
+}
+
+impl Default for QPalette {
+    /// Constructs an empty palette object with no color roles set.
+    fn default() -> Self {
+        ffi::qpalette_init_default()
+    }
+}
+
+impl Drop for QPalette {
+    fn drop(&mut self) {
+        ffi::qpalette_drop(self);
+    }
+}
+
+impl Clone for QPalette {
+    fn clone(&self) -> Self {
+        ffi::qpalette_clone(self)
+    }
+}
+
+impl PartialEq for QPalette {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpalette_eq(self, other)
+    }
+}
+
+impl Eq for QPalette {}
+
+impl From<&ffi::QColor> for QPalette {
+    /// Constructs a palette from the button color, the other colors are automatically calculated.
+    fn from(button: &ffi::QColor) -> Self {
+        ffi::qpalette_init_from_qcolor(button)
+    }
+}
+
+impl QPalette {
+    /// Returns the color in the current color group, used for the given color role.
+    pub fn current_color(&self, role: QPaletteColorRole) -> &ffi::QColor {
+        self.color(self.current_color_group(), role)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPalette {
+    type Id = type_id!("QPalette");
+    type Kind = cxx::kind::Trivial;
+}
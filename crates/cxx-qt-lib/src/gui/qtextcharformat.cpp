@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtextcharformat.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QTextCharFormat has no members of its own, QTextFormat has a shared pointer and an int
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qtextformat.h?h=v5.15.6-lts-lgpl#n355
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qtextformat.h?h=v6.2.4#n353
+assert_alignment_and_size(QTextCharFormat, {
+  ::std::size_t a0;
+  ::std::int32_t a1;
+});
+
+static_assert(!::std::is_trivially_copy_assignable<QTextCharFormat>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QTextCharFormat>::value);
+
+static_assert(!::std::is_trivially_destructible<QTextCharFormat>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QColor
+qtextcharformatBackground(const QTextCharFormat& format)
+{
+  return format.background().color();
+}
+
+QColor
+qtextcharformatForeground(const QTextCharFormat& format)
+{
+  return format.foreground().color();
+}
+
+void
+qtextcharformatSetBackground(QTextCharFormat& format, const QColor& color)
+{
+  format.setBackground(QBrush(color));
+}
+
+void
+qtextcharformatSetForeground(QTextCharFormat& format, const QColor& color)
+{
+  format.setForeground(QBrush(color));
+}
+
+}
+}
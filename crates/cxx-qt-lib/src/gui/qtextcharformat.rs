@@ -0,0 +1,173 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtextcharformat.h");
+        type QTextCharFormat = super::QTextCharFormat;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns true if the text format is marked as italic; otherwise returns false.
+        #[rust_name = "font_italic"]
+        fn fontItalic(self: &QTextCharFormat) -> bool;
+
+        /// Returns the font size used to display text in this format.
+        #[rust_name = "font_point_size"]
+        fn fontPointSize(self: &QTextCharFormat) -> f64;
+
+        /// Returns true if the text format's font is struck out (has a horizontal line drawn through it); otherwise returns false.
+        #[rust_name = "font_strike_out"]
+        fn fontStrikeOut(self: &QTextCharFormat) -> bool;
+
+        /// Returns true if the text format's font is underlined; otherwise returns false.
+        #[rust_name = "font_underline"]
+        fn fontUnderline(self: &QTextCharFormat) -> bool;
+
+        /// Returns the text format's font weight.
+        #[rust_name = "font_weight"]
+        fn fontWeight(self: &QTextCharFormat) -> i32;
+
+        /// Returns true if this character format is valid; otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QTextCharFormat) -> bool;
+
+        /// If italic is true, sets the text format's font to be italic; otherwise the font will be non-italic.
+        #[rust_name = "set_font_italic"]
+        fn setFontItalic(self: &mut QTextCharFormat, italic: bool);
+
+        /// Sets the text format's font size.
+        #[rust_name = "set_font_point_size"]
+        fn setFontPointSize(self: &mut QTextCharFormat, size: f64);
+
+        /// If strikeOut is true, sets the text format's font with strike-out enabled (with a horizontal line through it);
+        /// otherwise it is displayed without strikeout.
+        #[rust_name = "set_font_strike_out"]
+        fn setFontStrikeOut(self: &mut QTextCharFormat, strike_out: bool);
+
+        /// If underline is true, sets the text format's font to be underlined; otherwise it is displayed non-underlined.
+        #[rust_name = "set_font_underline"]
+        fn setFontUnderline(self: &mut QTextCharFormat, underline: bool);
+
+        /// Sets the text format's font weight to weight.
+        #[rust_name = "set_font_weight"]
+        fn setFontWeight(self: &mut QTextCharFormat, weight: i32);
+
+        /// Sets the tool tip for a fragment of text to the given text.
+        #[rust_name = "set_tool_tip"]
+        fn setToolTip(self: &mut QTextCharFormat, text: &QString);
+
+        /// Returns the tool tip that is displayed for a fragment of text.
+        #[rust_name = "tool_tip"]
+        fn toolTip(self: &QTextCharFormat) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_init_default"]
+        fn construct() -> QTextCharFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_drop"]
+        fn drop(format: &mut QTextCharFormat);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_clone"]
+        fn construct(format: &QTextCharFormat) -> QTextCharFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_eq"]
+        fn operatorEq(a: &QTextCharFormat, b: &QTextCharFormat) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_background"]
+        fn qtextcharformatBackground(format: &QTextCharFormat) -> QColor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_foreground"]
+        fn qtextcharformatForeground(format: &QTextCharFormat) -> QColor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_set_background"]
+        fn qtextcharformatSetBackground(format: &mut QTextCharFormat, color: &QColor);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_set_foreground"]
+        fn qtextcharformatSetForeground(format: &mut QTextCharFormat, color: &QColor);
+    }
+}
+
+/// The QTextCharFormat class provides formatting information for characters in a QTextDocument.
+///
+/// This can be used with `QSyntaxHighlighter::setFormat` when implementing `highlightBlock` in Rust.
+#[repr(C)]
+pub struct QTextCharFormat {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl QTextCharFormat {
+    /// Returns the color of the brush used to paint the background of the text.
+    pub fn background(&self) -> ffi::QColor {
+        ffi::qtextcharformat_background(self)
+    }
+
+    /// Returns the color of the brush used to render the text.
+    pub fn foreground(&self) -> ffi::QColor {
+        ffi::qtextcharformat_foreground(self)
+    }
+
+    /// Sets the brush used to paint the background of the text to a solid color.
+    pub fn set_background(&mut self, color: &ffi::QColor) {
+        ffi::qtextcharformat_set_background(self, color)
+    }
+
+    /// Sets the brush used to render the text to a solid color.
+    pub fn set_foreground(&mut self, color: &ffi::QColor) {
+        ffi::qtextcharformat_set_foreground(self, color)
+    }
+}
+
+impl Default for QTextCharFormat {
+    /// Constructs a new character format object.
+    fn default() -> Self {
+        ffi::qtextcharformat_init_default()
+    }
+}
+
+impl Drop for QTextCharFormat {
+    fn drop(&mut self) {
+        ffi::qtextcharformat_drop(self);
+    }
+}
+
+impl Clone for QTextCharFormat {
+    fn clone(&self) -> Self {
+        ffi::qtextcharformat_clone(self)
+    }
+}
+
+impl PartialEq for QTextCharFormat {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qtextcharformat_eq(self, other)
+    }
+}
+
+impl Eq for QTextCharFormat {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QTextCharFormat {
+    type Id = type_id!("QTextCharFormat");
+    type Kind = cxx::kind::Trivial;
+}
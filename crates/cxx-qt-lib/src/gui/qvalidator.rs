@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type defines the states in which a validated string can exist.
+    ///
+    /// This is the return type of QValidator::validate, so a QObject with `#[base = QValidator]`
+    /// can implement validate with `#[cxx_override]` in Rust.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QValidatorState {
+        /// The string is clearly invalid.
+        Invalid,
+        /// The string is a plausible intermediate value.
+        Intermediate,
+        /// The string is acceptable as a final result; i.e. it is valid.
+        Acceptable,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvalidator.h");
+        type QValidatorState;
+    }
+}
+
+pub use ffi::QValidatorState;
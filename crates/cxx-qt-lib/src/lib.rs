@@ -11,6 +11,9 @@ mod core;
 
 pub use crate::core::*;
 
+/// Derive conversions between a struct and [QVariantMap] and [QVariant], see the documentation of cxx-qt-macro
+pub use cxx_qt_macro::IntoQVariant;
+
 #[cfg(feature = "qt_gui")]
 mod gui;
 #[cfg(feature = "qt_gui")]
@@ -8,3 +8,6 @@ pub use qqmlapplicationengine::QQmlApplicationEngine;
 
 mod qqmlengine;
 pub use qqmlengine::QQmlEngine;
+
+mod qmlpromise;
+pub use qmlpromise::{QmlPromise, QmlPromiseResolver};
@@ -0,0 +1,93 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmlpromise.h"
+
+#include <QtCore/QMetaObject>
+#include <QtQml/QJSEngine>
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QmlPromise*
+QmlPromise::then(const QJSValue& onFulfilled, const QJSValue& onRejected)
+{
+  auto next = qmlpromiseNew();
+  const Reaction reaction{ onFulfilled, onRejected, next };
+  if (m_state == State::Pending) {
+    m_reactions.push_back(reaction);
+  } else {
+    // Like a JavaScript promise, callbacks are never invoked synchronously
+    QMetaObject::invokeMethod(
+      this, [this, reaction]() { react(reaction); }, Qt::QueuedConnection);
+  }
+  return next;
+}
+
+void
+QmlPromise::settle(bool fulfilled, const QVariant& value)
+{
+  if (m_state != State::Pending) {
+    return;
+  }
+
+  m_state = fulfilled ? State::Fulfilled : State::Rejected;
+  m_value = value;
+  for (const auto& reaction : m_reactions) {
+    react(reaction);
+  }
+  m_reactions.clear();
+
+  // Now that the promise is settled, QML can garbage collect it
+  QQmlEngine::setObjectOwnership(this, QQmlEngine::JavaScriptOwnership);
+}
+
+void
+QmlPromise::react(const Reaction& reaction) const
+{
+  if (!reaction.next) {
+    return;
+  }
+
+  QJSValue callback = m_state == State::Fulfilled ? reaction.onFulfilled
+                                                  : reaction.onRejected;
+  QJSEngine* engine = qjsEngine(this);
+  if (!callback.isCallable() || engine == nullptr) {
+    // Without a callback the next promise is settled in the same way
+    reaction.next->settle(m_state == State::Fulfilled, m_value);
+    return;
+  }
+
+  const QJSValue result = callback.call({ engine->toScriptValue(m_value) });
+  reaction.next->settle(!result.isError(), result.toVariant());
+}
+
+QmlPromise*
+qmlpromiseNew()
+{
+  qRegisterMetaType<QmlPromise*>();
+
+  auto promise = new QmlPromise();
+  // While the promise is pending it is only settled from Rust,
+  // so QML must not garbage collect it
+  QQmlEngine::setObjectOwnership(promise, QQmlEngine::CppOwnership);
+  return promise;
+}
+
+void
+qmlpromiseSettle(QmlPromise* promise, bool fulfilled, const QVariant& value)
+{
+  // The resolver can be used from any thread, so settle the promise on its
+  // thread
+  QMetaObject::invokeMethod(
+    promise,
+    [promise, fulfilled, value]() { promise->settle(fulfilled, value); },
+    Qt::AutoConnection);
+}
+
+}
+}
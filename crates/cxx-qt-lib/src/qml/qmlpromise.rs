@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QString, QVariant};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmlpromise.h");
+        /// A QObject which can be returned from an invokable to QML and used like a JavaScript promise,
+        /// for example `myObject.load(url).then(result => console.log(result))`.
+        ///
+        /// It is created with [QmlPromise::new] and settled from Rust with a [QmlPromiseResolver].
+        type QmlPromise;
+
+        #[doc(hidden)]
+        #[rust_name = "qmlpromise_new"]
+        fn qmlpromiseNew() -> *mut QmlPromise;
+
+        #[doc(hidden)]
+        #[rust_name = "qmlpromise_settle"]
+        unsafe fn qmlpromiseSettle(promise: *mut QmlPromise, fulfilled: bool, value: &QVariant);
+    }
+}
+
+pub use ffi::QmlPromise;
+
+impl QmlPromise {
+    /// Create a new pending promise and the resolver which settles it.
+    ///
+    /// The promise is meant to be returned from an invokable to QML, which then owns it once it is settled.
+    /// Until then the promise stays alive, so every promise should be given to QML.
+    pub fn new() -> (*mut QmlPromise, QmlPromiseResolver) {
+        let promise = ffi::qmlpromise_new();
+        (promise, QmlPromiseResolver { promise })
+    }
+}
+
+/// Settles a [QmlPromise], which invokes the callbacks that QML registered with `then()`.
+///
+/// The resolver can be sent to other threads, the promise is then settled on the thread it lives in.
+/// If the resolver is dropped without settling the promise, the promise is rejected.
+pub struct QmlPromiseResolver {
+    promise: *mut QmlPromise,
+}
+
+// Safety:
+//
+// The promise is only settled through this resolver, which queues the settling onto the thread of the promise.
+// Until then QML does not own the promise, so it stays alive while the resolver exists.
+unsafe impl Send for QmlPromiseResolver {}
+
+impl QmlPromiseResolver {
+    /// Fulfill the promise with the given value, which is passed to the `onFulfilled` callback in QML.
+    pub fn resolve(mut self, value: &QVariant) {
+        self.settle(true, value);
+    }
+
+    /// Reject the promise with the given reason, which is passed to the `onRejected` callback in QML.
+    pub fn reject(mut self, reason: &QVariant) {
+        self.settle(false, reason);
+    }
+
+    fn settle(&mut self, fulfilled: bool, value: &QVariant) {
+        let promise = std::mem::replace(&mut self.promise, std::ptr::null_mut());
+        if !promise.is_null() {
+            // SAFETY: the promise is kept alive until it has been settled, which only happens once here
+            unsafe { ffi::qmlpromise_settle(promise, fulfilled, value) };
+        }
+    }
+}
+
+impl Drop for QmlPromiseResolver {
+    fn drop(&mut self) {
+        if self.promise.is_null() {
+            return;
+        }
+
+        let reason = QVariant::from(&QString::from(
+            "The promise was dropped without being settled",
+        ));
+        self.settle(false, &reason);
+    }
+}
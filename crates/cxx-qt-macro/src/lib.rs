@@ -8,9 +8,10 @@
 //! The cxx-qt-macro crate provides the procedural attribute macros which are used with cxx-qt.
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemMod};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_macro_input, Data, DeriveInput, Error, Fields, ItemMod, Path};
 
-use cxx_qt_gen::{write_rust, GeneratedRustBlocks, Parser};
+use cxx_qt_gen::{camel_case_name, write_rust, GeneratedRustBlocks, Parser, TypeRegistry};
 
 /// A procedural macro which generates a QObject for a struct inside a module.
 ///
@@ -117,11 +118,373 @@ pub fn qobject(_args: TokenStream, _input: TokenStream) -> TokenStream {
     unreachable!("qobject should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
 }
 
-// Take the module and C++ namespace and generate the rust code
-fn extract_and_generate(module: ItemMod) -> TokenStream {
-    Parser::from(module)
-        .and_then(|parser| GeneratedRustBlocks::from(&parser))
-        .map(|generated_rust| write_rust(&generated_rust, None))
+/// A derive macro which converts a struct with named fields to and from a `QVariantMap`,
+/// so that it can be given to QML as a JavaScript object with a property for each field.
+///
+/// The names of the properties are the camel case names of the fields, e.g. `first_name` becomes `firstName`.
+/// All of the fields must be types that can be stored in a `QVariant`, including other structs with this derive.
+///
+/// A `QVariantMap` is converted into the struct with [TryFrom], which fails if a key is missing or has a value
+/// of the wrong type. Likewise `QVariant::value` returns [None] if the `QVariantMap` is missing a key.
+///
+/// This derive is re-exported by cxx-qt-lib, which is required for the generated code.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cxx_qt_lib::{IntoQVariant, QString, QVariant, QVariantMap};
+///
+/// #[derive(IntoQVariant)]
+/// pub struct Person {
+///     first_name: QString,
+///     age: i32,
+/// }
+///
+/// let variant = QVariant::from(&Person { first_name: QString::from("Kate"), age: 42 });
+/// let person: Option<Person> = variant.value();
+///
+/// let map = QVariantMap::from(&Person { first_name: QString::from("Kate"), age: 42 });
+/// let person = Person::try_from(&map);
+/// ```
+#[proc_macro_derive(IntoQVariant)]
+pub fn into_qvariant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    generate_into_qvariant(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn generate_into_qvariant(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &data.fields,
+                    "IntoQVariant can only be derived for structs with named fields!",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "IntoQVariant can only be derived for structs with named fields!",
+            ))
+        }
+    };
+
+    let field_idents = fields
+        .iter()
+        .map(|field| field.ident.clone().unwrap())
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let keys = field_idents
+        .iter()
+        .map(|ident| camel_case_name(&ident.unraw()))
+        .collect::<Vec<_>>();
+    let missing_key_errors = keys
+        .iter()
+        .map(|key| format!("The QVariantMap is missing the key {key}"))
+        .collect::<Vec<_>>();
+    let wrong_type_errors = keys
+        .iter()
+        .map(|key| format!("The value of the key {key} in the QVariantMap has the wrong type"))
+        .collect::<Vec<_>>();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#ident #ty_generics> for ::cxx_qt_lib::QVariantMap #where_clause {
+            fn from(value: &#ident #ty_generics) -> Self {
+                let mut map = ::cxx_qt_lib::QVariantMap::default();
+                #(
+                    map.insert(
+                        ::cxx_qt_lib::QString::from(#keys),
+                        ::cxx_qt_lib::QVariant::from(&value.#field_idents),
+                    );
+                )*
+                map
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<&::cxx_qt_lib::QVariantMap> for #ident #ty_generics #where_clause {
+            type Error = &'static str;
+
+            fn try_from(map: &::cxx_qt_lib::QVariantMap) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(
+                        #field_idents: map
+                            .get(&::cxx_qt_lib::QString::from(#keys))
+                            .ok_or(#missing_key_errors)?
+                            .value::<#field_types>()
+                            .ok_or(#wrong_type_errors)?,
+                    )*
+                })
+            }
+        }
+
+        impl #impl_generics ::cxx_qt_lib::QVariantValue for #ident #ty_generics #where_clause {
+            fn can_convert(variant: &::cxx_qt_lib::QVariant) -> bool {
+                if !<::cxx_qt_lib::QVariantMap as ::cxx_qt_lib::QVariantValue>::can_convert(variant) {
+                    return false;
+                }
+
+                let map = variant.value_or_default::<::cxx_qt_lib::QVariantMap>();
+                let keys: &[&str] = &[#(#keys),*];
+                keys.iter()
+                    .all(|key| map.contains(&::cxx_qt_lib::QString::from(*key)))
+            }
+
+            fn construct(value: &Self) -> ::cxx_qt_lib::QVariant {
+                ::cxx_qt_lib::QVariant::from(&::cxx_qt_lib::QVariantMap::from(value))
+            }
+
+            fn value_or_default(variant: &::cxx_qt_lib::QVariant) -> Self {
+                // Missing keys and values of the wrong type fall back to the default of the field
+                let map = variant.value_or_default::<::cxx_qt_lib::QVariantMap>();
+                Self {
+                    #(
+                        #field_idents: map
+                            .get_or_default(&::cxx_qt_lib::QString::from(#keys))
+                            .value_or_default::<#field_types>(),
+                    )*
+                }
+            }
+        }
+    })
+}
+
+/// A derive macro which implements `cxx_qt::SyncProperties` for a QObject,
+/// so that the properties of the QObject can be set from a struct and the struct created from the properties.
+///
+/// The QObject is specified with the `#[sync_properties(...)]` attribute and needs a property
+/// with the same name and type as each field of the struct. All of the fields must implement [Clone].
+///
+/// As the generated setters only emit the notify signal if the value changed,
+/// `sync_from` only notifies QML about the fields which actually changed.
+///
+/// This derive is re-exported by cxx-qt, which is required for the generated code.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cxx_qt::SyncProperties;
+///
+/// #[derive(Clone, SyncProperties)]
+/// #[sync_properties(qobject::Backend)]
+/// pub struct BackendState {
+///     name: QString,
+///     count: i32,
+/// }
+///
+/// impl qobject::Backend {
+///     fn refresh(self: Pin<&mut Self>, state: &BackendState) {
+///         // Only the properties whose values differ from the state emit their notify signal
+///         self.sync_from(state);
+///     }
+/// }
+/// ```
+#[proc_macro_derive(SyncProperties, attributes(sync_properties))]
+pub fn sync_properties(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    generate_sync_properties(input)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+fn generate_sync_properties(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &data.fields,
+                    "SyncProperties can only be derived for structs with named fields!",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "SyncProperties can only be derived for structs with named fields!",
+            ))
+        }
+    };
+
+    let qobject: Path = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("sync_properties"))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                &input.ident,
+                "SyncProperties requires the QObject, eg `#[sync_properties(qobject::MyObject)]`",
+            )
+        })?
+        .parse_args()?;
+
+    let getters = fields
+        .iter()
+        .map(|field| field.ident.clone().unwrap())
+        .collect::<Vec<_>>();
+    let setters = getters
+        .iter()
+        .map(|ident| format_ident!("set_{}", ident.to_string().trim_start_matches("r#")))
+        .collect::<Vec<_>>();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::cxx_qt::SyncProperties<#ident #ty_generics> for #qobject #where_clause {
+            #[allow(unused_mut)]
+            fn sync_from(mut self: ::core::pin::Pin<&mut Self>, value: &#ident #ty_generics) {
+                #(
+                    self.as_mut().#setters(::core::clone::Clone::clone(&value.#getters));
+                )*
+            }
+
+            fn to_struct(&self) -> #ident #ty_generics {
+                #ident {
+                    #(
+                        #getters: ::core::clone::Clone::clone(self.#getters()),
+                    )*
+                }
+            }
+        }
+    })
+}
+
+// Take the module and C++ namespace and generate the rust code
+//
+// Items of the bridge that fail to parse are reported as errors, but the rest of the bridge
+// is still generated, so that IDEs such as rust-analyzer can resolve the generated API
+// (eg property getters and setters or signal emitters) while the bridge is being edited.
+fn extract_and_generate(module: ItemMod) -> TokenStream {
+    // Types registered with CxxQtBuilder::register_type are shared through the OUT_DIR of the crate
+    let registry = match TypeRegistry::from_out_dir() {
+        Ok(registry) => registry,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let (parser, errors) = match Parser::from_partial_with_registry(module, &registry) {
+        Ok(parsed) => parsed,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    match (GeneratedRustBlocks::from(&parser), errors) {
+        (Ok(generated_rust), None) => write_rust(&generated_rust, None),
+        (Ok(generated_rust), Some(errors)) => {
+            let mut tokens = errors.to_compile_error();
+            tokens.extend(write_rust(&generated_rust, None));
+            tokens
+        }
+        // Errors in the generation phase may be caused by the skipped items,
+        // so only report them when everything was parsed
+        (Err(err), None) | (Err(_), Some(err)) => err.to_compile_error(),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_into_qvariant_keys() {
+        let tokens = generate_into_qvariant(parse_quote! {
+            struct Person {
+                first_name: QString,
+                r#type: i32,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(tokens.contains("QString :: from (\"firstName\")"));
+        assert!(tokens.contains("QString :: from (\"type\")"));
+        assert!(tokens.contains("The QVariantMap is missing the key firstName"));
+        assert!(tokens.contains("The value of the key type in the QVariantMap has the wrong type"));
+    }
+
+    #[test]
+    fn test_into_qvariant_try_from() {
+        let tokens = generate_into_qvariant(parse_quote! {
+            struct Person {
+                age: i32,
+            }
+        })
+        .unwrap();
+
+        let expected = quote! {
+            impl ::core::convert::TryFrom<&::cxx_qt_lib::QVariantMap> for Person {
+                type Error = &'static str;
+
+                fn try_from(map: &::cxx_qt_lib::QVariantMap) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok(Self {
+                        age: map
+                            .get(&::cxx_qt_lib::QString::from("age"))
+                            .ok_or("The QVariantMap is missing the key age")?
+                            .value::<i32>()
+                            .ok_or("The value of the key age in the QVariantMap has the wrong type")?,
+                    })
+                }
+            }
+        };
+        assert!(tokens.to_string().contains(&expected.to_string()));
+    }
+
+    #[test]
+    fn test_into_qvariant_invalid() {
+        assert!(generate_into_qvariant(parse_quote! { struct Point(i32, i32); }).is_err());
+        assert!(generate_into_qvariant(parse_quote! { enum Kind { A, B } }).is_err());
+    }
+
+    #[test]
+    fn test_sync_properties() {
+        let tokens = generate_sync_properties(parse_quote! {
+            #[sync_properties(qobject::Backend)]
+            struct BackendState {
+                name: QString,
+                r#type: i32,
+            }
+        })
+        .unwrap();
+
+        let expected = quote! {
+            impl ::cxx_qt::SyncProperties<BackendState> for qobject::Backend {
+                #[allow(unused_mut)]
+                fn sync_from(mut self: ::core::pin::Pin<&mut Self>, value: &BackendState) {
+                    self.as_mut().set_name(::core::clone::Clone::clone(&value.name));
+                    self.as_mut().set_type(::core::clone::Clone::clone(&value.r#type));
+                }
+
+                fn to_struct(&self) -> BackendState {
+                    BackendState {
+                        name: ::core::clone::Clone::clone(self.name()),
+                        r#type: ::core::clone::Clone::clone(self.r#type()),
+                    }
+                }
+            }
+        };
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_sync_properties_invalid() {
+        // The QObject is required
+        assert!(generate_sync_properties(parse_quote! {
+            struct BackendState {
+                name: QString,
+            }
+        })
+        .is_err());
+        assert!(generate_sync_properties(parse_quote! {
+            #[sync_properties(qobject::Backend)]
+            struct BackendState(QString);
+        })
+        .is_err());
+    }
+}
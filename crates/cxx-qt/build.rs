@@ -12,8 +12,12 @@ fn main() {
     // Required for tests
     qt_build_utils::setup_linker();
 
-    let cpp_files = ["src/connection.cpp"];
-    let rust_bridges = ["src/connection.rs"];
+    let cpp_files = ["src/connection.cpp", "src/qobjectpointer.cpp"];
+    let rust_bridges = [
+        "src/connection.rs",
+        "src/qobject.rs",
+        "src/qobjectpointer.rs",
+    ];
 
     for bridge in &rust_bridges {
         println!("cargo:rerun-if-changed={bridge}");
@@ -36,6 +40,7 @@ fn main() {
     // ensure src/lib write_headers is consistent
     for (file_contents, file_name) in [
         (include_str!("include/connection.h"), "connection.h"),
+        (include_str!("include/qobjectpointer.h"), "qobjectpointer.h"),
         (include_str!("include/signalhandler.h"), "signalhandler.h"),
         (include_str!("include/thread.h"), "thread.h"),
         (include_str!("include/threading.h"), "threading.h"),
@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<::QPointer<::QObject>> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqt1 {
+
+using QObjectPointer = ::QPointer<::QObject>;
+
+QObjectPointer
+qobjectpointerNew(::QObject* object);
+
+QObjectPointer
+qobjectpointerClone(const QObjectPointer& pointer);
+
+::QObject*
+qobjectpointerData(const QObjectPointer& pointer);
+
+void
+qobjectpointerDrop(QObjectPointer& pointer);
+
+}
+}
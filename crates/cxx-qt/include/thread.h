@@ -6,19 +6,126 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
+#include <unordered_map>
 #include <shared_mutex>
 #include <stdexcept>
 
+#include <QtCore/QCoreApplication>
 #include <QtCore/QDebug>
+#include <QtCore/QEvent>
 #include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QThread>
 
 #include "rust/cxx.h"
 
 namespace rust {
 namespace cxxqt1 {
 
+inline QEvent::Type
+cxxQtQueuedEventType()
+{
+  static const auto type =
+    static_cast<QEvent::Type>(QEvent::registerEventType());
+  return type;
+}
+
+// An event which calls a function when it is delivered, this allows for
+// queueing a function with an event priority unlike QMetaObject::invokeMethod
+class CxxQtQueuedEventBase : public QEvent
+{
+public:
+  CxxQtQueuedEventBase()
+    : QEvent(cxxQtQueuedEventType())
+  {
+  }
+
+  virtual void call() = 0;
+};
+
+template<typename F>
+class CxxQtQueuedEvent final : public CxxQtQueuedEventBase
+{
+public:
+  explicit CxxQtQueuedEvent(F func)
+    : m_func(::std::move(func))
+  {
+  }
+
+  void call() override { m_func(); }
+
+private:
+  F m_func;
+};
+
+// Receives the CxxQtQueuedEvents of the thread it has been moved to
+//
+// There is a single receiver per thread, which is deleted when the thread
+// finishes, any events which have not been delivered by then are destroyed
+// with it.
+class CxxQtQueuedEventReceiver final : public QObject
+{
+public:
+  // Post an event to the receiver of the given thread, creating it if needed
+  static void post(QThread* thread, QEvent* event, int priority)
+  {
+    const auto guard = ::std::lock_guard(mutex());
+    auto& receiver = receivers()[thread];
+    if (!receiver) {
+      receiver = new CxxQtQueuedEventReceiver(thread);
+    }
+    QCoreApplication::postEvent(receiver, event, priority);
+  }
+
+  ~CxxQtQueuedEventReceiver() override
+  {
+    const auto guard = ::std::lock_guard(mutex());
+    receivers().erase(m_thread);
+  }
+
+  bool event(QEvent* event) override
+  {
+    if (event->type() == cxxQtQueuedEventType()) {
+      static_cast<CxxQtQueuedEventBase*>(event)->call();
+      return true;
+    }
+
+    return QObject::event(event);
+  }
+
+private:
+  explicit CxxQtQueuedEventReceiver(QThread* thread)
+    : m_thread(thread)
+  {
+    moveToThread(thread);
+    // The event loop of the thread has stopped once finished is emitted,
+    // deleteLater then destroys the receiver when the thread exits
+    QObject::connect(thread,
+                     &QThread::finished,
+                     this,
+                     &QObject::deleteLater,
+                     Qt::DirectConnection);
+  }
+
+  static ::std::mutex& mutex()
+  {
+    static ::std::mutex mutex;
+    return mutex;
+  }
+
+  static ::std::unordered_map<QThread*, CxxQtQueuedEventReceiver*>&
+  receivers()
+  {
+    static ::std::unordered_map<QThread*, CxxQtQueuedEventReceiver*> receivers;
+    return receivers;
+  }
+
+  QThread* m_thread;
+};
+
 template<typename T>
 class CxxQtGuardedPointer final
 {
@@ -45,9 +152,17 @@ public:
   CxxQtThread(const CxxQtThread<T>& other) = default;
   CxxQtThread(CxxQtThread<T>&& other) = default;
 
+  bool isCurrentThread() const
+  {
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return m_obj->ptr && m_obj->ptr->thread() == QThread::currentThread();
+  }
+
   template<typename A>
   void queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-             ::rust::Box<A> arg) const
+             ::rust::Box<A> arg,
+             ::std::int32_t priority) const
   {
     // Ensure that we can read the pointer and it's not being written to
     const auto guard = ::std::shared_lock(m_obj->mutex);
@@ -73,10 +188,19 @@ public:
     };
 
     // Add the lambda to the queue
-    if (!QMetaObject::invokeMethod(
-          m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
-      throw ::std::runtime_error(
-        "Cannot queue function pointer as invokeMethod on object failed");
+    if (priority == Qt::NormalEventPriority) {
+      if (!QMetaObject::invokeMethod(
+            m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
+        throw ::std::runtime_error(
+          "Cannot queue function pointer as invokeMethod on object failed");
+      }
+    } else {
+      // Other priorities need an event, which is posted to the receiver
+      // in the thread of the object
+      CxxQtQueuedEventReceiver::post(
+        m_obj->ptr->thread(),
+        new CxxQtQueuedEvent<decltype(lambda)>(::std::move(lambda)),
+        priority);
     }
   }
 
@@ -98,13 +222,21 @@ cxxQtThreadDrop(CxxQtThread<T>& cxxQtThread)
   cxxQtThread.~CxxQtThread<T>();
 }
 
+template<typename T>
+bool
+cxxQtThreadIsCurrentThread(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isCurrentThread();
+}
+
 template<typename A, typename T>
 void
 cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
                  ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-                 ::rust::Box<A> arg)
+                 ::rust::Box<A> arg,
+                 ::std::int32_t priority)
 {
-  cxxQtThread.queue(::std::move(func), ::std::move(arg));
+  cxxQtThread.queue(::std::move(func), ::std::move(arg), priority);
 }
 
 } // namespace cxxqt1
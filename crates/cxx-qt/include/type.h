@@ -27,6 +27,8 @@ public:
 
   T const& unsafeRust() const { return *m_rustObj; }
   T& unsafeRustMut() { return *m_rustObj; }
+  // The Rust struct is boxed separately, so it is not const when the QObject is
+  T* unsafeRustPtr() const { return const_cast<T*>(&*m_rustObj); }
 
 protected:
   ::rust::Box<T> m_rustObj;
@@ -46,4 +48,11 @@ unsafeRust(const Outer& outer)
   return static_cast<const CxxQtType<Inner>&>(outer).unsafeRust();
 }
 
+template<typename Inner, typename Outer>
+Inner*
+unsafeRustPtr(const Outer& outer)
+{
+  return static_cast<const CxxQtType<Inner>&>(outer).unsafeRustPtr();
+}
+
 }
@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    cell::RefCell,
+    fmt,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+use std::collections::HashMap;
+
+// QObjects can only be used from the thread that they live in, so the borrows of the Rust
+// structs are tracked per thread, keyed by the address of the QObject.
+//
+// A positive value is the number of shared borrows, -1 is an exclusive borrow.
+thread_local! {
+    static BORROWS: RefCell<HashMap<usize, isize>> = RefCell::new(HashMap::new());
+}
+
+/// The error returned by [CxxQtType::try_rust](crate::CxxQtType::try_rust) and
+/// [CxxQtType::try_rust_mut](crate::CxxQtType::try_rust_mut) when the Rust struct of the QObject
+/// is already borrowed in a way which conflicts with the requested borrow.
+///
+/// This happens when the QObject is re-entered, for example when a signal handler which is
+/// connected with a direct connection calls back into the QObject that emitted the signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorrowError {
+    exclusive: bool,
+}
+
+impl BorrowError {
+    /// Whether the Rust struct is already exclusively borrowed by [CxxQtType::try_rust_mut](crate::CxxQtType::try_rust_mut)
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exclusive {
+            write!(
+                f,
+                "the Rust struct of the QObject is already mutably borrowed"
+            )
+        } else {
+            write!(f, "the Rust struct of the QObject is already borrowed")
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+/// The key of a QObject in the registry of borrows, which is its address
+pub(crate) fn key<T: ?Sized>(qobject: &T) -> usize {
+    qobject as *const T as *const u8 as usize
+}
+
+/// Check whether the Rust struct of the QObject at the given address can be borrowed,
+/// without registering the borrow
+pub(crate) fn check(key: usize, exclusive: bool) -> Result<(), BorrowError> {
+    let count = BORROWS.with(|borrows| borrows.borrow().get(&key).copied().unwrap_or(0));
+    match count {
+        0 => Ok(()),
+        count if count < 0 => Err(BorrowError { exclusive: true }),
+        _ if exclusive => Err(BorrowError { exclusive: false }),
+        _ => Ok(()),
+    }
+}
+
+/// Panic if the Rust struct of the QObject is borrowed in a way which conflicts with a new borrow
+///
+/// This is used by the generated [CxxQtType::rust](crate::CxxQtType::rust) and
+/// [CxxQtType::rust_mut](crate::CxxQtType::rust_mut), so that re-entering the QObject while
+/// a tracked borrow is held is detected even when the re-entrant code does not track its borrows.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_can_borrow<T: ?Sized>(qobject: &T, exclusive: bool) {
+    if let Err(error) = check(key(qobject), exclusive) {
+        panic!("{error}, the QObject was re-entered");
+    }
+}
+
+/// Register a shared borrow of the QObject at the given address
+pub(crate) fn borrow(key: usize) -> Result<(), BorrowError> {
+    check(key, false)?;
+    BORROWS.with(|borrows| *borrows.borrow_mut().entry(key).or_insert(0) += 1);
+    Ok(())
+}
+
+/// Register an exclusive borrow of the QObject at the given address
+pub(crate) fn borrow_mut(key: usize) -> Result<(), BorrowError> {
+    check(key, true)?;
+    BORROWS.with(|borrows| borrows.borrow_mut().insert(key, -1));
+    Ok(())
+}
+
+fn release(key: usize) {
+    BORROWS.with(|borrows| {
+        let mut borrows = borrows.borrow_mut();
+        if let Some(count) = borrows.get_mut(&key) {
+            if *count > 1 {
+                *count -= 1;
+            } else {
+                borrows.remove(&key);
+            }
+        }
+    })
+}
+
+/// A shared borrow of the Rust struct of a QObject, created by [CxxQtType::try_rust](crate::CxxQtType::try_rust).
+///
+/// The borrow is released when this guard is dropped.
+pub struct RustRef<'a, T> {
+    value: &'a T,
+    key: usize,
+}
+
+impl<'a, T> RustRef<'a, T> {
+    pub(crate) fn new(value: &'a T, key: usize) -> Self {
+        Self { value, key }
+    }
+}
+
+impl<T> Deref for RustRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> Drop for RustRef<'_, T> {
+    fn drop(&mut self) {
+        release(self.key);
+    }
+}
+
+/// An exclusive borrow of the Rust struct of a QObject, created by [CxxQtType::try_rust_mut](crate::CxxQtType::try_rust_mut).
+///
+/// The borrow is released when this guard is dropped.
+pub struct RustMut<'a, T> {
+    value: Pin<&'a mut T>,
+    key: usize,
+}
+
+impl<'a, T> RustMut<'a, T> {
+    pub(crate) fn new(value: Pin<&'a mut T>, key: usize) -> Self {
+        Self { value, key }
+    }
+
+    /// Retrieve a pinned mutable reference to the Rust struct
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        self.value.as_mut()
+    }
+}
+
+impl<T> Deref for RustMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Unpin> DerefMut for RustMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T> Drop for RustMut<'_, T> {
+    fn drop(&mut self) {
+        release(self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn borrow_count(key: usize) -> Option<isize> {
+        BORROWS.with(|borrows| borrows.borrow().get(&key).copied())
+    }
+
+    #[test]
+    fn test_shared_borrows() {
+        let value = 1;
+        borrow(1).unwrap();
+        let first = RustRef::new(&value, 1);
+        borrow(1).unwrap();
+        let second = RustRef::new(&value, 1);
+        assert_eq!(borrow_count(1), Some(2));
+        assert_eq!(*first + *second, 2);
+
+        // Shared borrows prevent an exclusive borrow until all of them are released
+        assert_eq!(borrow_mut(1), Err(BorrowError { exclusive: false }));
+        drop(first);
+        assert_eq!(borrow_mut(1), Err(BorrowError { exclusive: false }));
+        drop(second);
+        assert_eq!(borrow_count(1), None);
+        borrow_mut(1).unwrap();
+        release(1);
+    }
+
+    #[test]
+    fn test_exclusive_borrow() {
+        let mut value = 1;
+        borrow_mut(2).unwrap();
+        let mut guard = RustMut::new(Pin::new(&mut value), 2);
+        *guard += 1;
+        assert_eq!(*guard.as_mut(), 2);
+
+        let error = borrow(2).unwrap_err();
+        assert!(error.is_exclusive());
+        assert_eq!(
+            error.to_string(),
+            "the Rust struct of the QObject is already mutably borrowed"
+        );
+        assert!(borrow_mut(2).unwrap_err().is_exclusive());
+
+        // Other QObjects are not affected
+        borrow(3).unwrap();
+        release(3);
+
+        drop(guard);
+        assert_eq!(borrow_count(2), None);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the Rust struct of the QObject is already mutably borrowed, the QObject was re-entered"
+    )]
+    fn test_assert_can_borrow_while_exclusively_borrowed() {
+        let value = 1;
+        borrow_mut(key(&value)).unwrap();
+        assert_can_borrow(&value, false);
+    }
+
+    #[test]
+    fn test_assert_can_borrow() {
+        let value = 1;
+        assert_can_borrow(&value, true);
+
+        // A shared borrow only conflicts with an exclusive borrow
+        borrow(key(&value)).unwrap();
+        assert_can_borrow(&value, false);
+        assert!(std::panic::catch_unwind(|| assert_can_borrow(&value, true)).is_err());
+        release(key(&value));
+        assert_can_borrow(&value, true);
+    }
+
+    #[test]
+    fn test_borrows_are_per_thread() {
+        borrow_mut(4).unwrap();
+        std::thread::spawn(|| {
+            borrow(4).unwrap();
+            release(4);
+        })
+        .join()
+        .unwrap();
+        release(4);
+    }
+}
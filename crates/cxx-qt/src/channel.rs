@@ -0,0 +1,530 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A channel which delivers values on the Qt thread of a QObject, see [channel].
+
+use core::{fmt, pin::Pin};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex, MutexGuard, Weak},
+};
+
+use crate::{CxxQtThread, Threading};
+
+/// Queues a delivery of the pending values, returns whether the delivery could be queued
+type Wake = Arc<dyn Fn() -> bool + Send + Sync>;
+
+struct State<T> {
+    /// Values that have not been delivered yet, in the order they were sent
+    pending: VecDeque<T>,
+    /// Whether a delivery of the pending values is queued onto the Qt thread
+    scheduled: bool,
+    /// Queues a delivery of the pending values onto the Qt thread once the receiver is connected
+    wake: Option<Wake>,
+    /// Whether the receiver was dropped without being connected, or its QObject can't receive values anymore
+    disconnected: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    /// Notified when pending values have been delivered or the channel is disconnected, to wake up blocked senders
+    space: Condvar,
+    /// The maximum number of pending values, if the channel is bounded
+    capacity: Option<usize>,
+}
+
+impl<T> Shared<T> {
+    fn new(capacity: Option<usize>) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(State {
+                pending: VecDeque::new(),
+                scheduled: false,
+                wake: None,
+                disconnected: false,
+            }),
+            space: Condvar::new(),
+            capacity,
+        })
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.capacity
+            .is_some_and(|capacity| state.pending.len() >= capacity)
+    }
+
+    /// Stop accepting values and wake up blocked senders, returning the values that were not delivered
+    ///
+    /// The values are returned so that they can be dropped once the state is unlocked.
+    fn disconnect(&self, state: &mut State<T>) -> VecDeque<T> {
+        state.disconnected = true;
+        state.wake = None;
+        self.space.notify_all();
+        std::mem::take(&mut state.pending)
+    }
+
+    /// Add a value to the pending values and queue a delivery if none is queued yet
+    ///
+    /// Returns the value if the channel is disconnected, including when the delivery can't be queued.
+    fn push(&self, mut state: MutexGuard<'_, State<T>>, value: T) -> Result<(), T> {
+        if state.disconnected {
+            return Err(value);
+        }
+        let wake = match &state.wake {
+            Some(wake) if !state.scheduled => wake.clone(),
+            _ => {
+                state.pending.push_back(value);
+                return Ok(());
+            }
+        };
+        state.scheduled = true;
+        // The value is kept out of the pending values until the delivery is queued,
+        // as a delivery which can't be queued is dropped, which disconnects the channel
+        // and drops the pending values
+        drop(state);
+        if !wake() {
+            let mut state = self.state.lock().unwrap();
+            let undelivered = self.disconnect(&mut state);
+            drop(state);
+            drop(undelivered);
+            return Err(value);
+        }
+
+        // The delivery may already have run on the Qt thread, then another one is queued
+        self.push(self.state.lock().unwrap(), value)
+    }
+
+    /// Take all pending values for a delivery on the Qt thread
+    fn take_pending(&self) -> VecDeque<T> {
+        let mut state = self.state.lock().unwrap();
+        state.scheduled = false;
+        let pending = std::mem::take(&mut state.pending);
+        self.space.notify_all();
+        pending
+    }
+}
+
+/// A delivery of the pending values which is queued onto the Qt thread
+///
+/// If it is dropped without being run, for example because the QObject was destroyed
+/// before the event loop ran it, the channel is disconnected.
+struct Delivery<T> {
+    shared: Arc<Shared<T>>,
+    delivered: bool,
+}
+
+impl<T> Delivery<T> {
+    fn take_pending(&mut self) -> VecDeque<T> {
+        self.delivered = true;
+        self.shared.take_pending()
+    }
+}
+
+impl<T> Drop for Delivery<T> {
+    fn drop(&mut self) {
+        if !self.delivered {
+            let mut state = self.shared.state.lock().unwrap();
+            let undelivered = self.shared.disconnect(&mut state);
+            drop(state);
+            drop(undelivered);
+        }
+    }
+}
+
+/// Create a channel whose values are delivered on the Qt thread of a QObject.
+///
+/// The [Sender] can be sent to and cloned for any number of threads,
+/// values that are sent are then queued onto the Qt event loop of the QObject
+/// that the [Receiver] is connected to with [Receiver::connect],
+/// where a callback is invoked with a pinned mutable reference to the QObject and the value.
+///
+/// Values are delivered in the order they were sent. Values which are sent while a delivery is
+/// already queued are delivered together, so that a burst of values only wakes up the event loop once.
+///
+/// Values that are sent before the [Receiver] is connected are delivered once it is connected.
+/// The channel is unbounded, use [bounded_channel] to limit the number of values which are not delivered yet.
+///
+/// The channel is disconnected when the [Receiver] is dropped without being connected,
+/// or when the QObject it is connected to is destroyed. Sending then fails and returns the value,
+/// and the values which were not delivered yet are dropped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cxx_qt::Threading;
+///
+/// impl qobject::Downloader {
+///     fn start(self: Pin<&mut Self>) {
+///         let (sender, receiver) = cxx_qt::channel::<i32>();
+///         receiver.connect(self.qt_thread(), |downloader, progress| {
+///             downloader.set_progress(progress);
+///         });
+///
+///         std::thread::spawn(move || {
+///             for progress in 0..=100 {
+///                 sender.send(progress).unwrap();
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub fn channel<T>() -> (Sender<T>, Receiver<T>)
+where
+    T: Send + 'static,
+{
+    let shared = Shared::new(None);
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// Create a [channel] which holds at most `capacity` values that have not been delivered yet.
+///
+/// This applies backpressure to senders which produce values faster than the Qt thread can handle them,
+/// such as a high frequency feed of measurements.
+/// When the channel is full, [Sender::send] blocks until the pending values have been delivered
+/// or the channel is disconnected, and [Sender::try_send] returns the value in a [TrySendError::Full].
+///
+/// Note that [Sender::send] must not be called from the Qt thread of the QObject when the channel is full,
+/// as the values can only be delivered once the event loop runs again.
+///
+/// # Panics
+///
+/// Panics if the capacity is zero.
+pub fn bounded_channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>)
+where
+    T: Send + 'static,
+{
+    assert!(
+        capacity > 0,
+        "the capacity of a bounded channel must not be zero"
+    );
+    let shared = Shared::new(Some(capacity));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// The sending half of a [channel], which can be sent to other threads and cloned
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Sender<T>
+where
+    T: Send + 'static,
+{
+    /// Send a value, which is delivered on the Qt thread of the QObject that the [Receiver] is connected to
+    ///
+    /// If the channel is a full [bounded_channel], this blocks until there is space for the value.
+    /// This fails and returns the value if the channel is disconnected,
+    /// for example because the QObject has been destroyed.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        while !state.disconnected && self.shared.is_full(&state) {
+            state = self.shared.space.wait(state).unwrap();
+        }
+        self.shared.push(state, value).map_err(SendError)
+    }
+
+    /// Try to send a value without blocking
+    ///
+    /// If the channel is a full [bounded_channel], the value is returned in a [TrySendError::Full].
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let state = self.shared.state.lock().unwrap();
+        if !state.disconnected && self.shared.is_full(&state) {
+            return Err(TrySendError::Full(value));
+        }
+        self.shared
+            .push(state, value)
+            .map_err(TrySendError::Disconnected)
+    }
+}
+
+/// An error which is returned by [Sender::send] when the channel is disconnected, containing the value
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a disconnected channel")
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// An error which is returned by [Sender::try_send]
+pub enum TrySendError<T> {
+    /// The [bounded_channel] is full, the value is returned so that it can be sent again later
+    Full(T),
+    /// The channel is disconnected, for example because the QObject has been destroyed
+    Disconnected(T),
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "Full(..)"),
+            Self::Disconnected(_) => write!(f, "Disconnected(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "the channel is full"),
+            Self::Disconnected(_) => write!(f, "sending on a disconnected channel"),
+        }
+    }
+}
+
+impl<T> std::error::Error for TrySendError<T> {}
+
+/// The receiving half of a [channel], which needs to be connected to a QObject to receive values
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T>
+where
+    T: Send + 'static,
+{
+    /// Connect the receiver to the QObject that `qt_thread` was created from,
+    /// so that the callback is invoked on its Qt thread for each value that is sent, in the order they were sent.
+    ///
+    /// Values that were sent before connecting are queued immediately,
+    /// if this fails the channel is disconnected.
+    pub fn connect<Q, F>(self, qt_thread: CxxQtThread<Q>, callback: F) -> Result<(), cxx::Exception>
+    where
+        Q: Threading + 'static,
+        F: FnMut(Pin<&mut Q>, T) + Send + 'static,
+    {
+        let callback = Arc::new(Mutex::new(callback));
+        // The state owns the wake closure, so only refer to it weakly to avoid a reference cycle.
+        // A sender which wakes up the receiver always holds a strong reference.
+        let weak: Weak<Shared<T>> = Arc::downgrade(&self.shared);
+        let queue = Arc::new(move || {
+            let Some(shared) = weak.upgrade() else {
+                return Ok(());
+            };
+            let callback = callback.clone();
+            let mut delivery = Delivery {
+                shared,
+                delivered: false,
+            };
+            qt_thread.queue(move |mut qobject| {
+                let pending = delivery.take_pending();
+                let mut callback = callback.lock().unwrap();
+                for value in pending {
+                    (*callback)(qobject.as_mut(), value);
+                }
+            })
+        });
+
+        let mut state = self.shared.state.lock().unwrap();
+        state.wake = Some({
+            let queue = queue.clone();
+            Arc::new(move || queue().is_ok())
+        });
+        if state.pending.is_empty() {
+            return Ok(());
+        }
+        state.scheduled = true;
+        // A delivery which can't be queued is dropped, which locks the state
+        drop(state);
+        queue().inspect_err(|_| {
+            let mut state = self.shared.state.lock().unwrap();
+            let undelivered = self.shared.disconnect(&mut state);
+            drop(state);
+            drop(undelivered);
+        })
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        // Once the receiver is connected, the values are delivered without it
+        if state.wake.is_none() {
+            let undelivered = self.shared.disconnect(&mut state);
+            drop(state);
+            drop(undelivered);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Connect the receiver with a wake closure instead of a QObject
+    fn connect_with<T>(receiver: Receiver<T>, wake: impl Fn() + Send + Sync + 'static) {
+        receiver.shared.state.lock().unwrap().wake = Some(Arc::new(move || {
+            wake();
+            true
+        }));
+    }
+
+    #[test]
+    fn test_values_are_pending_until_connected() {
+        let (sender, receiver) = channel::<i32>();
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+
+        let state = receiver.shared.state.lock().unwrap();
+        assert_eq!(state.pending, [1, 2]);
+        assert!(!state.scheduled);
+        assert!(!state.disconnected);
+    }
+
+    #[test]
+    fn test_wake_once_per_delivery() {
+        let (sender, receiver) = channel::<i32>();
+        let shared = receiver.shared.clone();
+        let wakes = Arc::new(AtomicUsize::new(0));
+        connect_with(receiver, {
+            let wakes = wakes.clone();
+            move || {
+                wakes.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        assert_eq!(wakes.load(Ordering::SeqCst), 1);
+        assert_eq!(shared.take_pending(), [1, 2]);
+
+        sender.send(3).unwrap();
+        assert_eq!(wakes.load(Ordering::SeqCst), 2);
+        assert_eq!(shared.take_pending(), [3]);
+    }
+
+    #[test]
+    fn test_send_after_receiver_dropped() {
+        let (sender, receiver) = channel::<i32>();
+        let shared = receiver.shared.clone();
+        sender.send(1).unwrap();
+        drop(receiver);
+
+        // The values which were not delivered are dropped
+        assert!(shared.state.lock().unwrap().pending.is_empty());
+        assert_eq!(sender.send(2).unwrap_err().0, 2);
+        assert!(matches!(
+            sender.try_send(3),
+            Err(TrySendError::Disconnected(3))
+        ));
+        assert!(shared.state.lock().unwrap().pending.is_empty());
+    }
+
+    #[test]
+    fn test_dropped_delivery_disconnects() {
+        let (sender, receiver) = channel::<i32>();
+        let weak = Arc::downgrade(&receiver.shared);
+        let queued = Arc::new(Mutex::new(vec![]));
+        connect_with(receiver, {
+            let queued = queued.clone();
+            move || {
+                queued.lock().unwrap().push(Delivery {
+                    shared: weak.upgrade().unwrap(),
+                    delivered: false,
+                });
+            }
+        });
+
+        sender.send(1).unwrap();
+        // Drop the delivery without running it, like Qt does when the QObject is destroyed
+        queued.lock().unwrap().clear();
+        assert_eq!(sender.send(2).unwrap_err().0, 2);
+    }
+
+    #[test]
+    fn test_send_fails_when_delivery_cant_be_queued() {
+        let (sender, receiver) = channel::<i32>();
+        let weak = Arc::downgrade(&receiver.shared);
+        // Drop the delivery and fail, like CxxQtThread::queue does when the QObject has been destroyed
+        receiver.shared.state.lock().unwrap().wake = Some(Arc::new(move || {
+            drop(Delivery {
+                shared: weak.upgrade().unwrap(),
+                delivered: false,
+            });
+            false
+        }));
+
+        assert_eq!(sender.send(1).unwrap_err().0, 1);
+        assert!(matches!(
+            sender.try_send(2),
+            Err(TrySendError::Disconnected(2))
+        ));
+        let state = receiver.shared.state.lock().unwrap();
+        assert!(state.disconnected);
+        assert!(state.pending.is_empty());
+    }
+
+    #[test]
+    fn test_send_requeues_when_delivery_already_ran() {
+        let (sender, receiver) = channel::<i32>();
+        let shared = receiver.shared.clone();
+        let wakes = Arc::new(AtomicUsize::new(0));
+        let weak = Arc::downgrade(&receiver.shared);
+        connect_with(receiver, {
+            let wakes = wakes.clone();
+            move || {
+                // The first delivery runs before the value is pending
+                if wakes.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Delivery {
+                        shared: weak.upgrade().unwrap(),
+                        delivered: false,
+                    }
+                    .take_pending();
+                }
+            }
+        });
+
+        sender.send(1).unwrap();
+        assert_eq!(wakes.load(Ordering::SeqCst), 2);
+        assert_eq!(shared.take_pending(), [1]);
+    }
+
+    #[test]
+    fn test_try_send_full() {
+        let (sender, _receiver) = bounded_channel::<i32>(1);
+        sender.try_send(1).unwrap();
+        assert!(matches!(sender.try_send(2), Err(TrySendError::Full(2))));
+    }
+
+    #[test]
+    fn test_blocked_send_fails_when_receiver_dropped() {
+        let (sender, receiver) = bounded_channel::<i32>(1);
+        sender.send(1).unwrap();
+
+        let blocked = std::thread::spawn(move || sender.send(2));
+        // Give the sender time to block on the full channel
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        drop(receiver);
+
+        assert_eq!(blocked.join().unwrap().unwrap_err().0, 2);
+    }
+}
@@ -11,18 +11,30 @@
 
 use std::{fs::File, io::Write, path::Path};
 
+mod borrow;
+pub mod channel;
 mod connection;
 mod connectionguard;
+mod qobject;
+mod qobjectpointer;
 #[doc(hidden)]
 pub mod signalhandler;
 mod threading;
+mod worker;
 
 pub use cxx_qt_macro::bridge;
 pub use cxx_qt_macro::qobject;
+pub use cxx_qt_macro::SyncProperties;
 
+#[doc(hidden)]
+pub use borrow::assert_can_borrow;
+pub use borrow::{BorrowError, RustMut, RustRef};
+pub use channel::{bounded_channel, channel};
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
-pub use threading::CxxQtThread;
+pub use qobjectpointer::{QObjectRef, QObjectWeakRef};
+pub use threading::{CxxQtThread, EventPriority, InvokeBlockingError};
+pub use worker::{Worker, WorkerError, WorkerThread};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
 //
@@ -30,6 +42,26 @@ pub use threading::CxxQtThread;
 #[doc(hidden)]
 pub use static_assertions;
 
+/// Include the Rust code of a bridge which was expanded by `cxx-qt-build` at build time,
+/// instead of expanding the bridge with the [bridge] macro.
+///
+/// The path is the path of the bridge which was passed to `CxxQtBuilder::file` or a QML module,
+/// relative to the directory of the crate. The bridge file itself must not be a module of the crate.
+///
+/// ```rust,ignore
+/// // The bridge is in bridges/my_object.rs, which is passed to CxxQtBuilder::file in build.rs
+/// cxx_qt::include_bridge!("bridges/my_object.rs");
+///
+/// #[derive(Default)]
+/// pub struct MyObjectRust;
+/// ```
+#[macro_export]
+macro_rules! include_bridge {
+    ($path:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/cxx-qt-gen/rust/", $path));
+    };
+}
+
 /// This trait is automatically implemented for all types which are marked as `#[qobject]`.
 /// It provides information about the type that is wrapped by the QObject, as well as the methods
 /// that Cxx-Qt will generate for the QObject.
@@ -38,10 +70,162 @@ pub trait CxxQtType {
     type Rust;
 
     /// Retrieve an immutable reference to the Rust struct backing this C++ object
+    ///
+    /// Panics if the Rust struct is currently borrowed with [CxxQtType::try_rust_mut].
     fn rust(&self) -> &Self::Rust;
 
     /// Retrieve a mutable reference to the Rust struct backing this C++ object
+    ///
+    /// Panics if the Rust struct is currently borrowed with [CxxQtType::try_rust]
+    /// or [CxxQtType::try_rust_mut].
     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust>;
+
+    /// Try to retrieve an immutable reference to the Rust struct backing this C++ object
+    ///
+    /// Returns a [BorrowError] if the Rust struct is currently borrowed with [CxxQtType::try_rust_mut],
+    /// for example when a signal handler calls back into the QObject while it is being modified.
+    fn try_rust(&self) -> Result<RustRef<'_, Self::Rust>, BorrowError> {
+        let key = borrow::key(self);
+        borrow::borrow(key)?;
+        Ok(RustRef::new(self.rust(), key))
+    }
+
+    /// Try to retrieve a mutable reference to the Rust struct backing this C++ object
+    ///
+    /// Returns a [BorrowError] if the Rust struct is currently borrowed with [CxxQtType::try_rust]
+    /// or [CxxQtType::try_rust_mut], instead of creating a second reference to it when the QObject is re-entered.
+    ///
+    /// The borrows made with these methods and by the generated property setters are tracked,
+    /// so that re-entering the QObject through [CxxQtType::rust], [CxxQtType::rust_mut] or a generated
+    /// property getter or setter panics instead of creating a conflicting reference.
+    fn try_rust_mut(
+        self: core::pin::Pin<&mut Self>,
+    ) -> Result<RustMut<'_, Self::Rust>, BorrowError> {
+        let key = borrow::key(&*self);
+        // Check before registering the borrow, as rust_mut asserts that there is no borrow
+        borrow::check(key, true)?;
+        let rust = self.rust_mut();
+        borrow::borrow_mut(key)?;
+        Ok(RustMut::new(rust, key))
+    }
+
+    /// Schedule this QObject for deletion when control returns to the event loop,
+    /// see [`QObject::deleteLater`](https://doc.qt.io/qt-6/qobject.html#deleteLater).
+    ///
+    /// The QObject must not be owned by a [cxx::UniquePtr] at the same time,
+    /// use [cxx::UniquePtr::into_raw] to release it first.
+    ///
+    /// This is generated for each QObject, so that C++ converts the pointer to the QObject base class.
+    fn delete_later(self: core::pin::Pin<&mut Self>);
+}
+
+/// This trait can be implemented on any [CxxQtType] to construct the QObject on the heap from Rust.
+///
+/// The QObject is constructed without a parent and is owned by the returned [cxx::UniquePtr],
+/// so that dynamic objects, such as the controller of a pop-up, can be managed from Rust.
+/// To hand the QObject over to Qt instead, for example to delete it with [CxxQtType::delete_later],
+/// release it with [cxx::UniquePtr::into_raw].
+///
+/// If this trait is implemented for a given [CxxQtType], it must also be declared inside the
+/// [cxx_qt::bridge](bridge) macro. The QObject must have a constructor without arguments,
+/// which is either the default constructor or a [Constructor] with `()` as its arguments.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         type Popup = super::PopupRust;
+///     }
+///
+///     impl cxx_qt::NewCppObject for Popup {}
+/// }
+///
+/// use cxx_qt::NewCppObject;
+///
+/// let popup = qobject::Popup::new_cpp_object();
+/// ```
+pub trait NewCppObject: CxxQtType + cxx::memory::UniquePtrTarget + Sized {
+    /// Construct a new instance of the QObject without a parent
+    fn new_cpp_object() -> cxx::UniquePtr<Self>;
+}
+
+/// This trait can be implemented on any [CxxQtType] to mutate the Rust struct through `&self`.
+///
+/// Invokables which only take `&self` can then modify independent fields without requiring
+/// `self: Pin<&mut Self>`. The borrows are tracked in the same way as [CxxQtType::try_rust] and
+/// [CxxQtType::try_rust_mut], so a conflicting borrow, for example when the QObject is re-entered,
+/// panics or returns a [BorrowError] instead of creating a second reference to the Rust struct.
+///
+/// References returned by [CxxQtType::rust] or by dereferencing the QObject are not tracked,
+/// so they must not be held while the Rust struct is mutably borrowed; use [CxxQtType::try_rust] instead.
+///
+/// If this trait is implemented for a given [CxxQtType], it must also be declared inside the
+/// [cxx_qt::bridge](bridge) macro.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         type Counter = super::CounterRust;
+///
+///         #[qinvokable]
+///         fn increment(self: &Counter);
+///     }
+///
+///     impl cxx_qt::InteriorMutability for Counter {}
+/// }
+///
+/// use cxx_qt::InteriorMutability;
+///
+/// #[derive(Default)]
+/// pub struct CounterRust {
+///     count: i32,
+/// }
+///
+/// impl qobject::Counter {
+///     pub fn increment(&self) {
+///         self.borrow_rust_mut().count += 1;
+///     }
+/// }
+/// ```
+pub trait InteriorMutability: CxxQtType {
+    #[doc(hidden)]
+    fn rust_ptr(&self) -> *mut Self::Rust;
+
+    /// Retrieve an immutable reference to the Rust struct backing this C++ object
+    ///
+    /// Panics if the Rust struct is currently mutably borrowed.
+    #[track_caller]
+    fn borrow_rust(&self) -> RustRef<'_, Self::Rust> {
+        self.try_rust().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Retrieve a mutable reference to the Rust struct backing this C++ object through `&self`
+    ///
+    /// Panics if the Rust struct is currently borrowed.
+    #[track_caller]
+    fn borrow_rust_mut(&self) -> RustMut<'_, Self::Rust> {
+        self.try_borrow_rust_mut()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Try to retrieve a mutable reference to the Rust struct backing this C++ object through `&self`
+    ///
+    /// Returns a [BorrowError] if the Rust struct is currently borrowed.
+    fn try_borrow_rust_mut(&self) -> Result<RustMut<'_, Self::Rust>, BorrowError> {
+        let key = borrow::key(self);
+        borrow::borrow_mut(key)?;
+        // SAFETY: the Rust struct is boxed separately from the C++ object and is never moved,
+        // the registry ensures that this is the only tracked borrow of it until the guard is dropped
+        let rust = unsafe { core::pin::Pin::new_unchecked(&mut *self.rust_ptr()) };
+        Ok(RustMut::new(rust, key))
+    }
 }
 
 /// This trait indicates that the object implements threading and has a method which returns a [CxxQtThread].
@@ -105,8 +289,25 @@ pub trait Threading: Sized {
     /// This allows for queueing closures onto the Qt event loop from a background thread.
     fn qt_thread(&self) -> CxxQtThread<Self>;
 
+    /// Queue the emission of the given signal onto the Qt event loop for this QObject
+    ///
+    /// Unlike calling the signal directly, the connected slots are only called on the next
+    /// cycle of the event loop, after the current invokable has returned.
+    /// This is a shorthand for `self.qt_thread().emit(signal)`, see [CxxQtThread::emit].
+    fn emit_queued<S>(&self, signal: S) -> Result<(), cxx::Exception>
+    where
+        S: QueuedSignal<Self>,
+        S: Send + 'static,
+    {
+        self.qt_thread().emit(signal)
+    }
+
     #[doc(hidden)]
-    fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), cxx::Exception>
+    fn queue<F>(
+        cxx_qt_thread: &CxxQtThread<Self>,
+        f: F,
+        priority: EventPriority,
+    ) -> Result<(), cxx::Exception>
     where
         F: FnOnce(core::pin::Pin<&mut Self>),
         F: Send + 'static;
@@ -116,6 +317,108 @@ pub trait Threading: Sized {
 
     #[doc(hidden)]
     fn threading_drop(cxx_qt_thread: &mut CxxQtThread<Self>);
+
+    #[doc(hidden)]
+    fn threading_is_current_thread(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+}
+
+/// This trait is implemented by the `<QObject>QueuedSignal` enum which is generated for a QObject
+/// that has signals and implements [Threading].
+///
+/// Each variant of the enum is a signal of the QObject with its parameters as fields,
+/// where reference parameters are stored as owned values.
+/// A background thread can then emit a signal with [CxxQtThread::emit].
+///
+/// ```rust,ignore
+/// let qt_thread = self.qt_thread();
+/// std::thread::spawn(move || {
+///     qt_thread
+///         .emit(MyObjectQueuedSignal::Error {
+///             message: QString::from("Connection lost"),
+///         })
+///         .unwrap();
+/// });
+/// ```
+pub trait QueuedSignal<T> {
+    /// Emit the signal on the given QObject
+    fn emit(self, qobject: core::pin::Pin<&mut T>);
+}
+
+/// This trait is implemented for a QObject by `#[derive(SyncProperties)]` on a struct whose fields mirror properties of the QObject.
+///
+/// This removes the boilerplate of copying a backend state object into the properties of a QObject and back.
+///
+/// See the [SyncProperties](derive.SyncProperties.html) derive for an example.
+pub trait SyncProperties<T> {
+    /// Set each property to the value of the field with the same name.
+    ///
+    /// As the generated setters only emit the notify signal if the value changed,
+    /// QML is only notified about the properties which changed.
+    fn sync_from(self: core::pin::Pin<&mut Self>, value: &T);
+
+    /// Create the struct from the current values of the properties
+    fn to_struct(&self) -> T;
+}
+
+/// This trait converts the Rust type of a property to a type which Qt understands and back.
+///
+/// It allows a property to store a Rust type which has no Qt meta type, such as a decimal from another crate,
+/// while QML sees a type from the bridge such as `QString`.
+/// As the trait is implemented on a separate mapper type, downstream crates can add mappings between
+/// types which they do not own.
+///
+/// The mapper is used by passing `cxx_type` and `map_with` to the `#[qproperty]` attribute,
+/// the generated getter then returns the `cxx_type` and the setter takes it.
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "C++" {
+///         include!("cxx-qt-lib/qstring.h");
+///         type QString = cxx_qt_lib::QString;
+///     }
+///
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qproperty(Decimal, amount, cxx_type = "QString", map_with = "DecimalAsQString")]
+///         type Account = super::AccountRust;
+///     }
+/// }
+///
+/// use cxx_qt_lib::QString;
+/// use rust_decimal::Decimal;
+///
+/// #[derive(Default)]
+/// pub struct AccountRust {
+///     amount: Decimal,
+/// }
+///
+/// pub struct DecimalAsQString;
+///
+/// impl cxx_qt::MapQtValue for DecimalAsQString {
+///     type Rust = Decimal;
+///     type Qt = QString;
+///
+///     fn to_qt(value: &Decimal) -> QString {
+///         QString::from(&value.to_string())
+///     }
+///
+///     fn from_qt(value: QString) -> Decimal {
+///         String::from(&value).parse().unwrap_or_default()
+///     }
+/// }
+/// ```
+pub trait MapQtValue {
+    /// The type stored in the Rust struct
+    type Rust;
+    /// The type exposed to Qt, which must be the `cxx_type` of the property
+    type Qt;
+
+    /// Convert the Rust value into the value returned to Qt
+    fn to_qt(value: &Self::Rust) -> Self::Qt;
+
+    /// Convert a value set by Qt into the Rust value
+    fn from_qt(value: Self::Qt) -> Self::Rust;
 }
 
 /// Placeholder for upcasting objects, suppresses dead code warning
@@ -334,6 +637,10 @@ pub fn write_headers(directory: impl AsRef<Path>) {
     // Note ensure that the build script is consistent with files that are copied
     for (file_contents, file_name) in [
         (include_str!("../include/connection.h"), "connection.h"),
+        (
+            include_str!("../include/qobjectpointer.h"),
+            "qobjectpointer.h",
+        ),
         (
             include_str!("../include/signalhandler.h"),
             "signalhandler.h",
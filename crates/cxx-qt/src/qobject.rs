@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        #[doc(hidden)]
+        type QObject;
+    }
+}
+
+pub(crate) use ffi::QObject;
+
+/// Cast a pointer to a QObject generated by CXX-Qt into a pointer to its QObject base class
+///
+/// This is only valid when the QObject base class is at the start of `T`.
+pub(crate) fn qobject_ptr<T>(object: *mut T) -> *mut QObject {
+    // The caller ensures that the QObject base class is at the start of T,
+    // so a pointer to T is also a pointer to the QObject.
+    object.cast::<QObject>()
+}
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt/qobjectpointer.h"
+
+#include <type_traits>
+
+// ::QPointer<::QObject> is a QWeakPointer, which is the size of two pointers
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v5.15.6-lts-lgpl#n58
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v6.2.4#n58
+static_assert(alignof(::QPointer<::QObject>) <= (alignof(::std::size_t)),
+              "unexpectedly large ::QPointer<::QObject> alignment!");
+static_assert(sizeof(::QPointer<::QObject>) == (sizeof(::std::size_t) * 2),
+              "unexpected ::QPointer<::QObject> size!");
+
+static_assert(
+  !::std::is_trivially_copy_assignable<::QPointer<::QObject>>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<::QPointer<::QObject>>::value);
+static_assert(!::std::is_trivially_destructible<::QPointer<::QObject>>::value);
+
+namespace rust {
+namespace cxxqt1 {
+
+QObjectPointer
+qobjectpointerNew(::QObject* object)
+{
+  return QObjectPointer(object);
+}
+
+QObjectPointer
+qobjectpointerClone(const QObjectPointer& pointer)
+{
+  return QObjectPointer(pointer);
+}
+
+::QObject*
+qobjectpointerData(const QObjectPointer& pointer)
+{
+  return pointer.data();
+}
+
+void
+qobjectpointerDrop(QObjectPointer& pointer)
+{
+  pointer.~QObjectPointer();
+}
+
+}
+}
@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::{marker::PhantomData, mem::MaybeUninit, ops::Deref, pin::Pin};
+
+use crate::{qobject::qobject_ptr, CxxQtType};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        #[doc(hidden)]
+        type QObject = crate::qobject::QObject;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/qobjectpointer.h");
+
+        #[doc(hidden)]
+        type QObjectPointer = super::QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_new"]
+        unsafe fn qobjectpointerNew(object: *mut QObject) -> QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_clone"]
+        fn qobjectpointerClone(pointer: &QObjectPointer) -> QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_data"]
+        fn qobjectpointerData(pointer: &QObjectPointer) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_drop"]
+        fn qobjectpointerDrop(pointer: &mut QObjectPointer);
+    }
+}
+
+/// A `QPointer<QObject>` which is cleared when the QObject is destroyed
+#[doc(hidden)]
+#[repr(C)]
+pub struct QObjectPointer {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl Clone for QObjectPointer {
+    fn clone(&self) -> Self {
+        ffi::qobjectpointer_clone(self)
+    }
+}
+
+impl Drop for QObjectPointer {
+    fn drop(&mut self) {
+        ffi::qobjectpointer_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QObjectPointer {
+    type Id = type_id!("rust::cxxqt1::QObjectPointer");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// A weak reference to a QObject generated by CXX-Qt, which is backed by a
+/// [`QPointer`](https://doc.qt.io/qt-6/qpointer.html).
+///
+/// The reference does not keep the QObject alive, instead [QObjectWeakRef::upgrade] returns `None`
+/// once the QObject has been destroyed. This allows long-lived Rust code outside of the QObject,
+/// such as a service, to safely refer to a QObject that is owned by QML or a parent.
+///
+/// Like the QObject itself, the reference can only be used on the thread of the QObject,
+/// use [Threading](crate::Threading) to refer to the QObject from other threads.
+///
+/// Qt does not track borrows of the QObject, so upgrading the reference is unsafe,
+/// see [QObjectWeakRef::upgrade] for the requirements.
+///
+/// ```rust,ignore
+/// // Safety: MyObject has QObject as its base class
+/// let weak = unsafe { QObjectWeakRef::new(&*my_object) };
+///
+/// // Later, for example when a timer fires
+/// // Safety: the QObject is not borrowed elsewhere and is not destroyed while it is upgraded
+/// if let Some(mut my_object) = unsafe { weak.upgrade() } {
+///     my_object.pin_mut().set_number(1);
+/// }
+/// ```
+pub struct QObjectWeakRef<T> {
+    pointer: QObjectPointer,
+    _marker: PhantomData<*mut T>,
+}
+
+impl<T> QObjectWeakRef<T>
+where
+    T: CxxQtType,
+{
+    /// Create a weak reference to the given QObject
+    ///
+    /// # Safety
+    ///
+    /// The QObject base class must be at the start of `T`, as the pointer to the QObject
+    /// is converted to a pointer to its QObject base class without adjusting it.
+    /// This is the case when the base class of the QObject is `QObject` or a class which
+    /// has `QObject` as its first base class, such as `QAbstractItemModel` or `QQuickItem`.
+    pub unsafe fn new(object: &T) -> Self {
+        // Safety:
+        //
+        // The pointer is valid as it comes from a reference to the QObject,
+        // and the caller guarantees that it is also a pointer to the QObject base class.
+        let pointer =
+            unsafe { ffi::qobjectpointer_new(qobject_ptr((object as *const T).cast_mut())) };
+        Self {
+            pointer,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns true if the QObject has been destroyed
+    pub fn is_null(&self) -> bool {
+        ffi::qobjectpointer_data(&self.pointer).is_null()
+    }
+
+    /// Returns a reference to the QObject if it has not been destroyed, otherwise `None`
+    ///
+    /// # Safety
+    ///
+    /// The returned [QObjectRef] gives mutable access to the QObject, so while it is alive:
+    ///
+    /// - there must be no other references to the QObject, this includes the reference
+    ///   the weak reference was created from, and references returned by other calls to `upgrade`
+    ///   on this weak reference or its clones
+    /// - the QObject must not be destroyed, for example by returning to the event loop
+    ///   or by calling code that may delete the QObject
+    pub unsafe fn upgrade(&self) -> Option<QObjectRef<'_, T>> {
+        let object = ffi::qobjectpointer_data(&self.pointer).cast::<T>();
+        if object.is_null() {
+            None
+        } else {
+            Some(QObjectRef {
+                object,
+                _weak: PhantomData,
+            })
+        }
+    }
+}
+
+impl<T> Clone for QObjectWeakRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            pointer: self.pointer.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A reference to a QObject generated by CXX-Qt, returned by [QObjectWeakRef::upgrade].
+///
+/// The QObject is guaranteed to exist when the reference is created,
+/// but Qt does not keep QObjects alive for references, so the reference should be short-lived.
+/// The caller of [QObjectWeakRef::upgrade] guarantees that the QObject is not destroyed
+/// and not otherwise borrowed while the reference is alive.
+pub struct QObjectRef<'a, T> {
+    object: *mut T,
+    _weak: PhantomData<&'a QObjectWeakRef<T>>,
+}
+
+impl<T> QObjectRef<'_, T> {
+    /// Returns a pinned mutable reference to the QObject
+    pub fn pin_mut(&mut self) -> Pin<&mut T> {
+        // Safety:
+        //
+        // The pointer was checked to be non-null by the QPointer when the reference was created,
+        // the caller of upgrade guarantees that the QObject is alive and not borrowed elsewhere,
+        // and QObjects are never moved by Qt.
+        unsafe { Pin::new_unchecked(&mut *self.object) }
+    }
+}
+
+impl<T> Deref for QObjectRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety:
+        //
+        // The pointer was checked to be non-null by the QPointer when the reference was created,
+        // and the caller of upgrade guarantees that the QObject is alive and not borrowed elsewhere.
+        unsafe { &*self.object }
+    }
+}
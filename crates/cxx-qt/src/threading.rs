@@ -3,10 +3,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, pin::Pin};
 use cxx::ExternType;
+use std::sync::mpsc;
 
-use crate::Threading;
+use crate::{QueuedSignal, Threading};
 
 /// A threading helper which is created from a QObject that implements [Threading].
 ///
@@ -78,6 +79,132 @@ where
         F: FnOnce(Pin<&mut T>),
         F: Send + 'static,
     {
-        T::queue(self, f)
+        self.queue_with_priority(f, EventPriority::Normal)
     }
+
+    /// Queue the given closure onto the Qt event loop for this QObject with the given priority
+    ///
+    /// Closures with a higher priority are called before closures and events with a lower priority
+    /// which were queued earlier, so that for example a bulk refresh doesn't delay updates for user interaction.
+    /// Closures with the same priority are called in the order they were queued.
+    pub fn queue_with_priority<F>(
+        &self,
+        f: F,
+        priority: EventPriority,
+    ) -> Result<(), cxx::Exception>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        T::queue(self, f, priority)
+    }
+    /// Queue the emission of the given signal onto the Qt event loop for this QObject
+    ///
+    /// The signal is one of the variants of the `<QObject>QueuedSignal` enum which is generated
+    /// for a QObject with signals that implements [Threading], such as `MyObjectQueuedSignal::Ready`.
+    /// This avoids wrapping every emission of a background thread in a closure.
+    pub fn emit<S>(&self, signal: S) -> Result<(), cxx::Exception>
+    where
+        S: QueuedSignal<T>,
+        S: Send + 'static,
+    {
+        self.queue(move |qobject| signal.emit(qobject))
+    }
+
+    /// Queue the given closures onto the Qt event loop for this QObject as a single event
+    ///
+    /// The closures are called in order, so that many updates only wake up the event loop once.
+    pub fn queue_batch<I, F>(&self, batch: I) -> Result<(), cxx::Exception>
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        let batch: Vec<F> = batch.into_iter().collect();
+        self.queue(move |mut qobject| {
+            for f in batch {
+                f(qobject.as_mut());
+            }
+        })
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject and block until it has returned its result
+    ///
+    /// This allows a background thread to synchronously fetch state from the QObject,
+    /// for example when a plugin API requires a result before returning.
+    ///
+    /// Waiting for the event loop of the QObject from its own thread would never return,
+    /// so an [InvokeBlockingError::Deadlock] is returned instead when this is called from the thread the QObject lives in.
+    pub fn invoke_blocking<F, R>(&self, f: F) -> Result<R, InvokeBlockingError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        if self.is_current_thread() {
+            return Err(InvokeBlockingError::Deadlock);
+        }
+
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.queue(move |qobject| {
+            // The receiver only disconnects once it stopped waiting, so the result can be ignored
+            let _ = sender.send(f(qobject));
+        })
+        .map_err(InvokeBlockingError::Queue)?;
+
+        // The sender is dropped without a result if the closure is dropped without being called,
+        // for example when the QObject is destroyed before the event loop reaches the closure
+        receiver.recv().map_err(|_| InvokeBlockingError::Dropped)
+    }
+
+    /// Returns true if this is called from the thread the QObject lives in
+    ///
+    /// Returns false if the QObject has been destroyed.
+    pub fn is_current_thread(&self) -> bool {
+        T::threading_is_current_thread(self)
+    }
+}
+
+/// The priority of a closure which is queued onto the Qt event loop with [CxxQtThread::queue_with_priority],
+/// see [`Qt::EventPriority`](https://doc.qt.io/qt-6/qt.html#EventPriority-enum).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum EventPriority {
+    /// Called before closures and events with a normal or low priority
+    High = 1,
+    /// The priority of [CxxQtThread::queue] and of queued signals
+    #[default]
+    Normal = 0,
+    /// Called after closures and events with a high or normal priority
+    Low = -1,
+}
+
+/// An error which is returned by [CxxQtThread::invoke_blocking]
+#[derive(Debug)]
+pub enum InvokeBlockingError {
+    /// The closure was not queued as it was invoked from the thread the QObject lives in,
+    /// where waiting for the result would deadlock the event loop
+    Deadlock,
+    /// The closure could not be queued, for example because the QObject has been destroyed
+    Queue(cxx::Exception),
+    /// The closure was dropped without being called, because the QObject was destroyed while it was queued
+    Dropped,
 }
+
+impl fmt::Display for InvokeBlockingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deadlock => write!(
+                f,
+                "cannot block on the thread the QObject lives in as this would deadlock"
+            ),
+            Self::Queue(exception) => write!(f, "cannot queue the closure: {exception}"),
+            Self::Dropped => write!(
+                f,
+                "the closure was dropped as the QObject has been destroyed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvokeBlockingError {}
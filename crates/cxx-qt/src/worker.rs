@@ -0,0 +1,258 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{fmt, pin::Pin};
+use std::{
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, Sender},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::{CxxQtThread, Threading};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// This trait gives a QObject a background thread which runs heavy work off its Qt thread,
+/// and queues the results back onto its Qt thread.
+///
+/// It is implemented by declaring it in the [cxx_qt::bridge](crate::bridge),
+/// which requires [Threading] to be implemented as well.
+/// CXX-Qt then generates a [WorkerThread] which is shared by all instances of the QObject type,
+/// so that work is run in the order that it is spawned. The thread is started when work is first spawned.
+///
+/// An invokable declared with `#[qinvokable(worker = method)]` is run on the worker thread automatically.
+/// Its implementation is an associated function without `self`, as the QObject can't be accessed from
+/// the worker thread, and the parameters are moved to the worker thread.
+/// The Q_INVOKABLE returns immediately and the result is given to `method` on the Qt thread of the QObject,
+/// for example a property setter or a signal. `#[qinvokable(worker)]` can be used when there is no result.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "RustQt" {
+///         #[qobject]
+///         #[qproperty(i32, result)]
+///         type Calculator = super::CalculatorRust;
+///
+///         #[qinvokable(worker = set_result)]
+///         fn calculate(self: &Calculator, input: i32) -> i32;
+///     }
+///
+///     impl cxx_qt::Threading for Calculator {}
+///     impl cxx_qt::Worker for Calculator {}
+/// }
+///
+/// #[derive(Default)]
+/// pub struct CalculatorRust {
+///     result: i32,
+/// }
+///
+/// impl qobject::Calculator {
+///     // Runs on the worker thread, then set_result is called with the result on the Qt thread
+///     fn calculate(input: i32) -> i32 {
+///         input * 2
+///     }
+/// }
+/// ```
+///
+/// Work can also be spawned manually with [Worker::spawn_work], for example to choose how the result is applied.
+///
+/// ```rust,ignore
+/// use cxx_qt::Worker;
+///
+/// impl qobject::Calculator {
+///     fn calculate_manually(&self, input: i32) {
+///         self.spawn_work(
+///             move || input * 2,
+///             |calculator, result| calculator.set_result(result),
+///         )
+///         .unwrap();
+///     }
+/// }
+/// ```
+pub trait Worker: Threading + 'static {
+    #[doc(hidden)]
+    fn worker_thread() -> &'static WorkerThread;
+
+    /// Run the `work` closure on the background thread of the QObject type, then queue the `done` closure
+    /// with its result onto the Qt thread of this QObject.
+    ///
+    /// If the QObject has been destroyed by the time the work finishes, the result is discarded.
+    fn spawn_work<Work, Output, Done>(&self, work: Work, done: Done) -> Result<(), WorkerError>
+    where
+        Work: FnOnce() -> Output + Send + 'static,
+        Output: Send + 'static,
+        Done: FnOnce(Pin<&mut Self>, Output) + Send + 'static,
+    {
+        Self::worker_thread().spawn(self.qt_thread(), work, done)
+    }
+}
+
+/// A background thread which runs work off the Qt thread of a QObject
+/// and queues the results back onto the Qt thread with a [CxxQtThread].
+///
+/// A [WorkerThread] is generated for QObjects which implement [Worker],
+/// but it can also be stored in the Rust struct of a QObject which implements [Threading].
+/// Work is run in the order that it is spawned.
+///
+/// The thread is started when work is first spawned. A panic in the work is caught,
+/// so that the thread keeps running the work which is spawned afterwards.
+/// When the [WorkerThread] is dropped, the thread finishes the work that has already been spawned and then exits,
+/// without blocking the thread which dropped the [WorkerThread].
+pub struct WorkerThread {
+    // Dropping the sender ends the loop of the thread once the remaining work is finished
+    sender: Mutex<Option<Sender<Job>>>,
+}
+
+impl Default for WorkerThread {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkerThread {
+    /// Create a worker, whose background thread is started when work is first spawned
+    pub const fn new() -> Self {
+        Self {
+            sender: Mutex::new(None),
+        }
+    }
+
+    fn start() -> Result<Sender<Job>, WorkerError> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        thread::Builder::new()
+            .name("cxx-qt-worker".to_owned())
+            .spawn(move || {
+                for job in receiver {
+                    // The panic has already been reported by the panic hook,
+                    // keep running so that the work spawned afterwards isn't lost
+                    let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                }
+            })
+            .map_err(WorkerError::Spawn)?;
+
+        Ok(sender)
+    }
+
+    /// Run the `work` closure on the background thread, then queue the `done` closure
+    /// with its result onto the Qt thread of the QObject that `qt_thread` was created from.
+    ///
+    /// The first argument of the `done` closure is a pinned mutable reference to the QObject,
+    /// so that it can be updated with the result, for example by calling a property setter.
+    /// If the QObject has been destroyed by the time the work finishes, the result is discarded.
+    pub fn spawn<T, Work, Output, Done>(
+        &self,
+        qt_thread: CxxQtThread<T>,
+        work: Work,
+        done: Done,
+    ) -> Result<(), WorkerError>
+    where
+        T: Threading + 'static,
+        Work: FnOnce() -> Output + Send + 'static,
+        Output: Send + 'static,
+        Done: FnOnce(Pin<&mut T>, Output) + Send + 'static,
+    {
+        self.run(move || {
+            let output = work();
+            // If the QObject has been destroyed there is nothing to update, so the result is discarded
+            let _ = qt_thread.queue(move |qobject| done(qobject, output));
+        })
+    }
+
+    /// Run the given closure on the background thread, without queueing a result
+    ///
+    /// This fails if the background thread could not be started.
+    pub fn run<Work>(&self, work: Work) -> Result<(), WorkerError>
+    where
+        Work: FnOnce() + Send + 'static,
+    {
+        let mut sender = self.sender.lock().unwrap();
+        let job: Job = Box::new(work);
+        let job = match sender.as_ref() {
+            Some(running) => match running.send(job) {
+                Ok(()) => return Ok(()),
+                // The thread has stopped, so start a new one
+                Err(mpsc::SendError(job)) => job,
+            },
+            None => job,
+        };
+
+        let started = sender.insert(Self::start()?);
+        started.send(job).map_err(|_| WorkerError::Stopped)
+    }
+}
+
+/// The error returned when work can't be handed to a [WorkerThread]
+#[derive(Debug)]
+pub enum WorkerError {
+    /// The background thread could not be started
+    Spawn(io::Error),
+    /// The background thread stopped before it received the work
+    Stopped,
+}
+
+impl fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn(error) => write!(f, "cannot start the worker thread: {error}"),
+            Self::Stopped => write!(f, "the worker thread has stopped"),
+        }
+    }
+}
+
+impl std::error::Error for WorkerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Spawn(error) => Some(error),
+            Self::Stopped => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_is_started_lazily() {
+        let worker = WorkerThread::default();
+        assert!(worker.sender.lock().unwrap().is_none());
+
+        let (sender, receiver) = mpsc::channel();
+        worker.run(move || sender.send(1).unwrap()).unwrap();
+        assert_eq!(receiver.recv().unwrap(), 1);
+        assert!(worker.sender.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_work_runs_in_order() {
+        let worker = WorkerThread::new();
+        let (sender, receiver) = mpsc::channel();
+        for value in 0..10 {
+            let sender = sender.clone();
+            worker.run(move || sender.send(value).unwrap()).unwrap();
+        }
+        assert_eq!(
+            receiver.iter().take(10).collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_panic_does_not_stop_the_thread() {
+        let worker = WorkerThread::new();
+        worker.run(|| panic!("the work failed")).unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        worker.run(move || sender.send(1).unwrap()).unwrap();
+        assert_eq!(receiver.recv().unwrap(), 1);
+    }
+}
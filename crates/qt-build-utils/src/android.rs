@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This module contains helpers for cross-compiling to Android with Qt for Android.
+
+use std::{env, path::PathBuf};
+
+/// The Android ABI of the current target, for example `arm64-v8a`, or `None` if not targeting Android
+///
+/// Qt for Android uses the ABI as a suffix of the library names, for example `libQt6Core_arm64-v8a.so`.
+pub fn android_abi() -> Option<&'static str> {
+    if env::var("CARGO_CFG_TARGET_OS").ok()? != "android" {
+        return None;
+    }
+
+    match env::var("CARGO_CFG_TARGET_ARCH").ok()?.as_str() {
+        "aarch64" => Some("arm64-v8a"),
+        "arm" => Some("armeabi-v7a"),
+        "x86" => Some("x86"),
+        "x86_64" => Some("x86_64"),
+        _ => None,
+    }
+}
+
+/// The name of the directory of the Qt for Android kit of the current target within a Qt
+/// installation, for example `android_arm64_v8a` in `~/Qt/6.5.0/android_arm64_v8a`
+pub(crate) fn android_kit_dir_name() -> Option<&'static str> {
+    match android_abi()? {
+        "arm64-v8a" => Some("android_arm64_v8a"),
+        "armeabi-v7a" => Some("android_armv7"),
+        "x86" => Some("android_x86"),
+        "x86_64" => Some("android_x86_64"),
+        _ => None,
+    }
+}
+
+/// The minimum Android API level, from the `ANDROID_PLATFORM` or `ANDROID_NATIVE_API_LEVEL`
+/// environment variables (like the Android CMake toolchain file), defaulting to 23 which is the
+/// minimum supported by Qt 6.
+pub fn android_api_level() -> u32 {
+    ["ANDROID_PLATFORM", "ANDROID_NATIVE_API_LEVEL"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find_map(|value| value.trim_start_matches("android-").parse().ok())
+        .unwrap_or(23)
+}
+
+/// The root of the Android NDK, from the `ANDROID_NDK_ROOT` or `ANDROID_NDK_HOME` environment variables
+pub fn android_ndk_root() -> Option<PathBuf> {
+    ["ANDROID_NDK_ROOT", "ANDROID_NDK_HOME"]
+        .iter()
+        .find_map(|var| env::var(var).ok())
+        .map(PathBuf::from)
+}
+
+/// The name of the prebuilt toolchain directory of the Android NDK for the host, for example `linux-x86_64`
+pub fn android_ndk_host_tag() -> &'static str {
+    match env::consts::OS {
+        "windows" => "windows-x86_64",
+        "macos" => "darwin-x86_64",
+        _ => "linux-x86_64",
+    }
+}
+
+/// When targeting Android, set the C++ compiler of the [cc::Build] to the clang of the Android NDK
+/// for the target and API level.
+///
+/// cc-rs looks for a compiler named after the Rust target, which is not how the NDK names its compilers,
+/// so without this the `CXX_<target>` environment variable needs to be set manually.
+/// If the `CXX` or `CXX_<target>` environment variables are set, they are respected instead.
+///
+/// This does nothing when not targeting Android or if the NDK could not be found.
+pub fn setup_android_compiler(builder: &mut cc::Build) {
+    if android_abi().is_none() {
+        return;
+    }
+
+    let target = env::var("TARGET").unwrap_or_default();
+    for var in [
+        "CXX".to_string(),
+        format!("CXX_{target}"),
+        format!("CXX_{}", target.replace('-', "_")),
+    ] {
+        println!("cargo:rerun-if-env-changed={var}");
+        if env::var(&var).is_ok() {
+            return;
+        }
+    }
+
+    let ndk_root = match android_ndk_root() {
+        Some(ndk_root) => ndk_root,
+        None => {
+            println!("cargo:warning=Could not find the Android NDK, set ANDROID_NDK_ROOT");
+            return;
+        }
+    };
+
+    // The NDK uses armv7a for the compilers of the armv7-linux-androideabi target
+    let compiler_target = target.replace("armv7-linux-androideabi", "armv7a-linux-androideabi");
+    let compiler = ndk_root
+        .join("toolchains")
+        .join("llvm")
+        .join("prebuilt")
+        .join(android_ndk_host_tag())
+        .join("bin")
+        .join(format!(
+            "{compiler_target}{}-clang++{}",
+            android_api_level(),
+            if env::consts::OS == "windows" {
+                ".cmd"
+            } else {
+                ""
+            }
+        ));
+    builder.compiler(compiler);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::TestEnv;
+
+    #[test]
+    fn test_android_abi() {
+        let test_env = TestEnv::new();
+        test_env.set("CARGO_CFG_TARGET_ARCH", "aarch64");
+        assert_eq!(android_abi(), None);
+
+        test_env.set("CARGO_CFG_TARGET_OS", "android");
+        for (arch, abi, kit) in [
+            ("aarch64", "arm64-v8a", "android_arm64_v8a"),
+            ("arm", "armeabi-v7a", "android_armv7"),
+            ("x86", "x86", "android_x86"),
+            ("x86_64", "x86_64", "android_x86_64"),
+        ] {
+            test_env.set("CARGO_CFG_TARGET_ARCH", arch);
+            assert_eq!(android_abi(), Some(abi));
+            assert_eq!(android_kit_dir_name(), Some(kit));
+        }
+
+        test_env.set("CARGO_CFG_TARGET_ARCH", "riscv64");
+        assert_eq!(android_abi(), None);
+        assert_eq!(android_kit_dir_name(), None);
+    }
+
+    #[test]
+    fn test_android_api_level() {
+        let test_env = TestEnv::new();
+        assert_eq!(android_api_level(), 23);
+
+        test_env.set("ANDROID_NATIVE_API_LEVEL", "26");
+        assert_eq!(android_api_level(), 26);
+
+        // ANDROID_PLATFORM is preferred and may have an android- prefix
+        test_env.set("ANDROID_PLATFORM", "android-28");
+        assert_eq!(android_api_level(), 28);
+
+        test_env.set("ANDROID_PLATFORM", "invalid");
+        assert_eq!(android_api_level(), 26);
+    }
+
+    #[test]
+    fn test_android_ndk_root() {
+        let test_env = TestEnv::new();
+        assert_eq!(android_ndk_root(), None);
+
+        test_env.set("ANDROID_NDK_HOME", "/ndk/home");
+        assert_eq!(android_ndk_root(), Some(PathBuf::from("/ndk/home")));
+
+        test_env.set("ANDROID_NDK_ROOT", "/ndk/root");
+        assert_eq!(android_ndk_root(), Some(PathBuf::from("/ndk/root")));
+    }
+
+    fn compiler_path(target: &str) -> PathBuf {
+        let mut builder = cc::Build::new();
+        builder
+            .cpp(true)
+            .target(target)
+            .host("x86_64-unknown-linux-gnu")
+            .opt_level(0)
+            .cargo_metadata(false);
+        setup_android_compiler(&mut builder);
+        builder.get_compiler().path().to_path_buf()
+    }
+
+    #[test]
+    fn test_setup_android_compiler() {
+        let test_env = TestEnv::new();
+        test_env
+            .set("CARGO_CFG_TARGET_OS", "android")
+            .set("CARGO_CFG_TARGET_ARCH", "arm")
+            .set("TARGET", "armv7-linux-androideabi")
+            .set("ANDROID_NDK_ROOT", "/ndk")
+            .set("ANDROID_PLATFORM", "android-26");
+
+        // The NDK names the compilers of armv7 targets armv7a
+        assert_eq!(
+            compiler_path("armv7-linux-androideabi"),
+            PathBuf::from("/ndk/toolchains/llvm/prebuilt")
+                .join(android_ndk_host_tag())
+                .join("bin")
+                .join(format!(
+                    "armv7a-linux-androideabi26-clang++{}",
+                    if env::consts::OS == "windows" {
+                        ".cmd"
+                    } else {
+                        ""
+                    }
+                ))
+        );
+    }
+
+    #[test]
+    fn test_setup_android_compiler_respects_cxx() {
+        let test_env = TestEnv::new();
+        test_env
+            .set("CARGO_CFG_TARGET_OS", "android")
+            .set("CARGO_CFG_TARGET_ARCH", "aarch64")
+            .set("TARGET", "aarch64-linux-android")
+            .set("ANDROID_NDK_ROOT", "/ndk")
+            .set("CXX_aarch64_linux_android", "my-clang++");
+
+        assert_eq!(
+            compiler_path("aarch64-linux-android"),
+            PathBuf::from("my-clang++")
+        );
+    }
+}
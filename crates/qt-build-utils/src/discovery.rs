@@ -0,0 +1,398 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This module contains the strategies that are used to find the qmake executable of a Qt installation.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Fedora 36 renames Qt5's qmake to qmake-qt5
+const QMAKE_EXECUTABLE_NAMES: [&str; 3] = ["qmake6", "qmake-qt5", "qmake"];
+
+/// A strategy for finding the qmake executable of a Qt installation, see [crate::QtBuild::with_discovery]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QtDiscovery {
+    /// Use the installation prefix specified by the `QT_DIR` or `QTDIR` environment variable.
+    /// When cross-compiling to Android, iOS or WebAssembly, the kit for the target within it is preferred,
+    /// for example `~/Qt/6.5.0/ios` when `QT_DIR` is `~/Qt/6.5.0`.
+    QtDirEnv,
+    /// Search the prefixes specified by the `CMAKE_PREFIX_PATH` environment variable
+    CMakePrefixPath,
+    /// Search the Qt installed by [vcpkg](https://vcpkg.io) in the `VCPKG_ROOT` or
+    /// `VCPKG_INSTALLATION_ROOT` environment variable. The triplet can be specified by the
+    /// `VCPKG_DEFAULT_TRIPLET` environment variable, otherwise all installed triplets are searched.
+    Vcpkg,
+    /// Ask `pkg-config` for the binary directory of the Qt Core module
+    PkgConfig,
+    /// Search the directories of the `PATH` environment variable
+    Path,
+}
+
+impl QtDiscovery {
+    /// The order in which the strategies are tried by [crate::QtBuild::new]
+    pub const DEFAULT_ORDER: [QtDiscovery; 5] = [
+        QtDiscovery::QtDirEnv,
+        QtDiscovery::CMakePrefixPath,
+        QtDiscovery::Vcpkg,
+        QtDiscovery::PkgConfig,
+        QtDiscovery::Path,
+    ];
+
+    /// The environment variables that can change the result of the strategies
+    pub(crate) const ENV_VARS: [&'static str; 7] = [
+        "QT_DIR",
+        "QTDIR",
+        "CMAKE_PREFIX_PATH",
+        "VCPKG_ROOT",
+        "VCPKG_INSTALLATION_ROOT",
+        "VCPKG_DEFAULT_TRIPLET",
+        "PKG_CONFIG_PATH",
+    ];
+
+    /// Parse the name of a strategy as used in the `QT_DISCOVERY` environment variable
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "qtdir" => Some(QtDiscovery::QtDirEnv),
+            "cmake" => Some(QtDiscovery::CMakePrefixPath),
+            "vcpkg" => Some(QtDiscovery::Vcpkg),
+            "pkg-config" => Some(QtDiscovery::PkgConfig),
+            "path" => Some(QtDiscovery::Path),
+            _ => None,
+        }
+    }
+
+    /// The qmake executables found by this strategy, in order of preference
+    pub(crate) fn candidates(&self) -> Vec<String> {
+        match self {
+            QtDiscovery::QtDirEnv => ["QT_DIR", "QTDIR"]
+                .iter()
+                .filter_map(|var| env::var(var).ok())
+                .flat_map(|prefix| {
+                    let prefix = Path::new(&prefix);
+                    // The directory may contain the kits of a Qt version, for example ~/Qt/6.5.0,
+                    // so prefer the kit for the target
+                    let mut candidates = target_kit_dir_names()
+                        .iter()
+                        .flat_map(|kit| qmake_in_dir(prefix.join(kit).join("bin")))
+                        .collect::<Vec<_>>();
+                    candidates.extend(qmake_in_dir(prefix.join("bin")));
+                    candidates
+                })
+                .collect(),
+            QtDiscovery::CMakePrefixPath => env::var_os("CMAKE_PREFIX_PATH")
+                .map(|prefixes| {
+                    // CMake also accepts ; as a separator on all platforms
+                    env::split_paths(&prefixes)
+                        .flat_map(|prefix| {
+                            prefix
+                                .to_string_lossy()
+                                .split(';')
+                                .map(PathBuf::from)
+                                .collect::<Vec<_>>()
+                        })
+                        .flat_map(|prefix| qmake_in_dir(prefix.join("bin")))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            QtDiscovery::Vcpkg => vcpkg_candidates(),
+            QtDiscovery::PkgConfig => pkg_config_candidates(),
+            QtDiscovery::Path => QMAKE_EXECUTABLE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// The names of the kit directories within a Qt version directory of the Qt installer
+/// that are specific to the target
+fn target_kit_dir_names() -> Vec<&'static str> {
+    if let Some(kit) = crate::android::android_kit_dir_name() {
+        return vec![kit];
+    }
+
+    match env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("ios") => vec!["ios"],
+        _ => crate::wasm::wasm_kit_dir_names(),
+    }
+}
+
+/// Find the qmake executables that exist in the given directory
+fn qmake_in_dir(dir: PathBuf) -> Vec<String> {
+    QMAKE_EXECUTABLE_NAMES
+        .iter()
+        .map(|name| dir.join(format!("{name}{}", env::consts::EXE_SUFFIX)))
+        .filter(|path| path.is_file())
+        .map(|path| path.to_string_lossy().to_string())
+        .collect()
+}
+
+fn vcpkg_candidates() -> Vec<String> {
+    let root = match env::var("VCPKG_ROOT").or_else(|_| env::var("VCPKG_INSTALLATION_ROOT")) {
+        Ok(root) => PathBuf::from(root),
+        Err(_) => return vec![],
+    };
+    let installed = root.join("installed");
+
+    let triplets = if let Ok(triplet) = env::var("VCPKG_DEFAULT_TRIPLET") {
+        vec![installed.join(triplet)]
+    } else {
+        let mut triplets = std::fs::read_dir(&installed)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        triplets.sort();
+        triplets
+    };
+
+    triplets
+        .iter()
+        .flat_map(|triplet| {
+            ["Qt6", "qt5"]
+                .iter()
+                .flat_map(|tools| qmake_in_dir(triplet.join("tools").join(tools).join("bin")))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn pkg_config_candidates() -> Vec<String> {
+    let pkg_config = env::var("PKG_CONFIG").unwrap_or_else(|_| "pkg-config".to_string());
+    let variable = |package: &str, variable: &str| -> Option<PathBuf> {
+        let output = Command::new(&pkg_config)
+            .args([&format!("--variable={variable}"), package])
+            .output()
+            .ok()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !value.is_empty() {
+            Some(PathBuf::from(value))
+        } else {
+            None
+        }
+    };
+
+    let mut candidates = Vec::new();
+    for package in ["Qt6Core", "Qt5Core"] {
+        let bin_dirs = [
+            variable(package, "host_bins"),
+            variable(package, "bindir"),
+            variable(package, "prefix").map(|prefix| prefix.join("bin")),
+        ];
+        for bin_dir in bin_dirs.into_iter().flatten() {
+            for candidate in qmake_in_dir(bin_dir) {
+                if !candidates.contains(&candidate) {
+                    candidates.push(candidate);
+                }
+            }
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{test_dir, TestEnv};
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(QtDiscovery::from_name("qtdir"), Some(QtDiscovery::QtDirEnv));
+        assert_eq!(
+            QtDiscovery::from_name(" cmake "),
+            Some(QtDiscovery::CMakePrefixPath)
+        );
+        assert_eq!(QtDiscovery::from_name("vcpkg"), Some(QtDiscovery::Vcpkg));
+        assert_eq!(
+            QtDiscovery::from_name("pkg-config"),
+            Some(QtDiscovery::PkgConfig)
+        );
+        assert_eq!(QtDiscovery::from_name("path"), Some(QtDiscovery::Path));
+        assert_eq!(QtDiscovery::from_name("qmake"), None);
+    }
+
+    #[test]
+    fn test_default_order_has_all_strategies() {
+        for name in ["qtdir", "cmake", "vcpkg", "pkg-config", "path"] {
+            let strategy = QtDiscovery::from_name(name).unwrap();
+            assert!(QtDiscovery::DEFAULT_ORDER.contains(&strategy));
+        }
+    }
+
+    /// Create the qmake executable in the given directory
+    fn create_qmake(dir: &Path) -> String {
+        std::fs::create_dir_all(dir).unwrap();
+        let qmake = dir.join(format!("qmake{}", env::consts::EXE_SUFFIX));
+        std::fs::write(&qmake, "").unwrap();
+        qmake.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_qt_dir_env() {
+        let dir = test_dir("qt_dir_env");
+        let qmake = create_qmake(&dir.join("bin"));
+        let test_env = TestEnv::new();
+        assert!(QtDiscovery::QtDirEnv.candidates().is_empty());
+
+        test_env.set("QTDIR", &dir);
+        assert_eq!(QtDiscovery::QtDirEnv.candidates(), vec![qmake.clone()]);
+
+        // QT_DIR is preferred over QTDIR
+        let other_dir = test_dir("qt_dir_env_other");
+        let other_qmake = create_qmake(&other_dir.join("bin"));
+        test_env.set("QT_DIR", &other_dir);
+        assert_eq!(QtDiscovery::QtDirEnv.candidates(), vec![other_qmake, qmake]);
+    }
+
+    #[test]
+    fn test_cmake_prefix_path() {
+        let dir = test_dir("cmake_prefix_path");
+        let first = create_qmake(&dir.join("first").join("bin"));
+        let second = create_qmake(&dir.join("second").join("bin"));
+        let test_env = TestEnv::new();
+
+        // CMake also accepts ; as a separator, and prefixes without qmake are skipped
+        test_env.set(
+            "CMAKE_PREFIX_PATH",
+            format!(
+                "{};{};{}",
+                dir.join("first").display(),
+                dir.join("missing").display(),
+                dir.join("second").display()
+            ),
+        );
+        assert_eq!(
+            QtDiscovery::CMakePrefixPath.candidates(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    fn test_vcpkg() {
+        let dir = test_dir("vcpkg");
+        let installed = dir.join("installed");
+        let x64 = create_qmake(
+            &installed
+                .join("x64-linux")
+                .join("tools")
+                .join("Qt6")
+                .join("bin"),
+        );
+        let arm64 = create_qmake(
+            &installed
+                .join("arm64-linux")
+                .join("tools")
+                .join("qt5")
+                .join("bin"),
+        );
+        let test_env = TestEnv::new();
+        assert!(QtDiscovery::Vcpkg.candidates().is_empty());
+
+        // All triplets are searched in a stable order
+        test_env.set("VCPKG_INSTALLATION_ROOT", &dir);
+        assert_eq!(QtDiscovery::Vcpkg.candidates(), vec![arm64, x64.clone()]);
+
+        test_env.set("VCPKG_DEFAULT_TRIPLET", "x64-linux");
+        assert_eq!(QtDiscovery::Vcpkg.candidates(), vec![x64]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_pkg_config() {
+        let dir = test_dir("pkg_config");
+        let qmake = create_qmake(&dir.join("qt6").join("bin"));
+        // Only Qt 6 is installed and provides the prefix
+        crate::tests::write_script(
+            &dir.join("pkg-config"),
+            &format!(
+                r#"if [ "$1" = "--variable=prefix" ] && [ "$2" = "Qt6Core" ]; then echo '{}'; else exit 1; fi"#,
+                dir.join("qt6").display()
+            ),
+        );
+        let test_env = TestEnv::new();
+        test_env.set("PKG_CONFIG", dir.join("pkg-config"));
+
+        assert_eq!(QtDiscovery::PkgConfig.candidates(), vec![qmake]);
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            QtDiscovery::Path.candidates(),
+            vec!["qmake6", "qmake-qt5", "qmake"]
+        );
+    }
+
+    #[test]
+    fn test_qt_dir_env_android_kit() {
+        let dir = test_dir("qt_dir_env_android_kit");
+        let host = create_qmake(&dir.join("bin"));
+        let kit = create_qmake(&dir.join("android_arm64_v8a").join("bin"));
+        create_qmake(&dir.join("android_x86_64").join("bin"));
+        let test_env = TestEnv::new();
+        test_env
+            .set("QT_DIR", &dir)
+            .set("CARGO_CFG_TARGET_OS", "android")
+            .set("CARGO_CFG_TARGET_ARCH", "aarch64");
+
+        // The kit of the target is preferred over the host Qt
+        assert_eq!(QtDiscovery::QtDirEnv.candidates(), vec![kit, host]);
+    }
+
+    #[test]
+    fn test_target_kit_dir_names() {
+        let test_env = TestEnv::new();
+        assert!(target_kit_dir_names().is_empty());
+
+        test_env.set("CARGO_CFG_TARGET_OS", "ios");
+        assert_eq!(target_kit_dir_names(), vec!["ios"]);
+
+        test_env
+            .set("CARGO_CFG_TARGET_OS", "android")
+            .set("CARGO_CFG_TARGET_ARCH", "x86_64");
+        assert_eq!(target_kit_dir_names(), vec!["android_x86_64"]);
+    }
+
+    #[test]
+    fn test_qt_dir_env_ios_kit() {
+        let dir = test_dir("qt_dir_env_ios_kit");
+        let host = create_qmake(&dir.join("macos").join("bin"));
+        let kit = create_qmake(&dir.join("ios").join("bin"));
+        let test_env = TestEnv::new();
+        test_env
+            .set("QT_DIR", &dir)
+            .set("CARGO_CFG_TARGET_OS", "ios");
+        assert_eq!(QtDiscovery::QtDirEnv.candidates(), vec![kit]);
+
+        // The host Qt is used when the prefix is a kit itself
+        test_env.set("QT_DIR", dir.join("macos"));
+        assert_eq!(QtDiscovery::QtDirEnv.candidates(), vec![host]);
+    }
+
+    #[test]
+    fn test_qt_dir_env_wasm_kit() {
+        let dir = test_dir("qt_dir_env_wasm_kit");
+        let multithread = create_qmake(&dir.join("wasm_multithread").join("bin"));
+        let singlethread = create_qmake(&dir.join("wasm_singlethread").join("bin"));
+        let test_env = TestEnv::new();
+        test_env
+            .set("QT_DIR", &dir)
+            .set("CARGO_CFG_TARGET_OS", "emscripten");
+
+        // The single threaded kit is preferred
+        assert_eq!(
+            QtDiscovery::QtDirEnv.candidates(),
+            vec![singlethread, multithread]
+        );
+    }
+}
@@ -15,14 +15,28 @@
 
 #![allow(clippy::too_many_arguments)]
 
+mod android;
+pub use android::{
+    android_abi, android_api_level, android_ndk_host_tag, android_ndk_root, setup_android_compiler,
+};
+
+mod discovery;
+pub use discovery::QtDiscovery;
+
 mod parse_cflags;
 
+mod wasm;
+pub use wasm::emscripten_targeted;
+
+mod windows;
+
 use std::{
     env,
     fs::File,
     io::Write,
     path::{Path, PathBuf},
-    process::Command,
+    process::{Command, Stdio},
+    time::{Duration, Instant},
 };
 
 pub use versions::SemVer;
@@ -54,6 +68,22 @@ pub enum QtBuildError {
         /// The [std::num::ParseIntError] when parsing the `QT_VERSION_MAJOR`
         source: std::num::ParseIntError,
     },
+    /// `QT_DISCOVERY` environment variable contains an unknown strategy
+    #[error("QT_DISCOVERY environment variable specified as {qt_discovery_env_var} but {name} is not a known strategy")]
+    QtDiscoveryInvalid {
+        /// The value of the `QT_DISCOVERY` environment variable
+        qt_discovery_env_var: String,
+        /// The name of the strategy that is not known
+        name: String,
+    },
+    /// The Qt installation was built for a different Windows toolchain (MSVC or MinGW) than the Rust target
+    #[error("qmake is for the {qmake_target_env} toolchain but the Rust target environment is {rust_target_env}")]
+    QtToolchainMismatch {
+        /// The Rust target environment that matches the Qt installation, `msvc` or `gnu`
+        qmake_target_env: String,
+        /// The target environment of the Rust target, from `CARGO_CFG_TARGET_ENV`
+        rust_target_env: String,
+    },
     /// `QT_VERSION_MAJOR` environment variable was specified but the Qt version specified by `qmake -query QT_VERSION` did not match
     #[error("qmake version ({qmake_version}) does not match version specified by QT_VERSION_MAJOR ({qt_version_major})")]
     QtVersionMajorDoesNotMatch {
@@ -134,6 +164,8 @@ pub struct MocProducts {
     pub cpp: PathBuf,
     /// Generated JSON file
     pub metatypes_json: PathBuf,
+    /// How long moc took to run, or `None` if the generated files were up to date
+    pub duration: Option<Duration>,
 }
 
 /// Arguments for a Qt moc invocation.
@@ -178,6 +210,11 @@ pub struct QmlModuleRegistrationFiles {
     pub plugin: PathBuf,
     /// File that automatically registers the QQmlExtensionPlugin at startup. Must be linked with `+whole-archive`.
     pub plugin_init: PathBuf,
+    /// The [qmldir file](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) generated for the QML module.
+    pub qmldir: PathBuf,
+    /// The `plugin.qmltypes` file generated by qmltyperegistrar.
+    /// This file only exists if the module contains at least one QML type.
+    pub qmltypes: PathBuf,
     /// An optional include path that should be included
     pub include_path: Option<PathBuf>,
 }
@@ -197,6 +234,7 @@ pub struct QtBuild {
     qmltyperegistrar_executable: Option<String>,
     qmlcachegen_executable: Option<String>,
     rcc_executable: Option<String>,
+    qmlcachegen_enabled: Option<bool>,
     qt_modules: Vec<String>,
 }
 
@@ -206,9 +244,13 @@ impl QtBuild {
     /// rather than `"QtCore"`). After construction, use the [QtBuild::qmake_query]
     /// method to get information about the Qt installation.
     ///
-    /// The directories specified by the `PATH` environment variable are where qmake is
-    /// searched for. Alternatively, the `QMAKE` environment variable may be set to specify
-    /// an explicit path to qmake.
+    /// The `QMAKE` environment variable may be set to specify an explicit path to qmake.
+    /// Otherwise qmake is searched for with the strategies of [QtDiscovery::DEFAULT_ORDER], in order:
+    /// the `QT_DIR`/`QTDIR` environment variables, `CMAKE_PREFIX_PATH`, vcpkg, pkg-config and
+    /// finally the directories specified by the `PATH` environment variable.
+    /// Set the `QT_DISCOVERY` environment variable to a comma separated list of
+    /// `qtdir`, `cmake`, `vcpkg`, `pkg-config` and `path` to force which strategies are used,
+    /// or use [QtBuild::with_discovery].
     ///
     /// If multiple major versions (for example, `5` and `6`) of Qt could be installed, set
     /// the `QT_VERSION_MAJOR` environment variable to force which one to use. When using Cargo
@@ -239,12 +281,43 @@ impl QtBuild {
     ///     WORKING_DIRECTORY ${CMAKE_CURRENT_SOURCE_DIR}
     /// )
     /// ```
-    pub fn new(mut qt_modules: Vec<String>) -> Result<Self, QtBuildError> {
+    pub fn new(qt_modules: Vec<String>) -> Result<Self, QtBuildError> {
+        println!("cargo:rerun-if-env-changed=QT_DISCOVERY");
+        match env::var("QT_DISCOVERY") {
+            Ok(qt_discovery_env_var) => {
+                let mut discovery = Vec::new();
+                for name in qt_discovery_env_var.split(',') {
+                    match QtDiscovery::from_name(name) {
+                        Some(strategy) => discovery.push(strategy),
+                        None => {
+                            return Err(QtBuildError::QtDiscoveryInvalid {
+                                name: name.to_string(),
+                                qt_discovery_env_var,
+                            })
+                        }
+                    }
+                }
+                Self::with_discovery(qt_modules, &discovery)
+            }
+            Err(_) => Self::with_discovery(qt_modules, &QtDiscovery::DEFAULT_ORDER),
+        }
+    }
+
+    /// Same as [QtBuild::new], but only search for qmake with the given strategies, in order.
+    ///
+    /// The `QMAKE` environment variable still takes precedence over all strategies.
+    pub fn with_discovery(
+        mut qt_modules: Vec<String>,
+        discovery: &[QtDiscovery],
+    ) -> Result<Self, QtBuildError> {
         if qt_modules.is_empty() {
             qt_modules.push("Core".to_string());
         }
         println!("cargo:rerun-if-env-changed=QMAKE");
         println!("cargo:rerun-if-env-changed=QT_VERSION_MAJOR");
+        for env_var in QtDiscovery::ENV_VARS {
+            println!("cargo:rerun-if-env-changed={env_var}");
+        }
         fn verify_candidate(candidate: &str) -> Result<(&str, versions::SemVer), QtBuildError> {
             match Command::new(candidate)
                 .args(["-query", "QT_VERSION"])
@@ -259,6 +332,26 @@ impl QtBuild {
                             .trim()
                             .to_string();
                         let qmake_version = versions::SemVer::new(version_string).unwrap();
+
+                        // Qt built with MSVC can't be linked with the GNU toolchain and vice versa
+                        if let Some(rust_target_env) = windows::windows_target_env() {
+                            let qmake_xspec = Command::new(candidate)
+                                .args(["-query", "QMAKE_XSPEC"])
+                                .output()
+                                .map(|output| {
+                                    String::from_utf8_lossy(&output.stdout).trim().to_string()
+                                })
+                                .unwrap_or_default();
+                            if let Some(qmake_target_env) = windows::qt_target_env(&qmake_xspec) {
+                                if qmake_target_env != rust_target_env {
+                                    return Err(QtBuildError::QtToolchainMismatch {
+                                        qmake_target_env: qmake_target_env.to_string(),
+                                        rust_target_env,
+                                    });
+                                }
+                            }
+                        }
+
                         if let Ok(env_version) = env::var("QT_VERSION_MAJOR") {
                             let env_version = match env_version.trim().parse::<u32>() {
                                 Err(e) if *e.kind() == std::num::IntErrorKind::Empty => {
@@ -301,6 +394,7 @@ impl QtBuild {
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
                         rcc_executable: None,
+                        qmlcachegen_enabled: None,
                         version,
                         qt_modules,
                     });
@@ -314,9 +408,16 @@ impl QtBuild {
             }
         }
 
-        // Fedora 36 renames Qt5's qmake to qmake-qt5
-        let candidate_executable_names = ["qmake6", "qmake-qt5", "qmake"];
-        for (index, executable_name) in candidate_executable_names.iter().enumerate() {
+        let candidate_executables = discovery
+            .iter()
+            .flat_map(|strategy| strategy.candidates())
+            .collect::<Vec<_>>();
+        // If QT_VERSION_MAJOR is specified, it is expected that some of the candidate
+        // executables will not match, so QtVersionMajorDoesNotMatch should only be returned
+        // if none of the candidate executables match.
+        // The same applies to Qt installations for another Windows toolchain.
+        let mut version_mismatch = None;
+        for executable_name in &candidate_executables {
             match verify_candidate(executable_name) {
                 Ok((executable_name, version)) => {
                     return Ok(Self {
@@ -325,25 +426,31 @@ impl QtBuild {
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
                         rcc_executable: None,
+                        qmlcachegen_enabled: None,
                         version,
                         qt_modules,
                     });
                 }
-                // If QT_VERSION_MAJOR is specified, it is expected that one of the versioned
-                // executable names will not match, so the unversioned `qmake` needs to be
-                // attempted last and QtVersionMajorDoesNotMatch should only be returned if
-                // none of the candidate executable names match.
                 Err(QtBuildError::QtVersionMajorDoesNotMatch {
                     qmake_version,
                     qt_version_major,
                 }) => {
-                    if index == candidate_executable_names.len() - 1 {
-                        return Err(QtBuildError::QtVersionMajorDoesNotMatch {
-                            qmake_version,
-                            qt_version_major,
-                        });
-                    }
-                    eprintln!("Candidate qmake executable `{executable_name}` is for Qt{qmake_version} but QT_VERSION_MAJOR environment variable specified as {qt_version_major}. Trying next candidate executable name `{}`...", candidate_executable_names[index + 1]);
+                    eprintln!("Candidate qmake executable `{executable_name}` is for Qt{qmake_version} but QT_VERSION_MAJOR environment variable specified as {qt_version_major}. Trying next candidate executable...");
+                    version_mismatch = Some(QtBuildError::QtVersionMajorDoesNotMatch {
+                        qmake_version,
+                        qt_version_major,
+                    });
+                    continue;
+                }
+                Err(QtBuildError::QtToolchainMismatch {
+                    qmake_target_env,
+                    rust_target_env,
+                }) => {
+                    eprintln!("Candidate qmake executable `{executable_name}` is for the {qmake_target_env} toolchain but the Rust target environment is {rust_target_env}. Trying next candidate executable...");
+                    version_mismatch = Some(QtBuildError::QtToolchainMismatch {
+                        qmake_target_env,
+                        rust_target_env,
+                    });
                     continue;
                 }
                 Err(QtBuildError::QtMissing) => continue,
@@ -351,7 +458,17 @@ impl QtBuild {
             }
         }
 
-        Err(QtBuildError::QtMissing)
+        Err(version_mismatch.unwrap_or(QtBuildError::QtMissing))
+    }
+
+    /// Enable or disable running [qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html)
+    /// in [register_qml_module](Self::register_qml_module).
+    ///
+    /// By default qmlcachegen is used when it is available, which requires Qt 6.
+    /// When explicitly enabled, the build fails if qmlcachegen can not be used. This is useful
+    /// for targets where the QML JIT is disabled and QML files must be compiled ahead-of-time.
+    pub fn qmlcachegen(&mut self, enabled: bool) {
+        self.qmlcachegen_enabled = Some(enabled);
     }
 
     /// Get the output of running `qmake -query var_name`
@@ -459,6 +576,18 @@ impl QtBuild {
             }
         }
 
+        // Headers of frameworks are found with -F, for example <QtCore/QObject>
+        // is QtCore.framework/Headers/QObject
+        for framework_path in self.framework_paths() {
+            builder.flag(format!("-F{}", framework_path.display()));
+        }
+
+        // Executables run with `cargo run` and `cargo test` need to find the Qt libraries and
+        // frameworks without setting DYLD_FALLBACK_LIBRARY_PATH or DYLD_FALLBACK_FRAMEWORK_PATH
+        if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("macos") && !self.is_static() {
+            println!("cargo:rustc-link-arg=-Wl,-rpath,{lib_path}");
+        }
+
         let prefix = match &target {
             Ok(target) => {
                 if target.contains("windows") {
@@ -487,6 +616,12 @@ impl QtBuild {
                     format!("framework=Qt{qt_module}"),
                     format!("{lib_path}/Qt{qt_module}.framework/Resources/Qt{qt_module}.prl"),
                 )
+            } else if let Some(abi) = android_abi() {
+                // Qt for Android suffixes the libraries with the ABI
+                (
+                    format!("Qt{}{qt_module}_{abi}", self.version.major),
+                    self.find_qt_module_prl(&lib_path, prefix, self.version.major, qt_module),
+                )
             } else {
                 (
                     format!("Qt{}{qt_module}", self.version.major),
@@ -494,6 +629,16 @@ impl QtBuild {
                 )
             };
 
+            // Rust always links the release C runtime, which is incompatible with the debug
+            // libraries of Qt for MSVC, so only the release libraries can be used
+            if windows::windows_target_env().as_deref() == Some("msvc") {
+                let release_lib = format!("{lib_path}/Qt{}{qt_module}.lib", self.version.major);
+                let debug_lib = format!("{lib_path}/Qt{}{qt_module}d.lib", self.version.major);
+                if !Path::new(&release_lib).exists() && Path::new(&debug_lib).exists() {
+                    println!("cargo:warning=Only the debug library of Qt{}{qt_module} was found in {lib_path}, but Rust always uses the release C runtime. Install the release libraries of Qt.", self.version.major);
+                }
+            }
+
             self.cargo_link_qt_library(
                 &format!("Qt{}{qt_module}", self.version.major),
                 &prefix_path,
@@ -504,41 +649,336 @@ impl QtBuild {
             );
         }
 
-        let emscripten_targeted = match env::var("CARGO_CFG_TARGET_OS") {
-            Ok(val) => val == "emscripten",
-            Err(_) => false,
+        // The C++ code needs to use the same C runtime as Qt, which is the dynamic one unless Qt
+        // was configured with -static-runtime
+        if windows::windows_target_env().as_deref() == Some("msvc") {
+            let static_runtime = self.qconfig_contains("static_runtime");
+            builder.static_crt(static_runtime);
+            if windows::rust_static_crt() != static_runtime {
+                println!(
+                    "cargo:warning=Qt is built with the {} C runtime but Rust with the {} C runtime. {} the crt-static target feature in RUSTFLAGS.",
+                    if static_runtime { "static" } else { "dynamic" },
+                    if static_runtime { "dynamic" } else { "static" },
+                    if static_runtime { "Enable" } else { "Disable" },
+                );
+            }
+        }
+
+        // Qt for iOS needs to set up the UIApplication before main is called,
+        // so the entry point of executables linking to Qt Gui is a wrapper around main
+        let ios_targeted = env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("ios");
+        if ios_targeted && self.qt_modules.iter().any(|qt_module| qt_module == "Gui") {
+            println!("cargo:rustc-link-arg-bins=-Wl,-e,_qt_main_wrapper");
+        }
+
+        if emscripten_targeted() {
+            // Qt for WebAssembly with threads needs all code to be compiled with pthread support,
+            // otherwise wasm-ld refuses to link objects without the atomics feature
+            let threads = self.wasm_threads_enabled();
+            if threads {
+                builder.flag("-pthread");
+            }
+            for flag in wasm::emscripten_link_flags(threads) {
+                println!("cargo:rustc-link-arg={flag}");
+            }
+
+            if self.qt_modules.iter().any(|qt_module| qt_module == "Gui") {
+                self.cargo_link_static_plugin("platforms", "qwasm", builder);
+            }
+        }
+    }
+
+    /// Whether the detected Qt installation is a Qt for WebAssembly build with thread support,
+    /// such as the `wasm_multithread` kit of the Qt installer.
+    ///
+    /// This is read from the enabled features of the Qt Core module in its mkspecs.
+    pub fn wasm_threads_enabled(&self) -> bool {
+        let module_pri_path = format!(
+            "{}/mkspecs/modules/qt_lib_core.pri",
+            self.qmake_query("QT_INSTALL_ARCHDATA")
+        );
+        std::fs::read_to_string(module_pri_path)
+            .map(|module_pri| {
+                module_pri.lines().any(|line| {
+                    let line = line.trim();
+                    line.starts_with("QT.core.enabled_features")
+                        && line.split_whitespace().any(|word| word == "thread")
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// The prefix of static library and prl file names for the current target
+    fn static_library_prefix() -> &'static str {
+        match env::var("TARGET") {
+            Ok(target) if target.contains("windows") => "",
+            _ => "lib",
+        }
+    }
+
+    /// Whether the detected Qt installation was built as static libraries.
+    ///
+    /// With a static Qt, plugins are not loaded at runtime, instead they need to be linked with
+    /// [QtBuild::cargo_link_static_plugin] and imported with `Q_IMPORT_PLUGIN`.
+    pub fn is_static(&self) -> bool {
+        self.qconfig_contains("static")
+    }
+
+    /// Whether the `CONFIG` or `QT_CONFIG` variables of the qconfig.pri of Qt contain the given word
+    fn qconfig_contains(&self, config: &str) -> bool {
+        let qconfig_path = format!(
+            "{}/mkspecs/qconfig.pri",
+            self.qmake_query("QT_INSTALL_ARCHDATA")
+        );
+        std::fs::read_to_string(qconfig_path)
+            .map(|qconfig| {
+                qconfig.lines().any(|line| {
+                    let line = line.trim();
+                    (line.starts_with("CONFIG") || line.starts_with("QT_CONFIG"))
+                        && line.split_whitespace().any(|word| word == config)
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// When targeting Windows, copy the DLLs of the linked Qt modules into the given directory,
+    /// so that executables in it can be run without adding the bin directory of Qt to `PATH`.
+    ///
+    /// The `qwindows` platform plugin is copied into the `platforms` subdirectory if Qt Gui is linked.
+    /// With Qt for MinGW, the MinGW runtime DLLs shipped with Qt are copied too.
+    /// This does nothing for other targets or when Qt is static.
+    pub fn deploy_windows_dlls(&self, dir: impl AsRef<Path>) {
+        if windows::windows_target_env().is_none() || self.is_static() {
+            return;
+        }
+
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .unwrap_or_else(|_| panic!("Could not create directory {}", dir.display()));
+        let bin_path = PathBuf::from(self.qmake_query("QT_INSTALL_BINS"));
+        let copy_if_newer = |source: &Path, destination: &Path| {
+            let is_outdated = match (source.metadata(), destination.metadata()) {
+                (Ok(source), Ok(destination)) => {
+                    match (source.modified(), destination.modified()) {
+                        (Ok(source), Ok(destination)) => source > destination,
+                        _ => true,
+                    }
+                }
+                (Ok(_), Err(_)) => true,
+                (Err(_), _) => return,
+            };
+            if is_outdated {
+                std::fs::copy(source, destination).unwrap_or_else(|_| {
+                    panic!(
+                        "Could not copy {} to {}",
+                        source.display(),
+                        destination.display()
+                    )
+                });
+            }
         };
-        if emscripten_targeted {
-            let platforms_path = format!("{}/platforms", self.qmake_query("QT_INSTALL_PLUGINS"));
-            println!("cargo:rustc-link-search={platforms_path}");
-            self.cargo_link_qt_library(
-                "qwasm",
-                &prefix_path,
-                &lib_path,
-                "qwasm",
-                &format!("{platforms_path}/libqwasm.prl"),
-                builder,
+
+        for qt_module in &self.qt_modules {
+            let dll_name = format!("Qt{}{qt_module}.dll", self.version.major);
+            copy_if_newer(&bin_path.join(&dll_name), &dir.join(&dll_name));
+        }
+        for dll_name in windows::MINGW_RUNTIME_DLLS {
+            copy_if_newer(&bin_path.join(dll_name), &dir.join(dll_name));
+        }
+
+        if self.qt_modules.iter().any(|qt_module| qt_module == "Gui") {
+            let platforms_dir = dir.join("platforms");
+            std::fs::create_dir_all(&platforms_dir).unwrap_or_else(|_| {
+                panic!("Could not create directory {}", platforms_dir.display())
+            });
+            copy_if_newer(
+                &PathBuf::from(self.qmake_query("QT_INSTALL_PLUGINS"))
+                    .join("platforms")
+                    .join("qwindows.dll"),
+                &platforms_dir.join("qwindows.dll"),
             );
         }
     }
 
+    /// Same as [QtBuild::deploy_windows_dlls], for the directories of the current Cargo profile
+    /// containing the executables and the test executables, so that `cargo run` and `cargo test`
+    /// work without adding the bin directory of Qt to `PATH`.
+    pub fn cargo_deploy_windows_dlls(&self) {
+        // OUT_DIR is target/<profile>/build/<package>-<hash>/out
+        let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+        if let Some(profile_dir) = out_dir.ancestors().nth(3) {
+            self.deploy_windows_dlls(profile_dir);
+            self.deploy_windows_dlls(profile_dir.join("deps"));
+        }
+    }
+
+    /// Link a plugin of a static Qt installation, for example `("platforms", "qxcb")`.
+    ///
+    /// The plugin also needs to be imported with `Q_IMPORT_PLUGIN` using its class name
+    /// in a file that is linked into the final binary.
+    pub fn cargo_link_static_plugin(
+        &self,
+        plugin_type: &str,
+        plugin_name: &str,
+        builder: &mut cc::Build,
+    ) {
+        let plugin_path = format!("{}/{plugin_type}", self.qmake_query("QT_INSTALL_PLUGINS"));
+        println!("cargo:rustc-link-search={plugin_path}");
+        self.cargo_link_qt_library(
+            plugin_name,
+            &self.qmake_query("QT_INSTALL_PREFIX"),
+            &self.qmake_query("QT_INSTALL_LIBS"),
+            plugin_name,
+            &format!(
+                "{plugin_path}/{}{plugin_name}.prl",
+                Self::static_library_prefix()
+            ),
+            builder,
+        );
+    }
+
+    /// Link the plugin of a QML module that is shipped with a static Qt installation,
+    /// for example `"QtQuick.Controls"`.
+    ///
+    /// The plugin is found using the qmldir of the module in `QT_INSTALL_QML`.
+    /// Returns the class name of the plugin, which needs to be imported with `Q_IMPORT_PLUGIN`,
+    /// or `None` if the module does not have a plugin.
+    pub fn cargo_link_static_qml_module(
+        &self,
+        uri: &str,
+        builder: &mut cc::Build,
+    ) -> Option<String> {
+        let module_path = format!(
+            "{}/{}",
+            self.qmake_query("QT_INSTALL_QML"),
+            uri.replace('.', "/")
+        );
+        let qmldir = std::fs::read_to_string(format!("{module_path}/qmldir"))
+            .unwrap_or_else(|_| panic!("Could not find the qmldir of QML module {uri}"));
+
+        let mut plugin = None;
+        let mut class_name = None;
+        for line in qmldir.lines() {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                ["plugin", name] | ["optional", "plugin", name] => {
+                    plugin = Some((name.to_string(), module_path.clone()));
+                }
+                ["plugin", name, path] | ["optional", "plugin", name, path] => {
+                    plugin = Some((name.to_string(), format!("{module_path}/{path}")));
+                }
+                ["classname", name] => class_name = Some(name.to_string()),
+                _ => {}
+            }
+        }
+
+        let (plugin_name, plugin_path) = plugin?;
+        println!("cargo:rustc-link-search={plugin_path}");
+        self.cargo_link_qt_library(
+            &plugin_name,
+            &self.qmake_query("QT_INSTALL_PREFIX"),
+            &self.qmake_query("QT_INSTALL_LIBS"),
+            &plugin_name,
+            &format!(
+                "{plugin_path}/{}{plugin_name}.prl",
+                Self::static_library_prefix()
+            ),
+            builder,
+        );
+        class_name
+    }
+
+    /// The platform plugin and its class name that is used by default for the current target,
+    /// which needs to be linked when using a static Qt installation.
+    pub fn default_static_platform_plugin() -> Option<(&'static str, &'static str)> {
+        let target_os = env::var("CARGO_CFG_TARGET_OS").ok()?;
+        match target_os.as_str() {
+            "linux" | "freebsd" | "netbsd" | "openbsd" => Some(("qxcb", "QXcbIntegrationPlugin")),
+            "windows" => Some(("qwindows", "QWindowsIntegrationPlugin")),
+            "macos" => Some(("qcocoa", "QCocoaIntegrationPlugin")),
+            "ios" => Some(("qios", "QIOSIntegrationPlugin")),
+            "android" => Some(("qtforandroid", "QAndroidPlatformIntegrationPlugin")),
+            "emscripten" => Some(("qwasm", "QWasmIntegrationPlugin")),
+            _ => None,
+        }
+    }
+
     /// Get the include paths for Qt, including Qt module subdirectories. This is intended
     /// to be passed to whichever tool you are using to invoke the C++ compiler.
+    ///
+    /// When Qt is installed as frameworks on Apple platforms, the `Headers` directories of the
+    /// frameworks are included too, but [QtBuild::framework_paths] also need to be passed with `-F`
+    /// for includes such as `<QtCore/QObject>`.
     pub fn include_paths(&self) -> Vec<PathBuf> {
         let root_path = self.qmake_query("QT_INSTALL_HEADERS");
         let mut paths = Vec::new();
         for qt_module in &self.qt_modules {
             paths.push(format!("{root_path}/Qt{qt_module}"));
         }
+        for framework_path in self.framework_paths() {
+            for qt_module in &self.qt_modules {
+                let headers_path = framework_path
+                    .join(format!("Qt{qt_module}.framework"))
+                    .join("Headers");
+                if headers_path.exists() {
+                    paths.push(headers_path.to_string_lossy().to_string());
+                }
+            }
+        }
         paths.push(root_path);
         paths.iter().map(PathBuf::from).collect()
     }
 
+    /// Get the directories containing the Qt frameworks when Qt is installed as frameworks,
+    /// which is the default for Qt on macOS. This is intended to be passed with `-F` to the
+    /// C++ compiler. For other installations of Qt, this is empty.
+    pub fn framework_paths(&self) -> Vec<PathBuf> {
+        let is_apple = env::var("CARGO_CFG_TARGET_VENDOR").as_deref() == Ok("apple");
+        let lib_path = PathBuf::from(self.qmake_query("QT_INSTALL_LIBS"));
+        if is_apple && lib_path.join("QtCore.framework").exists() {
+            vec![lib_path]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Run [macdeployqt](https://doc.qt.io/qt-6/macos-deployment.html) on an application bundle,
+    /// which copies the Qt frameworks and plugins that are used by the application into the bundle.
+    ///
+    /// The `qml_dirs` are scanned for QML imports, so that the QML modules that are used are deployed too.
+    /// This cannot be called from the build script of the application, as the bundle is
+    /// created after the executable is linked, but it can be called from an xtask or a packaging tool.
+    pub fn macdeployqt(&self, app_bundle: impl AsRef<Path>, qml_dirs: &[impl AsRef<Path>]) {
+        let macdeployqt = self
+            .get_qt_tool("macdeployqt")
+            .expect("Could not find macdeployqt");
+        let mut cmd = Command::new(macdeployqt);
+        cmd.arg(app_bundle.as_ref());
+        for qml_dir in qml_dirs {
+            cmd.arg(format!("-qmldir={}", qml_dir.as_ref().display()));
+        }
+        let output = cmd
+            .output()
+            .unwrap_or_else(|_| panic!("macdeployqt failed for {}", app_bundle.as_ref().display()));
+        if !output.status.success() {
+            panic!(
+                "macdeployqt failed for {}:\n{}",
+                app_bundle.as_ref().display(),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
     /// Version of the detected Qt installation
     pub fn version(&self) -> &SemVer {
         &self.version
     }
 
+    /// Find the path of a Qt executable tool, for example `"rcc"` or `"qmlimportscanner"`
+    pub fn tool_path(&self, tool_name: &str) -> Option<PathBuf> {
+        self.get_qt_tool(tool_name).ok().map(PathBuf::from)
+    }
+
     /// Lazy load the path of a Qt executable tool
     /// Skip doing this in the constructor because not every user of this crate will use each tool
     fn get_qt_tool(&self, tool_name: &str) -> Result<String, ()> {
@@ -594,76 +1034,198 @@ impl QtBuild {
         Err(())
     }
 
+    /// Find the metatypes.json files that Qt installs for the enabled Qt modules.
+    ///
+    /// These are passed to qmltyperegistrar as foreign types, so that the generated qmltypes
+    /// describe the Qt base classes (e.g. QAbstractListModel) of the QML types as well.
+    /// Qt 5 does not install metatypes files, so this is empty for Qt 5.
+    fn foreign_metatypes(&self) -> Vec<String> {
+        if self.version.major < 6 {
+            return vec![];
+        }
+
+        // Qt < 6.5 installs the metatypes into the lib directory, newer versions into the arch data directory
+        let mut metatypes = Vec::new();
+        for qmake_query_var in ["QT_INSTALL_ARCHDATA", "QT_INSTALL_LIBS"] {
+            let metatypes_dir = Path::new(&self.qmake_query(qmake_query_var)).join("metatypes");
+            let entries = match std::fs::read_dir(&metatypes_dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+
+            let mut files: Vec<_> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    let file_name = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    file_name.ends_with("metatypes.json")
+                        && self.qt_modules.iter().any(|qt_module| {
+                            file_name.starts_with(&format!(
+                                "qt{}{}_",
+                                self.version.major,
+                                qt_module.to_lowercase()
+                            ))
+                        })
+                })
+                .map(|path| path.to_string_lossy().to_string())
+                .collect();
+            // Sort to keep the command line stable between builds
+            files.sort();
+
+            if !files.is_empty() {
+                metatypes.append(&mut files);
+                break;
+            }
+        }
+        metatypes
+    }
+
     /// Run moc on a C++ header file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
     /// The return value contains the path to the generated C++ file, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file),
     /// as well as the path to the generated metatypes.json file, which can be passed to [register_qml_module](Self::register_qml_module).
     ///
     pub fn moc(&mut self, input_file: impl AsRef<Path>, arguments: MocArguments) -> MocProducts {
+        self.moc_parallel(vec![(input_file.as_ref().to_path_buf(), arguments)])
+            .pop()
+            .unwrap()
+    }
+
+    /// Same as [QtBuild::moc], but run moc for multiple files in parallel.
+    ///
+    /// Up to as many moc processes as Cargo allows jobs (the `NUM_JOBS` environment variable)
+    /// are run at the same time. The products are returned in the same order as the inputs.
+    /// moc is not run again for files that did not change since the previous run with the same arguments.
+    pub fn moc_parallel(&mut self, inputs: Vec<(PathBuf, MocArguments)>) -> Vec<MocProducts> {
         if self.moc_executable.is_none() {
             self.moc_executable = Some(self.get_qt_tool("moc").expect("Could not find moc"));
         }
 
-        let input_path = input_file.as_ref();
-
         // Put all the moc files into one place, this can then be added to the include path
         let moc_dir = PathBuf::from(format!(
             "{}/qt-build-utils/moc",
             env::var("OUT_DIR").unwrap()
         ));
         std::fs::create_dir_all(&moc_dir).expect("Could not create moc dir");
-        let output_path = moc_dir.join(format!(
-            "moc_{}.cpp",
-            input_path.file_name().unwrap().to_str().unwrap()
-        ));
 
-        let metatypes_json_path = PathBuf::from(&format!("{}.json", output_path.display()));
+        let qt_include_paths = self.include_paths();
+        let framework_paths = self.framework_paths();
+        let num_jobs = env::var("NUM_JOBS")
+            .ok()
+            .and_then(|num_jobs| num_jobs.parse::<usize>().ok())
+            .unwrap_or(1)
+            .max(1);
+
+        let mut products = Vec::with_capacity(inputs.len());
+        let mut commands = Vec::new();
+        for (input_path, arguments) in &inputs {
+            let output_path = moc_dir.join(format!(
+                "moc_{}.cpp",
+                input_path.file_name().unwrap().to_str().unwrap()
+            ));
+            let metatypes_json_path = PathBuf::from(&format!("{}.json", output_path.display()));
+
+            let mut include_args = String::new();
+            // Qt includes
+            for include_path in qt_include_paths
+                .iter()
+                .chain(arguments.include_paths.iter())
+            {
+                include_args += &format!("-I {} ", include_path.display());
+            }
 
-        let mut include_args = String::new();
-        // Qt includes
-        for include_path in self
-            .include_paths()
-            .iter()
-            .chain(arguments.include_paths.iter())
-        {
-            include_args += &format!("-I {} ", include_path.display());
-        }
+            let mut cmd = Command::new(self.moc_executable.as_ref().unwrap());
+
+            for framework_path in &framework_paths {
+                cmd.arg(format!("-F{}", framework_path.display()));
+            }
+            if let Some(uri) = &arguments.uri {
+                cmd.arg(format!("-Muri={uri}"));
+            }
 
-        let mut cmd = Command::new(self.moc_executable.as_ref().unwrap());
+            cmd.args(include_args.trim_end().split(' '));
+            cmd.arg(input_path.to_str().unwrap())
+                .arg("-o")
+                .arg(output_path.to_str().unwrap())
+                .arg("--output-json")
+                .stdout(Stdio::null())
+                .stderr(Stdio::piped());
+
+            // Skip running moc again if neither the input nor the command changed since the last run
+            let command_path = PathBuf::from(format!("{}.cmd", output_path.display()));
+            let command_line = format!("{cmd:?}");
+            let is_up_to_date = std::fs::read_to_string(&command_path)
+                .is_ok_and(|previous| previous == command_line)
+                && metatypes_json_path.exists()
+                && match (input_path.metadata(), output_path.metadata()) {
+                    (Ok(input), Ok(output)) => match (input.modified(), output.modified()) {
+                        (Ok(input), Ok(output)) => output >= input,
+                        _ => false,
+                    },
+                    _ => false,
+                };
+            if !is_up_to_date {
+                commands.push((products.len(), input_path, cmd, command_path, command_line));
+            }
 
-        if let Some(uri) = arguments.uri {
-            cmd.arg(format!("-Muri={uri}"));
+            products.push(MocProducts {
+                cpp: output_path,
+                metatypes_json: metatypes_json_path,
+                duration: None,
+            });
         }
 
-        cmd.args(include_args.trim_end().split(' '));
-        cmd.arg(input_path.to_str().unwrap())
-            .arg("-o")
-            .arg(output_path.to_str().unwrap())
-            .arg("--output-json");
-        let cmd = cmd
-            .output()
-            .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
+        for chunk in commands.chunks_mut(num_jobs) {
+            let start = Instant::now();
+            let children = chunk
+                .iter_mut()
+                .map(|(index, input_path, cmd, _, _)| {
+                    let child = cmd
+                        .spawn()
+                        .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
+                    (*index, input_path, child)
+                })
+                .collect::<Vec<_>>();
+
+            for (index, input_path, child) in children {
+                let cmd = child
+                    .wait_with_output()
+                    .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
 
-        if !cmd.status.success() {
-            panic!(
-                "moc failed for {}:\n{}",
-                input_path.display(),
-                String::from_utf8_lossy(&cmd.stderr)
-            );
-        }
+                if !cmd.status.success() {
+                    panic!(
+                        "moc failed for {}:\n{}",
+                        input_path.display(),
+                        String::from_utf8_lossy(&cmd.stderr)
+                    );
+                }
+                products[index].duration = Some(start.elapsed());
+            }
 
-        MocProducts {
-            cpp: output_path,
-            metatypes_json: metatypes_json_path,
+            for (_, _, _, command_path, command_line) in chunk.iter() {
+                std::fs::write(command_path, command_line)
+                    .expect("Could not write moc command file");
+            }
         }
+        products
     }
 
     /// Generate C++ files to automatically register a QML module at build time using the JSON output from [moc](Self::moc).
     ///
     /// This generates a [qmldir file](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) for the QML module.
+    /// `.qml` files with a relative path inside the module and a name starting with an uppercase letter
+    /// are listed in the qmldir as types of the module.
     /// The `qml_files` and `qrc_files` are registered with the [Qt Resource System](https://doc.qt.io/qt-6/resources.html) in
     /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
     ///
     /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html) to compile the specified .qml files ahead-of-time.
+    ///
+    /// A `plugin.qmltypes` file describing the QML types of the module is generated next to the qmldir,
+    /// this allows tooling such as qmllint and Qt Creator to understand the types.
+    /// When using Qt 6, the metatypes of the Qt modules are passed as foreign types so that the
+    /// Qt base classes are described as well.
     pub fn register_qml_module(
         &mut self,
         metatypes_json: &[impl AsRef<Path>],
@@ -681,11 +1243,33 @@ impl QtBuild {
             );
         }
         // qmlcachegen has a different CLI in Qt 5, so only support Qt >= 6
-        if self.qmlcachegen_executable.is_none() && self.version.major >= 6 {
-            if let Ok(qmlcachegen_executable) = self.get_qt_tool("qmlcachegen") {
-                self.qmlcachegen_executable = Some(qmlcachegen_executable);
+        let qmlcachegen_required = self.qmlcachegen_enabled == Some(true);
+        if qmlcachegen_required && self.version.major < 6 {
+            panic!(
+                "qmlcachegen was enabled for QML module {uri}, but it is only supported with Qt 6"
+            );
+        }
+        if self.qmlcachegen_executable.is_none()
+            && self.qmlcachegen_enabled != Some(false)
+            && self.version.major >= 6
+        {
+            match self.get_qt_tool("qmlcachegen") {
+                Ok(qmlcachegen_executable) => {
+                    self.qmlcachegen_executable = Some(qmlcachegen_executable);
+                }
+                Err(_) if qmlcachegen_required => {
+                    panic!(
+                        "qmlcachegen was enabled for QML module {uri}, but it could not be found"
+                    )
+                }
+                Err(_) => {}
             }
         }
+        let qmlcachegen_executable = if self.qmlcachegen_enabled == Some(false) {
+            None
+        } else {
+            self.qmlcachegen_executable.clone()
+        };
 
         let qml_uri_dirs = uri.replace('.', "/");
 
@@ -714,6 +1298,29 @@ prefer :/qt/qml/{qml_uri_dirs}/
 "
             )
             .expect("Could not write qmldir file");
+
+            // Register QML files as types of the module, like qt_add_qml_module does.
+            // Only relative paths within the module can be referenced from the qmldir.
+            for file in qml_files {
+                let path = file.as_ref();
+                let is_within_module = path
+                    .components()
+                    .all(|component| matches!(component, std::path::Component::Normal(_)));
+                if path.extension() != Some("qml".as_ref()) || !is_within_module {
+                    continue;
+                }
+
+                if let Some(type_name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    if type_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                        writeln!(
+                            qmldir,
+                            "{type_name} {version_major}.{version_minor} {}",
+                            path.display()
+                        )
+                        .expect("Could not write qmldir file");
+                    }
+                }
+            }
         }
 
         // Generate .qrc file and run rcc on it
@@ -761,7 +1368,7 @@ prefer :/qt/qml/{qml_uri_dirs}/
         // qmlcachegen needs to be run once for each .qml file with --resource-path,
         // then once for the module with --resource-name.
         let mut qmlcachegen_file_paths = Vec::new();
-        if let Some(qmlcachegen_executable) = &self.qmlcachegen_executable {
+        if let Some(qmlcachegen_executable) = &qmlcachegen_executable {
             let qmlcachegen_dir = qt_build_utils_dir.join("qmlcachegen").join(&qml_uri_dirs);
             std::fs::create_dir_all(&qmlcachegen_dir)
                 .expect("Could not create qmlcachegen directory for QML module");
@@ -878,6 +1485,10 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 "-o".to_string(),
                 qmltyperegistrar_output_path.to_string_lossy().to_string(),
             ];
+            let foreign_metatypes = self.foreign_metatypes();
+            if !foreign_metatypes.is_empty() {
+                args.push(format!("--foreign-types={}", foreign_metatypes.join(",")));
+            }
             args.extend(metatypes_json);
             let cmd = Command::new(self.qmltyperegistrar_executable.as_ref().unwrap())
                 .args(args)
@@ -911,7 +1522,7 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 &format!("qInitResources_qml_module_resources_{qml_uri_underscores}_qrc"),
             );
 
-            if !qml_files.is_empty() && self.qmlcachegen_executable.is_some() {
+            if !qml_files.is_empty() && qmlcachegen_executable.is_some() {
                 generate_usage(
                     "int",
                     &format!("qInitResources_qmlcache_{qml_uri_underscores}"),
@@ -975,10 +1586,180 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             qmltyperegistrar: qmltyperegistrar_output_path,
             plugin: qml_plugin_cpp_path,
             plugin_init: qml_plugin_init_path,
+            qmldir: qmldir_file_path,
+            qmltypes: qmltypes_path,
             include_path,
         }
     }
 
+    /// Run [qmllint](https://doc.qt.io/qt-6/qtqml-tooling-qmllint.html) on `.qml` files with the given QML import paths,
+    /// for example the directories containing the `qmldir` and `plugin.qmltypes` of the QML modules
+    /// registered with [register_qml_module](Self::register_qml_module), so that the types defined in C++ or Rust are known.
+    ///
+    /// Panics with the output of qmllint if it reports any warnings or errors.
+    pub fn qmllint(&self, qml_files: &[impl AsRef<Path>], import_paths: &[impl AsRef<Path>]) {
+        if qml_files.is_empty() {
+            return;
+        }
+        let qmllint_executable = self.get_qt_tool("qmllint").expect("Could not find qmllint");
+
+        let mut args = Vec::new();
+        for import_path in import_paths {
+            args.push("-I".to_string());
+            args.push(import_path.as_ref().to_string_lossy().to_string());
+        }
+        args.extend(
+            qml_files
+                .iter()
+                .map(|file| file.as_ref().to_string_lossy().to_string()),
+        );
+
+        let cmd = Command::new(qmllint_executable)
+            .args(args)
+            .output()
+            .unwrap_or_else(|_| panic!("qmllint failed to run"));
+        if !cmd.status.success() {
+            panic!(
+                "qmllint failed:\n{}{}",
+                String::from_utf8_lossy(&cmd.stdout),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
+    }
+
+    /// Check that `.qml` files are formatted with [qmlformat](https://doc.qt.io/qt-6/qtqml-tooling-qmlformat.html).
+    ///
+    /// The files are not modified. Panics listing the files that qmlformat fails to parse
+    /// or that are not formatted, which can be formatted by running `qmlformat -i` on them.
+    pub fn qmlformat(&self, qml_files: &[impl AsRef<Path>]) {
+        if qml_files.is_empty() {
+            return;
+        }
+        let qmlformat_executable = self
+            .get_qt_tool("qmlformat")
+            .expect("Could not find qmlformat");
+
+        let mut errors = Vec::new();
+        for file in qml_files {
+            let path = file.as_ref();
+            let cmd = Command::new(&qmlformat_executable)
+                .arg(path)
+                .output()
+                .unwrap_or_else(|_| panic!("qmlformat failed to run for {}", path.display()));
+            if !cmd.status.success() {
+                errors.push(format!(
+                    "{}: {}",
+                    path.display(),
+                    String::from_utf8_lossy(&cmd.stderr)
+                ));
+                continue;
+            }
+
+            let contents = std::fs::read(path)
+                .unwrap_or_else(|_| panic!("Could not read QML file {}", path.display()));
+            if contents != cmd.stdout {
+                errors.push(format!("{}: not formatted", path.display()));
+            }
+        }
+        if !errors.is_empty() {
+            panic!(
+                "qmlformat failed, run `qmlformat -i` on the files to format them:\n{}",
+                errors.join("\n")
+            );
+        }
+    }
+
+    /// Run [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html) on source files to update the
+    /// translatable strings of Qt Linguist `.ts` files, which are created if they do not exist.
+    ///
+    /// `.qml` and `.js` files are parsed as QML, other files such as `.rs` files with the C++ parser of lupdate.
+    /// The `.ts` files are only written when their contents change, so that they do not trigger
+    /// a rerun of the build script.
+    pub fn lupdate(&self, sources: &[impl AsRef<Path>], ts_files: &[impl AsRef<Path>]) {
+        if ts_files.is_empty() {
+            return;
+        }
+        let lupdate_executable = self.get_qt_tool("lupdate").expect("Could not find lupdate");
+
+        // Update copies of the .ts files, keeping their file names as lupdate uses them
+        // to find the language of new .ts files
+        let lupdate_dir = PathBuf::from(format!(
+            "{}/qt-build-utils/lupdate",
+            env::var("OUT_DIR").unwrap()
+        ));
+        let ts_copies = ts_files
+            .iter()
+            .enumerate()
+            .map(|(index, ts_file)| {
+                let ts_file = ts_file.as_ref();
+                let copy_dir = lupdate_dir.join(index.to_string());
+                std::fs::create_dir_all(&copy_dir).expect("Could not create lupdate dir");
+                let copy = copy_dir.join(ts_file.file_name().unwrap());
+                if ts_file.exists() {
+                    std::fs::copy(ts_file, &copy).unwrap_or_else(|_| {
+                        panic!("Could not copy translation file {}", ts_file.display())
+                    });
+                } else if copy.exists() {
+                    std::fs::remove_file(&copy).expect("Could not remove old translation file");
+                }
+                copy
+            })
+            .collect::<Vec<_>>();
+
+        let cmd = Command::new(lupdate_executable)
+            .arg("-silent")
+            .args(sources.iter().map(|source| source.as_ref()))
+            .arg("-ts")
+            .args(&ts_copies)
+            .output()
+            .unwrap_or_else(|_| panic!("lupdate failed to run"));
+        if !cmd.status.success() {
+            panic!("lupdate failed:\n{}", String::from_utf8_lossy(&cmd.stderr));
+        }
+
+        for (ts_file, copy) in ts_files.iter().zip(ts_copies) {
+            let ts_file = ts_file.as_ref();
+            let contents = std::fs::read(&copy).expect("Could not read updated translation file");
+            if std::fs::read(ts_file).ok().as_ref() != Some(&contents) {
+                std::fs::write(ts_file, contents).unwrap_or_else(|_| {
+                    panic!("Could not write translation file {}", ts_file.display())
+                });
+            }
+        }
+    }
+
+    /// Run [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html) on a Qt Linguist `.ts` file and save the
+    /// `.qm` file into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    /// The path to the `.qm` file is returned, which can be included into the binary with [qrc](Self::qrc)
+    /// and loaded with a [QTranslator](https://doc.qt.io/qt-6/qtranslator.html).
+    pub fn lrelease(&self, ts_file: impl AsRef<Path>) -> PathBuf {
+        let lrelease_executable = self
+            .get_qt_tool("lrelease")
+            .expect("Could not find lrelease");
+
+        let ts_file = ts_file.as_ref();
+        let output_folder = PathBuf::from(format!(
+            "{}/qt-build-utils/lrelease",
+            env::var("OUT_DIR").unwrap()
+        ));
+        std::fs::create_dir_all(&output_folder).expect("Could not create lrelease dir");
+        let output_path = output_folder.join(ts_file.with_extension("qm").file_name().unwrap());
+
+        let cmd = Command::new(lrelease_executable)
+            .args(["-silent".as_ref(), ts_file.as_os_str(), "-qm".as_ref()])
+            .arg(&output_path)
+            .output()
+            .unwrap_or_else(|_| panic!("lrelease failed for {}", ts_file.display()));
+        if !cmd.status.success() {
+            panic!(
+                "lrelease failed for {}:\n{}",
+                ts_file.display(),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
+        output_path
+    }
+
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
     /// The path to the generated C++ file is returned, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file).
     /// The compiled static library must be linked with [+whole-archive](https://doc.rust-lang.org/rustc/command-line-arguments.html#linking-modifiers-whole-archive)
@@ -1048,3 +1829,811 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{Mutex, MutexGuard};
+
+    /// The environment variables which are read by qt-build-utils
+    const TEST_ENV_VARS: [&str; 25] = [
+        "QMAKE",
+        "QT_DISCOVERY",
+        "QT_VERSION_MAJOR",
+        "QT_DIR",
+        "QTDIR",
+        "CMAKE_PREFIX_PATH",
+        "VCPKG_ROOT",
+        "VCPKG_INSTALLATION_ROOT",
+        "VCPKG_DEFAULT_TRIPLET",
+        "PKG_CONFIG",
+        "PKG_CONFIG_PATH",
+        "CARGO_CFG_TARGET_OS",
+        "CARGO_CFG_TARGET_ARCH",
+        "CARGO_CFG_TARGET_ENV",
+        "CARGO_CFG_TARGET_VENDOR",
+        "CARGO_CFG_TARGET_FEATURE",
+        "TARGET",
+        "OUT_DIR",
+        "ANDROID_PLATFORM",
+        "ANDROID_NATIVE_API_LEVEL",
+        "ANDROID_NDK_ROOT",
+        "ANDROID_NDK_HOME",
+        "CXX",
+        "CXX_aarch64-linux-android",
+        "CXX_aarch64_linux_android",
+    ];
+
+    /// The environment of a test, which is shared between the threads of the tests
+    ///
+    /// Creating it waits for other tests using the environment, and clears the variables
+    /// read by qt-build-utils. Variables set with [TestEnv::set] are removed and the
+    /// current directory is restored when it is dropped.
+    pub(crate) struct TestEnv {
+        _lock: MutexGuard<'static, ()>,
+        current_dir: PathBuf,
+    }
+
+    impl TestEnv {
+        pub(crate) fn new() -> Self {
+            static ENV_LOCK: Mutex<()> = Mutex::new(());
+            // A failed test poisons the lock, which is not a problem for the other tests
+            let lock = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+            for var in TEST_ENV_VARS {
+                env::remove_var(var);
+            }
+            Self {
+                _lock: lock,
+                current_dir: env::current_dir().unwrap(),
+            }
+        }
+
+        pub(crate) fn set(&self, var: &str, value: impl AsRef<std::ffi::OsStr>) -> &Self {
+            assert!(
+                TEST_ENV_VARS.contains(&var),
+                "{var} is not cleared by TestEnv"
+            );
+            env::set_var(var, value);
+            self
+        }
+
+        /// Change the current directory, which relative paths passed to Qt tools are relative to
+        pub(crate) fn set_current_dir(&self, dir: impl AsRef<Path>) -> &Self {
+            env::set_current_dir(dir).unwrap();
+            self
+        }
+    }
+
+    impl Drop for TestEnv {
+        fn drop(&mut self) {
+            for var in TEST_ENV_VARS {
+                env::remove_var(var);
+            }
+            let _ = env::set_current_dir(&self.current_dir);
+        }
+    }
+
+    /// A new empty directory for a test, inside the temporary directory of the system
+    pub(crate) fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir()
+            .join(format!("qt-build-utils-tests-{}", std::process::id()))
+            .join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Write an executable shell script
+    #[cfg(unix)]
+    pub(crate) fn write_script(path: &Path, script: &str) {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, format!("#!/bin/sh\n{script}\n")).unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    /// Create a fake Qt tool in the fake Qt installation, which creates the empty output file given with `-o`
+    ///
+    /// The arguments of each run are appended as a line to the `<tool>.log` file next to it.
+    #[cfg(unix)]
+    pub(crate) fn fake_qt_tool(prefix: &Path, tool_name: &str) -> PathBuf {
+        let tool = prefix.join("bin").join(tool_name);
+        write_script(
+            &tool,
+            r#"[ "$1" = "-help" ] && exit 0
+echo "$@" >> "$0.log"
+output=""
+for arg in "$@"; do
+    [ -n "$output" ] && : > "$arg"
+    if [ "$arg" = "-o" ]; then output=1; else output=""; fi
+done"#,
+        );
+        tool.with_extension("log")
+    }
+
+    /// Create a fake Qt installation in the given prefix, returning the path of its qmake
+    ///
+    /// qmake answers the queries for the version and the directories of the installation,
+    /// the mkspec is read from the `xspec` file in the prefix if it exists.
+    #[cfg(unix)]
+    pub(crate) fn fake_qt(prefix: &Path, version: &str) -> PathBuf {
+        let qmake = prefix.join("bin").join("qmake");
+        let prefix = prefix.display();
+        write_script(
+            &qmake,
+            &format!(
+                r#"prefix='{prefix}'
+case "$2" in
+    QT_VERSION) echo '{version}' ;;
+    QMAKE_XSPEC) cat "$prefix/xspec" 2>/dev/null || echo linux-g++ ;;
+    QT_INSTALL_PREFIX|QT_INSTALL_ARCHDATA) echo "$prefix" ;;
+    QT_HOST_BINS*|QT_HOST_LIBEXECS*|QT_INSTALL_BINS*|QT_INSTALL_LIBEXECS*) echo "$prefix/bin" ;;
+    QT_INSTALL_HEADERS) echo "$prefix/include" ;;
+    QT_INSTALL_LIBS) echo "$prefix/lib" ;;
+    QT_INSTALL_PLUGINS) echo "$prefix/plugins" ;;
+    QT_INSTALL_QML) echo "$prefix/qml" ;;
+    *) exit 1 ;;
+esac"#
+            ),
+        );
+        qmake
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qmake_env_var() {
+        let dir = test_dir("qmake_env_var");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        assert_eq!(qtbuild.version().major, 6);
+        assert_eq!(qtbuild.version().minor, 5);
+        assert_eq!(
+            qtbuild.qmake_query("QT_INSTALL_LIBS"),
+            format!("{}/lib", dir.display())
+        );
+        assert_eq!(qtbuild.qt_modules, vec!["Core".to_string()]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qmake_env_var_missing() {
+        let dir = test_dir("qmake_env_var_missing");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", dir.join("bin").join("qmake"));
+
+        assert!(matches!(
+            QtBuild::new(vec![]),
+            Err(QtBuildError::QMakeSetQtMissing { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qt_version_major() {
+        let dir = test_dir("qt_version_major");
+        let qmake = fake_qt(&dir, "5.15.2");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake).set("QT_VERSION_MAJOR", "5");
+        assert_eq!(QtBuild::new(vec![]).unwrap().version().major, 5);
+
+        test_env.set("QT_VERSION_MAJOR", "6");
+        match QtBuild::new(vec![]) {
+            Err(QtBuildError::QMakeSetQtMissing { error, .. }) => assert!(matches!(
+                *error,
+                QtBuildError::QtVersionMajorDoesNotMatch {
+                    qmake_version: 5,
+                    qt_version_major: 6
+                }
+            )),
+            _ => panic!("Expected QtVersionMajorDoesNotMatch"),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qt_discovery_env_var() {
+        let dir = test_dir("qt_discovery_env_var");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let test_env = TestEnv::new();
+        test_env.set("QT_DIR", &dir).set("QT_DISCOVERY", "qtdir");
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        assert_eq!(qtbuild.qmake_executable, qmake.to_string_lossy());
+
+        // Only the given strategies are used
+        test_env.set("QT_DISCOVERY", "cmake");
+        assert!(matches!(QtBuild::new(vec![]), Err(QtBuildError::QtMissing)));
+
+        test_env.set("QT_DISCOVERY", "qtdir,unknown");
+        assert!(matches!(
+            QtBuild::new(vec![]),
+            Err(QtBuildError::QtDiscoveryInvalid { name, .. }) if name == "unknown"
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_is_static() {
+        let dir = test_dir("is_static");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        assert!(!qtbuild.is_static());
+
+        std::fs::create_dir_all(dir.join("mkspecs")).unwrap();
+        std::fs::write(
+            dir.join("mkspecs").join("qconfig.pri"),
+            "QT_CONFIG += shared release\n",
+        )
+        .unwrap();
+        assert!(!qtbuild.is_static());
+
+        std::fs::write(
+            dir.join("mkspecs").join("qconfig.pri"),
+            "QT_ARCH = x86_64\nCONFIG += release static\n",
+        )
+        .unwrap();
+        assert!(qtbuild.is_static());
+    }
+
+    #[test]
+    fn test_default_static_platform_plugin() {
+        let test_env = TestEnv::new();
+        assert_eq!(QtBuild::default_static_platform_plugin(), None);
+
+        test_env.set("CARGO_CFG_TARGET_OS", "linux");
+        assert_eq!(
+            QtBuild::default_static_platform_plugin(),
+            Some(("qxcb", "QXcbIntegrationPlugin"))
+        );
+        test_env.set("CARGO_CFG_TARGET_OS", "windows");
+        assert_eq!(
+            QtBuild::default_static_platform_plugin(),
+            Some(("qwindows", "QWindowsIntegrationPlugin"))
+        );
+        test_env.set("CARGO_CFG_TARGET_OS", "emscripten");
+        assert_eq!(
+            QtBuild::default_static_platform_plugin(),
+            Some(("qwasm", "QWasmIntegrationPlugin"))
+        );
+        test_env.set("CARGO_CFG_TARGET_OS", "none");
+        assert_eq!(QtBuild::default_static_platform_plugin(), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cargo_link_static_qml_module() {
+        let dir = test_dir("cargo_link_static_qml_module");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let controls = dir.join("qml").join("QtQuick").join("Controls");
+        std::fs::create_dir_all(&controls).unwrap();
+        std::fs::write(
+            controls.join("qmldir"),
+            "module QtQuick.Controls\noptional plugin qtquickcontrols2plugin\nclassname QtQuickControls2Plugin\n",
+        )
+        .unwrap();
+        let layouts = dir.join("qml").join("QtQuick").join("Layouts");
+        std::fs::create_dir_all(&layouts).unwrap();
+        std::fs::write(layouts.join("qmldir"), "module QtQuick.Layouts\n").unwrap();
+
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        let mut builder = cc::Build::new();
+        assert_eq!(
+            qtbuild.cargo_link_static_qml_module("QtQuick.Controls", &mut builder),
+            Some("QtQuickControls2Plugin".to_string())
+        );
+        // Modules without a plugin have nothing to link or import
+        assert_eq!(
+            qtbuild.cargo_link_static_qml_module("QtQuick.Layouts", &mut builder),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_wasm_threads_enabled() {
+        let dir = test_dir("wasm_threads_enabled");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        assert!(!qtbuild.wasm_threads_enabled());
+
+        let modules = dir.join("mkspecs").join("modules");
+        std::fs::create_dir_all(&modules).unwrap();
+        std::fs::write(
+            modules.join("qt_lib_core.pri"),
+            "QT.core.enabled_features = cxx11_future\nQT.core.disabled_features = thread\n",
+        )
+        .unwrap();
+        assert!(!qtbuild.wasm_threads_enabled());
+
+        std::fs::write(
+            modules.join("qt_lib_core.pri"),
+            "QT.core.enabled_features = cxx11_future thread\n",
+        )
+        .unwrap();
+        assert!(qtbuild.wasm_threads_enabled());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qt_toolchain_mismatch() {
+        let dir = test_dir("qt_toolchain_mismatch");
+        let qmake = fake_qt(&dir, "6.5.0");
+        std::fs::write(dir.join("xspec"), "win32-msvc").unwrap();
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("CARGO_CFG_TARGET_OS", "windows")
+            .set("CARGO_CFG_TARGET_ENV", "msvc");
+        assert!(QtBuild::new(vec![]).is_ok());
+
+        test_env.set("CARGO_CFG_TARGET_ENV", "gnu");
+        match QtBuild::new(vec![]) {
+            Err(QtBuildError::QMakeSetQtMissing { error, .. }) => assert!(matches!(
+                *error,
+                QtBuildError::QtToolchainMismatch {
+                    qmake_target_env,
+                    rust_target_env,
+                } if qmake_target_env == "msvc" && rust_target_env == "gnu"
+            )),
+            _ => panic!("Expected QtToolchainMismatch"),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_deploy_windows_dlls() {
+        let dir = test_dir("deploy_windows_dlls");
+        let qmake = fake_qt(&dir, "6.5.0");
+        for dll in ["Qt6Core.dll", "Qt6Gui.dll", "Qt6Qml.dll", "libstdc++-6.dll"] {
+            std::fs::write(dir.join("bin").join(dll), dll).unwrap();
+        }
+        std::fs::create_dir_all(dir.join("plugins").join("platforms")).unwrap();
+        std::fs::write(
+            dir.join("plugins").join("platforms").join("qwindows.dll"),
+            "",
+        )
+        .unwrap();
+
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+        let qtbuild = QtBuild::new(vec!["Core".to_string(), "Gui".to_string()]).unwrap();
+        let deploy_dir = dir.join("deploy");
+
+        // Nothing is deployed when not targeting Windows
+        qtbuild.deploy_windows_dlls(&deploy_dir);
+        assert!(!deploy_dir.exists());
+
+        test_env
+            .set("CARGO_CFG_TARGET_OS", "windows")
+            .set("CARGO_CFG_TARGET_ENV", "gnu");
+        qtbuild.deploy_windows_dlls(&deploy_dir);
+        assert!(deploy_dir.join("Qt6Core.dll").exists());
+        assert!(deploy_dir.join("Qt6Gui.dll").exists());
+        assert!(deploy_dir.join("libstdc++-6.dll").exists());
+        assert!(deploy_dir.join("platforms").join("qwindows.dll").exists());
+        // Only the linked modules and the runtime DLLs that exist are deployed
+        assert!(!deploy_dir.join("Qt6Qml.dll").exists());
+        assert!(!deploy_dir.join("libwinpthread-1.dll").exists());
+
+        // Up to date DLLs are not copied again
+        std::fs::write(deploy_dir.join("Qt6Core.dll"), "modified").unwrap();
+        qtbuild.deploy_windows_dlls(&deploy_dir);
+        assert_eq!(
+            std::fs::read_to_string(deploy_dir.join("Qt6Core.dll")).unwrap(),
+            "modified"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_framework_paths() {
+        let dir = test_dir("framework_paths");
+        let qmake = fake_qt(&dir, "6.5.0");
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("CARGO_CFG_TARGET_VENDOR", "apple");
+        let qtbuild = QtBuild::new(vec!["Core".to_string(), "Gui".to_string()]).unwrap();
+        assert!(qtbuild.framework_paths().is_empty());
+        assert_eq!(
+            qtbuild.include_paths(),
+            vec![
+                dir.join("include").join("QtCore"),
+                dir.join("include").join("QtGui"),
+                dir.join("include"),
+            ]
+        );
+
+        let lib = dir.join("lib");
+        for framework in ["QtCore.framework", "QtGui.framework"] {
+            std::fs::create_dir_all(lib.join(framework).join("Headers")).unwrap();
+        }
+        assert_eq!(qtbuild.framework_paths(), vec![lib.clone()]);
+        assert_eq!(
+            qtbuild.include_paths(),
+            vec![
+                dir.join("include").join("QtCore"),
+                dir.join("include").join("QtGui"),
+                lib.join("QtCore.framework").join("Headers"),
+                lib.join("QtGui.framework").join("Headers"),
+                dir.join("include"),
+            ]
+        );
+
+        // Frameworks are only used on Apple platforms
+        test_env.set("CARGO_CFG_TARGET_VENDOR", "unknown");
+        assert!(qtbuild.framework_paths().is_empty());
+    }
+
+    /// Create fake lupdate and lrelease tools in the fake Qt installation
+    ///
+    /// lupdate writes the file names of the sources into the .ts files,
+    /// lrelease copies the .ts file into the .qm file.
+    #[cfg(unix)]
+    fn fake_linguist_tools(prefix: &Path) {
+        write_script(
+            &prefix.join("bin").join("lupdate"),
+            r#"[ "$1" = "-help" ] && exit 0
+sources=""
+ts=""
+for arg in "$@"; do
+    case "$arg" in
+        -silent) ;;
+        -ts) ts=1 ;;
+        *) if [ -n "$ts" ]; then printf '%s' "$sources" > "$arg"; else sources="$sources$(basename "$arg") "; fi ;;
+    esac
+done"#,
+        );
+        write_script(
+            &prefix.join("bin").join("lrelease"),
+            r#"[ "$1" = "-help" ] && exit 0
+cp "$2" "$4""#,
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_lupdate() {
+        let dir = test_dir("lupdate");
+        let qmake = fake_qt(&dir, "6.5.0");
+        fake_linguist_tools(&dir);
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("OUT_DIR", dir.join("out"));
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+
+        // Nothing is run without .ts files
+        qtbuild.lupdate(&[dir.join("main.qml")], &[] as &[PathBuf]);
+        assert!(!dir.join("out").exists());
+
+        let ts_de = dir.join("i18n").join("app_de.ts");
+        let ts_fr = dir.join("app_fr.ts");
+        std::fs::create_dir_all(ts_de.parent().unwrap()).unwrap();
+        std::fs::write(&ts_de, "old").unwrap();
+        qtbuild.lupdate(&[dir.join("main.qml")], &[&ts_de, &ts_fr]);
+        assert_eq!(std::fs::read_to_string(&ts_de).unwrap(), "main.qml ");
+        assert_eq!(std::fs::read_to_string(&ts_fr).unwrap(), "main.qml ");
+
+        // Unchanged .ts files are not written, so that they don't trigger a rebuild
+        let modified = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1000);
+        std::fs::File::options()
+            .write(true)
+            .open(&ts_de)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        qtbuild.lupdate(&[dir.join("main.qml")], &[&ts_de]);
+        assert_eq!(ts_de.metadata().unwrap().modified().unwrap(), modified);
+
+        qtbuild.lupdate(&[dir.join("main.qml"), dir.join("lib.rs")], &[&ts_de]);
+        assert_eq!(std::fs::read_to_string(&ts_de).unwrap(), "main.qml lib.rs ");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_lrelease() {
+        let dir = test_dir("lrelease");
+        let qmake = fake_qt(&dir, "6.5.0");
+        fake_linguist_tools(&dir);
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("OUT_DIR", dir.join("out"));
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+
+        let ts_file = dir.join("i18n").join("app_de.ts");
+        std::fs::create_dir_all(ts_file.parent().unwrap()).unwrap();
+        std::fs::write(&ts_file, "translations").unwrap();
+        let qm_file = qtbuild.lrelease(&ts_file);
+        assert_eq!(
+            qm_file,
+            dir.join("out")
+                .join("qt-build-utils")
+                .join("lrelease")
+                .join("app_de.qm")
+        );
+        assert_eq!(std::fs::read_to_string(qm_file).unwrap(), "translations");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[should_panic(expected = "lrelease failed")]
+    fn test_lrelease_failed() {
+        let dir = test_dir("lrelease_failed");
+        let qmake = fake_qt(&dir, "6.5.0");
+        fake_linguist_tools(&dir);
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("OUT_DIR", dir.join("out"));
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+
+        // The fake lrelease fails to copy a missing .ts file
+        qtbuild.lrelease(dir.join("missing.ts"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_foreign_metatypes() {
+        let dir = test_dir("foreign_metatypes");
+        let qmake = fake_qt(&dir, "6.4.0");
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+        let qtbuild = QtBuild::new(vec!["Core".to_string(), "Gui".to_string()]).unwrap();
+        assert!(qtbuild.foreign_metatypes().is_empty());
+
+        // Qt < 6.5 installs the metatypes into the lib directory
+        let lib_metatypes = dir.join("lib").join("metatypes");
+        std::fs::create_dir_all(&lib_metatypes).unwrap();
+        for file in [
+            "qt6gui_relwithdebinfo_metatypes.json",
+            "qt6core_relwithdebinfo_metatypes.json",
+            "qt6qml_relwithdebinfo_metatypes.json",
+            "qt6core_relwithdebinfo_metatypes.txt",
+        ] {
+            std::fs::write(lib_metatypes.join(file), "[]").unwrap();
+        }
+        assert_eq!(
+            qtbuild.foreign_metatypes(),
+            vec![
+                lib_metatypes
+                    .join("qt6core_relwithdebinfo_metatypes.json")
+                    .to_string_lossy()
+                    .to_string(),
+                lib_metatypes
+                    .join("qt6gui_relwithdebinfo_metatypes.json")
+                    .to_string_lossy()
+                    .to_string(),
+            ]
+        );
+
+        // Newer versions install them into the arch data directory, which is preferred
+        let archdata_metatypes = dir.join("metatypes");
+        std::fs::create_dir_all(&archdata_metatypes).unwrap();
+        std::fs::write(archdata_metatypes.join("qt6core_metatypes.json"), "[]").unwrap();
+        assert_eq!(
+            qtbuild.foreign_metatypes(),
+            vec![archdata_metatypes
+                .join("qt6core_metatypes.json")
+                .to_string_lossy()
+                .to_string()]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_foreign_metatypes_qt5() {
+        let dir = test_dir("foreign_metatypes_qt5");
+        let qmake = fake_qt(&dir, "5.15.2");
+        let metatypes = dir.join("lib").join("metatypes");
+        std::fs::create_dir_all(&metatypes).unwrap();
+        std::fs::write(metatypes.join("qt5core_metatypes.json"), "[]").unwrap();
+        let test_env = TestEnv::new();
+        test_env.set("QMAKE", &qmake);
+
+        // Qt 5 does not install metatypes files that can be used as foreign types
+        let qtbuild = QtBuild::new(vec![]).unwrap();
+        assert!(qtbuild.foreign_metatypes().is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_register_qml_module_qmldir() {
+        let dir = test_dir("register_qml_module_qmldir");
+        let qmake = fake_qt(&dir, "6.5.0");
+        for tool in ["moc", "rcc", "qmltyperegistrar"] {
+            fake_qt_tool(&dir, tool);
+        }
+        let module_dir = dir.join("module");
+        for file in [
+            "qml/Main.qml",
+            "qml/helper.js",
+            "qml/main.qml",
+            "Button.qml",
+            "images/icon.png",
+        ] {
+            let path = module_dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        std::fs::write(dir.join("Outside.qml"), "").unwrap();
+
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("OUT_DIR", dir.join("out"))
+            .set_current_dir(&module_dir);
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        qtbuild.qmlcachegen(false);
+        let files = qtbuild.register_qml_module(
+            &[] as &[PathBuf],
+            "com.kdab.demo",
+            1,
+            2,
+            "com_kdab_demo",
+            &[
+                "qml/Main.qml",
+                "qml/helper.js",
+                "qml/main.qml",
+                "Button.qml",
+                "../Outside.qml",
+            ],
+            &["images/icon.png"],
+        );
+
+        // Only QML files within the module whose names start with an uppercase letter are types
+        assert_eq!(
+            std::fs::read_to_string(&files.qmldir).unwrap(),
+            "module com.kdab.demo
+optional plugin com_kdab_demo
+classname com_kdab_demo_plugin
+typeinfo plugin.qmltypes
+prefer :/qt/qml/com/kdab/demo/
+Main 1.2 qml/Main.qml
+Button 1.2 Button.qml
+"
+        );
+        assert_eq!(
+            files.qmldir,
+            dir.join("out")
+                .join("qt-build-utils")
+                .join("qml_modules")
+                .join("com")
+                .join("kdab")
+                .join("demo")
+                .join("qmldir")
+        );
+        assert!(files.rcc.exists());
+        assert!(files.qmlcachegen.is_empty());
+    }
+
+    /// Create a fake Qt installation with the tools to register a QML module with a `Main.qml` file
+    #[cfg(unix)]
+    fn fake_qml_module(name: &str, version: &str) -> (PathBuf, TestEnv) {
+        let dir = test_dir(name);
+        let qmake = fake_qt(&dir, version);
+        for tool in ["moc", "rcc", "qmltyperegistrar"] {
+            fake_qt_tool(&dir, tool);
+        }
+        std::fs::write(dir.join("Main.qml"), "").unwrap();
+
+        let test_env = TestEnv::new();
+        test_env
+            .set("QMAKE", &qmake)
+            .set("OUT_DIR", dir.join("out"))
+            .set_current_dir(&dir);
+        (dir, test_env)
+    }
+
+    #[cfg(unix)]
+    fn register_qml_module_with_main(qtbuild: &mut QtBuild) -> QmlModuleRegistrationFiles {
+        qtbuild.register_qml_module(
+            &[] as &[PathBuf],
+            "com.kdab.demo",
+            1,
+            0,
+            "com_kdab_demo",
+            &["Main.qml"],
+            &[] as &[PathBuf],
+        )
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qmlcachegen() {
+        let (dir, _test_env) = fake_qml_module("qmlcachegen", "6.5.0");
+        let qmlcachegen_log = fake_qt_tool(&dir, "qmlcachegen");
+
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        let files = register_qml_module_with_main(&mut qtbuild);
+        let qmlcachegen_dir = dir
+            .join("out")
+            .join("qt-build-utils")
+            .join("qmlcachegen")
+            .join("com")
+            .join("kdab")
+            .join("demo");
+        assert_eq!(
+            files.qmlcachegen,
+            vec![
+                qmlcachegen_dir.join("Main.qml.cpp"),
+                qmlcachegen_dir.join("qmlcache_loader.cpp"),
+            ]
+        );
+
+        // qmlcachegen is run for each QML file, then for the loader of the module
+        let log = std::fs::read_to_string(&qmlcachegen_log).unwrap();
+        let runs = log.lines().collect::<Vec<_>>();
+        assert_eq!(runs.len(), 2);
+        assert!(runs[0].contains("--resource-path /qt/qml/com/kdab/demo/Main.qml"));
+        assert!(runs[1].contains("--resource-name qmlcache_com_kdab_demo"));
+        assert!(runs[1].ends_with("/qt/qml/com/kdab/demo/Main.qml"));
+
+        // The loader is kept alive by the plugin
+        assert!(std::fs::read_to_string(files.plugin)
+            .unwrap()
+            .contains("qInitResources_qmlcache_com_kdab_demo"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qmlcachegen_disabled() {
+        let (dir, _test_env) = fake_qml_module("qmlcachegen_disabled", "6.5.0");
+        let qmlcachegen_log = fake_qt_tool(&dir, "qmlcachegen");
+
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        qtbuild.qmlcachegen(false);
+        let files = register_qml_module_with_main(&mut qtbuild);
+        assert!(files.qmlcachegen.is_empty());
+        assert!(!qmlcachegen_log.exists());
+        assert!(!std::fs::read_to_string(files.plugin)
+            .unwrap()
+            .contains("qInitResources_qmlcache_com_kdab_demo"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_qmlcachegen_missing() {
+        let (_dir, _test_env) = fake_qml_module("qmlcachegen_missing", "6.5.0");
+
+        // By default qmlcachegen is only used when it is available
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        assert!(register_qml_module_with_main(&mut qtbuild)
+            .qmlcachegen
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[should_panic(expected = "but it could not be found")]
+    fn test_qmlcachegen_required_missing() {
+        let (_dir, _test_env) = fake_qml_module("qmlcachegen_required_missing", "6.5.0");
+
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        qtbuild.qmlcachegen(true);
+        register_qml_module_with_main(&mut qtbuild);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[should_panic(expected = "it is only supported with Qt 6")]
+    fn test_qmlcachegen_required_qt5() {
+        let (dir, _test_env) = fake_qml_module("qmlcachegen_required_qt5", "5.15.2");
+        fake_qt_tool(&dir, "qmlcachegen");
+
+        let mut qtbuild = QtBuild::new(vec![]).unwrap();
+        qtbuild.qmlcachegen(true);
+        register_qml_module_with_main(&mut qtbuild);
+    }
+}
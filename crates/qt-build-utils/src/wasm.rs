@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This module contains helpers for compiling to WebAssembly with Qt for WebAssembly.
+
+use std::env;
+
+/// Whether the current target is `wasm32-unknown-emscripten`, which is the target used by Qt for WebAssembly
+pub fn emscripten_targeted() -> bool {
+    env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("emscripten")
+}
+
+/// The names of the directories of the Qt for WebAssembly kits within a Qt installation,
+/// for example `wasm_singlethread` in `~/Qt/6.5.0/wasm_singlethread`, in order of preference.
+///
+/// `wasm_32` is used by Qt versions before 6.5.
+pub(crate) fn wasm_kit_dir_names() -> Vec<&'static str> {
+    if emscripten_targeted() {
+        vec!["wasm_singlethread", "wasm_32", "wasm_multithread"]
+    } else {
+        vec![]
+    }
+}
+
+/// The flags that need to be passed to emcc when linking an executable with Qt for WebAssembly,
+/// matching the `wasm-emscripten` mkspec of Qt.
+pub(crate) fn emscripten_link_flags(threads: bool) -> Vec<&'static str> {
+    let mut flags = vec![
+        "-sALLOW_MEMORY_GROWTH=1",
+        "-sMAX_WEBGL_VERSION=2",
+        "-sFETCH=1",
+        "-sWASM_BIGINT=1",
+    ];
+    if threads {
+        flags.extend(["-pthread", "-sUSE_PTHREADS=1", "-sPTHREAD_POOL_SIZE=4"]);
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::TestEnv;
+
+    #[test]
+    fn test_wasm_kit_dir_names() {
+        let test_env = TestEnv::new();
+        assert!(!emscripten_targeted());
+        assert!(wasm_kit_dir_names().is_empty());
+
+        test_env.set("CARGO_CFG_TARGET_OS", "emscripten");
+        assert!(emscripten_targeted());
+        assert_eq!(
+            wasm_kit_dir_names(),
+            vec!["wasm_singlethread", "wasm_32", "wasm_multithread"]
+        );
+    }
+
+    #[test]
+    fn test_emscripten_link_flags() {
+        let flags = emscripten_link_flags(false);
+        assert!(flags.contains(&"-sALLOW_MEMORY_GROWTH=1"));
+        assert!(!flags.contains(&"-pthread"));
+
+        let flags = emscripten_link_flags(true);
+        assert!(flags.contains(&"-sALLOW_MEMORY_GROWTH=1"));
+        assert!(flags.contains(&"-pthread"));
+        assert!(flags.contains(&"-sUSE_PTHREADS=1"));
+    }
+}
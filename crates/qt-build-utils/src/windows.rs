@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This module contains helpers for matching the Rust toolchain with the Qt build on Windows.
+
+use std::env;
+
+/// The runtime libraries of MinGW that are shipped in the bin directory of Qt for MinGW
+pub(crate) const MINGW_RUNTIME_DLLS: [&str; 3] = [
+    "libgcc_s_seh-1.dll",
+    "libstdc++-6.dll",
+    "libwinpthread-1.dll",
+];
+
+/// The Rust target environment (`msvc` or `gnu`) when targeting Windows, or `None` for other targets
+pub(crate) fn windows_target_env() -> Option<String> {
+    if env::var("CARGO_CFG_TARGET_OS").ok()? != "windows" {
+        return None;
+    }
+    env::var("CARGO_CFG_TARGET_ENV").ok()
+}
+
+/// The Rust target environment that a Qt build for Windows is compatible with,
+/// from the mkspec reported by `qmake -query QMAKE_XSPEC`, for example `win32-msvc` or `win32-g++`
+pub(crate) fn qt_target_env(qmake_xspec: &str) -> Option<&'static str> {
+    if !qmake_xspec.starts_with("win32-") {
+        return None;
+    }
+
+    if qmake_xspec.ends_with("msvc") {
+        Some("msvc")
+    } else if qmake_xspec.ends_with("g++") {
+        Some("gnu")
+    } else {
+        None
+    }
+}
+
+/// Whether the Rust code is compiled with the static C runtime
+pub(crate) fn rust_static_crt() -> bool {
+    env::var("CARGO_CFG_TARGET_FEATURE")
+        .map(|features| features.split(',').any(|feature| feature == "crt-static"))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::TestEnv;
+
+    #[test]
+    fn test_windows_target_env() {
+        let test_env = TestEnv::new();
+        test_env
+            .set("CARGO_CFG_TARGET_OS", "linux")
+            .set("CARGO_CFG_TARGET_ENV", "gnu");
+        assert_eq!(windows_target_env(), None);
+
+        test_env.set("CARGO_CFG_TARGET_OS", "windows");
+        assert_eq!(windows_target_env(), Some("gnu".to_string()));
+        test_env.set("CARGO_CFG_TARGET_ENV", "msvc");
+        assert_eq!(windows_target_env(), Some("msvc".to_string()));
+    }
+
+    #[test]
+    fn test_qt_target_env() {
+        assert_eq!(qt_target_env("win32-msvc"), Some("msvc"));
+        assert_eq!(qt_target_env("win32-clang-msvc"), Some("msvc"));
+        assert_eq!(qt_target_env("win32-g++"), Some("gnu"));
+        assert_eq!(qt_target_env("win32-clang-g++"), Some("gnu"));
+        assert_eq!(qt_target_env("win32-icc"), None);
+        assert_eq!(qt_target_env("linux-g++"), None);
+    }
+
+    #[test]
+    fn test_rust_static_crt() {
+        let test_env = TestEnv::new();
+        assert!(!rust_static_crt());
+
+        test_env.set("CARGO_CFG_TARGET_FEATURE", "fxsr,sse,sse2");
+        assert!(!rust_static_crt());
+        test_env.set("CARGO_CFG_TARGET_FEATURE", "crt-static,fxsr,sse,sse2");
+        assert!(rust_static_crt());
+    }
+}
@@ -4,8 +4,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{constants::SENSOR_MAXIMUM_COUNT, network::NetworkChannel, qobject};
-use core::pin::Pin;
+use crate::{
+    constants::SENSOR_MAXIMUM_COUNT, network::NetworkChannel, qobject, EnergyUsageQueuedSignal,
+};
 use cxx_qt::CxxQtThread;
 use cxx_qt_lib::QString;
 use std::{
@@ -85,12 +86,9 @@ impl SensorsWorker {
 
                         // Queue a Signal that the sensor has been removed to Qt
                         qt_thread
-                            .queue(
-                                move |qobject_energy_usage: Pin<&mut qobject::EnergyUsage>| {
-                                    qobject_energy_usage
-                                        .sensor_removed(QString::from(&uuid.to_string()));
-                                },
-                            )
+                            .emit(EnergyUsageQueuedSignal::SensorRemoved {
+                                uuid: QString::from(&uuid.to_string()),
+                            })
                             .unwrap();
                     }
                     // A new or existing sensor has a power value
@@ -116,25 +114,15 @@ impl SensorsWorker {
                             // changed to Qt
                             if is_occupied {
                                 qt_thread
-                                    .queue(
-                                        move |qobject_energy_usage: Pin<
-                                            &mut qobject::EnergyUsage,
-                                        >| {
-                                            qobject_energy_usage
-                                                .sensor_changed(QString::from(&uuid.to_string()));
-                                        },
-                                    )
+                                    .emit(EnergyUsageQueuedSignal::SensorChanged {
+                                        uuid: QString::from(&uuid.to_string()),
+                                    })
                                     .unwrap();
                             } else {
                                 qt_thread
-                                    .queue(
-                                        move |qobject_energy_usage: Pin<
-                                            &mut qobject::EnergyUsage,
-                                        >| {
-                                            qobject_energy_usage
-                                                .sensor_added(QString::from(&uuid.to_string()));
-                                        },
-                                    )
+                                    .emit(EnergyUsageQueuedSignal::SensorAdded {
+                                        uuid: QString::from(&uuid.to_string()),
+                                    })
                                     .unwrap();
                             }
                         } else {
@@ -0,0 +1,18 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTextDocument>
+#include <QtQuick/QQuickTextDocument>
+
+// QQuickTextDocument::textDocument is const in Qt 6 but not in Qt 5,
+// so provide a free function which can be bound with CXX for both.
+inline QTextDocument*
+quickTextDocumentTextDocument(QQuickTextDocument& document)
+{
+  return document.textDocument();
+}
@@ -124,6 +124,14 @@ ApplicationWindow {
                     name: "ExternCxxQt"
                     source: "pages/ExternCxxQtPage.qml"
                 }
+                ListElement {
+                    name: "Validator"
+                    source: "pages/ValidatorPage.qml"
+                }
+                ListElement {
+                    name: "Syntax Highlighter"
+                    source: "pages/SyntaxHighlighterPage.qml"
+                }
             }
         }
     }
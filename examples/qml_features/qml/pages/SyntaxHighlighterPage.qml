@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Layouts 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+Page {
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 10
+
+        Label {
+            Layout.fillWidth: true
+            horizontalAlignment: Text.AlignHCenter
+            text: qsTr("QSyntaxHighlighter::highlightBlock can be implemented in Rust.\n" +
+                       "Keywords and numbers typed below are highlighted by Rust code.")
+            wrapMode: Text.Wrap
+        }
+
+        TextArea {
+            id: textArea
+            Layout.fillHeight: true
+            Layout.fillWidth: true
+            text: "fn main() {\n    let value = 42;\n}"
+        }
+    }
+
+    RustSyntaxHighlighter {
+        id: highlighter
+    }
+
+    Component.onCompleted: highlighter.setTextDocument(textArea.textDocument)
+}
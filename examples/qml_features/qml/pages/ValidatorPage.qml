@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Layouts 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+Page {
+    ColumnLayout {
+        anchors.left: parent.left
+        anchors.right: parent.right
+        anchors.verticalCenter: parent.verticalCenter
+
+        Label {
+            Layout.fillWidth: true
+            horizontalAlignment: Text.AlignHCenter
+            text: qsTr("QValidator::validate can be overridden in Rust.\n" +
+                       "This field only accepts digits and the number must pass the Luhn checksum.")
+            wrapMode: Text.Wrap
+        }
+
+        TextField {
+            id: textField
+            Layout.alignment: Qt.AlignHCenter
+            placeholderText: qsTr("Enter a number")
+            validator: RustValidator {}
+        }
+
+        Label {
+            Layout.fillWidth: true
+            horizontalAlignment: Text.AlignHCenter
+            text: textField.acceptableInput ? qsTr("Checksum is valid") : qsTr("Checksum is invalid")
+            wrapMode: Text.Wrap
+        }
+    }
+}
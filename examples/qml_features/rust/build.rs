@@ -24,10 +24,12 @@ fn main() {
                 "src/serialisation.rs",
                 "src/signals.rs",
                 "src/singleton.rs",
+                "src/syntax_highlighter.rs",
                 "src/properties.rs",
                 "src/threading.rs",
                 "src/types.rs",
                 "src/uncreatable.rs",
+                "src/validator.rs",
             ],
             qml_files: &[
                 "../qml/main.qml",
@@ -43,8 +45,10 @@ fn main() {
                 "../qml/pages/SerialisationPage.qml",
                 "../qml/pages/SignalsPage.qml",
                 "../qml/pages/SingletonPage.qml",
+                "../qml/pages/SyntaxHighlighterPage.qml",
                 "../qml/pages/ThreadingPage.qml",
                 "../qml/pages/TypesPage.qml",
+                "../qml/pages/ValidatorPage.qml",
             ],
             ..Default::default()
         })
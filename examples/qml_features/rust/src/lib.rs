@@ -22,6 +22,8 @@ pub mod properties;
 pub mod serialisation;
 pub mod signals;
 pub mod singleton;
+pub mod syntax_highlighter;
 pub mod threading;
 pub mod types;
 pub mod uncreatable;
+pub mod validator;
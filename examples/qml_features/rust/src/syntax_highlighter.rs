@@ -0,0 +1,144 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how QSyntaxHighlighter::highlightBlock can be implemented in Rust
+
+/// A CXX-Qt bridge which shows a custom QSyntaxHighlighter implemented in Rust
+// ANCHOR: book_syntax_highlighter
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qtextcharformat.h");
+        /// QTextCharFormat from cxx_qt_lib
+        type QTextCharFormat = cxx_qt_lib::QTextCharFormat;
+    }
+
+    unsafe extern "C++" {
+        include!(<QtGui/QSyntaxHighlighter>);
+        /// Base for Qt type
+        type QSyntaxHighlighter;
+
+        include!("quick_text_document.h");
+        /// QTextDocument from Qt
+        type QTextDocument;
+        /// QQuickTextDocument from Qt, which is the textDocument property of a QML TextArea
+        type QQuickTextDocument;
+
+        /// Retrieve the QTextDocument from a QQuickTextDocument
+        #[rust_name = "quick_text_document_text_document"]
+        fn quickTextDocumentTextDocument(
+            document: Pin<&mut QQuickTextDocument>,
+        ) -> *mut QTextDocument;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[base = QSyntaxHighlighter]
+        type RustSyntaxHighlighter = super::RustSyntaxHighlighterRust;
+
+        /// Override QSyntaxHighlighter::highlightBlock to highlight the text in Rust
+        #[cxx_override]
+        #[cxx_name = "highlightBlock"]
+        fn highlight_block(self: Pin<&mut RustSyntaxHighlighter>, text: &QString);
+
+        /// Inherit QSyntaxHighlighter::setFormat so that formats can be applied
+        #[inherit]
+        #[cxx_name = "setFormat"]
+        fn set_format(
+            self: Pin<&mut RustSyntaxHighlighter>,
+            start: i32,
+            count: i32,
+            format: &QTextCharFormat,
+        );
+
+        /// Inherit QSyntaxHighlighter::setDocument so that the document can be changed
+        #[inherit]
+        #[cxx_name = "setDocument"]
+        unsafe fn set_document(self: Pin<&mut RustSyntaxHighlighter>, document: *mut QTextDocument);
+
+        /// Highlight the given QQuickTextDocument, eg the textDocument property of a TextArea
+        #[qinvokable]
+        #[cxx_name = "setTextDocument"]
+        unsafe fn set_text_document(
+            self: Pin<&mut RustSyntaxHighlighter>,
+            document: *mut QQuickTextDocument,
+        );
+    }
+}
+// ANCHOR_END: book_syntax_highlighter
+
+use core::pin::Pin;
+use cxx_qt_lib::{QColor, QString, QTextCharFormat};
+
+const KEYWORDS: [&str; 8] = ["fn", "let", "mut", "pub", "struct", "impl", "use", "mod"];
+
+/// A syntax highlighter which highlights Rust keywords and numbers
+#[derive(Default)]
+pub struct RustSyntaxHighlighterRust;
+
+impl qobject::RustSyntaxHighlighter {
+    /// Override QSyntaxHighlighter::highlightBlock to highlight the text in Rust
+    pub fn highlight_block(mut self: Pin<&mut Self>, text: &QString) {
+        let mut keyword_format = QTextCharFormat::default();
+        keyword_format.set_font_weight(75);
+        keyword_format.set_foreground(&QColor::from_rgb(0, 0, 255));
+
+        let mut number_format = QTextCharFormat::default();
+        number_format.set_foreground(&QColor::from_rgb(0, 128, 0));
+
+        // QSyntaxHighlighter uses UTF-16 positions, so track these while walking the text
+        let text = String::from(text);
+        let mut position = 0;
+        for (token, is_word) in tokenize(&text) {
+            let length = token.encode_utf16().count() as i32;
+            if is_word {
+                if KEYWORDS.contains(&token) {
+                    self.as_mut().set_format(position, length, &keyword_format);
+                } else if token.chars().all(|c| c.is_ascii_digit()) {
+                    self.as_mut().set_format(position, length, &number_format);
+                }
+            }
+            position += length;
+        }
+    }
+
+    /// Highlight the given QQuickTextDocument, eg the textDocument property of a TextArea
+    ///
+    /// # Safety
+    ///
+    /// As we deref a pointer in a public method this needs to be marked as unsafe
+    pub unsafe fn set_text_document(
+        self: Pin<&mut Self>,
+        document: *mut qobject::QQuickTextDocument,
+    ) {
+        if let Some(document) = document.as_mut() {
+            let text_document =
+                qobject::quick_text_document_text_document(Pin::new_unchecked(document));
+            self.set_document(text_document);
+        }
+    }
+}
+
+/// Split the text into runs of word characters and runs of other characters
+fn tokenize(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_word = first.is_alphanumeric() || first == '_';
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| (c.is_alphanumeric() || *c == '_') != is_word)
+            .map(|(index, _)| index)
+            .unwrap_or(rest.len());
+        let (token, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some((token, is_word))
+    })
+}
@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how QValidator::validate can be overridden in Rust
+
+/// A CXX-Qt bridge which shows a custom QValidator implemented in Rust
+// ANCHOR: book_validator
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvalidator.h");
+        /// Base for Qt type
+        type QValidator;
+        /// QValidatorState from cxx_qt_lib
+        type QValidatorState = cxx_qt_lib::QValidatorState;
+
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[base = QValidator]
+        type RustValidator = super::RustValidatorRust;
+
+        /// Override QValidator::validate to check the input in Rust
+        #[cxx_override]
+        fn validate(
+            self: &RustValidator,
+            input: Pin<&mut QString>,
+            pos: &mut i32,
+        ) -> QValidatorState;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QString, QValidatorState};
+
+/// A validator which accepts numbers that pass the Luhn checksum
+#[derive(Default)]
+pub struct RustValidatorRust;
+
+impl qobject::RustValidator {
+    /// Override QValidator::validate to check the input in Rust
+    pub fn validate(&self, input: Pin<&mut QString>, _pos: &mut i32) -> QValidatorState {
+        let input = String::from(&*input);
+        if !input.chars().all(|c| c.is_ascii_digit()) {
+            return QValidatorState::Invalid;
+        }
+
+        if luhn_checksum_is_valid(&input) {
+            QValidatorState::Acceptable
+        } else {
+            // The user may still be typing, so allow the input to be completed
+            QValidatorState::Intermediate
+        }
+    }
+}
+// ANCHOR_END: book_validator
+
+fn luhn_checksum_is_valid(digits: &str) -> bool {
+    if digits.len() < 2 {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
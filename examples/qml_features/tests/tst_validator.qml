@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "ValidatorTests"
+
+    Component {
+        id: componentTextInput
+
+        TextInput {
+            validator: RustValidator {}
+        }
+    }
+
+    function test_acceptable() {
+        const textInput = createTemporaryObject(componentTextInput, null, {});
+        textInput.text = "79927398713";
+        compare(textInput.text, "79927398713");
+        compare(textInput.acceptableInput, true);
+    }
+
+    function test_intermediate() {
+        const textInput = createTemporaryObject(componentTextInput, null, {});
+        textInput.text = "79927398710";
+        compare(textInput.text, "79927398710");
+        compare(textInput.acceptableInput, false);
+    }
+
+    function test_invalid() {
+        const textInput = createTemporaryObject(componentTextInput, null, {});
+        textInput.text = "abc";
+        compare(textInput.acceptableInput, false);
+    }
+}
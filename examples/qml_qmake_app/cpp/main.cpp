@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include <QtCore/QDebug>
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlApplicationEngine>
+
+// The header which is generated for the bridge and installed by cxx-qt-build
+#include <qml_qmake_app/src/greeter.cxxqt.h>
+
+int
+main(int argc, char* argv[])
+{
+  QGuiApplication app(argc, argv);
+
+  // The QObject which is implemented in Rust can be used from C++ like any other QObject
+  greeter::Greeter greeter;
+  greeter.setName(QStringLiteral("C++"));
+  qInfo() << greeter.greet();
+
+  // And from QML by importing the QML module of the crate
+  QQmlApplicationEngine engine;
+  const QUrl url(QStringLiteral("qrc:/main.qml"));
+  QObject::connect(
+    &engine,
+    &QQmlApplicationEngine::objectCreated,
+    &app,
+    [url](QObject* obj, const QUrl& objUrl) {
+      if (!obj && url == objUrl)
+        QCoreApplication::exit(-1);
+    },
+    Qt::QueuedConnection);
+  engine.load(url);
+
+  return app.exec();
+}
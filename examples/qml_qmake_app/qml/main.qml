@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+import com.kdab.cxx_qt.qmake_app
+
+ApplicationWindow {
+    height: 240
+    title: qsTr("CXX-Qt in a qmake application")
+    visible: true
+    width: 640
+
+    Greeter {
+        id: greeter
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: 10
+
+        TextField {
+            Layout.fillWidth: true
+            text: greeter.name
+
+            onTextChanged: greeter.name = text
+        }
+
+        Label {
+            id: greeting
+            Layout.fillWidth: true
+        }
+
+        Button {
+            text: qsTr("Greet")
+
+            onClicked: greeting.text = greeter.greet()
+        }
+    }
+}
@@ -0,0 +1,19 @@
+# SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+# SPDX-FileContributor: agent <agent@local>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+# A plain qmake application, the only addition for the Rust crate is including
+# the .pri file that cxx-qt-build writes when building the crate with cargo
+TEMPLATE = app
+TARGET = qml_qmake_app
+CONFIG += c++17
+QT += gui qml quick
+
+SOURCES += cpp/main.cpp
+RESOURCES += qml/qml.qrc
+
+!exists($$PWD/qml_qmake_app.pri) {
+    error("Build the Rust crate first with: cargo build -p qml_qmake_app")
+}
+include($$PWD/qml_qmake_app.pri)
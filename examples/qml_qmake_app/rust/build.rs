@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::{CxxQtBuilder, QmlModule};
+
+fn main() {
+    CxxQtBuilder::new()
+        .qml_module(QmlModule::<&str, &str> {
+            uri: "com.kdab.cxx_qt.qmake_app",
+            rust_files: &["src/greeter.rs"],
+            ..Default::default()
+        })
+        // Install the generated headers and a .pri file next to the crate,
+        // so that the qmake project can find them at a known location
+        .install_headers("../include")
+        .install_qmake_pri("../qml_qmake_app.pri")
+        .build();
+}
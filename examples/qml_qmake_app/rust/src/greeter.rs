@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// A QObject which is used from both C++ and QML
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// An alias to the QString type
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        /// Greets a person by name
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, name)]
+        #[qproperty(i32, count)]
+        #[namespace = "greeter"]
+        type Greeter = super::GreeterRust;
+
+        /// Returns a greeting for the current name and increments the count
+        #[qinvokable]
+        fn greet(self: Pin<&mut Greeter>) -> QString;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+/// The Rust struct for the Greeter
+pub struct GreeterRust {
+    name: QString,
+    count: i32,
+}
+
+impl Default for GreeterRust {
+    fn default() -> Self {
+        Self {
+            name: QString::from("World"),
+            count: 0,
+        }
+    }
+}
+
+impl qobject::Greeter {
+    /// Returns a greeting for the current name and increments the count
+    pub fn greet(self: Pin<&mut Self>) -> QString {
+        let count = self.count() + 1;
+        let greeting = QString::from(&format!("Hello {}! ({count})", self.name()));
+        self.set_count(count);
+        greeting
+    }
+}
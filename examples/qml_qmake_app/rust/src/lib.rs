@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A QML module which is implemented in Rust and used by a C++ application that is built with qmake
+
+pub mod greeter;
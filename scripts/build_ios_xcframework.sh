@@ -0,0 +1,65 @@
+#!/usr/bin/env bash
+
+# SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+# SPDX-FileContributor: agent <agent@local>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+# Build a crate as a static library for iOS devices and simulators and combine the
+# libraries into an xcframework that can be added to an Xcode project.
+#
+# The crate must have the staticlib crate type. QMAKE or QT_DIR needs to point to Qt for iOS,
+# for the simulator QMAKE_SIMULATOR can point to a different qmake if required.
+#
+# Usage: build_ios_xcframework.sh <package> <output directory> [cargo arguments...]
+
+set -e
+
+if [ "$#" -lt 2 ]; then
+    echo "Usage: $0 <package> <output directory> [cargo arguments...]"
+    exit 1
+fi
+
+PACKAGE="$1"
+mkdir -p "$2"
+OUTPUT_DIR=$(realpath "$2")
+shift 2
+
+LIB_NAME="lib${PACKAGE//-/_}.a"
+TARGET_DIR="${CARGO_TARGET_DIR:-target}"
+PROFILE_DIR="release"
+CARGO_ARGS=("--release" "$@")
+
+# Export the generated headers and initializer object files of each target,
+# so that they can be added to the xcframework and the Xcode target
+EXPORT_DIR="$OUTPUT_DIR/cxx-qt-export"
+
+function build_target() {
+    # The package name may contain dashes, so use env to set the variable
+    env "CXX_QT_EXPORT_CRATE_$PACKAGE=1" "CXX_QT_EXPORT_DIR=$EXPORT_DIR/$1" \
+        cargo build -p "$PACKAGE" --target "$1" "${CARGO_ARGS[@]}"
+}
+
+# Devices
+build_target aarch64-apple-ios
+
+# Simulators on Apple Silicon and Intel, combined into one fat library
+QMAKE="${QMAKE_SIMULATOR:-$QMAKE}" build_target aarch64-apple-ios-sim
+QMAKE="${QMAKE_SIMULATOR:-$QMAKE}" build_target x86_64-apple-ios
+mkdir -p "$OUTPUT_DIR/simulator"
+lipo -create \
+    "$TARGET_DIR/aarch64-apple-ios-sim/$PROFILE_DIR/$LIB_NAME" \
+    "$TARGET_DIR/x86_64-apple-ios/$PROFILE_DIR/$LIB_NAME" \
+    -output "$OUTPUT_DIR/simulator/$LIB_NAME"
+
+XCFRAMEWORK="$OUTPUT_DIR/${PACKAGE}.xcframework"
+rm -rf "$XCFRAMEWORK"
+xcodebuild -create-xcframework \
+    -library "$TARGET_DIR/aarch64-apple-ios/$PROFILE_DIR/$LIB_NAME" \
+    -headers "$EXPORT_DIR/aarch64-apple-ios/crates/$PACKAGE/include" \
+    -library "$OUTPUT_DIR/simulator/$LIB_NAME" \
+    -headers "$EXPORT_DIR/aarch64-apple-ios/crates/$PACKAGE/include" \
+    -output "$XCFRAMEWORK"
+
+echo "Created $XCFRAMEWORK"
+echo "Also link the object files in $EXPORT_DIR/<target> into the Xcode target, they contain the static initializers"
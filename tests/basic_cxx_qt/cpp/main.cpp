@@ -129,6 +129,101 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to queue the emission of a signal
+  void test_emit_queued()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QSignalSpy readySpy(&obj, &cxx_qt::my_object::MyObject::ready);
+    obj.emitReadyQueued();
+    QCOMPARE(readySpy.count(), 0);
+    QTRY_COMPARE(readySpy.count(), 1);
+  }
+
+  // CXX-Qt allows a background thread to wait for the result of a queued
+  // closure
+  void test_invoke_blocking()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(21);
+    QVERIFY(obj.invokeBlockingTest());
+    QCOMPARE(obj.fetchBlockingResult(), 0);
+    QTRY_COMPARE(obj.fetchBlockingResult(), 42);
+  }
+
+  // CXX-Qt detects when a property setter re-enters a borrowed QObject
+  void test_reenter_setter()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+    QVERIFY(obj.reenterSetterTest());
+    QCOMPARE(obj.getNumber(), 4);
+
+    // The borrow is released again afterwards
+    obj.setNumber(5);
+    QCOMPARE(obj.getNumber(), 5);
+  }
+
+  // CXX-Qt runs worker invokables on a background thread
+  void test_worker_invokable()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.doubleNumberOnWorker(21);
+    QTRY_COMPARE(obj.getNumber(), 42);
+  }
+
+  // CXX-Qt can mutate the Rust struct through a const QObject
+  void test_interior_mutability()
+  {
+    const cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.incrementSharedCount(), 1);
+    QCOMPARE(obj.incrementSharedCount(), 2);
+  }
+
+  // CXX-Qt weak references detect when the QObject is destroyed
+  void test_weak_ref()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.weakTargetNumber(), -1);
+
+    auto target = new cxx_qt::my_object::MyObject;
+    target->setNumber(4);
+    obj.setWeakTarget(*target);
+    QCOMPARE(obj.weakTargetNumber(), 4);
+
+    target->setNumber(8);
+    QCOMPARE(obj.weakTargetNumber(), 8);
+
+    delete target;
+    QCOMPARE(obj.weakTargetNumber(), -1);
+  }
+
+  // CXX-Qt can mirror a struct in the properties of a QObject
+  void test_sync_properties()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+    obj.setString(QStringLiteral("Hello"));
+
+    QSignalSpy numberSpy(&obj, &cxx_qt::my_object::MyObject::numberChanged);
+    QSignalSpy stringSpy(&obj, &cxx_qt::my_object::MyObject::stringChanged);
+
+    // Only the properties which changed are notified
+    obj.syncState(4, QStringLiteral("World"));
+    QCOMPARE(numberSpy.count(), 0);
+    QCOMPARE(stringSpy.count(), 1);
+    QCOMPARE(obj.getNumber(), 4);
+    QCOMPARE(obj.getString(), QStringLiteral("World"));
+
+    obj.syncState(8, QStringLiteral("World"));
+    QCOMPARE(numberSpy.count(), 1);
+    QCOMPARE(stringSpy.count(), 1);
+    QCOMPARE(obj.getNumber(), 8);
+
+    // The struct is created from the current values of the properties
+    obj.setString(QStringLiteral("Hi"));
+    QCOMPARE(obj.stateDescription(), QStringLiteral("8 Hi"));
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
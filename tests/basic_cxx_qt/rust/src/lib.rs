@@ -22,11 +22,20 @@ mod qobject {
         #[qproperty(i32, number)]
         #[qproperty(QString, string)]
         type MyObject = super::MyObjectRust;
+
+        #[qsignal]
+        fn ready(self: Pin<&mut MyObject>);
     }
 
     // Enabling threading on the qobject
     impl cxx_qt::Threading for MyObject {}
 
+    // Enabling mutating the Rust struct through &self
+    impl cxx_qt::InteriorMutability for MyObject {}
+
+    // Enabling running invokables on a background thread
+    impl cxx_qt::Worker for MyObject {}
+
     // Note that we are only testing with C++ here so we don't need qinvokable
     unsafe extern "RustQt" {
         fn double_number_self(self: Pin<&mut MyObject>);
@@ -42,18 +51,53 @@ mod qobject {
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
         fn throw_exception(self: &MyObject) -> Result<i32>;
+
+        fn set_weak_target(self: Pin<&mut MyObject>, target: &MyObject);
+
+        fn weak_target_number(self: &MyObject) -> i32;
+
+        fn sync_state(self: Pin<&mut MyObject>, number: i32, string: &QString);
+
+        fn state_description(self: &MyObject) -> QString;
+
+        fn emit_ready_queued(self: &MyObject);
+
+        fn invoke_blocking_test(self: Pin<&mut MyObject>) -> bool;
+
+        fn fetch_blocking_result(self: &MyObject) -> i32;
+
+        fn reenter_setter_test(self: Pin<&mut MyObject>) -> bool;
+
+        fn increment_shared_count(self: &MyObject) -> i32;
+
+        #[qinvokable(worker = set_number)]
+        fn double_number_on_worker(self: &MyObject, number: i32) -> i32;
     }
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
+use cxx_qt::{
+    CxxQtType, InteriorMutability, InvokeBlockingError, QObjectWeakRef, SyncProperties, Threading,
+};
 use cxx_qt_lib::QString;
 
+/// The state which is mirrored in the properties of MyObject
+#[derive(Clone, SyncProperties)]
+#[sync_properties(qobject::MyObject)]
+pub struct MyObjectState {
+    number: i32,
+    string: QString,
+}
+
 pub struct MyObjectRust {
     number: i32,
     string: QString,
 
     pub(crate) update_call_count: i32,
+    blocking_result: i32,
+    shared_count: i32,
+
+    weak_target: Option<QObjectWeakRef<qobject::MyObject>>,
 }
 
 impl Default for MyObjectRust {
@@ -62,6 +106,9 @@ impl Default for MyObjectRust {
             number: 0,
             string: QString::from(""),
             update_call_count: 0,
+            blocking_result: 0,
+            shared_count: 0,
+            weak_target: None,
         }
     }
 }
@@ -125,4 +172,93 @@ impl qobject::MyObject {
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
     }
+
+    fn set_weak_target(self: Pin<&mut Self>, target: &Self) {
+        // Safety:
+        //
+        // The base class of MyObject is QObject.
+        self.rust_mut().weak_target = Some(unsafe { QObjectWeakRef::new(target) });
+    }
+
+    fn weak_target_number(&self) -> i32 {
+        // Safety:
+        //
+        // The target is only read during this call, which doesn't destroy it,
+        // and C++ does not hold a mutable reference to it while calling us.
+        self.weak_target
+            .as_ref()
+            .and_then(|weak| unsafe { weak.upgrade() })
+            .map(|target| *target.number())
+            .unwrap_or(-1)
+    }
+
+    fn sync_state(self: Pin<&mut Self>, number: i32, string: &QString) {
+        self.sync_from(&MyObjectState {
+            number,
+            string: string.clone(),
+        });
+    }
+
+    fn state_description(&self) -> QString {
+        let state = self.to_struct();
+        QString::from(&format!("{} {}", state.number, state.string))
+    }
+
+    fn emit_ready_queued(&self) {
+        self.emit_queued(MyObjectQueuedSignal::Ready).unwrap();
+    }
+
+    fn invoke_blocking_test(self: Pin<&mut Self>) -> bool {
+        let qt_thread = self.qt_thread();
+        // Blocking on the thread of the QObject would deadlock, so it is an error
+        let deadlock_detected = matches!(
+            qt_thread.invoke_blocking(|_| ()),
+            Err(InvokeBlockingError::Deadlock)
+        );
+
+        std::thread::spawn(move || {
+            let number = qt_thread
+                .invoke_blocking(|qobject| *qobject.number())
+                .unwrap();
+            qt_thread
+                .queue(move |qobject| {
+                    qobject.rust_mut().blocking_result = number * 2;
+                })
+                .unwrap();
+        });
+
+        deadlock_detected
+    }
+
+    fn fetch_blocking_result(&self) -> i32 {
+        self.blocking_result
+    }
+
+    fn reenter_setter_test(self: Pin<&mut Self>) -> bool {
+        // Re-enter the QObject through a property setter while the Rust struct is borrowed,
+        // as a signal handler which is connected with a direct connection would
+        let qobject: *mut Self = unsafe { self.get_unchecked_mut() };
+        let _rust = unsafe { Pin::new_unchecked(&mut *qobject) }
+            .try_rust_mut()
+            .unwrap();
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            unsafe { Pin::new_unchecked(&mut *qobject) }.set_number(1);
+        }))
+        .is_err()
+    }
+
+    fn double_number_on_worker(number: i32) -> i32 {
+        number * 2
+    }
+
+    fn increment_shared_count(&self) -> i32 {
+        self.borrow_rust_mut().shared_count += 1;
+
+        // A mutable borrow through &self conflicts with a shared borrow
+        let rust = self.borrow_rust();
+        if self.try_borrow_rust_mut().is_ok() {
+            return -1;
+        }
+        rust.shared_count
+    }
 }
@@ -7,13 +7,28 @@
 #include <QtCore/QScopedPointer>
 #include <QtTest/QTest>
 
+#include "qaccessible.h"
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include "qaction.h"
+#endif
 #include "qbytearray.h"
 #include "qcolor.h"
+#include "qcommandlineparser.h"
 #include "qcoreapplication.h"
+#include "qcryptographichash.h"
+#include "qcursor.h"
+#include "qdatastream.h"
 #include "qdate.h"
 #include "qdatetime.h"
+#include "qfile.h"
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include "qfuture.h"
+#endif
 #include "qguiapplication.h"
 #include "qhash.h"
+#include "qicon.h"
+#include "qinputmethod.h"
+#include "qitemselectionmodel.h"
 #include "qline.h"
 #include "qlinef.h"
 #include "qlist.h"
@@ -21,31 +36,51 @@
 #include "qmargins.h"
 #include "qmarginsf.h"
 #include "qmetaobjectconnection.h"
+#include "qmetatype.h"
+#include "qmimedatabase.h"
+#include "qmlpromise.h"
 #include "qmodelindex.h"
+#include "qpalette.h"
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
 #include "qpointf.h"
 #include "qpolygon.h"
 #include "qpolygonf.h"
+#include "qprocess.h"
 #include "qqmlapplicationengine.h"
 #include "qqmlengine.h"
+#include "qquickitem.h"
+#include "qquickwindow.h"
 #include "qrect.h"
 #include "qrectf.h"
 #include "qregion.h"
+#include "qsavefile.h"
+#include "qscreen.h"
 #include "qset.h"
 #include "qsize.h"
 #include "qsizef.h"
+#include "qstandarditemmodel.h"
+#ifdef CXX_QT_TEST_STATEMACHINE
+#include "qstatemachine.h"
+#endif
 #include "qstring.h"
 #include "qstringlist.h"
+#include "qtenums.h"
+#include "qtextcharformat.h"
+#include "qthread.h"
 #include "qtime.h"
 #include "qtimezone.h"
+#include "qundostack.h"
 #include "qurl.h"
+#include "qvalidator.h"
 #include "qvariant.h"
+#include "qvariantanimation.h"
 #include "qvector.h"
 #include "qvector2d.h"
 #include "qvector3d.h"
 #include "qvector4d.h"
+#include "vecmodel.h"
 
 int
 main(int argc, char* argv[])
@@ -98,6 +133,39 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QPenTest));
   runTest(QScopedPointer<QObject>(new QPolygonFTest));
   runTest(QScopedPointer<QObject>(new QRegionTest));
+  runTest(QScopedPointer<QObject>(new QTextCharFormatTest));
+  runTest(QScopedPointer<QObject>(new VecModelTest));
+  runTest(QScopedPointer<QObject>(new QQuickItemTest));
+  runTest(QScopedPointer<QObject>(new QProcessTest));
+  runTest(QScopedPointer<QObject>(new QUndoStackTest));
+  runTest(QScopedPointer<QObject>(new QValidatorTest));
+  runTest(QScopedPointer<QObject>(new QStandardItemModelTest));
+  runTest(QScopedPointer<QObject>(new QItemSelectionModelTest));
+  runTest(QScopedPointer<QObject>(new QmlPromiseTest));
+  runTest(QScopedPointer<QObject>(new QVariantAnimationTest));
+  runTest(QScopedPointer<QObject>(new QQuickWindowTest));
+  runTest(QScopedPointer<QObject>(new QScreenTest));
+  runTest(QScopedPointer<QObject>(new QInputMethodTest));
+  runTest(QScopedPointer<QObject>(new QAccessibleTest));
+  runTest(QScopedPointer<QObject>(new QFileTest));
+  runTest(QScopedPointer<QObject>(new QSaveFileTest));
+  runTest(QScopedPointer<QObject>(new QDataStreamTest));
+  runTest(QScopedPointer<QObject>(new QPaletteTest));
+  runTest(QScopedPointer<QObject>(new QIconTest));
+  runTest(QScopedPointer<QObject>(new QCursorTest));
+  runTest(QScopedPointer<QObject>(new QMimeDatabaseTest));
+  runTest(QScopedPointer<QObject>(new QCryptographicHashTest));
+  runTest(QScopedPointer<QObject>(new QCommandLineParserTest));
+  runTest(QScopedPointer<QObject>(new QtEnumsTest));
+  runTest(QScopedPointer<QObject>(new QMetaTypeTest));
+  runTest(QScopedPointer<QObject>(new QThreadTest));
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  runTest(QScopedPointer<QObject>(new QActionTest));
+  runTest(QScopedPointer<QObject>(new QFutureTest));
+#endif
+#ifdef CXX_QT_TEST_STATEMACHINE
+  runTest(QScopedPointer<QObject>(new QStateMachineTest));
+#endif
 
   return status;
 }
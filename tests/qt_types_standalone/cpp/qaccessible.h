@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QAccessible>
+#include <QtGui/QGuiApplication>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qaccessible.cxxqt.h"
+
+class QAccessibleTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void install()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    AccessibleCounter counter;
+    counter.install();
+
+    auto* accessible = QAccessible::queryAccessibleInterface(&counter);
+    QVERIFY(accessible != nullptr);
+    QCOMPARE(accessible->object(), &counter);
+    QCOMPARE(accessible->role(), QAccessible::SpinBox);
+    QCOMPARE(accessible->text(QAccessible::Name), QStringLiteral("Counter"));
+    QCOMPARE(accessible->text(QAccessible::Value), QStringLiteral("0"));
+    QCOMPARE(accessible->childCount(), 0);
+
+    auto* actions = accessible->actionInterface();
+    QVERIFY(actions != nullptr);
+    QCOMPARE(actions->actionNames(),
+             QStringList{ QAccessibleActionInterface::increaseAction() });
+
+    actions->doAction(QAccessibleActionInterface::increaseAction());
+    QCOMPARE(counter.count(), 1);
+    QCOMPARE(accessible->text(QAccessible::Value), QStringLiteral("1"));
+  }
+};
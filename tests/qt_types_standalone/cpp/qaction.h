@@ -0,0 +1,66 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QAction>
+#include <QtGui/QGuiApplication>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qaction.cxx.h"
+
+class QActionTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  // QAction is created by the QGuiApplication in Qt 6
+  void initTestCase()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    m_app = new QGuiApplication(argc, args.data());
+  }
+
+  void cleanupTestCase() { delete m_app; }
+
+  void construct()
+  {
+    const auto action = construct_qaction();
+    QVERIFY(action != nullptr);
+    QCOMPARE(action->text(), QStringLiteral("Save"));
+    QCOMPARE(action->shortcut(), QKeySequence(QStringLiteral("Ctrl+S")));
+    QCOMPARE(action->toolTip(), QStringLiteral("Save the file"));
+    QVERIFY(action->isCheckable());
+    QVERIFY(action->isChecked());
+    QCOMPARE(action->data().toInt(), 42);
+  }
+
+  void read()
+  {
+    QAction action(QStringLiteral("Open"));
+    action.setShortcut(QKeySequence(QStringLiteral("Ctrl+O")));
+    action.setEnabled(false);
+    action.setData(7);
+    QVERIFY(read_qaction(action));
+  }
+
+  void trigger()
+  {
+    QAction action(QStringLiteral("Bold"));
+    QSignalSpy toggled(&action, &QAction::toggled);
+    // The action is checked by the first and third trigger
+    QCOMPARE(trigger_qaction(action), 2);
+    QCOMPARE(toggled.count(), 3);
+    QVERIFY(action.isChecked());
+  }
+
+private:
+  QGuiApplication* m_app = nullptr;
+};
@@ -0,0 +1,73 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCommandLineOption>
+#include <QtCore/QCommandLineParser>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qcommandlineparser.cxx.h"
+
+class QCommandLineParserTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto o = construct_qcommandlineoption();
+    QCOMPARE(o.names(),
+             QStringList({ QStringLiteral("o"), QStringLiteral("output") }));
+    QCOMPARE(o.description(), QStringLiteral("Output file"));
+    QCOMPARE(o.valueName(), QStringLiteral("file"));
+    QCOMPARE(o.defaultValues(), QStringList(QStringLiteral("out.txt")));
+  }
+
+  void read()
+  {
+    const QCommandLineOption o(
+      QStringList({ QStringLiteral("o"), QStringLiteral("output") }),
+      QStringLiteral("Output file"),
+      QStringLiteral("file"),
+      QStringLiteral("out.txt"));
+    QVERIFY(read_qcommandlineoption(o));
+  }
+
+  void clone()
+  {
+    const QCommandLineOption o(QStringLiteral("verbose"),
+                               QStringLiteral("Verbose output"));
+    const auto c = clone_qcommandlineoption(o);
+    QCOMPARE(c.names(), o.names());
+    QCOMPARE(c.description(), o.description());
+  }
+
+  void parse()
+  {
+    const auto values = parse_qcommandlineparser(
+      QStringList({ QStringLiteral("app"),
+                    QStringLiteral("--verbose"),
+                    QStringLiteral("-o"),
+                    QStringLiteral("result.txt"),
+                    QStringLiteral("input.txt") }));
+    QCOMPARE(values,
+             QStringList(
+               { QStringLiteral("result.txt"), QStringLiteral("input.txt") }));
+
+    // The default value is used when the option is not given
+    const auto defaults = parse_qcommandlineparser(
+      QStringList({ QStringLiteral("app"), QStringLiteral("--verbose") }));
+    QCOMPARE(defaults, QStringList(QStringLiteral("out.txt")));
+  }
+
+  void error()
+  {
+    QCOMPARE(error_qcommandlineparser(QStringList(
+               { QStringLiteral("app"), QStringLiteral("--unknown") })),
+             QStringLiteral("Unknown option 'unknown'."));
+  }
+};
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCryptographicHash>
+#include <QtCore/QMessageAuthenticationCode>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qcryptographichash.cxx.h"
+
+class QCryptographicHashTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void hash()
+  {
+    const auto result = hash_qcryptographichash(QByteArrayLiteral("abc"));
+    QCOMPARE(result,
+             QCryptographicHash::hash(QByteArrayLiteral("abc"),
+                                      QCryptographicHash::Sha256));
+    QCOMPARE(
+      result.toHex(),
+      QByteArrayLiteral(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"));
+  }
+
+  void hashLength() { QCOMPARE(hash_length_qcryptographichash(), 32); }
+
+  void messageAuthenticationCode()
+  {
+    const auto message =
+      QByteArrayLiteral("The quick brown fox jumps over the lazy dog");
+    const auto key = QByteArrayLiteral("key");
+    const auto result = hash_qmessageauthenticationcode(message, key);
+    QCOMPARE(result,
+             QMessageAuthenticationCode::hash(
+               message, key, QCryptographicHash::Sha256));
+    QCOMPARE(
+      result.toHex(),
+      QByteArrayLiteral(
+        "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"));
+  }
+};
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QCursor>
+#include <QtGui/QGuiApplication>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qcursor.cxx.h"
+
+class QCursorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto c = construct_qcursor();
+    QCOMPARE(c.shape(), Qt::PointingHandCursor);
+  }
+
+  void read()
+  {
+    const auto c = QCursor(Qt::OpenHandCursor);
+    QVERIFY(read_qcursor(c));
+  }
+
+  void clone()
+  {
+    const auto c = QCursor(Qt::CrossCursor);
+    const auto cloned = clone_qcursor(c);
+    QCOMPARE(cloned.shape(), Qt::CrossCursor);
+  }
+
+  void overrideCursor()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QVERIFY(override_qcursor(app));
+    QVERIFY(QGuiApplication::overrideCursor() == nullptr);
+  }
+};
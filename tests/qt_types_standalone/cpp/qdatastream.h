@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDataStream>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qdatastream.cxx.h"
+
+class QDataStreamTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void write()
+  {
+    const auto data = write_qdatastream();
+    QDataStream stream(data);
+
+    qint32 number = 0;
+    QString string;
+    bool boolean = false;
+    double real = 0.0;
+    stream >> number >> string >> boolean >> real;
+    QCOMPARE(stream.status(), QDataStream::Ok);
+    QVERIFY(stream.atEnd());
+    QCOMPARE(number, 42);
+    QCOMPARE(string, QStringLiteral("KDAB"));
+    QCOMPARE(boolean, true);
+    QCOMPARE(real, 1.5);
+  }
+
+  void writeLittleEndian()
+  {
+    QCOMPARE(write_qdatastream_little_endian(),
+             QByteArray("\x01\x00\x00\x00", 4));
+  }
+
+  void read()
+  {
+    QByteArray data;
+    {
+      QDataStream stream(&data, QIODevice::WriteOnly);
+      stream << quint64(42) << QByteArrayLiteral("KDAB");
+    }
+    QVERIFY(read_qdatastream(data));
+  }
+};
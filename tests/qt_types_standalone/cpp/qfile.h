@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qfile.cxx.h"
+
+class QFileTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void writeRead()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto path = dir.filePath(QStringLiteral("data.txt"));
+
+    QCOMPARE(write_qfile(path, QByteArrayLiteral("KDAB")), 4);
+    QCOMPARE(read_qfile(path), QByteArrayLiteral("KDAB"));
+
+    // Writing again truncates the file
+    QCOMPARE(write_qfile(path, QByteArrayLiteral("Qt")), 2);
+    QFile file(path);
+    QVERIFY(file.open(QIODevice::ReadOnly));
+    QCOMPARE(file.readAll(), QByteArrayLiteral("Qt"));
+    file.close();
+
+    QVERIFY(remove_qfile(path));
+    QVERIFY(!QFile::exists(path));
+  }
+
+  void readMissing()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto path = dir.filePath(QStringLiteral("missing.txt"));
+
+    QVERIFY(read_qfile(path).isEmpty());
+    QVERIFY(!read_qfile_error(path).isEmpty());
+    QVERIFY(!remove_qfile(path));
+  }
+};
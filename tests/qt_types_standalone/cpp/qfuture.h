@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QFuture>
+#include <QtCore/QPromise>
+#include <QtCore/QVariant>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qfuture.cxx.h"
+
+class QFutureTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto future = construct_qfuture_finished();
+    QVERIFY(future != nullptr);
+    QVERIFY(future->isFinished());
+    QCOMPARE(future->resultCount(), 1);
+    QCOMPARE(future->result().toInt(), 42);
+  }
+
+  void read()
+  {
+    QPromise<QVariant> promise;
+    auto future = promise.future();
+    promise.start();
+    promise.addResult(QVariant::fromValue(3));
+    promise.finish();
+    QCOMPARE(read_qfuture(future), 3);
+  }
+
+  void readWithoutResult()
+  {
+    QPromise<QVariant> promise;
+    auto future = promise.future();
+    promise.start();
+    promise.finish();
+    QCOMPARE(read_qfuture(future), -1);
+  }
+
+  void await()
+  {
+    // The future is completed by a Rust thread while Rust awaits the result
+    QCOMPARE(await_qpromise_from_thread(), 7);
+  }
+};
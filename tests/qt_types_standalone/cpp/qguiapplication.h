@@ -7,6 +7,8 @@
 #pragma once
 
 #include <QtGui/QGuiApplication>
+#include <QtGui/QPalette>
+#include <QtGui/QStyleHints>
 #include <QtTest/QTest>
 
 #include "qt_types_standalone/src/qguiapplication.cxx.h"
@@ -34,4 +36,31 @@ private Q_SLOTS:
     app.setApplicationName(QStringLiteral("kdab"));
     QVERIFY(read_qguiapplication(app));
   }
+
+  void palette()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QVERIFY(set_qguiapplication_palette(app));
+    QCOMPARE(QGuiApplication::palette().color(QPalette::Button),
+             QColor(255, 0, 0));
+  }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+  void colorScheme()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QCOMPARE(read_qguiapplication_color_scheme(app),
+             static_cast<int>(QGuiApplication::styleHints()->colorScheme()));
+  }
+#endif
 };
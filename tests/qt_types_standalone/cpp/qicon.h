@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtGui/QIcon>
+#include <QtGui/QImage>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qicon.cxx.h"
+
+class QIconTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  static QImage redImage()
+  {
+    QImage image(16, 16, QImage::Format_ARGB32);
+    image.fill(QColor(255, 0, 0));
+    return image;
+  }
+
+private Q_SLOTS:
+  // QIcon uses QPixmap, which requires a QGuiApplication
+  void initTestCase()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    m_app = new QGuiApplication(argc, args.data());
+  }
+
+  void cleanupTestCase() { delete m_app; }
+
+  void construct()
+  {
+    const auto icon = construct_qicon(redImage());
+    QVERIFY(!icon.isNull());
+    QCOMPARE(icon.pixmap(16).toImage().pixelColor(0, 0), QColor(255, 0, 0));
+  }
+
+  void read()
+  {
+    const auto icon = QIcon(QPixmap::fromImage(redImage()));
+    QVERIFY(read_qicon(icon));
+  }
+
+  void clone()
+  {
+    const auto icon = QIcon(QPixmap::fromImage(redImage()));
+    const auto c = clone_qicon(icon);
+    QCOMPARE(c.cacheKey(), icon.cacheKey());
+  }
+
+  void theme()
+  {
+    const auto themeName = QIcon::themeName();
+    QVERIFY(qicon_theme());
+    QCOMPARE(QIcon::themeName(), QStringLiteral("kdab"));
+    QIcon::setThemeName(themeName);
+  }
+
+private:
+  QGuiApplication* m_app = nullptr;
+};
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qinputmethod.cxx.h"
+
+class QInputMethodTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void instance()
+  {
+    // The input method only exists once the QGuiApplication has been created
+    QVERIFY(!has_qinputmethod());
+
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QVERIFY(has_qinputmethod());
+    QVERIFY(read_qinputmethod());
+  }
+};
@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QItemSelectionModel>
+#include <QtCore/QStringListModel>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qitemselectionmodel.cxx.h"
+
+class QItemSelectionModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void select()
+  {
+    QStringListModel model(QStringList() << QStringLiteral("a")
+                                         << QStringLiteral("b"));
+    QItemSelectionModel selectionModel(&model);
+    QSignalSpy currentSpy(&selectionModel,
+                          &QItemSelectionModel::currentChanged);
+
+    const auto index = model.index(1, 0);
+    select_qitemselectionmodel_row(selectionModel, index);
+    QVERIFY(selectionModel.isSelected(index));
+    QVERIFY(!selectionModel.isSelected(model.index(0, 0)));
+    QCOMPARE(selectionModel.currentIndex(), index);
+    QCOMPARE(currentSpy.count(), 1);
+
+    // ClearAndSelect replaces the previous selection
+    select_qitemselectionmodel_row(selectionModel, model.index(0, 0));
+    QVERIFY(!selectionModel.isSelected(index));
+    QCOMPARE(selectionModel.selectedIndexes().size(), 1);
+  }
+
+  void read()
+  {
+    QStringListModel model(QStringList() << QStringLiteral("a")
+                                         << QStringLiteral("b"));
+    QItemSelectionModel selectionModel(&model);
+    const auto index = model.index(1, 0);
+    selectionModel.setCurrentIndex(index, QItemSelectionModel::ClearAndSelect);
+    QVERIFY(read_qitemselectionmodel(selectionModel, index));
+  }
+};
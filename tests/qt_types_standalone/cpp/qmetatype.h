@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QMetaType>
+#include <QtCore/QVariant>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmetatype.cxxqt.h"
+
+class QMetaTypeTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void fromName()
+  {
+    QCOMPARE(qmetatype_id_from_name(QByteArrayLiteral("QString")),
+             qMetaTypeId<QString>());
+    // Unknown types are not valid
+    QCOMPARE(qmetatype_id_from_name(QByteArrayLiteral("UnknownType")), 0);
+  }
+
+  void fromVariant()
+  {
+    QCOMPARE(qmetatype_id_from_variant(QVariant(QStringLiteral("KDAB"))),
+             qMetaTypeId<QString>());
+    QCOMPARE(qmetatype_id_from_variant(QVariant(1)), qMetaTypeId<int>());
+  }
+
+  void name()
+  {
+    QCOMPARE(qmetatype_name(qMetaTypeId<int>()), QByteArrayLiteral("int"));
+    QCOMPARE(qmetatype_name(qMetaTypeId<QString>()),
+             QByteArrayLiteral("QString"));
+  }
+
+  void registerTypedef()
+  {
+    QVERIFY(qmetatype_register_typedef(QByteArrayLiteral("CxxQtTestString"),
+                                       qMetaTypeId<QString>()));
+  }
+
+  void qobjectRegistered()
+  {
+    // Registered when the library is loaded, without qRegisterMetaType
+    QVERIFY(qmetatype_is_qobject_registered());
+
+    MetaTypeObject object;
+    const auto variant = QVariant::fromValue(&object);
+    QCOMPARE(qmetatype_id_from_variant(variant),
+             qmetatype_id_from_name(QByteArrayLiteral("MetaTypeObject*")));
+  }
+};
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QMimeDatabase>
+#include <QtCore/QMimeType>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmimedatabase.cxx.h"
+
+class QMimeDatabaseTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto m = construct_qmimetype();
+    QVERIFY(m.isValid());
+    QCOMPARE(m.name(), QStringLiteral("text/x-csrc"));
+    QVERIFY(m.inherits(QStringLiteral("text/plain")));
+  }
+
+  void read()
+  {
+    const auto m = QMimeDatabase().mimeTypeForName(QStringLiteral("text/plain"));
+    QVERIFY(read_qmimetype(m));
+  }
+
+  void clone()
+  {
+    const auto m = QMimeDatabase().mimeTypeForName(QStringLiteral("image/png"));
+    const auto c = clone_qmimetype(m);
+    QCOMPARE(c, m);
+    QCOMPARE(c.name(), QStringLiteral("image/png"));
+  }
+
+  void database()
+  {
+    QCOMPARE(qmimedatabase_name_for_file(QStringLiteral("icon.png")),
+             QStringLiteral("image/png"));
+    QCOMPARE(qmimedatabase_name_for_data(
+               QByteArrayLiteral("\x89PNG\x0D\x0A\x1A\x0A\x00\x00\x00\x0DIHDR")),
+             QStringLiteral("image/png"));
+    // Unknown data is matched to the default MIME type
+    QCOMPARE(qmimedatabase_name_for_data(QByteArrayLiteral("\x01\x02\x03")),
+             QStringLiteral("application/octet-stream"));
+    QCOMPARE(qmimedatabase_suffix(QStringLiteral("archive.tar.gz")),
+             QStringLiteral("tar.gz"));
+  }
+};
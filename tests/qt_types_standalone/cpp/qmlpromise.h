@@ -0,0 +1,97 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QJSEngine>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmlpromise.cxx.h"
+
+class QmlPromiseTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  // Call then() on the promise from JavaScript and store the settled value
+  static void then(QJSEngine& engine, rust::cxxqtlib1::QmlPromise* promise)
+  {
+    engine.globalObject().setProperty(QStringLiteral("promise"),
+                                      engine.newQObject(promise));
+    const auto result = engine.evaluate(QStringLiteral(
+      "var fulfilled; var rejected; "
+      "promise.then(value => { fulfilled = value; }, "
+      "             reason => { rejected = reason; });"));
+    QVERIFY(!result.isError());
+  }
+
+  static QJSValue property(QJSEngine& engine, const QString& name)
+  {
+    return engine.globalObject().property(name);
+  }
+
+private Q_SLOTS:
+  void resolve()
+  {
+    // QmlPromise settles the callbacks with the event loop
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QJSEngine engine;
+    then(engine, construct_qmlpromise_resolved(42));
+    QTRY_COMPARE(property(engine, QStringLiteral("fulfilled")).toInt(), 42);
+    QVERIFY(property(engine, QStringLiteral("rejected")).isUndefined());
+  }
+
+  void reject()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QJSEngine engine;
+    then(engine, construct_qmlpromise_rejected("kdab"));
+    QTRY_COMPARE(property(engine, QStringLiteral("rejected")).toString(),
+                 QStringLiteral("kdab"));
+    QVERIFY(property(engine, QStringLiteral("fulfilled")).isUndefined());
+  }
+
+  void drop()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    // Dropping the resolver rejects the promise
+    QJSEngine engine;
+    then(engine, construct_qmlpromise_dropped());
+    QTRY_COMPARE(
+      property(engine, QStringLiteral("rejected")).toString(),
+      QStringLiteral("The promise was dropped without being settled"));
+  }
+
+  void resolveFromThread()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    // The promise is settled on its own thread once the event loop runs
+    QJSEngine engine;
+    then(engine, construct_qmlpromise_resolved_from_thread(7));
+    QTRY_COMPARE(property(engine, QStringLiteral("fulfilled")).toInt(), 7);
+  }
+};
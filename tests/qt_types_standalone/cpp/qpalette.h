@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPalette>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qpalette.cxx.h"
+
+class QPaletteTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto p = construct_qpalette();
+    QCOMPARE(p.color(QPalette::Active, QPalette::Button), QColor(255, 0, 0));
+    QCOMPARE(p.color(QPalette::Active, QPalette::WindowText),
+             QColor(0, 0, 255));
+    // Only the color of the active group was changed
+    QVERIFY(p.color(QPalette::Inactive, QPalette::WindowText) !=
+            QColor(0, 0, 255));
+  }
+
+  void read()
+  {
+    QPalette p(QColor(0, 255, 0));
+    p.setColor(QPalette::Disabled, QPalette::Text, QColor(0, 0, 255));
+    p.setCurrentColorGroup(QPalette::Active);
+    QVERIFY(read_qpalette(p));
+  }
+
+  void clone()
+  {
+    const auto p = QPalette(QColor(255, 0, 0));
+    const auto c = clone_qpalette(p);
+    QCOMPARE(c, p);
+    QCOMPARE(c.color(QPalette::Button), QColor(255, 0, 0));
+  }
+};
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QProcess>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qprocess.cxx.h"
+
+class QProcessTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void start()
+  {
+    // QProcess requires a QCoreApplication for its event dispatcher
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto process = start_missing_qprocess();
+    QVERIFY(process != nullptr);
+    QCOMPARE(process->program(), QStringLiteral("cxx-qt-missing-program"));
+    QCOMPARE(process->arguments(),
+             QStringList() << QStringLiteral("--version"));
+    QCOMPARE(process->workingDirectory(), QStringLiteral("/kdab"));
+    QCOMPARE(process->error(), QProcess::FailedToStart);
+    QCOMPARE(process->state(), QProcess::NotRunning);
+  }
+
+  void read()
+  {
+    // QProcess requires a QCoreApplication for its event dispatcher
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QProcess process;
+    process.setWorkingDirectory(QStringLiteral("/kdab"));
+    process.start(QStringLiteral("cxx-qt-missing-program"), QStringList());
+    process.waitForStarted(1000);
+    QVERIFY(read_qprocess_failed_to_start(process));
+  }
+};
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQuick/QQuickItem>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qquickitem.cxx.h"
+
+class QQuickItemTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void linked()
+  {
+    QQuickItem item;
+    item.setWidth(21.0);
+    double_qquickitem_width(item);
+    QCOMPARE(item.width(), 42.0);
+  }
+};
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtQuick/QQuickWindow>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qquickwindow.cxx.h"
+
+class QQuickWindowTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void read()
+  {
+    // A window needs a QGuiApplication for its screen
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQuickWindow window;
+    window.setGeometry(10, 20, 200, 100);
+    QVERIFY(read_qquickwindow(window));
+  }
+};
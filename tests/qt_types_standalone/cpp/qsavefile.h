@@ -0,0 +1,69 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qsavefile.cxx.h"
+
+class QSaveFileTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  static QByteArray readFile(const QString& path)
+  {
+    QFile file(path);
+    if (!file.open(QIODevice::ReadOnly)) {
+      return QByteArray();
+    }
+    return file.readAll();
+  }
+
+private Q_SLOTS:
+  void writeAll()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto path = dir.filePath(QStringLiteral("data.txt"));
+
+    QVERIFY(write_all_qsavefile(path, QByteArrayLiteral("KDAB")).isEmpty());
+    QCOMPARE(readFile(path), QByteArrayLiteral("KDAB"));
+
+    // The file is left unchanged when writing fails
+    const auto missing = dir.filePath(QStringLiteral("missing/data.txt"));
+    QVERIFY(!write_all_qsavefile(missing, QByteArrayLiteral("KDAB")).isEmpty());
+    QVERIFY(!QFile::exists(missing));
+  }
+
+  void cancel()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto path = dir.filePath(QStringLiteral("data.txt"));
+
+    QVERIFY(write_all_qsavefile(path, QByteArrayLiteral("KDAB")).isEmpty());
+    QVERIFY(cancel_qsavefile(path, QByteArrayLiteral("Qt")));
+    QCOMPARE(readFile(path), QByteArrayLiteral("KDAB"));
+  }
+
+  void temporaryFile()
+  {
+    QCOMPARE(roundtrip_qtemporaryfile(QByteArrayLiteral("KDAB")),
+             QByteArrayLiteral("KDAB"));
+
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto name =
+      qtemporaryfile_name(dir.filePath(QStringLiteral("kdab-XXXXXX.txt")));
+    QVERIFY(name.startsWith(dir.filePath(QStringLiteral("kdab-"))));
+    QVERIFY(name.endsWith(QStringLiteral(".txt")));
+    QVERIFY(!QFile::exists(name));
+  }
+};
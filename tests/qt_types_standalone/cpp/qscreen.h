@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtGui/QScreen>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qscreen.cxx.h"
+
+class QScreenTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void screens()
+  {
+    // The screens are owned by the QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QVERIFY(QGuiApplication::primaryScreen() != nullptr);
+    QVERIFY(read_qscreens());
+    QCOMPARE(primary_qscreen_name(), QGuiApplication::primaryScreen()->name());
+  }
+};
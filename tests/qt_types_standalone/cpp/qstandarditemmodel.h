@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QStandardItemModel>
+#include <QtTest/QAbstractItemModelTester>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qstandarditemmodel.cxx.h"
+
+class QStandardItemModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto model = construct_qstandarditemmodel();
+    QVERIFY(model != nullptr);
+    QAbstractItemModelTester tester(model.get());
+
+    QCOMPARE(model->rowCount(), 2);
+    QCOMPARE(model->columnCount(), 1);
+    QCOMPARE(model->horizontalHeaderItem(0)->text(), QStringLiteral("Fruit"));
+    QCOMPARE(model->item(0)->text(), QStringLiteral("apple"));
+    QCOMPARE(model->item(0)->rowCount(), 1);
+    QCOMPARE(model->item(0)->child(0)->text(), QStringLiteral("seed"));
+    QCOMPARE(model->item(1)->text(), QStringLiteral("banana"));
+    QVERIFY(!model->item(1)->isEditable());
+  }
+
+  void read()
+  {
+    QStandardItemModel model;
+    auto apple = new QStandardItem(QStringLiteral("apple"));
+    apple->appendRow(new QStandardItem(QStringLiteral("seed")));
+    model.appendRow(apple);
+    auto banana = new QStandardItem(QStringLiteral("banana"));
+    banana->setEditable(false);
+    model.appendRow(banana);
+    QVERIFY(read_qstandarditemmodel(model));
+  }
+};
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qstatemachine.cxx.h"
+
+class QStateMachineTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void transitions()
+  {
+    // QStateMachine processes its events with the event loop
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto light = construct_traffic_light();
+    QVERIFY(!light->is_running());
+    light->start();
+    QTRY_VERIFY(light->is_running());
+    QVERIFY(light->is_red());
+    QVERIFY(!light->is_green());
+
+    light->post_event(QStringLiteral("go"));
+    QTRY_VERIFY(light->is_green());
+    QVERIFY(!light->is_red());
+    QCOMPARE(light->transitions(), 1);
+
+    // Events without a transition from the active state are ignored
+    light->post_event(QStringLiteral("go"));
+    light->post_event(QStringLiteral("stop"));
+    QTRY_VERIFY(light->is_red());
+    QCOMPARE(light->transitions(), 2);
+  }
+};
@@ -52,4 +52,25 @@ private Q_SLOTS:
     modify_qstring(s);
     QCOMPARE(s, QStringLiteral("Updated string value"));
   }
+
+  void arg()
+  {
+    const auto s = QStringLiteral("%1 %2 %3");
+    QCOMPARE(arg_qstring(s), QStringLiteral("-89 18446744073709551615 1.5"));
+  }
+
+  void localeAwareCompare()
+  {
+    const auto a = QStringLiteral("a");
+    const auto b = QStringLiteral("b");
+    QCOMPARE(locale_aware_compare_qstring(a, b), -1);
+    QCOMPARE(locale_aware_compare_qstring(b, a), 1);
+    QCOMPARE(locale_aware_compare_qstring(a, a), 0);
+  }
+
+  void qformat()
+  {
+    QCOMPARE(qformat_qstring(QStringLiteral("Basket")),
+             QStringLiteral("Basket has 3 items weighing 1.5kg by KDAB"));
+  }
 };
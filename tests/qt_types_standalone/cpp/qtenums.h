@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/Qt>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtenums.cxx.h"
+
+class QtEnumsTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void alignment()
+  {
+    // Combined flags are preserved across the bridge
+    QCOMPARE(alignment_center(), Qt::AlignCenter);
+  }
+
+  void itemDataRole()
+  {
+    QCOMPARE(user_role(1), Qt::UserRole + 1);
+    QCOMPARE(item_data_role_from_int(Qt::EditRole), Qt::EditRole);
+    // Values without a variant, like custom roles, are preserved
+    QCOMPARE(static_cast<int>(item_data_role_from_int(Qt::UserRole + 2)),
+             Qt::UserRole + 2);
+  }
+
+  void key()
+  {
+    QVERIFY(read_key(Qt::Key_A));
+    QVERIFY(!read_key(Qt::Key_Escape));
+  }
+
+  void mouseButton()
+  {
+    QCOMPARE(read_mouse_button(Qt::LeftButton), 1);
+    QCOMPARE(read_mouse_button(Qt::RightButton), 2);
+    QCOMPARE(read_mouse_button(Qt::MiddleButton), 4);
+  }
+};
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTextCharFormat>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtextcharformat.cxx.h"
+
+class QTextCharFormatTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto f = construct_qtextcharformat();
+    QVERIFY(f.fontItalic());
+    QCOMPARE(f.foreground().color(), QColor(255, 0, 0));
+  }
+
+  void clone()
+  {
+    auto f = QTextCharFormat();
+    f.setFontWeight(75);
+    f.setFontUnderline(true);
+    const auto c = clone_qtextcharformat(f);
+    QCOMPARE(c.fontWeight(), 75);
+    QVERIFY(c.fontUnderline());
+    QCOMPARE(c, f);
+  }
+};
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QThread>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qthread.cxxqt.h"
+
+class QThreadTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void run() { QVERIFY(run_qthread()); }
+
+  void moveToThread()
+  {
+    QThread thread;
+    ThreadWorker worker;
+    QCOMPARE(worker.thread(), QThread::currentThread());
+
+    QVERIFY(move_to_qthread(worker, thread));
+    QCOMPARE(worker.thread(), &thread);
+  }
+
+  void threadAffinity()
+  {
+    QThread thread;
+    QVERIFY(!is_current_qthread(thread));
+    QVERIFY(is_current_qthread(*QThread::currentThread()));
+
+    ThreadWorker worker;
+    QVERIFY(is_on_current_qthread(worker));
+    QVERIFY(lives_in_qthread(worker, *QThread::currentThread()));
+    // Does not assert on the thread which the object lives in
+    worker.checkThread();
+
+    QVERIFY(move_to_qthread(worker, thread));
+    QVERIFY(!is_on_current_qthread(worker));
+    QVERIFY(lives_in_qthread(worker, thread));
+  }
+
+  void moveToThreadWithParent()
+  {
+    QThread thread;
+    QObject parent;
+    ThreadWorker worker(&parent);
+
+    // Objects with a parent cannot be moved
+    QTest::ignoreMessage(QtWarningMsg,
+                         "QObject::moveToThread: Cannot move objects with a "
+                         "parent");
+    QVERIFY(!move_to_qthread(worker, thread));
+    QCOMPARE(worker.thread(), QThread::currentThread());
+  }
+};
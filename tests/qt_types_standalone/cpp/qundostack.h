@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QtGlobal>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtGui/QUndoStack>
+#else
+#include <QtWidgets/QUndoStack>
+#endif
+
+#include "qt_types_standalone/src/qundostack.cxx.h"
+
+class QUndoStackTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void push()
+  {
+    QUndoStack stack;
+    QSignalSpy indexSpy(&stack, &QUndoStack::indexChanged);
+    const auto counter = construct_undo_counter();
+
+    // Commands are redone when they are pushed
+    counter->push_increment(stack);
+    QCOMPARE(counter->value(), 1);
+    counter->push_double(stack);
+    QCOMPARE(counter->value(), 2);
+
+    QCOMPARE(stack.count(), 2);
+    QCOMPARE(stack.index(), 2);
+    QCOMPARE(stack.undoText(), QStringLiteral("Double"));
+    QCOMPARE(indexSpy.count(), 2);
+  }
+
+  void undoRedo()
+  {
+    QUndoStack stack;
+    const auto counter = construct_undo_counter();
+    counter->push_increment(stack);
+    counter->push_double(stack);
+
+    stack.undo();
+    QCOMPARE(counter->value(), 1);
+    QCOMPARE(stack.redoText(), QStringLiteral("Double"));
+    stack.undo();
+    QCOMPARE(counter->value(), 0);
+    QVERIFY(!stack.canUndo());
+
+    stack.redo();
+    QCOMPARE(counter->value(), 1);
+    QCOMPARE(stack.undoText(), QStringLiteral("Increment"));
+  }
+};
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QValidator>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qvalidator.cxx.h"
+
+class QValidatorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    QCOMPARE(validate_digits(QStringLiteral("")), QValidator::Intermediate);
+    QCOMPARE(validate_digits(QStringLiteral("42")), QValidator::Acceptable);
+    QCOMPARE(validate_digits(QStringLiteral("4a")), QValidator::Invalid);
+  }
+
+  void read()
+  {
+    QCOMPARE(read_qvalidatorstate(QValidator::Invalid),
+             QStringLiteral("Invalid"));
+    QCOMPARE(read_qvalidatorstate(QValidator::Intermediate),
+             QStringLiteral("Intermediate"));
+    QCOMPARE(read_qvalidatorstate(QValidator::Acceptable),
+             QStringLiteral("Acceptable"));
+  }
+};
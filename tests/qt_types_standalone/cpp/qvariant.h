@@ -6,6 +6,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <QtCore/QDebug>
 #include <QtCore/QVariant>
 #include <QtTest/QTest>
 
@@ -76,6 +77,10 @@ test_constructed_qvariant(const QVariant& v, VariantTest test)
     case VariantTest::QUrl:
       return v.value<QUrl>().toString() ==
              QStringLiteral("https://github.com/KDAB");
+    case VariantTest::QVariantMap:
+      return v.toMap().value(QStringLiteral("firstName")).toString() ==
+               QStringLiteral("Rust") &&
+             v.toMap().value(QStringLiteral("age")).toInt() == 42;
     case VariantTest::U8:
       return v.value<::std::uint8_t>() == 12;
     case VariantTest::U16:
@@ -125,6 +130,7 @@ private Q_SLOTS:
     QTest::newRow("QString") << VariantTest::QString;
     QTest::newRow("QTime") << VariantTest::QTime;
     QTest::newRow("QUrl") << VariantTest::QUrl;
+    QTest::newRow("QVariantMap") << VariantTest::QVariantMap;
     QTest::newRow("U8") << VariantTest::U8;
     QTest::newRow("U16") << VariantTest::U16;
     QTest::newRow("U32") << VariantTest::U32;
@@ -187,6 +193,15 @@ private Q_SLOTS:
     QTest::newRow("QUrl") << QVariant::fromValue<QUrl>(QUrl(QStringLiteral(
                                "https://github.com/KDAB/cxx-qt")))
                           << VariantTest::QUrl;
+    QTest::newRow("QVariantMap")
+      << QVariant::fromValue<QVariantMap>(
+           QVariantMap{ { QStringLiteral("firstName"), QStringLiteral("C++") },
+                        { QStringLiteral("age"), 89 } })
+      << VariantTest::QVariantMap;
+    QTest::newRow("QVariantMapMissingKey")
+      << QVariant::fromValue<QVariantMap>(
+           QVariantMap{ { QStringLiteral("firstName"), QStringLiteral("C++") } })
+      << VariantTest::QVariantMapMissingKey;
     QTest::newRow("U8") << QVariant::fromValue<::std::uint8_t>(89)
                         << VariantTest::U8;
     QTest::newRow("U16") << QVariant::fromValue<::std::uint16_t>(8910)
@@ -202,4 +217,23 @@ private Q_SLOTS:
     QCOMPARE(c.toPoint().x(), 8);
     QCOMPARE(c.toPoint().y(), 9);
   }
+
+  void typeName()
+  {
+    QCOMPARE(type_name_qvariant(QVariant::fromValue<::std::int32_t>(89)),
+             QStringLiteral("int"));
+    QCOMPARE(type_name_qvariant(QVariant::fromValue(QStringLiteral("KDAB"))),
+             QStringLiteral("QString"));
+    // An invalid variant has no type
+    QVERIFY(type_name_qvariant(QVariant()).isEmpty());
+  }
+
+  void debug()
+  {
+    const auto v = QVariant::fromValue<::std::int32_t>(89);
+    QString expected;
+    QDebug(&expected) << v;
+    QCOMPARE(debug_qvariant(v), expected);
+    QVERIFY(debug_qvariant(v).startsWith(QStringLiteral("QVariant(int, 89)")));
+  }
 };
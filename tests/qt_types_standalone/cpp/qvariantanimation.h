@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QPropertyAnimation>
+#include <QtCore/QVariantAnimation>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qvariantanimation.cxx.h"
+
+class QVariantAnimationTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto animation = construct_qvariantanimation();
+    QVERIFY(animation != nullptr);
+    QCOMPARE(animation->startValue().toInt(), 0);
+    QCOMPARE(animation->endValue().toInt(), 100);
+    QCOMPARE(animation->duration(), 1000);
+    QCOMPARE(animation->loopCount(), 2);
+    QCOMPARE(animation->direction(), QAbstractAnimation::Backward);
+    QCOMPARE(animation->easingCurve().type(), QEasingCurve::OutCubic);
+  }
+
+  void read()
+  {
+    QVariantAnimation animation;
+    animation.setStartValue(10);
+    animation.setEndValue(20);
+    animation.setDuration(500);
+    animation.setEasingCurve(QEasingCurve::InQuad);
+    QVERIFY(read_qvariantanimation(animation));
+  }
+
+  void start()
+  {
+    // Animations are driven by the event loop
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QVariantAnimation animation;
+    animation.setStartValue(0);
+    animation.setEndValue(10);
+    animation.setDuration(10);
+    QSignalSpy finishedSpy(&animation, &QVariantAnimation::finished);
+
+    QVERIFY(start_qvariantanimation(animation));
+    QTRY_COMPARE(finishedSpy.count(), 1);
+    QCOMPARE(animation.state(), QAbstractAnimation::Stopped);
+    QCOMPARE(animation.currentValue().toInt(), 10);
+  }
+
+  void propertyAnimation()
+  {
+    QObject target;
+    QPropertyAnimation animation(&target, "opacity");
+    animation.setDuration(250);
+    QVERIFY(read_qpropertyanimation(animation));
+
+    modify_qpropertyanimation(animation);
+    QCOMPARE(animation.propertyName(), QByteArray("scale"));
+    QCOMPARE(animation.duration(), 750);
+  }
+};
@@ -6,6 +6,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <QtCore/QList>
 #include <QtCore/QVector>
 #include <QtTest/QTest>
 
@@ -48,4 +49,15 @@ private Q_SLOTS:
     QVERIFY(c.contains(3));
     QCOMPARE(c.size(), 4);
   }
+
+  void toQList()
+  {
+    auto v = QVector<::std::int32_t>();
+    v.append(1);
+    v.append(1);
+    v.append(3);
+    v.append(3);
+    const auto l = qlist_from_qvector_i32(v);
+    QCOMPARE(l, QList<::std::int32_t>({ 1, 1, 3, 3 }));
+  }
 };
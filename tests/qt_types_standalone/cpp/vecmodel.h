@@ -0,0 +1,118 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QAbstractItemModel>
+#include <QtTest/QAbstractItemModelTester>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/vecmodel.cxx.h"
+
+class VecModelTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  static QVariant fruitData(const QAbstractItemModel* model,
+                            int row,
+                            const QByteArray& role)
+  {
+    return model->data(model->index(row, 0), model->roleNames().key(role));
+  }
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto fruits = construct_fruit_model();
+    const QAbstractItemModel* model = fruits->model();
+    QVERIFY(model != nullptr);
+
+    QCOMPARE(model->rowCount(), 1);
+    QCOMPARE(model->roleNames().value(Qt::UserRole), QByteArray("name"));
+    QCOMPARE(model->roleNames().value(Qt::UserRole + 1), QByteArray("count"));
+    QCOMPARE(fruitData(model, 0, "name").toString(), QStringLiteral("apple"));
+    QCOMPARE(fruitData(model, 0, "count").toInt(), 1);
+    QVERIFY(!model->data(model->index(0, 0), Qt::DisplayRole).isValid());
+  }
+
+  void insert()
+  {
+    auto fruits = construct_fruit_model();
+    QAbstractItemModel* model = fruits->model();
+    QAbstractItemModelTester tester(model);
+    QSignalSpy spy(model, &QAbstractItemModel::rowsInserted);
+
+    fruits->insert(0, QStringLiteral("banana"), 2);
+    fruits->insert(2, QStringLiteral("cherry"), 3);
+
+    QCOMPARE(spy.count(), 2);
+    QCOMPARE(spy.at(0).at(1).toInt(), 0);
+    QCOMPARE(spy.at(1).at(1).toInt(), 2);
+    QCOMPARE(fruits->len(), ::std::size_t(3));
+    QCOMPARE(model->rowCount(), 3);
+    QCOMPARE(fruitData(model, 0, "name").toString(), QStringLiteral("banana"));
+    QCOMPARE(fruitData(model, 1, "name").toString(), QStringLiteral("apple"));
+    QCOMPARE(fruitData(model, 2, "count").toInt(), 3);
+  }
+
+  void remove()
+  {
+    auto fruits = construct_fruit_model();
+    QAbstractItemModel* model = fruits->model();
+    QAbstractItemModelTester tester(model);
+    fruits->insert(1, QStringLiteral("banana"), 2);
+    QSignalSpy spy(model, &QAbstractItemModel::rowsRemoved);
+
+    QCOMPARE(fruits->remove(0), QStringLiteral("apple"));
+
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(spy.at(0).at(1).toInt(), 0);
+    QCOMPARE(spy.at(0).at(2).toInt(), 0);
+    QCOMPARE(model->rowCount(), 1);
+    QCOMPARE(fruitData(model, 0, "name").toString(), QStringLiteral("banana"));
+  }
+
+  void set()
+  {
+    auto fruits = construct_fruit_model();
+    QAbstractItemModel* model = fruits->model();
+    QAbstractItemModelTester tester(model);
+    QSignalSpy spy(model, &QAbstractItemModel::dataChanged);
+
+    QCOMPARE(fruits->set(0, QStringLiteral("banana"), 2),
+             QStringLiteral("apple"));
+    fruits->update_count(0, 5);
+
+    QCOMPARE(spy.count(), 2);
+    QCOMPARE(spy.at(0).at(0).toModelIndex(), model->index(0, 0));
+    QCOMPARE(spy.at(0).at(1).toModelIndex(), model->index(0, 0));
+    QCOMPARE(model->rowCount(), 1);
+    QCOMPARE(fruitData(model, 0, "name").toString(), QStringLiteral("banana"));
+    QCOMPARE(fruitData(model, 0, "count").toInt(), 5);
+  }
+
+  void reset()
+  {
+    auto fruits = construct_fruit_model();
+    QAbstractItemModel* model = fruits->model();
+    QAbstractItemModelTester tester(model);
+    QSignalSpy aboutToBeReset(model, &QAbstractItemModel::modelAboutToBeReset);
+    QSignalSpy reset(model, &QAbstractItemModel::modelReset);
+
+    fruits->reset(QStringLiteral("banana,cherry"));
+
+    QCOMPARE(aboutToBeReset.count(), 1);
+    QCOMPARE(reset.count(), 1);
+    QCOMPARE(model->rowCount(), 2);
+    QCOMPARE(fruitData(model, 1, "name").toString(), QStringLiteral("cherry"));
+
+    fruits->reset(QString());
+    QCOMPARE(reset.count(), 2);
+    QCOMPARE(model->rowCount(), 0);
+  }
+};
@@ -5,15 +5,31 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx_qt_build::CxxQtBuilder;
 
+fn qt_version_major() -> u32 {
+    qt_build_utils::QtBuild::new(vec![])
+        .expect("Could not find Qt installation")
+        .version()
+        .major
+}
+
 fn main() {
-    CxxQtBuilder::new()
+    let mut builder = CxxQtBuilder::new()
+        .file("src/qaccessible.rs")
         .file("src/qbytearray.rs")
         .file("src/qcolor.rs")
+        .file("src/qcommandlineparser.rs")
         .file("src/qcoreapplication.rs")
+        .file("src/qcryptographichash.rs")
+        .file("src/qcursor.rs")
+        .file("src/qdatastream.rs")
         .file("src/qdate.rs")
         .file("src/qdatetime.rs")
+        .file("src/qfile.rs")
         .file("src/qguiapplication.rs")
         .file("src/qhash.rs")
+        .file("src/qicon.rs")
+        .file("src/qinputmethod.rs")
+        .file("src/qitemselectionmodel.rs")
         .file("src/qline.rs")
         .file("src/qlinef.rs")
         .file("src/qlist.rs")
@@ -21,30 +37,59 @@ fn main() {
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
         .file("src/qmetaobjectconnection.rs")
+        .file("src/qmetatype.rs")
+        .file("src/qmimedatabase.rs")
+        .file("src/qmlpromise.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qpalette.rs")
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
         .file("src/qpointf.rs")
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
+        .file("src/qprocess.rs")
         .file("src/qqmlapplicationengine.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquickitem.rs")
+        .file("src/qquickwindow.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qregion.rs")
+        .file("src/qsavefile.rs")
+        .file("src/qscreen.rs")
         .file("src/qset.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
+        .file("src/qstandarditemmodel.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
+        .file("src/qtenums.rs")
+        .file("src/qtextcharformat.rs")
+        .file("src/qthread.rs")
         .file("src/qtime.rs")
         .file("src/qtimezone.rs")
+        .file("src/qundostack.rs")
         .file("src/qurl.rs")
+        .file("src/qvalidator.rs")
         .file("src/qvariant.rs")
+        .file("src/qvariantanimation.rs")
         .file("src/qvector.rs")
         .file("src/qvector2d.rs")
         .file("src/qvector3d.rs")
         .file("src/qvector4d.rs")
-        .build();
+        .file("src/vecmodel.rs");
+
+    // QAction is only in QtGui and QPromise is only available in Qt 6,
+    // this needs to match the cxxqt_qt_version_major cfg of the modules
+    if qt_version_major() == 6 {
+        builder = builder.file("src/qaction.rs").file("src/qfuture.rs");
+    }
+
+    // The state machine framework is an optional module in Qt 6
+    if std::env::var("CARGO_FEATURE_QT_STATEMACHINE").is_ok() {
+        builder = builder.file("src/qstatemachine.rs");
+    }
+
+    builder.build();
 }
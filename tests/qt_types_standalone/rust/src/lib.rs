@@ -4,13 +4,26 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qaccessible;
+#[cfg(cxxqt_qt_version_major = "6")]
+mod qaction;
 mod qbytearray;
 mod qcolor;
+mod qcommandlineparser;
 mod qcoreapplication;
+mod qcryptographichash;
+mod qcursor;
+mod qdatastream;
 mod qdate;
 mod qdatetime;
+mod qfile;
+#[cfg(cxxqt_qt_version_major = "6")]
+mod qfuture;
 mod qguiapplication;
 mod qhash;
+mod qicon;
+mod qinputmethod;
+mod qitemselectionmodel;
 mod qline;
 mod qlinef;
 mod qlist;
@@ -18,28 +31,47 @@ mod qmap;
 mod qmargins;
 mod qmarginsf;
 mod qmetaobjectconnection;
+mod qmetatype;
+mod qmimedatabase;
+mod qmlpromise;
 mod qmodelindex;
+mod qpalette;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
 mod qpointf;
 mod qpolygon;
 mod qpolygonf;
+mod qprocess;
 mod qqmlapplicationengine;
 mod qqmlengine;
+mod qquickitem;
+mod qquickwindow;
 mod qrect;
 mod qrectf;
 mod qregion;
+mod qsavefile;
+mod qscreen;
 mod qset;
 mod qsize;
 mod qsizef;
+mod qstandarditemmodel;
+#[cfg(feature = "qt_statemachine")]
+mod qstatemachine;
 mod qstring;
 mod qstringlist;
+mod qtenums;
+mod qtextcharformat;
+mod qthread;
 mod qtime;
 mod qtimezone;
+mod qundostack;
 mod qurl;
+mod qvalidator;
 mod qvariant;
+mod qvariantanimation;
 mod qvector;
 mod qvector2d;
 mod qvector3d;
 mod qvector4d;
+mod vecmodel;
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QStringList};
+use cxx_qt_lib_extras::{AccessibleItem, QAccessible, QAccessibleRole};
+use std::{cell::Cell, rc::Rc};
+
+// QAccessible can only be installed on QObjects defined in Rust
+#[cxx_qt::bridge]
+mod qaccessible_cxx {
+    extern "RustQt" {
+        #[qobject]
+        type AccessibleCounter = super::AccessibleCounterRust;
+
+        #[qinvokable]
+        fn install(self: &AccessibleCounter);
+
+        #[qinvokable]
+        fn count(self: &AccessibleCounter) -> i32;
+    }
+}
+
+#[derive(Default)]
+pub struct AccessibleCounterRust {
+    count: Rc<Cell<i32>>,
+}
+
+impl qaccessible_cxx::AccessibleCounter {
+    fn install(&self) {
+        QAccessible::install(
+            self,
+            CounterItem {
+                count: self.count.clone(),
+            },
+        );
+    }
+
+    fn count(&self) -> i32 {
+        self.count.get()
+    }
+}
+
+struct CounterItem {
+    count: Rc<Cell<i32>>,
+}
+
+impl AccessibleItem for CounterItem {
+    fn role(&self) -> QAccessibleRole {
+        QAccessibleRole::SpinBox
+    }
+
+    fn name(&self) -> QString {
+        QString::from("Counter")
+    }
+
+    fn value(&self) -> QString {
+        QString::from(&self.count.get().to_string())
+    }
+
+    fn action_names(&self) -> QStringList {
+        QStringList::from(&QAccessible::increase_action())
+    }
+
+    fn do_action(&mut self, name: &QString) {
+        if *name == QAccessible::increase_action() {
+            self.count.set(self.count.get() + 1);
+        }
+    }
+}
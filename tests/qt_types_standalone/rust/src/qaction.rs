@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QAction, QString, QVariant};
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+
+#[cxx::bridge]
+mod qaction_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qaction.h");
+        type QAction = cxx_qt_lib::QAction;
+    }
+
+    extern "Rust" {
+        fn construct_qaction() -> UniquePtr<QAction>;
+        fn read_qaction(action: &QAction) -> bool;
+        fn trigger_qaction(action: Pin<&mut QAction>) -> i32;
+    }
+}
+
+fn construct_qaction() -> UniquePtr<QAction> {
+    let mut action = QAction::new(&QString::from("Save"));
+    if let Some(mut action) = action.as_mut() {
+        action.as_mut().set_shortcut(&QString::from("Ctrl+S"));
+        action
+            .as_mut()
+            .set_tool_tip(&QString::from("Save the file"));
+        action.as_mut().set_checkable(true);
+        action.as_mut().set_checked(true);
+        action.as_mut().set_data(&QVariant::from(&42_i32));
+    }
+    action
+}
+
+fn read_qaction(action: &QAction) -> bool {
+    action.text() == QString::from("Open")
+        && action.shortcut() == QString::from("Ctrl+O")
+        // The tool tip defaults to the text
+        && action.tool_tip() == QString::from("Open")
+        && !action.is_enabled()
+        && action.is_visible()
+        && action.data().value::<i32>() == Some(7)
+}
+
+fn trigger_qaction(mut action: Pin<&mut QAction>) -> i32 {
+    // The closure of a connection has to be Send
+    let triggered = Arc::new(AtomicI32::new(0));
+    let _connection = action.as_mut().on_triggered({
+        let triggered = triggered.clone();
+        move |_, checked| {
+            if checked {
+                triggered.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    });
+
+    action.as_mut().set_checkable(true);
+    // Triggering a checkable action toggles it
+    action.as_mut().trigger();
+    action.as_mut().trigger();
+    action.as_mut().trigger();
+    triggered.load(Ordering::Relaxed)
+}
@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QList, QString, QStringList};
+use cxx_qt_lib_extras::{QCommandLineOption, QCommandLineParser};
+
+#[cxx::bridge]
+mod qcommandlineparser_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qcommandlineoption.h");
+        type QCommandLineOption = cxx_qt_lib_extras::QCommandLineOption;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    extern "Rust" {
+        fn construct_qcommandlineoption() -> QCommandLineOption;
+        fn read_qcommandlineoption(o: &QCommandLineOption) -> bool;
+        fn clone_qcommandlineoption(o: &QCommandLineOption) -> QCommandLineOption;
+        fn parse_qcommandlineparser(arguments: &QStringList) -> QStringList;
+        fn error_qcommandlineparser(arguments: &QStringList) -> QString;
+    }
+}
+
+fn string_list(values: &[&str]) -> QStringList {
+    let mut list = QList::<QString>::default();
+    for value in values {
+        list.append(QString::from(*value));
+    }
+    QStringList::from(&list)
+}
+
+fn construct_qcommandlineoption() -> QCommandLineOption {
+    QCommandLineOption::new(
+        &string_list(&["o", "output"]),
+        &QString::from("Output file"),
+        &QString::from("file"),
+        &QString::from("out.txt"),
+    )
+}
+
+fn read_qcommandlineoption(o: &QCommandLineOption) -> bool {
+    o.names() == string_list(&["o", "output"])
+        && o.description() == QString::from("Output file")
+        && o.value_name() == QString::from("file")
+        && o.default_values() == string_list(&["out.txt"])
+}
+
+fn clone_qcommandlineoption(o: &QCommandLineOption) -> QCommandLineOption {
+    o.clone()
+}
+
+fn parser() -> QCommandLineParser {
+    let mut parser = QCommandLineParser::default();
+    parser.set_application_description(&QString::from("Test application"));
+    assert!(parser.add_option(&QCommandLineOption::from(&QString::from("verbose"))));
+    assert!(parser.add_option(&construct_qcommandlineoption()));
+    parser.add_positional_argument(
+        &QString::from("input"),
+        &QString::from("Input file"),
+        &QString::default(),
+    );
+    parser
+}
+
+fn parse_qcommandlineparser(arguments: &QStringList) -> QStringList {
+    let mut parser = parser();
+    assert!(parser.parse(arguments));
+    assert!(parser.error_text().is_empty());
+    assert!(parser.is_set(&QString::from("verbose")));
+
+    // Return the output file followed by the positional arguments
+    let mut values = QList::<QString>::default();
+    values.append(parser.value(&QString::from("output")));
+    for argument in QList::<QString>::from(&parser.positional_arguments()).iter() {
+        values.append_clone(argument);
+    }
+    QStringList::from(&values)
+}
+
+fn error_qcommandlineparser(arguments: &QStringList) -> QString {
+    let mut parser = parser();
+    assert!(!parser.parse(arguments));
+    parser.error_text()
+}
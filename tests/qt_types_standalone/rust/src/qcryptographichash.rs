@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    QByteArray, QCryptographicHash, QCryptographicHashAlgorithm, QMessageAuthenticationCode,
+};
+
+#[cxx::bridge]
+mod qcryptographichash_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+    }
+
+    extern "Rust" {
+        fn hash_qcryptographichash(data: &QByteArray) -> QByteArray;
+        fn hash_length_qcryptographichash() -> i32;
+        fn hash_qmessageauthenticationcode(message: &QByteArray, key: &QByteArray) -> QByteArray;
+    }
+}
+
+fn hash_qcryptographichash(data: &QByteArray) -> QByteArray {
+    let mut hash = QCryptographicHash::new(QCryptographicHashAlgorithm::Sha256);
+    hash.pin_mut().add_data(&QByteArray::from("unused"));
+    hash.pin_mut().reset();
+    hash.pin_mut().add_data(data);
+
+    // Adding the data incrementally gives the same result as hashing it at once
+    let result = hash.result();
+    assert!(result == QCryptographicHash::hash(data, QCryptographicHashAlgorithm::Sha256));
+    result
+}
+
+fn hash_length_qcryptographichash() -> i32 {
+    QCryptographicHash::hash_length(QCryptographicHashAlgorithm::Sha256)
+}
+
+fn hash_qmessageauthenticationcode(message: &QByteArray, key: &QByteArray) -> QByteArray {
+    let mut code = QMessageAuthenticationCode::new(
+        QCryptographicHashAlgorithm::Sha256,
+        &QByteArray::from("unused"),
+    );
+    // Setting the key resets the data which has been added
+    code.pin_mut().add_data(&QByteArray::from("unused"));
+    code.pin_mut().set_key(key);
+    code.pin_mut().add_data(message);
+
+    let result = code.result();
+    assert!(
+        result
+            == QMessageAuthenticationCode::hash(message, key, QCryptographicHashAlgorithm::Sha256)
+    );
+    result
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{CursorShape, QCursor, QGuiApplication};
+
+#[cxx::bridge]
+mod qcursor_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcursor.h");
+        type QCursor = cxx_qt_lib::QCursor;
+        include!("cxx-qt-lib/qguiapplication.h");
+        type QGuiApplication = cxx_qt_lib::QGuiApplication;
+    }
+
+    extern "Rust" {
+        fn construct_qcursor() -> QCursor;
+        fn read_qcursor(c: &QCursor) -> bool;
+        fn clone_qcursor(c: &QCursor) -> QCursor;
+        fn override_qcursor(app: Pin<&mut QGuiApplication>) -> bool;
+    }
+}
+
+fn construct_qcursor() -> QCursor {
+    let mut cursor = QCursor::from(&CursorShape::WaitCursor);
+    cursor.set_shape(CursorShape::PointingHandCursor);
+    cursor
+}
+
+fn read_qcursor(c: &QCursor) -> bool {
+    c.shape() == CursorShape::OpenHandCursor
+}
+
+fn clone_qcursor(c: &QCursor) -> QCursor {
+    c.clone()
+}
+
+fn override_qcursor(mut app: Pin<&mut QGuiApplication>) -> bool {
+    let override_shape = |app: &QGuiApplication| app.override_cursor().map(|cursor| cursor.shape());
+    if override_shape(&app).is_some() {
+        return false;
+    }
+
+    app.as_mut()
+        .set_override_cursor(&QCursor::from(&CursorShape::WaitCursor));
+    let set = override_shape(&app) == Some(CursorShape::WaitCursor);
+
+    app.as_mut()
+        .change_override_cursor(&QCursor::from(&CursorShape::BusyCursor));
+    let changed = override_shape(&app) == Some(CursorShape::BusyCursor);
+
+    app.as_mut().restore_override_cursor();
+    set && changed && override_shape(&app).is_none()
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QString};
+use cxx_qt_lib_extras::{QDataStream, QDataStreamByteOrder, QDataStreamStatus, QDataStreamWriter};
+
+#[cxx::bridge]
+mod qdatastream_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+    }
+
+    extern "Rust" {
+        fn write_qdatastream() -> QByteArray;
+        fn write_qdatastream_little_endian() -> QByteArray;
+        fn read_qdatastream(data: &QByteArray) -> bool;
+    }
+}
+
+fn write_qdatastream() -> QByteArray {
+    let mut writer = QDataStreamWriter::new();
+    writer.stream().write(&42_i32);
+    writer.stream().write(&QString::from("KDAB"));
+    writer.stream().write(&true);
+    writer.stream().write(&1.5_f64);
+    writer.into_bytes()
+}
+
+fn write_qdatastream_little_endian() -> QByteArray {
+    let mut writer = QDataStreamWriter::new();
+    writer
+        .stream()
+        .set_byte_order(QDataStreamByteOrder::LittleEndian);
+    writer.stream().write(&1_u32);
+    writer.into_bytes()
+}
+
+fn read_qdatastream(data: &QByteArray) -> bool {
+    let mut stream = QDataStream::from_bytes(data);
+    let number: u64 = stream.pin_mut().read();
+    let bytes: QByteArray = stream.pin_mut().read();
+    if number != 42 || bytes != QByteArray::from("KDAB") || !stream.at_end() {
+        return false;
+    }
+
+    // Reading past the end gives a default value and reports the error
+    let missing: i32 = stream.pin_mut().read();
+    missing == 0 && stream.status() == QDataStreamStatus::ReadPastEnd
+}
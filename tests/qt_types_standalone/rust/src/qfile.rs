@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QString};
+use cxx_qt_lib_extras::{QFile, QIODeviceOpenModeFlag};
+
+#[cxx::bridge]
+mod qfile_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn write_qfile(path: &QString, data: &QByteArray) -> i64;
+        fn read_qfile(path: &QString) -> QByteArray;
+        fn read_qfile_error(path: &QString) -> QString;
+        fn remove_qfile(path: &QString) -> bool;
+    }
+}
+
+fn write_qfile(path: &QString, data: &QByteArray) -> i64 {
+    let mut file = QFile::new(path);
+    if !file
+        .pin_mut()
+        .open(QIODeviceOpenModeFlag::WriteOnly | QIODeviceOpenModeFlag::Truncate)
+    {
+        return -1;
+    }
+    let written = file.pin_mut().write(data);
+    file.pin_mut().close();
+    if written == file.size() {
+        written
+    } else {
+        -1
+    }
+}
+
+fn read_qfile(path: &QString) -> QByteArray {
+    QFile::read(path).unwrap_or_default()
+}
+
+fn read_qfile_error(path: &QString) -> QString {
+    QFile::read(path).err().unwrap_or_default()
+}
+
+fn remove_qfile(path: &QString) -> bool {
+    let mut file = QFile::new(path);
+    file.exists() && file.pin_mut().remove() && !file.exists()
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Wake, Waker},
+};
+use cxx_qt_lib::QVariant;
+use cxx_qt_lib_extras::{QFuture, QPromise};
+use std::{
+    sync::Arc,
+    thread::{self, Thread},
+};
+
+#[cxx::bridge]
+mod qfuture_cxx {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qfuture.h");
+        #[cxx_name = "QFuture_QVariant"]
+        type QFuture = cxx_qt_lib_extras::QFuture;
+    }
+
+    extern "Rust" {
+        fn construct_qfuture_finished() -> UniquePtr<QFuture>;
+        fn read_qfuture(f: &QFuture) -> i32;
+        fn await_qpromise_from_thread() -> i32;
+    }
+}
+
+/// Wakes the thread which is blocked on a future
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn construct_qfuture_finished() -> cxx::UniquePtr<QFuture> {
+    let mut promise = QPromise::new();
+    let future = promise.future();
+    promise
+        .pin_mut()
+        .finish_with_result(&QVariant::from(&42_i32));
+    future
+}
+
+fn read_qfuture(future: &QFuture) -> i32 {
+    future
+        .result()
+        .and_then(|result| result.value::<i32>())
+        .unwrap_or(-1)
+}
+
+fn await_qpromise_from_thread() -> i32 {
+    let mut promise = QPromise::new();
+    let result = promise.future().to_future();
+    let thread = thread::spawn(move || {
+        promise
+            .pin_mut()
+            .finish_with_result(&QVariant::from(&7_i32));
+    });
+
+    let value = block_on(result)
+        .and_then(|result| result.value::<i32>())
+        .unwrap_or(-1);
+    thread.join().unwrap();
+    value
+}
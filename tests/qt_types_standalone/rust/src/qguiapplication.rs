@@ -3,7 +3,8 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QGuiApplication, QString};
+use core::pin::Pin;
+use cxx_qt_lib::{QColor, QGuiApplication, QPalette, QString};
 
 #[cxx::bridge]
 mod qguiapplication_cxx {
@@ -15,6 +16,9 @@ mod qguiapplication_cxx {
     extern "Rust" {
         fn construct_qguiapplication() -> UniquePtr<QGuiApplication>;
         fn read_qguiapplication(c: &QGuiApplication) -> bool;
+        fn set_qguiapplication_palette(app: Pin<&mut QGuiApplication>) -> bool;
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+        fn read_qguiapplication_color_scheme(app: &QGuiApplication) -> i32;
     }
 }
 
@@ -29,3 +33,14 @@ fn construct_qguiapplication() -> cxx::UniquePtr<QGuiApplication> {
 fn read_qguiapplication(app: &QGuiApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
+
+fn set_qguiapplication_palette(mut app: Pin<&mut QGuiApplication>) -> bool {
+    let palette = QPalette::from(&QColor::from_rgb(255, 0, 0));
+    app.as_mut().set_palette(&palette);
+    app.palette() == palette
+}
+
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+fn read_qguiapplication_color_scheme(app: &QGuiApplication) -> i32 {
+    app.color_scheme().repr
+}
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QColor, QIcon, QIconMode, QIconState, QImage, QSize, QString, QVariant};
+
+#[cxx::bridge]
+mod qicon_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qicon.h");
+        type QIcon = cxx_qt_lib::QIcon;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = cxx_qt_lib::QImage;
+    }
+
+    extern "Rust" {
+        fn construct_qicon(image: &QImage) -> QIcon;
+        fn read_qicon(icon: &QIcon) -> bool;
+        fn clone_qicon(icon: &QIcon) -> QIcon;
+        fn qicon_theme() -> bool;
+    }
+}
+
+fn construct_qicon(image: &QImage) -> QIcon {
+    QIcon::from(image)
+}
+
+fn read_qicon(icon: &QIcon) -> bool {
+    let image = icon.to_image(&QSize::new(16, 16), QIconMode::Normal, QIconState::Off);
+    let variant = QVariant::from(icon);
+    !icon.is_null()
+        && image.size() == QSize::new(16, 16)
+        && image.pixel_color(0, 0) == QColor::from_rgb(255, 0, 0)
+        && variant.value::<QIcon>().map(|icon| icon.cache_key()) == Some(icon.cache_key())
+}
+
+fn clone_qicon(icon: &QIcon) -> QIcon {
+    icon.clone()
+}
+
+fn qicon_theme() -> bool {
+    QIcon::set_theme_name(&QString::from("kdab"));
+    let missing = QString::from("cxx-qt-missing-icon");
+    QIcon::theme_name() == QString::from("kdab")
+        && !QIcon::has_theme_icon(&missing)
+        && QIcon::from_theme(&missing).is_none()
+}
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib_extras::QInputMethod;
+
+#[cxx::bridge]
+mod qinputmethod_cxx {
+    extern "Rust" {
+        fn has_qinputmethod() -> bool;
+        fn read_qinputmethod() -> bool;
+    }
+}
+
+fn has_qinputmethod() -> bool {
+    !QInputMethod::instance().is_null()
+}
+
+fn read_qinputmethod() -> bool {
+    // Safety: the input method is owned by the QGuiApplication, which is alive
+    if let Some(input_method) = unsafe { QInputMethod::instance().as_ref() } {
+        // There is no virtual keyboard without a focused input item
+        !input_method.is_visible()
+            && !input_method.is_animating()
+            && input_method.keyboard_rectangle().is_empty()
+    } else {
+        false
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::QModelIndex;
+use cxx_qt_lib_extras::{QItemSelectionModel, QItemSelectionModelSelectionFlag};
+
+#[cxx::bridge]
+mod qitemselectionmodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+
+        include!("cxx-qt-lib-extras/qitemselectionmodel.h");
+        type QItemSelectionModel = cxx_qt_lib_extras::QItemSelectionModel;
+    }
+
+    extern "Rust" {
+        fn select_qitemselectionmodel_row(m: Pin<&mut QItemSelectionModel>, index: &QModelIndex);
+        fn read_qitemselectionmodel(m: &QItemSelectionModel, index: &QModelIndex) -> bool;
+    }
+}
+
+fn select_qitemselectionmodel_row(
+    mut selection_model: Pin<&mut QItemSelectionModel>,
+    index: &QModelIndex,
+) {
+    selection_model.as_mut().select(
+        index,
+        QItemSelectionModelSelectionFlag::ClearAndSelect | QItemSelectionModelSelectionFlag::Rows,
+    );
+    selection_model
+        .as_mut()
+        .set_current_index(index, QItemSelectionModelSelectionFlag::NoUpdate);
+}
+
+fn read_qitemselectionmodel(selection_model: &QItemSelectionModel, index: &QModelIndex) -> bool {
+    let selected = selection_model.selected_indexes();
+    selection_model.has_selection()
+        && selection_model.is_selected(index)
+        && selection_model.is_row_selected(index.row(), &QModelIndex::default())
+        && selection_model.current_index() == *index
+        && selected.len() == 1
+        && selected.get(0).map(|selected| selected.row()) == Some(index.row())
+}
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::QList;
+use cxx_qt_lib::{QList, QVector};
 
 #[cxx::bridge]
 mod qlist_cxx {
@@ -32,7 +32,10 @@ fn read_qlist_i32(v: &QList<i32>) -> bool {
     // Ensure that the iterator works by building a vector from it
     let vec = v.iter().cloned().collect::<Vec<i32>>();
 
-    vec == vec![1, 1, 3, 3]
+    // Ensure that converting to a QVector and back keeps the values
+    let qvector = QVector::from(v);
+
+    vec == vec![1, 1, 3, 3] && QList::from(&qvector) == *v
 }
 
 fn clone_qlist_i32(v: &QList<i32>) -> QList<i32> {
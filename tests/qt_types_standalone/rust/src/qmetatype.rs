@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QMetaType, QVariant};
+
+// The pointer types of QObjects defined in Rust are registered automatically
+#[cxx_qt::bridge]
+mod qmetatype_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        type MetaTypeObject = super::MetaTypeObjectRust;
+    }
+
+    extern "Rust" {
+        fn qmetatype_id_from_name(name: &QByteArray) -> i32;
+        fn qmetatype_id_from_variant(variant: &QVariant) -> i32;
+        fn qmetatype_name(id: i32) -> QByteArray;
+        fn qmetatype_register_typedef(name: &QByteArray, id: i32) -> bool;
+        fn qmetatype_is_qobject_registered() -> bool;
+    }
+}
+
+#[derive(Default)]
+pub struct MetaTypeObjectRust;
+
+fn qmetatype_id_from_name(name: &QByteArray) -> i32 {
+    QMetaType::from_name(name)
+        .map(|meta_type| meta_type.id())
+        .unwrap_or_default()
+}
+
+fn qmetatype_id_from_variant(variant: &QVariant) -> i32 {
+    QMetaType::from_variant(variant).id()
+}
+
+fn qmetatype_name(id: i32) -> QByteArray {
+    QMetaType::from_id(id).name()
+}
+
+fn qmetatype_register_typedef(name: &QByteArray, id: i32) -> bool {
+    let meta_type = QMetaType::from_id(id);
+    meta_type.register_typedef(name);
+    QMetaType::from_name(name) == Some(meta_type)
+}
+
+fn qmetatype_is_qobject_registered() -> bool {
+    QMetaType::from_name(&QByteArray::from("MetaTypeObject*"))
+        .is_some_and(|meta_type| meta_type.is_valid() && meta_type.is_registered())
+}
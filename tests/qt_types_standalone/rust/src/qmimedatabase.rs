@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    CaseSensitivity, QByteArray, QMimeDatabase, QMimeDatabaseMatchMode, QMimeType, QString,
+};
+
+#[cxx::bridge]
+mod qmimedatabase_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qmimetype.h");
+        type QMimeType = cxx_qt_lib::QMimeType;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qmimetype() -> QMimeType;
+        fn read_qmimetype(m: &QMimeType) -> bool;
+        fn clone_qmimetype(m: &QMimeType) -> QMimeType;
+        fn qmimedatabase_name_for_file(file_name: &QString) -> QString;
+        fn qmimedatabase_name_for_data(data: &QByteArray) -> QString;
+        fn qmimedatabase_suffix(file_name: &QString) -> QString;
+    }
+}
+
+fn construct_qmimetype() -> QMimeType {
+    QMimeDatabase::default().mime_type_for_name(&QString::from("text/x-csrc"))
+}
+
+fn read_qmimetype(m: &QMimeType) -> bool {
+    m.is_valid()
+        && !m.is_default()
+        && m.name() == QString::from("text/plain")
+        && m.preferred_suffix() == QString::from("txt")
+        && m.suffixes()
+            .contains(&QString::from("txt"), CaseSensitivity::CaseSensitive)
+}
+
+fn clone_qmimetype(m: &QMimeType) -> QMimeType {
+    let c = m.clone();
+    assert!(c == *m);
+    c
+}
+
+fn qmimedatabase_name_for_file(file_name: &QString) -> QString {
+    // The file doesn't exist, so only match the extension
+    QMimeDatabase::default()
+        .mime_type_for_file(file_name, QMimeDatabaseMatchMode::MatchExtension)
+        .name()
+}
+
+fn qmimedatabase_name_for_data(data: &QByteArray) -> QString {
+    QMimeDatabase::default().mime_type_for_data(data).name()
+}
+
+fn qmimedatabase_suffix(file_name: &QString) -> QString {
+    QMimeDatabase::default().suffix_for_file_name(file_name)
+}
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QVariant, QmlPromise};
+
+#[cxx::bridge]
+mod qmlpromise_cxx {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmlpromise.h");
+        type QmlPromise = cxx_qt_lib::QmlPromise;
+    }
+
+    extern "Rust" {
+        fn construct_qmlpromise_resolved(value: i32) -> *mut QmlPromise;
+        fn construct_qmlpromise_rejected(reason: &str) -> *mut QmlPromise;
+        fn construct_qmlpromise_dropped() -> *mut QmlPromise;
+        fn construct_qmlpromise_resolved_from_thread(value: i32) -> *mut QmlPromise;
+    }
+}
+
+fn construct_qmlpromise_resolved(value: i32) -> *mut QmlPromise {
+    let (promise, resolver) = QmlPromise::new();
+    resolver.resolve(&QVariant::from(&value));
+    promise
+}
+
+fn construct_qmlpromise_rejected(reason: &str) -> *mut QmlPromise {
+    let (promise, resolver) = QmlPromise::new();
+    resolver.reject(&QVariant::from(&QString::from(reason)));
+    promise
+}
+
+fn construct_qmlpromise_dropped() -> *mut QmlPromise {
+    let (promise, resolver) = QmlPromise::new();
+    drop(resolver);
+    promise
+}
+
+fn construct_qmlpromise_resolved_from_thread(value: i32) -> *mut QmlPromise {
+    let (promise, resolver) = QmlPromise::new();
+    std::thread::spawn(move || resolver.resolve(&QVariant::from(&value)))
+        .join()
+        .unwrap();
+    promise
+}
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QColor, QPalette, QPaletteColorGroup, QPaletteColorRole};
+
+#[cxx::bridge]
+mod qpalette_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpalette.h");
+        type QPalette = cxx_qt_lib::QPalette;
+    }
+
+    extern "Rust" {
+        fn construct_qpalette() -> QPalette;
+        fn read_qpalette(p: &QPalette) -> bool;
+        fn clone_qpalette(p: &QPalette) -> QPalette;
+    }
+}
+
+fn construct_qpalette() -> QPalette {
+    let mut palette = QPalette::from(&QColor::from_rgb(255, 0, 0));
+    palette.set_color(
+        QPaletteColorGroup::Active,
+        QPaletteColorRole::WindowText,
+        &QColor::from_rgb(0, 0, 255),
+    );
+    palette
+}
+
+fn read_qpalette(p: &QPalette) -> bool {
+    p.current_color_group() == QPaletteColorGroup::Active
+        && *p.current_color(QPaletteColorRole::Button) == QColor::from_rgb(0, 255, 0)
+        && *p.color(QPaletteColorGroup::Disabled, QPaletteColorRole::Text)
+            == QColor::from_rgb(0, 0, 255)
+        && !p.is_equal(QPaletteColorGroup::Active, QPaletteColorGroup::Disabled)
+}
+
+fn clone_qpalette(p: &QPalette) -> QPalette {
+    let c = p.clone();
+    assert!(c.is_copy_of(p));
+    c
+}
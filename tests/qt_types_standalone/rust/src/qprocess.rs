@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QStringList};
+use cxx_qt_lib_extras::{QProcess, QProcessProcessError, QProcessProcessState};
+
+#[cxx::bridge]
+mod qprocess_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qprocess.h");
+        type QProcess = cxx_qt_lib_extras::QProcess;
+    }
+
+    extern "Rust" {
+        fn start_missing_qprocess() -> UniquePtr<QProcess>;
+        fn read_qprocess_failed_to_start(p: &QProcess) -> bool;
+    }
+}
+
+fn start_missing_qprocess() -> cxx::UniquePtr<QProcess> {
+    let mut process = QProcess::new();
+    if let Some(mut process) = process.as_mut() {
+        process
+            .as_mut()
+            .set_working_directory(&QString::from("/kdab"));
+        process.as_mut().start(
+            &QString::from("cxx-qt-missing-program"),
+            &QStringList::from(&QString::from("--version")),
+        );
+        process.as_mut().wait_for_started(1000);
+    }
+    process
+}
+
+fn read_qprocess_failed_to_start(process: &QProcess) -> bool {
+    process.error() == QProcessProcessError::FailedToStart
+        && process.state() == QProcessProcessState::NotRunning
+        && process.program().to_string() == "cxx-qt-missing-program"
+        && process.working_directory().to_string() == "/kdab"
+}
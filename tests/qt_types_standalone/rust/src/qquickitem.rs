@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This crate doesn't link QtQuick itself, so the QtQuick headers are only found
+// when the qt_quick feature of cxx-qt-lib exports the module to its dependents
+#[cxx::bridge]
+mod qquickitem_cxx {
+    unsafe extern "C++" {
+        include!(<QtQuick/QQuickItem>);
+        type QQuickItem;
+
+        fn width(self: &QQuickItem) -> f64;
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+    }
+
+    extern "Rust" {
+        fn double_qquickitem_width(item: Pin<&mut QQuickItem>);
+    }
+}
+
+use core::pin::Pin;
+
+fn double_qquickitem_width(item: Pin<&mut qquickitem_cxx::QQuickItem>) {
+    let width = item.width();
+    item.set_width(width * 2.0);
+}
@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib_extras::QQuickWindow;
+
+#[cxx::bridge]
+mod qquickwindow_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qquickwindow.h");
+        type QQuickWindow = cxx_qt_lib_extras::QQuickWindow;
+    }
+
+    extern "Rust" {
+        fn read_qquickwindow(window: &QQuickWindow) -> bool;
+    }
+}
+
+fn read_qquickwindow(window: &QQuickWindow) -> bool {
+    window.x() == 10
+        && window.y() == 20
+        && window.width() == 200
+        && window.height() == 100
+        && !window.is_visible()
+        && !window.is_exposed()
+        && !window.screen().is_null()
+}
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QString};
+use cxx_qt_lib_extras::{QIODeviceOpenModeFlag, QSaveFile, QTemporaryFile};
+
+#[cxx::bridge]
+mod qsavefile_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn write_all_qsavefile(path: &QString, data: &QByteArray) -> QString;
+        fn cancel_qsavefile(path: &QString, data: &QByteArray) -> bool;
+        fn roundtrip_qtemporaryfile(data: &QByteArray) -> QByteArray;
+        fn qtemporaryfile_name(template_name: &QString) -> QString;
+    }
+}
+
+fn write_all_qsavefile(path: &QString, data: &QByteArray) -> QString {
+    QSaveFile::write_all(path, data).err().unwrap_or_default()
+}
+
+fn cancel_qsavefile(path: &QString, data: &QByteArray) -> bool {
+    let mut file = QSaveFile::new(path);
+    let mut file = file.pin_mut();
+    if !file.as_mut().open(QIODeviceOpenModeFlag::WriteOnly) {
+        return false;
+    }
+    file.as_mut().write(data);
+    file.as_mut().cancel_writing();
+    // The target file is unchanged once writing has been cancelled
+    !file.commit()
+}
+
+fn roundtrip_qtemporaryfile(data: &QByteArray) -> QByteArray {
+    let mut file = QTemporaryFile::new();
+    let mut file = file.pin_mut();
+    if !file.as_mut().open()
+        || file.as_mut().write(data) != data.len() as i64
+        || file.size() != data.len() as i64
+        || !file.as_mut().seek(0)
+    {
+        return QByteArray::default();
+    }
+    file.read_all()
+}
+
+fn qtemporaryfile_name(template_name: &QString) -> QString {
+    let mut file = QTemporaryFile::new_with_template(template_name);
+    if !file.auto_remove() || !file.pin_mut().open() {
+        return QString::default();
+    }
+    // The file is removed once it is dropped
+    file.file_name()
+}
@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QString;
+use cxx_qt_lib_extras::QScreen;
+
+#[cxx::bridge]
+mod qscreen_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn read_qscreens() -> bool;
+        fn primary_qscreen_name() -> QString;
+    }
+}
+
+fn read_qscreens() -> bool {
+    let screens = QScreen::screens();
+    let primary = QScreen::primary_screen();
+    if screens.first() != Some(&primary) {
+        return false;
+    }
+
+    screens.iter().all(|screen| {
+        // Safety: the screens are owned by the QGuiApplication, which is alive
+        let screen = unsafe { &**screen };
+        screen.geometry().size() == screen.size()
+    })
+}
+
+fn primary_qscreen_name() -> QString {
+    // Safety: the screens are owned by the QGuiApplication, which is alive
+    unsafe { QScreen::primary_screen().as_ref() }
+        .map(QScreen::name)
+        .unwrap_or_default()
+}
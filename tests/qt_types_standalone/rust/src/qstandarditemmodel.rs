@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QModelIndex, QString, QStringList};
+use cxx_qt_lib_extras::{QStandardItem, QStandardItemModel};
+
+#[cxx::bridge]
+mod qstandarditemmodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qstandarditemmodel.h");
+        type QStandardItemModel = cxx_qt_lib_extras::QStandardItemModel;
+    }
+
+    extern "Rust" {
+        fn construct_qstandarditemmodel() -> UniquePtr<QStandardItemModel>;
+        fn read_qstandarditemmodel(m: &QStandardItemModel) -> bool;
+    }
+}
+
+fn construct_qstandarditemmodel() -> cxx::UniquePtr<QStandardItemModel> {
+    let mut model = QStandardItemModel::new();
+    if let Some(mut model) = model.as_mut() {
+        model
+            .as_mut()
+            .set_horizontal_header_labels(&QStringList::from(&QString::from("Fruit")));
+
+        let mut apple = QStandardItem::new_with_text(&QString::from("apple"));
+        if let Some(apple) = apple.as_mut() {
+            apple.append_row(QStandardItem::new_with_text(&QString::from("seed")));
+        }
+        model.as_mut().append_row(apple);
+
+        let mut banana = QStandardItem::new();
+        if let Some(mut banana) = banana.as_mut() {
+            banana.as_mut().set_text(&QString::from("banana"));
+            banana.as_mut().set_editable(false);
+        }
+        model.as_mut().append_row(banana);
+    }
+    model
+}
+
+fn read_qstandarditemmodel(model: &QStandardItemModel) -> bool {
+    if model.row_count(&QModelIndex::default()) != 2
+        || model.column_count(&QModelIndex::default()) != 1
+    {
+        return false;
+    }
+
+    // Safety: the items are owned by the model, which outlives this function
+    let (apple, banana) = unsafe { (model.item(0, 0).as_ref(), model.item(1, 0).as_ref()) };
+    match (apple, banana) {
+        (Some(apple), Some(banana)) => {
+            apple.text().to_string() == "apple"
+                && apple.has_children()
+                && apple.row_count() == 1
+                && banana.text().to_string() == "banana"
+                && !banana.is_editable()
+                && banana.is_enabled()
+                && unsafe { model.item(2, 0).as_ref() }.is_none()
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::QString;
+use cxx_qt_lib_extras::{QState, QStateMachine};
+use std::{cell::Cell, rc::Rc};
+
+#[cxx::bridge]
+mod qstatemachine_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        type TrafficLight;
+
+        fn construct_traffic_light() -> Box<TrafficLight>;
+        fn start(self: &mut TrafficLight);
+        fn post_event(self: &mut TrafficLight, event: &QString);
+        fn is_running(self: &TrafficLight) -> bool;
+        fn is_red(self: &TrafficLight) -> bool;
+        fn is_green(self: &TrafficLight) -> bool;
+        fn transitions(self: &TrafficLight) -> i32;
+    }
+}
+
+struct TrafficLight {
+    machine: UniquePtr<QStateMachine>,
+    red: *mut QState,
+    green: *mut QState,
+    transitions: Rc<Cell<i32>>,
+}
+
+fn construct_traffic_light() -> Box<TrafficLight> {
+    let mut machine = QStateMachine::new();
+    let transitions = Rc::new(Cell::new(0));
+
+    let mut root = machine.pin_mut().as_state_mut();
+    let red = root.as_mut().add_state();
+    let green = root.as_mut().add_state();
+    // Safety: the states are owned by the machine, which outlives them in the TrafficLight
+    unsafe {
+        root.set_initial_state(red);
+        for (source, event, target) in [(red, "go", green), (green, "stop", red)] {
+            let transitions = transitions.clone();
+            Pin::new_unchecked(&mut *source).add_transition(
+                &QString::from(event),
+                target,
+                move || transitions.set(transitions.get() + 1),
+            );
+        }
+    }
+
+    Box::new(TrafficLight {
+        machine,
+        red,
+        green,
+        transitions,
+    })
+}
+
+impl TrafficLight {
+    fn start(&mut self) {
+        self.machine.pin_mut().start();
+    }
+
+    fn post_event(&mut self, event: &QString) {
+        self.machine.pin_mut().post_event(event);
+    }
+
+    fn is_running(&self) -> bool {
+        self.machine.is_running()
+    }
+
+    fn is_red(&self) -> bool {
+        // Safety: the state is owned by the machine, which is alive
+        unsafe { &*self.red }.active()
+    }
+
+    fn is_green(&self) -> bool {
+        // Safety: the state is owned by the machine, which is alive
+        unsafe { &*self.green }.active()
+    }
+
+    fn transitions(&self) -> i32 {
+        self.transitions.get()
+    }
+}
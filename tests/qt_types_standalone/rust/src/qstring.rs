@@ -5,6 +5,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use cxx_qt_lib::QString;
+use std::cmp::Ordering;
 
 #[cxx::bridge]
 mod qstring_cxx {
@@ -20,6 +21,9 @@ mod qstring_cxx {
         fn modify_qstring(s: Pin<&mut QString>);
         fn can_handle_qstring_change() -> bool;
         fn clone_qstring(s: &QString) -> QString;
+        fn arg_qstring(s: &QString) -> QString;
+        fn locale_aware_compare_qstring(a: &QString, b: &QString) -> i32;
+        fn qformat_qstring(name: &QString) -> QString;
     }
 }
 
@@ -57,3 +61,28 @@ fn can_handle_qstring_change() -> bool {
 fn clone_qstring(s: &QString) -> QString {
     s.clone()
 }
+
+fn arg_qstring(s: &QString) -> QString {
+    s.arg_i64(-89).arg_u64(u64::MAX).arg_f64(1.5)
+}
+
+fn locale_aware_compare_qstring(a: &QString, b: &QString) -> i32 {
+    match a.locale_aware_compare(b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+fn qformat_qstring(name: &QString) -> QString {
+    let unit = "kg".to_owned();
+    // The place markers are numbered, so they can be in a different order than the arguments
+    cxx_qt_lib::qformat!(
+        "%2 has %1 items weighing %3%4 by %5",
+        3_u8,
+        name,
+        1.5_f32,
+        unit,
+        "KDAB"
+    )
+}
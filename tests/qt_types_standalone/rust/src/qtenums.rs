@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::qt::{AlignmentFlag, ItemDataRole, Key, MouseButton};
+
+#[cxx::bridge]
+mod qtenums_cxx {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type AlignmentFlag = cxx_qt_lib::qt::AlignmentFlag;
+        type ItemDataRole = cxx_qt_lib::qt::ItemDataRole;
+        type Key = cxx_qt_lib::qt::Key;
+        type MouseButton = cxx_qt_lib::qt::MouseButton;
+    }
+
+    extern "Rust" {
+        fn alignment_center() -> AlignmentFlag;
+        fn user_role(offset: i32) -> i32;
+        fn read_key(key: Key) -> bool;
+        fn read_mouse_button(button: MouseButton) -> i32;
+        fn item_data_role_from_int(role: i32) -> ItemDataRole;
+    }
+}
+
+fn alignment_center() -> AlignmentFlag {
+    AlignmentFlag::from(AlignmentFlag::AlignHCenter.repr | AlignmentFlag::AlignVCenter.repr)
+}
+
+fn user_role(offset: i32) -> i32 {
+    i32::from(ItemDataRole::UserRole) + offset
+}
+
+fn read_key(key: Key) -> bool {
+    key == Key::Key_A
+}
+
+fn read_mouse_button(button: MouseButton) -> i32 {
+    i32::from(button)
+}
+
+fn item_data_role_from_int(role: i32) -> ItemDataRole {
+    ItemDataRole::from(role)
+}
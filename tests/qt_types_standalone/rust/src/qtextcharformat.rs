@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QColor, QTextCharFormat};
+
+#[cxx::bridge]
+mod qtextcharformat_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtextcharformat.h");
+
+        type QTextCharFormat = cxx_qt_lib::QTextCharFormat;
+    }
+
+    extern "Rust" {
+        fn construct_qtextcharformat() -> QTextCharFormat;
+        fn clone_qtextcharformat(f: &QTextCharFormat) -> QTextCharFormat;
+    }
+}
+
+fn construct_qtextcharformat() -> QTextCharFormat {
+    let mut format = QTextCharFormat::default();
+    format.set_font_italic(true);
+    format.set_foreground(&QColor::from_rgb(255, 0, 0));
+    format
+}
+
+fn clone_qtextcharformat(f: &QTextCharFormat) -> QTextCharFormat {
+    f.clone()
+}
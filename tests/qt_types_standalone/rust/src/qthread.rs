@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{MoveToThread, QThread, ThreadAffinity};
+
+// Only QObjects defined in Rust can be moved to a thread from Rust
+#[cxx_qt::bridge]
+mod qthread_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = cxx_qt_lib::QThread;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        type ThreadWorker = super::ThreadWorkerRust;
+
+        #[qinvokable]
+        fn check_thread(self: &ThreadWorker);
+    }
+
+    extern "Rust" {
+        fn run_qthread() -> bool;
+        fn move_to_qthread(worker: Pin<&mut ThreadWorker>, thread: &QThread) -> bool;
+        fn is_current_qthread(thread: &QThread) -> bool;
+        fn is_on_current_qthread(worker: &ThreadWorker) -> bool;
+        fn lives_in_qthread(worker: &ThreadWorker, thread: &QThread) -> bool;
+    }
+}
+
+#[derive(Default)]
+pub struct ThreadWorkerRust;
+
+impl qthread_cxx::ThreadWorker {
+    fn check_thread(&self) {
+        cxx_qt_lib::assert_on_object_thread!(self);
+    }
+}
+
+fn run_qthread() -> bool {
+    let mut thread = QThread::new();
+    if thread.is_running() || thread.is_finished() {
+        return false;
+    }
+
+    thread.pin_mut().start();
+    if !thread.is_running() {
+        return false;
+    }
+
+    thread.pin_mut().quit();
+    thread.pin_mut().wait() && thread.is_finished() && !thread.is_running()
+}
+
+fn move_to_qthread(worker: Pin<&mut qthread_cxx::ThreadWorker>, thread: &QThread) -> bool {
+    worker.move_to_thread(thread)
+}
+
+fn is_current_qthread(thread: &QThread) -> bool {
+    thread.is_current_thread()
+}
+
+fn is_on_current_qthread(worker: &qthread_cxx::ThreadWorker) -> bool {
+    worker.is_on_current_thread()
+}
+
+fn lives_in_qthread(worker: &qthread_cxx::ThreadWorker, thread: &QThread) -> bool {
+    core::ptr::eq(worker.thread(), thread)
+}
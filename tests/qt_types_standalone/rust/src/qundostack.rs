@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+use cxx_qt_lib_extras::{QUndoStack, UndoCommand};
+use std::{cell::Cell, rc::Rc};
+
+#[cxx::bridge]
+mod qundostack_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qundostack.h");
+        type QUndoStack = cxx_qt_lib_extras::QUndoStack;
+    }
+
+    extern "Rust" {
+        type UndoCounter;
+
+        fn construct_undo_counter() -> Box<UndoCounter>;
+        fn push_increment(self: &UndoCounter, stack: Pin<&mut QUndoStack>);
+        fn push_double(self: &UndoCounter, stack: Pin<&mut QUndoStack>);
+        fn value(self: &UndoCounter) -> i32;
+    }
+}
+
+struct Increment {
+    value: Rc<Cell<i32>>,
+}
+
+impl UndoCommand for Increment {
+    fn redo(&mut self) {
+        self.value.set(self.value.get() + 1);
+    }
+
+    fn undo(&mut self) {
+        self.value.set(self.value.get() - 1);
+    }
+}
+
+struct UndoCounter {
+    value: Rc<Cell<i32>>,
+}
+
+fn construct_undo_counter() -> Box<UndoCounter> {
+    Box::new(UndoCounter {
+        value: Rc::new(Cell::new(0)),
+    })
+}
+
+impl UndoCounter {
+    fn push_increment(&self, stack: Pin<&mut QUndoStack>) {
+        stack.push(
+            &QString::from("Increment"),
+            Increment {
+                value: self.value.clone(),
+            },
+        );
+    }
+
+    fn push_double(&self, stack: Pin<&mut QUndoStack>) {
+        let redo_value = self.value.clone();
+        let undo_value = self.value.clone();
+        stack.push_fn(
+            &QString::from("Double"),
+            move || redo_value.set(redo_value.get() * 2),
+            move || undo_value.set(undo_value.get() / 2),
+        );
+    }
+
+    fn value(&self) -> i32 {
+        self.value.get()
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QValidatorState};
+
+#[cxx::bridge]
+mod qvalidator_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qvalidator.h");
+        type QValidatorState = cxx_qt_lib::QValidatorState;
+    }
+
+    extern "Rust" {
+        fn validate_digits(input: &QString) -> QValidatorState;
+        fn read_qvalidatorstate(state: QValidatorState) -> QString;
+    }
+}
+
+fn validate_digits(input: &QString) -> QValidatorState {
+    let input = input.to_string();
+    if input.is_empty() {
+        QValidatorState::Intermediate
+    } else if input.chars().all(|c| c.is_ascii_digit()) {
+        QValidatorState::Acceptable
+    } else {
+        QValidatorState::Invalid
+    }
+}
+
+fn read_qvalidatorstate(state: QValidatorState) -> QString {
+    QString::from(match state {
+        QValidatorState::Invalid => "Invalid",
+        QValidatorState::Intermediate => "Intermediate",
+        QValidatorState::Acceptable => "Acceptable",
+        _ => "Unknown",
+    })
+}
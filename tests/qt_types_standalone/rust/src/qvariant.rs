@@ -4,10 +4,16 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx_qt_lib::{
-    QByteArray, QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString,
-    QTime, QTimeZone, QUrl, QVariant,
+    IntoQVariant, QByteArray, QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize,
+    QSizeF, QString, QTime, QTimeZone, QUrl, QVariant, QVariantMap,
 };
 
+#[derive(IntoQVariant)]
+struct Person {
+    first_name: QString,
+    age: i32,
+}
+
 #[cxx::bridge]
 mod qvariant_cxx {
     enum VariantTest {
@@ -30,6 +36,8 @@ mod qvariant_cxx {
         QTime,
         QUrl,
         QString,
+        QVariantMap,
+        QVariantMapMissingKey,
         U8,
         U16,
         U32,
@@ -39,12 +47,16 @@ mod qvariant_cxx {
         include!("cxx-qt-lib/qvariant.h");
 
         type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
     }
 
     extern "Rust" {
         fn construct_qvariant(test: VariantTest) -> QVariant;
         fn read_qvariant(v: &QVariant, test: VariantTest) -> bool;
         fn clone_qvariant(v: &QVariant) -> QVariant;
+        fn type_name_qvariant(v: &QVariant) -> QString;
+        fn debug_qvariant(v: &QVariant) -> QString;
     }
 }
 
@@ -75,6 +87,10 @@ fn construct_qvariant(test: VariantTest) -> QVariant {
         VariantTest::QString => QVariant::from(&QString::from("Rust string")),
         VariantTest::QTime => QVariant::from(&QTime::new(1, 2, 3, 4)),
         VariantTest::QUrl => QVariant::from(&QUrl::from("https://github.com/KDAB")),
+        VariantTest::QVariantMap => QVariant::from(&Person {
+            first_name: QString::from("Rust"),
+            age: 42,
+        }),
         VariantTest::U8 => QVariant::from(&12_u8),
         VariantTest::U16 => QVariant::from(&123_u16),
         VariantTest::U32 => QVariant::from(&123_u32),
@@ -84,8 +100,9 @@ fn construct_qvariant(test: VariantTest) -> QVariant {
 
 fn read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
     match test {
+        // QMetaType::Bool is 1 in both Qt 5 and Qt 6
         VariantTest::Bool => match v.value::<bool>() {
-            Some(b) => !b,
+            Some(b) => !b && v.user_type() == 1,
             None => false,
         },
         VariantTest::F32 => match v.value::<f32>() {
@@ -183,6 +200,18 @@ fn read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
             Some(url) => url.to_string() == "https://github.com/KDAB/cxx-qt",
             None => false,
         },
+        VariantTest::QVariantMap => match v.value::<Person>() {
+            Some(person) => person.first_name.to_string() == "C++" && person.age == 89,
+            None => false,
+        },
+        VariantTest::QVariantMapMissingKey => {
+            let map = v.value_or_default::<QVariantMap>();
+            v.value::<Person>().is_none()
+                && matches!(
+                    Person::try_from(&map),
+                    Err("The QVariantMap is missing the key age")
+                )
+        }
         VariantTest::U8 => match v.value::<u8>() {
             Some(i) => i == 89,
             None => false,
@@ -202,3 +231,11 @@ fn read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
 fn clone_qvariant(v: &QVariant) -> QVariant {
     v.clone()
 }
+
+fn type_name_qvariant(v: &QVariant) -> QString {
+    v.type_name()
+}
+
+fn debug_qvariant(v: &QVariant) -> QString {
+    QString::from(&format!("{v:?}"))
+}
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QVariant};
+use cxx_qt_lib_extras::{
+    QAbstractAnimationDirection, QAbstractAnimationState, QEasingCurveType, QPropertyAnimation,
+    QVariantAnimation,
+};
+
+#[cxx::bridge]
+mod qvariantanimation_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qvariantanimation.h");
+        type QVariantAnimation = cxx_qt_lib_extras::QVariantAnimation;
+
+        include!("cxx-qt-lib-extras/qpropertyanimation.h");
+        type QPropertyAnimation = cxx_qt_lib_extras::QPropertyAnimation;
+    }
+
+    extern "Rust" {
+        fn construct_qvariantanimation() -> UniquePtr<QVariantAnimation>;
+        fn read_qvariantanimation(a: &QVariantAnimation) -> bool;
+        fn start_qvariantanimation(a: Pin<&mut QVariantAnimation>) -> bool;
+        fn read_qpropertyanimation(a: &QPropertyAnimation) -> bool;
+        fn modify_qpropertyanimation(a: Pin<&mut QPropertyAnimation>);
+    }
+}
+
+fn construct_qvariantanimation() -> cxx::UniquePtr<QVariantAnimation> {
+    let mut animation = QVariantAnimation::new();
+    if let Some(mut animation) = animation.as_mut() {
+        animation.as_mut().set_start_value(&QVariant::from(&0_i32));
+        animation.as_mut().set_end_value(&QVariant::from(&100_i32));
+        animation.as_mut().set_duration(1000);
+        animation.as_mut().set_loop_count(2);
+        animation
+            .as_mut()
+            .set_direction(QAbstractAnimationDirection::Backward);
+        animation
+            .as_mut()
+            .set_easing_curve_type(QEasingCurveType::OutCubic);
+    }
+    animation
+}
+
+fn read_qvariantanimation(animation: &QVariantAnimation) -> bool {
+    animation.start_value().value::<i32>() == Some(10)
+        && animation.end_value().value::<i32>() == Some(20)
+        && animation.duration() == 500
+        && animation.loop_count() == 1
+        && animation.direction() == QAbstractAnimationDirection::Forward
+        && animation.easing_curve_type() == QEasingCurveType::InQuad
+        && animation.state() == QAbstractAnimationState::Stopped
+}
+
+fn start_qvariantanimation(mut animation: Pin<&mut QVariantAnimation>) -> bool {
+    animation.as_mut().start();
+    animation.state() == QAbstractAnimationState::Running
+}
+
+fn read_qpropertyanimation(animation: &QPropertyAnimation) -> bool {
+    // QPropertyAnimation derefs to its QVariantAnimation base class
+    animation.property_name() == QByteArray::from("opacity") && animation.duration() == 250
+}
+
+fn modify_qpropertyanimation(mut animation: Pin<&mut QPropertyAnimation>) {
+    animation
+        .as_mut()
+        .set_property_name(&QByteArray::from("scale"));
+    animation.as_variant_animation_mut().set_duration(750);
+}
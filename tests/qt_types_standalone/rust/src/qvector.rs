@@ -3,19 +3,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::QVector;
+use cxx_qt_lib::{QList, QVector};
 
 #[cxx::bridge]
 mod qvector_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qvector.h");
         type QVector_i32 = cxx_qt_lib::QVector<i32>;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_i32 = cxx_qt_lib::QList<i32>;
     }
 
     extern "Rust" {
         fn construct_qvector_i32() -> QVector_i32;
         fn read_qvector_i32(v: &QVector_i32) -> bool;
         fn clone_qvector_i32(v: &QVector_i32) -> QVector_i32;
+        fn qlist_from_qvector_i32(v: &QVector_i32) -> QList_i32;
     }
 }
 
@@ -38,3 +41,10 @@ fn read_qvector_i32(v: &QVector<i32>) -> bool {
 fn clone_qvector_i32(v: &QVector<i32>) -> QVector<i32> {
     v.clone()
 }
+
+fn qlist_from_qvector_i32(v: &QVector<i32>) -> QList<i32> {
+    let list = QList::from(v);
+    // Converting back to a QVector keeps the values
+    assert!(QVector::from(&list) == *v);
+    list
+}
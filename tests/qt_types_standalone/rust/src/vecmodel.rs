@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QVariant};
+use cxx_qt_lib_extras::{VecModel, VecModelItem};
+
+#[cxx::bridge]
+mod vecmodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/vecmodel.h");
+        type VecListModel = cxx_qt_lib_extras::VecListModel;
+    }
+
+    extern "Rust" {
+        type FruitModel;
+
+        fn construct_fruit_model() -> Box<FruitModel>;
+        fn model(self: &FruitModel) -> *mut VecListModel;
+        fn len(self: &FruitModel) -> usize;
+        fn insert(self: &mut FruitModel, index: usize, name: &QString, count: i32);
+        fn remove(self: &mut FruitModel, index: usize) -> QString;
+        fn set(self: &mut FruitModel, index: usize, name: &QString, count: i32) -> QString;
+        fn update_count(self: &mut FruitModel, index: usize, count: i32);
+        fn reset(self: &mut FruitModel, names: &QString);
+    }
+}
+
+use vecmodel_cxx::VecListModel;
+
+struct Fruit {
+    name: QString,
+    count: i32,
+}
+
+impl VecModelItem for Fruit {
+    fn role_names() -> Vec<&'static str> {
+        vec!["name", "count"]
+    }
+
+    fn data(&self, role: usize) -> QVariant {
+        match role {
+            0 => QVariant::from(&self.name),
+            1 => QVariant::from(&self.count),
+            _ => QVariant::default(),
+        }
+    }
+}
+
+struct FruitModel {
+    fruits: VecModel<Fruit>,
+}
+
+fn construct_fruit_model() -> Box<FruitModel> {
+    Box::new(FruitModel {
+        fruits: VecModel::new(vec![Fruit {
+            name: QString::from("apple"),
+            count: 1,
+        }]),
+    })
+}
+
+impl FruitModel {
+    fn model(&self) -> *mut VecListModel {
+        self.fruits.model()
+    }
+
+    fn len(&self) -> usize {
+        self.fruits.len()
+    }
+
+    fn insert(&mut self, index: usize, name: &QString, count: i32) {
+        self.fruits.insert(
+            index,
+            Fruit {
+                name: name.clone(),
+                count,
+            },
+        );
+    }
+
+    fn remove(&mut self, index: usize) -> QString {
+        self.fruits.remove(index).name
+    }
+
+    fn set(&mut self, index: usize, name: &QString, count: i32) -> QString {
+        self.fruits
+            .set(
+                index,
+                Fruit {
+                    name: name.clone(),
+                    count,
+                },
+            )
+            .name
+    }
+
+    fn update_count(&mut self, index: usize, count: i32) {
+        self.fruits.update(index, |fruit| fruit.count = count);
+    }
+
+    fn reset(&mut self, names: &QString) {
+        self.fruits.set_items(
+            names
+                .to_string()
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(|name| Fruit {
+                    name: QString::from(name),
+                    count: 0,
+                })
+                .collect(),
+        );
+    }
+}